@@ -0,0 +1,71 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time::Duration;
+
+extern crate alloc;
+use alloc::boxed::Box;
+
+use iceoryx2::prelude::*;
+use iceoryx2_bb_container::slice_container::SliceContainer;
+use iceoryx2_bb_container::vector::*;
+
+const CYCLE_TIME: Duration = Duration::from_secs(1);
+const MAX_CAPACITY: usize = 1000;
+
+fn main() -> Result<(), Box<dyn core::error::Error>> {
+    set_log_level_from_env_or(LogLevel::Info);
+
+    let node = NodeBuilder::new().create::<ipc::Service>()?;
+
+    let service = node
+        .service_builder(&"Service With Dynamic Container".try_into()?)
+        .publish_subscribe::<[u8]>()
+        // the container inside the slice requires a stricter alignment than u8
+        .payload_alignment(
+            Alignment::new(RelocatableVec::<u64>::required_alignment())
+                .ok_or("invalid alignment")?,
+        )
+        .open_or_create()?;
+
+    let publisher = service
+        .publisher_builder()
+        .initial_max_slice_len(RelocatableVec::<u64>::required_slice_len(16))
+        .allocation_strategy(AllocationStrategy::PowerOfTwo)
+        .create()?;
+
+    let mut counter: usize = 1;
+
+    while node.wait(CYCLE_TIME).is_ok() {
+        // the capacity of the vector is only known at runtime
+        let capacity = counter.min(MAX_CAPACITY);
+        let mut sample =
+            publisher.loan_slice_uninit(RelocatableVec::<u64>::required_slice_len(capacity))?;
+
+        let vec = RelocatableVec::<u64>::create_in_slice(sample.payload_mut(), capacity)?;
+        for n in 0..capacity {
+            vec.push((n * counter) as u64)?;
+        }
+
+        // SAFETY: create_in_slice() initializes every byte of the slice
+        let sample = unsafe { sample.assume_init() };
+        sample.send()?;
+
+        coutln!("Send vector with {capacity} elements...");
+
+        counter += 1;
+    }
+
+    coutln!("exit");
+
+    Ok(())
+}
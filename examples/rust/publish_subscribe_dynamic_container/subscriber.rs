@@ -0,0 +1,59 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time::Duration;
+
+extern crate alloc;
+use alloc::boxed::Box;
+
+use iceoryx2::prelude::*;
+use iceoryx2_bb_container::slice_container::SliceContainer;
+use iceoryx2_bb_container::vector::*;
+
+const CYCLE_TIME: Duration = Duration::from_secs(1);
+
+fn main() -> Result<(), Box<dyn core::error::Error>> {
+    set_log_level_from_env_or(LogLevel::Info);
+
+    let node = NodeBuilder::new().create::<ipc::Service>()?;
+
+    let service = node
+        .service_builder(&"Service With Dynamic Container".try_into()?)
+        .publish_subscribe::<[u8]>()
+        .payload_alignment(
+            Alignment::new(RelocatableVec::<u64>::required_alignment())
+                .ok_or("invalid alignment")?,
+        )
+        .open_or_create()?;
+
+    let subscriber = service.subscriber_builder().create()?;
+
+    coutln!("Subscriber ready to receive data!");
+
+    while node.wait(CYCLE_TIME).is_ok() {
+        while let Some(sample) = subscriber.receive()? {
+            // the layout of the vector is validated before it can be accessed
+            match RelocatableVec::<u64>::view_in_slice(sample.payload()) {
+                Ok(vec) => coutln!(
+                    "received vector with {} elements, last element: {:?}",
+                    vec.len(),
+                    vec.last()
+                ),
+                Err(e) => coutln!("received sample with an invalid vector: {e}"),
+            }
+        }
+    }
+
+    coutln!("exit");
+
+    Ok(())
+}
@@ -12,11 +12,21 @@
 
 use core::ops::{Deref, DerefMut};
 
+#[cfg(not(all(loom, feature = "std")))]
 pub use iceoryx2_pal_concurrency_sync::atomic::Ordering;
+#[cfg(not(all(loom, feature = "std")))]
 pub use iceoryx2_pal_concurrency_sync::atomic::fence;
 
+#[cfg(all(loom, feature = "std"))]
+pub use loom::sync::atomic::Ordering;
+#[cfg(all(loom, feature = "std"))]
+pub use loom::sync::atomic::fence;
+
 use iceoryx2_bb_elementary_traits::{
-    placement_default::PlacementDefault, zero_copy_send::ZeroCopySend, zeroable::Zeroable,
+    placement_default::PlacementDefault,
+    validate::{Validate, ValidationBounds, ValidationError},
+    zero_copy_send::ZeroCopySend,
+    zeroable::Zeroable,
 };
 
 #[derive(Debug, Default)]
@@ -66,10 +76,18 @@ pub struct AtomicIsize(internal::AtomicIsize);
 macro_rules! Impl {
     ($type_name:ident, $base_type:ident) => {
         impl $type_name {
+            #[cfg(not(all(loom, feature = "std")))]
             #[inline]
             pub const fn new(v: $base_type) -> Self {
                 Self(internal::$type_name::new(v))
             }
+
+            // loom does not provide const-initialization for atomic variables
+            #[cfg(all(loom, feature = "std"))]
+            #[inline]
+            pub fn new(v: $base_type) -> Self {
+                Self(internal::$type_name::new(v))
+            }
         }
 
         impl Deref for $type_name {
@@ -93,6 +111,30 @@ macro_rules! Impl {
 
         unsafe impl ZeroCopySend for $type_name {}
         unsafe impl Zeroable for $type_name {}
+
+        // the core atomics have the same size, alignment and bit validity as the underlying
+        // type, the atomics of loom do not
+        #[cfg(not(all(loom, feature = "std")))]
+        unsafe impl Validate for $type_name {
+            unsafe fn validate(
+                ptr: *const Self,
+                bounds: &ValidationBounds,
+            ) -> Result<(), ValidationError> {
+                unsafe { <$base_type as Validate>::validate(ptr.cast(), bounds) }
+            }
+        }
+
+        // the memory of a loom atomic is never received from another process and its bit
+        // pattern cannot be validated, therefore it is always rejected
+        #[cfg(all(loom, feature = "std"))]
+        unsafe impl Validate for $type_name {
+            unsafe fn validate(
+                _ptr: *const Self,
+                _bounds: &ValidationBounds,
+            ) -> Result<(), ValidationError> {
+                Err(ValidationError::InvalidBitPattern)
+            }
+        }
     };
 }
 
@@ -152,17 +194,19 @@ unsafe impl<T: internal::AtomicInteger> ZeroCopySend for Atomic<T> {}
 
 pub mod internal {
     pub use iceoryx2_pal_concurrency_sync::atomic::Atomic;
-    pub use iceoryx2_pal_concurrency_sync::atomic::AtomicBool;
-    pub use iceoryx2_pal_concurrency_sync::atomic::AtomicI8;
-    pub use iceoryx2_pal_concurrency_sync::atomic::AtomicI16;
-    pub use iceoryx2_pal_concurrency_sync::atomic::AtomicI32;
-    pub use iceoryx2_pal_concurrency_sync::atomic::AtomicI64;
-    pub use iceoryx2_pal_concurrency_sync::atomic::AtomicIsize;
-    pub use iceoryx2_pal_concurrency_sync::atomic::AtomicU8;
-    pub use iceoryx2_pal_concurrency_sync::atomic::AtomicU16;
-    pub use iceoryx2_pal_concurrency_sync::atomic::AtomicU32;
-    pub use iceoryx2_pal_concurrency_sync::atomic::AtomicU64;
-    pub use iceoryx2_pal_concurrency_sync::atomic::AtomicUsize;
+
+    #[cfg(not(all(loom, feature = "std")))]
+    pub use iceoryx2_pal_concurrency_sync::atomic::{
+        AtomicBool, AtomicI8, AtomicI16, AtomicI32, AtomicI64, AtomicIsize, AtomicU8, AtomicU16,
+        AtomicU32, AtomicU64, AtomicUsize,
+    };
+
+    // a build with `--cfg loom` replaces all atomics with the model checked atomics of loom
+    #[cfg(all(loom, feature = "std"))]
+    pub use loom::sync::atomic::{
+        AtomicBool, AtomicI8, AtomicI16, AtomicI32, AtomicI64, AtomicIsize, AtomicU8, AtomicU16,
+        AtomicU32, AtomicU64, AtomicUsize,
+    };
 
     #[doc(hidden)]
     pub use iceoryx2_pal_concurrency_sync::atomic::internal::AtomicInteger;
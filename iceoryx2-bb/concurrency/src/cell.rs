@@ -92,16 +92,66 @@ impl<T: Default> PlacementDefault for RefCell<T> {
 pub struct UnsafeCell<T>(internal::UnsafeCell<T>);
 
 impl<T> UnsafeCell<T> {
+    #[cfg(not(all(loom, feature = "std")))]
     pub const fn new(value: T) -> Self {
         Self(internal::UnsafeCell::new(value))
     }
 
+    // loom does not provide const-initialization for cells
+    #[cfg(all(loom, feature = "std"))]
+    pub fn new(value: T) -> Self {
+        Self(internal::UnsafeCell::new(value))
+    }
+
+    /// Returns a raw pointer to the underlying data. In a loom build the access is tracked
+    /// as mutable access, prefer [`UnsafeCell::with()`] or [`UnsafeCell::with_mut()`] so that
+    /// concurrent reads are not reported as data race.
     pub fn get(&self) -> *mut T {
-        self.0.get()
+        #[cfg(not(all(loom, feature = "std")))]
+        {
+            self.0.get()
+        }
+        #[cfg(all(loom, feature = "std"))]
+        {
+            self.0.get_mut().with(|ptr| ptr)
+        }
     }
 
     pub fn get_mut(&mut self) -> &mut T {
-        self.0.get_mut()
+        #[cfg(not(all(loom, feature = "std")))]
+        {
+            self.0.get_mut()
+        }
+        #[cfg(all(loom, feature = "std"))]
+        {
+            self.0.with_mut(|ptr| unsafe { &mut *ptr })
+        }
+    }
+
+    /// Calls the closure with a pointer that is only allowed to be used for reading the
+    /// underlying data.
+    pub fn with<R, F: FnOnce(*const T) -> R>(&self, f: F) -> R {
+        #[cfg(not(all(loom, feature = "std")))]
+        {
+            f(self.0.get())
+        }
+        #[cfg(all(loom, feature = "std"))]
+        {
+            self.0.with(f)
+        }
+    }
+
+    /// Calls the closure with a pointer that can be used to read and write the underlying
+    /// data.
+    pub fn with_mut<R, F: FnOnce(*mut T) -> R>(&self, f: F) -> R {
+        #[cfg(not(all(loom, feature = "std")))]
+        {
+            f(self.0.get())
+        }
+        #[cfg(all(loom, feature = "std"))]
+        {
+            self.0.with_mut(f)
+        }
     }
 }
 
@@ -121,5 +171,9 @@ mod internal {
     pub use iceoryx2_pal_concurrency_sync::cell::Ref;
     pub use iceoryx2_pal_concurrency_sync::cell::RefCell;
     pub use iceoryx2_pal_concurrency_sync::cell::RefMut;
+
+    #[cfg(not(all(loom, feature = "std")))]
     pub use iceoryx2_pal_concurrency_sync::cell::UnsafeCell;
+    #[cfg(all(loom, feature = "std"))]
+    pub use loom::cell::UnsafeCell;
 }
@@ -0,0 +1,853 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Ordered maps and sets that are shared-memory compatible.
+//!
+//!  * [`StaticBTreeMap`] and [`StaticBTreeSet`], compile-time fixed-capacity variants that are
+//!    self-contained.
+//!  * [`RelocatableBTreeMap`] and [`RelocatableBTreeSet`], run-time fixed-capacity variants
+//!    that acquire the memory from an allocator.
+//!
+//! The entries are stored in nodes of a fixed-capacity arena and the nodes are linked by their
+//! index in the arena, so that the containers contain no absolute pointers. The nodes form a
+//! self-balancing (AVL) search tree, therefore insert, remove and lookup are `O(log n)` and
+//! the entries are always iterated in ascending key order.
+//!
+//! # Example
+//!
+//! ```
+//! # extern crate iceoryx2_bb_loggers;
+//!
+//! use iceoryx2_bb_container::btree::*;
+//!
+//! const CAPACITY: usize = 16;
+//! let mut map = StaticBTreeMap::<u32, u64, CAPACITY>::new();
+//!
+//! map.insert(3, 30).unwrap();
+//! map.insert(1, 10).unwrap();
+//! map.insert(2, 20).unwrap();
+//!
+//! assert_eq!(map.get(&2), Some(&20));
+//! assert_eq!(map.iter().map(|(k, _)| *k).collect::<Vec<_>>(), vec![1, 2, 3]);
+//! assert_eq!(map.range(2..).count(), 2);
+//! ```
+
+use core::cmp::Ordering;
+use core::mem::MaybeUninit;
+use core::ops::{Bound, RangeBounds};
+
+/// Runtime fixed-capacity shared-memory compatible ordered map
+pub mod relocatable_btree_map;
+/// Runtime fixed-capacity shared-memory compatible ordered set
+pub mod relocatable_btree_set;
+/// Compile-time fixed-capacity shared-memory compatible ordered map
+pub mod static_btree_map;
+/// Compile-time fixed-capacity shared-memory compatible ordered set
+pub mod static_btree_set;
+
+use alloc::format;
+use iceoryx2_bb_elementary_traits::validate::{Validate, ValidationBounds, ValidationError};
+use iceoryx2_log::fail;
+pub use relocatable_btree_map::*;
+pub use relocatable_btree_set::*;
+pub use static_btree_map::*;
+pub use static_btree_set::*;
+
+const INVALID_INDEX: u64 = u64::MAX;
+
+/// Error which can occur when an entry is inserted into a [`BTreeMap`] or [`BTreeSet`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum BTreeModificationError {
+    /// The container already contains the key that shall be inserted.
+    KeyAlreadyExists,
+    /// The container is full and cannot hold an additional entry.
+    IsFull,
+}
+
+impl core::fmt::Display for BTreeModificationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "BTreeModificationError::{self:?}")
+    }
+}
+
+impl core::error::Error for BTreeModificationError {}
+
+#[doc(hidden)]
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct Links {
+    left: u64,
+    right: u64,
+    parent: u64,
+    height: u64,
+}
+
+#[doc(hidden)]
+#[repr(C)]
+pub struct Node<K, V> {
+    links: Links,
+    key: MaybeUninit<K>,
+    value: MaybeUninit<V>,
+}
+
+#[doc(hidden)]
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct TreeState {
+    root: u64,
+    len: u64,
+    free_list_head: u64,
+    next_unused_node: u64,
+}
+
+impl TreeState {
+    pub(crate) const fn new() -> Self {
+        Self {
+            root: INVALID_INDEX,
+            len: 0,
+            free_list_head: INVALID_INDEX,
+            next_unused_node: 0,
+        }
+    }
+}
+
+pub(crate) mod internal {
+    use super::*;
+
+    #[doc(hidden)]
+    pub trait BTreeMapView<K, V> {
+        fn state(&self) -> &TreeState;
+
+        fn nodes(&self) -> &[MaybeUninit<Node<K, V>>];
+
+        /// # Safety
+        ///
+        /// * user must keep the [`TreeState`] consistent with the nodes
+        unsafe fn parts_mut(&mut self) -> (&mut TreeState, &mut [MaybeUninit<Node<K, V>>]);
+    }
+
+    #[doc(hidden)]
+    pub trait BTreeSetView<T: Ord> {
+        type Map: BTreeMap<T, ()>;
+
+        fn map(&self) -> &Self::Map;
+
+        fn map_mut(&mut self) -> &mut Self::Map;
+    }
+}
+
+/// Validates that the [`TreeState`] is consistent with the nodes, that the entries form a
+/// proper tree and the free nodes a proper list and that all keys and values are valid. The
+/// order of the keys is not validated, a wrong order results in wrong lookup results but does
+/// not corrupt the memory.
+///
+/// # Safety
+///
+/// * `nodes` must point to `capacity` readable nodes of which the first
+///   `state.next_unused_node` are initialized, when `state.next_unused_node <= capacity`
+unsafe fn validate_nodes<K: Validate, V: Validate>(
+    state: &TreeState,
+    nodes: *const MaybeUninit<Node<K, V>>,
+    capacity: u64,
+    bounds: &ValidationBounds,
+) -> Result<(), ValidationError> {
+    if capacity < state.next_unused_node || state.next_unused_node < state.len {
+        return Err(ValidationError::InvariantViolation);
+    }
+
+    let node_ptr = |index: u64| unsafe { nodes.add(index as usize) }.cast::<Node<K, V>>();
+    let links = |index: u64| unsafe { (*node_ptr(index)).links };
+    let is_child_of =
+        |child: u64, parent: u64| child < state.next_unused_node && links(child).parent == parent;
+
+    // in-order traversal that uses the parent links, every node is passed at most three times
+    let max_number_of_steps = 3 * state.len + 1;
+    let mut number_of_steps = 0;
+    let mut number_of_entries = 0;
+    let mut previous = INVALID_INDEX;
+    let mut current = state.root;
+    if current != INVALID_INDEX && !is_child_of(current, INVALID_INDEX) {
+        return Err(ValidationError::InvariantViolation);
+    }
+
+    while current != INVALID_INDEX {
+        number_of_steps += 1;
+        if number_of_steps > max_number_of_steps {
+            return Err(ValidationError::InvariantViolation);
+        }
+
+        let current_links = links(current);
+        let has_left = current_links.left != INVALID_INDEX;
+        let has_right = current_links.right != INVALID_INDEX;
+        let next = if previous == current_links.parent {
+            if number_of_entries == state.len
+                || current_links.height == 0
+                || (has_left && !is_child_of(current_links.left, current))
+                || (has_right && !is_child_of(current_links.right, current))
+                || (has_left && current_links.left == current_links.right)
+            {
+                return Err(ValidationError::InvariantViolation);
+            }
+
+            unsafe {
+                K::validate(
+                    core::ptr::addr_of!((*node_ptr(current)).key).cast::<K>(),
+                    bounds,
+                )?;
+                V::validate(
+                    core::ptr::addr_of!((*node_ptr(current)).value).cast::<V>(),
+                    bounds,
+                )?;
+            }
+            number_of_entries += 1;
+
+            match (has_left, has_right) {
+                (true, _) => current_links.left,
+                (false, true) => current_links.right,
+                (false, false) => current_links.parent,
+            }
+        } else if has_left && previous == current_links.left {
+            match has_right {
+                true => current_links.right,
+                false => current_links.parent,
+            }
+        } else if has_right && previous == current_links.right {
+            current_links.parent
+        } else {
+            return Err(ValidationError::InvariantViolation);
+        };
+
+        previous = current;
+        current = next;
+    }
+
+    if number_of_entries != state.len {
+        return Err(ValidationError::InvariantViolation);
+    }
+
+    let number_of_free_nodes = state.next_unused_node - state.len;
+    let mut current = state.free_list_head;
+    let mut number_of_visited_free_nodes = 0;
+    while current != INVALID_INDEX {
+        if state.next_unused_node <= current
+            || number_of_visited_free_nodes == number_of_free_nodes
+            || links(current).height != 0
+        {
+            return Err(ValidationError::InvariantViolation);
+        }
+
+        number_of_visited_free_nodes += 1;
+        current = links(current).left;
+    }
+
+    match number_of_visited_free_nodes == number_of_free_nodes {
+        true => Ok(()),
+        false => Err(ValidationError::InvariantViolation),
+    }
+}
+
+/// Read-only access to the nodes of a tree.
+struct Tree<'a, K, V> {
+    state: &'a TreeState,
+    nodes: &'a [MaybeUninit<Node<K, V>>],
+}
+
+impl<'a, K: Ord, V> Tree<'a, K, V> {
+    fn node(&self, index: u64) -> &'a Node<K, V> {
+        unsafe { self.nodes[index as usize].assume_init_ref() }
+    }
+
+    fn key(&self, index: u64) -> &'a K {
+        unsafe { self.node(index).key.assume_init_ref() }
+    }
+
+    fn find(&self, key: &K) -> u64 {
+        let mut current = self.state.root;
+        while current != INVALID_INDEX {
+            let node = self.node(current);
+            current = match key.cmp(self.key(current)) {
+                Ordering::Less => node.links.left,
+                Ordering::Greater => node.links.right,
+                Ordering::Equal => return current,
+            };
+        }
+
+        INVALID_INDEX
+    }
+
+    fn leftmost(&self, mut index: u64) -> u64 {
+        if index == INVALID_INDEX {
+            return index;
+        }
+
+        while self.node(index).links.left != INVALID_INDEX {
+            index = self.node(index).links.left;
+        }
+        index
+    }
+
+    fn rightmost(&self, mut index: u64) -> u64 {
+        if index == INVALID_INDEX {
+            return index;
+        }
+
+        while self.node(index).links.right != INVALID_INDEX {
+            index = self.node(index).links.right;
+        }
+        index
+    }
+
+    fn successor(&self, index: u64) -> u64 {
+        let links = self.node(index).links;
+        if links.right != INVALID_INDEX {
+            return self.leftmost(links.right);
+        }
+
+        let mut child = index;
+        let mut parent = links.parent;
+        while parent != INVALID_INDEX && self.node(parent).links.right == child {
+            child = parent;
+            parent = self.node(parent).links.parent;
+        }
+        parent
+    }
+
+    /// Returns the first node whose key is not below the bound.
+    fn lower_bound(&self, bound: Bound<&K>) -> u64 {
+        let is_below = |key: &K| match bound {
+            Bound::Included(start) => key < start,
+            Bound::Excluded(start) => key <= start,
+            Bound::Unbounded => false,
+        };
+
+        let mut result = INVALID_INDEX;
+        let mut current = self.state.root;
+        while current != INVALID_INDEX {
+            let node = self.node(current);
+            if is_below(self.key(current)) {
+                current = node.links.right;
+            } else {
+                result = current;
+                current = node.links.left;
+            }
+        }
+        result
+    }
+
+    /// Returns the first node whose key is above the bound.
+    fn upper_bound(&self, bound: Bound<&K>) -> u64 {
+        let is_above = |key: &K| match bound {
+            Bound::Included(end) => key > end,
+            Bound::Excluded(end) => key >= end,
+            Bound::Unbounded => false,
+        };
+
+        let mut result = INVALID_INDEX;
+        let mut current = self.state.root;
+        while current != INVALID_INDEX {
+            let node = self.node(current);
+            if is_above(self.key(current)) {
+                result = current;
+                current = node.links.left;
+            } else {
+                current = node.links.right;
+            }
+        }
+        result
+    }
+}
+
+/// Modifying access to the nodes of a tree.
+struct TreeMut<'a, K, V> {
+    state: &'a mut TreeState,
+    nodes: &'a mut [MaybeUninit<Node<K, V>>],
+}
+
+impl<K: Ord, V> TreeMut<'_, K, V> {
+    fn node(&self, index: u64) -> &Node<K, V> {
+        unsafe { self.nodes[index as usize].assume_init_ref() }
+    }
+
+    fn links(&mut self, index: u64) -> &mut Links {
+        unsafe { &mut self.nodes[index as usize].assume_init_mut().links }
+    }
+
+    fn key(&self, index: u64) -> &K {
+        unsafe { self.node(index).key.assume_init_ref() }
+    }
+
+    fn height(&self, index: u64) -> u64 {
+        if index == INVALID_INDEX {
+            0
+        } else {
+            self.node(index).links.height
+        }
+    }
+
+    fn allocate_node(&mut self, key: K, value: V) -> u64 {
+        let index = if self.state.free_list_head != INVALID_INDEX {
+            let index = self.state.free_list_head;
+            self.state.free_list_head = self.node(index).links.left;
+            index
+        } else {
+            self.state.next_unused_node += 1;
+            self.state.next_unused_node - 1
+        };
+
+        self.nodes[index as usize].write(Node {
+            links: Links {
+                left: INVALID_INDEX,
+                right: INVALID_INDEX,
+                parent: INVALID_INDEX,
+                height: 1,
+            },
+            key: MaybeUninit::new(key),
+            value: MaybeUninit::new(value),
+        });
+        self.state.len += 1;
+        index
+    }
+
+    /// Moves the key-value pair out of the node and adds the node to the free list.
+    fn release_node(&mut self, index: u64) -> (K, V) {
+        let free_list_head = self.state.free_list_head;
+        let node = unsafe { self.nodes[index as usize].assume_init_mut() };
+        let key_value = unsafe { (node.key.assume_init_read(), node.value.assume_init_read()) };
+        // nodes of the tree have at least a height of 1, free nodes are marked with 0
+        node.links.height = 0;
+        node.links.left = free_list_head;
+        self.state.free_list_head = index;
+        self.state.len -= 1;
+        key_value
+    }
+
+    /// Recalculates the height of the node and sets it as parent of its children.
+    fn update(&mut self, index: u64) {
+        let links = self.node(index).links;
+        let height = 1 + self.height(links.left).max(self.height(links.right));
+        self.links(index).height = height;
+        if links.left != INVALID_INDEX {
+            self.links(links.left).parent = index;
+        }
+        if links.right != INVALID_INDEX {
+            self.links(links.right).parent = index;
+        }
+    }
+
+    fn rotate_right(&mut self, index: u64) -> u64 {
+        let new_root = self.node(index).links.left;
+        self.links(index).left = self.node(new_root).links.right;
+        self.links(new_root).right = index;
+        self.update(index);
+        self.update(new_root);
+        new_root
+    }
+
+    fn rotate_left(&mut self, index: u64) -> u64 {
+        let new_root = self.node(index).links.right;
+        self.links(index).right = self.node(new_root).links.left;
+        self.links(new_root).left = index;
+        self.update(index);
+        self.update(new_root);
+        new_root
+    }
+
+    /// Restores the AVL property of the subtree and returns its new root.
+    fn balance(&mut self, index: u64) -> u64 {
+        self.update(index);
+        let links = self.node(index).links;
+        let left_height = self.height(links.left);
+        let right_height = self.height(links.right);
+
+        if left_height > right_height + 1 {
+            let left = self.node(links.left).links;
+            if self.height(left.left) < self.height(left.right) {
+                self.links(index).left = self.rotate_left(links.left);
+            }
+            self.rotate_right(index)
+        } else if right_height > left_height + 1 {
+            let right = self.node(links.right).links;
+            if self.height(right.right) < self.height(right.left) {
+                self.links(index).right = self.rotate_right(links.right);
+            }
+            self.rotate_left(index)
+        } else {
+            index
+        }
+    }
+
+    fn set_root(&mut self, root: u64) {
+        self.state.root = root;
+        if root != INVALID_INDEX {
+            self.links(root).parent = INVALID_INDEX;
+        }
+    }
+
+    /// # Safety
+    ///
+    /// * the key must not be contained and the tree must not be full
+    unsafe fn insert(&mut self, key: K, value: V) {
+        let root = self.state.root;
+        let root = self.insert_into(root, key, value);
+        self.set_root(root);
+    }
+
+    fn insert_into(&mut self, index: u64, key: K, value: V) -> u64 {
+        if index == INVALID_INDEX {
+            return self.allocate_node(key, value);
+        }
+
+        let links = self.node(index).links;
+        if key < *self.key(index) {
+            self.links(index).left = self.insert_into(links.left, key, value);
+        } else {
+            self.links(index).right = self.insert_into(links.right, key, value);
+        }
+        self.balance(index)
+    }
+
+    fn remove(&mut self, key: &K) -> Option<(K, V)> {
+        let root = self.state.root;
+        let (root, removed) = self.remove_from(root, key);
+        self.set_root(root);
+        removed
+    }
+
+    fn remove_from(&mut self, index: u64, key: &K) -> (u64, Option<(K, V)>) {
+        if index == INVALID_INDEX {
+            return (index, None);
+        }
+
+        let links = self.node(index).links;
+        match key.cmp(self.key(index)) {
+            Ordering::Less => {
+                let (left, removed) = self.remove_from(links.left, key);
+                self.links(index).left = left;
+                (self.balance(index), removed)
+            }
+            Ordering::Greater => {
+                let (right, removed) = self.remove_from(links.right, key);
+                self.links(index).right = right;
+                (self.balance(index), removed)
+            }
+            Ordering::Equal => {
+                let replacement = if links.left == INVALID_INDEX {
+                    links.right
+                } else if links.right == INVALID_INDEX {
+                    links.left
+                } else {
+                    let (right, min) = self.detach_min(links.right);
+                    self.links(min).left = links.left;
+                    self.links(min).right = right;
+                    self.balance(min)
+                };
+                (replacement, Some(self.release_node(index)))
+            }
+        }
+    }
+
+    /// Detaches the node with the smallest key from the subtree. Returns the new root of the
+    /// subtree and the detached node.
+    fn detach_min(&mut self, index: u64) -> (u64, u64) {
+        let links = self.node(index).links;
+        if links.left == INVALID_INDEX {
+            return (links.right, index);
+        }
+
+        let (left, min) = self.detach_min(links.left);
+        self.links(index).left = left;
+        (self.balance(index), min)
+    }
+
+    fn clear(&mut self) {
+        let tree = Tree {
+            state: self.state,
+            nodes: self.nodes,
+        };
+        let mut current = tree.leftmost(self.state.root);
+        while current != INVALID_INDEX {
+            // the links stay valid when the key and value are dropped
+            let next = Tree {
+                state: self.state,
+                nodes: self.nodes,
+            }
+            .successor(current);
+            let node = unsafe { self.nodes[current as usize].assume_init_mut() };
+            unsafe {
+                node.key.assume_init_drop();
+                node.value.assume_init_drop();
+            }
+            current = next;
+        }
+
+        *self.state = TreeState::new();
+    }
+}
+
+/// Iterator over the entries of a [`BTreeMap`] in ascending key order.
+pub struct Iter<'a, K, V> {
+    state: &'a TreeState,
+    nodes: &'a [MaybeUninit<Node<K, V>>],
+    next: u64,
+    end: u64,
+}
+
+impl<'a, K: Ord, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next == self.end {
+            return None;
+        }
+
+        let tree = Tree {
+            state: self.state,
+            nodes: self.nodes,
+        };
+        let current = self.next;
+        self.next = tree.successor(current);
+        let node = tree.node(current);
+        Some(unsafe { (node.key.assume_init_ref(), node.value.assume_init_ref()) })
+    }
+}
+
+/// Iterator over the elements of a [`BTreeSet`] in ascending order.
+pub struct SetIter<'a, T> {
+    iter: Iter<'a, T, ()>,
+}
+
+impl<'a, T: Ord> Iterator for SetIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(key, _)| key)
+    }
+}
+
+/// Defines the interface of an ordered map.
+pub trait BTreeMap<K: Ord, V>: internal::BTreeMapView<K, V> {
+    /// Returns the capacity of the map
+    fn capacity(&self) -> usize;
+
+    /// Returns the number of entries stored inside the map
+    fn len(&self) -> usize {
+        self.state().len as usize
+    }
+
+    /// Returns true if the map is empty, otherwise false
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns true if the map is full, otherwise false
+    fn is_full(&self) -> bool {
+        self.len() == self.capacity()
+    }
+
+    /// Inserts a new key-value pair. If the key is already contained or the map is full
+    /// it returns a [`BTreeModificationError`].
+    fn insert(&mut self, key: K, value: V) -> Result<(), BTreeModificationError> {
+        let origin = format!(
+            "BTreeMap::<{}, {}>::insert()",
+            core::any::type_name::<K>(),
+            core::any::type_name::<V>()
+        );
+        if self.contains_key(&key) {
+            fail!(from origin, with BTreeModificationError::KeyAlreadyExists,
+                "Failed to insert the key-value pair since the key already exists.");
+        }
+        if self.is_full() {
+            fail!(from origin, with BTreeModificationError::IsFull,
+                "Failed to insert the key-value pair since it would exceed the maps capacity of {}.",
+                self.capacity());
+        }
+
+        let (state, nodes) = unsafe { self.parts_mut() };
+        unsafe { TreeMut { state, nodes }.insert(key, value) };
+        Ok(())
+    }
+
+    /// Returns a reference to the value corresponding to the key. If there is no such key,
+    /// [`None`] is returned.
+    fn get<'a>(&'a self, key: &K) -> Option<&'a V>
+    where
+        K: 'a,
+    {
+        let tree = Tree {
+            state: self.state(),
+            nodes: self.nodes(),
+        };
+        match tree.find(key) {
+            INVALID_INDEX => None,
+            index => Some(unsafe { tree.node(index).value.assume_init_ref() }),
+        }
+    }
+
+    /// Returns a mutable reference to the value corresponding to the key. If there is no such
+    /// key, [`None`] is returned.
+    fn get_mut<'a>(&'a mut self, key: &K) -> Option<&'a mut V>
+    where
+        K: 'a,
+    {
+        let index = Tree {
+            state: self.state(),
+            nodes: self.nodes(),
+        }
+        .find(key);
+        if index == INVALID_INDEX {
+            return None;
+        }
+
+        let (_, nodes) = unsafe { self.parts_mut() };
+        Some(unsafe {
+            nodes[index as usize]
+                .assume_init_mut()
+                .value
+                .assume_init_mut()
+        })
+    }
+
+    /// Returns true if the map contains the key, otherwise false
+    fn contains_key(&self, key: &K) -> bool {
+        self.range(key..=key).next().is_some()
+    }
+
+    /// Removes the key from the map and returns the key-value pair. If there is no such key,
+    /// [`None`] is returned.
+    fn remove(&mut self, key: &K) -> Option<(K, V)> {
+        let (state, nodes) = unsafe { self.parts_mut() };
+        TreeMut { state, nodes }.remove(key)
+    }
+
+    /// Removes all entries from the map
+    fn clear(&mut self) {
+        let (state, nodes) = unsafe { self.parts_mut() };
+        TreeMut { state, nodes }.clear()
+    }
+
+    /// Returns the entry with the smallest key. If the map is empty, [`None`] is returned.
+    fn first_key_value(&self) -> Option<(&K, &V)> {
+        self.iter().next()
+    }
+
+    /// Returns the entry with the largest key. If the map is empty, [`None`] is returned.
+    fn last_key_value(&self) -> Option<(&K, &V)> {
+        let tree = Tree {
+            state: self.state(),
+            nodes: self.nodes(),
+        };
+        match tree.rightmost(self.state().root) {
+            INVALID_INDEX => None,
+            index => {
+                let node = tree.node(index);
+                Some(unsafe { (node.key.assume_init_ref(), node.value.assume_init_ref()) })
+            }
+        }
+    }
+
+    /// Returns an iterator over all entries in ascending key order.
+    fn iter(&self) -> Iter<'_, K, V> {
+        self.range(..)
+    }
+
+    /// Returns an iterator over the entries whose keys are contained in the range, in
+    /// ascending key order.
+    fn range<R: RangeBounds<K>>(&self, range: R) -> Iter<'_, K, V> {
+        let tree = Tree {
+            state: self.state(),
+            nodes: self.nodes(),
+        };
+        let start = tree.lower_bound(range.start_bound());
+        let end = tree.upper_bound(range.end_bound());
+
+        // an empty range, for instance when the start is greater than the end
+        let is_empty =
+            start == INVALID_INDEX || (end != INVALID_INDEX && tree.key(start) >= tree.key(end));
+
+        Iter {
+            state: self.state(),
+            nodes: self.nodes(),
+            next: if is_empty { end } else { start },
+            end,
+        }
+    }
+}
+
+/// Defines the interface of an ordered set.
+pub trait BTreeSet<T: Ord>: internal::BTreeSetView<T> {
+    /// Returns the capacity of the set
+    fn capacity(&self) -> usize {
+        self.map().capacity()
+    }
+
+    /// Returns the number of elements stored inside the set
+    fn len(&self) -> usize {
+        self.map().len()
+    }
+
+    /// Returns true if the set is empty, otherwise false
+    fn is_empty(&self) -> bool {
+        self.map().is_empty()
+    }
+
+    /// Returns true if the set is full, otherwise false
+    fn is_full(&self) -> bool {
+        self.map().is_full()
+    }
+
+    /// Inserts a new element. If the element is already contained or the set is full
+    /// it returns a [`BTreeModificationError`].
+    fn insert(&mut self, value: T) -> Result<(), BTreeModificationError> {
+        self.map_mut().insert(value, ())
+    }
+
+    /// Returns true if the set contains the element, otherwise false
+    fn contains(&self, value: &T) -> bool {
+        self.map().contains_key(value)
+    }
+
+    /// Removes the element from the set and returns it. If there is no such element,
+    /// [`None`] is returned.
+    fn remove(&mut self, value: &T) -> Option<T> {
+        self.map_mut().remove(value).map(|(value, _)| value)
+    }
+
+    /// Removes all elements from the set
+    fn clear(&mut self) {
+        self.map_mut().clear()
+    }
+
+    /// Returns the smallest element. If the set is empty, [`None`] is returned.
+    fn first(&self) -> Option<&T> {
+        self.map().first_key_value().map(|(value, _)| value)
+    }
+
+    /// Returns the largest element. If the set is empty, [`None`] is returned.
+    fn last(&self) -> Option<&T> {
+        self.map().last_key_value().map(|(value, _)| value)
+    }
+
+    /// Returns an iterator over all elements in ascending order.
+    fn iter(&self) -> SetIter<'_, T> {
+        SetIter {
+            iter: self.map().iter(),
+        }
+    }
+
+    /// Returns an iterator over the elements that are contained in the range, in ascending
+    /// order.
+    fn range<R: RangeBounds<T>>(&self, range: R) -> SetIter<'_, T> {
+        SetIter {
+            iter: self.map().range(range),
+        }
+    }
+}
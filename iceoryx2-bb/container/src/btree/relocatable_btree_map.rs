@@ -0,0 +1,200 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Contains the [`RelocatableBTreeMap`], a
+//! run-time fixed size ordered map that is shared memory compatible
+//!
+//! # Expert Examples
+//!
+//! ## Create [`RelocatableBTreeMap`] with allocator
+//!
+//! ```
+//! # extern crate iceoryx2_bb_loggers;
+//! use iceoryx2_bb_container::btree::*;
+//! use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
+//! use core::ptr::NonNull;
+//!
+//! const MAP_CAPACITY: usize = 12;
+//! const MEM_SIZE: usize = RelocatableBTreeMap::<u32, u64>::const_memory_size(MAP_CAPACITY);
+//! let mut memory = [0u8; MEM_SIZE];
+//!
+//! let bump_allocator = BumpAllocator::new(NonNull::new(memory.as_mut_ptr()).unwrap(), MEM_SIZE);
+//!
+//! let mut map = unsafe { RelocatableBTreeMap::<u32, u64>::new_uninit(MAP_CAPACITY) };
+//! unsafe { map.init(&bump_allocator).expect("map init failed") };
+//!
+//! map.insert(12, 34).unwrap();
+//! ```
+
+use alloc::format;
+use core::{alloc::Layout, fmt::Debug, mem::MaybeUninit};
+
+use iceoryx2_bb_elementary::{math::unaligned_mem_size, relocatable_ptr::*};
+pub use iceoryx2_bb_elementary_traits::relocatable_container::RelocatableContainer;
+use iceoryx2_bb_elementary_traits::validate::{Validate, ValidationBounds, ValidationError};
+use iceoryx2_bb_elementary_traits::zero_copy_send::{ZeroCopySend, combine_layout_hashes};
+use iceoryx2_log::{fail, fatal_panic};
+
+pub use crate::btree::BTreeMap;
+use crate::btree::{Node, TreeState, internal, validate_nodes};
+
+/// **Non-movable** relocatable shared-memory compatible ordered map with runtime fixed size capacity.
+#[repr(C)]
+pub struct RelocatableBTreeMap<K: Ord, V> {
+    nodes_ptr: RelocatablePointer<MaybeUninit<Node<K, V>>>,
+    capacity: u64,
+    state: TreeState,
+}
+
+impl<K: Ord, V> Drop for RelocatableBTreeMap<K, V> {
+    fn drop(&mut self) {
+        if self.nodes_ptr.is_initialized() {
+            self.clear()
+        }
+    }
+}
+
+unsafe impl<K: Ord + Send, V: Send> Send for RelocatableBTreeMap<K, V> {}
+
+unsafe impl<K: Ord + ZeroCopySend, V: ZeroCopySend> ZeroCopySend for RelocatableBTreeMap<K, V> {
+    const TYPE_LAYOUT_HASH: u64 = combine_layout_hashes(K::TYPE_LAYOUT_HASH, V::TYPE_LAYOUT_HASH);
+}
+
+unsafe impl<K: Ord + Validate, V: Validate> Validate for RelocatableBTreeMap<K, V> {
+    unsafe fn validate(ptr: *const Self, bounds: &ValidationBounds) -> Result<(), ValidationError> {
+        // every bit pattern of the fields is valid
+        let this = unsafe { &*ptr };
+        let capacity =
+            usize::try_from(this.capacity).map_err(|_| ValidationError::InvariantViolation)?;
+        let nodes = this.nodes_ptr.validated_ptr(capacity, bounds)?;
+        if ValidationBounds::of_elements(nodes, capacity)
+            .overlaps(&ValidationBounds::of_value(this))
+        {
+            return Err(ValidationError::InvariantViolation);
+        }
+
+        unsafe { validate_nodes(&this.state, nodes, this.capacity, bounds) }
+    }
+}
+
+impl<K: Ord + Debug, V: Debug> Debug for RelocatableBTreeMap<K, V> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "RelocatableBTreeMap<{}, {}> {{ capacity: {}, len: {}, content: ",
+            core::any::type_name::<K>(),
+            core::any::type_name::<V>(),
+            self.capacity,
+            self.state.len,
+        )?;
+        f.debug_map().entries(self.iter()).finish()?;
+        write!(f, " }}")
+    }
+}
+
+impl<K: Ord + PartialEq, V: PartialEq> PartialEq for RelocatableBTreeMap<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<K: Ord + Eq, V: Eq> Eq for RelocatableBTreeMap<K, V> {}
+
+impl<K: Ord, V> RelocatableBTreeMap<K, V> {
+    #[inline(always)]
+    fn verify_init(&self, source: &str) {
+        debug_assert!(
+            self.nodes_ptr.is_initialized(),
+            "From: RelocatableBTreeMap<{}, {}>::{}, Undefined behavior - the object was not initialized with 'init' before.",
+            core::any::type_name::<K>(),
+            core::any::type_name::<V>(),
+            source
+        );
+    }
+
+    /// Returns the required memory size for a map with a specified capacity
+    pub const fn const_memory_size(capacity: usize) -> usize {
+        unaligned_mem_size::<Node<K, V>>(capacity)
+    }
+}
+
+impl<K: Ord, V> RelocatableContainer for RelocatableBTreeMap<K, V> {
+    unsafe fn new_uninit(capacity: usize) -> Self {
+        Self {
+            nodes_ptr: unsafe { RelocatablePointer::new_uninit() },
+            capacity: capacity as u64,
+            state: TreeState::new(),
+        }
+    }
+
+    unsafe fn init<Allocator: iceoryx2_bb_elementary_traits::allocator::BaseAllocator>(
+        &mut self,
+        allocator: &Allocator,
+    ) -> Result<(), iceoryx2_bb_elementary_traits::allocator::AllocationError> {
+        let origin = || {
+            format!(
+                "RelocatableBTreeMap<{}, {}>::init()",
+                core::any::type_name::<K>(),
+                core::any::type_name::<V>()
+            )
+        };
+        if self.nodes_ptr.is_initialized() {
+            fatal_panic!(from origin(),
+                "Memory already initialized. Initializing it twice may lead to undefined behavior.");
+        }
+
+        let ptr = match allocator.allocate(unsafe {
+            Layout::from_size_align_unchecked(
+                core::mem::size_of::<Node<K, V>>() * self.capacity as usize,
+                core::mem::align_of::<Node<K, V>>(),
+            )
+        }) {
+            Ok(ptr) => ptr,
+            Err(e) => {
+                fail!(from origin(), with e,
+                    "Failed to initialize since the allocation of the node memory failed.");
+            }
+        };
+        unsafe {
+            self.nodes_ptr.init(ptr);
+        }
+        Ok(())
+    }
+
+    fn memory_size(capacity: usize) -> usize {
+        Self::const_memory_size(capacity)
+    }
+}
+
+impl<K: Ord, V> internal::BTreeMapView<K, V> for RelocatableBTreeMap<K, V> {
+    fn state(&self) -> &TreeState {
+        &self.state
+    }
+
+    fn nodes(&self) -> &[MaybeUninit<Node<K, V>>] {
+        self.verify_init("nodes()");
+        unsafe { core::slice::from_raw_parts(self.nodes_ptr.as_ptr(), self.capacity as usize) }
+    }
+
+    unsafe fn parts_mut(&mut self) -> (&mut TreeState, &mut [MaybeUninit<Node<K, V>>]) {
+        self.verify_init("parts_mut()");
+        (&mut self.state, unsafe {
+            core::slice::from_raw_parts_mut(self.nodes_ptr.as_mut_ptr(), self.capacity as usize)
+        })
+    }
+}
+
+impl<K: Ord, V> BTreeMap<K, V> for RelocatableBTreeMap<K, V> {
+    fn capacity(&self) -> usize {
+        self.capacity as usize
+    }
+}
@@ -0,0 +1,111 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Contains the [`RelocatableBTreeSet`], a
+//! run-time fixed size ordered set that is shared memory compatible
+//!
+//! # Expert Examples
+//!
+//! ## Create [`RelocatableBTreeSet`] with allocator
+//!
+//! ```
+//! # extern crate iceoryx2_bb_loggers;
+//! use iceoryx2_bb_container::btree::*;
+//! use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
+//! use core::ptr::NonNull;
+//!
+//! const SET_CAPACITY: usize = 12;
+//! const MEM_SIZE: usize = RelocatableBTreeSet::<u32>::const_memory_size(SET_CAPACITY);
+//! let mut memory = [0u8; MEM_SIZE];
+//!
+//! let bump_allocator = BumpAllocator::new(NonNull::new(memory.as_mut_ptr()).unwrap(), MEM_SIZE);
+//!
+//! let mut set = unsafe { RelocatableBTreeSet::<u32>::new_uninit(SET_CAPACITY) };
+//! unsafe { set.init(&bump_allocator).expect("set init failed") };
+//!
+//! set.insert(12).unwrap();
+//! ```
+
+use core::fmt::Debug;
+
+use iceoryx2_bb_derive_macros::Validate;
+pub use iceoryx2_bb_elementary_traits::relocatable_container::RelocatableContainer;
+use iceoryx2_bb_elementary_traits::validate::{Validate, ValidationBounds, ValidationError};
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+
+pub use crate::btree::BTreeSet;
+use crate::btree::{RelocatableBTreeMap, internal};
+
+/// **Non-movable** relocatable shared-memory compatible ordered set with runtime fixed size capacity.
+#[repr(C)]
+#[derive(PartialEq, Eq, Validate)]
+pub struct RelocatableBTreeSet<T: Ord> {
+    map: RelocatableBTreeMap<T, ()>,
+}
+
+unsafe impl<T: Ord + ZeroCopySend> ZeroCopySend for RelocatableBTreeSet<T> {
+    const TYPE_LAYOUT_HASH: u64 = T::TYPE_LAYOUT_HASH;
+}
+
+impl<T: Ord + Debug> Debug for RelocatableBTreeSet<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "RelocatableBTreeSet<{}> {{ capacity: {}, len: {}, content: ",
+            core::any::type_name::<T>(),
+            self.capacity(),
+            self.len(),
+        )?;
+        f.debug_set().entries(self.iter()).finish()?;
+        write!(f, " }}")
+    }
+}
+
+impl<T: Ord> RelocatableBTreeSet<T> {
+    /// Returns the required memory size for a set with a specified capacity
+    pub const fn const_memory_size(capacity: usize) -> usize {
+        RelocatableBTreeMap::<T, ()>::const_memory_size(capacity)
+    }
+}
+
+impl<T: Ord> RelocatableContainer for RelocatableBTreeSet<T> {
+    unsafe fn new_uninit(capacity: usize) -> Self {
+        Self {
+            map: unsafe { RelocatableBTreeMap::new_uninit(capacity) },
+        }
+    }
+
+    unsafe fn init<Allocator: iceoryx2_bb_elementary_traits::allocator::BaseAllocator>(
+        &mut self,
+        allocator: &Allocator,
+    ) -> Result<(), iceoryx2_bb_elementary_traits::allocator::AllocationError> {
+        unsafe { self.map.init(allocator) }
+    }
+
+    fn memory_size(capacity: usize) -> usize {
+        Self::const_memory_size(capacity)
+    }
+}
+
+impl<T: Ord> internal::BTreeSetView<T> for RelocatableBTreeSet<T> {
+    type Map = RelocatableBTreeMap<T, ()>;
+
+    fn map(&self) -> &Self::Map {
+        &self.map
+    }
+
+    fn map_mut(&mut self) -> &mut Self::Map {
+        &mut self.map
+    }
+}
+
+impl<T: Ord> BTreeSet<T> for RelocatableBTreeSet<T> {}
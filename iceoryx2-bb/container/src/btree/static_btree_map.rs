@@ -0,0 +1,231 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Relocatable shared-memory compatible ordered map with compile time fixed size capacity.
+//!
+//! # Example
+//!
+//! ```
+//! # extern crate iceoryx2_bb_loggers;
+//!
+//! use iceoryx2_bb_container::btree::*;
+//!
+//! const CAPACITY: usize = 123;
+//! let mut my_map = StaticBTreeMap::<u64, u64, CAPACITY>::new();
+//!
+//! my_map.insert(2, 456).unwrap();
+//! my_map.insert(1, 123).unwrap();
+//! assert_eq!(my_map.first_key_value(), Some((&1, &123)));
+//! ```
+
+use alloc::format;
+use core::marker::PhantomData;
+use core::{fmt::Debug, mem::MaybeUninit};
+
+use iceoryx2_bb_elementary_traits::{
+    placement_default::PlacementDefault,
+    validate::{Validate, ValidationBounds, ValidationError},
+    zero_copy_send::{ZeroCopySend, combine_layout_hashes},
+};
+use serde::{Deserialize, Serialize, de::Visitor, ser::SerializeMap};
+
+pub use crate::btree::BTreeMap;
+use crate::btree::{Node, TreeState, internal, validate_nodes};
+
+/// Relocatable shared-memory compatible ordered map with compile time fixed size capacity.
+#[repr(C)]
+pub struct StaticBTreeMap<K: Ord, V, const CAPACITY: usize> {
+    nodes: [MaybeUninit<Node<K, V>>; CAPACITY],
+    state: TreeState,
+}
+
+impl<K: Ord + Debug, V: Debug, const CAPACITY: usize> Debug for StaticBTreeMap<K, V, CAPACITY> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "StaticBTreeMap<{}, {}, {}> {{ len: {}, content: ",
+            core::any::type_name::<K>(),
+            core::any::type_name::<V>(),
+            CAPACITY,
+            self.len(),
+        )?;
+        f.debug_map().entries(self.iter()).finish()?;
+        write!(f, " }}")
+    }
+}
+
+unsafe impl<K: Ord + ZeroCopySend, V: ZeroCopySend, const CAPACITY: usize> ZeroCopySend
+    for StaticBTreeMap<K, V, CAPACITY>
+{
+    const TYPE_LAYOUT_HASH: u64 = combine_layout_hashes(K::TYPE_LAYOUT_HASH, V::TYPE_LAYOUT_HASH);
+}
+
+unsafe impl<K: Ord + Validate, V: Validate, const CAPACITY: usize> Validate
+    for StaticBTreeMap<K, V, CAPACITY>
+{
+    unsafe fn validate(ptr: *const Self, bounds: &ValidationBounds) -> Result<(), ValidationError> {
+        unsafe {
+            validate_nodes::<K, V>(
+                &*core::ptr::addr_of!((*ptr).state),
+                core::ptr::addr_of!((*ptr).nodes).cast(),
+                CAPACITY as u64,
+                bounds,
+            )
+        }
+    }
+}
+
+unsafe impl<K: Ord + Send, V: Send, const CAPACITY: usize> Send for StaticBTreeMap<K, V, CAPACITY> {}
+
+impl<K: Ord, V, const CAPACITY: usize> Drop for StaticBTreeMap<K, V, CAPACITY> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<K: Ord + Serialize, V: Serialize, const CAPACITY: usize> Serialize
+    for StaticBTreeMap<K, V, CAPACITY>
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(self.len()))?;
+        for (key, value) in self.iter() {
+            map.serialize_entry(key, value)?;
+        }
+        map.end()
+    }
+}
+
+struct StaticBTreeMapVisitor<K, V, const CAPACITY: usize> {
+    _value: PhantomData<(K, V)>,
+}
+
+impl<'de, K: Ord + Deserialize<'de>, V: Deserialize<'de>, const CAPACITY: usize> Visitor<'de>
+    for StaticBTreeMapVisitor<K, V, CAPACITY>
+{
+    type Value = StaticBTreeMap<K, V, CAPACITY>;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let str = format!(
+            "a map of at most {} unique keys of type {} with values of type {}",
+            CAPACITY,
+            core::any::type_name::<K>(),
+            core::any::type_name::<V>()
+        );
+        formatter.write_str(&str)
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        let mut new_map = Self::Value::new();
+
+        while let Some((key, value)) = map.next_entry()? {
+            if let Err(e) = new_map.insert(key, value) {
+                return Err(<A::Error as serde::de::Error>::custom(format!(
+                    "the map can hold at most {CAPACITY} unique keys ({e})"
+                )));
+            }
+        }
+
+        Ok(new_map)
+    }
+}
+
+impl<'de, K: Ord + Deserialize<'de>, V: Deserialize<'de>, const CAPACITY: usize> Deserialize<'de>
+    for StaticBTreeMap<K, V, CAPACITY>
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(StaticBTreeMapVisitor::<K, V, CAPACITY> {
+            _value: PhantomData,
+        })
+    }
+}
+
+impl<K: Ord, V, const CAPACITY: usize> PlacementDefault for StaticBTreeMap<K, V, CAPACITY> {
+    unsafe fn placement_default(ptr: *mut Self) {
+        unsafe {
+            core::ptr::addr_of_mut!((*ptr).state).write(TreeState::new());
+            // the nodes are initialized on demand when they are acquired for the first time
+        }
+    }
+}
+
+impl<K: Ord, V, const CAPACITY: usize> Default for StaticBTreeMap<K, V, CAPACITY> {
+    fn default() -> Self {
+        Self {
+            nodes: [const { MaybeUninit::uninit() }; CAPACITY],
+            state: TreeState::new(),
+        }
+    }
+}
+
+impl<K: Ord + PartialEq, V: PartialEq, const CAPACITY: usize> PartialEq
+    for StaticBTreeMap<K, V, CAPACITY>
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<K: Ord + Eq, V: Eq, const CAPACITY: usize> Eq for StaticBTreeMap<K, V, CAPACITY> {}
+
+impl<K: Ord + Clone, V: Clone, const CAPACITY: usize> Clone for StaticBTreeMap<K, V, CAPACITY> {
+    fn clone(&self) -> Self {
+        let mut new_map = Self::new();
+        for (key, value) in self.iter() {
+            // cannot fail, the keys are unique and the capacity is identical
+            let _ = new_map.insert(key.clone(), value.clone());
+        }
+        new_map
+    }
+}
+
+impl<K: Ord, V, const CAPACITY: usize> StaticBTreeMap<K, V, CAPACITY> {
+    /// Creates a new map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the capacity of the map
+    pub const fn capacity() -> usize {
+        CAPACITY
+    }
+}
+
+impl<K: Ord, V, const CAPACITY: usize> internal::BTreeMapView<K, V>
+    for StaticBTreeMap<K, V, CAPACITY>
+{
+    fn state(&self) -> &TreeState {
+        &self.state
+    }
+
+    fn nodes(&self) -> &[MaybeUninit<Node<K, V>>] {
+        &self.nodes
+    }
+
+    unsafe fn parts_mut(&mut self) -> (&mut TreeState, &mut [MaybeUninit<Node<K, V>>]) {
+        (&mut self.state, &mut self.nodes)
+    }
+}
+
+impl<K: Ord, V, const CAPACITY: usize> BTreeMap<K, V> for StaticBTreeMap<K, V, CAPACITY> {
+    fn capacity(&self) -> usize {
+        CAPACITY
+    }
+}
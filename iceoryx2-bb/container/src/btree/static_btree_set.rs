@@ -0,0 +1,171 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Relocatable shared-memory compatible ordered set with compile time fixed size capacity.
+//!
+//! # Example
+//!
+//! ```
+//! # extern crate iceoryx2_bb_loggers;
+//!
+//! use iceoryx2_bb_container::btree::*;
+//!
+//! const CAPACITY: usize = 123;
+//! let mut my_set = StaticBTreeSet::<u64, CAPACITY>::new();
+//!
+//! my_set.insert(2).unwrap();
+//! my_set.insert(1).unwrap();
+//! assert_eq!(my_set.first(), Some(&1));
+//! ```
+
+use alloc::format;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+use iceoryx2_bb_derive_macros::Validate;
+use iceoryx2_bb_elementary_traits::validate::{Validate, ValidationBounds, ValidationError};
+use iceoryx2_bb_elementary_traits::{
+    placement_default::PlacementDefault, zero_copy_send::ZeroCopySend,
+};
+use serde::{Deserialize, Serialize, de::Visitor, ser::SerializeSeq};
+
+pub use crate::btree::BTreeSet;
+use crate::btree::{StaticBTreeMap, internal};
+
+/// Relocatable shared-memory compatible ordered set with compile time fixed size capacity.
+#[repr(C)]
+#[derive(Clone, PartialEq, Eq, Validate)]
+pub struct StaticBTreeSet<T: Ord, const CAPACITY: usize> {
+    map: StaticBTreeMap<T, (), CAPACITY>,
+}
+
+impl<T: Ord + Debug, const CAPACITY: usize> Debug for StaticBTreeSet<T, CAPACITY> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "StaticBTreeSet<{}, {}> {{ len: {}, content: ",
+            core::any::type_name::<T>(),
+            CAPACITY,
+            self.len(),
+        )?;
+        f.debug_set().entries(self.iter()).finish()?;
+        write!(f, " }}")
+    }
+}
+
+unsafe impl<T: Ord + ZeroCopySend, const CAPACITY: usize> ZeroCopySend
+    for StaticBTreeSet<T, CAPACITY>
+{
+    const TYPE_LAYOUT_HASH: u64 = T::TYPE_LAYOUT_HASH;
+}
+
+impl<T: Ord + Serialize, const CAPACITY: usize> Serialize for StaticBTreeSet<T, CAPACITY> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for element in self.iter() {
+            seq.serialize_element(element)?;
+        }
+        seq.end()
+    }
+}
+
+struct StaticBTreeSetVisitor<T, const CAPACITY: usize> {
+    _value: PhantomData<T>,
+}
+
+impl<'de, T: Ord + Deserialize<'de>, const CAPACITY: usize> Visitor<'de>
+    for StaticBTreeSetVisitor<T, CAPACITY>
+{
+    type Value = StaticBTreeSet<T, CAPACITY>;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let str = format!(
+            "an array of at most {} unique elements of type {}",
+            CAPACITY,
+            core::any::type_name::<T>()
+        );
+        formatter.write_str(&str)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        let mut new_set = Self::Value::new();
+
+        while let Some(element) = seq.next_element()? {
+            if let Err(e) = new_set.insert(element) {
+                return Err(<A::Error as serde::de::Error>::custom(format!(
+                    "the set can hold at most {CAPACITY} unique elements ({e})"
+                )));
+            }
+        }
+
+        Ok(new_set)
+    }
+}
+
+impl<'de, T: Ord + Deserialize<'de>, const CAPACITY: usize> Deserialize<'de>
+    for StaticBTreeSet<T, CAPACITY>
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(StaticBTreeSetVisitor::<T, CAPACITY> {
+            _value: PhantomData,
+        })
+    }
+}
+
+impl<T: Ord, const CAPACITY: usize> PlacementDefault for StaticBTreeSet<T, CAPACITY> {
+    unsafe fn placement_default(ptr: *mut Self) {
+        unsafe { StaticBTreeMap::placement_default(core::ptr::addr_of_mut!((*ptr).map)) }
+    }
+}
+
+impl<T: Ord, const CAPACITY: usize> Default for StaticBTreeSet<T, CAPACITY> {
+    fn default() -> Self {
+        Self {
+            map: StaticBTreeMap::new(),
+        }
+    }
+}
+
+impl<T: Ord, const CAPACITY: usize> StaticBTreeSet<T, CAPACITY> {
+    /// Creates a new set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the capacity of the set
+    pub const fn capacity() -> usize {
+        CAPACITY
+    }
+}
+
+impl<T: Ord, const CAPACITY: usize> internal::BTreeSetView<T> for StaticBTreeSet<T, CAPACITY> {
+    type Map = StaticBTreeMap<T, (), CAPACITY>;
+
+    fn map(&self) -> &Self::Map {
+        &self.map
+    }
+
+    fn map_mut(&mut self) -> &mut Self::Map {
+        &mut self.map
+    }
+}
+
+impl<T: Ord, const CAPACITY: usize> BTreeSet<T> for StaticBTreeSet<T, CAPACITY> {}
@@ -44,6 +44,7 @@ use core::mem::MaybeUninit;
 use iceoryx2_bb_concurrency::atomic::{AtomicBool, AtomicU8, Ordering};
 use iceoryx2_bb_elementary::relocatable_ptr::{PointerTrait, RelocatablePointer};
 use iceoryx2_bb_elementary_traits::allocator::{AllocationError, BaseAllocator};
+use iceoryx2_bb_elementary_traits::validate::{Validate, ValidationBounds, ValidationError};
 use iceoryx2_bb_elementary_traits::{atomic_copy::AtomicCopy, zero_copy_send::ZeroCopySend};
 use iceoryx2_log::fail;
 use iceoryx2_log::fatal_panic;
@@ -63,7 +64,29 @@ pub struct RelocatableByteAtomic<T: AtomicCopy> {
     _inner_type: PhantomData<T>,
 }
 
-unsafe impl<T: AtomicCopy + ZeroCopySend> ZeroCopySend for RelocatableByteAtomic<T> {}
+unsafe impl<T: AtomicCopy + ZeroCopySend> ZeroCopySend for RelocatableByteAtomic<T> {
+    const TYPE_LAYOUT_HASH: u64 = T::TYPE_LAYOUT_HASH;
+}
+
+unsafe impl<T: AtomicCopy + Validate> Validate for RelocatableByteAtomic<T> {
+    unsafe fn validate(ptr: *const Self, bounds: &ValidationBounds) -> Result<(), ValidationError> {
+        unsafe { AtomicBool::validate(core::ptr::addr_of!((*ptr).is_initialized), bounds) }?;
+
+        let this = unsafe { &*ptr };
+        if !this.is_initialized.load(Ordering::Relaxed) || this.capacity != size_of::<T>() {
+            return Err(ValidationError::InvariantViolation);
+        }
+
+        let data = this.data_ptr.validated_ptr(this.capacity, bounds)?;
+        if ValidationBounds::of_elements(data, this.capacity)
+            .overlaps(&ValidationBounds::of_value(this))
+        {
+            return Err(ValidationError::InvariantViolation);
+        }
+
+        unsafe { validate_bytes::<T>(data) }
+    }
+}
 
 impl<T: AtomicCopy> RelocatableByteAtomic<T> {
     #[inline(always)]
@@ -177,6 +200,20 @@ pub struct FixedSizeByteAtomic<T: AtomicCopy, const SIZE: usize> {
 unsafe impl<T: AtomicCopy + ZeroCopySend, const SIZE: usize> ZeroCopySend
     for FixedSizeByteAtomic<T, SIZE>
 {
+    const TYPE_LAYOUT_HASH: u64 = T::TYPE_LAYOUT_HASH;
+}
+
+unsafe impl<T: AtomicCopy + Validate, const SIZE: usize> Validate for FixedSizeByteAtomic<T, SIZE> {
+    unsafe fn validate(
+        ptr: *const Self,
+        _bounds: &ValidationBounds,
+    ) -> Result<(), ValidationError> {
+        if size_of::<T>() != SIZE {
+            return Err(ValidationError::InvariantViolation);
+        }
+
+        unsafe { validate_bytes::<T>(core::ptr::addr_of!((*ptr).data).cast()) }
+    }
 }
 
 impl<T: AtomicCopy, const SIZE: usize> FixedSizeByteAtomic<T, SIZE> {
@@ -241,6 +278,17 @@ unsafe fn read_impl<T: AtomicCopy>(src_data_ptr: *const AtomicU8) -> MaybeUninit
     data
 }
 
+/// The bytes are not aligned for `T`, therefore they are copied into an aligned value before
+/// it is validated. Since the copy does not refer to other memory, the bounds contain only
+/// the copy itself.
+unsafe fn validate_bytes<T: AtomicCopy + Validate>(
+    src_data_ptr: *const AtomicU8,
+) -> Result<(), ValidationError> {
+    let value = unsafe { read_impl::<T>(src_data_ptr) };
+    let bounds = ValidationBounds::of_value(&value);
+    unsafe { T::validate(value.as_ptr(), &bounds) }
+}
+
 unsafe fn write_impl<T: AtomicCopy>(dest_data_ptr: *const AtomicU8, value: T) {
     let value_ptr = (&value as *const T) as *const u8;
     value.__for_each_field(0, &mut |offset, size| {
@@ -0,0 +1,370 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Relocatable shared-memory compatible double-ended queue with compile time fixed size
+//! capacity. In contrast to the [`FixedSizeQueue`](crate::queue::FixedSizeQueue) elements can
+//! be added and removed at both ends and every element can be accessed by its position.
+//!
+//! # Example
+//!
+//! ```
+//! # extern crate iceoryx2_bb_loggers;
+//!
+//! use iceoryx2_bb_container::deque::StaticDeque;
+//!
+//! const CAPACITY: usize = 4;
+//! let mut deque = StaticDeque::<u64, CAPACITY>::new();
+//!
+//! deque.push_back(2); // returns false, when capacity is exceeded
+//! deque.push_front(1);
+//! deque.push_back(3);
+//!
+//! assert_eq!(deque.get(1), Some(&2));
+//! assert_eq!(deque.pop_front(), Some(1));
+//! assert_eq!(deque.pop_back(), Some(3));
+//! ```
+
+use alloc::format;
+use core::marker::PhantomData;
+use core::{fmt::Debug, mem::MaybeUninit};
+
+use iceoryx2_bb_elementary_traits::{
+    placement_default::PlacementDefault,
+    validate::{Validate, ValidationBounds, ValidationError},
+    zero_copy_send::ZeroCopySend,
+};
+use serde::{Deserialize, Serialize, de::Visitor, ser::SerializeSeq};
+
+/// Relocatable shared-memory compatible double-ended queue with compile time fixed size
+/// capacity.
+#[repr(C)]
+pub struct StaticDeque<T, const CAPACITY: usize> {
+    data: [MaybeUninit<T>; CAPACITY],
+    start: u64,
+    len: u64,
+}
+
+/// Iterator over the elements of a [`StaticDeque`] from front to back.
+pub struct Iter<'a, T, const CAPACITY: usize> {
+    deque: &'a StaticDeque<T, CAPACITY>,
+    front: usize,
+    back: usize,
+}
+
+impl<'a, T, const CAPACITY: usize> Iterator for Iter<'a, T, CAPACITY> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.front += 1;
+        self.deque.get(self.front - 1)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.back - self.front, Some(self.back - self.front))
+    }
+}
+
+impl<T, const CAPACITY: usize> DoubleEndedIterator for Iter<'_, T, CAPACITY> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        self.deque.get(self.back)
+    }
+}
+
+impl<T, const CAPACITY: usize> ExactSizeIterator for Iter<'_, T, CAPACITY> {}
+
+impl<T: Debug, const CAPACITY: usize> Debug for StaticDeque<T, CAPACITY> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "StaticDeque<{}, {}> {{ len: {}, content: ",
+            core::any::type_name::<T>(),
+            CAPACITY,
+            self.len,
+        )?;
+        f.debug_list().entries(self.iter()).finish()?;
+        write!(f, " }}")
+    }
+}
+
+unsafe impl<T: ZeroCopySend, const CAPACITY: usize> ZeroCopySend for StaticDeque<T, CAPACITY> {
+    const TYPE_LAYOUT_HASH: u64 = T::TYPE_LAYOUT_HASH;
+}
+
+unsafe impl<T: Validate, const CAPACITY: usize> Validate for StaticDeque<T, CAPACITY> {
+    unsafe fn validate(ptr: *const Self, bounds: &ValidationBounds) -> Result<(), ValidationError> {
+        let start = unsafe { core::ptr::addr_of!((*ptr).start).read() };
+        let len = unsafe { core::ptr::addr_of!((*ptr).len).read() };
+        if len > CAPACITY as u64 || (start >= CAPACITY as u64 && start != 0) {
+            return Err(ValidationError::InvariantViolation);
+        }
+
+        let data = unsafe { core::ptr::addr_of!((*ptr).data) }.cast::<T>();
+        for i in 0..len as usize {
+            let index = (start as usize + i) % CAPACITY;
+            unsafe { T::validate(data.add(index), bounds) }?;
+        }
+
+        Ok(())
+    }
+}
+
+unsafe impl<T: Send, const CAPACITY: usize> Send for StaticDeque<T, CAPACITY> {}
+
+impl<T, const CAPACITY: usize> Drop for StaticDeque<T, CAPACITY> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T: Serialize, const CAPACITY: usize> Serialize for StaticDeque<T, CAPACITY> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for element in self.iter() {
+            seq.serialize_element(element)?;
+        }
+        seq.end()
+    }
+}
+
+struct StaticDequeVisitor<T, const CAPACITY: usize> {
+    _value: PhantomData<T>,
+}
+
+impl<'de, T: Deserialize<'de>, const CAPACITY: usize> Visitor<'de>
+    for StaticDequeVisitor<T, CAPACITY>
+{
+    type Value = StaticDeque<T, CAPACITY>;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let str = format!(
+            "an array of at most {} elements of type {}",
+            CAPACITY,
+            core::any::type_name::<T>()
+        );
+        formatter.write_str(&str)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        let mut new_deque = Self::Value::new();
+
+        while let Some(element) = seq.next_element()? {
+            if !new_deque.push_back(element) {
+                return Err(<A::Error as serde::de::Error>::custom(format!(
+                    "the array can hold at most {CAPACITY} elements"
+                )));
+            }
+        }
+
+        Ok(new_deque)
+    }
+}
+
+impl<'de, T: Deserialize<'de>, const CAPACITY: usize> Deserialize<'de>
+    for StaticDeque<T, CAPACITY>
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(StaticDequeVisitor::<T, CAPACITY> {
+            _value: PhantomData,
+        })
+    }
+}
+
+impl<T, const CAPACITY: usize> PlacementDefault for StaticDeque<T, CAPACITY> {
+    unsafe fn placement_default(ptr: *mut Self) {
+        unsafe {
+            core::ptr::addr_of_mut!((*ptr).start).write(0);
+            core::ptr::addr_of_mut!((*ptr).len).write(0);
+        }
+    }
+}
+
+impl<T, const CAPACITY: usize> Default for StaticDeque<T, CAPACITY> {
+    fn default() -> Self {
+        Self {
+            data: [const { MaybeUninit::uninit() }; CAPACITY],
+            start: 0,
+            len: 0,
+        }
+    }
+}
+
+impl<T: PartialEq, const CAPACITY: usize> PartialEq for StaticDeque<T, CAPACITY> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, const CAPACITY: usize> Eq for StaticDeque<T, CAPACITY> {}
+
+impl<T: Clone, const CAPACITY: usize> Clone for StaticDeque<T, CAPACITY> {
+    fn clone(&self) -> Self {
+        let mut new_deque = Self::new();
+        for element in self.iter() {
+            new_deque.push_back(element.clone());
+        }
+        new_deque
+    }
+}
+
+impl<T, const CAPACITY: usize> StaticDeque<T, CAPACITY> {
+    /// Creates a new deque.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the capacity of the deque
+    pub const fn capacity(&self) -> usize {
+        CAPACITY
+    }
+
+    /// Returns the number of elements inside the deque
+    pub fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    /// Returns true if the deque is empty, otherwise false
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns true if the deque is full, otherwise false
+    pub fn is_full(&self) -> bool {
+        self.len() == CAPACITY
+    }
+
+    fn physical_index(&self, index: usize) -> usize {
+        (self.start as usize + index) % CAPACITY
+    }
+
+    /// Returns a reference to the element at the position counted from the front. If the
+    /// position is out of bounds, [`None`] is returned.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if self.len() <= index {
+            return None;
+        }
+
+        Some(unsafe { self.data[self.physical_index(index)].assume_init_ref() })
+    }
+
+    /// Returns a mutable reference to the element at the position counted from the front. If
+    /// the position is out of bounds, [`None`] is returned.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if self.len() <= index {
+            return None;
+        }
+
+        let index = self.physical_index(index);
+        Some(unsafe { self.data[index].assume_init_mut() })
+    }
+
+    /// Returns a reference to the first element or [`None`] when the deque is empty.
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    /// Returns a reference to the last element or [`None`] when the deque is empty.
+    pub fn back(&self) -> Option<&T> {
+        self.get(self.len().checked_sub(1)?)
+    }
+
+    /// Returns a mutable reference to the first element or [`None`] when the deque is empty.
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(0)
+    }
+
+    /// Returns a mutable reference to the last element or [`None`] when the deque is empty.
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(self.len().checked_sub(1)?)
+    }
+
+    /// Adds an element at the front of the deque. If the deque is full it returns false,
+    /// otherwise true.
+    pub fn push_front(&mut self, value: T) -> bool {
+        if self.is_full() {
+            return false;
+        }
+
+        self.start = ((self.start as usize + CAPACITY - 1) % CAPACITY) as u64;
+        self.data[self.start as usize].write(value);
+        self.len += 1;
+        true
+    }
+
+    /// Adds an element at the back of the deque. If the deque is full it returns false,
+    /// otherwise true.
+    pub fn push_back(&mut self, value: T) -> bool {
+        if self.is_full() {
+            return false;
+        }
+
+        let index = self.physical_index(self.len());
+        self.data[index].write(value);
+        self.len += 1;
+        true
+    }
+
+    /// Removes the first element and returns it or [`None`] when the deque is empty.
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let index = self.start as usize;
+        self.start = ((index + 1) % CAPACITY) as u64;
+        self.len -= 1;
+        Some(unsafe { self.data[index].assume_init_read() })
+    }
+
+    /// Removes the last element and returns it or [`None`] when the deque is empty.
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        self.len -= 1;
+        let index = self.physical_index(self.len());
+        Some(unsafe { self.data[index].assume_init_read() })
+    }
+
+    /// Removes all elements from the deque
+    pub fn clear(&mut self) {
+        while self.pop_back().is_some() {}
+        self.start = 0;
+    }
+
+    /// Returns an iterator over all elements from front to back.
+    pub fn iter(&self) -> Iter<'_, T, CAPACITY> {
+        Iter {
+            deque: self,
+            front: 0,
+            back: self.len(),
+        }
+    }
+}
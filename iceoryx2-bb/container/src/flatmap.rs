@@ -33,11 +33,13 @@
 //! assert_eq!(map.get(&23).unwrap(), 4);
 //! ```
 
+use crate::relocatable_option::RelocatableOption;
 use crate::slotmap::FreeListEntry;
 use crate::slotmap::{MetaSlotMap, RelocatableSlotMap};
 use core::fmt::Debug;
 use core::mem::MaybeUninit;
 use iceoryx2_bb_concurrency::atomic::AtomicBool;
+use iceoryx2_bb_derive_macros::Validate;
 use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
 use iceoryx2_bb_elementary::relocatable_ptr::GenericRelocatablePointer;
@@ -45,7 +47,9 @@ use iceoryx2_bb_elementary_traits::generic_pointer::GenericPointer;
 use iceoryx2_bb_elementary_traits::owning_pointer::GenericOwningPointer;
 pub use iceoryx2_bb_elementary_traits::relocatable_container::RelocatableContainer;
 use iceoryx2_bb_elementary_traits::{
-    placement_default::PlacementDefault, zero_copy_send::ZeroCopySend,
+    placement_default::PlacementDefault,
+    validate::{Validate, ValidationBounds, ValidationError},
+    zero_copy_send::{ZeroCopySend, combine_layout_hashes},
 };
 use iceoryx2_log::{fail, fatal_panic};
 
@@ -59,12 +63,15 @@ pub enum FlatMapError {
 }
 
 #[repr(C)]
+#[derive(Validate)]
 struct Entry<K: Eq, V: Clone> {
     id: K,
     value: V,
 }
 
-unsafe impl<K: Eq + ZeroCopySend, V: Clone + ZeroCopySend> ZeroCopySend for Entry<K, V> {}
+unsafe impl<K: Eq + ZeroCopySend, V: Clone + ZeroCopySend> ZeroCopySend for Entry<K, V> {
+    const TYPE_LAYOUT_HASH: u64 = combine_layout_hashes(K::TYPE_LAYOUT_HASH, V::TYPE_LAYOUT_HASH);
+}
 
 /// A runtime fixed-size, non-shared memory compatible [`FlatMap`]. The [`FlatMap`]s memory resides
 /// in the heap.
@@ -437,6 +444,24 @@ impl<K: Eq, V: Clone> RelocatableContainer for RelocatableFlatMap<K, V> {
 unsafe impl<K: Eq + ZeroCopySend, V: Clone + ZeroCopySend> ZeroCopySend
     for RelocatableFlatMap<K, V>
 {
+    const TYPE_LAYOUT_HASH: u64 = combine_layout_hashes(K::TYPE_LAYOUT_HASH, V::TYPE_LAYOUT_HASH);
+}
+
+unsafe impl<K: Eq + Validate, V: Clone + Validate> Validate for RelocatableFlatMap<K, V> {
+    unsafe fn validate(ptr: *const Self, bounds: &ValidationBounds) -> Result<(), ValidationError> {
+        unsafe {
+            AtomicBool::validate(core::ptr::addr_of!((*ptr).is_initialized), bounds)?;
+            RelocatableSlotMap::validate(core::ptr::addr_of!((*ptr).map), bounds)?;
+        }
+
+        match unsafe { &*ptr }
+            .is_initialized
+            .load(core::sync::atomic::Ordering::Relaxed)
+        {
+            true => Ok(()),
+            false => Err(ValidationError::InvariantViolation),
+        }
+    }
 }
 
 impl<K: Eq, V: Clone> RelocatableFlatMap<K, V> {
@@ -654,13 +679,37 @@ pub struct FixedSizeFlatMap<K: Eq, V: Clone, const CAPACITY: usize> {
     map: RelocatableFlatMap<K, V>,
     _idx_to_data: MaybeUninit<[usize; CAPACITY]>,
     _idx_to_data_free_list: MaybeUninit<[FreeListEntry; CAPACITY]>,
-    _data: MaybeUninit<[Option<Entry<K, V>>; CAPACITY]>,
+    _data: MaybeUninit<[RelocatableOption<Entry<K, V>>; CAPACITY]>,
     _data_next_free_index: MaybeUninit<[usize; CAPACITY]>,
 }
 
 unsafe impl<K: Eq + ZeroCopySend, V: Clone + ZeroCopySend, const CAPACITY: usize> ZeroCopySend
     for FixedSizeFlatMap<K, V, CAPACITY>
 {
+    const TYPE_LAYOUT_HASH: u64 = combine_layout_hashes(K::TYPE_LAYOUT_HASH, V::TYPE_LAYOUT_HASH);
+}
+
+unsafe impl<K: Eq + Validate, V: Clone + Validate, const CAPACITY: usize> Validate
+    for FixedSizeFlatMap<K, V, CAPACITY>
+{
+    unsafe fn validate(ptr: *const Self, bounds: &ValidationBounds) -> Result<(), ValidationError> {
+        let map = unsafe { core::ptr::addr_of!((*ptr).map) };
+        unsafe { RelocatableFlatMap::validate(map, bounds) }?;
+
+        let is_located_in_self = unsafe {
+            (*map).map.is_stored_in(
+                core::ptr::addr_of!((*ptr)._idx_to_data),
+                core::ptr::addr_of!((*ptr)._idx_to_data_free_list),
+                core::ptr::addr_of!((*ptr)._data),
+                core::ptr::addr_of!((*ptr)._data_next_free_index),
+            )
+        };
+        if unsafe { (*map).map.capacity_impl() } != CAPACITY || !is_located_in_self {
+            return Err(ValidationError::InvariantViolation);
+        }
+
+        Ok(())
+    }
 }
 
 impl<K: Eq, V: Clone, const CAPACITY: usize> PlacementDefault for FixedSizeFlatMap<K, V, CAPACITY> {
@@ -0,0 +1,777 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A HashMap is a data structure to store key-value pairs with constant time lookup. In contrast
+//! to the [`FlatMap`](crate::flatmap::FlatMap), that scans all entries, the key is hashed
+//! to find its slot. Multiple variations of that container are available.
+//!
+//!  * [`FixedSizeHashMap`](crate::hashmap::FixedSizeHashMap), compile-time fixed-size hashmap
+//!    that is self-contained and shared-memory compatible.
+//!  * [`RelocatableHashMap`](crate::hashmap::RelocatableHashMap), run-time fixed-size hashmap
+//!    that is shared-memory compatible.
+//!  * [`HashMap`](crate::hashmap::HashMap), run-time fixed-size hashmap that is not
+//!    shared-memory compatible since the memory resides in the heap.
+//!
+//! The entries are stored with open addressing and linear probing in twice as many slots as
+//! the map has capacity. The key is hashed with the [`DeterministicHasher`], which does not
+//! depend on a random seed, so that every process finds the entries at the same slot.
+//! Therefore, the [`Hash`] implementation of the key must be deterministic as well, it must
+//! not hash pointers or other process-local state.
+//!
+//! # User Examples
+//!
+//! ```
+//! # extern crate iceoryx2_bb_loggers;
+//!
+//! use iceoryx2_bb_container::hashmap::FixedSizeHashMap;
+//!
+//! const CAPACITY: usize = 100;
+//! let mut map = FixedSizeHashMap::<u64, u8, CAPACITY>::new();
+//! assert_eq!(map.insert(23, 4).is_ok(), true);
+//! assert_eq!(map.get(&23).unwrap(), 4);
+//! ```
+
+use core::fmt::Debug;
+use core::hash::{Hash, Hasher};
+use core::mem::MaybeUninit;
+use iceoryx2_bb_concurrency::atomic::AtomicBool;
+use iceoryx2_bb_derive_macros::Validate;
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
+use iceoryx2_bb_elementary::relocatable_ptr::GenericRelocatablePointer;
+use iceoryx2_bb_elementary_traits::generic_pointer::GenericPointer;
+use iceoryx2_bb_elementary_traits::owning_pointer::GenericOwningPointer;
+pub use iceoryx2_bb_elementary_traits::relocatable_container::RelocatableContainer;
+use iceoryx2_bb_elementary_traits::{
+    placement_default::PlacementDefault,
+    validate::{Validate, ValidationBounds, ValidationError},
+    zero_copy_send::{ZeroCopySend, combine_layout_hashes},
+};
+use iceoryx2_log::{fail, fatal_panic};
+
+use crate::relocatable_option::RelocatableOption;
+use crate::vec::{MetaVec, RelocatableVec, Vec};
+
+/// Failures caused by insert()
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum HashMapError {
+    /// The HashMap already contains the key that shall be inserted.
+    KeyAlreadyExists,
+    /// The HashMap is full and cannot hold an additional key-value pair.
+    IsFull,
+}
+
+/// A [`Hasher`] that produces the same hash for the same input in every process. It uses
+/// FNV-1a and finalizes the hash with the MurmurHash3 mixer so that the lower bits, which
+/// select the slot, are well distributed.
+#[derive(Debug, Clone, Copy)]
+pub struct DeterministicHasher {
+    state: u64,
+}
+
+impl DeterministicHasher {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x100000001b3;
+
+    /// Creates a new [`DeterministicHasher`].
+    pub const fn new() -> Self {
+        Self {
+            state: Self::OFFSET_BASIS,
+        }
+    }
+
+    /// Returns the hash of the provided value.
+    pub fn hash_of<T: Hash + ?Sized>(value: &T) -> u64 {
+        let mut hasher = Self::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+impl Default for DeterministicHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Hasher for DeterministicHasher {
+    fn finish(&self) -> u64 {
+        let mut hash = self.state;
+        hash ^= hash >> 33;
+        hash = hash.wrapping_mul(0xff51afd7ed558ccd);
+        hash ^= hash >> 33;
+        hash = hash.wrapping_mul(0xc4ceb9fe1a85ec53);
+        hash ^ (hash >> 33)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.state ^= *byte as u64;
+            self.state = self.state.wrapping_mul(Self::PRIME);
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Validate)]
+struct Entry<K, V> {
+    hash: u64,
+    key: K,
+    value: V,
+}
+
+unsafe impl<K: ZeroCopySend, V: ZeroCopySend> ZeroCopySend for Entry<K, V> {
+    const TYPE_LAYOUT_HASH: u64 = combine_layout_hashes(K::TYPE_LAYOUT_HASH, V::TYPE_LAYOUT_HASH);
+}
+
+type Slot<K, V> = RelocatableOption<Entry<K, V>>;
+
+/// Returns the number of slots that are required for a map with the provided capacity.
+const fn number_of_slots(capacity: usize) -> usize {
+    2 * capacity
+}
+
+/// A runtime fixed-size, non-shared memory compatible [`HashMap`]. The [`HashMap`]s memory
+/// resides in the heap.
+pub type HashMap<K, V> = MetaHashMap<K, V, GenericOwningPointer>;
+
+/// A runtime fixed-size, shared-memory compatible [`RelocatableHashMap`].
+pub type RelocatableHashMap<K, V> = MetaHashMap<K, V, GenericRelocatablePointer>;
+
+#[doc(hidden)]
+#[repr(C)]
+pub struct MetaHashMap<K: Eq + Hash, V, Ptr: GenericPointer> {
+    slots: MetaVec<Slot<K, V>, Ptr>,
+    capacity: usize,
+    len: usize,
+    is_initialized: AtomicBool,
+}
+
+unsafe impl<K: Eq + Hash + Send, V: Send, Ptr: GenericPointer> Send for MetaHashMap<K, V, Ptr> {}
+
+impl<K: Eq + Hash + Debug, V: Debug, Ptr: GenericPointer> Debug for MetaHashMap<K, V, Ptr> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "MetaHashMap<{}, {}, {}> {{ capacity: {}, len: {}, is_initialized: {} }}",
+            core::any::type_name::<K>(),
+            core::any::type_name::<V>(),
+            core::any::type_name::<Ptr>(),
+            self.capacity,
+            self.len,
+            self.is_initialized
+                .load(core::sync::atomic::Ordering::Relaxed),
+        )
+    }
+}
+
+impl<K: Eq + Hash, V, Ptr: GenericPointer> MetaHashMap<K, V, Ptr> {
+    #[inline(always)]
+    fn verify_init(&self, source: &str) {
+        debug_assert!(
+            self.is_initialized
+                .load(core::sync::atomic::Ordering::Relaxed),
+            "From: MetaHashMap<{}, {}>::{}, Undefined behavior - the object was not initialized with 'init' before.",
+            core::any::type_name::<K>(),
+            core::any::type_name::<V>(),
+            source
+        );
+    }
+
+    fn initialize_data_structures(&mut self) {
+        for _ in 0..self.slots.capacity() {
+            unsafe { self.slots.push_impl(RelocatableOption::None) };
+        }
+    }
+
+    fn home_slot(&self, hash: u64) -> usize {
+        (hash % self.slots.len() as u64) as usize
+    }
+
+    fn next_slot(&self, slot: usize) -> usize {
+        (slot + 1) % self.slots.len()
+    }
+
+    /// Returns the slot that contains the key or [`None`] when the key is not contained.
+    fn find_slot(&self, key: &K, hash: u64) -> Option<usize> {
+        if self.slots.is_empty() {
+            return None;
+        }
+
+        let mut slot = self.home_slot(hash);
+        for _ in 0..self.slots.len() {
+            match self.slots[slot].as_option_ref() {
+                None => return None,
+                Some(entry) if entry.hash == hash && entry.key == *key => return Some(slot),
+                Some(_) => slot = self.next_slot(slot),
+            }
+        }
+
+        None
+    }
+
+    pub(crate) unsafe fn insert_impl(&mut self, key: K, value: V) -> Result<(), HashMapError> {
+        self.verify_init("insert()");
+
+        let msg = "Unable to insert key-value pair into HashMap";
+        let origin = "MetaHashMap::insert_impl()";
+
+        let hash = DeterministicHasher::hash_of(&key);
+        if self.find_slot(&key, hash).is_some() {
+            fail!(from origin, with HashMapError::KeyAlreadyExists, "{msg} since the passed key already exists.");
+        }
+        if self.len == self.capacity {
+            fail!(from origin, with HashMapError::IsFull, "{msg} since the HashMap is full.");
+        }
+
+        // there are always more slots than entries, therefore a free slot exists
+        let mut slot = self.home_slot(hash);
+        while self.slots[slot].is_some() {
+            slot = self.next_slot(slot);
+        }
+        self.slots[slot] = RelocatableOption::Some(Entry { hash, key, value });
+        self.len += 1;
+
+        Ok(())
+    }
+
+    pub(crate) unsafe fn get_ref_impl(&self, key: &K) -> Option<&V> {
+        self.verify_init("get_ref()");
+
+        let slot = self.find_slot(key, DeterministicHasher::hash_of(key))?;
+        self.slots[slot].as_option_ref().map(|entry| &entry.value)
+    }
+
+    pub(crate) unsafe fn get_mut_ref_impl(&mut self, key: &K) -> Option<&mut V> {
+        self.verify_init("get_mut_ref()");
+
+        let slot = self.find_slot(key, DeterministicHasher::hash_of(key))?;
+        self.slots[slot]
+            .as_option_mut()
+            .map(|entry| &mut entry.value)
+    }
+
+    pub(crate) unsafe fn remove_impl(&mut self, key: &K) -> Option<V> {
+        self.verify_init("remove()");
+
+        let slot = self.find_slot(key, DeterministicHasher::hash_of(key))?;
+        let entry = self.slots[slot].take().to_option()?;
+        self.len -= 1;
+
+        // Backward shift deletion: every following entry of the probe sequence, that could
+        // have been stored in the hole, is moved into it so that no tombstones are required.
+        let number_of_slots = self.slots.len();
+        let mut hole = slot;
+        let mut next = self.next_slot(slot);
+        while let Some(next_hash) = self.slots[next].as_option_ref().map(|entry| entry.hash) {
+            let home = self.home_slot(next_hash);
+            let distance_to_home = (next + number_of_slots - home) % number_of_slots;
+            let distance_to_hole = (next + number_of_slots - hole) % number_of_slots;
+            if distance_to_home >= distance_to_hole {
+                self.slots[hole] = self.slots[next].take();
+                hole = next;
+            }
+            next = self.next_slot(next);
+        }
+
+        Some(entry.value)
+    }
+
+    pub(crate) unsafe fn contains_impl(&self, key: &K) -> bool {
+        self.verify_init("contains()");
+        self.find_slot(key, DeterministicHasher::hash_of(key))
+            .is_some()
+    }
+
+    pub(crate) unsafe fn clear_impl(&mut self) {
+        self.verify_init("clear()");
+
+        for slot in self.slots.iter_mut() {
+            *slot = RelocatableOption::None;
+        }
+        self.len = 0;
+    }
+
+    pub(crate) unsafe fn list_keys_impl<F: FnMut(&K) -> CallbackProgression>(
+        &self,
+        mut callback: F,
+    ) {
+        self.verify_init("list_keys()");
+
+        for entry in self.slots.iter().filter_map(|slot| slot.as_option_ref()) {
+            if callback(&entry.key) == CallbackProgression::Stop {
+                break;
+            }
+        }
+    }
+
+    pub(crate) unsafe fn list_impl<F: FnMut(&K, &V) -> CallbackProgression>(
+        &self,
+        mut callback: F,
+    ) {
+        self.verify_init("list()");
+
+        for entry in self.slots.iter().filter_map(|slot| slot.as_option_ref()) {
+            if callback(&entry.key, &entry.value) == CallbackProgression::Stop {
+                break;
+            }
+        }
+    }
+
+    /// Returns true if the map is empty, otherwise false.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns true if the map is full, otherwise false.
+    pub fn is_full(&self) -> bool {
+        self.len == self.capacity
+    }
+
+    /// Returns the number of stored key-value pairs.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the maximum number of key-value pairs the map can hold.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+impl<K: Eq + Hash, V> HashMap<K, V> {
+    /// Creates a new runtime-fixed size [`HashMap`] on the heap with the given capacity.
+    pub fn new(capacity: usize) -> Self {
+        let mut new_self = Self {
+            slots: Vec::new(number_of_slots(capacity)),
+            capacity,
+            len: 0,
+            is_initialized: AtomicBool::new(true),
+        };
+        new_self.initialize_data_structures();
+        new_self
+    }
+
+    /// Inserts a new key-value pair into the [`HashMap`]. On success, the method returns [`Ok`],
+    /// otherwise a [`HashMapError`] describing the failure.
+    pub fn insert(&mut self, key: K, value: V) -> Result<(), HashMapError> {
+        unsafe { self.insert_impl(key, value) }
+    }
+
+    /// Returns a reference to the value corresponding to the given key. If there is no such
+    /// key, [`None`] is returned.
+    pub fn get_ref(&self, key: &K) -> Option<&V> {
+        unsafe { self.get_ref_impl(key) }
+    }
+
+    /// Returns a mutable reference to the value corresponding to the given key. If there is
+    /// no such key, [`None`] is returned.
+    pub fn get_mut_ref(&mut self, key: &K) -> Option<&mut V> {
+        unsafe { self.get_mut_ref_impl(key) }
+    }
+
+    /// Removes a key from the [`HashMap`], returning the Some(value) at the key if the key
+    /// was previously in the map or [`None`] otherwise.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        unsafe { self.remove_impl(key) }
+    }
+
+    /// Returns true if the [`HashMap`] contains the given key, otherwise false.
+    pub fn contains(&self, key: &K) -> bool {
+        unsafe { self.contains_impl(key) }
+    }
+
+    /// Removes all key-value pairs from the [`HashMap`].
+    pub fn clear(&mut self) {
+        unsafe { self.clear_impl() }
+    }
+
+    /// Iterates over all keys of the map and calls the provided callback.
+    pub fn list_keys<F: FnMut(&K) -> CallbackProgression>(&self, callback: F) {
+        unsafe { self.list_keys_impl(callback) };
+    }
+
+    /// Iterates over all key-value pairs of the map and calls the provided callback.
+    pub fn list<F: FnMut(&K, &V) -> CallbackProgression>(&self, callback: F) {
+        unsafe { self.list_impl(callback) };
+    }
+}
+
+impl<K: Eq + Hash, V: Clone> HashMap<K, V> {
+    /// Returns a copy of the value corresponding to the given key. If there is no such key,
+    /// [`None`] is returned.
+    pub fn get(&self, key: &K) -> Option<V> {
+        self.get_ref(key).cloned()
+    }
+}
+
+impl<K: Eq + Hash, V> RelocatableContainer for RelocatableHashMap<K, V> {
+    unsafe fn new_uninit(capacity: usize) -> Self {
+        Self {
+            slots: unsafe { RelocatableVec::new_uninit(number_of_slots(capacity)) },
+            capacity,
+            len: 0,
+            is_initialized: AtomicBool::new(false),
+        }
+    }
+
+    unsafe fn init<Allocator: iceoryx2_bb_elementary_traits::allocator::BaseAllocator>(
+        &mut self,
+        allocator: &Allocator,
+    ) -> Result<(), iceoryx2_bb_elementary_traits::allocator::AllocationError> {
+        if self
+            .is_initialized
+            .load(core::sync::atomic::Ordering::Relaxed)
+        {
+            fatal_panic!(from "RelocatableHashMap::init()", "Memory already initialized. Initializing it twice may lead to undefined behavior.");
+        }
+        let msg = "Unable to initialize RelocatableHashMap";
+        fail!(from "RelocatableHashMap::init()", when unsafe { self.slots.init(allocator) }, "{msg} since the underlying slots could not be initialized.");
+
+        self.initialize_data_structures();
+        self.is_initialized
+            .store(true, core::sync::atomic::Ordering::Relaxed);
+        Ok(())
+    }
+
+    fn memory_size(capacity: usize) -> usize {
+        Self::const_memory_size(capacity)
+    }
+}
+
+unsafe impl<K: Eq + Hash + ZeroCopySend, V: ZeroCopySend> ZeroCopySend
+    for RelocatableHashMap<K, V>
+{
+    const TYPE_LAYOUT_HASH: u64 = combine_layout_hashes(K::TYPE_LAYOUT_HASH, V::TYPE_LAYOUT_HASH);
+}
+
+unsafe impl<K: Eq + Hash + Validate, V: Validate> Validate for RelocatableHashMap<K, V> {
+    unsafe fn validate(ptr: *const Self, bounds: &ValidationBounds) -> Result<(), ValidationError> {
+        unsafe {
+            AtomicBool::validate(core::ptr::addr_of!((*ptr).is_initialized), bounds)?;
+            RelocatableVec::validate(core::ptr::addr_of!((*ptr).slots), bounds)?;
+        }
+
+        let this = unsafe { &*ptr };
+        let has_required_slots = this
+            .capacity
+            .checked_mul(2)
+            .is_some_and(|n| n == this.slots.len() && n == this.slots.capacity());
+        if !this
+            .is_initialized
+            .load(core::sync::atomic::Ordering::Relaxed)
+            || !has_required_slots
+            || this.capacity < this.len
+            || this
+                .slots
+                .data_bounds()
+                .overlaps(&ValidationBounds::of_value(this))
+        {
+            return Err(ValidationError::InvariantViolation);
+        }
+
+        let mut number_of_entries = 0;
+        for entry in this.slots.iter().filter_map(|slot| slot.as_option_ref()) {
+            // an entry with a wrong hash is not found anymore and breaks the probe sequence
+            if entry.hash != DeterministicHasher::hash_of(&entry.key) {
+                return Err(ValidationError::InvariantViolation);
+            }
+            number_of_entries += 1;
+        }
+
+        match number_of_entries == this.len {
+            true => Ok(()),
+            false => Err(ValidationError::InvariantViolation),
+        }
+    }
+}
+
+impl<K: Eq + Hash, V> RelocatableHashMap<K, V> {
+    /// Returns how much memory the [`RelocatableHashMap`] will allocate from the allocator
+    /// in [`RelocatableHashMap::init()`].
+    pub const fn const_memory_size(capacity: usize) -> usize {
+        RelocatableVec::<Slot<K, V>>::const_memory_size(number_of_slots(capacity))
+    }
+
+    /// Inserts a new key-value pair into the map. On success, the method returns [`Ok`],
+    /// otherwise a [`HashMapError`] describing the failure.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableHashMap::init()`] must be called once before
+    ///
+    pub unsafe fn insert(&mut self, key: K, value: V) -> Result<(), HashMapError> {
+        unsafe { self.insert_impl(key, value) }
+    }
+
+    /// Returns a reference to the value corresponding to the given key. If there is no such
+    /// key, [`None`] is returned.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableHashMap::init()`] must be called once before
+    ///
+    pub unsafe fn get_ref(&self, key: &K) -> Option<&V> {
+        unsafe { self.get_ref_impl(key) }
+    }
+
+    /// Returns a mutable reference to the value corresponding to the given key. If there is
+    /// no such key, [`None`] is returned.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableHashMap::init()`] must be called once before
+    ///
+    pub unsafe fn get_mut_ref(&mut self, key: &K) -> Option<&mut V> {
+        unsafe { self.get_mut_ref_impl(key) }
+    }
+
+    /// Removes a key from the map, returning the Some(value) at the key if the key was
+    /// previously in the map or [`None`] otherwise.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableHashMap::init()`] must be called once before
+    ///
+    pub unsafe fn remove(&mut self, key: &K) -> Option<V> {
+        unsafe { self.remove_impl(key) }
+    }
+
+    /// Returns true if the map contains the given key, otherwise false.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableHashMap::init()`] must be called once before
+    ///
+    pub unsafe fn contains(&self, key: &K) -> bool {
+        unsafe { self.contains_impl(key) }
+    }
+
+    /// Removes all key-value pairs from the map.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableHashMap::init()`] must be called once before
+    ///
+    pub unsafe fn clear(&mut self) {
+        unsafe { self.clear_impl() }
+    }
+
+    /// Iterates over all keys of the map and calls the provided callback.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableHashMap::init()`] must be called once before
+    ///
+    pub unsafe fn list_keys<F: FnMut(&K) -> CallbackProgression>(&self, callback: F) {
+        unsafe { self.list_keys_impl(callback) };
+    }
+
+    /// Iterates over all key-value pairs of the map and calls the provided callback.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableHashMap::init()`] must be called once before
+    ///
+    pub unsafe fn list<F: FnMut(&K, &V) -> CallbackProgression>(&self, callback: F) {
+        unsafe { self.list_impl(callback) };
+    }
+}
+
+impl<K: Eq + Hash, V: Clone> RelocatableHashMap<K, V> {
+    /// Returns a copy of the value corresponding to the given key. If there is no such key,
+    /// [`None`] is returned.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableHashMap::init()`] must be called once before
+    ///
+    pub unsafe fn get(&self, key: &K) -> Option<V> {
+        unsafe { self.get_ref_impl(key) }.cloned()
+    }
+}
+
+/// A compile-time fixed-size, shared-memory compatible [`FixedSizeHashMap`].
+#[repr(C)]
+pub struct FixedSizeHashMap<K: Eq + Hash, V, const CAPACITY: usize> {
+    map: RelocatableHashMap<K, V>,
+    // the map requires twice as many slots as its capacity, see `number_of_slots()`
+    _slots: MaybeUninit<[Slot<K, V>; CAPACITY]>,
+    _additional_slots: MaybeUninit<[Slot<K, V>; CAPACITY]>,
+}
+
+unsafe impl<K: Eq + Hash + ZeroCopySend, V: ZeroCopySend, const CAPACITY: usize> ZeroCopySend
+    for FixedSizeHashMap<K, V, CAPACITY>
+{
+    const TYPE_LAYOUT_HASH: u64 = combine_layout_hashes(K::TYPE_LAYOUT_HASH, V::TYPE_LAYOUT_HASH);
+}
+
+unsafe impl<K: Eq + Hash + Validate, V: Validate, const CAPACITY: usize> Validate
+    for FixedSizeHashMap<K, V, CAPACITY>
+{
+    unsafe fn validate(ptr: *const Self, bounds: &ValidationBounds) -> Result<(), ValidationError> {
+        let map = unsafe { core::ptr::addr_of!((*ptr).map) };
+        unsafe { RelocatableHashMap::validate(map, bounds) }?;
+
+        let slots = unsafe { core::ptr::addr_of!((*ptr)._slots) }.cast::<Slot<K, V>>();
+        if unsafe { (*map).capacity } != CAPACITY
+            || unsafe { (*map).slots.data_bounds() }
+                != ValidationBounds::of_elements(slots, number_of_slots(CAPACITY))
+        {
+            return Err(ValidationError::InvariantViolation);
+        }
+
+        Ok(())
+    }
+}
+
+impl<K: Eq + Hash, V, const CAPACITY: usize> PlacementDefault for FixedSizeHashMap<K, V, CAPACITY> {
+    unsafe fn placement_default(ptr: *mut Self) {
+        unsafe {
+            let map_ptr = core::ptr::addr_of_mut!((*ptr).map);
+            map_ptr.write(RelocatableHashMap::new_uninit(CAPACITY));
+
+            // SAFETY: Creating a pointer to an existing member is always not null
+            let data_ptr =
+                core::ptr::NonNull::<u8>::new_unchecked((*ptr)._slots.as_mut_ptr().cast());
+
+            let allocator = BumpAllocator::new(
+                data_ptr,
+                size_of::<Self>() - core::mem::offset_of!(Self, _slots),
+            );
+            (*ptr)
+                .map
+                .init(&allocator)
+                .expect("All required memory is preallocated.");
+        }
+    }
+}
+
+impl<K: Eq + Hash, V, const CAPACITY: usize> Default for FixedSizeHashMap<K, V, CAPACITY> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Eq + Hash + Debug, V: Debug, const CAPACITY: usize> Debug
+    for FixedSizeHashMap<K, V, CAPACITY>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "FixedSizeHashMap<{}, {}, {}> {{ {:?} }}",
+            core::any::type_name::<K>(),
+            core::any::type_name::<V>(),
+            CAPACITY,
+            self.map
+        )
+    }
+}
+
+impl<K: Eq + Hash, V, const CAPACITY: usize> FixedSizeHashMap<K, V, CAPACITY> {
+    /// Creates a new [`FixedSizeHashMap`]
+    pub fn new() -> Self {
+        let mut new_self = Self {
+            map: unsafe { RelocatableHashMap::new_uninit(CAPACITY) },
+            _slots: MaybeUninit::uninit(),
+            _additional_slots: MaybeUninit::uninit(),
+        };
+
+        // SAFETY: Creating a pointer to an existing member is always not null
+        let data_ptr =
+            unsafe { core::ptr::NonNull::<u8>::new_unchecked(new_self._slots.as_mut_ptr().cast()) };
+
+        let allocator = BumpAllocator::new(
+            data_ptr,
+            size_of::<Self>() - core::mem::offset_of!(Self, _slots),
+        );
+        unsafe {
+            new_self
+                .map
+                .init(&allocator)
+                .expect("All required memory is preallocated.")
+        };
+        new_self
+    }
+
+    /// Inserts a new key-value pair into the [`FixedSizeHashMap`]. On success, the method
+    /// returns [`Ok`], otherwise a [`HashMapError`] describing the failure.
+    pub fn insert(&mut self, key: K, value: V) -> Result<(), HashMapError> {
+        unsafe { self.map.insert(key, value) }
+    }
+
+    /// Returns a reference to the value corresponding to the given key. If there is no such
+    /// key, [`None`] is returned.
+    pub fn get_ref(&self, key: &K) -> Option<&V> {
+        unsafe { self.map.get_ref(key) }
+    }
+
+    /// Returns a mutable reference to the value corresponding to the given key. If there is
+    /// no such key, [`None`] is returned.
+    pub fn get_mut_ref(&mut self, key: &K) -> Option<&mut V> {
+        unsafe { self.map.get_mut_ref(key) }
+    }
+
+    /// Removes a key from the [`FixedSizeHashMap`], returning the Some(value) at the key if
+    /// the key was previously in the map or [`None`] otherwise.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        unsafe { self.map.remove(key) }
+    }
+
+    /// Returns true if the [`FixedSizeHashMap`] is empty, otherwise false.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Returns true if the [`FixedSizeHashMap`] is full, otherwise false.
+    pub fn is_full(&self) -> bool {
+        self.map.is_full()
+    }
+
+    /// Returns true if the [`FixedSizeHashMap`] contains the given key, otherwise false.
+    pub fn contains(&self, key: &K) -> bool {
+        unsafe { self.map.contains(key) }
+    }
+
+    /// Returns the number of stored key-value pairs.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns the maximum number of key-value pairs the [`FixedSizeHashMap`] can hold.
+    pub fn capacity(&self) -> usize {
+        self.map.capacity()
+    }
+
+    /// Removes all key-value pairs from the [`FixedSizeHashMap`].
+    pub fn clear(&mut self) {
+        unsafe { self.map.clear() }
+    }
+
+    /// Iterates over all keys of the map and calls the provided callback.
+    pub fn list_keys<F: FnMut(&K) -> CallbackProgression>(&self, callback: F) {
+        unsafe { self.map.list_keys(callback) };
+    }
+
+    /// Iterates over all key-value pairs of the map and calls the provided callback.
+    pub fn list<F: FnMut(&K, &V) -> CallbackProgression>(&self, callback: F) {
+        unsafe { self.map.list(callback) };
+    }
+}
+
+impl<K: Eq + Hash, V: Clone, const CAPACITY: usize> FixedSizeHashMap<K, V, CAPACITY> {
+    /// Returns a copy of the value corresponding to the given key. If there is no such key,
+    /// [`None`] is returned.
+    pub fn get(&self, key: &K) -> Option<V> {
+        unsafe { self.map.get(key) }
+    }
+}
@@ -95,6 +95,8 @@
 
 extern crate alloc;
 
+/// A double-ended queue with access to every element by its position.
+pub mod deque;
 /// A queue similar to [`alloc::collections::vec_deque::VecDeque`]
 pub mod queue;
 /// A container with persistent unique keys to access values.
@@ -107,6 +109,15 @@ pub mod semantic_string;
 /// A container to store key-value pairs.
 pub mod flatmap;
 
+/// A container to store key-value pairs with constant time lookup.
+pub mod hashmap;
+
+/// Ordered maps and sets with logarithmic time lookup.
+pub mod btree;
+
+/// Doubly linked lists with stable handles to their elements.
+pub mod list;
+
 /// A trait that defines the interface of a string and several string variants.
 pub mod string;
 
@@ -114,6 +125,9 @@ pub mod string;
 /// shared memory compatible.
 pub mod relocatable_option;
 
+/// Constructs relocatable containers with a runtime capacity inside a byte slice.
+pub mod slice_container;
+
 #[doc(hidden)]
 pub(crate) mod vec;
 /// A trait that defines the interface of a vector and several vector variants.
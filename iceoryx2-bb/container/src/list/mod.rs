@@ -0,0 +1,641 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Doubly linked lists that are shared-memory compatible.
+//!
+//!  * [`StaticList`], compile-time fixed-capacity variant that is self-contained.
+//!  * [`RelocatableList`], run-time fixed-capacity variant that acquires the memory from an
+//!    allocator.
+//!
+//! The elements are stored in nodes of a fixed-capacity arena and the nodes are linked by their
+//! index in the arena, so that the lists contain no absolute pointers. Every inserted element is
+//! identified by a [`ListHandle`] that stays valid until the element is removed, independent of
+//! any other insert or remove operation. The handle can be used, directly or via a
+//! [`CursorMut`], to insert or remove elements at arbitrary positions in `O(1)`.
+//!
+//! # Example
+//!
+//! ```
+//! # extern crate iceoryx2_bb_loggers;
+//!
+//! use iceoryx2_bb_container::list::*;
+//!
+//! const CAPACITY: usize = 16;
+//! let mut list = StaticList::<u32, CAPACITY>::new();
+//!
+//! let first = list.push_back(1).unwrap();
+//! list.push_back(3).unwrap();
+//! list.insert_after(first, 2).unwrap();
+//!
+//! assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+//!
+//! assert_eq!(list.remove(first), Some(1));
+//! assert_eq!(list.get(first), None);
+//! ```
+
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+
+/// Runtime fixed-capacity shared-memory compatible doubly linked list
+pub mod relocatable_list;
+/// Compile-time fixed-capacity shared-memory compatible doubly linked list
+pub mod static_list;
+
+use alloc::format;
+use iceoryx2_bb_derive_macros::Validate;
+use iceoryx2_bb_elementary_traits::validate::{Validate, ValidationBounds, ValidationError};
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_log::fail;
+pub use relocatable_list::*;
+pub use static_list::*;
+
+const INVALID_INDEX: u64 = u64::MAX;
+
+/// Error which can occur when an element is inserted into a [`List`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ListModificationError {
+    /// The list is full and cannot hold an additional element.
+    IsFull,
+    /// The provided [`ListHandle`] does not refer to an element of the list.
+    InvalidHandle,
+}
+
+impl core::fmt::Display for ListModificationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "ListModificationError::{self:?}")
+    }
+}
+
+impl core::error::Error for ListModificationError {}
+
+/// Identifies an element of a [`List`]. It stays valid until the element is removed and since it
+/// consists only of the position in the list it can be shared with other processes together
+/// with the list.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Validate)]
+pub struct ListHandle {
+    index: u64,
+    generation: u64,
+}
+
+unsafe impl ZeroCopySend for ListHandle {}
+
+#[doc(hidden)]
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct Links {
+    prev: u64,
+    next: u64,
+    // incremented on every acquire and release of the node, it is odd when the node is in use
+    generation: u64,
+}
+
+#[doc(hidden)]
+#[repr(C)]
+pub struct Node<T> {
+    links: Links,
+    value: MaybeUninit<T>,
+}
+
+#[doc(hidden)]
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct ListState {
+    head: u64,
+    tail: u64,
+    len: u64,
+    free_list_head: u64,
+    next_unused_node: u64,
+}
+
+impl ListState {
+    pub(crate) const fn new() -> Self {
+        Self {
+            head: INVALID_INDEX,
+            tail: INVALID_INDEX,
+            len: 0,
+            free_list_head: INVALID_INDEX,
+            next_unused_node: 0,
+        }
+    }
+}
+
+pub(crate) mod internal {
+    use super::*;
+
+    #[doc(hidden)]
+    pub trait ListView<T> {
+        fn state(&self) -> &ListState;
+
+        fn nodes(&self) -> &[MaybeUninit<Node<T>>];
+
+        /// # Safety
+        ///
+        /// * user must keep the [`ListState`] consistent with the nodes
+        unsafe fn parts_mut(&mut self) -> (&mut ListState, &mut [MaybeUninit<Node<T>>]);
+    }
+}
+
+/// Validates that the [`ListState`] is consistent with the nodes, that the elements and the
+/// free nodes form proper lists and that all elements are valid.
+///
+/// # Safety
+///
+/// * `nodes` must point to `capacity` readable nodes of which the first
+///   `state.next_unused_node` are initialized, when `state.next_unused_node <= capacity`
+unsafe fn validate_nodes<T: Validate>(
+    state: &ListState,
+    nodes: *const MaybeUninit<Node<T>>,
+    capacity: u64,
+    bounds: &ValidationBounds,
+) -> Result<(), ValidationError> {
+    if capacity < state.next_unused_node || state.next_unused_node < state.len {
+        return Err(ValidationError::InvariantViolation);
+    }
+
+    let node_ptr = |index: u64| unsafe { nodes.add(index as usize) }.cast::<Node<T>>();
+    let links = |index: u64| unsafe { (*node_ptr(index)).links };
+
+    // the step limits ensure that every node is visited only once, even when links are cyclic
+    let mut prev = INVALID_INDEX;
+    let mut current = state.head;
+    let mut number_of_elements = 0;
+    while current != INVALID_INDEX {
+        if state.next_unused_node <= current || number_of_elements == state.len {
+            return Err(ValidationError::InvariantViolation);
+        }
+
+        let current_links = links(current);
+        if current_links.prev != prev || current_links.generation % 2 == 0 {
+            return Err(ValidationError::InvariantViolation);
+        }
+
+        unsafe {
+            T::validate(
+                core::ptr::addr_of!((*node_ptr(current)).value).cast::<T>(),
+                bounds,
+            )
+        }?;
+
+        number_of_elements += 1;
+        prev = current;
+        current = current_links.next;
+    }
+
+    if number_of_elements != state.len || state.tail != prev {
+        return Err(ValidationError::InvariantViolation);
+    }
+
+    let number_of_free_nodes = state.next_unused_node - state.len;
+    let mut current = state.free_list_head;
+    let mut number_of_visited_free_nodes = 0;
+    while current != INVALID_INDEX {
+        if state.next_unused_node <= current
+            || number_of_visited_free_nodes == number_of_free_nodes
+            || links(current).generation % 2 == 1
+        {
+            return Err(ValidationError::InvariantViolation);
+        }
+
+        number_of_visited_free_nodes += 1;
+        current = links(current).next;
+    }
+
+    match number_of_visited_free_nodes == number_of_free_nodes {
+        true => Ok(()),
+        false => Err(ValidationError::InvariantViolation),
+    }
+}
+
+fn node<T>(nodes: &[MaybeUninit<Node<T>>], index: u64) -> &Node<T> {
+    unsafe { nodes[index as usize].assume_init_ref() }
+}
+
+fn node_mut<T>(nodes: &mut [MaybeUninit<Node<T>>], index: u64) -> &mut Node<T> {
+    unsafe { nodes[index as usize].assume_init_mut() }
+}
+
+fn handle_of<T>(nodes: &[MaybeUninit<Node<T>>], index: u64) -> Option<ListHandle> {
+    if index == INVALID_INDEX {
+        return None;
+    }
+
+    Some(ListHandle {
+        index,
+        generation: node(nodes, index).links.generation,
+    })
+}
+
+/// Iterator over the elements of a [`List`] from front to back.
+pub struct Iter<'a, T> {
+    nodes: &'a [MaybeUninit<Node<T>>],
+    front: u64,
+    back: u64,
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let node = node(self.nodes, self.front);
+        self.front = node.links.next;
+        self.remaining -= 1;
+        Some(unsafe { node.value.assume_init_ref() })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let node = node(self.nodes, self.back);
+        self.back = node.links.prev;
+        self.remaining -= 1;
+        Some(unsafe { node.value.assume_init_ref() })
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+/// A cursor over a [`List`] that can insert and remove elements at its position in `O(1)`.
+/// Besides the elements, the cursor can point to a "ghost" position that is located between
+/// the back and the front of the list.
+pub struct CursorMut<'a, T, L: List<T>> {
+    list: &'a mut L,
+    current: Option<ListHandle>,
+    _data: PhantomData<T>,
+}
+
+impl<T, L: List<T>> CursorMut<'_, T, L> {
+    /// Returns the [`ListHandle`] of the current element or [`None`] when the cursor points
+    /// to the ghost position.
+    pub fn handle(&self) -> Option<ListHandle> {
+        self.current
+    }
+
+    /// Returns a reference to the current element or [`None`] when the cursor points to the
+    /// ghost position.
+    pub fn current(&self) -> Option<&T> {
+        self.list.get(self.current?)
+    }
+
+    /// Returns a mutable reference to the current element or [`None`] when the cursor points
+    /// to the ghost position.
+    pub fn current_mut(&mut self) -> Option<&mut T> {
+        self.list.get_mut(self.current?)
+    }
+
+    /// Moves the cursor to the next element. When the cursor points to the back of the list it
+    /// moves to the ghost position and from the ghost position to the front of the list.
+    pub fn move_next(&mut self) {
+        self.current = match self.current {
+            Some(handle) => self.list.next(handle),
+            None => self.list.front_handle(),
+        };
+    }
+
+    /// Moves the cursor to the previous element. When the cursor points to the front of the
+    /// list it moves to the ghost position and from the ghost position to the back of the list.
+    pub fn move_prev(&mut self) {
+        self.current = match self.current {
+            Some(handle) => self.list.prev(handle),
+            None => self.list.back_handle(),
+        };
+    }
+
+    /// Inserts the value before the current element. When the cursor points to the ghost
+    /// position the value is inserted at the back of the list.
+    pub fn insert_before(&mut self, value: T) -> Result<ListHandle, ListModificationError> {
+        match self.current {
+            Some(handle) => self.list.insert_before(handle, value),
+            None => self.list.push_back(value),
+        }
+    }
+
+    /// Inserts the value after the current element. When the cursor points to the ghost
+    /// position the value is inserted at the front of the list.
+    pub fn insert_after(&mut self, value: T) -> Result<ListHandle, ListModificationError> {
+        match self.current {
+            Some(handle) => self.list.insert_after(handle, value),
+            None => self.list.push_front(value),
+        }
+    }
+
+    /// Removes the current element and moves the cursor to the next element. When the cursor
+    /// points to the ghost position nothing is removed and [`None`] is returned.
+    pub fn remove_current(&mut self) -> Option<T> {
+        let handle = self.current?;
+        self.current = self.list.next(handle);
+        self.list.remove(handle)
+    }
+}
+
+/// Defines the interface of a doubly linked list.
+pub trait List<T>: internal::ListView<T> + Sized {
+    /// Returns the capacity of the list
+    fn capacity(&self) -> usize;
+
+    /// Returns the number of elements stored inside the list
+    fn len(&self) -> usize {
+        self.state().len as usize
+    }
+
+    /// Returns true if the list is empty, otherwise false
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns true if the list is full, otherwise false
+    fn is_full(&self) -> bool {
+        self.len() == self.capacity()
+    }
+
+    /// Returns true if the [`ListHandle`] refers to an element of the list, otherwise false
+    fn contains(&self, handle: ListHandle) -> bool {
+        handle.index < self.state().next_unused_node
+            && node(self.nodes(), handle.index).links.generation == handle.generation
+    }
+
+    /// Returns a reference to the element the [`ListHandle`] refers to. If the element was
+    /// already removed, [`None`] is returned.
+    fn get(&self, handle: ListHandle) -> Option<&T> {
+        if !self.contains(handle) {
+            return None;
+        }
+
+        Some(unsafe { node(self.nodes(), handle.index).value.assume_init_ref() })
+    }
+
+    /// Returns a mutable reference to the element the [`ListHandle`] refers to. If the element
+    /// was already removed, [`None`] is returned.
+    fn get_mut(&mut self, handle: ListHandle) -> Option<&mut T> {
+        if !self.contains(handle) {
+            return None;
+        }
+
+        let (_, nodes) = unsafe { self.parts_mut() };
+        Some(unsafe { node_mut(nodes, handle.index).value.assume_init_mut() })
+    }
+
+    /// Returns the [`ListHandle`] of the first element or [`None`] when the list is empty.
+    fn front_handle(&self) -> Option<ListHandle> {
+        handle_of(self.nodes(), self.state().head)
+    }
+
+    /// Returns the [`ListHandle`] of the last element or [`None`] when the list is empty.
+    fn back_handle(&self) -> Option<ListHandle> {
+        handle_of(self.nodes(), self.state().tail)
+    }
+
+    /// Returns the [`ListHandle`] of the element that follows the provided one. If it is the
+    /// last element or the handle is invalid, [`None`] is returned.
+    fn next(&self, handle: ListHandle) -> Option<ListHandle> {
+        if !self.contains(handle) {
+            return None;
+        }
+
+        handle_of(self.nodes(), node(self.nodes(), handle.index).links.next)
+    }
+
+    /// Returns the [`ListHandle`] of the element that precedes the provided one. If it is the
+    /// first element or the handle is invalid, [`None`] is returned.
+    fn prev(&self, handle: ListHandle) -> Option<ListHandle> {
+        if !self.contains(handle) {
+            return None;
+        }
+
+        handle_of(self.nodes(), node(self.nodes(), handle.index).links.prev)
+    }
+
+    /// Returns a reference to the first element or [`None`] when the list is empty.
+    fn front(&self) -> Option<&T> {
+        self.get(self.front_handle()?)
+    }
+
+    /// Returns a reference to the last element or [`None`] when the list is empty.
+    fn back(&self) -> Option<&T> {
+        self.get(self.back_handle()?)
+    }
+
+    /// Returns a mutable reference to the first element or [`None`] when the list is empty.
+    fn front_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(self.front_handle()?)
+    }
+
+    /// Returns a mutable reference to the last element or [`None`] when the list is empty.
+    fn back_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(self.back_handle()?)
+    }
+
+    /// Adds an element at the front of the list and returns its [`ListHandle`]. If the list is
+    /// full it returns [`ListModificationError::IsFull`].
+    fn push_front(&mut self, value: T) -> Result<ListHandle, ListModificationError> {
+        let head = self.state().head;
+        unsafe { link(self, INVALID_INDEX, head, value, "push_front") }
+    }
+
+    /// Adds an element at the back of the list and returns its [`ListHandle`]. If the list is
+    /// full it returns [`ListModificationError::IsFull`].
+    fn push_back(&mut self, value: T) -> Result<ListHandle, ListModificationError> {
+        let tail = self.state().tail;
+        unsafe { link(self, tail, INVALID_INDEX, value, "push_back") }
+    }
+
+    /// Inserts an element directly before the element the [`ListHandle`] refers to and returns
+    /// the [`ListHandle`] of the new element.
+    fn insert_before(
+        &mut self,
+        handle: ListHandle,
+        value: T,
+    ) -> Result<ListHandle, ListModificationError> {
+        if !self.contains(handle) {
+            fail!(from format!("List::<{}>::insert_before()", core::any::type_name::<T>()), with ListModificationError::InvalidHandle,
+                "Failed to insert the element since the handle {:?} does not refer to an element of the list.",
+                handle);
+        }
+
+        let prev = node(self.nodes(), handle.index).links.prev;
+        unsafe { link(self, prev, handle.index, value, "insert_before") }
+    }
+
+    /// Inserts an element directly after the element the [`ListHandle`] refers to and returns
+    /// the [`ListHandle`] of the new element.
+    fn insert_after(
+        &mut self,
+        handle: ListHandle,
+        value: T,
+    ) -> Result<ListHandle, ListModificationError> {
+        if !self.contains(handle) {
+            fail!(from format!("List::<{}>::insert_after()", core::any::type_name::<T>()), with ListModificationError::InvalidHandle,
+                "Failed to insert the element since the handle {:?} does not refer to an element of the list.",
+                handle);
+        }
+
+        let next = node(self.nodes(), handle.index).links.next;
+        unsafe { link(self, handle.index, next, value, "insert_after") }
+    }
+
+    /// Removes the element the [`ListHandle`] refers to and returns it. If the element was
+    /// already removed, [`None`] is returned.
+    fn remove(&mut self, handle: ListHandle) -> Option<T> {
+        if !self.contains(handle) {
+            return None;
+        }
+
+        let (state, nodes) = unsafe { self.parts_mut() };
+        let links = node(nodes, handle.index).links;
+
+        match links.prev {
+            INVALID_INDEX => state.head = links.next,
+            prev => node_mut(nodes, prev).links.next = links.next,
+        }
+        match links.next {
+            INVALID_INDEX => state.tail = links.prev,
+            next => node_mut(nodes, next).links.prev = links.prev,
+        }
+
+        let node = node_mut(nodes, handle.index);
+        node.links.generation = node.links.generation.wrapping_add(1);
+        node.links.next = state.free_list_head;
+        state.free_list_head = handle.index;
+        state.len -= 1;
+
+        Some(unsafe { node.value.assume_init_read() })
+    }
+
+    /// Removes the first element and returns it or [`None`] when the list is empty.
+    fn pop_front(&mut self) -> Option<T> {
+        self.remove(self.front_handle()?)
+    }
+
+    /// Removes the last element and returns it or [`None`] when the list is empty.
+    fn pop_back(&mut self) -> Option<T> {
+        self.remove(self.back_handle()?)
+    }
+
+    /// Removes all elements from the list
+    fn clear(&mut self) {
+        while self.pop_back().is_some() {}
+    }
+
+    /// Returns an iterator over all elements from front to back.
+    fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            nodes: self.nodes(),
+            front: self.state().head,
+            back: self.state().tail,
+            remaining: self.len(),
+        }
+    }
+
+    /// Returns a [`CursorMut`] that points to the first element or to the ghost position when
+    /// the list is empty.
+    fn cursor_front_mut(&mut self) -> CursorMut<'_, T, Self> {
+        CursorMut {
+            current: self.front_handle(),
+            list: self,
+            _data: PhantomData,
+        }
+    }
+
+    /// Returns a [`CursorMut`] that points to the last element or to the ghost position when
+    /// the list is empty.
+    fn cursor_back_mut(&mut self) -> CursorMut<'_, T, Self> {
+        CursorMut {
+            current: self.back_handle(),
+            list: self,
+            _data: PhantomData,
+        }
+    }
+
+    /// Returns a [`CursorMut`] that points to the element the [`ListHandle`] refers to. If the
+    /// element was already removed, [`None`] is returned.
+    fn cursor_mut(&mut self, handle: ListHandle) -> Option<CursorMut<'_, T, Self>> {
+        if !self.contains(handle) {
+            return None;
+        }
+
+        Some(CursorMut {
+            current: Some(handle),
+            list: self,
+            _data: PhantomData,
+        })
+    }
+}
+
+/// Links a new node between `prev` and `next`.
+///
+/// # Safety
+///
+/// * `prev` and `next` must be adjacent nodes of the list or [`INVALID_INDEX`] for the
+///   respective end of the list
+unsafe fn link<T, L: List<T>>(
+    list: &mut L,
+    prev: u64,
+    next: u64,
+    value: T,
+    source: &str,
+) -> Result<ListHandle, ListModificationError> {
+    if list.is_full() {
+        fail!(from format!("List::<{}>::{source}()", core::any::type_name::<T>()), with ListModificationError::IsFull,
+                "Failed to insert the element since it would exceed the lists capacity of {}.",
+                list.capacity());
+    }
+
+    let (state, nodes) = unsafe { list.parts_mut() };
+    let index = if state.free_list_head != INVALID_INDEX {
+        let index = state.free_list_head;
+        state.free_list_head = node(nodes, index).links.next;
+        index
+    } else {
+        nodes[state.next_unused_node as usize].write(Node {
+            links: Links {
+                prev: INVALID_INDEX,
+                next: INVALID_INDEX,
+                generation: 0,
+            },
+            value: MaybeUninit::uninit(),
+        });
+        state.next_unused_node += 1;
+        state.next_unused_node - 1
+    };
+
+    let node = node_mut(nodes, index);
+    node.links.prev = prev;
+    node.links.next = next;
+    node.links.generation = node.links.generation.wrapping_add(1);
+    node.value.write(value);
+    let generation = node.links.generation;
+
+    match prev {
+        INVALID_INDEX => state.head = index,
+        prev => node_mut(nodes, prev).links.next = index,
+    }
+    match next {
+        INVALID_INDEX => state.tail = index,
+        next => node_mut(nodes, next).links.prev = index,
+    }
+    state.len += 1;
+
+    Ok(ListHandle { index, generation })
+}
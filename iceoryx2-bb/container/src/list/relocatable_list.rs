@@ -0,0 +1,193 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Contains the [`RelocatableList`], a
+//! run-time fixed size doubly linked list that is shared memory compatible
+//!
+//! # Expert Examples
+//!
+//! ## Create [`RelocatableList`] with allocator
+//!
+//! ```
+//! # extern crate iceoryx2_bb_loggers;
+//! use iceoryx2_bb_container::list::*;
+//! use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
+//! use core::ptr::NonNull;
+//!
+//! const LIST_CAPACITY: usize = 12;
+//! const MEM_SIZE: usize = RelocatableList::<u64>::const_memory_size(LIST_CAPACITY);
+//! let mut memory = [0u8; MEM_SIZE];
+//!
+//! let bump_allocator = BumpAllocator::new(NonNull::new(memory.as_mut_ptr()).unwrap(), MEM_SIZE);
+//!
+//! let mut list = unsafe { RelocatableList::<u64>::new_uninit(LIST_CAPACITY) };
+//! unsafe { list.init(&bump_allocator).expect("list init failed") };
+//!
+//! list.push_back(1234).unwrap();
+//! ```
+
+use alloc::format;
+use core::{alloc::Layout, fmt::Debug, mem::MaybeUninit};
+
+use iceoryx2_bb_elementary::{math::unaligned_mem_size, relocatable_ptr::*};
+pub use iceoryx2_bb_elementary_traits::relocatable_container::RelocatableContainer;
+use iceoryx2_bb_elementary_traits::validate::{Validate, ValidationBounds, ValidationError};
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_log::{fail, fatal_panic};
+
+pub use crate::list::List;
+use crate::list::{ListState, Node, internal, validate_nodes};
+
+/// **Non-movable** relocatable shared-memory compatible doubly linked list with runtime fixed size capacity.
+#[repr(C)]
+pub struct RelocatableList<T> {
+    nodes_ptr: RelocatablePointer<MaybeUninit<Node<T>>>,
+    capacity: u64,
+    state: ListState,
+}
+
+impl<T> Drop for RelocatableList<T> {
+    fn drop(&mut self) {
+        if self.nodes_ptr.is_initialized() {
+            self.clear()
+        }
+    }
+}
+
+unsafe impl<T: Send> Send for RelocatableList<T> {}
+
+unsafe impl<T: ZeroCopySend> ZeroCopySend for RelocatableList<T> {
+    const TYPE_LAYOUT_HASH: u64 = T::TYPE_LAYOUT_HASH;
+}
+
+unsafe impl<T: Validate> Validate for RelocatableList<T> {
+    unsafe fn validate(ptr: *const Self, bounds: &ValidationBounds) -> Result<(), ValidationError> {
+        // every bit pattern of the fields is valid
+        let this = unsafe { &*ptr };
+        let capacity =
+            usize::try_from(this.capacity).map_err(|_| ValidationError::InvariantViolation)?;
+        let nodes = this.nodes_ptr.validated_ptr(capacity, bounds)?;
+        if ValidationBounds::of_elements(nodes, capacity)
+            .overlaps(&ValidationBounds::of_value(this))
+        {
+            return Err(ValidationError::InvariantViolation);
+        }
+
+        unsafe { validate_nodes(&this.state, nodes, this.capacity, bounds) }
+    }
+}
+
+impl<T: Debug> Debug for RelocatableList<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "RelocatableList<{}> {{ capacity: {}, len: {}, content: ",
+            core::any::type_name::<T>(),
+            self.capacity,
+            self.state.len,
+        )?;
+        f.debug_list().entries(self.iter()).finish()?;
+        write!(f, " }}")
+    }
+}
+
+impl<T: PartialEq> PartialEq for RelocatableList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for RelocatableList<T> {}
+
+impl<T> RelocatableList<T> {
+    #[inline(always)]
+    fn verify_init(&self, source: &str) {
+        debug_assert!(
+            self.nodes_ptr.is_initialized(),
+            "From: RelocatableList<{}>::{}, Undefined behavior - the object was not initialized with 'init' before.",
+            core::any::type_name::<T>(),
+            source
+        );
+    }
+
+    /// Returns the required memory size for a list with a specified capacity
+    pub const fn const_memory_size(capacity: usize) -> usize {
+        unaligned_mem_size::<Node<T>>(capacity)
+    }
+}
+
+impl<T> RelocatableContainer for RelocatableList<T> {
+    unsafe fn new_uninit(capacity: usize) -> Self {
+        Self {
+            nodes_ptr: unsafe { RelocatablePointer::new_uninit() },
+            capacity: capacity as u64,
+            state: ListState::new(),
+        }
+    }
+
+    unsafe fn init<Allocator: iceoryx2_bb_elementary_traits::allocator::BaseAllocator>(
+        &mut self,
+        allocator: &Allocator,
+    ) -> Result<(), iceoryx2_bb_elementary_traits::allocator::AllocationError> {
+        if self.nodes_ptr.is_initialized() {
+            let origin = format!("RelocatableList<{}>::init()", core::any::type_name::<T>());
+            fatal_panic!(from origin,
+                "Memory already initialized. Initializing it twice may lead to undefined behavior.");
+        }
+
+        let ptr = match allocator.allocate(unsafe {
+            Layout::from_size_align_unchecked(
+                core::mem::size_of::<Node<T>>() * self.capacity as usize,
+                core::mem::align_of::<Node<T>>(),
+            )
+        }) {
+            Ok(ptr) => ptr,
+            Err(e) => {
+                let origin = format!("RelocatableList<{}>::init()", core::any::type_name::<T>());
+                fail!(from origin, with e,
+                    "Failed to initialize since the allocation of the node memory failed.");
+            }
+        };
+        unsafe {
+            self.nodes_ptr.init(ptr);
+        }
+        Ok(())
+    }
+
+    fn memory_size(capacity: usize) -> usize {
+        Self::const_memory_size(capacity)
+    }
+}
+
+impl<T> internal::ListView<T> for RelocatableList<T> {
+    fn state(&self) -> &ListState {
+        &self.state
+    }
+
+    fn nodes(&self) -> &[MaybeUninit<Node<T>>] {
+        self.verify_init("nodes()");
+        unsafe { core::slice::from_raw_parts(self.nodes_ptr.as_ptr(), self.capacity as usize) }
+    }
+
+    unsafe fn parts_mut(&mut self) -> (&mut ListState, &mut [MaybeUninit<Node<T>>]) {
+        self.verify_init("parts_mut()");
+        (&mut self.state, unsafe {
+            core::slice::from_raw_parts_mut(self.nodes_ptr.as_mut_ptr(), self.capacity as usize)
+        })
+    }
+}
+
+impl<T> List<T> for RelocatableList<T> {
+    fn capacity(&self) -> usize {
+        self.capacity as usize
+    }
+}
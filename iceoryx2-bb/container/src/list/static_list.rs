@@ -0,0 +1,219 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Relocatable shared-memory compatible doubly linked list with compile time fixed size capacity.
+//!
+//! # Example
+//!
+//! ```
+//! # extern crate iceoryx2_bb_loggers;
+//!
+//! use iceoryx2_bb_container::list::*;
+//!
+//! const CAPACITY: usize = 123;
+//! let mut my_list = StaticList::<u64, CAPACITY>::new();
+//!
+//! let handle = my_list.push_back(456).unwrap();
+//! my_list.push_front(123).unwrap();
+//! assert_eq!(my_list.get(handle), Some(&456));
+//! ```
+
+use alloc::format;
+use core::marker::PhantomData;
+use core::{fmt::Debug, mem::MaybeUninit};
+
+use iceoryx2_bb_elementary_traits::{
+    placement_default::PlacementDefault,
+    validate::{Validate, ValidationBounds, ValidationError},
+    zero_copy_send::ZeroCopySend,
+};
+use serde::{Deserialize, Serialize, de::Visitor, ser::SerializeSeq};
+
+pub use crate::list::List;
+use crate::list::{ListState, Node, internal, validate_nodes};
+
+/// Relocatable shared-memory compatible doubly linked list with compile time fixed size capacity.
+#[repr(C)]
+pub struct StaticList<T, const CAPACITY: usize> {
+    nodes: [MaybeUninit<Node<T>>; CAPACITY],
+    state: ListState,
+}
+
+impl<T: Debug, const CAPACITY: usize> Debug for StaticList<T, CAPACITY> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "StaticList<{}, {}> {{ len: {}, content: ",
+            core::any::type_name::<T>(),
+            CAPACITY,
+            self.len(),
+        )?;
+        f.debug_list().entries(self.iter()).finish()?;
+        write!(f, " }}")
+    }
+}
+
+unsafe impl<T: ZeroCopySend, const CAPACITY: usize> ZeroCopySend for StaticList<T, CAPACITY> {
+    const TYPE_LAYOUT_HASH: u64 = T::TYPE_LAYOUT_HASH;
+}
+
+unsafe impl<T: Validate, const CAPACITY: usize> Validate for StaticList<T, CAPACITY> {
+    unsafe fn validate(ptr: *const Self, bounds: &ValidationBounds) -> Result<(), ValidationError> {
+        unsafe {
+            validate_nodes::<T>(
+                &*core::ptr::addr_of!((*ptr).state),
+                core::ptr::addr_of!((*ptr).nodes).cast(),
+                CAPACITY as u64,
+                bounds,
+            )
+        }
+    }
+}
+
+unsafe impl<T: Send, const CAPACITY: usize> Send for StaticList<T, CAPACITY> {}
+
+impl<T, const CAPACITY: usize> Drop for StaticList<T, CAPACITY> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T: Serialize, const CAPACITY: usize> Serialize for StaticList<T, CAPACITY> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for element in self.iter() {
+            seq.serialize_element(element)?;
+        }
+        seq.end()
+    }
+}
+
+struct StaticListVisitor<T, const CAPACITY: usize> {
+    _value: PhantomData<T>,
+}
+
+impl<'de, T: Deserialize<'de>, const CAPACITY: usize> Visitor<'de>
+    for StaticListVisitor<T, CAPACITY>
+{
+    type Value = StaticList<T, CAPACITY>;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let str = format!(
+            "an array of at most {} elements of type {}",
+            CAPACITY,
+            core::any::type_name::<T>()
+        );
+        formatter.write_str(&str)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        let mut new_list = Self::Value::new();
+
+        while let Some(element) = seq.next_element()? {
+            if new_list.push_back(element).is_err() {
+                return Err(<A::Error as serde::de::Error>::custom(format!(
+                    "the array can hold at most {CAPACITY} elements"
+                )));
+            }
+        }
+
+        Ok(new_list)
+    }
+}
+
+impl<'de, T: Deserialize<'de>, const CAPACITY: usize> Deserialize<'de> for StaticList<T, CAPACITY> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(StaticListVisitor::<T, CAPACITY> {
+            _value: PhantomData,
+        })
+    }
+}
+
+impl<T, const CAPACITY: usize> PlacementDefault for StaticList<T, CAPACITY> {
+    unsafe fn placement_default(ptr: *mut Self) {
+        unsafe {
+            core::ptr::addr_of_mut!((*ptr).state).write(ListState::new());
+            // the nodes are initialized on demand when they are acquired for the first time
+        }
+    }
+}
+
+impl<T, const CAPACITY: usize> Default for StaticList<T, CAPACITY> {
+    fn default() -> Self {
+        Self {
+            nodes: [const { MaybeUninit::uninit() }; CAPACITY],
+            state: ListState::new(),
+        }
+    }
+}
+
+impl<T: PartialEq, const CAPACITY: usize> PartialEq for StaticList<T, CAPACITY> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, const CAPACITY: usize> Eq for StaticList<T, CAPACITY> {}
+
+impl<T: Clone, const CAPACITY: usize> Clone for StaticList<T, CAPACITY> {
+    /// Clones the elements in their order. The [`ListHandle`](crate::list::ListHandle)s of the
+    /// original list are not valid for the clone.
+    fn clone(&self) -> Self {
+        let mut new_list = Self::new();
+        for element in self.iter() {
+            // cannot fail, the capacity is identical
+            let _ = new_list.push_back(element.clone());
+        }
+        new_list
+    }
+}
+
+impl<T, const CAPACITY: usize> StaticList<T, CAPACITY> {
+    /// Creates a new list.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the capacity of the list
+    pub const fn capacity() -> usize {
+        CAPACITY
+    }
+}
+
+impl<T, const CAPACITY: usize> internal::ListView<T> for StaticList<T, CAPACITY> {
+    fn state(&self) -> &ListState {
+        &self.state
+    }
+
+    fn nodes(&self) -> &[MaybeUninit<Node<T>>] {
+        &self.nodes
+    }
+
+    unsafe fn parts_mut(&mut self) -> (&mut ListState, &mut [MaybeUninit<Node<T>>]) {
+        (&mut self.state, &mut self.nodes)
+    }
+}
+
+impl<T, const CAPACITY: usize> List<T> for StaticList<T, CAPACITY> {
+    fn capacity(&self) -> usize {
+        CAPACITY
+    }
+}
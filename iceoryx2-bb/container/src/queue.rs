@@ -129,9 +129,12 @@ use iceoryx2_bb_elementary_traits::owning_pointer::{GenericOwningPointer, Owning
 use iceoryx2_bb_elementary_traits::placement_default::PlacementDefault;
 use iceoryx2_bb_elementary_traits::pointer_trait::PointerTrait;
 pub use iceoryx2_bb_elementary_traits::relocatable_container::RelocatableContainer;
+use iceoryx2_bb_elementary_traits::validate::{Validate, ValidationBounds, ValidationError};
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_log::{fail, fatal_panic};
 
+use crate::slice_container::{self, SliceContainer};
+
 /// Queue with run-time fixed size capacity. In contrast to its counterpart the
 /// [`RelocatableQueue`] it is movable but is not shared memory compatible.
 pub type Queue<T> = MetaQueue<T, GenericOwningPointer>;
@@ -272,7 +275,75 @@ impl<T> RelocatableContainer for RelocatableQueue<T> {
     }
 }
 
-unsafe impl<T: ZeroCopySend> ZeroCopySend for RelocatableQueue<T> {}
+unsafe impl<T: ZeroCopySend> ZeroCopySend for RelocatableQueue<T> {
+    const TYPE_LAYOUT_HASH: u64 = T::TYPE_LAYOUT_HASH;
+}
+
+unsafe impl<T: Validate> Validate for RelocatableQueue<T> {
+    unsafe fn validate(ptr: *const Self, bounds: &ValidationBounds) -> Result<(), ValidationError> {
+        unsafe { AtomicBool::validate(core::ptr::addr_of!((*ptr).is_initialized), bounds) }?;
+
+        let this = unsafe { &*ptr };
+        if !this
+            .is_initialized
+            .load(core::sync::atomic::Ordering::Relaxed)
+            || this.capacity < this.len
+            || this.start < this.len
+        {
+            return Err(ValidationError::InvariantViolation);
+        }
+
+        let data = this.data_ptr.validated_ptr(this.capacity, bounds)?;
+        if this
+            .data_bounds()
+            .overlaps(&ValidationBounds::of_value(this))
+        {
+            return Err(ValidationError::InvariantViolation);
+        }
+
+        for i in 0..this.len {
+            let index = (this.start - this.len + i) % this.capacity;
+            unsafe { T::validate(data.add(index).cast::<T>(), bounds) }?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> RelocatableQueue<T> {
+    /// Returns the memory that is reserved for the elements.
+    pub(crate) fn data_bounds(&self) -> ValidationBounds {
+        ValidationBounds::of_elements(unsafe { self.data_ptr.as_ptr() }, self.capacity)
+    }
+}
+
+impl<T: ZeroCopySend> slice_container::internal::SliceContainerView for RelocatableQueue<T> {
+    const CONTAINER_NAME: &'static str = "RelocatableQueue";
+
+    type Element = T;
+
+    unsafe fn has_valid_bit_pattern(ptr: *const Self) -> bool {
+        // a bool is only valid when its byte is either 0 or 1
+        let is_initialized = unsafe { core::ptr::addr_of!((*ptr).is_initialized) }.cast::<u8>();
+        core::mem::size_of::<AtomicBool>() != 1 || unsafe { is_initialized.read() } <= 1
+    }
+
+    fn stored_capacity(&self) -> usize {
+        self.capacity
+    }
+
+    fn data_address(&self) -> Option<usize> {
+        self.is_initialized
+            .load(core::sync::atomic::Ordering::Relaxed)
+            .then(|| unsafe { self.data_ptr.as_ptr() } as usize)
+    }
+
+    fn has_consistent_state(&self) -> bool {
+        self.len <= self.capacity && self.len <= self.start
+    }
+}
+
+impl<T: ZeroCopySend> SliceContainer for RelocatableQueue<T> {}
 
 impl<T> RelocatableQueue<T> {
     /// Returns the required memory size for a queue with a specified capacity
@@ -481,7 +552,25 @@ pub struct FixedSizeQueue<T, const CAPACITY: usize> {
     _data: [MaybeUninit<T>; CAPACITY],
 }
 
-unsafe impl<T: ZeroCopySend, const CAPACITY: usize> ZeroCopySend for FixedSizeQueue<T, CAPACITY> {}
+unsafe impl<T: ZeroCopySend, const CAPACITY: usize> ZeroCopySend for FixedSizeQueue<T, CAPACITY> {
+    const TYPE_LAYOUT_HASH: u64 = T::TYPE_LAYOUT_HASH;
+}
+
+unsafe impl<T: Validate, const CAPACITY: usize> Validate for FixedSizeQueue<T, CAPACITY> {
+    unsafe fn validate(ptr: *const Self, bounds: &ValidationBounds) -> Result<(), ValidationError> {
+        let state = unsafe { core::ptr::addr_of!((*ptr).state) };
+        unsafe { RelocatableQueue::validate(state, bounds) }?;
+
+        let data = unsafe { core::ptr::addr_of!((*ptr)._data) };
+        if unsafe { (*state).capacity } != CAPACITY
+            || unsafe { (*state).data_ptr.as_ptr() } != data.cast()
+        {
+            return Err(ValidationError::InvariantViolation);
+        }
+
+        Ok(())
+    }
+}
 
 impl<T, const CAPACITY: usize> PlacementDefault for FixedSizeQueue<T, CAPACITY> {
     unsafe fn placement_default(ptr: *mut Self) {
@@ -17,8 +17,11 @@ use core::{
     ops::{Deref, DerefMut},
 };
 
+use iceoryx2_bb_derive_macros::Validate;
 use iceoryx2_bb_elementary_traits::{
-    placement_default::PlacementDefault, zero_copy_send::ZeroCopySend,
+    placement_default::PlacementDefault,
+    validate::{Validate, ValidationBounds, ValidationError},
+    zero_copy_send::ZeroCopySend,
 };
 use iceoryx2_log::fatal_panic;
 use serde::{Deserialize, Serialize, de::Visitor};
@@ -72,7 +75,7 @@ use serde::{Deserialize, Serialize, de::Visitor};
 /// }
 /// ```
 #[repr(C, u8)]
-#[derive(Default, Clone, Copy, Hash, Debug, PartialEq, Eq)]
+#[derive(Default, Clone, Copy, Hash, Debug, PartialEq, Eq, Validate)]
 pub enum RelocatableOption<T> {
     /// Default value, defines an [`RelocatableOption`] that does contain nothing.
     #[default]
@@ -147,7 +150,9 @@ impl<'de, T: Deserialize<'de>> Deserialize<'de> for RelocatableOption<T> {
     }
 }
 
-unsafe impl<T: ZeroCopySend> ZeroCopySend for RelocatableOption<T> {}
+unsafe impl<T: ZeroCopySend> ZeroCopySend for RelocatableOption<T> {
+    const TYPE_LAYOUT_HASH: u64 = T::TYPE_LAYOUT_HASH;
+}
 
 impl<T: PlacementDefault> PlacementDefault for RelocatableOption<T> {
     unsafe fn placement_default(ptr: *mut Self) {
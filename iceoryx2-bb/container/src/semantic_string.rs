@@ -630,5 +630,23 @@ macro_rules! semantic_string {
             }
         }
 
+        unsafe impl iceoryx2_bb_elementary_traits::validate::Validate for $string_name {
+            unsafe fn validate(
+                ptr: *const Self,
+                bounds: &iceoryx2_bb_elementary_traits::validate::ValidationBounds,
+            ) -> Result<(), iceoryx2_bb_elementary_traits::validate::ValidationError> {
+                use iceoryx2_bb_container::semantic_string::internal::SemanticStringAccessor;
+                use iceoryx2_bb_container::string::String;
+                use iceoryx2_bb_elementary_traits::validate::Validate;
+
+                let value = unsafe { core::ptr::addr_of!((*ptr).value) };
+                unsafe { iceoryx2_bb_container::string::StaticString::<$capacity>::validate(value, bounds) }?;
+                if Self::is_invalid_content(unsafe { (*value).as_bytes() }) {
+                    return Err(iceoryx2_bb_elementary_traits::validate::ValidationError::InvariantViolation);
+                }
+
+                Ok(())
+            }
+        }
     };
 }
@@ -0,0 +1,298 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Constructs relocatable containers with a runtime capacity inside a byte slice and provides
+//! a validated view onto them.
+//!
+//! The relocatable containers, [`RelocatableVec`](crate::vector::RelocatableVec),
+//! [`RelocatableString`](crate::string::RelocatableString) and
+//! [`RelocatableQueue`](crate::queue::RelocatableQueue), store their data behind a
+//! relative pointer. When the container and its data are placed into the same byte slice, the
+//! slice can be transferred as a whole, for instance as the payload of a
+//! `Publisher<ipc::Service, [u8], ()>` whose size is only known at runtime.
+//!
+//! The slice is structured as follows:
+//!
+//! ```text
+//!  | header: layout id, capacity | container | data of the container |
+//! ```
+//!
+//! The layout id identifies the type of the container and of its elements. Before
+//! [`SliceContainer::view_in_slice()`] hands out a reference to the container, it verifies
+//! that
+//!  * the slice is aligned to [`SliceContainer::required_alignment()`],
+//!  * the layout id matches the expected container type,
+//!  * the slice is large enough for the stored capacity,
+//!  * the data of the container is located directly behind the container inside the slice and
+//!  * the state of the container, like its length, is consistent with its capacity.
+//!
+//! The content of the elements is not verified, therefore the element types must be valid for
+//! every bit pattern the sender can produce.
+//!
+//! **Important:** The container inside the slice is never dropped, the elements that are
+//! contained when the slice is released are leaked.
+//!
+//! # Example
+//!
+//! ```
+//! # extern crate iceoryx2_bb_loggers;
+//!
+//! use core::mem::MaybeUninit;
+//! use iceoryx2_bb_container::slice_container::SliceContainer;
+//! use iceoryx2_bb_container::vector::*;
+//!
+//! #[repr(C, align(8))]
+//! struct Memory([MaybeUninit<u8>; 256]);
+//! let mut memory = Memory([MaybeUninit::uninit(); 256]);
+//!
+//! let capacity = 10;
+//! assert!(RelocatableVec::<u64>::required_slice_len(capacity) <= memory.0.len());
+//!
+//! // sender side, for instance a loaned slice of a publisher
+//! let vec = RelocatableVec::<u64>::create_in_slice(&mut memory.0, capacity).unwrap();
+//! vec.push(123).unwrap();
+//!
+//! // receiver side, for instance the payload of a received sample
+//! let bytes = unsafe { &*(&memory.0 as *const [MaybeUninit<u8>] as *const [u8]) };
+//! let vec = RelocatableVec::<u64>::view_in_slice(bytes).unwrap();
+//! assert_eq!(vec[0], 123);
+//! ```
+
+use core::mem::MaybeUninit;
+
+use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
+use iceoryx2_bb_elementary::math::align;
+pub use iceoryx2_bb_elementary_traits::relocatable_container::RelocatableContainer;
+use iceoryx2_log::fail;
+
+use crate::hashmap::DeterministicHasher;
+
+/// Failures that can occur when a [`SliceContainer`] is created inside or viewed from a
+/// byte slice.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SliceContainerError {
+    /// The slice is not aligned to [`SliceContainer::required_alignment()`].
+    MisalignedSlice,
+    /// The slice is too small to hold the container with the required capacity.
+    InsufficientSize,
+    /// The slice contains a different container type or element type.
+    LayoutMismatch,
+    /// The state of the container inside the slice is inconsistent.
+    CorruptedState,
+    /// The container could not be initialized with the provided capacity.
+    InitializationFailed,
+}
+
+impl core::fmt::Display for SliceContainerError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SliceContainerError::{self:?}")
+    }
+}
+
+impl core::error::Error for SliceContainerError {}
+
+#[repr(C)]
+struct SliceHeader {
+    layout_id: u64,
+    capacity: u64,
+}
+
+pub(crate) mod internal {
+    use super::*;
+
+    #[doc(hidden)]
+    pub trait SliceContainerView: RelocatableContainer + Sized {
+        /// Unique name of the container type, it is part of the layout id
+        const CONTAINER_NAME: &'static str;
+
+        type Element;
+
+        /// Number of elements that must be stored for the given capacity.
+        fn number_of_elements(capacity: usize) -> usize {
+            capacity
+        }
+
+        /// Returns true when the fields of the container can be accessed by reference.
+        ///
+        /// # Safety
+        ///
+        /// * ptr must point to readable memory of the size of Self
+        unsafe fn has_valid_bit_pattern(_ptr: *const Self) -> bool {
+            true
+        }
+
+        /// Returns the capacity stored in the container
+        fn stored_capacity(&self) -> usize;
+
+        /// Returns the address of the data the container points to or [`None`] when the
+        /// container is not initialized.
+        fn data_address(&self) -> Option<usize>;
+
+        /// Returns true when the state of the container, like the length, is consistent
+        /// with the stored capacity.
+        fn has_consistent_state(&self) -> bool;
+    }
+}
+
+fn layout_id<C: internal::SliceContainerView>() -> u64 {
+    DeterministicHasher::hash_of(&(
+        C::CONTAINER_NAME,
+        core::mem::size_of::<C>(),
+        core::mem::align_of::<C>(),
+        core::any::type_name::<C::Element>(),
+        core::mem::size_of::<C::Element>(),
+        core::mem::align_of::<C::Element>(),
+    ))
+}
+
+fn container_offset<C>() -> usize {
+    align(
+        core::mem::size_of::<SliceHeader>(),
+        core::mem::align_of::<C>(),
+    )
+}
+
+fn data_offset<C: internal::SliceContainerView>() -> usize {
+    align(
+        container_offset::<C>() + core::mem::size_of::<C>(),
+        core::mem::align_of::<C::Element>(),
+    )
+}
+
+fn checked_required_slice_len<C: internal::SliceContainerView>(capacity: usize) -> Option<usize> {
+    core::mem::size_of::<C::Element>()
+        .checked_mul(C::number_of_elements(capacity))?
+        .checked_add(data_offset::<C>())
+}
+
+/// A relocatable container that can be constructed inside a byte slice with a runtime
+/// capacity. See the [module documentation](crate::slice_container) for details.
+pub trait SliceContainer: internal::SliceContainerView {
+    /// Returns the alignment the start of the byte slice must have.
+    fn required_alignment() -> usize {
+        core::mem::align_of::<SliceHeader>()
+            .max(core::mem::align_of::<Self>())
+            .max(core::mem::align_of::<Self::Element>())
+    }
+
+    /// Returns the number of bytes the slice requires to hold the container with the
+    /// provided capacity.
+    fn required_slice_len(capacity: usize) -> usize {
+        checked_required_slice_len::<Self>(capacity).unwrap_or(usize::MAX)
+    }
+
+    /// Constructs an empty container with the provided capacity inside the slice and returns
+    /// a mutable reference to it. The slice is zeroed before the container is constructed,
+    /// so that it is fully initialized afterwards.
+    fn create_in_slice(
+        slice: &mut [MaybeUninit<u8>],
+        capacity: usize,
+    ) -> Result<&mut Self, SliceContainerError> {
+        let origin = "SliceContainer::create_in_slice()";
+        if (slice.as_ptr() as usize) % Self::required_alignment() != 0 {
+            fail!(from origin, with SliceContainerError::MisalignedSlice,
+                "Unable to create {} since the slice is not aligned to {}.",
+                core::any::type_name::<Self>(), Self::required_alignment());
+        }
+
+        if slice.len() < Self::required_slice_len(capacity) {
+            fail!(from origin, with SliceContainerError::InsufficientSize,
+                "Unable to create {} with a capacity of {} since the slice has a size of {} but {} bytes are required.",
+                core::any::type_name::<Self>(), capacity, slice.len(),
+                Self::required_slice_len(capacity));
+        }
+
+        let base = slice.as_mut_ptr().cast::<u8>();
+        unsafe {
+            core::ptr::write_bytes(base, 0, slice.len());
+            base.cast::<SliceHeader>().write(SliceHeader {
+                layout_id: layout_id::<Self>(),
+                capacity: capacity as u64,
+            });
+        }
+
+        let container_ptr = unsafe { base.add(container_offset::<Self>()).cast::<Self>() };
+        unsafe { container_ptr.write(Self::new_uninit(capacity)) };
+        let container = unsafe { &mut *container_ptr };
+        let data_offset = data_offset::<Self>();
+
+        // the data offset is aligned and the slice is large enough, so the bump allocator
+        // places the data exactly at the data offset
+        let allocator = BumpAllocator::new(
+            unsafe { core::ptr::NonNull::new_unchecked(base.add(data_offset)) },
+            slice.len() - data_offset,
+        );
+        if let Err(e) = unsafe { container.init(&allocator) } {
+            fail!(from origin, with SliceContainerError::InitializationFailed,
+                "Unable to create {} with a capacity of {} since the initialization failed ({:?}).",
+                core::any::type_name::<Self>(), capacity, e);
+        }
+
+        Ok(container)
+    }
+
+    /// Validates the layout of the container inside the slice and returns a reference to it.
+    fn view_in_slice(slice: &[u8]) -> Result<&Self, SliceContainerError> {
+        let origin = "SliceContainer::view_in_slice()";
+        let base = slice.as_ptr();
+        if (base as usize) % Self::required_alignment() != 0 {
+            fail!(from origin, with SliceContainerError::MisalignedSlice,
+                "Unable to view {} since the slice is not aligned to {}.",
+                core::any::type_name::<Self>(), Self::required_alignment());
+        }
+
+        let data_offset = data_offset::<Self>();
+        if slice.len() < data_offset {
+            fail!(from origin, with SliceContainerError::InsufficientSize,
+                "Unable to view {} since the slice size of {} is smaller than the container.",
+                core::any::type_name::<Self>(), slice.len());
+        }
+
+        let header = unsafe { &*base.cast::<SliceHeader>() };
+        if header.layout_id != layout_id::<Self>() {
+            fail!(from origin, with SliceContainerError::LayoutMismatch,
+                "Unable to view {} since the slice contains a different container or element type.",
+                core::any::type_name::<Self>());
+        }
+
+        let required_len = usize::try_from(header.capacity)
+            .ok()
+            .and_then(checked_required_slice_len::<Self>);
+        let capacity = match required_len {
+            Some(len) if len <= slice.len() => header.capacity as usize,
+            _ => {
+                fail!(from origin, with SliceContainerError::InsufficientSize,
+                    "Unable to view {} since the slice size of {} is too small for the stored capacity of {}.",
+                    core::any::type_name::<Self>(), slice.len(), header.capacity);
+            }
+        };
+
+        let container_ptr = unsafe { base.add(container_offset::<Self>()).cast::<Self>() };
+        if !unsafe { Self::has_valid_bit_pattern(container_ptr) } {
+            fail!(from origin, with SliceContainerError::CorruptedState,
+                "Unable to view {} since the container contains invalid values.",
+                core::any::type_name::<Self>());
+        }
+
+        let container = unsafe { &*container_ptr };
+        if container.stored_capacity() != capacity
+            || container.data_address() != Some(base as usize + data_offset)
+            || !container.has_consistent_state()
+        {
+            fail!(from origin, with SliceContainerError::CorruptedState,
+                "Unable to view {} since the state of the container is inconsistent.",
+                core::any::type_name::<Self>());
+        }
+
+        Ok(container)
+    }
+}
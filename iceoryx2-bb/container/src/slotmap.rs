@@ -47,11 +47,12 @@
 //! ```
 
 use crate::queue::MetaQueue;
+use crate::relocatable_option::RelocatableOption;
 use crate::vec::MetaVec;
 use crate::{queue::RelocatableQueue, vec::RelocatableVec};
 use core::mem::MaybeUninit;
 use iceoryx2_bb_concurrency::atomic::AtomicBool;
-use iceoryx2_bb_derive_macros::ZeroCopySend;
+use iceoryx2_bb_derive_macros::{Validate, ZeroCopySend};
 use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
 use iceoryx2_bb_elementary::relocatable_ptr::GenericRelocatablePointer;
 use iceoryx2_bb_elementary_traits::generic_pointer::GenericPointer;
@@ -60,6 +61,7 @@ use iceoryx2_bb_elementary_traits::owning_pointer::GenericOwningPointer;
 use iceoryx2_bb_elementary_traits::placement_default::PlacementDefault;
 pub use iceoryx2_bb_elementary_traits::relocatable_container::RelocatableContainer;
 use iceoryx2_bb_elementary_traits::testing::abandonable::Abandonable;
+use iceoryx2_bb_elementary_traits::validate::{Validate, ValidationBounds, ValidationError};
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_log::{fail, fatal_panic};
 
@@ -83,7 +85,7 @@ impl SlotMapKey {
 }
 
 #[repr(C)]
-#[derive(Debug, Clone, Copy, ZeroCopySend)]
+#[derive(Debug, Clone, Copy, ZeroCopySend, Validate)]
 pub(crate) struct FreeListEntry {
     previous: usize,
     next: usize,
@@ -129,7 +131,7 @@ impl<'slotmap, T, Ptr: GenericPointer> Iterator for Iter<'slotmap, T, Ptr> {
 pub struct MetaSlotMap<T, Ptr: GenericPointer> {
     idx_to_data: MetaVec<usize, Ptr>,
     idx_to_data_free_list: MetaVec<FreeListEntry, Ptr>,
-    data: MetaVec<Option<T>, Ptr>,
+    data: MetaVec<RelocatableOption<T>, Ptr>,
     data_next_free_index: MetaQueue<usize, Ptr>,
     idx_to_data_free_list_head: usize,
     is_initialized: AtomicBool,
@@ -139,7 +141,7 @@ pub struct MetaSlotMap<T, Ptr: GenericPointer> {
 impl<T: Abandonable, Ptr: GenericPointer> Abandonable for MetaSlotMap<T, Ptr> {
     unsafe fn abandon_in_place(mut this: NonNull<Self>) {
         let this = unsafe { this.as_mut() };
-        for element in this.data.iter_mut().flatten() {
+        for element in this.data.iter_mut().filter_map(|e| e.as_option_mut()) {
             unsafe { T::abandon_in_place(NonNull::iox2_from_mut(element)) };
         }
     }
@@ -180,7 +182,7 @@ impl<T, Ptr: GenericPointer> MetaSlotMap<T, Ptr> {
         for n in 0..capacity {
             unsafe {
                 self.idx_to_data.push_impl(INVALID);
-                self.data.push_impl(None);
+                self.data.push_impl(RelocatableOption::None);
                 self.data_next_free_index.push_impl(n);
             }
             let previous = if n == 0 { INVALID } else { n - 1 };
@@ -297,13 +299,13 @@ impl<T, Ptr: GenericPointer> MetaSlotMap<T, Ptr> {
 
         let data_idx = self.idx_to_data[key.0];
         if data_idx != INVALID {
-            self.data[data_idx] = Some(value);
+            self.data[data_idx] = RelocatableOption::Some(value);
         } else {
             let n = unsafe { self.data_next_free_index.pop_impl() }.expect(
                 "data and idx_to_data correspond and there must be always a free index available.",
             );
             self.idx_to_data[key.0] = n;
-            self.data[n] = Some(value);
+            self.data[n] = RelocatableOption::Some(value);
             self.len += 1;
         }
 
@@ -318,7 +320,7 @@ impl<T, Ptr: GenericPointer> MetaSlotMap<T, Ptr> {
 
         let data_idx = self.idx_to_data[key.0];
         if data_idx != INVALID {
-            let ret = self.data[data_idx].take();
+            let ret = self.data[data_idx].take().to_option();
             let push_result = unsafe { self.data_next_free_index.push_impl(data_idx) };
             debug_assert!(push_result);
             unsafe { self.release_free_index(key.0) };
@@ -488,15 +490,113 @@ impl<T> SlotMap<T> {
     }
 }
 
-unsafe impl<T: ZeroCopySend> ZeroCopySend for RelocatableSlotMap<T> {}
+unsafe impl<T: ZeroCopySend> ZeroCopySend for RelocatableSlotMap<T> {
+    const TYPE_LAYOUT_HASH: u64 = T::TYPE_LAYOUT_HASH;
+}
+
+unsafe impl<T: Validate> Validate for RelocatableSlotMap<T> {
+    unsafe fn validate(ptr: *const Self, bounds: &ValidationBounds) -> Result<(), ValidationError> {
+        unsafe {
+            AtomicBool::validate(core::ptr::addr_of!((*ptr).is_initialized), bounds)?;
+            RelocatableVec::validate(core::ptr::addr_of!((*ptr).idx_to_data), bounds)?;
+            RelocatableVec::validate(core::ptr::addr_of!((*ptr).idx_to_data_free_list), bounds)?;
+            RelocatableVec::validate(core::ptr::addr_of!((*ptr).data), bounds)?;
+            RelocatableQueue::validate(core::ptr::addr_of!((*ptr).data_next_free_index), bounds)?;
+        }
+
+        let this = unsafe { &*ptr };
+        let capacity = this.capacity_impl();
+        let is_valid_index = |index: usize| index == INVALID || index < capacity;
+        let has_capacity_and_len =
+            |vec_capacity: usize, vec_len: usize| vec_capacity == capacity && vec_len == capacity;
+        if !this
+            .is_initialized
+            .load(core::sync::atomic::Ordering::Relaxed)
+            || !has_capacity_and_len(this.idx_to_data.capacity(), this.idx_to_data.len())
+            || !has_capacity_and_len(
+                this.idx_to_data_free_list.capacity(),
+                this.idx_to_data_free_list.len(),
+            )
+            || !has_capacity_and_len(this.data.capacity(), this.data.len())
+            || this.data_next_free_index.capacity() != capacity
+            || this.data_next_free_index.len() + this.len != capacity
+            || !is_valid_index(this.idx_to_data_free_list_head)
+        {
+            return Err(ValidationError::InvariantViolation);
+        }
+
+        // the containers must not share memory, otherwise modifying one corrupts another
+        let regions = [
+            ValidationBounds::of_value(this),
+            this.idx_to_data.data_bounds(),
+            this.idx_to_data_free_list.data_bounds(),
+            this.data.data_bounds(),
+            this.data_next_free_index.data_bounds(),
+        ];
+        for (n, region) in regions.iter().enumerate() {
+            if regions[n + 1..].iter().any(|other| region.overlaps(other)) {
+                return Err(ValidationError::InvariantViolation);
+            }
+        }
+
+        let mut number_of_used_indices = 0;
+        for data_idx in this.idx_to_data.iter() {
+            if *data_idx != INVALID {
+                if capacity <= *data_idx || this.data[*data_idx].is_none() {
+                    return Err(ValidationError::InvariantViolation);
+                }
+                number_of_used_indices += 1;
+            }
+        }
+
+        let number_of_values = this.data.iter().filter(|value| value.is_some()).count();
+        let has_valid_free_list = this
+            .idx_to_data_free_list
+            .iter()
+            .all(|entry| is_valid_index(entry.previous) && is_valid_index(entry.next));
+        let has_valid_free_data_indices = (0..this.data_next_free_index.len())
+            .all(|n| unsafe { this.data_next_free_index.get_unchecked(n) } < capacity);
+        if number_of_used_indices != this.len
+            || number_of_values != this.len
+            || !has_valid_free_list
+            || !has_valid_free_data_indices
+        {
+            return Err(ValidationError::InvariantViolation);
+        }
+
+        Ok(())
+    }
+}
 
 impl<T> RelocatableSlotMap<T> {
+    /// Returns true when the data of the [`RelocatableSlotMap`] is stored exactly in the
+    /// provided arrays, like it is the case for the [`FixedSizeSlotMap`].
+    pub(crate) fn is_stored_in<const CAPACITY: usize>(
+        &self,
+        idx_to_data: *const MaybeUninit<[usize; CAPACITY]>,
+        idx_to_data_free_list: *const MaybeUninit<[FreeListEntry; CAPACITY]>,
+        data: *const MaybeUninit<[RelocatableOption<T>; CAPACITY]>,
+        data_next_free_index: *const MaybeUninit<[usize; CAPACITY]>,
+    ) -> bool {
+        self.idx_to_data.data_bounds()
+            == ValidationBounds::of_elements(idx_to_data.cast::<usize>(), CAPACITY)
+            && self.idx_to_data_free_list.data_bounds()
+                == ValidationBounds::of_elements(
+                    idx_to_data_free_list.cast::<FreeListEntry>(),
+                    CAPACITY,
+                )
+            && self.data.data_bounds()
+                == ValidationBounds::of_elements(data.cast::<RelocatableOption<T>>(), CAPACITY)
+            && self.data_next_free_index.data_bounds()
+                == ValidationBounds::of_elements(data_next_free_index.cast::<usize>(), CAPACITY)
+    }
+
     /// Returns how many memory the [`RelocatableSlotMap`] will allocate from the allocator
     /// in [`RelocatableSlotMap::init()`].
     pub const fn const_memory_size(capacity: usize) -> usize {
         RelocatableVec::<usize>::const_memory_size(capacity)
             + RelocatableVec::<FreeListEntry>::const_memory_size(capacity)
-            + RelocatableVec::<Option<T>>::const_memory_size(capacity)
+            + RelocatableVec::<RelocatableOption<T>>::const_memory_size(capacity)
             + RelocatableQueue::<usize>::const_memory_size(capacity)
     }
 
@@ -615,11 +715,34 @@ pub struct FixedSizeSlotMap<T, const CAPACITY: usize> {
     state: RelocatableSlotMap<T>,
     _idx_to_data: MaybeUninit<[usize; CAPACITY]>,
     _idx_to_data_free_list: MaybeUninit<[FreeListEntry; CAPACITY]>,
-    _data: MaybeUninit<[Option<T>; CAPACITY]>,
+    _data: MaybeUninit<[RelocatableOption<T>; CAPACITY]>,
     _data_next_free_index: MaybeUninit<[usize; CAPACITY]>,
 }
 
-unsafe impl<T: ZeroCopySend, const CAPACITY: usize> ZeroCopySend for FixedSizeSlotMap<T, CAPACITY> {}
+unsafe impl<T: ZeroCopySend, const CAPACITY: usize> ZeroCopySend for FixedSizeSlotMap<T, CAPACITY> {
+    const TYPE_LAYOUT_HASH: u64 = T::TYPE_LAYOUT_HASH;
+}
+
+unsafe impl<T: Validate, const CAPACITY: usize> Validate for FixedSizeSlotMap<T, CAPACITY> {
+    unsafe fn validate(ptr: *const Self, bounds: &ValidationBounds) -> Result<(), ValidationError> {
+        let state = unsafe { core::ptr::addr_of!((*ptr).state) };
+        unsafe { RelocatableSlotMap::validate(state, bounds) }?;
+
+        let is_located_in_self = unsafe {
+            (*state).is_stored_in(
+                core::ptr::addr_of!((*ptr)._idx_to_data),
+                core::ptr::addr_of!((*ptr)._idx_to_data_free_list),
+                core::ptr::addr_of!((*ptr)._data),
+                core::ptr::addr_of!((*ptr)._data_next_free_index),
+            )
+        };
+        if unsafe { (*state).capacity_impl() } != CAPACITY || !is_located_in_self {
+            return Err(ValidationError::InvariantViolation);
+        }
+
+        Ok(())
+    }
+}
 
 impl<T, const CAPACITY: usize> PlacementDefault for FixedSizeSlotMap<T, CAPACITY> {
     unsafe fn placement_default(ptr: *mut Self) {
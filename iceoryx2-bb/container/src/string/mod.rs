@@ -17,6 +17,7 @@ use core::{
     hash::Hash,
     ops::{Deref, DerefMut},
 };
+use iceoryx2_bb_elementary_traits::validate::ValidationError;
 use iceoryx2_log::{fail, fatal_panic};
 
 /// Runtime fixed-capacity string where the user can provide a stateful allocator.
@@ -76,6 +77,23 @@ pub(crate) mod internal {
     }
 }
 
+/// Validates that the first `len` bytes of a string contain only supported unicode code
+/// points.
+///
+/// # Safety
+///
+/// * `data` must point to `len` readable and initialized bytes
+pub(crate) unsafe fn validate_content(data: *const u8, len: usize) -> Result<(), ValidationError> {
+    for n in 0..len {
+        let byte = unsafe { data.add(n).read() };
+        if 128 <= byte || 0 == byte {
+            return Err(ValidationError::InvalidBitPattern);
+        }
+    }
+
+    Ok(())
+}
+
 /// A UTF-8 string trait.
 /// The string class uses Unicode (ISO/IEC 10646) terminology throughout its interface. In particular:
 ///   - A code point is the numerical index assigned to a character in the Unicode standard.
@@ -252,7 +270,9 @@ pub trait String:
 
             let new_len = self.len() + bytes.len();
             self.set_len(new_len as u64);
-            if new_len < self.capacity() {
+            // the terminator of a full string is either part of the data or, in case of the
+            // StaticString, a separate member that is always zero
+            if new_len < self.data().len() {
                 self.data_mut()[new_len].write(0);
             }
         }
@@ -209,8 +209,12 @@ impl<'a, Allocator: BaseAllocator> PolymorphicString<'a, Allocator> {
             }
         };
 
+        let data_ptr: *mut MaybeUninit<u8> = unsafe { data_ptr.as_mut() }.as_mut_ptr().cast();
+        // the memory is not initialized, the empty string requires the terminator
+        unsafe { data_ptr.write(MaybeUninit::new(0)) };
+
         Ok(Self {
-            data_ptr: unsafe { data_ptr.as_mut() }.as_mut_ptr().cast(),
+            data_ptr,
             len: 0,
             capacity: capacity as _,
             allocator,
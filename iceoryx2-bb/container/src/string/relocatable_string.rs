@@ -85,9 +85,11 @@ use iceoryx2_bb_elementary::math::unaligned_mem_size;
 use iceoryx2_bb_elementary::relocatable_ptr::RelocatablePointer;
 use iceoryx2_bb_elementary_traits::pointer_trait::PointerTrait;
 pub use iceoryx2_bb_elementary_traits::relocatable_container::RelocatableContainer;
+use iceoryx2_bb_elementary_traits::validate::{Validate, ValidationBounds, ValidationError};
 use iceoryx2_log::{fail, fatal_panic};
 
-use crate::string::{String, as_escaped_string, internal};
+use crate::slice_container::{self, SliceContainer};
+use crate::string::{String, as_escaped_string, internal, validate_content};
 
 /// **Non-movable** relocatable shared-memory compatible string with runtime fixed size capacity.
 #[repr(C)]
@@ -127,6 +129,35 @@ impl Debug for RelocatableString {
 
 unsafe impl Send for RelocatableString {}
 
+unsafe impl Validate for RelocatableString {
+    unsafe fn validate(ptr: *const Self, bounds: &ValidationBounds) -> Result<(), ValidationError> {
+        // every bit pattern of the fields is valid
+        let this = unsafe { &*ptr };
+        if this.capacity < this.len {
+            return Err(ValidationError::InvariantViolation);
+        }
+
+        // the string is always null-terminated
+        let number_of_bytes = usize::try_from(this.capacity)
+            .ok()
+            .and_then(|capacity| capacity.checked_add(1))
+            .ok_or(ValidationError::InvariantViolation)?;
+        let data = this.data_ptr.validated_ptr(number_of_bytes, bounds)?;
+        if ValidationBounds::of_elements(data, number_of_bytes)
+            .overlaps(&ValidationBounds::of_value(this))
+        {
+            return Err(ValidationError::InvariantViolation);
+        }
+
+        let data = data.cast::<u8>();
+        unsafe { validate_content(data, this.len as usize) }?;
+        match unsafe { data.add(this.len as usize).read() } {
+            0 => Ok(()),
+            _ => Err(ValidationError::InvariantViolation),
+        }
+    }
+}
+
 impl PartialOrd<RelocatableString> for RelocatableString {
     fn partial_cmp(&self, other: &RelocatableString) -> Option<Ordering> {
         Some(self.cmp(other))
@@ -253,6 +284,8 @@ impl RelocatableContainer for RelocatableString {
 
         unsafe {
             self.data_ptr.init(ptr);
+            // the memory is not initialized, the empty string requires the terminator
+            self.data_ptr.as_mut_ptr().write(MaybeUninit::new(0));
         }
         Ok(())
     }
@@ -271,3 +304,33 @@ impl String for RelocatableString {
         self.len as usize
     }
 }
+
+impl slice_container::internal::SliceContainerView for RelocatableString {
+    const CONTAINER_NAME: &'static str = "RelocatableString";
+
+    type Element = u8;
+
+    fn number_of_elements(capacity: usize) -> usize {
+        // the string is always null-terminated
+        capacity + 1
+    }
+
+    fn stored_capacity(&self) -> usize {
+        self.capacity as usize
+    }
+
+    fn data_address(&self) -> Option<usize> {
+        self.data_ptr
+            .is_initialized()
+            .then(|| unsafe { self.data_ptr.as_ptr() } as usize)
+    }
+
+    fn has_consistent_state(&self) -> bool {
+        // the content is accessed via `as_str()` which requires valid UTF-8
+        self.len <= self.capacity
+            && unsafe { validate_content(self.as_bytes().as_ptr(), self.len as usize) }.is_ok()
+            && self.as_bytes_with_nul()[self.len as usize] == 0
+    }
+}
+
+impl SliceContainer for RelocatableString {}
@@ -47,12 +47,14 @@ use iceoryx2_bb_elementary_traits::atomic_copy::AtomicCopy;
 use iceoryx2_bb_derive_macros::{PlacementDefault, ZeroCopySend};
 use iceoryx2_bb_elementary::math::align_to;
 use iceoryx2_bb_elementary_traits::placement_default::PlacementDefault;
+use iceoryx2_bb_elementary_traits::validate::{Validate, ValidationBounds, ValidationError};
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_log::fail;
 use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Visitor};
 
 use crate::string::{
     String, StringModificationError, as_escaped_string, internal::StringView, strnlen,
+    validate_content,
 };
 
 /// Variant of the [`String`] that has a compile-time fixed capacity and is
@@ -65,6 +67,30 @@ pub struct StaticString<const CAPACITY: usize> {
     len: u64,
 }
 
+unsafe impl<const CAPACITY: usize> Validate for StaticString<CAPACITY> {
+    unsafe fn validate(
+        ptr: *const Self,
+        _bounds: &ValidationBounds,
+    ) -> Result<(), ValidationError> {
+        let len = unsafe { core::ptr::addr_of!((*ptr).len).read() };
+        let terminator = unsafe { core::ptr::addr_of!((*ptr).terminator).read() };
+        if len > CAPACITY as u64 || terminator != 0 {
+            return Err(ValidationError::InvariantViolation);
+        }
+
+        let len = len as usize;
+        let data = unsafe { core::ptr::addr_of!((*ptr).data) }.cast::<u8>();
+        unsafe { validate_content(data, len) }?;
+
+        // when the string is full, the terminator field is the null terminator
+        if len < CAPACITY && unsafe { data.add(len).read() } != 0 {
+            return Err(ValidationError::InvariantViolation);
+        }
+
+        Ok(())
+    }
+}
+
 unsafe impl<const CAPACITY: usize> AtomicCopy for StaticString<CAPACITY> {
     fn __for_each_field<F: FnMut(usize, usize)>(&self, base_offset: usize, callback: &mut F) {
         let aligned_base_offset = align_to::<Self>(base_offset);
@@ -20,9 +20,13 @@ use core::{
 
 use iceoryx2_bb_elementary::relocatable_ptr::GenericRelocatablePointer;
 use iceoryx2_bb_elementary_traits::{
-    generic_pointer::GenericPointer, owning_pointer::GenericOwningPointer,
-    owning_pointer::OwningPointer, pointer_trait::PointerTrait,
-    relocatable_container::RelocatableContainer, zero_copy_send::ZeroCopySend,
+    generic_pointer::GenericPointer,
+    owning_pointer::GenericOwningPointer,
+    owning_pointer::OwningPointer,
+    pointer_trait::PointerTrait,
+    relocatable_container::RelocatableContainer,
+    validate::{Validate, ValidationBounds, ValidationError},
+    zero_copy_send::ZeroCopySend,
 };
 
 use iceoryx2_bb_elementary::{math::unaligned_mem_size, relocatable_ptr::RelocatablePointer};
@@ -227,10 +231,42 @@ impl<T> Vec<T> {
     }
 }
 
-unsafe impl<T: ZeroCopySend> ZeroCopySend for RelocatableVec<T> {}
+unsafe impl<T: ZeroCopySend> ZeroCopySend for RelocatableVec<T> {
+    const TYPE_LAYOUT_HASH: u64 = T::TYPE_LAYOUT_HASH;
+}
+
+unsafe impl<T: Validate> Validate for RelocatableVec<T> {
+    unsafe fn validate(ptr: *const Self, bounds: &ValidationBounds) -> Result<(), ValidationError> {
+        // every bit pattern of the fields is valid
+        let this = unsafe { &*ptr };
+        if this.capacity < this.len {
+            return Err(ValidationError::InvariantViolation);
+        }
+
+        let data = this.data_ptr.validated_ptr(this.capacity, bounds)?;
+        if this
+            .data_bounds()
+            .overlaps(&ValidationBounds::of_value(this))
+        {
+            return Err(ValidationError::InvariantViolation);
+        }
+
+        unsafe {
+            <[T]>::validate(
+                core::ptr::slice_from_raw_parts(data.cast::<T>(), this.len),
+                bounds,
+            )
+        }
+    }
+}
 
 impl<T> RelocatableVec<T> {
     pub const fn const_memory_size(capacity: usize) -> usize {
         unaligned_mem_size::<T>(capacity)
     }
+
+    /// Returns the memory that is reserved for the elements.
+    pub(crate) fn data_bounds(&self) -> ValidationBounds {
+        ValidationBounds::of_elements(unsafe { self.data_ptr.as_ptr() }, self.capacity)
+    }
 }
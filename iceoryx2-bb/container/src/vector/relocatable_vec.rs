@@ -80,9 +80,11 @@ use core::{fmt::Debug, mem::MaybeUninit};
 
 use iceoryx2_bb_elementary::{math::unaligned_mem_size, relocatable_ptr::*};
 pub use iceoryx2_bb_elementary_traits::relocatable_container::RelocatableContainer;
+use iceoryx2_bb_elementary_traits::validate::{Validate, ValidationBounds, ValidationError};
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_log::{fail, fatal_panic};
 
+use crate::slice_container::{self, SliceContainer};
 pub use crate::vector::Vector;
 use crate::vector::internal;
 
@@ -104,7 +106,34 @@ impl<T> Drop for RelocatableVec<T> {
 
 unsafe impl<T: Send> Send for RelocatableVec<T> {}
 
-unsafe impl<T: ZeroCopySend> ZeroCopySend for RelocatableVec<T> {}
+unsafe impl<T: ZeroCopySend> ZeroCopySend for RelocatableVec<T> {
+    const TYPE_LAYOUT_HASH: u64 = T::TYPE_LAYOUT_HASH;
+}
+
+unsafe impl<T: Validate> Validate for RelocatableVec<T> {
+    unsafe fn validate(ptr: *const Self, bounds: &ValidationBounds) -> Result<(), ValidationError> {
+        // every bit pattern of the fields is valid
+        let this = unsafe { &*ptr };
+        if this.capacity < this.len {
+            return Err(ValidationError::InvariantViolation);
+        }
+
+        let capacity =
+            usize::try_from(this.capacity).map_err(|_| ValidationError::InvariantViolation)?;
+        let data = this.data_ptr.validated_ptr(capacity, bounds)?;
+        if ValidationBounds::of_elements(data, capacity).overlaps(&ValidationBounds::of_value(this))
+        {
+            return Err(ValidationError::InvariantViolation);
+        }
+
+        unsafe {
+            <[T]>::validate(
+                core::ptr::slice_from_raw_parts(data.cast::<T>(), this.len as usize),
+                bounds,
+            )
+        }
+    }
+}
 
 impl<T: Debug> Debug for RelocatableVec<T> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
@@ -247,3 +276,25 @@ impl<T> Vector<T> for RelocatableVec<T> {
         self.len as usize
     }
 }
+
+impl<T: ZeroCopySend> slice_container::internal::SliceContainerView for RelocatableVec<T> {
+    const CONTAINER_NAME: &'static str = "RelocatableVec";
+
+    type Element = T;
+
+    fn stored_capacity(&self) -> usize {
+        self.capacity as usize
+    }
+
+    fn data_address(&self) -> Option<usize> {
+        self.data_ptr
+            .is_initialized()
+            .then(|| unsafe { self.data_ptr.as_ptr() } as usize)
+    }
+
+    fn has_consistent_state(&self) -> bool {
+        self.len <= self.capacity
+    }
+}
+
+impl<T: ZeroCopySend> SliceContainer for RelocatableVec<T> {}
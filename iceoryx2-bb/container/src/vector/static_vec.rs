@@ -36,7 +36,9 @@ use core::{
 };
 
 use iceoryx2_bb_elementary_traits::{
-    placement_default::PlacementDefault, zero_copy_send::ZeroCopySend,
+    placement_default::PlacementDefault,
+    validate::{Validate, ValidationBounds, ValidationError},
+    zero_copy_send::ZeroCopySend,
 };
 use iceoryx2_log::fail;
 use serde::{Deserialize, Serialize, de::Visitor};
@@ -78,7 +80,21 @@ impl<T: Debug, const CAPACITY: usize> Debug for StaticVec<T, CAPACITY> {
     }
 }
 
-unsafe impl<T: ZeroCopySend, const CAPACITY: usize> ZeroCopySend for StaticVec<T, CAPACITY> {}
+unsafe impl<T: ZeroCopySend, const CAPACITY: usize> ZeroCopySend for StaticVec<T, CAPACITY> {
+    const TYPE_LAYOUT_HASH: u64 = T::TYPE_LAYOUT_HASH;
+}
+
+unsafe impl<T: Validate, const CAPACITY: usize> Validate for StaticVec<T, CAPACITY> {
+    unsafe fn validate(ptr: *const Self, bounds: &ValidationBounds) -> Result<(), ValidationError> {
+        let len = unsafe { core::ptr::addr_of!((*ptr).len).read() };
+        if len > CAPACITY as u64 {
+            return Err(ValidationError::InvariantViolation);
+        }
+
+        let data = unsafe { core::ptr::addr_of!((*ptr).data) }.cast::<T>();
+        unsafe { <[T]>::validate(core::ptr::slice_from_raw_parts(data, len as usize), bounds) }
+    }
+}
 
 impl<T, const CAPACITY: usize> Drop for StaticVec<T, CAPACITY> {
     fn drop(&mut self) {
@@ -0,0 +1,405 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use alloc::collections;
+use alloc::vec::Vec;
+
+use iceoryx2_bb_container::btree::*;
+use iceoryx2_bb_derive_macros::ZeroCopySend;
+use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
+use iceoryx2_bb_elementary_traits::placement_default::PlacementDefault;
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing::lifetime_tracker::LifetimeTracker;
+use iceoryx2_bb_testing::memory::RawMemory;
+use iceoryx2_bb_testing_macros::requires_std;
+use iceoryx2_bb_testing_macros::test;
+
+const CAPACITY: usize = 100;
+
+/// Deterministic pseudo random sequence of keys, so that the tree is not filled in order
+fn shuffled_keys(number_of_keys: u32) -> Vec<u32> {
+    let mut keys: Vec<u32> = (0..number_of_keys).collect();
+    let mut state: u64 = 0x2545f4914f6cdd1d;
+    for i in (1..keys.len()).rev() {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        keys.swap(i, (state >> 33) as usize % (i + 1));
+    }
+    keys
+}
+
+fn keys_of<M: BTreeMap<u32, u32>>(map: &M) -> Vec<u32> {
+    map.iter().map(|(key, _)| *key).collect()
+}
+
+#[test]
+pub fn new_creates_empty_map() {
+    let sut = StaticBTreeMap::<u32, u32, CAPACITY>::new();
+    assert_that!(sut.is_empty(), eq true);
+    assert_that!(sut.is_full(), eq false);
+    assert_that!(sut.len(), eq 0);
+    assert_that!(sut.capacity(), eq CAPACITY);
+    assert_that!(sut.iter().next(), is_none);
+    assert_that!(sut.first_key_value(), is_none);
+    assert_that!(sut.last_key_value(), is_none);
+}
+
+#[test]
+pub fn placement_default_works() {
+    type Sut = StaticBTreeMap<u32, u32, CAPACITY>;
+    let mut sut = RawMemory::<Sut>::new_zeroed();
+    unsafe { Sut::placement_default(sut.as_mut_ptr()) };
+
+    let sut = unsafe { sut.assume_init_mut() };
+    assert_that!(sut.is_empty(), eq true);
+    assert_that!(sut.insert(4, 6), is_ok);
+    assert_that!(sut.get(&4), eq Some(&6));
+}
+
+#[test]
+pub fn insert_in_random_order_iterates_in_ascending_order() {
+    let mut sut = StaticBTreeMap::<u32, u32, CAPACITY>::new();
+    for key in shuffled_keys(CAPACITY as u32) {
+        assert_that!(sut.insert(key, key * 2), is_ok);
+    }
+
+    assert_that!(sut.is_full(), eq true);
+    assert_that!(keys_of(&sut), eq(0..CAPACITY as u32).collect::<Vec<_>>());
+    for key in 0..CAPACITY as u32 {
+        assert_that!(sut.get(&key), eq Some(&(key * 2)));
+    }
+    assert_that!(sut.first_key_value(), eq Some((&0, &0)));
+    assert_that!(sut.last_key_value(), eq Some((&(CAPACITY as u32 - 1), &((CAPACITY as u32 - 1) * 2))));
+}
+
+#[test]
+pub fn insert_the_same_key_fails() {
+    let mut sut = StaticBTreeMap::<i16, i16, CAPACITY>::new();
+    assert_that!(sut.insert(-2023, -9), is_ok);
+
+    let result = sut.insert(-2023, 19);
+    assert_that!(result, is_err);
+    assert_that!(result.unwrap_err(), eq BTreeModificationError::KeyAlreadyExists);
+    assert_that!(sut.len(), eq 1);
+    assert_that!(sut.get(&-2023), eq Some(&-9));
+}
+
+#[test]
+pub fn insert_into_full_map_fails() {
+    let mut sut = StaticBTreeMap::<u32, u32, CAPACITY>::new();
+    for key in 0..CAPACITY as u32 {
+        assert_that!(sut.insert(key, key), is_ok);
+    }
+
+    let result = sut.insert(CAPACITY as u32, 0);
+    assert_that!(result, is_err);
+    assert_that!(result.unwrap_err(), eq BTreeModificationError::IsFull);
+    assert_that!(sut.contains_key(&(CAPACITY as u32)), eq false);
+    assert_that!(sut.len(), eq CAPACITY);
+}
+
+#[test]
+pub fn map_with_zero_capacity_is_full() {
+    let mut sut = StaticBTreeMap::<u32, u32, 0>::new();
+    assert_that!(sut.is_full(), eq true);
+    assert_that!(sut.insert(1, 1).unwrap_err(), eq BTreeModificationError::IsFull);
+    assert_that!(sut.remove(&1), is_none);
+}
+
+#[test]
+pub fn get_mut_modifies_the_value() {
+    let mut sut = StaticBTreeMap::<u8, u8, CAPACITY>::new();
+    assert_that!(sut.insert(34, 40), is_ok);
+
+    *sut.get_mut(&34).unwrap() = 41;
+    assert_that!(sut.get(&34), eq Some(&41));
+    assert_that!(sut.get_mut(&35), is_none);
+}
+
+#[test]
+pub fn remove_behaves_like_the_std_btree_map() {
+    let mut sut = StaticBTreeMap::<u32, u32, CAPACITY>::new();
+    let mut reference = collections::BTreeMap::new();
+    let keys = shuffled_keys(CAPACITY as u32);
+
+    for round in 0..3 {
+        for key in &keys {
+            if !reference.contains_key(key) {
+                assert_that!(sut.insert(*key, key + round), is_ok);
+                reference.insert(*key, key + round);
+            }
+        }
+
+        for key in keys.iter().skip(round as usize).step_by(2) {
+            assert_that!(sut.remove(key), eq reference.remove_entry(key));
+            assert_that!(sut.remove(key), is_none);
+        }
+
+        assert_that!(sut.len(), eq reference.len());
+        assert_that!(
+            sut.iter().map(|(k, v)| (*k, *v)).collect::<Vec<_>>(),
+            eq reference.iter().map(|(k, v)| (*k, *v)).collect::<Vec<_>>()
+        );
+    }
+}
+
+#[test]
+pub fn remove_until_empty_and_reinsert_works() {
+    let mut sut = StaticBTreeMap::<u32, u32, CAPACITY>::new();
+    for _ in 0..3 {
+        for key in shuffled_keys(CAPACITY as u32) {
+            assert_that!(sut.insert(key, key), is_ok);
+        }
+        assert_that!(sut.is_full(), eq true);
+
+        for key in (0..CAPACITY as u32).rev() {
+            assert_that!(sut.remove(&key), eq Some((key, key)));
+        }
+        assert_that!(sut.is_empty(), eq true);
+    }
+}
+
+#[test]
+pub fn range_returns_entries_within_the_bounds() {
+    let mut sut = StaticBTreeMap::<u32, u32, CAPACITY>::new();
+    for key in shuffled_keys(20) {
+        assert_that!(sut.insert(key * 10, key), is_ok);
+    }
+
+    let keys_in = |range: (core::ops::Bound<u32>, core::ops::Bound<u32>)| {
+        sut.range(range).map(|(key, _)| *key).collect::<Vec<_>>()
+    };
+    use core::ops::Bound::*;
+
+    assert_that!(keys_in((Included(30), Excluded(60))), eq alloc::vec![30, 40, 50]);
+    assert_that!(keys_in((Excluded(30), Included(60))), eq alloc::vec![40, 50, 60]);
+    assert_that!(keys_in((Included(35), Included(55))), eq alloc::vec![40, 50]);
+    assert_that!(keys_in((Included(175), Unbounded)), eq alloc::vec![180, 190]);
+    assert_that!(keys_in((Unbounded, Excluded(20))), eq alloc::vec![0, 10]);
+    assert_that!(keys_in((Included(41), Excluded(50))), len 0);
+    assert_that!(keys_in((Included(60), Excluded(30))), len 0);
+    assert_that!(keys_in((Included(500), Unbounded)), len 0);
+    assert_that!(sut.range(..).count(), eq 20);
+}
+
+#[test]
+pub fn drop_called_for_keys_and_values() {
+    let state = LifetimeTracker::start_tracking();
+    let mut sut = StaticBTreeMap::<u32, LifetimeTracker, CAPACITY>::new();
+    for key in shuffled_keys(CAPACITY as u32) {
+        assert_that!(sut.insert(key, LifetimeTracker::new()), is_ok);
+    }
+    assert_that!(state.number_of_living_instances(), eq CAPACITY);
+
+    drop(sut.remove(&7));
+    assert_that!(state.number_of_living_instances(), eq CAPACITY - 1);
+
+    drop(sut);
+    assert_that!(state.number_of_living_instances(), eq 0);
+}
+
+#[test]
+pub fn clear_removes_all_entries() {
+    let state = LifetimeTracker::start_tracking();
+    let mut sut = StaticBTreeMap::<u32, LifetimeTracker, CAPACITY>::new();
+    for key in shuffled_keys(CAPACITY as u32) {
+        assert_that!(sut.insert(key, LifetimeTracker::new()), is_ok);
+    }
+
+    sut.clear();
+    assert_that!(sut.is_empty(), eq true);
+    assert_that!(state.number_of_living_instances(), eq 0);
+    assert_that!(sut.contains_key(&0), eq false);
+    assert_that!(sut.insert(0, LifetimeTracker::new()), is_ok);
+}
+
+#[test]
+pub fn clone_creates_equal_map() {
+    let mut sut = StaticBTreeMap::<u32, u32, CAPACITY>::new();
+    for key in shuffled_keys(CAPACITY as u32 / 2) {
+        assert_that!(sut.insert(key, key + 1), is_ok);
+    }
+
+    let mut sut_clone = sut.clone();
+    assert_that!(sut_clone, eq sut);
+
+    assert_that!(sut_clone.remove(&3), is_some);
+    assert_that!(sut_clone, ne sut);
+}
+
+#[test]
+pub fn static_map_can_be_moved() {
+    let mut sut = StaticBTreeMap::<u32, u32, CAPACITY>::new();
+    for key in shuffled_keys(CAPACITY as u32) {
+        assert_that!(sut.insert(key, key + 1), is_ok);
+    }
+
+    let mut moved = alloc::vec![sut];
+    let sut = moved.pop().unwrap();
+    assert_that!(keys_of(&sut), eq(0..CAPACITY as u32).collect::<Vec<_>>());
+}
+
+#[test]
+pub fn static_map_is_zero_copy_send_inside_a_derived_type() {
+    #[repr(C)]
+    #[derive(ZeroCopySend)]
+    struct Payload {
+        id: u64,
+        map: StaticBTreeMap<u32, u64, CAPACITY>,
+    }
+
+    fn is_zero_copy_send<T: ZeroCopySend>(_: &T) -> bool {
+        true
+    }
+
+    let payload = Payload {
+        id: 1,
+        map: StaticBTreeMap::new(),
+    };
+    assert_that!(is_zero_copy_send(&payload), eq true);
+    assert_that!(payload.id, eq 1);
+    assert_that!(payload.map.is_empty(), eq true);
+}
+
+#[test]
+pub fn relocatable_map_works_with_bump_allocator() {
+    const MEM_SIZE: usize = RelocatableBTreeMap::<u32, u32>::const_memory_size(CAPACITY);
+    let mut memory = [0u8; MEM_SIZE];
+    let bump_allocator = BumpAllocator::new(
+        core::ptr::NonNull::<u8>::new(memory.as_mut_ptr().cast())
+            .expect("Precondition failed: Pointer to memory is null"),
+        core::mem::size_of_val(&memory),
+    );
+
+    let mut sut = unsafe { RelocatableBTreeMap::<u32, u32>::new_uninit(CAPACITY) };
+    unsafe { sut.init(&bump_allocator).expect("sut init failed") };
+
+    for key in shuffled_keys(CAPACITY as u32) {
+        assert_that!(sut.insert(key, key), is_ok);
+    }
+    assert_that!(sut.is_full(), eq true);
+    assert_that!(sut.remove(&5), eq Some((5, 5)));
+    assert_that!(sut.get(&6), eq Some(&6));
+    assert_that!(sut.range(3..8).count(), eq 4);
+}
+
+#[test]
+#[should_panic]
+pub fn double_init_call_causes_panic() {
+    const MEM_SIZE: usize = RelocatableBTreeMap::<u8, u8>::const_memory_size(CAPACITY);
+    let mut memory = [0u8; MEM_SIZE];
+    let bump_allocator = BumpAllocator::new(
+        core::ptr::NonNull::<u8>::new(memory.as_mut_ptr().cast())
+            .expect("Precondition failed: Pointer to memory is null"),
+        core::mem::size_of_val(&memory),
+    );
+
+    let mut sut = unsafe { RelocatableBTreeMap::<u8, u8>::new_uninit(CAPACITY) };
+    unsafe { sut.init(&bump_allocator).expect("sut init failed") };
+
+    unsafe { sut.init(&bump_allocator).expect("sut init failed") };
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic]
+pub fn panic_is_called_in_debug_mode_if_map_is_not_initialized() {
+    let mut sut = unsafe { RelocatableBTreeMap::<u8, u8>::new_uninit(CAPACITY) };
+    assert_that!(sut.insert(1, 1), is_ok);
+}
+
+#[test]
+pub fn set_insert_contains_and_remove_work() {
+    let mut sut = StaticBTreeSet::<u32, CAPACITY>::new();
+    for key in shuffled_keys(CAPACITY as u32) {
+        assert_that!(sut.insert(key), is_ok);
+    }
+    assert_that!(sut.is_full(), eq true);
+    assert_that!(sut.insert(0).unwrap_err(), eq BTreeModificationError::KeyAlreadyExists);
+
+    assert_that!(sut.contains(&12), eq true);
+    assert_that!(sut.remove(&12), eq Some(12));
+    assert_that!(sut.contains(&12), eq false);
+    assert_that!(sut.remove(&12), is_none);
+
+    assert_that!(sut.first(), eq Some(&0));
+    assert_that!(sut.last(), eq Some(&(CAPACITY as u32 - 1)));
+    assert_that!(sut.range(10..15).copied().collect::<Vec<_>>(), eq alloc::vec![10, 11, 13, 14]);
+    assert_that!(sut.iter().count(), eq CAPACITY - 1);
+}
+
+#[test]
+pub fn relocatable_set_works_with_bump_allocator() {
+    const MEM_SIZE: usize = RelocatableBTreeSet::<u64>::const_memory_size(CAPACITY);
+    let mut memory = [0u8; MEM_SIZE];
+    let bump_allocator = BumpAllocator::new(
+        core::ptr::NonNull::<u8>::new(memory.as_mut_ptr().cast())
+            .expect("Precondition failed: Pointer to memory is null"),
+        core::mem::size_of_val(&memory),
+    );
+
+    let mut sut = unsafe { RelocatableBTreeSet::<u64>::new_uninit(CAPACITY) };
+    unsafe { sut.init(&bump_allocator).expect("sut init failed") };
+
+    for key in (0..CAPACITY as u64).rev() {
+        assert_that!(sut.insert(key), is_ok);
+    }
+    assert_that!(sut.insert(CAPACITY as u64).unwrap_err(), eq BTreeModificationError::IsFull);
+    assert_that!(
+        sut.iter().copied().collect::<Vec<_>>(),
+        eq(0..CAPACITY as u64).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+#[requires_std("serde_test")]
+pub fn map_serialization_works() {
+    use serde_test::{Token, assert_tokens};
+
+    let mut sut = StaticBTreeMap::<u32, u64, CAPACITY>::new();
+    assert_that!(sut.insert(7, 70), is_ok);
+    assert_that!(sut.insert(3, 30), is_ok);
+
+    assert_tokens(
+        &sut,
+        &[
+            Token::Map { len: Some(2) },
+            Token::U32(3),
+            Token::U64(30),
+            Token::U32(7),
+            Token::U64(70),
+            Token::MapEnd,
+        ],
+    );
+}
+
+#[test]
+#[requires_std("serde_test")]
+pub fn set_serialization_works() {
+    use serde_test::{Token, assert_tokens};
+
+    let mut sut = StaticBTreeSet::<u32, CAPACITY>::new();
+    assert_that!(sut.insert(9), is_ok);
+    assert_that!(sut.insert(1), is_ok);
+
+    assert_tokens(
+        &sut,
+        &[
+            Token::Seq { len: Some(2) },
+            Token::U32(1),
+            Token::U32(9),
+            Token::SeqEnd,
+        ],
+    );
+}
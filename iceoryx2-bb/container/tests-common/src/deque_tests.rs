@@ -0,0 +1,161 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use alloc::collections::VecDeque;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use iceoryx2_bb_container::deque::*;
+use iceoryx2_bb_elementary_traits::placement_default::PlacementDefault;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing::lifetime_tracker::LifetimeTracker;
+use iceoryx2_bb_testing::memory::RawMemory;
+use iceoryx2_bb_testing_macros::requires_std;
+use iceoryx2_bb_testing_macros::test;
+
+const CAPACITY: usize = 7;
+
+#[test]
+pub fn new_creates_empty_deque() {
+    let sut = StaticDeque::<u32, CAPACITY>::new();
+    assert_that!(sut.is_empty(), eq true);
+    assert_that!(sut.is_full(), eq false);
+    assert_that!(sut.len(), eq 0);
+    assert_that!(sut.capacity(), eq CAPACITY);
+    assert_that!(sut.front(), is_none);
+    assert_that!(sut.back(), is_none);
+    assert_that!(sut.get(0), is_none);
+}
+
+#[test]
+pub fn placement_default_works() {
+    type Sut = StaticDeque<u32, CAPACITY>;
+    let mut sut = RawMemory::<Sut>::new_zeroed();
+    unsafe { Sut::placement_default(sut.as_mut_ptr()) };
+
+    let sut = unsafe { sut.assume_init_mut() };
+    assert_that!(sut.push_front(4), eq true);
+    assert_that!(sut.back(), eq Some(&4));
+}
+
+#[test]
+pub fn push_into_full_deque_fails() {
+    let mut sut = StaticDeque::<u32, CAPACITY>::new();
+    for n in 0..CAPACITY as u32 {
+        assert_that!(sut.push_front(n), eq true);
+    }
+    assert_that!(sut.is_full(), eq true);
+    assert_that!(sut.push_front(99), eq false);
+    assert_that!(sut.push_back(99), eq false);
+    assert_that!(sut.len(), eq CAPACITY);
+}
+
+#[test]
+pub fn behaves_like_std_vec_deque_when_wrapping_around() {
+    let mut sut = StaticDeque::<u32, CAPACITY>::new();
+    let mut reference = VecDeque::new();
+
+    for n in 0..100u32 {
+        match n % 5 {
+            0 | 1 if reference.len() < CAPACITY => {
+                assert_that!(sut.push_back(n), eq true);
+                reference.push_back(n);
+            }
+            2 if reference.len() < CAPACITY => {
+                assert_that!(sut.push_front(n), eq true);
+                reference.push_front(n);
+            }
+            3 => assert_that!(sut.pop_front(), eq reference.pop_front()),
+            _ => assert_that!(sut.pop_back(), eq reference.pop_back()),
+        }
+
+        assert_that!(sut.len(), eq reference.len());
+        assert_that!(
+            sut.iter().copied().collect::<Vec<_>>(),
+            eq reference.iter().copied().collect::<Vec<_>>()
+        );
+        assert_that!(
+            sut.iter().rev().copied().collect::<Vec<_>>(),
+            eq reference.iter().rev().copied().collect::<Vec<_>>()
+        );
+    }
+}
+
+#[test]
+pub fn element_access_works() {
+    let mut sut = StaticDeque::<u32, CAPACITY>::new();
+    assert_that!(sut.push_back(2), eq true);
+    assert_that!(sut.push_front(1), eq true);
+    assert_that!(sut.push_back(3), eq true);
+
+    assert_that!(sut.get(0), eq Some(&1));
+    assert_that!(sut.get(2), eq Some(&3));
+    assert_that!(sut.get(3), is_none);
+
+    *sut.get_mut(1).unwrap() = 20;
+    *sut.front_mut().unwrap() = 10;
+    *sut.back_mut().unwrap() = 30;
+    assert_that!(sut.iter().copied().collect::<Vec<_>>(), eq vec![10, 20, 30]);
+}
+
+#[test]
+pub fn drop_called_for_all_elements() {
+    let state = LifetimeTracker::start_tracking();
+    let mut sut = StaticDeque::<LifetimeTracker, CAPACITY>::new();
+    for _ in 0..CAPACITY {
+        assert_that!(sut.push_front(LifetimeTracker::new()), eq true);
+    }
+    drop(sut.pop_back());
+    assert_that!(state.number_of_living_instances(), eq CAPACITY - 1);
+
+    sut.clear();
+    assert_that!(state.number_of_living_instances(), eq 0);
+
+    assert_that!(sut.push_back(LifetimeTracker::new()), eq true);
+    drop(sut);
+    assert_that!(state.number_of_living_instances(), eq 0);
+}
+
+#[test]
+pub fn clone_and_compare_works() {
+    let mut sut = StaticDeque::<u32, CAPACITY>::new();
+    for n in 0..CAPACITY as u32 {
+        assert_that!(sut.push_back(n), eq true);
+    }
+    assert_that!(sut.pop_front(), eq Some(0));
+    assert_that!(sut.push_back(7), eq true);
+
+    let mut sut_clone = sut.clone();
+    assert_that!(sut_clone, eq sut);
+    assert_that!(sut_clone.pop_back(), eq Some(7));
+    assert_that!(sut_clone, ne sut);
+}
+
+#[test]
+#[requires_std("serde_test")]
+pub fn serialization_works() {
+    use serde_test::{Token, assert_tokens};
+
+    let mut sut = StaticDeque::<u32, CAPACITY>::new();
+    assert_that!(sut.push_back(2), eq true);
+    assert_that!(sut.push_front(1), eq true);
+
+    assert_tokens(
+        &sut,
+        &[
+            Token::Seq { len: Some(2) },
+            Token::U32(1),
+            Token::U32(2),
+            Token::SeqEnd,
+        ],
+    );
+}
@@ -0,0 +1,287 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use alloc::vec;
+
+use iceoryx2_bb_container::hashmap::*;
+use iceoryx2_bb_container::string::StaticString;
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
+use iceoryx2_bb_elementary_traits::placement_default::PlacementDefault;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing::lifetime_tracker::LifetimeTracker;
+use iceoryx2_bb_testing::memory::RawMemory;
+use iceoryx2_bb_testing_macros::test;
+
+const CAPACITY: usize = 100;
+
+#[test]
+pub fn new_creates_empty_hash_map() {
+    let map = HashMap::<u8, i32>::new(CAPACITY);
+    assert_that!(map, is_empty);
+    assert_that!(map.is_full(), eq false);
+    assert_that!(map, len 0);
+    assert_that!(map.capacity(), eq CAPACITY);
+}
+
+#[test]
+pub fn new_creates_empty_fixed_size_hash_map() {
+    let map = FixedSizeHashMap::<u8, i32, CAPACITY>::new();
+    assert_that!(map, is_empty);
+    assert_that!(map.is_full(), eq false);
+    assert_that!(map, len 0);
+    assert_that!(map.capacity(), eq CAPACITY);
+}
+
+#[test]
+pub fn placement_default_works() {
+    type Sut = FixedSizeHashMap<u8, u8, CAPACITY>;
+    let mut sut = RawMemory::<Sut>::new_zeroed();
+    unsafe { Sut::placement_default(sut.as_mut_ptr()) };
+
+    let sut = unsafe { sut.assume_init_mut() };
+    assert_that!(sut.insert(4, 6), is_ok);
+    assert_that!(sut.get(&4), eq Some(6));
+}
+
+#[test]
+pub fn drop_called_for_keys_and_values() {
+    let state = LifetimeTracker::start_tracking();
+    let mut map = FixedSizeHashMap::<u32, LifetimeTracker, CAPACITY>::new();
+    for n in 0..CAPACITY {
+        assert_that!(
+            map.insert(n as u32, LifetimeTracker::new_with_value(n)),
+            is_ok
+        );
+    }
+    assert_that!(state.number_of_living_instances(), eq CAPACITY);
+
+    drop(map.remove(&0));
+    assert_that!(state.number_of_living_instances(), eq CAPACITY - 1);
+
+    drop(map);
+    assert_that!(state.number_of_living_instances(), eq 0);
+}
+
+#[test]
+pub fn insert_the_same_key_fails() {
+    let mut map = FixedSizeHashMap::<i16, i16, CAPACITY>::new();
+    let key = -2023;
+
+    assert_that!(map.insert(key, -9), is_ok);
+
+    let res = map.insert(key, 19);
+    assert_that!(res, is_err);
+    assert_that!(res.unwrap_err(), eq HashMapError::KeyAlreadyExists);
+    assert_that!(map, len 1);
+    assert_that!(map.get(&key), eq Some(-9));
+}
+
+#[test]
+pub fn insert_until_full_works() {
+    let mut map = FixedSizeHashMap::<u32, u32, CAPACITY>::new();
+    for i in 0..CAPACITY as u32 {
+        assert_that!(map.insert(i, i), is_ok);
+        assert_that!(map.contains(&i), eq true);
+    }
+    assert_that!(map.is_full(), eq true);
+
+    let res = map.insert(CAPACITY as u32, CAPACITY as u32);
+    assert_that!(res, is_err);
+    assert_that!(res.unwrap_err(), eq HashMapError::IsFull);
+    assert_that!(map.contains(&(CAPACITY as u32)), eq false);
+    assert_that!(map, len CAPACITY);
+
+    for i in 0..CAPACITY as u32 {
+        assert_that!(map.get(&i), eq Some(i));
+    }
+}
+
+#[test]
+pub fn map_with_zero_capacity_is_full() {
+    let mut map = HashMap::<u32, u32>::new(0);
+    assert_that!(map.is_full(), eq true);
+    assert_that!(map.insert(1, 1).unwrap_err(), eq HashMapError::IsFull);
+    assert_that!(map.contains(&1), eq false);
+    assert_that!(map.remove(&1), is_none);
+}
+
+#[test]
+pub fn get_mut_ref_modifies_the_value() {
+    let mut map = FixedSizeHashMap::<u8, u8, CAPACITY>::new();
+    assert_that!(map.insert(34, 40), is_ok);
+
+    *map.get_mut_ref(&34).unwrap() = 41;
+    assert_that!(*map.get_ref(&34).unwrap(), eq 41);
+
+    assert_that!(map.get_mut_ref(&35), is_none);
+}
+
+#[test]
+pub fn remove_keeps_all_other_keys_accessible() {
+    const NUMBER_OF_KEYS: u64 = 3000;
+    let mut map = HashMap::<u64, u64>::new(NUMBER_OF_KEYS as usize);
+    for i in 0..NUMBER_OF_KEYS {
+        assert_that!(map.insert(i, i * 2), is_ok);
+    }
+
+    for i in (0..NUMBER_OF_KEYS).step_by(3) {
+        assert_that!(map.remove(&i), eq Some(i * 2));
+    }
+
+    for i in 0..NUMBER_OF_KEYS {
+        if i % 3 == 0 {
+            assert_that!(map.contains(&i), eq false);
+        } else {
+            assert_that!(map.get(&i), eq Some(i * 2));
+        }
+    }
+    assert_that!(
+        map,
+        len(NUMBER_OF_KEYS - NUMBER_OF_KEYS.div_ceil(3)) as usize
+    );
+}
+
+#[test]
+pub fn remove_until_empty_and_reinsert_works() {
+    let mut map = FixedSizeHashMap::<u32, u32, CAPACITY>::new();
+    for _ in 0..3 {
+        for i in 0..CAPACITY as u32 {
+            assert_that!(map.insert(i, i), is_ok);
+        }
+        assert_that!(map.is_full(), eq true);
+
+        for i in (0..CAPACITY as u32).rev() {
+            assert_that!(map.remove(&i), eq Some(i));
+        }
+        assert_that!(map, is_empty);
+    }
+}
+
+#[test]
+pub fn clear_removes_all_entries() {
+    let state = LifetimeTracker::start_tracking();
+    let mut map = HashMap::<u32, LifetimeTracker>::new(CAPACITY);
+    for i in 0..CAPACITY as u32 {
+        assert_that!(map.insert(i, LifetimeTracker::new()), is_ok);
+    }
+
+    map.clear();
+    assert_that!(map, is_empty);
+    assert_that!(state.number_of_living_instances(), eq 0);
+    assert_that!(map.contains(&0), eq false);
+    assert_that!(map.insert(0, LifetimeTracker::new()), is_ok);
+}
+
+#[test]
+pub fn string_keys_work() {
+    let mut map = FixedSizeHashMap::<StaticString<16>, u32, CAPACITY>::new();
+    let key = StaticString::from_bytes(b"fuu").unwrap();
+    assert_that!(map.insert(key, 123), is_ok);
+    assert_that!(map.get(&key), eq Some(123));
+    assert_that!(map.get(&StaticString::from_bytes(b"bar").unwrap()), is_none);
+}
+
+#[test]
+pub fn hash_does_not_depend_on_the_instance() {
+    let hash = DeterministicHasher::hash_of(&0xdeadbeef_u64);
+    assert_that!(DeterministicHasher::hash_of(&0xdeadbeef_u64), eq hash);
+    assert_that!(DeterministicHasher::hash_of(&0xdeadbeee_u64), ne hash);
+}
+
+#[test]
+pub fn fixed_size_hash_map_can_be_moved() {
+    let mut map = FixedSizeHashMap::<u32, u32, CAPACITY>::new();
+    for i in 0..CAPACITY as u32 {
+        assert_that!(map.insert(i, i + 1), is_ok);
+    }
+
+    let mut moved_map = vec![map];
+    let map = moved_map.pop().unwrap();
+    for i in 0..CAPACITY as u32 {
+        assert_that!(map.get(&i), eq Some(i + 1));
+    }
+}
+
+#[test]
+pub fn relocatable_hash_map_works_with_bump_allocator() {
+    const MEM_SIZE: usize = RelocatableHashMap::<u64, u64>::const_memory_size(CAPACITY);
+    let mut memory = [0u8; MEM_SIZE];
+    let bump_allocator = BumpAllocator::new(
+        core::ptr::NonNull::<u8>::new(memory.as_mut_ptr().cast())
+            .expect("Precondition failed: Pointer to memory is null"),
+        core::mem::size_of_val(&memory),
+    );
+
+    let mut sut = unsafe { RelocatableHashMap::<u64, u64>::new_uninit(CAPACITY) };
+    unsafe { sut.init(&bump_allocator).expect("sut init failed") };
+
+    for i in 0..CAPACITY as u64 {
+        assert_that!(unsafe { sut.insert(i, i) }, is_ok);
+    }
+    assert_that!(sut.is_full(), eq true);
+    assert_that!(unsafe { sut.remove(&5) }, eq Some(5));
+    assert_that!(unsafe { sut.get(&6) }, eq Some(6));
+}
+
+#[test]
+#[should_panic]
+pub fn double_init_call_causes_panic() {
+    const MEM_SIZE: usize = RelocatableHashMap::<u8, u8>::const_memory_size(CAPACITY);
+    let mut memory = [0u8; MEM_SIZE];
+    let bump_allocator = BumpAllocator::new(
+        core::ptr::NonNull::<u8>::new(memory.as_mut_ptr().cast())
+            .expect("Precondition failed: Pointer to memory is null"),
+        core::mem::size_of_val(&memory),
+    );
+
+    let mut sut = unsafe { RelocatableHashMap::<u8, u8>::new_uninit(CAPACITY) };
+    unsafe { sut.init(&bump_allocator).expect("sut init failed") };
+
+    unsafe { sut.init(&bump_allocator).expect("sut init failed") };
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic]
+pub fn panic_is_called_in_debug_mode_if_map_is_not_initialized() {
+    let mut sut = unsafe { RelocatableHashMap::<u8, u8>::new_uninit(CAPACITY) };
+    unsafe { sut.remove(&1) };
+}
+
+#[test]
+pub fn list_works_correctly() {
+    const CAPA: usize = 10;
+    let mut map = FixedSizeHashMap::<u32, u32, CAPA>::new();
+    for i in 0..CAPA as u32 {
+        assert_that!(map.insert(i, i * 3), is_ok);
+    }
+
+    let mut listed_keys = vec![];
+    map.list_keys(|&key| {
+        listed_keys.push(key);
+        CallbackProgression::Continue
+    });
+    listed_keys.sort();
+    assert_that!(
+        listed_keys,
+        eq(0..CAPA as u32).collect::<alloc::vec::Vec<_>>()
+    );
+
+    let mut listed_entries = vec![];
+    map.list(|&key, &value| {
+        listed_entries.push((key, value));
+        CallbackProgression::Stop
+    });
+    assert_that!(listed_entries, len 1);
+    assert_that!(listed_entries[0].1, eq listed_entries[0].0 * 3);
+}
@@ -15,16 +15,22 @@
 extern crate alloc;
 extern crate iceoryx2_bb_loggers;
 
+pub mod btree_tests;
 pub mod byte_atomic_tests;
+pub mod deque_tests;
 pub mod flatmap_tests;
+pub mod hashmap_tests;
+pub mod list_tests;
 pub mod polymorphic_string_tests;
 pub mod polymorphic_vec_tests;
 pub mod queue_tests;
 pub mod relocatable_option_tests;
 pub mod relocatable_vec_tests;
+pub mod slice_container_tests;
 pub mod slotmap_tests;
 pub mod static_string_tests;
 pub mod static_vec_tests;
 pub mod string_tests;
 pub mod string_utils_tests;
+pub mod validate_tests;
 pub mod vector_tests;
@@ -0,0 +1,335 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use iceoryx2_bb_container::list::*;
+use iceoryx2_bb_derive_macros::ZeroCopySend;
+use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
+use iceoryx2_bb_elementary_traits::placement_default::PlacementDefault;
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing::lifetime_tracker::LifetimeTracker;
+use iceoryx2_bb_testing::memory::RawMemory;
+use iceoryx2_bb_testing_macros::requires_std;
+use iceoryx2_bb_testing_macros::test;
+
+const CAPACITY: usize = 10;
+
+fn content_of<L: List<u32>>(list: &L) -> Vec<u32> {
+    list.iter().copied().collect()
+}
+
+#[test]
+pub fn new_creates_empty_list() {
+    let sut = StaticList::<u32, CAPACITY>::new();
+    assert_that!(sut.is_empty(), eq true);
+    assert_that!(sut.is_full(), eq false);
+    assert_that!(sut.len(), eq 0);
+    assert_that!(sut.capacity(), eq CAPACITY);
+    assert_that!(sut.front(), is_none);
+    assert_that!(sut.back(), is_none);
+    assert_that!(sut.front_handle(), is_none);
+    assert_that!(sut.iter().next(), is_none);
+}
+
+#[test]
+pub fn placement_default_works() {
+    type Sut = StaticList<u32, CAPACITY>;
+    let mut sut = RawMemory::<Sut>::new_zeroed();
+    unsafe { Sut::placement_default(sut.as_mut_ptr()) };
+
+    let sut = unsafe { sut.assume_init_mut() };
+    assert_that!(sut.is_empty(), eq true);
+    let handle = sut.push_back(4).unwrap();
+    assert_that!(sut.get(handle), eq Some(&4));
+}
+
+#[test]
+pub fn push_and_pop_at_both_ends_works() {
+    let mut sut = StaticList::<u32, CAPACITY>::new();
+    assert_that!(sut.push_back(2), is_ok);
+    assert_that!(sut.push_front(1), is_ok);
+    assert_that!(sut.push_back(3), is_ok);
+
+    assert_that!(content_of(&sut), eq vec![1, 2, 3]);
+    assert_that!(sut.iter().rev().copied().collect::<Vec<_>>(), eq vec![3, 2, 1]);
+    assert_that!(sut.front(), eq Some(&1));
+    assert_that!(sut.back(), eq Some(&3));
+
+    assert_that!(sut.pop_front(), eq Some(1));
+    assert_that!(sut.pop_back(), eq Some(3));
+    assert_that!(sut.pop_back(), eq Some(2));
+    assert_that!(sut.pop_front(), is_none);
+    assert_that!(sut.is_empty(), eq true);
+}
+
+#[test]
+pub fn push_into_full_list_fails() {
+    let mut sut = StaticList::<u32, CAPACITY>::new();
+    for n in 0..CAPACITY as u32 {
+        assert_that!(sut.push_back(n), is_ok);
+    }
+    assert_that!(sut.is_full(), eq true);
+
+    assert_that!(sut.push_back(99).unwrap_err(), eq ListModificationError::IsFull);
+    assert_that!(sut.push_front(99).unwrap_err(), eq ListModificationError::IsFull);
+    let front = sut.front_handle().unwrap();
+    assert_that!(sut.insert_after(front, 99).unwrap_err(), eq ListModificationError::IsFull);
+    assert_that!(sut.len(), eq CAPACITY);
+}
+
+#[test]
+pub fn insert_before_and_after_handle_works() {
+    let mut sut = StaticList::<u32, CAPACITY>::new();
+    let two = sut.push_back(2).unwrap();
+    let four = sut.push_back(4).unwrap();
+
+    assert_that!(sut.insert_before(two, 1), is_ok);
+    assert_that!(sut.insert_after(two, 3), is_ok);
+    assert_that!(sut.insert_after(four, 5), is_ok);
+
+    assert_that!(content_of(&sut), eq vec![1, 2, 3, 4, 5]);
+    assert_that!(sut.iter().rev().copied().collect::<Vec<_>>(), eq vec![5, 4, 3, 2, 1]);
+}
+
+#[test]
+pub fn handles_stay_valid_when_other_elements_are_modified() {
+    let mut sut = StaticList::<u32, CAPACITY>::new();
+    let handles: Vec<_> = (0..CAPACITY as u32)
+        .map(|n| sut.push_back(n).unwrap())
+        .collect();
+
+    for handle in handles.iter().step_by(2) {
+        assert_that!(sut.remove(*handle), is_some);
+    }
+    for n in 0..CAPACITY as u32 / 2 {
+        assert_that!(sut.push_front(100 + n), is_ok);
+    }
+
+    for (n, handle) in handles.iter().enumerate() {
+        if n % 2 == 0 {
+            assert_that!(sut.get(*handle), is_none);
+        } else {
+            assert_that!(sut.get(*handle), eq Some(&(n as u32)));
+        }
+    }
+}
+
+#[test]
+pub fn removed_handle_is_invalid_even_when_node_is_reused() {
+    let mut sut = StaticList::<u32, CAPACITY>::new();
+    let handle = sut.push_back(1).unwrap();
+    assert_that!(sut.remove(handle), eq Some(1));
+
+    let new_handle = sut.push_back(2).unwrap();
+    assert_that!(new_handle, ne handle);
+    assert_that!(sut.contains(handle), eq false);
+    assert_that!(sut.get(handle), is_none);
+    assert_that!(sut.remove(handle), is_none);
+    assert_that!(sut.next(handle), is_none);
+    assert_that!(sut.insert_after(handle, 3).unwrap_err(), eq ListModificationError::InvalidHandle);
+    assert_that!(sut.insert_before(handle, 3).unwrap_err(), eq ListModificationError::InvalidHandle);
+    assert_that!(sut.get(new_handle), eq Some(&2));
+}
+
+#[test]
+pub fn navigation_with_handles_works() {
+    let mut sut = StaticList::<u32, CAPACITY>::new();
+    let one = sut.push_back(1).unwrap();
+    let two = sut.push_back(2).unwrap();
+
+    assert_that!(sut.front_handle(), eq Some(one));
+    assert_that!(sut.back_handle(), eq Some(two));
+    assert_that!(sut.next(one), eq Some(two));
+    assert_that!(sut.next(two), is_none);
+    assert_that!(sut.prev(two), eq Some(one));
+    assert_that!(sut.prev(one), is_none);
+
+    *sut.get_mut(two).unwrap() = 22;
+    *sut.front_mut().unwrap() = 11;
+    assert_that!(content_of(&sut), eq vec![11, 22]);
+}
+
+#[test]
+pub fn cursor_inserts_and_removes_at_its_position() {
+    let mut sut = StaticList::<u32, CAPACITY>::new();
+    for n in 1..=5 {
+        assert_that!(sut.push_back(n), is_ok);
+    }
+
+    let mut cursor = sut.cursor_front_mut();
+    while let Some(value) = cursor.current() {
+        if value % 2 == 0 {
+            assert_that!(cursor.remove_current(), is_some);
+        } else {
+            *cursor.current_mut().unwrap() *= 10;
+            assert_that!(cursor.insert_after(0), is_ok);
+            cursor.move_next();
+            cursor.move_next();
+        }
+    }
+    assert_that!(cursor.handle(), is_none);
+
+    // from the ghost position insert_after adds to the front and insert_before to the back
+    assert_that!(cursor.insert_after(7), is_ok);
+    assert_that!(cursor.insert_before(8), is_ok);
+    cursor.move_prev();
+    assert_that!(cursor.current(), eq Some(&8));
+
+    assert_that!(content_of(&sut), eq vec![7, 10, 0, 30, 0, 50, 0, 8]);
+}
+
+#[test]
+pub fn cursor_from_handle_works() {
+    let mut sut = StaticList::<u32, CAPACITY>::new();
+    assert_that!(sut.push_back(1), is_ok);
+    let handle = sut.push_back(3).unwrap();
+
+    let mut cursor = sut.cursor_mut(handle).unwrap();
+    assert_that!(cursor.insert_before(2), is_ok);
+    cursor.move_next();
+    assert_that!(cursor.current(), is_none);
+    cursor.move_next();
+    assert_that!(cursor.current(), eq Some(&1));
+
+    let mut cursor = sut.cursor_back_mut();
+    assert_that!(cursor.remove_current(), eq Some(3));
+    assert_that!(content_of(&sut), eq vec![1, 2]);
+
+    assert_that!(sut.cursor_mut(handle), is_none);
+}
+
+#[test]
+pub fn drop_called_for_all_elements() {
+    let state = LifetimeTracker::start_tracking();
+    let mut sut = StaticList::<LifetimeTracker, CAPACITY>::new();
+    for _ in 0..CAPACITY {
+        assert_that!(sut.push_back(LifetimeTracker::new()), is_ok);
+    }
+    assert_that!(state.number_of_living_instances(), eq CAPACITY);
+
+    drop(sut.pop_front());
+    assert_that!(state.number_of_living_instances(), eq CAPACITY - 1);
+
+    sut.clear();
+    assert_that!(state.number_of_living_instances(), eq 0);
+    assert_that!(sut.is_empty(), eq true);
+
+    assert_that!(sut.push_back(LifetimeTracker::new()), is_ok);
+    drop(sut);
+    assert_that!(state.number_of_living_instances(), eq 0);
+}
+
+#[test]
+pub fn clone_and_compare_works() {
+    let mut sut = StaticList::<u32, CAPACITY>::new();
+    for n in 0..CAPACITY as u32 / 2 {
+        assert_that!(sut.push_front(n), is_ok);
+    }
+
+    let mut sut_clone = sut.clone();
+    assert_that!(sut_clone, eq sut);
+    assert_that!(content_of(&sut_clone), eq content_of(&sut));
+
+    assert_that!(sut_clone.pop_back(), is_some);
+    assert_that!(sut_clone, ne sut);
+}
+
+#[test]
+pub fn static_list_is_zero_copy_send_inside_a_derived_type() {
+    #[repr(C)]
+    #[derive(ZeroCopySend)]
+    struct Tracks {
+        newest: ListHandle,
+        tracks: StaticList<u64, CAPACITY>,
+    }
+
+    fn is_zero_copy_send<T: ZeroCopySend>(_: &T) -> bool {
+        true
+    }
+
+    let mut tracks = StaticList::new();
+    let newest = tracks.push_back(42).unwrap();
+    let payload = Tracks { newest, tracks };
+
+    assert_that!(is_zero_copy_send(&payload), eq true);
+    assert_that!(payload.tracks.get(payload.newest), eq Some(&42));
+}
+
+#[test]
+pub fn relocatable_list_works_with_bump_allocator() {
+    const MEM_SIZE: usize = RelocatableList::<u32>::const_memory_size(CAPACITY);
+    let mut memory = [0u8; MEM_SIZE];
+    let bump_allocator = BumpAllocator::new(
+        core::ptr::NonNull::<u8>::new(memory.as_mut_ptr().cast())
+            .expect("Precondition failed: Pointer to memory is null"),
+        core::mem::size_of_val(&memory),
+    );
+
+    let mut sut = unsafe { RelocatableList::<u32>::new_uninit(CAPACITY) };
+    unsafe { sut.init(&bump_allocator).expect("sut init failed") };
+
+    for n in 0..CAPACITY as u32 {
+        assert_that!(sut.push_front(n), is_ok);
+    }
+    assert_that!(sut.is_full(), eq true);
+    assert_that!(sut.pop_back(), eq Some(0));
+    assert_that!(sut.front(), eq Some(&(CAPACITY as u32 - 1)));
+    assert_that!(sut.iter().len(), eq CAPACITY - 1);
+}
+
+#[test]
+#[should_panic]
+pub fn double_init_call_causes_panic() {
+    const MEM_SIZE: usize = RelocatableList::<u8>::const_memory_size(CAPACITY);
+    let mut memory = [0u8; MEM_SIZE];
+    let bump_allocator = BumpAllocator::new(
+        core::ptr::NonNull::<u8>::new(memory.as_mut_ptr().cast())
+            .expect("Precondition failed: Pointer to memory is null"),
+        core::mem::size_of_val(&memory),
+    );
+
+    let mut sut = unsafe { RelocatableList::<u8>::new_uninit(CAPACITY) };
+    unsafe { sut.init(&bump_allocator).expect("sut init failed") };
+
+    unsafe { sut.init(&bump_allocator).expect("sut init failed") };
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic]
+pub fn panic_is_called_in_debug_mode_if_list_is_not_initialized() {
+    let mut sut = unsafe { RelocatableList::<u8>::new_uninit(CAPACITY) };
+    assert_that!(sut.push_back(1), is_ok);
+}
+
+#[test]
+#[requires_std("serde_test")]
+pub fn serialization_works() {
+    use serde_test::{Token, assert_tokens};
+
+    let mut sut = StaticList::<u32, CAPACITY>::new();
+    assert_that!(sut.push_back(2), is_ok);
+    assert_that!(sut.push_front(1), is_ok);
+
+    assert_tokens(
+        &sut,
+        &[
+            Token::Seq { len: Some(2) },
+            Token::U32(1),
+            Token::U32(2),
+            Token::SeqEnd,
+        ],
+    );
+}
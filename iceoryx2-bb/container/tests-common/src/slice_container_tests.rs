@@ -0,0 +1,266 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::mem::MaybeUninit;
+
+use iceoryx2_bb_container::queue::RelocatableQueue;
+use iceoryx2_bb_container::slice_container::*;
+use iceoryx2_bb_container::string::*;
+use iceoryx2_bb_container::vector::*;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing_macros::test;
+
+const MEMORY_SIZE: usize = 1024;
+
+#[repr(C, align(16))]
+struct Memory {
+    data: [MaybeUninit<u8>; MEMORY_SIZE],
+}
+
+impl Memory {
+    fn new() -> Self {
+        Self {
+            data: [MaybeUninit::new(0xff); MEMORY_SIZE],
+        }
+    }
+
+    fn bytes(&self) -> &[u8] {
+        unsafe { &*(&self.data as *const [MaybeUninit<u8>] as *const [u8]) }
+    }
+
+    fn bytes_mut(&mut self) -> &mut [u8] {
+        unsafe { &mut *(&mut self.data as *mut [MaybeUninit<u8>] as *mut [u8]) }
+    }
+}
+
+#[test]
+pub fn vec_created_in_slice_can_be_viewed() {
+    let mut memory = Memory::new();
+    let capacity = 12;
+    let len = RelocatableVec::<u64>::required_slice_len(capacity);
+
+    let sut = RelocatableVec::<u64>::create_in_slice(&mut memory.data[..len], capacity).unwrap();
+    assert_that!(sut.capacity(), eq capacity);
+    assert_that!(sut.is_empty(), eq true);
+    for n in 0..capacity as u64 {
+        assert_that!(sut.push(n * 3), is_ok);
+    }
+    assert_that!(sut.push(0), is_err);
+
+    let view = RelocatableVec::<u64>::view_in_slice(&memory.bytes()[..len]).unwrap();
+    assert_that!(view.len(), eq capacity);
+    for n in 0..capacity {
+        assert_that!(view[n], eq n as u64 * 3);
+    }
+}
+
+#[test]
+pub fn view_works_after_the_slice_was_copied_to_another_location() {
+    let mut memory = Memory::new();
+    let capacity = 5;
+    let len = RelocatableVec::<u32>::required_slice_len(capacity);
+
+    let sut = RelocatableVec::<u32>::create_in_slice(&mut memory.data, capacity).unwrap();
+    assert_that!(sut.extend_from_slice(&[1, 2, 3]), is_ok);
+
+    let mut other_memory = Memory::new();
+    other_memory.bytes_mut()[..len].copy_from_slice(&memory.bytes()[..len]);
+    memory.bytes_mut().fill(0);
+
+    let view = RelocatableVec::<u32>::view_in_slice(&other_memory.bytes()[..len]).unwrap();
+    assert_that!(view.as_slice(), eq [1, 2, 3]);
+}
+
+#[test]
+pub fn string_created_in_slice_can_be_viewed() {
+    let mut memory = Memory::new();
+    let capacity = 32;
+
+    let sut = RelocatableString::create_in_slice(&mut memory.data, capacity).unwrap();
+    assert_that!(sut.push_bytes(b"hypnotoad"), is_ok);
+
+    let view = RelocatableString::view_in_slice(memory.bytes()).unwrap();
+    assert_that!(view.capacity(), eq capacity);
+    assert_that!(view.as_bytes(), eq b"hypnotoad");
+}
+
+#[test]
+pub fn queue_created_in_slice_can_be_viewed() {
+    let mut memory = Memory::new();
+    let capacity = 4;
+
+    let sut = RelocatableQueue::<u16>::create_in_slice(&mut memory.data, capacity).unwrap();
+    for n in 0..6 {
+        unsafe { sut.push_with_overflow(n) };
+    }
+
+    let view = RelocatableQueue::<u16>::view_in_slice(memory.bytes()).unwrap();
+    assert_that!(view.len(), eq capacity);
+    assert_that!(view.peek(), eq Some(&2));
+    assert_that!(view.get(3), eq 5);
+}
+
+#[test]
+pub fn create_in_too_small_slice_fails() {
+    let mut memory = Memory::new();
+    let capacity = 12;
+    let len = RelocatableVec::<u64>::required_slice_len(capacity);
+
+    let result = RelocatableVec::<u64>::create_in_slice(&mut memory.data[..len - 1], capacity);
+    assert_that!(result.err(), eq Some(SliceContainerError::InsufficientSize));
+}
+
+#[test]
+pub fn create_in_misaligned_slice_fails() {
+    let mut memory = Memory::new();
+
+    let result = RelocatableVec::<u64>::create_in_slice(&mut memory.data[1..], 12);
+    assert_that!(result.err(), eq Some(SliceContainerError::MisalignedSlice));
+}
+
+#[test]
+pub fn create_with_zero_capacity_fails() {
+    let mut memory = Memory::new();
+
+    let result = RelocatableVec::<u64>::create_in_slice(&mut memory.data, 0);
+    assert_that!(result.err(), eq Some(SliceContainerError::InitializationFailed));
+}
+
+#[test]
+pub fn view_of_uninitialized_slice_fails() {
+    let memory = Memory::new();
+
+    let result = RelocatableVec::<u64>::view_in_slice(memory.bytes());
+    assert_that!(result.err(), eq Some(SliceContainerError::LayoutMismatch));
+}
+
+#[test]
+pub fn view_of_different_container_or_element_type_fails() {
+    let mut memory = Memory::new();
+    assert_that!(
+        RelocatableVec::<u64>::create_in_slice(&mut memory.data, 8),
+        is_ok
+    );
+
+    assert_that!(
+        RelocatableVec::<i64>::view_in_slice(memory.bytes()).err(),
+        eq Some(SliceContainerError::LayoutMismatch)
+    );
+    assert_that!(
+        RelocatableQueue::<u64>::view_in_slice(memory.bytes()).err(),
+        eq Some(SliceContainerError::LayoutMismatch)
+    );
+    assert_that!(RelocatableVec::<u64>::view_in_slice(memory.bytes()), is_ok);
+}
+
+#[test]
+pub fn view_of_truncated_slice_fails() {
+    let mut memory = Memory::new();
+    let capacity = 8;
+    let len = RelocatableVec::<u64>::required_slice_len(capacity);
+    assert_that!(
+        RelocatableVec::<u64>::create_in_slice(&mut memory.data, capacity),
+        is_ok
+    );
+
+    assert_that!(
+        RelocatableVec::<u64>::view_in_slice(&memory.bytes()[..len - 1]).err(),
+        eq Some(SliceContainerError::InsufficientSize)
+    );
+    assert_that!(
+        RelocatableVec::<u64>::view_in_slice(&memory.bytes()[..8]).err(),
+        eq Some(SliceContainerError::InsufficientSize)
+    );
+}
+
+#[test]
+pub fn view_of_misaligned_slice_fails() {
+    let memory = Memory::new();
+
+    assert_that!(
+        RelocatableVec::<u64>::view_in_slice(&memory.bytes()[4..]).err(),
+        eq Some(SliceContainerError::MisalignedSlice)
+    );
+}
+
+#[test]
+pub fn view_of_corrupted_container_fails() {
+    let mut memory = Memory::new();
+    let capacity = 8;
+    let sut = RelocatableVec::<u64>::create_in_slice(&mut memory.data, capacity).unwrap();
+    assert_that!(sut.push(1), is_ok);
+
+    // the header contains the layout id and the capacity, the vector starts with its
+    // relative pointer followed by the capacity and the length
+    let header_size = 16;
+    let len_position = header_size + 16;
+    let bytes = memory.bytes_mut();
+    bytes[len_position..len_position + 8].copy_from_slice(&(capacity as u64 + 1).to_ne_bytes());
+    assert_that!(
+        RelocatableVec::<u64>::view_in_slice(memory.bytes()).err(),
+        eq Some(SliceContainerError::CorruptedState)
+    );
+
+    let bytes = memory.bytes_mut();
+    bytes[len_position..len_position + 8].copy_from_slice(&1u64.to_ne_bytes());
+    assert_that!(RelocatableVec::<u64>::view_in_slice(memory.bytes()), is_ok);
+
+    // relative pointer points to a location outside of the slice
+    let bytes = memory.bytes_mut();
+    bytes[header_size..header_size + 8].copy_from_slice(&(-4096i64).to_ne_bytes());
+    assert_that!(
+        RelocatableVec::<u64>::view_in_slice(memory.bytes()).err(),
+        eq Some(SliceContainerError::CorruptedState)
+    );
+}
+
+#[test]
+pub fn view_of_string_without_null_termination_fails() {
+    let mut memory = Memory::new();
+    let capacity = 8;
+    let len = RelocatableString::required_slice_len(capacity);
+    let sut = RelocatableString::create_in_slice(&mut memory.data, capacity).unwrap();
+    assert_that!(sut.push_bytes(b"abc"), is_ok);
+
+    // the data of the string is located at the end of the slice
+    let data_position = len - (capacity + 1);
+    memory.bytes_mut()[data_position + 3] = b'd';
+    assert_that!(
+        RelocatableString::view_in_slice(&memory.bytes()[..len]).err(),
+        eq Some(SliceContainerError::CorruptedState)
+    );
+}
+
+#[test]
+pub fn view_of_string_with_invalid_content_fails() {
+    let mut memory = Memory::new();
+    let capacity = 8;
+    let len = RelocatableString::required_slice_len(capacity);
+    let sut = RelocatableString::create_in_slice(&mut memory.data, capacity).unwrap();
+    assert_that!(sut.push_bytes(b"abc"), is_ok);
+
+    // the data of the string is located at the end of the slice
+    let data_position = len - (capacity + 1);
+    for invalid_byte in [0x80, 0xff, 0] {
+        memory.bytes_mut()[data_position + 1] = invalid_byte;
+        assert_that!(
+            RelocatableString::view_in_slice(&memory.bytes()[..len]).err(),
+            eq Some(SliceContainerError::CorruptedState)
+        );
+    }
+
+    memory.bytes_mut()[data_position + 1] = b'b';
+    assert_that!(
+        RelocatableString::view_in_slice(&memory.bytes()[..len]),
+        is_ok
+    );
+}
@@ -40,6 +40,9 @@ pub mod generic {
     const POLYMORPHIC_STRING_MEM_SIZE: usize =
         core::mem::size_of::<u8>() * ((SUT_CAPACITY + 1) * 3);
 
+    // the memory of the allocators is not zeroed so that a missing null-terminator is detected
+    const UNINITIALIZED_MEMORY: u8 = 0xff;
+
     pub trait StringTestFactory {
         type Sut: String;
 
@@ -86,7 +89,9 @@ pub mod generic {
 
         fn new() -> Self {
             Self {
-                raw_memory: UnsafeCell::new(Box::new([0u8; RELOCATABLE_STRING_MEM_SIZE])),
+                raw_memory: UnsafeCell::new(Box::new(
+                    [UNINITIALIZED_MEMORY; RELOCATABLE_STRING_MEM_SIZE],
+                )),
                 allocator: UnsafeCell::new(None),
             }
         }
@@ -124,7 +129,9 @@ pub mod generic {
 
         fn new() -> Self {
             Self {
-                raw_memory: UnsafeCell::new(Box::new([0u8; POLYMORPHIC_STRING_MEM_SIZE])),
+                raw_memory: UnsafeCell::new(Box::new(
+                    [UNINITIALIZED_MEMORY; POLYMORPHIC_STRING_MEM_SIZE],
+                )),
                 allocator: UnsafeCell::new(None),
             }
         }
@@ -148,6 +155,18 @@ pub mod generic {
         assert_that!(sut.as_bytes_with_nul(), eq b"\0");
     }
 
+    #[test]
+    pub fn full_string_is_null_terminated<Factory: StringTestFactory>() {
+        let factory = Factory::new();
+        let mut sut = factory.create_sut();
+        let content = [b'x'; SUT_CAPACITY];
+
+        assert_that!(sut.push_bytes(&content), is_ok);
+        assert_that!(sut.is_full(), eq true);
+        assert_that!(sut.as_bytes(), eq content);
+        assert_that!(sut.as_bytes_with_nul()[SUT_CAPACITY], eq 0);
+    }
+
     #[test]
     pub fn capacity_is_correct<Factory: StringTestFactory>() {
         let factory = Factory::new();
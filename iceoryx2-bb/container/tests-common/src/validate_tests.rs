@@ -0,0 +1,250 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::mem::MaybeUninit;
+
+use iceoryx2_bb_container::btree::{BTreeMap, StaticBTreeMap};
+use iceoryx2_bb_container::byte_atomic::FixedSizeByteAtomic;
+use iceoryx2_bb_container::deque::StaticDeque;
+use iceoryx2_bb_container::flatmap::FixedSizeFlatMap;
+use iceoryx2_bb_container::hashmap::FixedSizeHashMap;
+use iceoryx2_bb_container::list::{List, StaticList};
+use iceoryx2_bb_container::queue::{FixedSizeQueue, RelocatableQueue};
+use iceoryx2_bb_container::relocatable_option::RelocatableOption;
+use iceoryx2_bb_container::slice_container::SliceContainer;
+use iceoryx2_bb_container::slotmap::FixedSizeSlotMap;
+use iceoryx2_bb_container::string::*;
+use iceoryx2_bb_container::vector::*;
+use iceoryx2_bb_elementary_traits::validate::{Validate, ValidationBounds, ValidationError};
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing_macros::test;
+
+const CAPACITY: usize = 8;
+
+fn validate<T: Validate>(value: &T) -> Result<(), ValidationError> {
+    unsafe { T::validate(value, &ValidationBounds::of_value(value)) }
+}
+
+/// Modifies the raw memory of the value and validates it afterwards. The modified value is
+/// never dropped since it may be corrupted.
+fn validate_modified<T: Validate, F: FnOnce(*mut u8)>(
+    value: T,
+    modify: F,
+) -> Result<(), ValidationError> {
+    let mut value = MaybeUninit::new(value);
+    modify(value.as_mut_ptr().cast::<u8>());
+    let bounds = ValidationBounds::new(value.as_ptr().cast(), size_of::<T>());
+    unsafe { T::validate(value.as_ptr(), &bounds) }
+}
+
+fn write_u64(ptr: *mut u8, offset: usize, value: u64) {
+    unsafe { ptr.add(offset).cast::<u64>().write_unaligned(value) };
+}
+
+#[test]
+fn static_string_with_valid_content_is_valid() {
+    let sut = StaticString::<CAPACITY>::try_from("hello").unwrap();
+    assert_that!(validate(&sut), is_ok);
+
+    let sut = StaticString::<CAPACITY>::try_from("12345678").unwrap();
+    assert_that!(validate(&sut), is_ok);
+
+    let sut = StaticString::<CAPACITY>::new();
+    assert_that!(validate(&sut), is_ok);
+}
+
+#[test]
+fn static_string_with_unsupported_character_is_invalid() {
+    let sut = StaticString::<CAPACITY>::try_from("hello").unwrap();
+    let result = validate_modified(sut, |ptr| unsafe { ptr.add(1).write(0xc3) });
+    assert_that!(result, eq Err(ValidationError::InvalidBitPattern));
+
+    let result = validate_modified(sut, |ptr| unsafe { ptr.add(1).write(0) });
+    assert_that!(result, eq Err(ValidationError::InvalidBitPattern));
+}
+
+#[test]
+fn static_string_with_inconsistent_state_is_invalid() {
+    let sut = StaticString::<CAPACITY>::try_from("hello").unwrap();
+    let len_offset = size_of::<StaticString<CAPACITY>>() - size_of::<u64>();
+
+    let result = validate_modified(sut, |ptr| write_u64(ptr, len_offset, CAPACITY as u64 + 1));
+    assert_that!(result, eq Err(ValidationError::InvariantViolation));
+
+    // missing null terminator
+    let result = validate_modified(sut, |ptr| unsafe { ptr.add(5).write(b'a') });
+    assert_that!(result, eq Err(ValidationError::InvariantViolation));
+}
+
+#[test]
+fn static_vec_validates_only_contained_elements() {
+    let mut sut = StaticVec::<bool, CAPACITY>::new();
+    assert_that!(sut.push(true), is_ok);
+    assert_that!(sut.push(false), is_ok);
+    assert_that!(validate(&sut), is_ok);
+
+    let result = validate_modified(sut.clone(), |ptr| unsafe { ptr.add(1).write(2) });
+    assert_that!(result, eq Err(ValidationError::InvalidBitPattern));
+
+    let result = validate_modified(sut.clone(), |ptr| unsafe { ptr.add(2).write(2) });
+    assert_that!(result, is_ok);
+
+    let len_offset = size_of::<StaticVec<bool, CAPACITY>>() - size_of::<u64>();
+    let result = validate_modified(sut, |ptr| write_u64(ptr, len_offset, CAPACITY as u64 + 1));
+    assert_that!(result, eq Err(ValidationError::InvariantViolation));
+}
+
+#[test]
+fn relocatable_vec_with_data_outside_of_bounds_is_invalid() {
+    #[repr(C, align(16))]
+    struct Memory([MaybeUninit<u8>; 256]);
+    let mut memory = Memory([MaybeUninit::uninit(); 256]);
+    let len = RelocatableVec::<u64>::required_slice_len(CAPACITY);
+    let bounds = ValidationBounds::new(memory.0.as_ptr().cast(), len);
+
+    let sut = RelocatableVec::<u64>::create_in_slice(&mut memory.0[..len], CAPACITY).unwrap();
+    assert_that!(sut.push(123), is_ok);
+    let sut = &*sut;
+
+    assert_that!(unsafe { RelocatableVec::validate(sut, &bounds) }, is_ok);
+    assert_that!(validate(sut), eq Err(ValidationError::OutOfBounds));
+}
+
+#[test]
+fn fixed_size_queue_validates_contained_elements() {
+    let mut sut = FixedSizeQueue::<bool, CAPACITY>::new();
+    for _ in 0..CAPACITY + 3 {
+        sut.push_with_overflow(true);
+    }
+    assert_that!(sut.pop(), eq Some(true));
+    assert_that!(validate(&sut), is_ok);
+
+    let data_offset = size_of::<RelocatableQueue<bool>>();
+    let result = validate_modified(sut, |ptr| unsafe { ptr.add(data_offset + 4).write(2) });
+    assert_that!(result, eq Err(ValidationError::InvalidBitPattern));
+}
+
+#[test]
+fn static_deque_validates_contained_elements() {
+    let mut sut = StaticDeque::<bool, CAPACITY>::new();
+    assert_that!(sut.push_back(true), eq true);
+    assert_that!(sut.push_front(false), eq true);
+    assert_that!(validate(&sut), is_ok);
+
+    let result = validate_modified(sut, |ptr| unsafe { ptr.add(CAPACITY - 1).write(2) });
+    assert_that!(result, eq Err(ValidationError::InvalidBitPattern));
+}
+
+#[test]
+fn relocatable_option_with_invalid_tag_or_value_is_invalid() {
+    assert_that!(validate(&RelocatableOption::<bool>::None), is_ok);
+    assert_that!(validate(&RelocatableOption::Some(true)), is_ok);
+
+    let result = validate_modified(RelocatableOption::Some(true), |ptr| unsafe { ptr.write(2) });
+    assert_that!(result, eq Err(ValidationError::InvalidBitPattern));
+
+    let result = validate_modified(RelocatableOption::Some(true), |ptr| unsafe {
+        ptr.add(1).write(7)
+    });
+    assert_that!(result, eq Err(ValidationError::InvalidBitPattern));
+}
+
+#[test]
+fn fixed_size_byte_atomic_with_invalid_value_is_invalid() {
+    let sut = FixedSizeByteAtomic::<bool, 1>::new(true).unwrap();
+    assert_that!(validate(&sut), is_ok);
+
+    let result = validate_modified(sut, |ptr| unsafe { ptr.write(2) });
+    assert_that!(result, eq Err(ValidationError::InvalidBitPattern));
+}
+
+#[test]
+fn maps_with_modified_content_are_valid() {
+    let mut slotmap = FixedSizeSlotMap::<u64, CAPACITY>::new();
+    let mut hashmap = FixedSizeHashMap::<u64, u64, CAPACITY>::new();
+    let mut flatmap = FixedSizeFlatMap::<u64, u64, CAPACITY>::new();
+
+    for n in 0..CAPACITY as u64 {
+        assert_that!(slotmap.insert(n), is_some);
+        assert_that!(hashmap.insert(n, 2 * n), is_ok);
+        assert_that!(flatmap.insert(n, 3 * n), is_ok);
+    }
+
+    let key = slotmap.iter().next().unwrap().0;
+    assert_that!(slotmap.remove(key), is_some);
+    assert_that!(hashmap.remove(&3), eq Some(6));
+    assert_that!(flatmap.remove(&5), eq Some(15));
+
+    assert_that!(validate(&slotmap), is_ok);
+    assert_that!(validate(&hashmap), is_ok);
+    assert_that!(validate(&flatmap), is_ok);
+}
+
+#[test]
+fn map_validation_fails_when_data_is_outside_of_bounds() {
+    let sut = FixedSizeHashMap::<u64, u64, CAPACITY>::new();
+    let bounds = ValidationBounds::new((&sut as *const FixedSizeHashMap<_, _, CAPACITY>).cast(), 8);
+
+    assert_that!(unsafe { FixedSizeHashMap::validate(&sut, &bounds) }, eq Err(ValidationError::OutOfBounds));
+}
+
+#[test]
+fn static_list_with_invalid_element_or_links_is_invalid() {
+    let create_sut = || {
+        let mut sut = StaticList::<bool, CAPACITY>::new();
+        let handle = sut.push_back(true).unwrap();
+        assert_that!(sut.push_back(false), is_ok);
+        assert_that!(sut.push_front(true), is_ok);
+        assert_that!(sut.remove(handle), eq Some(true));
+        sut
+    };
+    assert_that!(validate(&create_sut()), is_ok);
+
+    // the first node is free, the second contains the element false
+    let node_size = size_of::<iceoryx2_bb_container::list::Node<bool>>();
+    let value_offset = 3 * size_of::<u64>();
+    let result = validate_modified(create_sut(), |ptr| unsafe {
+        ptr.add(node_size + value_offset).write(2)
+    });
+    assert_that!(result, eq Err(ValidationError::InvalidBitPattern));
+
+    // an odd generation marks the free first node as used
+    let generation_offset = 2 * size_of::<u64>();
+    let result = validate_modified(create_sut(), |ptr| write_u64(ptr, generation_offset, 3));
+    assert_that!(result, eq Err(ValidationError::InvariantViolation));
+}
+
+#[test]
+fn static_btree_map_with_invalid_value_or_links_is_invalid() {
+    let create_sut = || {
+        let mut sut = StaticBTreeMap::<u64, bool, CAPACITY>::new();
+        for n in 0..CAPACITY as u64 {
+            assert_that!(sut.insert(n, n % 2 == 0), is_ok);
+        }
+        assert_that!(sut.remove(&0), is_some);
+        assert_that!(sut.remove(&5), is_some);
+        assert_that!(sut.insert(10, true), is_ok);
+        sut
+    };
+    assert_that!(validate(&create_sut()), is_ok);
+
+    let node_size = size_of::<iceoryx2_bb_container::btree::Node<u64, bool>>();
+    let value_offset = 5 * size_of::<u64>();
+    let result = validate_modified(create_sut(), |ptr| unsafe {
+        ptr.add(node_size + value_offset).write(2)
+    });
+    assert_that!(result, eq Err(ValidationError::InvalidBitPattern));
+
+    // the left child of a node refers to the node itself
+    let result = validate_modified(create_sut(), |ptr| write_u64(ptr, node_size, 1));
+    assert_that!(result, eq Err(ValidationError::InvariantViolation));
+}
@@ -0,0 +1,33 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tests of the pointer arithmetic of the relocatable containers that are executable with
+//! [miri](https://github.com/rust-lang/miri) to detect undefined behavior like out of bounds
+//! accesses, misaligned accesses or provenance violations.
+//!
+//! ```text
+//! cd iceoryx2-bb/container/tests-miri
+//! cargo +nightly miri test
+//! ```
+//!
+//! The memory the containers are placed in is provided by [`shared_memory::SharedMemory`],
+//! which mimics a shared memory segment that is mapped at different addresses.
+
+extern crate iceoryx2_bb_loggers;
+
+pub mod shared_memory;
+
+pub mod relocatable_pointer_tests;
+pub mod relocatable_queue_tests;
+pub mod relocatable_string_tests;
+pub mod relocatable_vec_tests;
+pub mod slice_container_tests;
@@ -0,0 +1,95 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::ptr::NonNull;
+
+use iceoryx2_bb_elementary::relocatable_ptr::*;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing_macros::test;
+
+use crate::shared_memory::SharedMemory;
+
+const NUMBER_OF_ELEMENTS: usize = 8;
+
+fn place_pointer_and_data(
+    memory: &mut SharedMemory,
+    pointer_offset: usize,
+    data_offset: usize,
+) -> *mut RelocatablePointer<u64> {
+    let base = memory.as_uninit_slice_mut().as_mut_ptr().cast::<u8>();
+    let pointer = unsafe { base.add(pointer_offset) }.cast::<RelocatablePointer<u64>>();
+    unsafe {
+        pointer.write(RelocatablePointer::new_uninit());
+        (*pointer).init(NonNull::slice_from_raw_parts(
+            NonNull::new_unchecked(base.add(data_offset)),
+            NUMBER_OF_ELEMENTS * core::mem::size_of::<u64>(),
+        ));
+    }
+
+    for n in 0..NUMBER_OF_ELEMENTS {
+        unsafe { (*pointer).as_mut_ptr().add(n).write(n as u64 * 7) };
+    }
+
+    pointer
+}
+
+fn verify_data(pointer: &RelocatablePointer<u64>) {
+    for n in 0..NUMBER_OF_ELEMENTS {
+        assert_that!(unsafe { *pointer.as_ptr().add(n) }, eq n as u64 * 7);
+    }
+}
+
+#[test]
+pub fn pointer_to_data_behind_it_can_be_relocated() {
+    let mut memory = SharedMemory::new(128);
+    place_pointer_and_data(&mut memory, 0, 16);
+
+    let mut relocated = memory.map_at_new_location();
+    drop(memory);
+
+    let pointer = unsafe { &*relocated.as_uninit_slice_mut().as_ptr().cast() };
+    verify_data(pointer);
+}
+
+#[test]
+pub fn pointer_to_data_in_front_of_it_can_be_relocated() {
+    let mut memory = SharedMemory::new(128);
+    place_pointer_and_data(&mut memory, 112, 0);
+
+    let mut relocated = memory.map_at_new_location();
+    drop(memory);
+
+    let pointer = unsafe {
+        &*relocated
+            .as_uninit_slice_mut()
+            .as_ptr()
+            .add(112)
+            .cast::<RelocatablePointer<u64>>()
+    };
+    verify_data(pointer);
+}
+
+#[test]
+pub fn write_through_relocated_pointer_is_visible_at_new_location_only() {
+    let mut memory = SharedMemory::new(128);
+    let original = place_pointer_and_data(&mut memory, 0, 64);
+
+    let mut relocated = memory.map_at_new_location();
+    let pointer = relocated
+        .as_uninit_slice_mut()
+        .as_mut_ptr()
+        .cast::<RelocatablePointer<u64>>();
+    unsafe { (*pointer).as_mut_ptr().write(1234) };
+
+    assert_that!(unsafe { *(*pointer).as_ptr() }, eq 1234);
+    assert_that!(unsafe { *(*original).as_ptr() }, eq 0);
+}
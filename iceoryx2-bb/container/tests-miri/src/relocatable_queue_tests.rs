@@ -0,0 +1,66 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_container::queue::*;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing_macros::test;
+
+use crate::shared_memory::SharedMemory;
+
+const CAPACITY: usize = 5;
+
+#[test]
+pub fn queue_wraps_around_at_the_end_of_its_memory() {
+    let mut memory = SharedMemory::new(SharedMemory::required_size::<RelocatableQueue<u32>>(
+        CAPACITY,
+    ));
+    let sut = memory.place::<RelocatableQueue<u32>>(CAPACITY);
+
+    for n in 0..3 * CAPACITY as u32 {
+        assert_that!(unsafe { sut.push(n) }, eq true);
+        assert_that!(unsafe { sut.pop() }, eq Some(n));
+    }
+
+    for n in 0..2 * CAPACITY as u32 {
+        let overflow = unsafe { sut.push_with_overflow(n) };
+        if n < CAPACITY as u32 {
+            assert_that!(overflow, is_none);
+        } else {
+            assert_that!(overflow, eq Some(n - CAPACITY as u32));
+        }
+    }
+    assert_that!(sut.peek(), eq Some(&(CAPACITY as u32)));
+}
+
+#[test]
+pub fn queue_can_be_used_after_relocation() {
+    let mut memory = SharedMemory::new(SharedMemory::required_size::<RelocatableQueue<u32>>(
+        CAPACITY,
+    ));
+    let sut = memory.place::<RelocatableQueue<u32>>(CAPACITY);
+    for n in 0..CAPACITY as u32 + 2 {
+        unsafe { sut.push_with_overflow(n) };
+    }
+
+    let mut relocated = memory.map_at_new_location();
+    drop(memory);
+
+    let sut = unsafe { relocated.get_mut::<RelocatableQueue<u32>>() };
+    assert_that!(sut.len(), eq CAPACITY);
+    for n in 0..CAPACITY {
+        assert_that!(sut.get(n), eq n as u32 + 2);
+    }
+    for n in 2..CAPACITY as u32 + 2 {
+        assert_that!(unsafe { sut.pop() }, eq Some(n));
+    }
+    assert_that!(unsafe { sut.pop() }, is_none);
+}
@@ -0,0 +1,48 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_container::string::*;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing_macros::test;
+
+use crate::shared_memory::SharedMemory;
+
+const CAPACITY: usize = 16;
+
+#[test]
+pub fn string_can_be_filled_to_capacity() {
+    let mut memory = SharedMemory::new(SharedMemory::required_size::<RelocatableString>(CAPACITY));
+    memory.fill(0xff);
+    let sut = memory.place::<RelocatableString>(CAPACITY);
+
+    assert_that!(sut.push_bytes(b"all glory to the"), is_ok);
+    assert_that!(sut.push(b'x'), is_err);
+    assert_that!(sut.as_bytes(), eq b"all glory to the");
+    assert_that!(sut.as_bytes_with_nul()[CAPACITY], eq 0);
+}
+
+#[test]
+pub fn string_can_be_used_after_relocation() {
+    let mut memory = SharedMemory::new(SharedMemory::required_size::<RelocatableString>(CAPACITY));
+    let sut = memory.place::<RelocatableString>(CAPACITY);
+    assert_that!(sut.push_bytes(b"hypnotoad"), is_ok);
+
+    let mut relocated = memory.map_at_new_location();
+    drop(memory);
+
+    let sut = unsafe { relocated.get_mut::<RelocatableString>() };
+    assert_that!(sut.as_bytes(), eq b"hypnotoad");
+    assert_that!(sut.insert_bytes(0, b"all "), is_ok);
+    assert_that!(sut.remove(4), eq Some(b'h'));
+    assert_that!(sut.as_bytes(), eq b"all ypnotoad");
+    assert_that!(sut.as_bytes_with_nul()[sut.len()], eq 0);
+}
@@ -0,0 +1,82 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_container::vector::*;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing_macros::test;
+
+use crate::shared_memory::SharedMemory;
+
+const CAPACITY: usize = 9;
+
+#[test]
+pub fn vec_can_be_filled_and_emptied() {
+    let mut memory =
+        SharedMemory::new(SharedMemory::required_size::<RelocatableVec<u64>>(CAPACITY));
+    let sut = memory.place::<RelocatableVec<u64>>(CAPACITY);
+
+    for n in 0..CAPACITY as u64 {
+        assert_that!(sut.push(n), is_ok);
+    }
+    assert_that!(sut.push(0), is_err);
+    assert_that!(sut.as_slice(), eq(0..CAPACITY as u64).collect::<Vec<_>>());
+
+    for n in (0..CAPACITY as u64).rev() {
+        assert_that!(sut.pop(), eq Some(n));
+    }
+    assert_that!(sut.pop(), is_none);
+}
+
+#[test]
+pub fn vec_can_be_used_after_relocation() {
+    let mut memory =
+        SharedMemory::new(SharedMemory::required_size::<RelocatableVec<u64>>(CAPACITY));
+    let sut = memory.place::<RelocatableVec<u64>>(CAPACITY);
+    for n in 0..4 {
+        assert_that!(sut.push(n * 11), is_ok);
+    }
+
+    let mut relocated = memory.map_at_new_location();
+    drop(memory);
+
+    let sut = unsafe { relocated.get_mut::<RelocatableVec<u64>>() };
+    assert_that!(sut.as_slice(), eq [0, 11, 22, 33]);
+    assert_that!(sut.insert(1, 5), is_ok);
+    sut.remove(0);
+    for n in sut.len()..CAPACITY {
+        assert_that!(sut.push(n as u64), is_ok);
+    }
+    assert_that!(sut.as_slice(), eq [5, 11, 22, 33, 4, 5, 6, 7, 8]);
+}
+
+#[test]
+pub fn vec_with_unaligned_element_size_can_be_used_after_relocation() {
+    type Element = [u8; 3];
+    let mut memory = SharedMemory::new(SharedMemory::required_size::<RelocatableVec<Element>>(
+        CAPACITY,
+    ));
+    let sut = memory.place::<RelocatableVec<Element>>(CAPACITY);
+    for n in 0..CAPACITY as u8 {
+        assert_that!(sut.push([n, n + 1, n + 2]), is_ok);
+    }
+
+    let mut relocated = memory.map_at_new_location();
+    drop(memory);
+
+    let sut = unsafe { relocated.get_mut::<RelocatableVec<Element>>() };
+    for (n, element) in sut.iter().enumerate() {
+        let n = n as u8;
+        assert_that!(*element, eq[n, n + 1, n + 2]);
+    }
+    sut.clear();
+    assert_that!(sut.is_empty(), eq true);
+}
@@ -0,0 +1,114 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::mem::MaybeUninit;
+use core::ptr::NonNull;
+use std::alloc::Layout;
+
+use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
+use iceoryx2_bb_elementary::math::align;
+use iceoryx2_bb_elementary_traits::relocatable_container::RelocatableContainer;
+
+const ALIGNMENT: usize = 16;
+
+/// Heap memory that behaves like a shared memory segment. As with memory that is received
+/// from the operating system, its provenance is exposed, so that the relocatable constructs
+/// can access it via the distance they have stored. [`SharedMemory::map_at_new_location()`]
+/// copies the content into a new segment like a second process that maps the same shared
+/// memory at a different address.
+#[derive(Debug)]
+pub struct SharedMemory {
+    ptr: NonNull<u8>,
+    layout: Layout,
+}
+
+impl Drop for SharedMemory {
+    fn drop(&mut self) {
+        unsafe { std::alloc::dealloc(self.ptr.as_ptr(), self.layout) };
+    }
+}
+
+impl SharedMemory {
+    pub fn new(size: usize) -> Self {
+        let layout = Layout::from_size_align(size, ALIGNMENT).unwrap();
+        let ptr = NonNull::new(unsafe { std::alloc::alloc_zeroed(layout) }).unwrap();
+        let _ = ptr.as_ptr().expose_provenance();
+
+        Self { ptr, layout }
+    }
+
+    /// Returns the number of bytes a [`RelocatableContainer`] with the provided capacity
+    /// requires when it is placed with [`SharedMemory::place()`].
+    pub fn required_size<C: RelocatableContainer>(capacity: usize) -> usize {
+        Self::data_offset::<C>() + C::memory_size(capacity)
+    }
+
+    /// Creates a new segment with a different address and copies the content into it.
+    pub fn map_at_new_location(&self) -> Self {
+        let new_location = Self::new(self.layout.size());
+        unsafe {
+            core::ptr::copy_nonoverlapping(
+                self.ptr.as_ptr(),
+                new_location.ptr.as_ptr(),
+                self.layout.size(),
+            )
+        };
+        new_location
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        unsafe { core::slice::from_raw_parts(self.ptr.as_ptr(), self.layout.size()) }
+    }
+
+    pub fn as_uninit_slice_mut(&mut self) -> &mut [MaybeUninit<u8>] {
+        unsafe { core::slice::from_raw_parts_mut(self.ptr.as_ptr().cast(), self.layout.size()) }
+    }
+
+    pub fn fill(&mut self, value: u8) {
+        unsafe { core::ptr::write_bytes(self.ptr.as_ptr(), value, self.layout.size()) };
+    }
+
+    /// Constructs the container at the start of the segment and places its data directly
+    /// behind it.
+    pub fn place<C: RelocatableContainer>(&mut self, capacity: usize) -> &mut C {
+        let data_offset = Self::data_offset::<C>();
+        assert!(Self::required_size::<C>(capacity) <= self.layout.size());
+
+        let container_ptr = self.ptr.as_ptr().cast::<C>();
+        unsafe { container_ptr.write(C::new_uninit(capacity)) };
+        let allocator = BumpAllocator::new(
+            unsafe { NonNull::new_unchecked(self.ptr.as_ptr().add(data_offset)) },
+            self.layout.size() - data_offset,
+        );
+        unsafe {
+            (*container_ptr)
+                .init(&allocator)
+                .expect("The segment is large enough.")
+        };
+
+        unsafe { &mut *container_ptr }
+    }
+
+    /// Returns the container that was placed with [`SharedMemory::place()`].
+    ///
+    /// # Safety
+    ///
+    ///  * the container must have been placed with [`SharedMemory::place()`] in this or
+    ///    the original segment
+    pub unsafe fn get_mut<C: RelocatableContainer>(&mut self) -> &mut C {
+        unsafe { &mut *self.ptr.as_ptr().cast::<C>() }
+    }
+
+    fn data_offset<C>() -> usize {
+        align(core::mem::size_of::<C>(), ALIGNMENT)
+    }
+}
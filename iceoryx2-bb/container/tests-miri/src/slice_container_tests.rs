@@ -0,0 +1,87 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_container::queue::RelocatableQueue;
+use iceoryx2_bb_container::slice_container::*;
+use iceoryx2_bb_container::string::*;
+use iceoryx2_bb_container::vector::*;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing_macros::test;
+
+use crate::shared_memory::SharedMemory;
+
+const CAPACITY: usize = 7;
+
+#[test]
+pub fn vec_in_slice_can_be_viewed_after_relocation() {
+    let mut memory = SharedMemory::new(RelocatableVec::<u16>::required_slice_len(CAPACITY));
+    let sut =
+        RelocatableVec::<u16>::create_in_slice(memory.as_uninit_slice_mut(), CAPACITY).unwrap();
+    for n in 0..CAPACITY as u16 {
+        assert_that!(sut.push(n * 3), is_ok);
+    }
+
+    let relocated = memory.map_at_new_location();
+    drop(memory);
+
+    let view = RelocatableVec::<u16>::view_in_slice(relocated.as_slice()).unwrap();
+    for n in 0..CAPACITY {
+        assert_that!(view[n], eq n as u16 * 3);
+    }
+}
+
+#[test]
+pub fn string_in_slice_can_be_viewed_after_relocation() {
+    let mut memory = SharedMemory::new(RelocatableString::required_slice_len(CAPACITY));
+    let sut = RelocatableString::create_in_slice(memory.as_uninit_slice_mut(), CAPACITY).unwrap();
+    assert_that!(sut.push_bytes(b"fuu"), is_ok);
+
+    let relocated = memory.map_at_new_location();
+    drop(memory);
+
+    let view = RelocatableString::view_in_slice(relocated.as_slice()).unwrap();
+    assert_that!(view.as_bytes(), eq b"fuu");
+}
+
+#[test]
+pub fn queue_in_slice_can_be_viewed_after_relocation() {
+    let mut memory = SharedMemory::new(RelocatableQueue::<u64>::required_slice_len(CAPACITY));
+    let sut =
+        RelocatableQueue::<u64>::create_in_slice(memory.as_uninit_slice_mut(), CAPACITY).unwrap();
+    for n in 0..CAPACITY as u64 + 3 {
+        unsafe { sut.push_with_overflow(n) };
+    }
+
+    let relocated = memory.map_at_new_location();
+    drop(memory);
+
+    let view = RelocatableQueue::<u64>::view_in_slice(relocated.as_slice()).unwrap();
+    assert_that!(view.peek(), eq Some(&3));
+    assert_that!(view.get(CAPACITY - 1), eq CAPACITY as u64 + 2);
+}
+
+#[test]
+pub fn view_of_truncated_slice_does_not_access_memory_out_of_bounds() {
+    let mut memory = SharedMemory::new(RelocatableVec::<u64>::required_slice_len(CAPACITY));
+    assert_that!(
+        RelocatableVec::<u64>::create_in_slice(memory.as_uninit_slice_mut(), CAPACITY),
+        is_ok
+    );
+
+    let len = memory.as_slice().len();
+    for truncated_len in [0, 8, 16, len / 2, len - 1] {
+        assert_that!(
+            RelocatableVec::<u64>::view_in_slice(&memory.as_slice()[..truncated_len]),
+            is_err
+        );
+    }
+}
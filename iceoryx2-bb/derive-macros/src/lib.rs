@@ -21,6 +21,7 @@ extern crate alloc;
 extern crate proc_macro;
 
 use alloc::format;
+use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 
 use proc_macro::TokenStream;
@@ -114,6 +115,13 @@ pub fn placement_default_derive(input: TokenStream) -> TokenStream {
 /// the struct implement it and the struct is annotated with `repr(C)`. A type name can be optionally
 /// set with the helper attribute `type_name`.
 ///
+/// The `TYPE_LAYOUT_HASH` is computed at compile-time from the names, the offsets, the sizes and
+/// the alignments of all fields as well as the layout hashes of the field types. Every primitive
+/// type has its own layout hash, therefore changing a field from `u64` to `f64` changes the hash.
+/// How a field type is spelled, for instance via a type alias or a fully qualified path, does not
+/// affect the hash. Offsets of enum variant fields are not part of the hash since they cannot be
+/// determined at compile-time, the size of the enum is.
+///
 /// ```
 /// use iceoryx2_bb_derive_macros::ZeroCopySend;
 /// use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
@@ -339,8 +347,12 @@ pub fn zero_copy_send_derive(input: TokenStream) -> TokenStream {
         }
     };
 
+    let type_layout_hash_impl = type_layout_hash_impl(&ast.data);
+
     let expanded = quote! {
         unsafe impl #impl_generics ZeroCopySend for #struct_name #ty_generics #where_clause {
+            #type_layout_hash_impl
+
             #zero_copy_send_impl
         }
     };
@@ -348,6 +360,378 @@ pub fn zero_copy_send_derive(input: TokenStream) -> TokenStream {
     TokenStream::from(expanded)
 }
 
+// Generates the FNV-1a hash over the kind of the type, the name, offset, size, alignment and
+// layout hash of every field and the size and alignment of the type.
+fn type_layout_hash_impl(data: &Data) -> proc_macro2::TokenStream {
+    let hash_field = |name: String, offset: Option<proc_macro2::TokenStream>, ty: &syn::Type| {
+        let hash_offset = offset.map(|member| {
+            quote! { hash = __hash_u64(hash, core::mem::offset_of!(Self, #member) as u64); }
+        });
+
+        quote! {
+            hash = __hash_str(hash, #name);
+            #hash_offset
+            hash = __hash_u64(hash, core::mem::size_of::<#ty>() as u64);
+            hash = __hash_u64(hash, core::mem::align_of::<#ty>() as u64);
+            hash = __hash_u64(hash, <#ty as ZeroCopySend>::TYPE_LAYOUT_HASH);
+        }
+    };
+
+    let hash_fields = |fields: &Fields, with_offsets: bool| -> Vec<proc_macro2::TokenStream> {
+        fields
+            .iter()
+            .enumerate()
+            .map(|(i, f)| {
+                let (name, member) = match &f.ident {
+                    Some(ident) => (ident.to_string(), quote!(#ident)),
+                    None => {
+                        let index = syn::Index::from(i);
+                        (i.to_string(), quote!(#index))
+                    }
+                };
+                hash_field(name, with_offsets.then_some(member), &f.ty)
+            })
+            .collect()
+    };
+
+    let (kind, hash_content) = match data {
+        Data::Struct(data_struct) => {
+            let fields = hash_fields(&data_struct.fields, true);
+            ("struct", quote! { #(#fields)* })
+        }
+        Data::Enum(data_enum) => {
+            let variants = data_enum.variants.iter().map(|variant| {
+                let name = variant.ident.to_string();
+                let discriminant = variant
+                    .discriminant
+                    .as_ref()
+                    .map(|(_, expr)| quote!(#expr).to_string())
+                    .unwrap_or_default();
+                let fields = hash_fields(&variant.fields, false);
+                quote! {
+                    hash = __hash_str(hash, #name);
+                    hash = __hash_str(hash, #discriminant);
+                    #(#fields)*
+                }
+            });
+            ("enum", quote! { #(#variants)* })
+        }
+        Data::Union(data_union) => {
+            let fields = hash_fields(&Fields::Named(data_union.fields.clone()), true);
+            ("union", quote! { #(#fields)* })
+        }
+    };
+
+    quote! {
+        const TYPE_LAYOUT_HASH: u64 = {
+            const fn __hash_u64(mut hash: u64, value: u64) -> u64 {
+                let bytes = value.to_le_bytes();
+                let mut n = 0;
+                while n < bytes.len() {
+                    hash ^= bytes[n] as u64;
+                    hash = hash.wrapping_mul(0x100000001b3);
+                    n += 1;
+                }
+                hash
+            }
+
+            const fn __hash_str(mut hash: u64, value: &str) -> u64 {
+                let bytes = value.as_bytes();
+                hash = __hash_u64(hash, bytes.len() as u64);
+                let mut n = 0;
+                while n < bytes.len() {
+                    hash ^= bytes[n] as u64;
+                    hash = hash.wrapping_mul(0x100000001b3);
+                    n += 1;
+                }
+                hash
+            }
+
+            let mut hash: u64 = 0xcbf29ce484222325;
+            hash = __hash_str(hash, #kind);
+            #hash_content
+            hash = __hash_u64(hash, core::mem::size_of::<Self>() as u64);
+            hash = __hash_u64(hash, core::mem::align_of::<Self>() as u64);
+
+            if hash == 0 { 1 } else { hash }
+        };
+    }
+}
+
+/// Implements the [`iceoryx2_bb_elementary_traits::validate::Validate`] trait when all fields
+/// of the struct or enum implement it. Enums must be annotated with `#[repr(C)]` or a primitive
+/// representation like `#[repr(u8)]` so that the discriminant can be verified before the fields
+/// of the corresponding variant are validated. `Validate`, `ValidationBounds` and
+/// `ValidationError` must be in scope.
+///
+/// ```
+/// use iceoryx2_bb_derive_macros::{Validate, ZeroCopySend};
+/// use iceoryx2_bb_elementary_traits::validate::{Validate, ValidationBounds, ValidationError};
+/// use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+///
+/// #[repr(C)]
+/// #[derive(ZeroCopySend, Validate)]
+/// enum Shape {
+///     Point,
+///     Circle(f64),
+///     Rectangle { width: f64, height: f64 },
+/// }
+///
+/// #[repr(C)]
+/// #[derive(ZeroCopySend, Validate)]
+/// struct MyValidatedStruct {
+///     is_visible: bool,
+///     shape: Shape,
+/// }
+///
+/// let value = MyValidatedStruct {
+///     is_visible: true,
+///     shape: Shape::Circle(5.0),
+/// };
+/// let bounds = ValidationBounds::of_value(&value);
+/// assert_eq!(unsafe { MyValidatedStruct::validate(&value, &bounds) }, Ok(()));
+///
+/// // a bool with the value 2 is invalid
+/// let mut memory = core::mem::MaybeUninit::new(value);
+/// unsafe { memory.as_mut_ptr().cast::<u8>().write(2) };
+/// assert_eq!(
+///     unsafe { MyValidatedStruct::validate(memory.as_ptr(), &bounds) },
+///     Err(ValidationError::InvalidBitPattern)
+/// );
+/// ```
+#[proc_macro_derive(Validate)]
+pub fn validate_derive(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    // like the derives of the standard library, every type parameter must implement the trait
+    let mut validate_generics = ast.generics.clone();
+    for type_param in validate_generics.type_params_mut() {
+        type_param.bounds.push(syn::parse_quote!(Validate));
+    }
+    let (validate_impl_generics, _, _) = validate_generics.split_for_impl();
+
+    let expanded = match ast.data {
+        Data::Struct(ref data_struct) => {
+            let (field_accessors, field_types): (Vec<_>, Vec<_>) = match data_struct.fields {
+                Fields::Named(ref fields_named) => fields_named
+                    .named
+                    .iter()
+                    .map(|field| {
+                        let ident = &field.ident;
+                        (quote! { #ident }, &field.ty)
+                    })
+                    .unzip(),
+                Fields::Unnamed(ref fields_unnamed) => fields_unnamed
+                    .unnamed
+                    .iter()
+                    .enumerate()
+                    .map(|(i, field)| {
+                        let index = syn::Index::from(i);
+                        (quote! { #index }, &field.ty)
+                    })
+                    .unzip(),
+                Fields::Unit => (Vec::new(), Vec::new()),
+            };
+
+            quote! {
+                unsafe impl #validate_impl_generics Validate for #name #ty_generics #where_clause {
+                    unsafe fn validate(
+                        ptr: *const Self,
+                        bounds: &ValidationBounds,
+                    ) -> Result<(), ValidationError> {
+                        #(unsafe {
+                            <#field_types as Validate>::validate(
+                                core::ptr::addr_of!((*ptr).#field_accessors),
+                                bounds,
+                            )?;
+                        })*
+                        Ok(())
+                    }
+                }
+            }
+        }
+        Data::Enum(ref data_enum) => {
+            const PRIMITIVE_REPRESENTATIONS: [&str; 10] = [
+                "u8", "u16", "u32", "u64", "usize", "i8", "i16", "i32", "i64", "isize",
+            ];
+
+            // The layout of enums with a `repr(C)` or primitive representation is defined in
+            // RFC 2195. The types below mirror this layout so that the offsets of the fields of
+            // every variant can be acquired with `core::mem::offset_of!`.
+            let mut is_repr_c = false;
+            let mut tag_repr = None;
+            for attribute in ast.attrs.iter().filter(|a| a.path().is_ident("repr")) {
+                let _ = attribute.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("C") {
+                        is_repr_c = true;
+                    } else if let Some(ident) = meta.path.get_ident() {
+                        if ident == "u128" || ident == "i128" {
+                            panic!(
+                                "`#[derive(Validate)]` does not support enums with a 128-bit representation"
+                            );
+                        }
+                        if PRIMITIVE_REPRESENTATIONS.iter().any(|r| ident == r) {
+                            tag_repr = Some(ident.clone());
+                        }
+                    }
+                    Ok(())
+                });
+            }
+
+            // the tag of a `repr(C, u8)` enum is a `repr(u8)` enum
+            let tag_repr = match (is_repr_c, tag_repr) {
+                (_, Some(tag_repr)) => quote! { #[repr(#tag_repr)] },
+                (true, None) => quote! { #[repr(C)] },
+                (false, None) => panic!(
+                    "`#[derive(Validate)]` requires the enum to be annotated with #[repr(C)] or a primitive representation like #[repr(u8)]"
+                ),
+            };
+
+            let tag_variants = data_enum.variants.iter().map(|variant| {
+                let ident = &variant.ident;
+                match &variant.discriminant {
+                    Some((_, discriminant)) => quote! { #ident = #discriminant },
+                    None => quote! { #ident },
+                }
+            });
+
+            let phantom = quote! { __phantom: core::marker::PhantomData<#name #ty_generics> };
+            let mut variant_types = Vec::new();
+            let mut payload_fields = Vec::new();
+            let mut variant_checks = Vec::new();
+            for (n, variant) in data_enum.variants.iter().enumerate() {
+                let variant_ident = &variant.ident;
+                let variant_type =
+                    syn::Ident::new(&format!("__{name}ValidateVariant{n}"), name.span());
+                let payload_field = syn::Ident::new(&format!("v{n}"), name.span());
+
+                let (field_idents, field_types): (Vec<_>, Vec<_>) = variant
+                    .fields
+                    .iter()
+                    .enumerate()
+                    .map(|(i, field)| {
+                        (
+                            syn::Ident::new(&format!("field_{i}"), name.span()),
+                            &field.ty,
+                        )
+                    })
+                    .unzip();
+
+                let variant_offset = if is_repr_c {
+                    quote! {
+                        core::mem::offset_of!(__Repr #ty_generics, __payload)
+                    }
+                } else {
+                    quote! { 0 }
+                };
+
+                let tag_field = if is_repr_c {
+                    quote! {}
+                } else {
+                    quote! { __tag: __Tag, }
+                };
+
+                variant_types.push(quote! {
+                    #[repr(C)]
+                    struct #variant_type #impl_generics #where_clause {
+                        #tag_field
+                        #(#field_idents: #field_types,)*
+                        #phantom
+                    }
+                });
+                payload_fields.push(quote! {
+                    #payload_field: core::mem::ManuallyDrop<#variant_type #ty_generics>
+                });
+                variant_checks.push(quote! {
+                    if raw_tag == tag_bits(__Tag::#variant_ident) {
+                        #(unsafe {
+                            let offset = #variant_offset
+                                + core::mem::offset_of!(#variant_type #ty_generics, #field_idents);
+                            <#field_types as Validate>::validate(
+                                ptr.cast::<u8>().add(offset).cast::<#field_types>(),
+                                bounds,
+                            )?;
+                        })*
+                        return Ok(());
+                    }
+                });
+            }
+
+            let repr_c_types = if is_repr_c && !data_enum.variants.is_empty() {
+                quote! {
+                    #[repr(C)]
+                    union __Payload #impl_generics #where_clause {
+                        #(#payload_fields,)*
+                    }
+
+                    #[repr(C)]
+                    struct __Repr #impl_generics #where_clause {
+                        __tag: __Tag,
+                        __payload: __Payload #ty_generics,
+                    }
+                }
+            } else {
+                quote! {}
+            };
+
+            quote! {
+                const _: () = {
+                    #tag_repr
+                    #[derive(Clone, Copy)]
+                    #[allow(dead_code)]
+                    enum __Tag {
+                        #(#tag_variants,)*
+                    }
+
+                    #(#[allow(dead_code, non_camel_case_types)] #variant_types)*
+
+                    #[allow(dead_code)]
+                    #repr_c_types
+
+                    unsafe impl #validate_impl_generics Validate for #name #ty_generics #where_clause {
+                        unsafe fn validate(
+                            ptr: *const Self,
+                            bounds: &ValidationBounds,
+                        ) -> Result<(), ValidationError> {
+                            // the tag is not read as __Tag since an invalid discriminant would
+                            // already be undefined behavior
+                            let tag_bits = |tag: __Tag| -> u64 {
+                                match core::mem::size_of::<__Tag>() {
+                                    1 => tag as u8 as u64,
+                                    2 => tag as u16 as u64,
+                                    4 => tag as u32 as u64,
+                                    _ => tag as u64,
+                                }
+                            };
+                            let raw_tag = unsafe {
+                                match core::mem::size_of::<__Tag>() {
+                                    1 => ptr.cast::<u8>().read() as u64,
+                                    2 => ptr.cast::<u16>().read() as u64,
+                                    4 => ptr.cast::<u32>().read() as u64,
+                                    _ => ptr.cast::<u64>().read(),
+                                }
+                            };
+
+                            #(#variant_checks)*
+
+                            Err(ValidationError::InvalidBitPattern)
+                        }
+                    }
+                };
+            }
+        }
+        Data::Union(_) => {
+            panic!(
+                "`#[derive(Validate)]` is not implemented for unions since the active field is unknown."
+            );
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
 /// Implements the [`iceoryx2_bb_elementary_traits::atomic_copy::AtomicCopy`] trait for structs
 /// when all fields of the struct implement it and the struct implements `Copy`.
 ///
@@ -631,3 +1015,6 @@ mod plain_old_data_without_padding_compile_tests;
 
 #[cfg(doctest)]
 mod atomic_copy_compile_tests;
+
+#[cfg(doctest)]
+mod validate_compile_tests;
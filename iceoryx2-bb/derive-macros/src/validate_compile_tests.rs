@@ -0,0 +1,80 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+/// ``` compile_fail
+/// use iceoryx2_bb_derive_macros::Validate;
+/// use iceoryx2_bb_elementary_traits::validate::{Validate, ValidationBounds, ValidationError};
+///
+/// #[derive(Validate)]
+/// union MyUnion {
+///     a: u32,
+///     b: u64,
+/// }
+/// ```
+#[cfg(doctest)]
+fn validate_derive_does_not_work_for_unions() {}
+
+/// ``` compile_fail
+/// use iceoryx2_bb_derive_macros::Validate;
+/// use iceoryx2_bb_elementary_traits::validate::{Validate, ValidationBounds, ValidationError};
+///
+/// #[derive(Validate)]
+/// enum Color {
+///     Red,
+///     Blue,
+/// }
+/// ```
+#[cfg(doctest)]
+fn validate_derive_does_not_work_for_enums_without_defined_representation() {}
+
+/// ``` compile_fail
+/// use iceoryx2_bb_derive_macros::Validate;
+/// use iceoryx2_bb_elementary_traits::validate::{Validate, ValidationBounds, ValidationError};
+///
+/// #[repr(u128)]
+/// #[derive(Validate)]
+/// enum Color {
+///     Red,
+///     Blue,
+/// }
+/// ```
+#[cfg(doctest)]
+fn validate_derive_does_not_work_for_enums_with_128_bit_representation() {}
+
+/// ``` compile_fail
+/// use iceoryx2_bb_derive_macros::Validate;
+/// use iceoryx2_bb_elementary_traits::validate::{Validate, ValidationBounds, ValidationError};
+///
+/// struct NotValidatable(u8);
+///
+/// #[derive(Validate)]
+/// struct MyStruct {
+///     value: NotValidatable,
+/// }
+/// ```
+#[cfg(doctest)]
+fn validate_derive_does_not_work_when_not_all_fields_implement_it() {}
+
+/// ``` compile_fail
+/// use iceoryx2_bb_derive_macros::Validate;
+/// use iceoryx2_bb_elementary_traits::validate::{Validate, ValidationBounds, ValidationError};
+///
+/// struct NotValidatable(u8);
+///
+/// #[repr(C)]
+/// #[derive(Validate)]
+/// enum MyEnum {
+///     Value(NotValidatable),
+/// }
+/// ```
+#[cfg(doctest)]
+fn validate_derive_does_not_work_when_not_all_variant_fields_implement_it() {}
@@ -0,0 +1,283 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::mem::MaybeUninit;
+
+use iceoryx2_bb_derive_macros::Validate;
+use iceoryx2_bb_elementary_traits::validate::{Validate, ValidationBounds, ValidationError};
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing_macros::test;
+
+fn validate<T: Validate>(value: &T) -> Result<(), ValidationError> {
+    unsafe { T::validate(value, &ValidationBounds::of_value(value)) }
+}
+
+fn validate_with_byte<T: Validate, F: Fn(&T) -> *const u8>(
+    value: T,
+    byte: u8,
+    location: F,
+) -> Result<(), ValidationError> {
+    let offset = location(&value) as usize - (&value as *const T) as usize;
+    let mut memory = MaybeUninit::new(value);
+    unsafe { memory.as_mut_ptr().cast::<u8>().add(offset).write(byte) };
+
+    let bounds = ValidationBounds::new(memory.as_ptr().cast(), core::mem::size_of::<T>());
+    unsafe { T::validate(memory.as_ptr(), &bounds) }
+}
+
+#[allow(dead_code)]
+#[derive(Validate)]
+struct NamedTestStruct {
+    value1: u64,
+    value2: bool,
+    value3: [char; 2],
+}
+
+#[allow(dead_code)]
+#[derive(Validate)]
+struct UnnamedTestStruct(u32, bool);
+
+#[allow(dead_code)]
+#[derive(Validate)]
+struct GenericTestStruct<T: Validate, const N: usize> {
+    values: [T; N],
+}
+
+#[derive(Validate)]
+struct UnitTestStruct;
+
+#[allow(dead_code)]
+#[repr(C)]
+#[derive(Validate)]
+enum ReprCEnum {
+    Empty,
+    Unnamed(u64, bool),
+    Named { value: u16, flag: bool },
+}
+
+#[allow(dead_code)]
+#[repr(u8)]
+#[derive(Validate)]
+enum ReprU8Enum {
+    Empty,
+    Unnamed(u64, bool),
+    Named { value: u16, flag: bool },
+}
+
+#[allow(dead_code)]
+#[repr(C, u16)]
+#[derive(Validate)]
+enum ReprCU16Enum {
+    Empty,
+    Unnamed(u8, bool),
+}
+
+#[repr(u16)]
+#[derive(Validate, Clone, Copy)]
+enum FieldlessEnum {
+    First = 3,
+    Second = 7,
+    Third,
+}
+
+#[allow(dead_code)]
+#[repr(C)]
+#[derive(Validate)]
+enum GenericEnum<T> {
+    Nothing,
+    Something(T),
+}
+
+#[allow(dead_code)]
+#[derive(Validate)]
+struct NestedTestStruct {
+    inner: NamedTestStruct,
+    state: ReprU8Enum,
+}
+
+#[test]
+fn validate_derive_for_named_struct_works() {
+    let sut = NamedTestStruct {
+        value1: 123,
+        value2: true,
+        value3: ['a', 'b'],
+    };
+    assert_that!(validate(&sut), eq Ok(()));
+    assert_that!(sut.value1, eq 123);
+
+    let sut = NamedTestStruct {
+        value1: 123,
+        value2: true,
+        value3: ['a', 'b'],
+    };
+    assert_that!(validate_with_byte(sut, 2, |v| (&v.value2 as *const bool).cast()),
+                 eq Err(ValidationError::InvalidBitPattern));
+}
+
+#[test]
+fn validate_derive_detects_invalid_char_in_array() {
+    let sut = NamedTestStruct {
+        value1: 0,
+        value2: false,
+        value3: ['a', 'b'],
+    };
+    // 0x00d8_0000 is a surrogate and therefore no valid char
+    assert_that!(validate_with_byte(sut, 0xd8, |v| unsafe { (&v.value3[1] as *const char).cast::<u8>().add(1) }),
+                 eq Err(ValidationError::InvalidBitPattern));
+}
+
+#[test]
+fn validate_derive_for_unnamed_struct_works() {
+    assert_that!(validate(&UnnamedTestStruct(1, false)), eq Ok(()));
+    assert_that!(validate_with_byte(UnnamedTestStruct(1, false), 255, |v| (&v.1 as *const bool).cast()),
+                 eq Err(ValidationError::InvalidBitPattern));
+}
+
+#[test]
+fn validate_derive_for_generic_and_unit_struct_works() {
+    let sut = GenericTestStruct::<bool, 3> {
+        values: [true, false, true],
+    };
+    assert_that!(validate(&sut), eq Ok(()));
+    assert_that!(validate(&UnitTestStruct), eq Ok(()));
+
+    let sut = GenericTestStruct::<bool, 3> {
+        values: [true, false, true],
+    };
+    assert_that!(validate_with_byte(sut, 3, |v| (&v.values[2] as *const bool).cast()),
+                 eq Err(ValidationError::InvalidBitPattern));
+}
+
+#[test]
+fn validate_derive_for_repr_c_enum_accepts_all_variants() {
+    assert_that!(validate(&ReprCEnum::Empty), eq Ok(()));
+    assert_that!(validate(&ReprCEnum::Unnamed(12, true)), eq Ok(()));
+    assert_that!(validate(&ReprCEnum::Named { value: 4, flag: false }), eq Ok(()));
+}
+
+#[test]
+fn validate_derive_for_repr_c_enum_detects_invalid_discriminant() {
+    assert_that!(validate_with_byte(ReprCEnum::Empty, 3, |v| (v as *const ReprCEnum).cast()),
+                 eq Err(ValidationError::InvalidBitPattern));
+}
+
+#[test]
+fn validate_derive_for_repr_c_enum_detects_invalid_variant_field() {
+    let location = |v: &ReprCEnum| match v {
+        ReprCEnum::Unnamed(_, flag) => (flag as *const bool).cast(),
+        ReprCEnum::Named { flag, .. } => (flag as *const bool).cast(),
+        ReprCEnum::Empty => core::ptr::null(),
+    };
+
+    assert_that!(validate_with_byte(ReprCEnum::Unnamed(12, true), 2, location),
+                 eq Err(ValidationError::InvalidBitPattern));
+    assert_that!(validate_with_byte(ReprCEnum::Named { value: 1, flag: true }, 2, location),
+                 eq Err(ValidationError::InvalidBitPattern));
+}
+
+#[test]
+fn validate_derive_for_repr_u8_enum_works() {
+    let location = |v: &ReprU8Enum| match v {
+        ReprU8Enum::Unnamed(_, flag) => (flag as *const bool).cast(),
+        ReprU8Enum::Named { flag, .. } => (flag as *const bool).cast(),
+        ReprU8Enum::Empty => (v as *const ReprU8Enum).cast(),
+    };
+
+    assert_that!(validate(&ReprU8Enum::Empty), eq Ok(()));
+    assert_that!(validate(&ReprU8Enum::Unnamed(12, true)), eq Ok(()));
+    assert_that!(validate(&ReprU8Enum::Named { value: 4, flag: false }), eq Ok(()));
+
+    assert_that!(validate_with_byte(ReprU8Enum::Empty, 3, location),
+                 eq Err(ValidationError::InvalidBitPattern));
+    assert_that!(validate_with_byte(ReprU8Enum::Unnamed(12, true), 2, location),
+                 eq Err(ValidationError::InvalidBitPattern));
+    assert_that!(validate_with_byte(ReprU8Enum::Named { value: 1, flag: true }, 2, location),
+                 eq Err(ValidationError::InvalidBitPattern));
+}
+
+#[test]
+fn validate_derive_for_repr_c_with_primitive_enum_works() {
+    let location = |v: &ReprCU16Enum| match v {
+        ReprCU16Enum::Unnamed(_, flag) => (flag as *const bool).cast(),
+        ReprCU16Enum::Empty => (v as *const ReprCU16Enum).cast(),
+    };
+
+    assert_that!(core::mem::size_of::<ReprCU16Enum>(), eq 4);
+    assert_that!(validate(&ReprCU16Enum::Empty), eq Ok(()));
+    assert_that!(validate(&ReprCU16Enum::Unnamed(1, true)), eq Ok(()));
+    assert_that!(validate_with_byte(ReprCU16Enum::Empty, 2, location),
+                 eq Err(ValidationError::InvalidBitPattern));
+    assert_that!(validate_with_byte(ReprCU16Enum::Unnamed(1, true), 2, location),
+                 eq Err(ValidationError::InvalidBitPattern));
+}
+
+#[test]
+fn validate_derive_for_fieldless_enum_respects_explicit_discriminants() {
+    let sut = FieldlessEnum::First;
+    assert_that!(validate(&sut), eq Ok(()));
+    assert_that!(validate(&FieldlessEnum::Second), eq Ok(()));
+    assert_that!(validate(&FieldlessEnum::Third), eq Ok(()));
+
+    let raw = |discriminant: u16| -> Result<(), ValidationError> {
+        let bounds = ValidationBounds::of_value(&discriminant);
+        unsafe {
+            FieldlessEnum::validate(
+                (&discriminant as *const u16).cast::<FieldlessEnum>(),
+                &bounds,
+            )
+        }
+    };
+
+    assert_that!(raw(3), eq Ok(()));
+    assert_that!(raw(7), eq Ok(()));
+    assert_that!(raw(8), eq Ok(()));
+    assert_that!(raw(0), eq Err(ValidationError::InvalidBitPattern));
+    assert_that!(raw(4), eq Err(ValidationError::InvalidBitPattern));
+    assert_that!(raw(9), eq Err(ValidationError::InvalidBitPattern));
+}
+
+#[test]
+fn validate_derive_for_generic_enum_works() {
+    assert_that!(validate(&GenericEnum::<bool>::Nothing), eq Ok(()));
+    assert_that!(validate(&GenericEnum::Something(true)), eq Ok(()));
+
+    let location = |v: &GenericEnum<bool>| match v {
+        GenericEnum::Something(flag) => (flag as *const bool).cast(),
+        GenericEnum::Nothing => (v as *const GenericEnum<bool>).cast(),
+    };
+    assert_that!(validate_with_byte(GenericEnum::Something(true), 2, location),
+                 eq Err(ValidationError::InvalidBitPattern));
+}
+
+#[test]
+fn validate_derive_for_nested_types_validates_inner_types() {
+    let sut = NestedTestStruct {
+        inner: NamedTestStruct {
+            value1: 1,
+            value2: true,
+            value3: ['x', 'y'],
+        },
+        state: ReprU8Enum::Unnamed(1, false),
+    };
+    assert_that!(validate(&sut), eq Ok(()));
+
+    let sut = NestedTestStruct {
+        inner: NamedTestStruct {
+            value1: 1,
+            value2: true,
+            value3: ['x', 'y'],
+        },
+        state: ReprU8Enum::Unnamed(1, false),
+    };
+    assert_that!(validate_with_byte(sut, 10, |v| (&v.state as *const ReprU8Enum).cast()),
+                 eq Err(ValidationError::InvalidBitPattern));
+}
@@ -186,3 +186,172 @@ pub fn for_unions() {
     assert_that!(is_zero_copy_send(&sut), eq true);
     assert_that!(unsafe { BasicUnionTest::type_name() }, eq "TryMadHoney");
 }
+
+#[allow(dead_code)]
+mod layout_v1 {
+    use super::*;
+
+    #[repr(C)]
+    #[derive(ZeroCopySend)]
+    pub struct Inner {
+        pub a: u32,
+        pub b: u32,
+    }
+
+    #[repr(C)]
+    #[derive(ZeroCopySend)]
+    pub struct Message {
+        pub id: u64,
+        pub inner: [Inner; 2],
+    }
+
+    #[repr(C)]
+    #[derive(ZeroCopySend)]
+    pub enum Shape {
+        Point,
+        Circle(f64),
+    }
+}
+
+#[allow(dead_code)]
+mod layout_v1_copy {
+    use super::*;
+
+    #[repr(C)]
+    #[derive(ZeroCopySend)]
+    pub struct Inner {
+        pub a: u32,
+        pub b: u32,
+    }
+
+    #[repr(C)]
+    #[derive(ZeroCopySend)]
+    pub struct Message {
+        pub id: u64,
+        pub inner: [Inner; 2],
+    }
+}
+
+#[allow(dead_code)]
+mod layout_v1_respelled {
+    use super::*;
+
+    type Identifier = u64;
+
+    #[repr(C)]
+    #[derive(ZeroCopySend)]
+    pub struct Message {
+        pub id: Identifier,
+        pub inner: [layout_v1::Inner; 2],
+    }
+
+    #[repr(C)]
+    #[derive(ZeroCopySend)]
+    pub struct Inner {
+        pub a: core::primitive::u32,
+        pub b: u32,
+    }
+}
+
+#[allow(dead_code)]
+mod layout_v2 {
+    use super::*;
+
+    // fields of the nested type are swapped
+    #[repr(C)]
+    #[derive(ZeroCopySend)]
+    pub struct Inner {
+        pub b: u32,
+        pub a: u32,
+    }
+
+    #[repr(C)]
+    #[derive(ZeroCopySend)]
+    pub struct Message {
+        pub id: u64,
+        pub inner: [Inner; 2],
+    }
+
+    #[repr(C)]
+    #[derive(ZeroCopySend)]
+    pub struct RenamedField {
+        pub identifier: u64,
+        pub inner: [layout_v1::Inner; 2],
+    }
+
+    #[repr(C)]
+    #[derive(ZeroCopySend)]
+    pub struct ChangedType {
+        pub id: i64,
+        pub inner: [layout_v1::Inner; 2],
+    }
+
+    #[repr(C)]
+    #[derive(ZeroCopySend)]
+    pub struct ChangedToFloat {
+        pub id: f64,
+        pub inner: [layout_v1::Inner; 2],
+    }
+
+    #[repr(C)]
+    #[derive(ZeroCopySend)]
+    pub enum Shape {
+        Circle(f64),
+        Point,
+    }
+}
+
+#[test]
+pub fn layout_hash_is_equal_for_identical_definitions() {
+    assert_that!(layout_v1::Message::TYPE_LAYOUT_HASH, ne 0);
+    assert_that!(layout_v1::Message::TYPE_LAYOUT_HASH, eq layout_v1_copy::Message::TYPE_LAYOUT_HASH);
+    assert_that!(layout_v1::Inner::TYPE_LAYOUT_HASH, eq layout_v1_copy::Inner::TYPE_LAYOUT_HASH);
+}
+
+#[test]
+pub fn layout_hash_does_not_depend_on_the_spelling_of_field_types() {
+    assert_that!(layout_v1::Message::TYPE_LAYOUT_HASH, eq layout_v1_respelled::Message::TYPE_LAYOUT_HASH);
+    assert_that!(layout_v1::Inner::TYPE_LAYOUT_HASH, eq layout_v1_respelled::Inner::TYPE_LAYOUT_HASH);
+}
+
+#[test]
+pub fn layout_hash_detects_reordered_nested_fields() {
+    assert_that!(layout_v1::Inner::TYPE_LAYOUT_HASH, ne layout_v2::Inner::TYPE_LAYOUT_HASH);
+    assert_that!(layout_v1::Message::TYPE_LAYOUT_HASH, ne layout_v2::Message::TYPE_LAYOUT_HASH);
+}
+
+#[test]
+pub fn layout_hash_detects_renamed_fields_and_changed_types() {
+    assert_that!(layout_v1::Message::TYPE_LAYOUT_HASH, ne layout_v2::RenamedField::TYPE_LAYOUT_HASH);
+    assert_that!(layout_v1::Message::TYPE_LAYOUT_HASH, ne layout_v2::ChangedType::TYPE_LAYOUT_HASH);
+    assert_that!(layout_v1::Message::TYPE_LAYOUT_HASH, ne layout_v2::ChangedToFloat::TYPE_LAYOUT_HASH);
+    assert_that!(layout_v2::ChangedType::TYPE_LAYOUT_HASH, ne layout_v2::ChangedToFloat::TYPE_LAYOUT_HASH);
+}
+
+#[test]
+pub fn layout_hash_detects_reordered_enum_variants() {
+    assert_that!(layout_v1::Shape::TYPE_LAYOUT_HASH, ne 0);
+    assert_that!(layout_v1::Shape::TYPE_LAYOUT_HASH, ne layout_v2::Shape::TYPE_LAYOUT_HASH);
+}
+
+#[test]
+pub fn layout_hash_of_generic_struct_depends_on_type_parameters() {
+    assert_that!(GenericNamedTestStruct::<u64, u32>::TYPE_LAYOUT_HASH, ne GenericNamedTestStruct::<u32, u64>::TYPE_LAYOUT_HASH);
+    assert_that!(GenericNamedTestStruct::<u64, layout_v1::Inner>::TYPE_LAYOUT_HASH, ne GenericNamedTestStruct::<u64, layout_v2::Inner>::TYPE_LAYOUT_HASH);
+    assert_that!(GenericNamedTestStruct::<u64, u32>::TYPE_LAYOUT_HASH, eq GenericNamedTestStruct::<u64, u32>::TYPE_LAYOUT_HASH);
+}
+
+#[test]
+pub fn layout_hash_of_manual_implementation_is_unknown() {
+    assert_that!(Foo::TYPE_LAYOUT_HASH, eq 0);
+}
+
+#[test]
+pub fn layout_hash_of_primitives_differs_for_types_with_equal_size() {
+    assert_that!(u64::TYPE_LAYOUT_HASH, ne 0);
+    assert_that!(u64::TYPE_LAYOUT_HASH, ne i64::TYPE_LAYOUT_HASH);
+    assert_that!(u64::TYPE_LAYOUT_HASH, ne f64::TYPE_LAYOUT_HASH);
+    assert_that!(i64::TYPE_LAYOUT_HASH, ne f64::TYPE_LAYOUT_HASH);
+    assert_that!(u32::TYPE_LAYOUT_HASH, ne f32::TYPE_LAYOUT_HASH);
+    assert_that!(u8::TYPE_LAYOUT_HASH, ne bool::TYPE_LAYOUT_HASH);
+}
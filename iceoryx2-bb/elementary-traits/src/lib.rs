@@ -29,5 +29,6 @@ pub mod plain_old_data_without_padding;
 pub mod pointer_trait;
 pub mod relocatable_container;
 pub mod testing;
+pub mod validate;
 pub mod zero_copy_send;
 pub mod zeroable;
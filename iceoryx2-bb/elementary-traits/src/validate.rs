@@ -0,0 +1,203 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+/// Failures that can be detected with [`Validate::validate()`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ValidationError {
+    /// The bit pattern is not valid for the type, for instance a `bool` that is neither `0`
+    /// nor `1` or an enum with an unknown discriminant.
+    InvalidBitPattern,
+    /// The state violates an invariant of the type, for instance a length that exceeds the
+    /// capacity.
+    InvariantViolation,
+    /// Memory that is referred to by the value is not located inside the
+    /// [`ValidationBounds`].
+    OutOfBounds,
+}
+
+impl core::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "ValidationError::{self:?}")
+    }
+}
+
+impl core::error::Error for ValidationError {}
+
+/// The memory region in which every memory that is referred to by a validated value, for
+/// instance the data of a relocatable container, must be located.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ValidationBounds {
+    start: usize,
+    end: usize,
+}
+
+impl ValidationBounds {
+    /// Creates new [`ValidationBounds`] that start at `start` and contain `size` bytes.
+    pub fn new(start: *const u8, size: usize) -> Self {
+        let start = start as usize;
+        Self {
+            start,
+            end: start.saturating_add(size),
+        }
+    }
+
+    /// Creates new [`ValidationBounds`] that cover exactly the memory of `value`.
+    pub fn of_value<T: ?Sized>(value: &T) -> Self {
+        Self::new(
+            (value as *const T).cast::<u8>(),
+            core::mem::size_of_val(value),
+        )
+    }
+
+    /// Creates new [`ValidationBounds`] that cover `number_of_elements` consecutive elements of
+    /// type `T` that start at `ptr`.
+    pub fn of_elements<T>(ptr: *const T, number_of_elements: usize) -> Self {
+        Self::new(
+            ptr.cast::<u8>(),
+            core::mem::size_of::<T>().saturating_mul(number_of_elements),
+        )
+    }
+
+    /// Returns true when the [`ValidationBounds`] share at least one byte with `other`.
+    pub fn overlaps(&self, other: &ValidationBounds) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+
+    /// Returns true when the memory that starts at `address` and contains `size` bytes is
+    /// located inside the [`ValidationBounds`].
+    pub fn contains(&self, address: usize, size: usize) -> bool {
+        match address.checked_add(size) {
+            Some(end) => self.start <= address && end <= self.end,
+            None => false,
+        }
+    }
+
+    /// Returns true when `number_of_elements` consecutive elements of type `T` that start
+    /// at `ptr` are located inside the [`ValidationBounds`] and `ptr` is aligned.
+    pub fn contains_elements<T>(&self, ptr: *const T, number_of_elements: usize) -> bool {
+        match core::mem::size_of::<T>().checked_mul(number_of_elements) {
+            Some(size) => ptr.is_aligned() && self.contains(ptr as usize, size),
+            None => false,
+        }
+    }
+}
+
+/// Validates that the memory of a value, which was for instance received from another
+/// process, contains a valid value of the type before a reference to it is created.
+/// Creating a reference to an invalid value, like a `bool` with a value of `2`, or using a
+/// container whose length exceeds its capacity leads to undefined behavior.
+///
+/// It can be derived for structs and enums with
+/// [`iceoryx2_bb_derive_macros::Validate`](https://docs.rs/iceoryx2-bb-derive-macros).
+///
+/// The result of [`Validate::validate()`] holds only as long as the memory is not modified.
+/// When another process still has write access to the memory, it can change the value during
+/// or after the validation (time-of-check to time-of-use). [`Validate`] does not protect
+/// against such a process, it detects only values that were malformed when they were handed
+/// over, for instance due to a bug or a mismatching type.
+///
+/// # Safety
+///
+/// The user must ensure that
+///  * every bit pattern for which [`Validate::validate()`] returns [`Ok`] is a valid value
+///    of the type and
+///  * all internal invariants the type relies on in its safe API hold when
+///    [`Validate::validate()`] returns [`Ok`].
+///
+pub unsafe trait Validate {
+    /// Returns [`Ok`] when `ptr` points to a valid value of the type and all memory the value
+    /// refers to is located inside of `bounds`.
+    ///
+    /// # Safety
+    ///
+    ///  * `ptr` must be aligned and point to initialized memory that has the size of `Self`
+    ///    and is not modified during the call
+    unsafe fn validate(ptr: *const Self, bounds: &ValidationBounds) -> Result<(), ValidationError>;
+}
+
+macro_rules! impl_validate_for_every_bit_pattern {
+    ($($type:ty),*) => {
+        $(
+            unsafe impl Validate for $type {
+                unsafe fn validate(
+                    _ptr: *const Self,
+                    _bounds: &ValidationBounds,
+                ) -> Result<(), ValidationError> {
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+impl_validate_for_every_bit_pattern!(usize, u8, u16, u32, u64, u128);
+impl_validate_for_every_bit_pattern!(isize, i8, i16, i32, i64, i128);
+impl_validate_for_every_bit_pattern!(f32, f64, ());
+
+unsafe impl Validate for bool {
+    unsafe fn validate(
+        ptr: *const Self,
+        _bounds: &ValidationBounds,
+    ) -> Result<(), ValidationError> {
+        match unsafe { ptr.cast::<u8>().read() } {
+            0 | 1 => Ok(()),
+            _ => Err(ValidationError::InvalidBitPattern),
+        }
+    }
+}
+
+unsafe impl Validate for char {
+    unsafe fn validate(
+        ptr: *const Self,
+        _bounds: &ValidationBounds,
+    ) -> Result<(), ValidationError> {
+        match char::from_u32(unsafe { ptr.cast::<u32>().read() }) {
+            Some(_) => Ok(()),
+            None => Err(ValidationError::InvalidBitPattern),
+        }
+    }
+}
+
+unsafe impl<T> Validate for core::mem::MaybeUninit<T> {
+    unsafe fn validate(
+        _ptr: *const Self,
+        _bounds: &ValidationBounds,
+    ) -> Result<(), ValidationError> {
+        Ok(())
+    }
+}
+
+unsafe impl<T> Validate for core::marker::PhantomData<T> {
+    unsafe fn validate(
+        _ptr: *const Self,
+        _bounds: &ValidationBounds,
+    ) -> Result<(), ValidationError> {
+        Ok(())
+    }
+}
+
+unsafe impl<T: Validate> Validate for [T] {
+    unsafe fn validate(ptr: *const Self, bounds: &ValidationBounds) -> Result<(), ValidationError> {
+        let first_element = ptr.cast::<T>();
+        for n in 0..ptr.len() {
+            unsafe { T::validate(first_element.add(n), bounds)? };
+        }
+
+        Ok(())
+    }
+}
+
+unsafe impl<T: Validate, const N: usize> Validate for [T; N] {
+    unsafe fn validate(ptr: *const Self, bounds: &ValidationBounds) -> Result<(), ValidationError> {
+        unsafe { <[T]>::validate(ptr as *const [T], bounds) }
+    }
+}
@@ -41,37 +41,85 @@ pub unsafe trait ZeroCopySend {
         core::any::type_name::<Self>()
     }
 
+    /// Hash of the structural layout of the type, computed at compile-time from the names,
+    /// offsets, sizes and alignments of its fields and the layout hashes of the field types. It
+    /// is used to detect that two types with the same [`ZeroCopySend::type_name()`] have an
+    /// incompatible memory layout, for instance when a field was reordered or changed from an
+    /// integer to a floating point type. Every primitive type has its own hash. `0` states that
+    /// the type does not provide structural layout information.
+    ///
+    /// It is generated by
+    /// [`iceoryx2_bb_derive_macros::ZeroCopySend`](https://docs.rs/iceoryx2-bb-derive-macros).
+    /// Generic types that are implemented manually shall forward the hashes of their type
+    /// parameters, see [`combine_layout_hashes()`].
+    const TYPE_LAYOUT_HASH: u64 = 0;
+
     #[doc(hidden)]
     /// used as dummy call in the derive macro to ensure at compile-time that all fields of
     /// a struct implement ZeroCopySend
     fn __is_zero_copy_send(&self) {}
 }
 
-unsafe impl ZeroCopySend for usize {}
-unsafe impl ZeroCopySend for u8 {}
-unsafe impl ZeroCopySend for u16 {}
-unsafe impl ZeroCopySend for u32 {}
-unsafe impl ZeroCopySend for u64 {}
-unsafe impl ZeroCopySend for u128 {}
+/// Combines two [`ZeroCopySend::TYPE_LAYOUT_HASH`]es, for instance of the key and value type of
+/// a map, into one. The result is `0` when both hashes are `0`.
+pub const fn combine_layout_hashes(lhs: u64, rhs: u64) -> u64 {
+    if lhs == 0 && rhs == 0 {
+        return 0;
+    }
 
-unsafe impl ZeroCopySend for isize {}
-unsafe impl ZeroCopySend for i8 {}
-unsafe impl ZeroCopySend for i16 {}
-unsafe impl ZeroCopySend for i32 {}
-unsafe impl ZeroCopySend for i64 {}
-unsafe impl ZeroCopySend for i128 {}
+    // FNV-1a over the little-endian bytes of both hashes
+    let mut hash: u64 = 0xcbf29ce484222325;
+    let bytes = [lhs.to_le_bytes(), rhs.to_le_bytes()];
+    let mut n = 0;
+    while n < 16 {
+        hash ^= bytes[n / 8][n % 8] as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+        n += 1;
+    }
 
-unsafe impl ZeroCopySend for f32 {}
-unsafe impl ZeroCopySend for f64 {}
+    if hash == 0 { 1 } else { hash }
+}
 
-unsafe impl ZeroCopySend for char {}
-unsafe impl ZeroCopySend for bool {}
+// FNV-1a over the name of the primitive, so that for instance `u64`, `i64` and `f64` have
+// different layout hashes although their size and alignment are equal
+const fn primitive_layout_hash(name: &str) -> u64 {
+    let bytes = name.as_bytes();
+    let mut hash: u64 = 0xcbf29ce484222325;
+    let mut n = 0;
+    while n < bytes.len() {
+        hash ^= bytes[n] as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+        n += 1;
+    }
 
-unsafe impl ZeroCopySend for () {}
+    if hash == 0 { 1 } else { hash }
+}
 
-unsafe impl<T: ZeroCopySend> ZeroCopySend for [T] {}
-unsafe impl<T: ZeroCopySend, const N: usize> ZeroCopySend for [T; N] {}
-unsafe impl<T: ZeroCopySend> ZeroCopySend for core::mem::MaybeUninit<T> {}
+macro_rules! impl_zero_copy_send_for_primitives {
+    ($($t:ty),*) => {
+        $(
+            unsafe impl ZeroCopySend for $t {
+                const TYPE_LAYOUT_HASH: u64 = primitive_layout_hash(stringify!($t));
+            }
+        )*
+    };
+}
+
+impl_zero_copy_send_for_primitives!(usize, u8, u16, u32, u64, u128);
+impl_zero_copy_send_for_primitives!(isize, i8, i16, i32, i64, i128);
+impl_zero_copy_send_for_primitives!(f32, f64);
+impl_zero_copy_send_for_primitives!(char, bool);
+impl_zero_copy_send_for_primitives!(());
+
+unsafe impl<T: ZeroCopySend> ZeroCopySend for [T] {
+    const TYPE_LAYOUT_HASH: u64 = T::TYPE_LAYOUT_HASH;
+}
+unsafe impl<T: ZeroCopySend, const N: usize> ZeroCopySend for [T; N] {
+    const TYPE_LAYOUT_HASH: u64 = T::TYPE_LAYOUT_HASH;
+}
+unsafe impl<T: ZeroCopySend> ZeroCopySend for core::mem::MaybeUninit<T> {
+    const TYPE_LAYOUT_HASH: u64 = T::TYPE_LAYOUT_HASH;
+}
 
 // Note: `ZeroCopySend` cannot be implemented for tuples because `#[repr(C)]` can only be applied
 // to structs, enums, and unions.
@@ -33,6 +33,7 @@ pub mod relocatable_ptr;
 pub mod scope_guard;
 pub mod static_assert;
 pub mod unique_id;
+pub mod units;
 pub mod unsendable_marker;
 
 /// Defines how a callback based iteration shall progress after the calling the callback. Either
@@ -69,7 +69,9 @@ use core::{fmt::Debug, marker::PhantomData, ptr::NonNull};
 pub use iceoryx2_bb_elementary_traits::pointer_trait::PointerTrait;
 
 use iceoryx2_bb_elementary_traits::{
-    generic_pointer::GenericPointer, zero_copy_send::ZeroCopySend,
+    generic_pointer::GenericPointer,
+    validate::{ValidationBounds, ValidationError},
+    zero_copy_send::ZeroCopySend,
 };
 use iceoryx2_pal_concurrency_sync::atomic::{AtomicIsize, Ordering};
 
@@ -136,6 +138,27 @@ impl<T> RelocatablePointer<T> {
             core::sync::atomic::Ordering::Relaxed,
         );
     }
+
+    /// Returns the pointer to the memory destination when the [`RelocatablePointer`] is
+    /// initialized and `number_of_elements` aligned elements of `T` at the memory destination
+    /// are located inside the [`ValidationBounds`]. Used by containers to implement
+    /// [`iceoryx2_bb_elementary_traits::validate::Validate`].
+    pub fn validated_ptr(
+        &self,
+        number_of_elements: usize,
+        bounds: &ValidationBounds,
+    ) -> Result<*const T, ValidationError> {
+        if !self.is_initialized() {
+            return Err(ValidationError::InvariantViolation);
+        }
+
+        let ptr = unsafe { self.as_ptr() };
+        if !bounds.contains_elements(ptr, number_of_elements) {
+            return Err(ValidationError::OutOfBounds);
+        }
+
+        Ok(ptr)
+    }
 }
 
 impl<T> PointerTrait<T> for RelocatablePointer<T> {
@@ -0,0 +1,979 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! SI-unit and semantic types like [`MemorySize`], [`Percentage`], [`StrictPercentage`],
+//! [`Throughput`] and [`Resolution`]. Every type has a stable memory layout, can be sent via
+//! zero-copy, is constructed in a checked manner and can be parsed from and formatted into
+//! strings with units, like `"64MiB"`, `"12.5%"`, `"100MB/s"` or `"1920x1080"`.
+//!
+//! The [`duration`] module provides the same for [`Duration`] so that it can be written with
+//! units, like `"250ms"`, in configuration files.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_elementary::units::*;
+//!
+//! let size: MemorySize = "64MiB".parse().unwrap();
+//! assert_eq!(size.as_bytes(), 64 * 1024 * 1024);
+//! assert_eq!(size, MemorySize::new(64, MemoryUnit::Mebibyte).unwrap());
+//!
+//! let load: StrictPercentage = "75%".parse().unwrap();
+//! assert_eq!(load.complement().value(), 25.0);
+//!
+//! let throughput: Throughput = "1GB/s".parse().unwrap();
+//! assert_eq!(throughput.bytes_per_second(), 1_000_000_000);
+//!
+//! let resolution: Resolution = "1920x1080".parse().unwrap();
+//! assert_eq!(resolution.aspect_ratio(), (16, 9));
+//! ```
+
+use alloc::format;
+use alloc::string::String;
+use core::fmt::Display;
+use core::marker::PhantomData;
+use core::str::FromStr;
+use core::time::Duration;
+
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use serde::de::Visitor;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+const NANOS_PER_SECOND: u128 = 1_000_000_000;
+
+/// Failures that can occur when an SI-unit or semantic type is parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnitError {
+    /// The string does not consist of a number followed by a unit.
+    InvalidFormat,
+    /// The unit is not supported by the type.
+    UnknownUnit,
+    /// The value exceeds the range of the type or cannot be represented with its resolution.
+    OutOfRange,
+}
+
+impl Display for UnitError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "UnitError::{self:?}")
+    }
+}
+
+impl core::error::Error for UnitError {}
+
+/// Splits a string like `"1.5 GiB"` into the number `"1.5"` and the unit `"GiB"`.
+fn split_number_and_unit(value: &str) -> Result<(&str, &str), UnitError> {
+    let value = value.trim();
+    let end = value
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(end);
+
+    if number.is_empty() {
+        return Err(UnitError::InvalidFormat);
+    }
+
+    Ok((number, unit.trim_start()))
+}
+
+/// Multiplies the decimal number with the factor. Fails when the result is not integral or
+/// does not fit into an [`u128`].
+fn scale_decimal(number: &str, factor: u128) -> Result<u128, UnitError> {
+    let (integer, fraction) = number.split_once('.').unwrap_or((number, ""));
+    if (integer.is_empty() && fraction.is_empty()) || fraction.contains('.') {
+        return Err(UnitError::InvalidFormat);
+    }
+
+    let mut mantissa: u128 = 0;
+    let mut scale: u128 = 1;
+    for (digit, is_fraction) in integer
+        .bytes()
+        .map(|d| (d, false))
+        .chain(fraction.bytes().map(|d| (d, true)))
+    {
+        mantissa = mantissa
+            .checked_mul(10)
+            .and_then(|v| v.checked_add((digit - b'0') as u128))
+            .ok_or(UnitError::OutOfRange)?;
+        if is_fraction {
+            scale = scale.checked_mul(10).ok_or(UnitError::OutOfRange)?;
+        }
+    }
+
+    let value = mantissa.checked_mul(factor).ok_or(UnitError::OutOfRange)?;
+    if value % scale != 0 {
+        return Err(UnitError::OutOfRange);
+    }
+
+    Ok(value / scale)
+}
+
+/// Implemented by all types that are serialized as string with units and that can be
+/// deserialized from such a string or a plain number.
+trait UnitValue: FromStr<Err = UnitError> + Display {
+    const EXPECTING: &'static str;
+
+    fn from_u64(_value: u64) -> Result<Self, UnitError> {
+        Err(UnitError::InvalidFormat)
+    }
+
+    fn from_f64(_value: f64) -> Result<Self, UnitError> {
+        Err(UnitError::InvalidFormat)
+    }
+}
+
+struct UnitVisitor<T> {
+    _data: PhantomData<T>,
+}
+
+impl<T: UnitValue> Visitor<'_> for UnitVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        formatter.write_str(T::EXPECTING)
+    }
+
+    fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        T::from_str(v).map_err(|e| E::custom(format!("invalid value \"{v}\" ({e})")))
+    }
+
+    fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        T::from_u64(v).map_err(|e| E::custom(format!("invalid value {v} ({e})")))
+    }
+
+    fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        match u64::try_from(v) {
+            Ok(v) => self.visit_u64(v),
+            Err(_) => self.visit_f64(v as f64),
+        }
+    }
+
+    fn visit_f64<E: serde::de::Error>(self, v: f64) -> Result<Self::Value, E> {
+        T::from_f64(v).map_err(|e| E::custom(format!("invalid value {v} ({e})")))
+    }
+}
+
+macro_rules! impl_serde_with_units {
+    ($type:ty) => {
+        impl Serialize for $type {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(&format!("{self}"))
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $type {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                deserializer.deserialize_any(UnitVisitor::<$type> { _data: PhantomData })
+            }
+        }
+    };
+}
+
+/// The units in which a [`MemorySize`] can be expressed. Decimal units are based on 1000,
+/// binary units on 1024.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MemoryUnit {
+    /// `B`
+    Byte,
+    /// `kB`, 1000 bytes
+    Kilobyte,
+    /// `KiB`, 1024 bytes
+    Kibibyte,
+    /// `MB`, 1000^2 bytes
+    Megabyte,
+    /// `MiB`, 1024^2 bytes
+    Mebibyte,
+    /// `GB`, 1000^3 bytes
+    Gigabyte,
+    /// `GiB`, 1024^3 bytes
+    Gibibyte,
+    /// `TB`, 1000^4 bytes
+    Terabyte,
+    /// `TiB`, 1024^4 bytes
+    Tebibyte,
+}
+
+impl MemoryUnit {
+    // ordered by descending size, used to find the best fitting unit when formatting
+    const ALL: [MemoryUnit; 9] = [
+        MemoryUnit::Tebibyte,
+        MemoryUnit::Terabyte,
+        MemoryUnit::Gibibyte,
+        MemoryUnit::Gigabyte,
+        MemoryUnit::Mebibyte,
+        MemoryUnit::Megabyte,
+        MemoryUnit::Kibibyte,
+        MemoryUnit::Kilobyte,
+        MemoryUnit::Byte,
+    ];
+
+    /// Returns the number of bytes one unit consists of.
+    pub const fn bytes(&self) -> u64 {
+        match self {
+            MemoryUnit::Byte => 1,
+            MemoryUnit::Kilobyte => 1000,
+            MemoryUnit::Kibibyte => 1 << 10,
+            MemoryUnit::Megabyte => 1000 * 1000,
+            MemoryUnit::Mebibyte => 1 << 20,
+            MemoryUnit::Gigabyte => 1000 * 1000 * 1000,
+            MemoryUnit::Gibibyte => 1 << 30,
+            MemoryUnit::Terabyte => 1000 * 1000 * 1000 * 1000,
+            MemoryUnit::Tebibyte => 1 << 40,
+        }
+    }
+
+    /// Returns the symbol of the unit, e.g. `"MiB"`.
+    pub const fn symbol(&self) -> &'static str {
+        match self {
+            MemoryUnit::Byte => "B",
+            MemoryUnit::Kilobyte => "kB",
+            MemoryUnit::Kibibyte => "KiB",
+            MemoryUnit::Megabyte => "MB",
+            MemoryUnit::Mebibyte => "MiB",
+            MemoryUnit::Gigabyte => "GB",
+            MemoryUnit::Gibibyte => "GiB",
+            MemoryUnit::Terabyte => "TB",
+            MemoryUnit::Tebibyte => "TiB",
+        }
+    }
+
+    fn from_symbol(value: &str) -> Option<Self> {
+        match value {
+            "" | "B" => Some(MemoryUnit::Byte),
+            "kB" | "KB" => Some(MemoryUnit::Kilobyte),
+            _ => Self::ALL.into_iter().find(|unit| unit.symbol() == value),
+        }
+    }
+}
+
+/// An amount of memory in bytes. It is formatted with the largest [`MemoryUnit`] that
+/// represents the value exactly and can be parsed from strings like `"64MiB"`, `"1.5 GB"` or
+/// `"4096"`.
+///
+/// # Example
+///
+/// ```
+/// use iceoryx2_bb_elementary::units::*;
+///
+/// let size = MemorySize::new(2, MemoryUnit::Kibibyte).unwrap();
+/// assert_eq!(size.as_bytes(), 2048);
+/// assert_eq!(size.to_string(), "2KiB");
+///
+/// // overflow is detected
+/// assert_eq!(MemorySize::new(u64::MAX, MemoryUnit::Kilobyte), None);
+/// assert_eq!(size.checked_sub(MemorySize::from_bytes(4096)), None);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct MemorySize(u64);
+
+unsafe impl ZeroCopySend for MemorySize {}
+
+impl MemorySize {
+    /// A [`MemorySize`] of zero bytes
+    pub const ZERO: MemorySize = MemorySize(0);
+
+    /// Creates a new [`MemorySize`] from the given number of bytes.
+    pub const fn from_bytes(value: u64) -> Self {
+        Self(value)
+    }
+
+    /// Creates a new [`MemorySize`] from a value in the given [`MemoryUnit`]. If the number
+    /// of bytes does not fit into an [`u64`] it returns [`None`].
+    pub const fn new(value: u64, unit: MemoryUnit) -> Option<Self> {
+        match value.checked_mul(unit.bytes()) {
+            Some(v) => Some(Self(v)),
+            None => None,
+        }
+    }
+
+    /// Returns the number of bytes.
+    pub const fn as_bytes(&self) -> u64 {
+        self.0
+    }
+
+    /// Returns the number of bytes as [`usize`] or [`None`] when it does not fit into it.
+    pub fn as_usize(&self) -> Option<usize> {
+        usize::try_from(self.0).ok()
+    }
+
+    /// Adds two [`MemorySize`]s, returns [`None`] on overflow.
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        match self.0.checked_add(rhs.0) {
+            Some(v) => Some(Self(v)),
+            None => None,
+        }
+    }
+
+    /// Subtracts two [`MemorySize`]s, returns [`None`] when `rhs` is larger than `self`.
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        match self.0.checked_sub(rhs.0) {
+            Some(v) => Some(Self(v)),
+            None => None,
+        }
+    }
+
+    /// Multiplies the [`MemorySize`] with a factor, returns [`None`] on overflow.
+    pub const fn checked_mul(self, rhs: u64) -> Option<Self> {
+        match self.0.checked_mul(rhs) {
+            Some(v) => Some(Self(v)),
+            None => None,
+        }
+    }
+
+    /// Divides the [`MemorySize`] by a divisor, returns [`None`] when the divisor is zero.
+    pub const fn checked_div(self, rhs: u64) -> Option<Self> {
+        match self.0.checked_div(rhs) {
+            Some(v) => Some(Self(v)),
+            None => None,
+        }
+    }
+
+    /// Adds two [`MemorySize`]s, saturates at the maximum value.
+    pub const fn saturating_add(self, rhs: Self) -> Self {
+        Self(self.0.saturating_add(rhs.0))
+    }
+
+    /// Subtracts two [`MemorySize`]s, saturates at zero.
+    pub const fn saturating_sub(self, rhs: Self) -> Self {
+        Self(self.0.saturating_sub(rhs.0))
+    }
+}
+
+impl Display for MemorySize {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let unit = MemoryUnit::ALL
+            .into_iter()
+            .find(|unit| self.0 != 0 && self.0 % unit.bytes() == 0)
+            .unwrap_or(MemoryUnit::Byte);
+        write!(f, "{}{}", self.0 / unit.bytes(), unit.symbol())
+    }
+}
+
+impl FromStr for MemorySize {
+    type Err = UnitError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (number, unit) = split_number_and_unit(s)?;
+        let unit = MemoryUnit::from_symbol(unit).ok_or(UnitError::UnknownUnit)?;
+        let value = scale_decimal(number, unit.bytes() as u128)?;
+
+        u64::try_from(value)
+            .map(Self)
+            .map_err(|_| UnitError::OutOfRange)
+    }
+}
+
+impl UnitValue for MemorySize {
+    const EXPECTING: &'static str = "a memory size in bytes or with a unit, like \"64MiB\"";
+
+    fn from_u64(value: u64) -> Result<Self, UnitError> {
+        Ok(Self(value))
+    }
+}
+
+impl_serde_with_units!(MemorySize);
+
+/// A finite percentage that can be negative or exceed 100%, like the change of a value or the
+/// load of a multi-core system. Parsed from strings like `"150%"` or `"-2.5"`.
+///
+/// # Example
+///
+/// ```
+/// use iceoryx2_bb_elementary::units::*;
+///
+/// let change = Percentage::new(150.0).unwrap();
+/// assert_eq!(change.of(20.0), 30.0);
+/// assert_eq!(change.to_string(), "150%");
+///
+/// assert_eq!(Percentage::new(f64::NAN), None);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct Percentage(f64);
+
+unsafe impl ZeroCopySend for Percentage {}
+
+impl Percentage {
+    /// 0%
+    pub const ZERO: Percentage = Percentage(0.0);
+    /// 100%
+    pub const HUNDRED: Percentage = Percentage(100.0);
+
+    /// Creates a new [`Percentage`] from a value in percent. If the value is not finite it
+    /// returns [`None`].
+    pub fn new(value: f64) -> Option<Self> {
+        value.is_finite().then_some(Self(value))
+    }
+
+    /// Creates a new [`Percentage`] from a ratio, where `1.0` corresponds to 100%. If the
+    /// resulting value is not finite it returns [`None`].
+    pub fn from_ratio(ratio: f64) -> Option<Self> {
+        Self::new(ratio * 100.0)
+    }
+
+    /// Returns the value in percent.
+    pub const fn value(&self) -> f64 {
+        self.0
+    }
+
+    /// Returns the ratio, where 100% corresponds to `1.0`.
+    pub fn ratio(&self) -> f64 {
+        self.0 / 100.0
+    }
+
+    /// Returns the [`Percentage`] of the provided value.
+    pub fn of(&self, value: f64) -> f64 {
+        value * self.ratio()
+    }
+
+    /// Adds two [`Percentage`]s, returns [`None`] when the result is not finite.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        Self::new(self.0 + rhs.0)
+    }
+
+    /// Subtracts two [`Percentage`]s, returns [`None`] when the result is not finite.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Self::new(self.0 - rhs.0)
+    }
+
+    /// Multiplies the [`Percentage`] with a factor, returns [`None`] when the result is not
+    /// finite.
+    pub fn checked_mul(self, rhs: f64) -> Option<Self> {
+        Self::new(self.0 * rhs)
+    }
+}
+
+impl Display for Percentage {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}%", self.0)
+    }
+}
+
+impl FromStr for Percentage {
+    type Err = UnitError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s.trim();
+        let value = value.strip_suffix('%').unwrap_or(value).trim_end();
+        let value = value.parse::<f64>().map_err(|_| UnitError::InvalidFormat)?;
+
+        Self::from_f64(value)
+    }
+}
+
+impl UnitValue for Percentage {
+    const EXPECTING: &'static str = "a percentage, like \"12.5%\"";
+
+    fn from_u64(value: u64) -> Result<Self, UnitError> {
+        Self::from_f64(value as f64)
+    }
+
+    fn from_f64(value: f64) -> Result<Self, UnitError> {
+        Self::new(value).ok_or(UnitError::OutOfRange)
+    }
+}
+
+impl_serde_with_units!(Percentage);
+
+/// A percentage in the range of 0% to 100%, like the fill level of a buffer. Parsed from
+/// strings like `"75%"` or `"12.5"`.
+///
+/// # Example
+///
+/// ```
+/// use iceoryx2_bb_elementary::units::*;
+///
+/// let fill_level = StrictPercentage::new(75.0).unwrap();
+/// assert_eq!(fill_level.complement().value(), 25.0);
+/// assert_eq!(fill_level.of(64.0), 48.0);
+///
+/// assert_eq!(StrictPercentage::new(100.1), None);
+/// assert_eq!(fill_level.checked_add(fill_level), None);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct StrictPercentage(f64);
+
+unsafe impl ZeroCopySend for StrictPercentage {}
+
+impl StrictPercentage {
+    /// 0%
+    pub const ZERO: StrictPercentage = StrictPercentage(0.0);
+    /// 100%
+    pub const HUNDRED: StrictPercentage = StrictPercentage(100.0);
+
+    /// Creates a new [`StrictPercentage`] from a value in percent. If the value is not in the
+    /// range of 0 to 100 it returns [`None`].
+    pub fn new(value: f64) -> Option<Self> {
+        (0.0..=100.0).contains(&value).then_some(Self(value))
+    }
+
+    /// Creates a new [`StrictPercentage`] from a ratio in the range of `0.0` to `1.0`. If the
+    /// ratio is out of range it returns [`None`].
+    pub fn from_ratio(ratio: f64) -> Option<Self> {
+        Self::new(ratio * 100.0)
+    }
+
+    /// Returns the value in percent.
+    pub const fn value(&self) -> f64 {
+        self.0
+    }
+
+    /// Returns the ratio, where 100% corresponds to `1.0`.
+    pub fn ratio(&self) -> f64 {
+        self.0 / 100.0
+    }
+
+    /// Returns the [`StrictPercentage`] of the provided value.
+    pub fn of(&self, value: f64) -> f64 {
+        value * self.ratio()
+    }
+
+    /// Returns the remaining [`StrictPercentage`] to 100%.
+    pub fn complement(&self) -> Self {
+        Self(100.0 - self.0)
+    }
+
+    /// Adds two [`StrictPercentage`]s, returns [`None`] when the result exceeds 100%.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        Self::new(self.0 + rhs.0)
+    }
+
+    /// Subtracts two [`StrictPercentage`]s, returns [`None`] when the result is negative.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Self::new(self.0 - rhs.0)
+    }
+
+    /// Adds two [`StrictPercentage`]s, saturates at 100%.
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        Self((self.0 + rhs.0).min(100.0))
+    }
+
+    /// Subtracts two [`StrictPercentage`]s, saturates at 0%.
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        Self((self.0 - rhs.0).max(0.0))
+    }
+}
+
+impl From<StrictPercentage> for Percentage {
+    fn from(value: StrictPercentage) -> Self {
+        Percentage(value.0)
+    }
+}
+
+impl TryFrom<Percentage> for StrictPercentage {
+    type Error = UnitError;
+
+    fn try_from(value: Percentage) -> Result<Self, Self::Error> {
+        Self::new(value.0).ok_or(UnitError::OutOfRange)
+    }
+}
+
+impl Display for StrictPercentage {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}%", self.0)
+    }
+}
+
+impl FromStr for StrictPercentage {
+    type Err = UnitError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        StrictPercentage::try_from(Percentage::from_str(s)?)
+    }
+}
+
+impl UnitValue for StrictPercentage {
+    const EXPECTING: &'static str = "a percentage between 0% and 100%, like \"12.5%\"";
+
+    fn from_u64(value: u64) -> Result<Self, UnitError> {
+        Self::from_f64(value as f64)
+    }
+
+    fn from_f64(value: f64) -> Result<Self, UnitError> {
+        Self::new(value).ok_or(UnitError::OutOfRange)
+    }
+}
+
+impl_serde_with_units!(StrictPercentage);
+
+/// A data throughput in bytes per second. Parsed from strings like `"100MiB/s"` or
+/// `"1.5 GB/s"`.
+///
+/// # Example
+///
+/// ```
+/// use core::time::Duration;
+/// use iceoryx2_bb_elementary::units::*;
+///
+/// let throughput = Throughput::new(MemorySize::new(10, MemoryUnit::Megabyte).unwrap(),
+///                                  Duration::from_millis(100)).unwrap();
+/// assert_eq!(throughput.to_string(), "100MB/s");
+///
+/// let data = MemorySize::new(50, MemoryUnit::Megabyte).unwrap();
+/// assert_eq!(throughput.transfer_time(data), Some(Duration::from_millis(500)));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct Throughput(u64);
+
+unsafe impl ZeroCopySend for Throughput {}
+
+impl Throughput {
+    /// A [`Throughput`] of zero bytes per second
+    pub const ZERO: Throughput = Throughput(0);
+
+    /// Creates a new [`Throughput`] from the given number of bytes per second.
+    pub const fn from_bytes_per_second(value: u64) -> Self {
+        Self(value)
+    }
+
+    /// Creates a new [`Throughput`] from the data that is transferred in the given period.
+    /// If the period is zero or the result does not fit into an [`u64`] it returns [`None`].
+    pub fn new(data: MemorySize, period: Duration) -> Option<Self> {
+        if period.is_zero() {
+            return None;
+        }
+
+        let value = data.as_bytes() as u128 * NANOS_PER_SECOND / period.as_nanos();
+        u64::try_from(value).ok().map(Self)
+    }
+
+    /// Returns the number of bytes per second.
+    pub const fn bytes_per_second(&self) -> u64 {
+        self.0
+    }
+
+    /// Returns the [`Duration`] it takes to transfer the provided data. If the [`Throughput`]
+    /// is zero it returns [`None`].
+    pub fn transfer_time(&self, data: MemorySize) -> Option<Duration> {
+        if self.0 == 0 {
+            return None;
+        }
+
+        let nanos = (data.as_bytes() as u128 * NANOS_PER_SECOND).div_ceil(self.0 as u128);
+        let secs = u64::try_from(nanos / NANOS_PER_SECOND).ok()?;
+        Some(Duration::new(secs, (nanos % NANOS_PER_SECOND) as u32))
+    }
+
+    /// Returns the data that is transferred in the provided period. If it does not fit into a
+    /// [`MemorySize`] it returns [`None`].
+    pub fn data_in(&self, period: Duration) -> Option<MemorySize> {
+        let value = self.0 as u128 * period.as_nanos() / NANOS_PER_SECOND;
+        u64::try_from(value).ok().map(MemorySize::from_bytes)
+    }
+
+    /// Adds two [`Throughput`]s, returns [`None`] on overflow.
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        match self.0.checked_add(rhs.0) {
+            Some(v) => Some(Self(v)),
+            None => None,
+        }
+    }
+
+    /// Subtracts two [`Throughput`]s, returns [`None`] when `rhs` is larger than `self`.
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        match self.0.checked_sub(rhs.0) {
+            Some(v) => Some(Self(v)),
+            None => None,
+        }
+    }
+
+    /// Multiplies the [`Throughput`] with a factor, returns [`None`] on overflow.
+    pub const fn checked_mul(self, rhs: u64) -> Option<Self> {
+        match self.0.checked_mul(rhs) {
+            Some(v) => Some(Self(v)),
+            None => None,
+        }
+    }
+}
+
+impl Display for Throughput {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}/s", MemorySize(self.0))
+    }
+}
+
+impl FromStr for Throughput {
+    type Err = UnitError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s.trim();
+        let value = value.strip_suffix("/s").ok_or(UnitError::UnknownUnit)?;
+        Ok(Self(MemorySize::from_str(value)?.0))
+    }
+}
+
+impl UnitValue for Throughput {
+    const EXPECTING: &'static str = "a throughput with a unit, like \"100MiB/s\"";
+
+    fn from_u64(value: u64) -> Result<Self, UnitError> {
+        Ok(Self(value))
+    }
+}
+
+impl_serde_with_units!(Throughput);
+
+/// The resolution of an image or a grid in `width x height` pixels. Parsed from strings like
+/// `"1920x1080"`.
+///
+/// # Example
+///
+/// ```
+/// use iceoryx2_bb_elementary::units::*;
+///
+/// let resolution = Resolution::new(1280, 720).unwrap();
+/// assert_eq!(resolution.pixels(), 921_600);
+/// assert_eq!(resolution.aspect_ratio(), (16, 9));
+/// assert_eq!(resolution.checked_scale(3), Resolution::new(3840, 2160));
+///
+/// assert_eq!(Resolution::new(0, 720), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct Resolution {
+    width: u32,
+    height: u32,
+}
+
+unsafe impl ZeroCopySend for Resolution {}
+
+impl Resolution {
+    /// Creates a new [`Resolution`]. If the width or the height is zero it returns [`None`].
+    pub const fn new(width: u32, height: u32) -> Option<Self> {
+        if width == 0 || height == 0 {
+            return None;
+        }
+
+        Some(Self { width, height })
+    }
+
+    /// Returns the width in pixels.
+    pub const fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Returns the height in pixels.
+    pub const fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the total number of pixels.
+    pub const fn pixels(&self) -> u64 {
+        self.width as u64 * self.height as u64
+    }
+
+    /// Returns the reduced aspect ratio as `(width, height)`, e.g. `(16, 9)`.
+    pub const fn aspect_ratio(&self) -> (u32, u32) {
+        let mut a = self.width;
+        let mut b = self.height;
+        while b != 0 {
+            let remainder = a % b;
+            a = b;
+            b = remainder;
+        }
+
+        (self.width / a, self.height / a)
+    }
+
+    /// Multiplies width and height with the factor. Returns [`None`] when the factor is zero
+    /// or on overflow.
+    pub const fn checked_scale(self, factor: u32) -> Option<Self> {
+        match (
+            self.width.checked_mul(factor),
+            self.height.checked_mul(factor),
+        ) {
+            (Some(width), Some(height)) => Self::new(width, height),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Resolution {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}x{}", self.width, self.height)
+    }
+}
+
+impl FromStr for Resolution {
+    type Err = UnitError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (width, height) = s
+            .trim()
+            .split_once(['x', 'X'])
+            .ok_or(UnitError::InvalidFormat)?;
+        let width = width
+            .trim()
+            .parse::<u32>()
+            .map_err(|_| UnitError::InvalidFormat)?;
+        let height = height
+            .trim()
+            .parse::<u32>()
+            .map_err(|_| UnitError::InvalidFormat)?;
+
+        Self::new(width, height).ok_or(UnitError::OutOfRange)
+    }
+}
+
+impl UnitValue for Resolution {
+    const EXPECTING: &'static str = "a resolution, like \"1920x1080\"";
+}
+
+impl_serde_with_units!(Resolution);
+
+/// Parsing, formatting and serialization of [`Duration`]s with units, like `"1.5s"`,
+/// `"250ms"`, `"2min"` or `"1h"`. Supported units are `ns`, `us` (or `µs`), `ms`, `s`, `min`,
+/// `h` and `d`.
+///
+/// The serialization functions can be used with `#[serde(with = "...")]`. For compatibility
+/// they also accept the table representation serde uses by default, `{ secs = 1, nanos = 0 }`.
+///
+/// # Example
+///
+/// ```
+/// use core::time::Duration;
+/// use iceoryx2_bb_elementary::units::duration;
+///
+/// assert_eq!(duration::parse("1.5s"), Ok(Duration::from_millis(1500)));
+/// assert_eq!(duration::format(&Duration::from_secs(120)), "2min");
+/// ```
+pub mod duration {
+    use super::*;
+    use serde::de::{Error, MapAccess};
+
+    // ordered by descending size, used to find the best fitting unit when formatting
+    const UNITS: [(&str, u128); 7] = [
+        ("d", 24 * 60 * 60 * NANOS_PER_SECOND),
+        ("h", 60 * 60 * NANOS_PER_SECOND),
+        ("min", 60 * NANOS_PER_SECOND),
+        ("s", NANOS_PER_SECOND),
+        ("ms", 1_000_000),
+        ("us", 1_000),
+        ("ns", 1),
+    ];
+
+    /// Parses a [`Duration`] from a string with a unit, like `"250ms"`.
+    pub fn parse(value: &str) -> Result<Duration, UnitError> {
+        let (number, unit) = split_number_and_unit(value)?;
+        let unit = if unit == "µs" { "us" } else { unit };
+        let (_, factor) = UNITS
+            .into_iter()
+            .find(|(symbol, _)| *symbol == unit)
+            .ok_or(UnitError::UnknownUnit)?;
+
+        let nanos = scale_decimal(number, factor)?;
+        let secs = u64::try_from(nanos / NANOS_PER_SECOND).map_err(|_| UnitError::OutOfRange)?;
+        Ok(Duration::new(secs, (nanos % NANOS_PER_SECOND) as u32))
+    }
+
+    /// Formats the [`Duration`] with the largest unit that represents it exactly.
+    pub fn format(value: &Duration) -> String {
+        let nanos = value.as_nanos();
+        let (symbol, factor) = UNITS
+            .into_iter()
+            .find(|(_, factor)| nanos != 0 && nanos % factor == 0)
+            .unwrap_or(("s", NANOS_PER_SECOND));
+
+        format!("{}{}", nanos / factor, symbol)
+    }
+
+    struct DurationVisitor;
+
+    impl<'de> Visitor<'de> for DurationVisitor {
+        type Value = Duration;
+
+        fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+            formatter.write_str("a duration with a unit, like \"250ms\"")
+        }
+
+        fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+            parse(v).map_err(|e| E::custom(format!("invalid duration \"{v}\" ({e})")))
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+            let mut secs = 0u64;
+            let mut nanos = 0u32;
+            while let Some(key) = map.next_key::<String>()? {
+                match key.as_str() {
+                    "secs" => secs = map.next_value()?,
+                    "nanos" => nanos = map.next_value()?,
+                    _ => return Err(A::Error::unknown_field(&key, &["secs", "nanos"])),
+                }
+            }
+
+            if nanos as u128 >= NANOS_PER_SECOND {
+                return Err(A::Error::custom(format!(
+                    "invalid duration, nanos {nanos} exceed one second"
+                )));
+            }
+
+            Ok(Duration::new(secs, nanos))
+        }
+    }
+
+    /// Serializes the [`Duration`] as string with a unit.
+    pub fn serialize<S: Serializer>(value: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format(value))
+    }
+
+    /// Deserializes the [`Duration`] from a string with a unit or from a table with `secs`
+    /// and `nanos`.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        deserializer.deserialize_any(DurationVisitor)
+    }
+
+    /// The same as [`duration`](self) but for an optional [`Duration`].
+    pub mod option {
+        use super::*;
+
+        struct OptionalDurationVisitor;
+
+        impl<'de> Visitor<'de> for OptionalDurationVisitor {
+            type Value = Option<Duration>;
+
+            fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+                formatter.write_str("an optional duration with a unit, like \"250ms\"")
+            }
+
+            fn visit_none<E: Error>(self) -> Result<Self::Value, E> {
+                Ok(None)
+            }
+
+            fn visit_some<D: Deserializer<'de>>(
+                self,
+                deserializer: D,
+            ) -> Result<Self::Value, D::Error> {
+                super::deserialize(deserializer).map(Some)
+            }
+        }
+
+        /// Serializes the optional [`Duration`] as string with a unit.
+        pub fn serialize<S: Serializer>(
+            value: &Option<Duration>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            match value {
+                Some(v) => serializer.serialize_some(&format(v)),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        /// Deserializes the optional [`Duration`] from a string with a unit or from a table
+        /// with `secs` and `nanos`.
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<Duration>, D::Error> {
+            deserializer.deserialize_option(OptionalDurationVisitor)
+        }
+    }
+}
@@ -12,6 +12,7 @@
 
 #![no_std]
 
+extern crate alloc;
 extern crate iceoryx2_bb_loggers;
 
 pub mod alignment_tests;
@@ -22,3 +23,4 @@ pub mod package_version_tests;
 pub mod relocatable_ptr_tests;
 pub mod scope_guard_tests;
 pub mod unique_id_tests;
+pub mod units_tests;
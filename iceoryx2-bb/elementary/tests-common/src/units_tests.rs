@@ -0,0 +1,250 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::str::FromStr;
+use core::time::Duration;
+
+use alloc::string::ToString;
+
+use iceoryx2_bb_elementary::units::*;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing_macros::requires_std;
+use iceoryx2_bb_testing_macros::test;
+
+#[test]
+pub fn memory_size_construction_is_checked() {
+    let sut = MemorySize::new(3, MemoryUnit::Gibibyte).unwrap();
+    assert_that!(sut.as_bytes(), eq 3 * 1024 * 1024 * 1024);
+    assert_that!(MemorySize::new(12, MemoryUnit::Megabyte).unwrap().as_bytes(), eq 12_000_000);
+    assert_that!(
+        MemorySize::new(u64::MAX / 1000 + 1, MemoryUnit::Kilobyte),
+        is_none
+    );
+}
+
+#[test]
+pub fn memory_size_arithmetic_detects_overflow() {
+    let sut = MemorySize::from_bytes(100);
+    let max = MemorySize::from_bytes(u64::MAX);
+
+    assert_that!(sut.checked_add(sut), eq Some(MemorySize::from_bytes(200)));
+    assert_that!(sut.checked_add(max), is_none);
+    assert_that!(sut.checked_sub(MemorySize::from_bytes(101)), is_none);
+    assert_that!(sut.checked_mul(3), eq Some(MemorySize::from_bytes(300)));
+    assert_that!(max.checked_mul(2), is_none);
+    assert_that!(sut.checked_div(0), is_none);
+    assert_that!(sut.saturating_add(max), eq max);
+    assert_that!(sut.saturating_sub(max), eq MemorySize::ZERO);
+}
+
+#[test]
+pub fn memory_size_can_be_parsed_from_string_with_units() {
+    assert_that!(MemorySize::from_str("64MiB"), eq Ok(MemorySize::from_bytes(64 << 20)));
+    assert_that!(MemorySize::from_str(" 1.5 GB "), eq Ok(MemorySize::from_bytes(1_500_000_000)));
+    assert_that!(MemorySize::from_str("8kB"), eq Ok(MemorySize::from_bytes(8000)));
+    assert_that!(MemorySize::from_str("8KB"), eq Ok(MemorySize::from_bytes(8000)));
+    assert_that!(MemorySize::from_str("0.5KiB"), eq Ok(MemorySize::from_bytes(512)));
+    assert_that!(MemorySize::from_str("4096"), eq Ok(MemorySize::from_bytes(4096)));
+    assert_that!(MemorySize::from_str("12B"), eq Ok(MemorySize::from_bytes(12)));
+}
+
+#[test]
+pub fn memory_size_parsing_fails_for_invalid_strings() {
+    assert_that!(MemorySize::from_str(""), eq Err(UnitError::InvalidFormat));
+    assert_that!(MemorySize::from_str("MiB"), eq Err(UnitError::InvalidFormat));
+    assert_that!(MemorySize::from_str("1.2.3MiB"), eq Err(UnitError::InvalidFormat));
+    assert_that!(MemorySize::from_str("-1MiB"), eq Err(UnitError::InvalidFormat));
+    assert_that!(MemorySize::from_str("12mib"), eq Err(UnitError::UnknownUnit));
+    assert_that!(MemorySize::from_str("0.3B"), eq Err(UnitError::OutOfRange));
+    assert_that!(MemorySize::from_str("20000000TB"), eq Err(UnitError::OutOfRange));
+}
+
+#[test]
+pub fn memory_size_is_formatted_with_largest_exact_unit() {
+    assert_that!(MemorySize::from_bytes(0).to_string(), eq "0B");
+    assert_that!(MemorySize::from_bytes(1023).to_string(), eq "1023B");
+    assert_that!(MemorySize::from_bytes(64 << 20).to_string(), eq "64MiB");
+    assert_that!(MemorySize::from_bytes(3_000_000).to_string(), eq "3MB");
+    assert_that!(MemorySize::from_bytes(1536).to_string(), eq "1536B");
+
+    for value in [1, 999, 1000, 1024, 1 << 40, 5_000_000_000, u64::MAX] {
+        let sut = MemorySize::from_bytes(value);
+        assert_that!(MemorySize::from_str(&sut.to_string()), eq Ok(sut));
+    }
+}
+
+#[test]
+pub fn percentage_construction_is_checked() {
+    assert_that!(Percentage::new(-12.5).unwrap().value(), eq - 12.5);
+    assert_that!(Percentage::new(250.0).unwrap().ratio(), eq 2.5);
+    assert_that!(Percentage::from_ratio(0.25).unwrap().value(), eq 25.0);
+    assert_that!(Percentage::new(f64::NAN), is_none);
+    assert_that!(Percentage::new(f64::INFINITY), is_none);
+    assert_that!(
+        Percentage::new(f64::MAX)
+            .unwrap()
+            .checked_add(Percentage::new(f64::MAX).unwrap()),
+        is_none
+    );
+    assert_that!(Percentage::new(50.0).unwrap().of(30.0), eq 15.0);
+}
+
+#[test]
+pub fn strict_percentage_stays_in_range() {
+    let sut = StrictPercentage::new(60.0).unwrap();
+
+    assert_that!(StrictPercentage::new(-0.1), is_none);
+    assert_that!(StrictPercentage::new(100.1), is_none);
+    assert_that!(StrictPercentage::from_ratio(1.5), is_none);
+    assert_that!(sut.complement().value(), eq 40.0);
+    assert_that!(sut.checked_add(sut), is_none);
+    assert_that!(sut.checked_sub(sut.complement()).unwrap().value(), eq 20.0);
+    assert_that!(sut.complement().checked_sub(sut), is_none);
+    assert_that!(sut.saturating_add(sut), eq StrictPercentage::HUNDRED);
+    assert_that!(sut.complement().saturating_sub(sut), eq StrictPercentage::ZERO);
+
+    assert_that!(Percentage::from(sut).value(), eq 60.0);
+    assert_that!(StrictPercentage::try_from(Percentage::new(101.0).unwrap()), eq Err(UnitError::OutOfRange));
+}
+
+#[test]
+pub fn percentages_can_be_parsed_and_formatted() {
+    assert_that!(Percentage::from_str("12.5%"), eq Ok(Percentage::new(12.5).unwrap()));
+    assert_that!(Percentage::from_str(" -150 % "), eq Ok(Percentage::new(-150.0).unwrap()));
+    assert_that!(Percentage::from_str("abc%"), eq Err(UnitError::InvalidFormat));
+    assert_that!(Percentage::from_str("inf"), eq Err(UnitError::OutOfRange));
+    assert_that!(StrictPercentage::from_str("75"), eq Ok(StrictPercentage::new(75.0).unwrap()));
+    assert_that!(StrictPercentage::from_str("150%"), eq Err(UnitError::OutOfRange));
+
+    assert_that!(Percentage::new(-2.5).unwrap().to_string(), eq "-2.5%");
+    assert_that!(StrictPercentage::HUNDRED.to_string(), eq "100%");
+}
+
+#[test]
+pub fn throughput_converts_between_data_and_time() {
+    let data = MemorySize::new(10, MemoryUnit::Mebibyte).unwrap();
+    let sut = Throughput::new(data, Duration::from_secs(2)).unwrap();
+
+    assert_that!(sut.bytes_per_second(), eq 5 << 20);
+    assert_that!(sut.transfer_time(data), eq Some(Duration::from_secs(2)));
+    assert_that!(sut.data_in(Duration::from_millis(500)), eq MemorySize::new(2560, MemoryUnit::Kibibyte));
+    assert_that!(Throughput::new(data, Duration::ZERO), is_none);
+    assert_that!(Throughput::ZERO.transfer_time(data), is_none);
+    assert_that!(Throughput::from_bytes_per_second(3).transfer_time(MemorySize::from_bytes(1)), eq Some(Duration::from_nanos(333_333_334)));
+
+    assert_that!(sut.checked_add(sut), eq Some(Throughput::from_bytes_per_second(10 << 20)));
+    assert_that!(sut.checked_sub(sut.checked_mul(2).unwrap()), is_none);
+    assert_that!(sut.checked_mul(u64::MAX), is_none);
+}
+
+#[test]
+pub fn throughput_can_be_parsed_and_formatted() {
+    assert_that!(Throughput::from_str("100MiB/s"), eq Ok(Throughput::from_bytes_per_second(100 << 20)));
+    assert_that!(Throughput::from_str("1.5 GB/s"), eq Ok(Throughput::from_bytes_per_second(1_500_000_000)));
+    assert_that!(Throughput::from_str("100MiB"), eq Err(UnitError::UnknownUnit));
+    assert_that!(Throughput::from_str("/s"), eq Err(UnitError::InvalidFormat));
+
+    assert_that!(Throughput::from_bytes_per_second(2_000_000).to_string(), eq "2MB/s");
+}
+
+#[test]
+pub fn resolution_construction_is_checked() {
+    let sut = Resolution::new(1920, 1200).unwrap();
+
+    assert_that!(sut.width(), eq 1920);
+    assert_that!(sut.height(), eq 1200);
+    assert_that!(sut.pixels(), eq 2_304_000);
+    assert_that!(sut.aspect_ratio(), eq(8, 5));
+    assert_that!(sut.checked_scale(2), eq Resolution::new(3840, 2400));
+    assert_that!(sut.checked_scale(0), is_none);
+    assert_that!(sut.checked_scale(u32::MAX), is_none);
+    assert_that!(Resolution::new(0, 1), is_none);
+    assert_that!(Resolution::new(1, 0), is_none);
+}
+
+#[test]
+pub fn resolution_can_be_parsed_and_formatted() {
+    assert_that!(Resolution::from_str("640x480"), eq Ok(Resolution::new(640, 480).unwrap()));
+    assert_that!(Resolution::from_str(" 800 X 600 "), eq Ok(Resolution::new(800, 600).unwrap()));
+    assert_that!(Resolution::from_str("800"), eq Err(UnitError::InvalidFormat));
+    assert_that!(Resolution::from_str("800xabc"), eq Err(UnitError::InvalidFormat));
+    assert_that!(Resolution::from_str("0x600"), eq Err(UnitError::OutOfRange));
+
+    assert_that!(Resolution::new(1920, 1080).unwrap().to_string(), eq "1920x1080");
+}
+
+#[test]
+pub fn duration_can_be_parsed_from_string_with_units() {
+    assert_that!(duration::parse("250ms"), eq Ok(Duration::from_millis(250)));
+    assert_that!(duration::parse("1.5s"), eq Ok(Duration::from_millis(1500)));
+    assert_that!(duration::parse("2 min"), eq Ok(Duration::from_secs(120)));
+    assert_that!(duration::parse("1h"), eq Ok(Duration::from_secs(3600)));
+    assert_that!(duration::parse("1d"), eq Ok(Duration::from_secs(86400)));
+    assert_that!(duration::parse("10us"), eq Ok(Duration::from_micros(10)));
+    assert_that!(duration::parse("10µs"), eq Ok(Duration::from_micros(10)));
+    assert_that!(duration::parse("7ns"), eq Ok(Duration::from_nanos(7)));
+
+    assert_that!(duration::parse("10"), eq Err(UnitError::UnknownUnit));
+    assert_that!(duration::parse("10sec"), eq Err(UnitError::UnknownUnit));
+    assert_that!(duration::parse("s"), eq Err(UnitError::InvalidFormat));
+    assert_that!(duration::parse("0.5ns"), eq Err(UnitError::OutOfRange));
+}
+
+#[test]
+pub fn duration_is_formatted_with_largest_exact_unit() {
+    assert_that!(duration::format(&Duration::ZERO), eq "0s");
+    assert_that!(duration::format(&Duration::from_millis(1500)), eq "1500ms");
+    assert_that!(duration::format(&Duration::from_secs(7200)), eq "2h");
+    assert_that!(duration::format(&Duration::new(1, 1)), eq "1000000001ns");
+
+    for value in [
+        Duration::from_secs(1),
+        Duration::from_micros(3),
+        Duration::MAX,
+    ] {
+        assert_that!(duration::parse(&duration::format(&value)), eq Ok(value));
+    }
+}
+
+#[test]
+#[requires_std("serde_test")]
+pub fn serialization_works() {
+    use serde_test::{Token, assert_de_tokens, assert_de_tokens_error, assert_tokens};
+
+    assert_tokens(&MemorySize::from_bytes(64 << 20), &[Token::Str("64MiB")]);
+    assert_de_tokens(&MemorySize::from_bytes(4096), &[Token::U64(4096)]);
+    assert_de_tokens_error::<MemorySize>(
+        &[Token::Str("12XB")],
+        "invalid value \"12XB\" (UnitError::UnknownUnit)",
+    );
+
+    assert_tokens(&Percentage::new(-12.5).unwrap(), &[Token::Str("-12.5%")]);
+    assert_de_tokens(&Percentage::new(-3.0).unwrap(), &[Token::I64(-3)]);
+    assert_tokens(
+        &StrictPercentage::new(12.5).unwrap(),
+        &[Token::Str("12.5%")],
+    );
+    assert_de_tokens(&StrictPercentage::new(0.5).unwrap(), &[Token::F64(0.5)]);
+    assert_de_tokens_error::<StrictPercentage>(
+        &[Token::U64(101)],
+        "invalid value 101 (UnitError::OutOfRange)",
+    );
+
+    assert_tokens(
+        &Throughput::from_bytes_per_second(1 << 30),
+        &[Token::Str("1GiB/s")],
+    );
+    assert_tokens(
+        &Resolution::new(1920, 1080).unwrap(),
+        &[Token::Str("1920x1080")],
+    );
+}
@@ -28,6 +28,23 @@
 //!
 //! Lock-Free guarantees that a misbehaving thread cannot block any other thread.
 
+/// Creates an array where every element is initialized with the provided value. The model
+/// checked atomics and cells of a build with `--cfg loom` cannot be const-initialized,
+/// therefore every element is constructed individually in this case.
+#[cfg(not(all(loom, feature = "std")))]
+macro_rules! const_array {
+    ($value:expr; $len:expr) => {
+        [const { $value }; $len]
+    };
+}
+
+#[cfg(all(loom, feature = "std"))]
+macro_rules! const_array {
+    ($value:expr; $len:expr) => {
+        core::array::from_fn(|_| $value)
+    };
+}
+
 pub mod mpmc;
 pub mod spmc;
 pub mod spsc;
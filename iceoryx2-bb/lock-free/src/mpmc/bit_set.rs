@@ -315,7 +315,7 @@ impl<const CAPACITY: usize> Default for FixedSizeBitSet<CAPACITY> {
     fn default() -> Self {
         let mut new_self = Self {
             bitset: unsafe { RelocatableBitSet::new_uninit(CAPACITY) },
-            data: [const { details::BitsetElement::new(0) }; CAPACITY],
+            data: const_array![details::BitsetElement::new(0); CAPACITY],
         };
 
         // SAFETY: Creating a pointer to an existing member is always not null
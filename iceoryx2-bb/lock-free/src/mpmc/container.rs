@@ -641,8 +641,8 @@ impl<T: Copy + Debug, const CAPACITY: usize> Default for FixedSizeContainerData<
     fn default() -> Self {
         Self {
             unique_index_set_data: StaticRobustUniqueIndexSetData::default(),
-            active_index: [const { AtomicU64::new(0) }; CAPACITY],
-            data: [const { UnsafeCell::new(MaybeUninit::uninit()) }; CAPACITY],
+            active_index: const_array![AtomicU64::new(0); CAPACITY],
+            data: const_array![UnsafeCell::new(MaybeUninit::uninit()); CAPACITY],
         }
     }
 }
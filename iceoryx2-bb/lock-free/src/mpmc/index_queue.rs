@@ -0,0 +1,475 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A **threadsafe**, **lock-free** and **robust** bounded multi producer multi consumer FIFO
+//! queue which can store [`u32`] integers or indices.
+//!
+//! Every cell of the queue contains a single atomic which stores the value together with
+//! the lap of the position it belongs to. Adding or removing a value is one compare-and-swap
+//! operation on the cell, the read and write position are advanced afterwards by the
+//! participant itself or by any other participant that observes the modified cell. Therefore,
+//! the queue keeps a consistent state even when a participant dies in the middle of an
+//! operation. Either the operation had no effect at all or it was completed and the remaining
+//! participants advance the positions on its behalf.
+//!
+//! # Example
+//!
+//! ```
+//! # extern crate iceoryx2_bb_loggers;
+//!
+//! use iceoryx2_bb_lock_free::mpmc::index_queue::*;
+//!
+//! const QUEUE_CAPACITY: usize = 128;
+//! let queue = FixedSizeIndexQueue::<QUEUE_CAPACITY>::new();
+//!
+//! if !queue.push(1234) {
+//!     println!("queue is full");
+//! }
+//!
+//! match queue.pop() {
+//!     None => println!("queue is empty"),
+//!     Some(v) => println!("got {}", v)
+//! }
+//! ```
+
+use core::{alloc::Layout, fmt::Debug};
+
+use iceoryx2_bb_concurrency::atomic::AtomicBool;
+use iceoryx2_bb_concurrency::atomic::AtomicU64;
+use iceoryx2_bb_concurrency::atomic::Ordering;
+use iceoryx2_bb_elementary::math::unaligned_mem_size;
+use iceoryx2_bb_elementary::{bump_allocator::BumpAllocator, relocatable_ptr::RelocatablePointer};
+use iceoryx2_bb_elementary_traits::{
+    owning_pointer::OwningPointer, pointer_trait::PointerTrait,
+    relocatable_container::RelocatableContainer,
+};
+use iceoryx2_log::{fail, fatal_panic};
+
+// cell layout: | lap: 31 bit | is_occupied: 1 bit | value: 32 bit |
+const VALUE_BITS: u64 = 32;
+const OCCUPIED_FLAG: u64 = 1 << VALUE_BITS;
+const LAP_SHIFT: u64 = VALUE_BITS + 1;
+const LAP_MASK: u64 = (1 << (64 - LAP_SHIFT)) - 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Cell(u64);
+
+impl Cell {
+    const fn vacant(lap: u64) -> Self {
+        Self((lap & LAP_MASK) << LAP_SHIFT)
+    }
+
+    const fn occupied(lap: u64, value: u32) -> Self {
+        Self(((lap & LAP_MASK) << LAP_SHIFT) | OCCUPIED_FLAG | value as u64)
+    }
+
+    const fn lap(self) -> u64 {
+        self.0 >> LAP_SHIFT
+    }
+
+    const fn is_occupied(self) -> bool {
+        self.0 & OCCUPIED_FLAG != 0
+    }
+
+    const fn value(self) -> u32 {
+        self.0 as u32
+    }
+}
+
+pub type IndexQueue = details::IndexQueue<OwningPointer<AtomicU64>>;
+pub type RelocatableIndexQueue = details::IndexQueue<RelocatablePointer<AtomicU64>>;
+
+pub mod details {
+    use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+
+    use super::*;
+
+    /// A threadsafe lock-free multi producer multi consumer index queue with a capacity which
+    /// can be set up at runtime, when the queue is created.
+    #[repr(C)]
+    #[derive(Debug)]
+    pub struct IndexQueue<PointerType: PointerTrait<AtomicU64>> {
+        write_position: AtomicU64,
+        read_position: AtomicU64,
+        is_memory_initialized: AtomicBool,
+        capacity: usize,
+        data_ptr: PointerType,
+    }
+
+    unsafe impl<PointerType: PointerTrait<AtomicU64> + ZeroCopySend> ZeroCopySend
+        for IndexQueue<PointerType>
+    {
+    }
+    unsafe impl<PointerType: PointerTrait<AtomicU64>> Sync for IndexQueue<PointerType> {}
+    unsafe impl<PointerType: PointerTrait<AtomicU64>> Send for IndexQueue<PointerType> {}
+
+    impl IndexQueue<OwningPointer<AtomicU64>> {
+        pub fn new(capacity: usize) -> Self {
+            let mut data_ptr = OwningPointer::<AtomicU64>::new_with_alloc(capacity);
+
+            for i in 0..capacity {
+                unsafe {
+                    data_ptr
+                        .as_mut_ptr()
+                        .add(i)
+                        .write(AtomicU64::new(Cell::vacant(0).0))
+                };
+            }
+
+            Self {
+                data_ptr,
+                capacity,
+                write_position: AtomicU64::new(0),
+                read_position: AtomicU64::new(0),
+                is_memory_initialized: AtomicBool::new(true),
+            }
+        }
+    }
+
+    impl RelocatableContainer for IndexQueue<RelocatablePointer<AtomicU64>> {
+        unsafe fn new_uninit(capacity: usize) -> Self {
+            Self {
+                data_ptr: unsafe { RelocatablePointer::new_uninit() },
+                capacity,
+                write_position: AtomicU64::new(0),
+                read_position: AtomicU64::new(0),
+                is_memory_initialized: AtomicBool::new(false),
+            }
+        }
+
+        unsafe fn init<T: iceoryx2_bb_elementary_traits::allocator::BaseAllocator>(
+            &mut self,
+            allocator: &T,
+        ) -> Result<(), iceoryx2_bb_elementary_traits::allocator::AllocationError> {
+            if self.is_memory_initialized.load(Ordering::Relaxed) {
+                fatal_panic!(from self, "Memory already initialized. Initializing it twice may lead to undefined behavior.");
+            }
+            unsafe {
+                self.data_ptr.init(fail!(from self, when allocator
+            .allocate(Layout::from_size_align_unchecked(
+                    core::mem::size_of::<AtomicU64>() * self.capacity,
+                    core::mem::align_of::<AtomicU64>())),
+            "Failed to initialize since the allocation of the data memory failed."));
+
+                for i in 0..self.capacity {
+                    (self.data_ptr.as_ptr() as *mut AtomicU64)
+                        .add(i)
+                        .write(AtomicU64::new(Cell::vacant(0).0));
+                }
+            }
+            self.is_memory_initialized.store(true, Ordering::Relaxed);
+            Ok(())
+        }
+
+        fn memory_size(capacity: usize) -> usize {
+            Self::const_memory_size(capacity)
+        }
+    }
+
+    impl<PointerType: PointerTrait<AtomicU64> + Debug> IndexQueue<PointerType> {
+        #[inline(always)]
+        fn verify_init(&self, source: &str) {
+            debug_assert!(
+                self.is_memory_initialized.load(Ordering::Relaxed),
+                "Undefined behavior when calling IndexQueue::{source} and the object is not initialized."
+            );
+        }
+
+        /// Returns the amount of memory required to create a [`IndexQueue`] with the provided
+        /// capacity.
+        pub const fn const_memory_size(capacity: usize) -> usize {
+            unaligned_mem_size::<AtomicU64>(capacity)
+        }
+
+        pub(super) fn cell(&self, position: u64) -> &AtomicU64 {
+            unsafe {
+                &*self
+                    .data_ptr
+                    .as_ptr()
+                    .add((position % self.capacity as u64) as usize)
+            }
+        }
+
+        fn lap(&self, position: u64) -> u64 {
+            (position / self.capacity as u64) & LAP_MASK
+        }
+
+        fn advance(position: &AtomicU64, current: u64) {
+            // another participant may have advanced it already, which is fine
+            let _ = position.compare_exchange(
+                current,
+                current + 1,
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            );
+        }
+
+        /// Adds a value to the queue. If the queue is full it returns false, otherwise true.
+        ///
+        /// # Safety
+        ///
+        ///   * Ensure that either [`IndexQueue::new()`] was used or
+        ///     [`RelocatableContainer::init()`] was called once.
+        pub unsafe fn push(&self, value: u32) -> bool {
+            self.verify_init("push()");
+
+            loop {
+                let write_position = self.write_position.load(Ordering::Relaxed);
+                let lap = self.lap(write_position);
+                let cell = self.cell(write_position);
+                ////////////////
+                // SYNC POINT: reading value has finished
+                ////////////////
+                let current = Cell(cell.load(Ordering::Acquire));
+
+                if current == Cell::vacant(lap) {
+                    ////////////////
+                    // SYNC POINT: value content visible in pop
+                    ////////////////
+                    if cell
+                        .compare_exchange(
+                            current.0,
+                            Cell::occupied(lap, value).0,
+                            Ordering::AcqRel,
+                            Ordering::Relaxed,
+                        )
+                        .is_ok()
+                    {
+                        Self::advance(&self.write_position, write_position);
+                        return true;
+                    }
+                } else if current.is_occupied() && current.lap() == lap.wrapping_sub(1) & LAP_MASK {
+                    // the value of the previous lap was not yet consumed
+                    return false;
+                } else if current.lap() != lap.wrapping_sub(1) & LAP_MASK {
+                    // another producer has written the cell but did not yet advance the
+                    // write position, it may have died in between
+                    Self::advance(&self.write_position, write_position);
+                }
+            }
+        }
+
+        /// Acquires a value from the queue. If the queue is empty it returns [`None`].
+        ///
+        /// # Safety
+        ///
+        ///   * Ensure that either [`IndexQueue::new()`] was used or
+        ///     [`RelocatableContainer::init()`] was called once.
+        pub unsafe fn pop(&self) -> Option<u32> {
+            self.verify_init("pop()");
+
+            loop {
+                let read_position = self.read_position.load(Ordering::Relaxed);
+                let lap = self.lap(read_position);
+                let cell = self.cell(read_position);
+                ////////////////
+                // SYNC POINT: value content visible in pop
+                ////////////////
+                let current = Cell(cell.load(Ordering::Acquire));
+
+                if current.lap() == lap {
+                    if !current.is_occupied() {
+                        return None;
+                    }
+
+                    ////////////////
+                    // SYNC POINT: reading value has finished
+                    ////////////////
+                    if cell
+                        .compare_exchange(
+                            current.0,
+                            Cell::vacant(lap + 1).0,
+                            Ordering::AcqRel,
+                            Ordering::Relaxed,
+                        )
+                        .is_ok()
+                    {
+                        Self::advance(&self.read_position, read_position);
+                        return Some(current.value());
+                    }
+                } else if current.lap() != lap.wrapping_sub(1) & LAP_MASK {
+                    // another consumer has acquired the value but did not yet advance the
+                    // read position, it may have died in between
+                    Self::advance(&self.read_position, read_position);
+                }
+            }
+        }
+
+        fn acquire_read_and_write_position(&self) -> (u64, u64) {
+            loop {
+                let write_position = self.write_position.load(Ordering::Relaxed);
+                let read_position = self.read_position.load(Ordering::Relaxed);
+
+                if write_position == self.write_position.load(Ordering::Relaxed)
+                    && read_position == self.read_position.load(Ordering::Relaxed)
+                {
+                    return (write_position, read_position);
+                }
+            }
+        }
+
+        /// Returns true when the [`IndexQueue`] is empty, otherwise false.
+        /// Note: This method may make only sense in a non-concurrent setup since the information
+        ///       could be out-of-date as soon as it is acquired.
+        pub fn is_empty(&self) -> bool {
+            self.len() == 0
+        }
+
+        /// Returns the length of the [`IndexQueue`].
+        /// Note: This method may make only sense in a non-concurrent setup since the information
+        ///       could be out-of-date as soon as it is acquired.
+        pub fn len(&self) -> usize {
+            let (write_position, read_position) = self.acquire_read_and_write_position();
+            write_position.saturating_sub(read_position) as usize
+        }
+
+        /// Returns the capacity of the [`IndexQueue`].
+        pub const fn capacity(&self) -> usize {
+            self.capacity
+        }
+
+        /// Returns true when the [`IndexQueue`] is full, otherwise false.
+        /// Note: This method may make only sense in a non-concurrent setup since the information
+        ///       could be out-of-date as soon as it is acquired.
+        pub fn is_full(&self) -> bool {
+            self.len() == self.capacity
+        }
+    }
+}
+
+/// The compile-time fixed size version of the [`IndexQueue`].
+#[derive(Debug)]
+#[repr(C)]
+pub struct FixedSizeIndexQueue<const CAPACITY: usize> {
+    state: RelocatableIndexQueue,
+    data: [AtomicU64; CAPACITY],
+}
+
+unsafe impl<const CAPACITY: usize> Sync for FixedSizeIndexQueue<CAPACITY> {}
+unsafe impl<const CAPACITY: usize> Send for FixedSizeIndexQueue<CAPACITY> {}
+
+impl<const CAPACITY: usize> Default for FixedSizeIndexQueue<CAPACITY> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const CAPACITY: usize> FixedSizeIndexQueue<CAPACITY> {
+    /// Creates a new empty [`FixedSizeIndexQueue`].
+    pub fn new() -> Self {
+        let mut new_self = Self {
+            state: unsafe { RelocatableIndexQueue::new_uninit(CAPACITY) },
+            data: const_array![AtomicU64::new(0); CAPACITY],
+        };
+
+        // SAFETY: Creating a pointer to an existing member is always not null
+        let data_ptr =
+            unsafe { core::ptr::NonNull::<u8>::new_unchecked(new_self.data.as_mut_ptr().cast()) };
+
+        let allocator =
+            BumpAllocator::new(data_ptr, core::mem::size_of_val(new_self.data.as_ref()));
+        unsafe {
+            new_self
+                .state
+                .init(&allocator)
+                .expect("All required memory is preallocated.")
+        };
+
+        new_self
+    }
+
+    /// See [`IndexQueue::is_empty()`]
+    pub fn is_empty(&self) -> bool {
+        self.state.is_empty()
+    }
+
+    /// See [`IndexQueue::len()`]
+    pub fn len(&self) -> usize {
+        self.state.len()
+    }
+
+    /// See [`IndexQueue::capacity()`]
+    pub const fn capacity(&self) -> usize {
+        self.state.capacity()
+    }
+
+    /// See [`IndexQueue::is_full()`]
+    pub fn is_full(&self) -> bool {
+        self.state.is_full()
+    }
+
+    /// Adds a value to the queue. If the queue is full it returns false, otherwise true.
+    pub fn push(&self, value: u32) -> bool {
+        unsafe { self.state.push(value) }
+    }
+
+    /// Acquires a value from the queue. If the queue is empty it returns [`None`].
+    pub fn pop(&self) -> Option<u32> {
+        unsafe { self.state.pop() }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate iceoryx2_bb_loggers;
+
+    use iceoryx2_bb_testing::assert_that;
+
+    use super::*;
+
+    #[test]
+    fn cell_fields_are_non_overlapping() {
+        let sut = Cell::occupied(LAP_MASK, u32::MAX);
+
+        assert_that!(sut.lap(), eq LAP_MASK);
+        assert_that!(sut.is_occupied(), eq true);
+        assert_that!(sut.value(), eq u32::MAX);
+
+        let sut = Cell::vacant(LAP_MASK + 1);
+
+        assert_that!(sut.lap(), eq 0);
+        assert_that!(sut.is_occupied(), eq false);
+        assert_that!(sut.value(), eq 0);
+    }
+
+    #[test]
+    fn producer_that_died_before_advancing_the_write_position_does_not_block_the_queue() {
+        let sut = FixedSizeIndexQueue::<4>::new();
+
+        // a producer added a value but died before the write position was advanced
+        sut.state
+            .cell(0)
+            .store(Cell::occupied(0, 42).0, Ordering::Relaxed);
+
+        assert_that!(sut.push(43), eq true);
+        assert_that!(sut.pop(), eq Some(42));
+        assert_that!(sut.pop(), eq Some(43));
+        assert_that!(sut.pop(), is_none);
+    }
+
+    #[test]
+    fn consumer_that_died_before_advancing_the_read_position_does_not_block_the_queue() {
+        let sut = FixedSizeIndexQueue::<4>::new();
+        assert_that!(sut.push(42), eq true);
+        assert_that!(sut.push(43), eq true);
+
+        // a consumer acquired the value but died before the read position was advanced
+        sut.state
+            .cell(0)
+            .store(Cell::vacant(1).0, Ordering::Relaxed);
+
+        assert_that!(sut.pop(), eq Some(43));
+        assert_that!(sut.pop(), is_none);
+        assert_that!(sut.push(44), eq true);
+        assert_that!(sut.pop(), eq Some(44));
+    }
+}
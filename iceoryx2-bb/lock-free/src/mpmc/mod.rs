@@ -15,6 +15,8 @@
 pub mod bit_set;
 pub mod container;
 pub mod counting_bit_set;
+pub mod index_queue;
+pub mod queue;
 pub mod robust_unique_index_set;
 pub mod unique_index_set;
 pub mod unique_index_set_enums;
@@ -0,0 +1,383 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A **threadsafe**, **lock-free** and **robust** bounded multi producer multi consumer FIFO
+//! queue.
+//! **IMPORTANT** Can only be used with trivially copyable types which are also trivially dropable.
+//!
+//! The elements are stored in slots which are managed by two
+//! [`RelocatableIndexQueue`]s, one that contains the free slots and one that contains the
+//! occupied slots in the order they were added. When a participant dies in the middle of an
+//! operation, the queue stays consistent and usable for all other participants, but the slot
+//! the dead participant was working on is lost, which reduces the capacity by one.
+//!
+//! # Example
+//!
+//! ```
+//! # extern crate iceoryx2_bb_loggers;
+//!
+//! use iceoryx2_bb_lock_free::mpmc::queue::*;
+//!
+//! const QUEUE_CAPACITY: usize = 128;
+//! let queue = Queue::<u64, QUEUE_CAPACITY>::new();
+//!
+//! if !queue.push(&1234) {
+//!     println!("queue is full");
+//! }
+//!
+//! match queue.pop() {
+//!     None => println!("queue is empty"),
+//!     Some(v) => println!("got {}", v)
+//! }
+//! ```
+//!
+//! The [`RelocatableQueue`] provides the same functionality with a capacity that is defined
+//! at runtime and can be placed in shared memory.
+
+use core::{alloc::Layout, fmt::Debug, mem::MaybeUninit, ptr::NonNull};
+
+use iceoryx2_bb_concurrency::atomic::{AtomicBool, AtomicU64, Ordering};
+use iceoryx2_bb_concurrency::cell::UnsafeCell;
+use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
+use iceoryx2_bb_elementary::math::unaligned_mem_size;
+use iceoryx2_bb_elementary::relocatable_ptr::RelocatablePointer;
+use iceoryx2_bb_elementary_traits::allocator::{AllocationError, BaseAllocator};
+use iceoryx2_bb_elementary_traits::pointer_trait::PointerTrait;
+use iceoryx2_bb_elementary_traits::relocatable_container::RelocatableContainer;
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_log::{fail, fatal_panic};
+
+use crate::mpmc::index_queue::RelocatableIndexQueue;
+
+/// The threadsafe lock-free multi producer multi consumer queue with a capacity which can be
+/// set up at runtime, when the queue is created. The compile time fixed size version is
+/// called [`Queue`].
+#[derive(Debug)]
+#[repr(C)]
+pub struct RelocatableQueue<T: Copy> {
+    data_ptr: RelocatablePointer<UnsafeCell<MaybeUninit<T>>>,
+    capacity: usize,
+    is_initialized: AtomicBool,
+    free_slots: RelocatableIndexQueue,
+    used_slots: RelocatableIndexQueue,
+}
+
+unsafe impl<T: Copy + Send> Sync for RelocatableQueue<T> {}
+unsafe impl<T: Copy + Send> Send for RelocatableQueue<T> {}
+unsafe impl<T: Copy + ZeroCopySend> ZeroCopySend for RelocatableQueue<T> {}
+
+impl<T: Copy> RelocatableContainer for RelocatableQueue<T> {
+    unsafe fn new_uninit(capacity: usize) -> Self {
+        Self {
+            data_ptr: unsafe { RelocatablePointer::new_uninit() },
+            capacity,
+            is_initialized: AtomicBool::new(false),
+            free_slots: unsafe { RelocatableIndexQueue::new_uninit(capacity) },
+            used_slots: unsafe { RelocatableIndexQueue::new_uninit(capacity) },
+        }
+    }
+
+    unsafe fn init<Allocator: BaseAllocator>(
+        &mut self,
+        allocator: &Allocator,
+    ) -> Result<(), AllocationError> {
+        let origin = "RelocatableQueue::init()";
+        if self.is_initialized.load(Ordering::Relaxed) {
+            fatal_panic!(from origin, "Memory already initialized. Initializing it twice may lead to undefined behavior.");
+        }
+        let msg = "Unable to initialize";
+        unsafe {
+            fail!(from origin, when self.free_slots.init(allocator),
+                "{} since the free slot queue could not be initialized.", msg);
+            fail!(from origin, when self.used_slots.init(allocator),
+                "{} since the used slot queue could not be initialized.", msg);
+            self.data_ptr.init(
+                fail!(from origin, when allocator.allocate(Layout::from_size_align_unchecked(
+                        core::mem::size_of::<T>() * self.capacity,
+                        core::mem::align_of::<T>())),
+                    "{} since the allocation of the data memory failed.", msg),
+            );
+
+            for i in 0..self.capacity {
+                (self.data_ptr.as_ptr() as *mut UnsafeCell<MaybeUninit<T>>)
+                    .add(i)
+                    .write(UnsafeCell::new(MaybeUninit::uninit()));
+                self.free_slots.push(i as u32);
+            }
+        }
+        self.is_initialized.store(true, Ordering::Relaxed);
+
+        Ok(())
+    }
+
+    fn memory_size(capacity: usize) -> usize {
+        Self::const_memory_size(capacity)
+    }
+}
+
+impl<T: Copy> RelocatableQueue<T> {
+    #[inline(always)]
+    fn verify_init(&self, source: &str) {
+        debug_assert!(
+            self.is_initialized.load(Ordering::Relaxed),
+            "Undefined behavior when calling RelocatableQueue<{}>::{} and the object is not initialized with 'init'.",
+            core::any::type_name::<T>(),
+            source
+        );
+    }
+
+    /// Returns the amount of memory required to create a [`RelocatableQueue`] with the
+    /// provided capacity.
+    pub const fn const_memory_size(capacity: usize) -> usize {
+        // free slots
+        RelocatableIndexQueue::const_memory_size(capacity)
+        // used slots
+        + RelocatableIndexQueue::const_memory_size(capacity)
+        // data ptr
+        + unaligned_mem_size::<T>(capacity)
+    }
+
+    fn slot(&self, slot: u32) -> &UnsafeCell<MaybeUninit<T>> {
+        unsafe { &*self.data_ptr.as_ptr().add(slot as usize) }
+    }
+
+    /// Adds a copy of the value to the queue. If the queue is full it returns false,
+    /// otherwise true.
+    ///
+    /// # Safety
+    ///
+    ///  * Ensure that [`RelocatableQueue::init()`] was called before calling this method
+    ///
+    pub unsafe fn push(&self, t: &T) -> bool {
+        self.verify_init("push()");
+
+        let slot = match unsafe { self.free_slots.pop() } {
+            Some(slot) => slot,
+            None => return false,
+        };
+
+        self.slot(slot)
+            .with_mut(|ptr| unsafe { ptr.write(MaybeUninit::new(*t)) });
+
+        ////////////////
+        // SYNC POINT with `used_slots.pop()` in `pop`
+        // the slot content is visible before the slot is published
+        ////////////////
+        let is_added = unsafe { self.used_slots.push(slot) };
+        debug_assert!(
+            is_added,
+            "The number of slots never exceeds the capacity of the used slot queue."
+        );
+        true
+    }
+
+    /// Removes the oldest element from the queue. If the queue is empty it returns [`None`]
+    ///
+    /// # Safety
+    ///
+    ///  * Ensure that [`RelocatableQueue::init()`] was called before calling this method
+    ///
+    pub unsafe fn pop(&self) -> Option<T> {
+        self.verify_init("pop()");
+
+        ////////////////
+        // SYNC POINT with `used_slots.push()` in `push`
+        ////////////////
+        let slot = unsafe { self.used_slots.pop()? };
+
+        let out: T = self.slot(slot).with(|ptr| unsafe { (*ptr).assume_init() });
+
+        ////////////////
+        // SYNC POINT with `free_slots.pop()` in `push`
+        // the slot content is read before the slot can be reused
+        ////////////////
+        let is_added = unsafe { self.free_slots.push(slot) };
+        debug_assert!(
+            is_added,
+            "The number of slots never exceeds the capacity of the free slot queue."
+        );
+
+        Some(out)
+    }
+
+    /// Returns true if the queue is empty, otherwise false
+    pub fn is_empty(&self) -> bool {
+        self.used_slots.is_empty()
+    }
+
+    /// Returns the number of elements stored in the queue
+    pub fn len(&self) -> usize {
+        self.used_slots.len()
+    }
+
+    /// Returns the overall capacity of the queue
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns true if the queue is full, otherwise false
+    pub fn is_full(&self) -> bool {
+        self.free_slots.is_empty()
+    }
+}
+
+#[derive(Debug)]
+#[repr(C)]
+struct QueueData<T: Copy, const CAPACITY: usize> {
+    // DO NOT CHANGE MEMBER ORDER, it must match the allocation order of RelocatableQueue::init
+    free_slots: [AtomicU64; CAPACITY],
+    used_slots: [AtomicU64; CAPACITY],
+    data: [UnsafeCell<MaybeUninit<T>>; CAPACITY],
+}
+
+impl<T: Copy, const CAPACITY: usize> QueueData<T, CAPACITY> {
+    fn new() -> Self {
+        Self {
+            free_slots: const_array![AtomicU64::new(0); CAPACITY],
+            used_slots: const_array![AtomicU64::new(0); CAPACITY],
+            data: const_array![UnsafeCell::new(MaybeUninit::uninit()); CAPACITY],
+        }
+    }
+
+    fn allocator(&mut self) -> BumpAllocator {
+        // SAFETY: Creating a pointer to an existing member is always not null
+        let data_ptr = unsafe { NonNull::<u8>::new_unchecked(self.free_slots.as_mut_ptr().cast()) };
+        BumpAllocator::new(data_ptr, core::mem::size_of::<Self>())
+    }
+}
+
+/// The threadsafe lock-free multi producer multi consumer queue with a compile time fixed
+/// capacity. The runtime fixed size version is called [`RelocatableQueue`].
+#[derive(Debug)]
+#[repr(C)]
+pub struct Queue<T: Copy, const CAPACITY: usize> {
+    state: RelocatableQueue<T>,
+    data: QueueData<T, CAPACITY>,
+}
+
+unsafe impl<T: Copy + Send, const CAPACITY: usize> Sync for Queue<T, CAPACITY> {}
+unsafe impl<T: Copy + Send, const CAPACITY: usize> Send for Queue<T, CAPACITY> {}
+unsafe impl<T: Copy + ZeroCopySend, const CAPACITY: usize> ZeroCopySend for Queue<T, CAPACITY> {}
+
+impl<T: Copy, const CAPACITY: usize> Queue<T, CAPACITY> {
+    const CAPACITY_FITS_INTO_INDEX: () = assert!(
+        CAPACITY as u64 <= u32::MAX as u64 + 1,
+        "The capacity must not exceed the number of values an index can represent."
+    );
+
+    /// Creates a new empty queue
+    pub fn new() -> Self {
+        let () = Self::CAPACITY_FITS_INTO_INDEX;
+
+        let mut new_self = Self {
+            state: unsafe { RelocatableQueue::new_uninit(CAPACITY) },
+            data: QueueData::new(),
+        };
+
+        unsafe {
+            new_self
+                .state
+                .init(&new_self.data.allocator())
+                .expect("All required memory is preallocated.")
+        };
+
+        new_self
+    }
+
+    /// Adds a copy of the value to the queue. If the queue is full it returns false, otherwise
+    /// true.
+    pub fn push(&self, t: &T) -> bool {
+        unsafe { self.state.push(t) }
+    }
+
+    /// Removes the oldest element from the queue. If the queue is empty it returns [`None`]
+    pub fn pop(&self) -> Option<T> {
+        unsafe { self.state.pop() }
+    }
+
+    /// Returns true if the queue is empty, otherwise false
+    pub fn is_empty(&self) -> bool {
+        self.state.is_empty()
+    }
+
+    /// Returns the number of elements stored in the queue
+    pub fn len(&self) -> usize {
+        self.state.len()
+    }
+
+    /// Returns the overall capacity of the queue
+    pub fn capacity(&self) -> usize {
+        self.state.capacity()
+    }
+
+    /// Returns true if the queue is full, otherwise false
+    pub fn is_full(&self) -> bool {
+        self.state.is_full()
+    }
+}
+
+impl<T: Copy, const CAPACITY: usize> Default for Queue<T, CAPACITY> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate iceoryx2_bb_loggers;
+
+    use iceoryx2_bb_testing::assert_that;
+
+    use super::*;
+
+    #[test]
+    fn producer_that_died_after_acquiring_a_slot_reduces_the_capacity_by_one() {
+        const CAPACITY: usize = 4;
+        let sut = Queue::<u64, CAPACITY>::new();
+
+        // a producer acquired a free slot but died before it was published
+        assert_that!(unsafe { sut.state.free_slots.pop() }, is_some);
+
+        for i in 0..CAPACITY as u64 - 1 {
+            assert_that!(sut.push(&i), eq true);
+        }
+        assert_that!(sut.push(&1234), eq false);
+
+        for i in 0..CAPACITY as u64 - 1 {
+            assert_that!(sut.pop(), eq Some(i));
+        }
+        assert_that!(sut.pop(), is_none);
+    }
+
+    #[test]
+    fn consumer_that_died_after_acquiring_a_slot_reduces_the_capacity_by_one() {
+        const CAPACITY: usize = 4;
+        let sut = Queue::<u64, CAPACITY>::new();
+        for i in 0..CAPACITY as u64 {
+            assert_that!(sut.push(&i), eq true);
+        }
+
+        // a consumer acquired the oldest slot but died before it was released
+        assert_that!(unsafe { sut.state.used_slots.pop() }, is_some);
+
+        for i in 1..CAPACITY as u64 {
+            assert_that!(sut.pop(), eq Some(i));
+        }
+        assert_that!(sut.pop(), is_none);
+
+        for i in 0..CAPACITY as u64 - 1 {
+            assert_that!(sut.push(&i), eq true);
+        }
+        assert_that!(sut.push(&1234), eq false);
+        assert_that!(sut, len CAPACITY - 1);
+    }
+}
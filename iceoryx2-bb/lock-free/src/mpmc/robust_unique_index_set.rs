@@ -564,7 +564,7 @@ pub struct StaticRobustUniqueIndexSetData<const CAPACITY: usize> {
 impl<const CAPACITY: usize> Default for StaticRobustUniqueIndexSetData<CAPACITY> {
     fn default() -> Self {
         Self {
-            cells: [const { AtomicU64::new(0) }; CAPACITY],
+            cells: const_array![AtomicU64::new(0); CAPACITY],
         }
     }
 }
@@ -97,8 +97,7 @@ use core::fmt::Debug;
 
 use iceoryx2_bb_concurrency::atomic::Ordering;
 use iceoryx2_bb_concurrency::atomic::fence;
-use iceoryx2_bb_concurrency::atomic::{AtomicBool, AtomicU64};
-use iceoryx2_bb_concurrency::cell::UnsafeCell;
+use iceoryx2_bb_concurrency::atomic::{AtomicBool, AtomicU32, AtomicU64};
 use iceoryx2_bb_derive_macros::ZeroCopySend;
 use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
 use iceoryx2_bb_elementary::relocatable_ptr::RelocatablePointer;
@@ -219,7 +218,7 @@ impl Drop for UniqueIndex<'_> {
 #[repr(C)]
 #[derive(Debug, ZeroCopySend)]
 pub struct UniqueIndexSet {
-    data_ptr: RelocatablePointer<UnsafeCell<u32>>,
+    data_ptr: RelocatablePointer<AtomicU32>,
     capacity: u32,
     pub(crate) head: AtomicU64,
     is_memory_initialized: AtomicBool,
@@ -289,9 +288,9 @@ impl RelocatableContainer for UniqueIndexSet {
             ));
 
             for i in 0..self.capacity + 1 {
-                (self.data_ptr.as_ptr() as *mut UnsafeCell<u32>)
+                (self.data_ptr.as_ptr() as *mut AtomicU32)
                     .offset(i as isize)
-                    .write(UnsafeCell::new(i + 1));
+                    .write(AtomicU32::new(i + 1));
             }
         }
         self.is_memory_initialized.store(true, Ordering::Relaxed);
@@ -314,7 +313,7 @@ impl UniqueIndexSet {
 
     /// The compile time version of [`UniqueIndexSet::memory_size()`]
     pub const fn const_memory_size(capacity: usize) -> usize {
-        core::mem::size_of::<UnsafeCell<u32>>() * (capacity + 1) + core::mem::align_of::<u32>() - 1
+        core::mem::size_of::<AtomicU32>() * (capacity + 1) + core::mem::align_of::<u32>() - 1
     }
 
     /// Acquires a new [`UniqueIndex`]. If the set does not contain any more indices it returns
@@ -397,7 +396,7 @@ impl UniqueIndexSet {
             }
 
             let new_value = HeadDetails {
-                head: *self.get_next_free_index(old.head),
+                head: self.next_free_index(old.head).load(Ordering::Relaxed),
                 aba: old.aba.wrapping_add(1),
                 borrowed_indices: old.borrowed_indices + 1,
             }
@@ -418,7 +417,8 @@ impl UniqueIndexSet {
         }
 
         let index = old.head;
-        *self.get_next_free_index(index) = self.capacity + 1;
+        self.next_free_index(index)
+            .store(self.capacity + 1, Ordering::Relaxed);
 
         fence(Ordering::Acquire);
         Ok(index)
@@ -445,7 +445,8 @@ impl UniqueIndexSet {
         let mut old = HeadDetails::from(old_value);
 
         loop {
-            *self.get_next_free_index(index) = old.head;
+            self.next_free_index(index)
+                .store(old.head, Ordering::Relaxed);
 
             let borrowed_indices =
                 if mode == ReleaseMode::LockIfLastIndex && old.borrowed_indices == 1 {
@@ -480,13 +481,10 @@ impl UniqueIndexSet {
         }
     }
 
-    #[allow(clippy::mut_from_ref)]
-    // convenience function to access internally mutable object
-    fn get_next_free_index(&self, index: u32) -> &mut u32 {
-        #[deny(clippy::mut_from_ref)]
-        unsafe {
-            &mut *(*self.data_ptr.as_ptr().offset(index as isize)).get()
-        }
+    // The next free index is read by concurrent acquire operations that may lose the race for
+    // the head. Therefore, it must be atomic but the synchronization is performed via the head.
+    fn next_free_index(&self, index: u32) -> &AtomicU32 {
+        unsafe { &*self.data_ptr.as_ptr().offset(index as isize) }
     }
 }
 
@@ -512,8 +510,8 @@ impl UniqueIndexSet {
 #[repr(C)]
 pub struct FixedSizeUniqueIndexSet<const CAPACITY: usize> {
     pub(crate) state: UniqueIndexSet,
-    next_free_index: [UnsafeCell<u32>; CAPACITY],
-    next_free_index_plus_one: UnsafeCell<u32>,
+    next_free_index: [AtomicU32; CAPACITY],
+    next_free_index_plus_one: AtomicU32,
 }
 
 impl<const CAPACITY: usize> Default for FixedSizeUniqueIndexSet<CAPACITY> {
@@ -548,8 +546,8 @@ impl<const CAPACITY: usize> FixedSizeUniqueIndexSet<CAPACITY> {
 
         let mut new_self = Self {
             state: unsafe { UniqueIndexSet::new_uninit(capacity) },
-            next_free_index: core::array::from_fn(|i| UnsafeCell::new(i as u32 + 1)),
-            next_free_index_plus_one: UnsafeCell::new(capacity as u32 + 1),
+            next_free_index: core::array::from_fn(|i| AtomicU32::new(i as u32 + 1)),
+            next_free_index_plus_one: AtomicU32::new(capacity as u32 + 1),
         };
 
         // SAFETY: Creating a pointer to an existing member is always not null
@@ -0,0 +1,384 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A **threadsafe** **lock-free** single producer multi consumer ring buffer that broadcasts
+//! the last `CAPACITY` published values to an arbitrary number of readers.
+//! **IMPORTANT** Can only be used with trivially copyable types which are also trivially dropable.
+//!
+//! The [`Producer`] never waits for the readers. When a slot is reused, the oldest value is
+//! overwritten, no matter if a reader is still interested in it. Readers do not need to
+//! register, they copy the value out of the slot and detect with the sequence counter of the
+//! slot (seqlock) whether the value was overwritten during the copy operation.
+//!
+//! Every published value gets a consecutive sequence number starting at zero, so that a reader
+//! can follow the stream of values with a [`Reader`] and detect how many values it missed.
+//!
+//! # Example
+//!
+//! ```
+//! # extern crate iceoryx2_bb_loggers;
+//!
+//! use iceoryx2_bb_lock_free::spmc::broadcast_ring::*;
+//!
+//! const CAPACITY: usize = 4;
+//! let ring = BroadcastRing::<u64, CAPACITY>::new();
+//!
+//! let producer = match ring.acquire_producer() {
+//!     None => panic!("a producer has been already acquired."),
+//!     Some(p) => p,
+//! };
+//!
+//! let mut reader = ring.reader();
+//!
+//! for value in 0..6 {
+//!     producer.publish(value);
+//! }
+//!
+//! // the latest value
+//! assert_eq!(ring.load_latest(), Some(5));
+//!
+//! // the last values, the oldest first
+//! let mut values = [0u64; CAPACITY];
+//! let number_of_values = ring.load_last(&mut values);
+//! assert_eq!(&values[..number_of_values], &[2, 3, 4, 5]);
+//!
+//! // the reader follows the stream and skips the overwritten values
+//! assert_eq!(reader.read(), Some(2));
+//! assert_eq!(reader.number_of_missed_values(), 2);
+//! ```
+
+use core::{fmt::Debug, mem::MaybeUninit};
+
+use iceoryx2_bb_concurrency::atomic::{AtomicBool, AtomicU64};
+use iceoryx2_bb_concurrency::atomic::{Ordering, fence};
+use iceoryx2_bb_concurrency::cell::UnsafeCell;
+
+/// Failures that can occur when a value is loaded with [`BroadcastRing::load()`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum BroadcastRingLoadError {
+    /// The value with the sequence number was not yet published.
+    NotYetPublished,
+    /// The value with the sequence number was already overwritten by a newer value.
+    Overwritten,
+}
+
+impl core::fmt::Display for BroadcastRingLoadError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "BroadcastRingLoadError::{self:?}")
+    }
+}
+
+impl core::error::Error for BroadcastRingLoadError {}
+
+/// The [`Producer`] of the [`BroadcastRing`] which can publish values via
+/// [`Producer::publish()`]. There can only be one at a time. When it goes out of scope it
+/// deregisters at the [`BroadcastRing`].
+pub struct Producer<'a, T: Copy, const CAPACITY: usize> {
+    ring: &'a BroadcastRing<T, CAPACITY>,
+}
+
+impl<T: Copy, const CAPACITY: usize> Producer<'_, T, CAPACITY> {
+    /// Publishes a new value and returns its sequence number. If the [`BroadcastRing`] is
+    /// full, the oldest value is overwritten.
+    pub fn publish(&self, value: T) -> u64 {
+        unsafe { self.ring.publish(value) }
+    }
+}
+
+impl<T: Copy, const CAPACITY: usize> Drop for Producer<'_, T, CAPACITY> {
+    fn drop(&mut self) {
+        // SYNC POINT: producer
+        // sync the internal state with the next producer in another thread
+        self.ring.has_producer.store(true, Ordering::Release);
+    }
+}
+
+/// Follows the values published into a [`BroadcastRing`] without registering at it. Every
+/// value is returned at most once. When the [`Reader`] falls behind by more than `CAPACITY`
+/// values, the overwritten values are skipped and counted in
+/// [`Reader::number_of_missed_values()`].
+pub struct Reader<'a, T: Copy, const CAPACITY: usize> {
+    ring: &'a BroadcastRing<T, CAPACITY>,
+    next_sequence_number: u64,
+    number_of_missed_values: u64,
+}
+
+impl<T: Copy, const CAPACITY: usize> Reader<'_, T, CAPACITY> {
+    /// Returns the next value or [`None`] when no new value was published.
+    pub fn read(&mut self) -> Option<T> {
+        loop {
+            match self.ring.load(self.next_sequence_number) {
+                Ok(value) => {
+                    self.next_sequence_number += 1;
+                    return Some(value);
+                }
+                Err(BroadcastRingLoadError::NotYetPublished) => return None,
+                Err(BroadcastRingLoadError::Overwritten) => {
+                    let oldest = self.ring.oldest_sequence_number();
+                    let oldest = oldest.max(self.next_sequence_number + 1);
+                    self.number_of_missed_values += oldest - self.next_sequence_number;
+                    self.next_sequence_number = oldest;
+                }
+            }
+        }
+    }
+
+    /// Returns the sequence number of the next value that will be returned by
+    /// [`Reader::read()`].
+    pub fn next_sequence_number(&self) -> u64 {
+        self.next_sequence_number
+    }
+
+    /// Returns the number of values that were overwritten before they could be read.
+    pub fn number_of_missed_values(&self) -> u64 {
+        self.number_of_missed_values
+    }
+}
+
+#[derive(Debug)]
+struct Slot<T> {
+    // 0 when the slot was never written, `2 * sequence_number + 1` while the value with the
+    // sequence number is written and `2 * sequence_number + 2` when it is published
+    sequence: AtomicU64,
+    value: UnsafeCell<MaybeUninit<T>>,
+}
+
+/// The threadsafe lock-free broadcast ring buffer with a compile time fixed capacity.
+#[repr(C)]
+pub struct BroadcastRing<T: Copy, const CAPACITY: usize> {
+    write_position: AtomicU64,
+    has_producer: AtomicBool,
+    slots: [Slot<T>; CAPACITY],
+}
+
+impl<T: Copy + Debug, const CAPACITY: usize> Debug for BroadcastRing<T, CAPACITY> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "BroadcastRing<{}, {}> {{ write_position: {}, latest: {:?}, has_producer: {} }}",
+            core::any::type_name::<T>(),
+            CAPACITY,
+            self.write_position.load(Ordering::Relaxed),
+            self.load_latest(),
+            self.has_producer.load(Ordering::Relaxed)
+        )
+    }
+}
+
+unsafe impl<T: Copy + Send, const CAPACITY: usize> Send for BroadcastRing<T, CAPACITY> {}
+unsafe impl<T: Copy + Send, const CAPACITY: usize> Sync for BroadcastRing<T, CAPACITY> {}
+
+impl<T: Copy, const CAPACITY: usize> BroadcastRing<T, CAPACITY> {
+    /// Creates a new empty [`BroadcastRing`].
+    pub fn new() -> Self {
+        const {
+            assert!(
+                CAPACITY > 0,
+                "The BroadcastRing requires a capacity of at least 1."
+            );
+        }
+
+        Self {
+            write_position: AtomicU64::new(0),
+            has_producer: AtomicBool::new(true),
+            slots: core::array::from_fn(|_| Slot {
+                sequence: AtomicU64::new(0),
+                value: UnsafeCell::new(MaybeUninit::uninit()),
+            }),
+        }
+    }
+
+    /// Returns a [`Producer`] to publish values. If a producer was already acquired it
+    /// returns [`None`].
+    pub fn acquire_producer(&self) -> Option<Producer<'_, T, CAPACITY>> {
+        match self.has_producer.compare_exchange(
+            true,
+            false,
+            // SYNC POINT: producer
+            // sync the internal state with the next producer in another thread
+            Ordering::Acquire,
+            // the producer could not be acquired therefore we do not need to sync anything
+            Ordering::Relaxed,
+        ) {
+            Ok(_) => Some(Producer { ring: self }),
+            Err(_) => None,
+        }
+    }
+
+    /// Returns a [`Reader`] that starts with the next value that will be published.
+    pub fn reader(&self) -> Reader<'_, T, CAPACITY> {
+        self.reader_starting_at(self.number_of_published_values())
+    }
+
+    /// Returns a [`Reader`] that starts with the value of the provided sequence number.
+    pub fn reader_starting_at(&self, sequence_number: u64) -> Reader<'_, T, CAPACITY> {
+        Reader {
+            ring: self,
+            next_sequence_number: sequence_number,
+            number_of_missed_values: 0,
+        }
+    }
+
+    /// Publishes a value and returns its sequence number.
+    ///
+    /// # Safety
+    ///
+    ///  * [`BroadcastRing::publish()`] cannot be called concurrently. The user has
+    ///    to ensure that at most one thread access this method.
+    pub unsafe fn publish(&self, value: T) -> u64 {
+        let sequence_number = self.write_position.load(Ordering::Relaxed);
+        let slot = self.slot(sequence_number);
+
+        slot.sequence
+            .store(2 * sequence_number + 1, Ordering::Relaxed);
+        ////////////////
+        // SYNC POINT with the `sequence` load in `load_from_slot`
+        // prevent that writing the value is reordered before marking the slot as being
+        // written, otherwise a reader could not detect the torn read
+        ////////////////
+        fence(Ordering::Release);
+
+        slot.value
+            .with_mut(|ptr| unsafe { ptr.write(MaybeUninit::new(value)) });
+
+        ////////////////
+        // SYNC POINT with the `sequence` load in `load_from_slot`
+        // prevent that writing the value is reordered after marking the slot as published
+        ////////////////
+        slot.sequence
+            .store(2 * sequence_number + 2, Ordering::Release);
+
+        ////////////////
+        // SYNC POINT with the `write_position` loads of the readers
+        ////////////////
+        self.write_position
+            .store(sequence_number + 1, Ordering::Release);
+
+        sequence_number
+    }
+
+    /// Loads the value with the provided sequence number.
+    pub fn load(&self, sequence_number: u64) -> Result<T, BroadcastRingLoadError> {
+        if self.number_of_published_values() <= sequence_number {
+            return Err(BroadcastRingLoadError::NotYetPublished);
+        }
+
+        self.load_from_slot(sequence_number)
+    }
+
+    /// Returns the latest published value or [`None`] when no value was published so far.
+    pub fn load_latest(&self) -> Option<T> {
+        loop {
+            let write_position = self.number_of_published_values();
+            if write_position == 0 {
+                return None;
+            }
+
+            // the latest value can only be overwritten when the producer published
+            // `CAPACITY` new values in the meantime, then we retry with the new latest value
+            if let Ok(value) = self.load_from_slot(write_position - 1) {
+                return Some(value);
+            }
+        }
+    }
+
+    /// Copies the last published values into `values`, the oldest value first, and returns
+    /// the number of copied values. At most `CAPACITY` values can be copied. Values that are
+    /// overwritten during the operation are not copied, therefore the copied values are
+    /// always consecutive and end with the value that was the latest when the operation
+    /// started.
+    pub fn load_last(&self, values: &mut [T]) -> usize {
+        let end = self.number_of_published_values();
+        let number_of_values = (values.len() as u64).min(CAPACITY as u64).min(end);
+
+        let mut number_of_copied_values = 0;
+        for sequence_number in end - number_of_values..end {
+            match self.load_from_slot(sequence_number) {
+                Ok(value) => {
+                    values[number_of_copied_values] = value;
+                    number_of_copied_values += 1;
+                }
+                // the writer overtook us, all values copied so far are older
+                Err(_) => number_of_copied_values = 0,
+            }
+        }
+
+        number_of_copied_values
+    }
+
+    /// Returns the sequence number of the oldest value that is still contained.
+    pub fn oldest_sequence_number(&self) -> u64 {
+        self.number_of_published_values()
+            .saturating_sub(CAPACITY as u64)
+    }
+
+    /// Returns the number of values that were published so far. It is also the sequence
+    /// number of the next published value.
+    pub fn number_of_published_values(&self) -> u64 {
+        ////////////////
+        // SYNC POINT with the `write_position` store in `publish`
+        ////////////////
+        self.write_position.load(Ordering::Acquire)
+    }
+
+    /// Returns true if no value was published so far, otherwise false
+    pub fn is_empty(&self) -> bool {
+        self.number_of_published_values() == 0
+    }
+
+    /// Returns the number of values the [`BroadcastRing`] contains at most
+    pub fn capacity(&self) -> usize {
+        CAPACITY
+    }
+
+    fn slot(&self, sequence_number: u64) -> &Slot<T> {
+        &self.slots[(sequence_number % CAPACITY as u64) as usize]
+    }
+
+    fn load_from_slot(&self, sequence_number: u64) -> Result<T, BroadcastRingLoadError> {
+        let slot = self.slot(sequence_number);
+        let expected_sequence = 2 * sequence_number + 2;
+
+        ////////////////
+        // SYNC POINT with the `sequence` store after writing the value in `publish`
+        ////////////////
+        let sequence = slot.sequence.load(Ordering::Acquire);
+        if sequence < expected_sequence {
+            return Err(BroadcastRingLoadError::NotYetPublished);
+        } else if sequence > expected_sequence {
+            return Err(BroadcastRingLoadError::Overwritten);
+        }
+
+        // the producer may overwrite the value concurrently, therefore it is copied into
+        // uninitialized memory and only used when the sequence did not change
+        let mut value: MaybeUninit<T> = MaybeUninit::uninit();
+        slot.value
+            .with(|ptr| unsafe { core::ptr::copy_nonoverlapping(ptr, &mut value, 1) });
+
+        ////////////////
+        // SYNC POINT with the fence in `publish`
+        // prevent that copying the value is reordered after the verification of the sequence
+        ////////////////
+        fence(Ordering::Acquire);
+        if slot.sequence.load(Ordering::Relaxed) != expected_sequence {
+            return Err(BroadcastRingLoadError::Overwritten);
+        }
+
+        Ok(unsafe { value.assume_init() })
+    }
+}
+
+impl<T: Copy, const CAPACITY: usize> Default for BroadcastRing<T, CAPACITY> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -194,23 +194,23 @@ impl UnrestrictedAtomicMgmt {
         value_alignment: usize,
         data_ptr: *const u8,
     ) {
+        self.load_cell(|read_cell| unsafe {
+            let data_cell_ptr =
+                Self::__internal_get_data_cell(value_size, value_alignment, data_ptr, read_cell);
+            core::ptr::copy_nonoverlapping(data_cell_ptr as *const u8, value_ptr, value_size);
+        });
+    }
+
+    /// Calls `copy_cell` with the cell that contains the latest value until the cell was not
+    /// modified during the copy operation.
+    fn load_cell<F: FnMut(u64)>(&self, mut copy_cell: F) {
         /////////////////////////
         // SYNC POINT - read
         /////////////////////////
         let mut current_write_cell = self.write_cell.load(Ordering::Acquire);
 
         loop {
-            let read_cell = current_write_cell - 1;
-
-            unsafe {
-                let data_cell_ptr = Self::__internal_get_data_cell(
-                    value_size,
-                    value_alignment,
-                    data_ptr,
-                    read_cell,
-                );
-                core::ptr::copy_nonoverlapping(data_cell_ptr as *const u8, value_ptr, value_size);
-            }
+            copy_cell(current_write_cell - 1);
 
             let old_write_cell = current_write_cell;
 
@@ -329,11 +329,8 @@ impl<T: Copy> UnrestrictedAtomic<T> {
 
     fn store(&self, new_value: T) {
         let write_cell = self.mgmt.write_cell.load(Ordering::Relaxed);
-        unsafe {
-            (*self.data[write_cell as usize % NUMBER_OF_CELLS].get())
-                .as_mut_ptr()
-                .write(new_value);
-        }
+        self.data[write_cell as usize % NUMBER_OF_CELLS]
+            .with_mut(|cell_ptr| unsafe { cell_ptr.write(MaybeUninit::new(new_value)) });
 
         /////////////////////////
         // SYNC POINT - write
@@ -347,15 +344,12 @@ impl<T: Copy> UnrestrictedAtomic<T> {
     /// Loads the underlying value and returns a copy of it.
     pub fn load(&self) -> T {
         let mut return_value: MaybeUninit<T> = MaybeUninit::uninit();
-        unsafe {
-            self.mgmt.load(
-                return_value.as_mut_ptr().cast(),
-                core::mem::size_of::<T>(),
-                core::mem::align_of::<T>(),
-                self.data.as_ptr().cast(),
-            );
-            return_value.assume_init()
-        }
+        self.mgmt.load_cell(|read_cell| {
+            self.data[read_cell as usize % NUMBER_OF_CELLS].with(|cell_ptr| unsafe {
+                core::ptr::copy_nonoverlapping(cell_ptr, &mut return_value, 1)
+            })
+        });
+        unsafe { return_value.assume_init() }
     }
 
     #[doc(hidden)]
@@ -217,14 +217,7 @@ pub mod details {
                     .add((position % self.capacity as u64) as usize)
             };
 
-            #[cfg(all(test, loom, feature = "std"))]
-            {
-                cell.get_mut().deref() as *mut u64
-            }
-            #[cfg(not(all(test, loom, feature = "std")))]
-            {
-                cell.get()
-            }
+            cell.get()
         }
 
         /// Acquires the [`Producer`] of the [`IndexQueue`]. This is threadsafe and lock-free without
@@ -420,7 +413,7 @@ impl<const CAPACITY: usize> FixedSizeIndexQueue<CAPACITY> {
     pub fn new() -> Self {
         let mut new_self = Self {
             state: unsafe { RelocatableIndexQueue::new_uninit(CAPACITY) },
-            data: [const { UnsafeCell::new(0) }; CAPACITY],
+            data: const_array![UnsafeCell::new(0); CAPACITY],
         };
 
         // SAFETY: Creating a pointer to an existing member is always not null
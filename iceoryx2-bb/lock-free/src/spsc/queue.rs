@@ -106,7 +106,7 @@ impl<T: Copy, const CAPACITY: usize> Queue<T, CAPACITY> {
     /// Creates a new empty queue
     pub fn new() -> Self {
         Self {
-            data: [const { UnsafeCell::new(MaybeUninit::uninit()) }; CAPACITY],
+            data: const_array![UnsafeCell::new(MaybeUninit::uninit()); CAPACITY],
             write_position: AtomicU64::new(0),
             read_position: AtomicU64::new(0),
             has_producer: AtomicBool::new(true),
@@ -201,11 +201,8 @@ impl<T: Copy, const CAPACITY: usize> Queue<T, CAPACITY> {
             return false;
         }
 
-        unsafe {
-            self.data[(current_write_pos % (CAPACITY as u64)) as usize]
-                .get()
-                .write(MaybeUninit::new(*t));
-        }
+        self.data[(current_write_pos % (CAPACITY as u64)) as usize]
+            .with_mut(|ptr| unsafe { ptr.write(MaybeUninit::new(*t)) });
         ////////////////
         // SYNC POINT with `write_position` load in `pop`
         // prevent that writing to `data` is reordered after advancing of
@@ -235,13 +232,8 @@ impl<T: Copy, const CAPACITY: usize> Queue<T, CAPACITY> {
             return None;
         }
 
-        let out: T = unsafe {
-            *self.data[(current_read_pos % (CAPACITY as u64)) as usize]
-                .get()
-                .as_ref()
-                .unwrap()
-                .as_ptr()
-        };
+        let out: T = self.data[(current_read_pos % (CAPACITY as u64)) as usize]
+            .with(|ptr| unsafe { (*ptr).assume_init() });
 
         ////////////////
         // SYNC POINT with `read_position` load in `push`
@@ -231,13 +231,12 @@ pub mod details {
             unaligned_mem_size::<UnsafeCell<u64>>(capacity + 1)
         }
 
-        fn at(&self, position: u64) -> *mut u64 {
+        fn at(&self, position: u64) -> &UnsafeCell<u64> {
             unsafe {
-                (*self
+                &*self
                     .data_ptr
                     .as_ptr()
-                    .add((position % (self.capacity as u64 + 1)) as usize))
-                .get()
+                    .add((position % (self.capacity as u64 + 1)) as usize)
             }
         }
         /// Acquires the [`Producer`] of the [`SafelyOverflowingIndexQueue`]. This is threadsafe and
@@ -334,7 +333,8 @@ pub mod details {
             let read_position = self.read_position.load(Ordering::Relaxed);
             let is_full = write_position == read_position + self.capacity as u64;
 
-            unsafe { self.at(write_position).write(value) };
+            self.at(write_position)
+                .with_mut(|ptr| unsafe { ptr.write(value) });
 
             ////////////////
             // SYNC POINT W
@@ -356,7 +356,7 @@ pub mod details {
                     )
                     .is_ok()
             {
-                let value = unsafe { *self.at(read_position) };
+                let value = self.at(read_position).with(|ptr| unsafe { *ptr });
                 Some(value)
             } else {
                 None
@@ -385,7 +385,7 @@ pub mod details {
 
             let mut value;
             loop {
-                value = unsafe { *self.at(read_position) };
+                value = self.at(read_position).with(|ptr| unsafe { *ptr });
 
                 match self.read_position.compare_exchange(
                     read_position,
@@ -471,7 +471,7 @@ impl<const CAPACITY: usize> FixedSizeSafelyOverflowingIndexQueue<CAPACITY> {
     pub fn new() -> Self {
         let mut new_self = Self {
             state: unsafe { RelocatableSafelyOverflowingIndexQueue::new_uninit(CAPACITY) },
-            data: [const { UnsafeCell::new(0) }; CAPACITY],
+            data: const_array![UnsafeCell::new(0); CAPACITY],
             data_plus_one: UnsafeCell::new(0),
         };
 
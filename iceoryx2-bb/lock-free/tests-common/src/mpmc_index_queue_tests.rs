@@ -0,0 +1,209 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use alloc::vec;
+
+use iceoryx2_bb_concurrency::atomic::{AtomicU64, Ordering};
+use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
+use iceoryx2_bb_elementary_traits::relocatable_container::RelocatableContainer;
+use iceoryx2_bb_lock_free::mpmc::index_queue::*;
+use iceoryx2_bb_posix::barrier::{BarrierBuilder, BarrierHandle, Handle};
+use iceoryx2_bb_posix::thread::thread_scope;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing_macros::test;
+
+#[test]
+pub fn push_works_until_full() {
+    const CAPACITY: usize = 128;
+    let sut = FixedSizeIndexQueue::<CAPACITY>::new();
+
+    assert_that!(sut.capacity(), eq CAPACITY);
+    assert_that!(sut, len 0);
+    assert_that!(sut.is_full(), eq false);
+    assert_that!(sut, is_empty);
+
+    for i in 0..CAPACITY {
+        assert_that!(sut, len i);
+        assert_that!(sut.push(i as u32), eq true);
+    }
+    assert_that!(sut.push(1234), eq false);
+
+    assert_that!(sut.capacity(), eq CAPACITY);
+    assert_that!(sut, len CAPACITY);
+    assert_that!(sut.is_full(), eq true);
+    assert_that!(sut, is_not_empty);
+}
+
+#[test]
+pub fn pop_works_until_empty() {
+    const CAPACITY: usize = 128;
+    let sut = FixedSizeIndexQueue::<CAPACITY>::new();
+    for i in 0..CAPACITY {
+        assert_that!(sut.push(i as u32), eq true);
+    }
+
+    for i in 0..CAPACITY {
+        assert_that!(sut, len CAPACITY - i);
+        assert_that!(sut.pop(), eq Some(i as u32));
+    }
+    assert_that!(sut.pop(), is_none);
+
+    assert_that!(sut, len 0);
+    assert_that!(sut.is_full(), eq false);
+    assert_that!(sut, is_empty);
+}
+
+#[test]
+pub fn push_pop_alteration_works() {
+    const CAPACITY: usize = 128;
+    let sut = FixedSizeIndexQueue::<CAPACITY>::new();
+
+    for i in 0..CAPACITY - 1 {
+        assert_that!(sut.push(i as u32), eq true);
+        assert_that!(sut.push(i as u32), eq true);
+
+        assert_that!(sut.pop(), eq Some(i as u32 / 2))
+    }
+}
+
+#[test]
+pub fn push_pop_works_over_many_laps_with_odd_capacity() {
+    const CAPACITY: usize = 7;
+    let sut = FixedSizeIndexQueue::<CAPACITY>::new();
+
+    for lap in 0..1000u32 {
+        for i in 0..CAPACITY as u32 {
+            assert_that!(sut.push(lap * 10 + i), eq true);
+        }
+        assert_that!(sut.push(0), eq false);
+
+        for i in 0..CAPACITY as u32 {
+            assert_that!(sut.pop(), eq Some(lap * 10 + i));
+        }
+        assert_that!(sut.pop(), is_none);
+    }
+}
+
+#[test]
+pub fn full_value_range_can_be_stored() {
+    let sut = FixedSizeIndexQueue::<2>::new();
+
+    assert_that!(sut.push(u32::MAX), eq true);
+    assert_that!(sut.push(0), eq true);
+
+    assert_that!(sut.pop(), eq Some(u32::MAX));
+    assert_that!(sut.pop(), eq Some(0));
+}
+
+#[test]
+pub fn relocatable_index_queue_works() {
+    const CAPACITY: usize = 12;
+    let mut memory = vec![0u64; CAPACITY];
+    let allocator = BumpAllocator::new(
+        core::ptr::NonNull::new(memory.as_mut_ptr().cast()).unwrap(),
+        RelocatableIndexQueue::const_memory_size(CAPACITY),
+    );
+
+    let mut sut = unsafe { RelocatableIndexQueue::new_uninit(CAPACITY) };
+    assert_that!(unsafe { sut.init(&allocator) }, is_ok);
+
+    assert_that!(sut.capacity(), eq CAPACITY);
+    for i in 0..CAPACITY {
+        assert_that!(unsafe { sut.push(i as u32) }, eq true);
+    }
+    assert_that!(unsafe { sut.push(0) }, eq false);
+
+    for i in 0..CAPACITY {
+        assert_that!(unsafe { sut.pop() }, eq Some(i as u32));
+    }
+    assert_that!(unsafe { sut.pop() }, is_none);
+}
+
+#[test]
+pub fn owning_index_queue_works() {
+    let sut = IndexQueue::new(3);
+
+    assert_that!(unsafe { sut.push(5) }, eq true);
+    assert_that!(unsafe { sut.push(6) }, eq true);
+    assert_that!(unsafe { sut.pop() }, eq Some(5));
+    assert_that!(unsafe { sut.pop() }, eq Some(6));
+    assert_that!(unsafe { sut.pop() }, is_none);
+}
+
+#[test]
+pub fn push_pop_works_with_multiple_producers_and_consumers() {
+    const NUMBER_OF_PRODUCERS: usize = 4;
+    const NUMBER_OF_CONSUMERS: usize = 4;
+    const VALUES_PER_PRODUCER: usize = 100000;
+    const CAPACITY: usize = 64;
+
+    let sut = FixedSizeIndexQueue::<CAPACITY>::new();
+    let received_sum = AtomicU64::new(0);
+    let received_values = AtomicU64::new(0);
+
+    let handle = BarrierHandle::new();
+    let barrier = BarrierBuilder::new((NUMBER_OF_PRODUCERS + NUMBER_OF_CONSUMERS) as u32)
+        .is_interprocess_capable(false)
+        .create(&handle)
+        .unwrap();
+
+    thread_scope(|s| {
+        for producer in 0..NUMBER_OF_PRODUCERS {
+            let sut = &sut;
+            let barrier = &barrier;
+            s.thread_builder()
+                .spawn(move || {
+                    barrier.wait();
+                    let mut last_value: Option<u32> = None;
+                    for i in 0..VALUES_PER_PRODUCER {
+                        let value = (producer * VALUES_PER_PRODUCER + i) as u32;
+                        while !sut.push(value) {}
+                        last_value = Some(value);
+                    }
+                    assert_that!(last_value, is_some);
+                })
+                .expect("failed to spawn thread");
+        }
+
+        for _ in 0..NUMBER_OF_CONSUMERS {
+            s.thread_builder()
+                .spawn(|| {
+                    // values of one producer must arrive in order
+                    let mut last_value_of_producer = [None; NUMBER_OF_PRODUCERS];
+                    barrier.wait();
+                    while received_values.load(Ordering::Relaxed)
+                        < (NUMBER_OF_PRODUCERS * VALUES_PER_PRODUCER) as u64
+                    {
+                        if let Some(value) = sut.pop() {
+                            let producer = value as usize / VALUES_PER_PRODUCER;
+                            if let Some(last) = last_value_of_producer[producer] {
+                                assert_that!(value, gt last);
+                            }
+                            last_value_of_producer[producer] = Some(value);
+
+                            received_sum.fetch_add(value as u64, Ordering::Relaxed);
+                            received_values.fetch_add(1, Ordering::Relaxed);
+                        }
+                    }
+                })
+                .expect("failed to spawn thread");
+        }
+
+        Ok(())
+    })
+    .expect("failed to run thread scope");
+
+    let n = (NUMBER_OF_PRODUCERS * VALUES_PER_PRODUCER) as u64;
+    assert_that!(received_values.load(Ordering::Relaxed), eq n);
+    assert_that!(received_sum.load(Ordering::Relaxed), eq n * (n - 1) / 2);
+    assert_that!(sut, is_empty);
+}
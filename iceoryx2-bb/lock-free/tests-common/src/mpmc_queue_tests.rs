@@ -0,0 +1,180 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use alloc::vec;
+
+use iceoryx2_bb_concurrency::atomic::{AtomicU64, Ordering};
+use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
+use iceoryx2_bb_elementary_traits::relocatable_container::RelocatableContainer;
+use iceoryx2_bb_lock_free::mpmc::queue::*;
+use iceoryx2_bb_posix::barrier::{BarrierBuilder, BarrierHandle, Handle};
+use iceoryx2_bb_posix::thread::thread_scope;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing_macros::test;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Payload {
+    value: u64,
+    checksum: u64,
+}
+
+impl Payload {
+    fn new(value: u64) -> Self {
+        Self {
+            value,
+            checksum: !value,
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        self.checksum == !self.value
+    }
+}
+
+#[test]
+pub fn relocatable_queue_works() {
+    const CAPACITY: usize = 12;
+    let mut memory = vec![0u8; RelocatableQueue::<Payload>::const_memory_size(CAPACITY)];
+    let allocator = BumpAllocator::new(
+        core::ptr::NonNull::new(memory.as_mut_ptr()).unwrap(),
+        RelocatableQueue::<Payload>::const_memory_size(CAPACITY),
+    );
+
+    let mut sut = unsafe { RelocatableQueue::<Payload>::new_uninit(CAPACITY) };
+    assert_that!(unsafe { sut.init(&allocator) }, is_ok);
+
+    assert_that!(sut.capacity(), eq CAPACITY);
+    for i in 0..CAPACITY as u64 {
+        assert_that!(unsafe { sut.push(&Payload::new(i)) }, eq true);
+    }
+    assert_that!(unsafe { sut.push(&Payload::new(0)) }, eq false);
+    assert_that!(sut.is_full(), eq true);
+
+    for i in 0..CAPACITY as u64 {
+        assert_that!(unsafe { sut.pop() }, eq Some(Payload::new(i)));
+    }
+    assert_that!(unsafe { sut.pop() }, is_none);
+    assert_that!(sut, is_empty);
+}
+
+#[test]
+pub fn push_works_until_full() {
+    const CAPACITY: usize = 128;
+    let sut = Queue::<i64, CAPACITY>::new();
+
+    assert_that!(sut.capacity(), eq CAPACITY);
+    assert_that!(sut, len 0);
+    assert_that!(sut.is_full(), eq false);
+    assert_that!(sut, is_empty);
+
+    for i in 0..CAPACITY {
+        assert_that!(sut, len i);
+        assert_that!(sut.push(&(i as i64)), eq true);
+    }
+    assert_that!(sut.push(&1234), eq false);
+
+    assert_that!(sut.capacity(), eq CAPACITY);
+    assert_that!(sut, len CAPACITY);
+    assert_that!(sut.is_full(), eq true);
+    assert_that!(sut, is_not_empty);
+}
+
+#[test]
+pub fn pop_works_until_empty() {
+    const CAPACITY: usize = 128;
+    let sut = Queue::<i64, CAPACITY>::new();
+    for i in 0..CAPACITY {
+        assert_that!(sut.push(&(i as i64)), eq true);
+    }
+
+    for i in 0..CAPACITY {
+        assert_that!(sut, len CAPACITY - i);
+        assert_that!(sut.pop(), eq Some(i as i64));
+    }
+    assert_that!(sut.pop(), is_none);
+
+    assert_that!(sut, len 0);
+    assert_that!(sut.is_full(), eq false);
+    assert_that!(sut, is_empty);
+}
+
+#[test]
+pub fn push_pop_alteration_works() {
+    const CAPACITY: usize = 128;
+    let sut = Queue::<Payload, CAPACITY>::new();
+
+    for i in 0..CAPACITY as u64 - 1 {
+        assert_that!(sut.push(&Payload::new(i)), eq true);
+        assert_that!(sut.push(&Payload::new(i)), eq true);
+
+        assert_that!(sut.pop(), eq Some(Payload::new(i / 2)));
+    }
+}
+
+#[test]
+pub fn push_pop_works_with_multiple_producers_and_consumers() {
+    const NUMBER_OF_PRODUCERS: usize = 4;
+    const NUMBER_OF_CONSUMERS: usize = 4;
+    const VALUES_PER_PRODUCER: u64 = 100000;
+    const CAPACITY: usize = 64;
+
+    let sut = Queue::<Payload, CAPACITY>::new();
+    let received_sum = AtomicU64::new(0);
+    let received_values = AtomicU64::new(0);
+
+    let handle = BarrierHandle::new();
+    let barrier = BarrierBuilder::new((NUMBER_OF_PRODUCERS + NUMBER_OF_CONSUMERS) as u32)
+        .is_interprocess_capable(false)
+        .create(&handle)
+        .unwrap();
+
+    thread_scope(|s| {
+        for producer in 0..NUMBER_OF_PRODUCERS as u64 {
+            let sut = &sut;
+            let barrier = &barrier;
+            s.thread_builder()
+                .spawn(move || {
+                    barrier.wait();
+                    for i in 0..VALUES_PER_PRODUCER {
+                        let payload = Payload::new(producer * VALUES_PER_PRODUCER + i);
+                        while !sut.push(&payload) {}
+                    }
+                })
+                .expect("failed to spawn thread");
+        }
+
+        for _ in 0..NUMBER_OF_CONSUMERS {
+            s.thread_builder()
+                .spawn(|| {
+                    barrier.wait();
+                    while received_values.load(Ordering::Relaxed)
+                        < NUMBER_OF_PRODUCERS as u64 * VALUES_PER_PRODUCER
+                    {
+                        if let Some(payload) = sut.pop() {
+                            assert_that!(payload.is_valid(), eq true);
+                            received_sum.fetch_add(payload.value, Ordering::Relaxed);
+                            received_values.fetch_add(1, Ordering::Relaxed);
+                        }
+                    }
+                })
+                .expect("failed to spawn thread");
+        }
+
+        Ok(())
+    })
+    .expect("failed to run thread scope");
+
+    let n = NUMBER_OF_PRODUCERS as u64 * VALUES_PER_PRODUCER;
+    assert_that!(received_values.load(Ordering::Relaxed), eq n);
+    assert_that!(received_sum.load(Ordering::Relaxed), eq n * (n - 1) / 2);
+    assert_that!(sut, is_empty);
+}
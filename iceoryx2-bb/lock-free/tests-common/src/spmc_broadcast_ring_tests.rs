@@ -0,0 +1,296 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_concurrency::atomic::{AtomicBool, Ordering};
+use iceoryx2_bb_lock_free::spmc::broadcast_ring::*;
+use iceoryx2_bb_posix::barrier::{BarrierBuilder, BarrierHandle, Handle};
+use iceoryx2_bb_posix::system_configuration::SystemInfo;
+use iceoryx2_bb_posix::thread::thread_scope;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing_macros::test;
+
+const CAPACITY: usize = 8;
+const NUMBER_OF_RUNS: u64 = 100000;
+const DATA_SIZE: usize = 256;
+
+#[test]
+pub fn new_ring_is_empty() {
+    let sut = BroadcastRing::<u64, CAPACITY>::new();
+
+    assert_that!(sut.capacity(), eq CAPACITY);
+    assert_that!(sut.is_empty(), eq true);
+    assert_that!(sut.number_of_published_values(), eq 0);
+    assert_that!(sut.oldest_sequence_number(), eq 0);
+    assert_that!(sut.load_latest(), is_none);
+    assert_that!(sut.load(0), eq Err(BroadcastRingLoadError::NotYetPublished));
+}
+
+#[test]
+pub fn acquire_multiple_producer_fails() {
+    let sut = BroadcastRing::<u64, CAPACITY>::new();
+
+    let p1 = sut.acquire_producer();
+    assert_that!(p1, is_some);
+    let p2 = sut.acquire_producer();
+    assert_that!(p2, is_none);
+
+    drop(p1);
+
+    let p3 = sut.acquire_producer();
+    assert_that!(p3, is_some);
+}
+
+#[test]
+pub fn publish_returns_consecutive_sequence_numbers() {
+    let sut = BroadcastRing::<u64, CAPACITY>::new();
+    let producer = sut.acquire_producer().unwrap();
+
+    for i in 0..3 * CAPACITY as u64 {
+        assert_that!(producer.publish(i * 2), eq i);
+        assert_that!(sut.number_of_published_values(), eq i + 1);
+        assert_that!(sut.is_empty(), eq false);
+    }
+}
+
+#[test]
+pub fn load_latest_returns_last_published_value() {
+    let sut = BroadcastRing::<u64, CAPACITY>::new();
+    let producer = sut.acquire_producer().unwrap();
+
+    for i in 0..3 * CAPACITY as u64 {
+        producer.publish(i * 3);
+        assert_that!(sut.load_latest(), eq Some(i * 3));
+    }
+}
+
+#[test]
+pub fn load_returns_contained_values_and_fails_for_overwritten_values() {
+    let sut = BroadcastRing::<u64, CAPACITY>::new();
+    let producer = sut.acquire_producer().unwrap();
+    let number_of_values = CAPACITY as u64 + 3;
+
+    for i in 0..number_of_values {
+        producer.publish(i * 5);
+    }
+
+    assert_that!(sut.oldest_sequence_number(), eq 3);
+    for i in 0..3 {
+        assert_that!(sut.load(i), eq Err(BroadcastRingLoadError::Overwritten));
+    }
+    for i in 3..number_of_values {
+        assert_that!(sut.load(i), eq Ok(i * 5));
+    }
+    assert_that!(sut.load(number_of_values), eq Err(BroadcastRingLoadError::NotYetPublished));
+}
+
+#[test]
+pub fn load_last_returns_values_oldest_first() {
+    let sut = BroadcastRing::<u64, CAPACITY>::new();
+    let producer = sut.acquire_producer().unwrap();
+    let mut values = [0u64; CAPACITY];
+
+    assert_that!(sut.load_last(&mut values), eq 0);
+
+    for i in 0..3 {
+        producer.publish(i);
+    }
+    assert_that!(sut.load_last(&mut values), eq 3);
+    assert_that!(values[..3], eq [0, 1, 2]);
+
+    for i in 3..20 {
+        producer.publish(i);
+    }
+    assert_that!(sut.load_last(&mut values), eq CAPACITY);
+    for (n, value) in values.iter().enumerate() {
+        assert_that!(*value, eq 20 - CAPACITY as u64 + n as u64);
+    }
+}
+
+#[test]
+pub fn load_last_with_small_buffer_returns_latest_values() {
+    let sut = BroadcastRing::<u64, CAPACITY>::new();
+    let producer = sut.acquire_producer().unwrap();
+    let mut values = [0u64; 2];
+
+    for i in 0..5 {
+        producer.publish(i);
+    }
+
+    assert_that!(sut.load_last(&mut values), eq 2);
+    assert_that!(values, eq [3, 4]);
+}
+
+#[test]
+pub fn load_last_with_large_buffer_returns_at_most_capacity_values() {
+    let sut = BroadcastRing::<u64, CAPACITY>::new();
+    let producer = sut.acquire_producer().unwrap();
+    let mut values = [0u64; 2 * CAPACITY];
+
+    for i in 0..3 * CAPACITY as u64 {
+        producer.publish(i);
+    }
+
+    assert_that!(sut.load_last(&mut values), eq CAPACITY);
+    assert_that!(values[0], eq 2 * CAPACITY as u64);
+}
+
+#[test]
+pub fn reader_returns_every_value_once() {
+    let sut = BroadcastRing::<u64, CAPACITY>::new();
+    let producer = sut.acquire_producer().unwrap();
+    producer.publish(1234);
+
+    let mut reader = sut.reader();
+    assert_that!(reader.next_sequence_number(), eq 1);
+    assert_that!(reader.read(), is_none);
+
+    for i in 0..3 * CAPACITY as u64 {
+        producer.publish(i);
+        assert_that!(reader.read(), eq Some(i));
+        assert_that!(reader.read(), is_none);
+    }
+    assert_that!(reader.number_of_missed_values(), eq 0);
+}
+
+#[test]
+pub fn reader_skips_overwritten_values() {
+    let sut = BroadcastRing::<u64, CAPACITY>::new();
+    let producer = sut.acquire_producer().unwrap();
+    let mut reader = sut.reader();
+
+    let number_of_values = 3 * CAPACITY as u64 + 2;
+    for i in 0..number_of_values {
+        producer.publish(i);
+    }
+
+    for i in number_of_values - CAPACITY as u64..number_of_values {
+        assert_that!(reader.read(), eq Some(i));
+    }
+    assert_that!(reader.read(), is_none);
+    assert_that!(reader.number_of_missed_values(), eq number_of_values - CAPACITY as u64);
+}
+
+#[test]
+pub fn reader_starting_at_sequence_number_returns_values_from_there() {
+    let sut = BroadcastRing::<u64, CAPACITY>::new();
+    let producer = sut.acquire_producer().unwrap();
+
+    for i in 0..5 {
+        producer.publish(i * 7);
+    }
+
+    let mut reader = sut.reader_starting_at(2);
+    for i in 2..5 {
+        assert_that!(reader.read(), eq Some(i * 7));
+    }
+    assert_that!(reader.read(), is_none);
+}
+
+#[test]
+pub fn readers_do_not_observe_torn_values_concurrently() {
+    let number_of_readers = SystemInfo::NumberOfCpuCores.value();
+    let publish_finished = AtomicBool::new(false);
+    let sut = BroadcastRing::<[u8; DATA_SIZE], CAPACITY>::new();
+    let handle = BarrierHandle::new();
+    let barrier = BarrierBuilder::new(number_of_readers as u32 + 1)
+        .create(&handle)
+        .unwrap();
+
+    let is_consistent = |rhs: &[u8; DATA_SIZE]| -> bool { rhs.iter().all(|v| *v == rhs[0]) };
+
+    thread_scope(|s| {
+        for _ in 0..number_of_readers {
+            s.thread_builder()
+                .spawn(|| {
+                    let mut values = [[0u8; DATA_SIZE]; CAPACITY];
+                    barrier.wait();
+                    while !publish_finished.load(Ordering::Relaxed) {
+                        if let Some(value) = sut.load_latest() {
+                            assert_that!(is_consistent(&value), eq true);
+                        }
+
+                        let number_of_values = sut.load_last(&mut values);
+                        for n in 1..number_of_values {
+                            assert_that!(is_consistent(&values[n]), eq true);
+                            assert_that!(values[n][0], eq values[n - 1][0].wrapping_add(1));
+                        }
+                    }
+                })
+                .expect("failed to spawn thread");
+        }
+
+        s.thread_builder()
+            .spawn(|| {
+                let producer = sut.acquire_producer().unwrap();
+                barrier.wait();
+
+                for i in 0..NUMBER_OF_RUNS {
+                    producer.publish([i as u8; DATA_SIZE]);
+                }
+
+                publish_finished.store(true, Ordering::Relaxed);
+            })
+            .expect("failed to spawn thread");
+
+        Ok(())
+    })
+    .expect("failed to run thread scope");
+}
+
+#[test]
+pub fn reader_follows_stream_concurrently() {
+    let publish_finished = AtomicBool::new(false);
+    let sut = BroadcastRing::<u64, CAPACITY>::new();
+    let handle = BarrierHandle::new();
+    let barrier = BarrierBuilder::new(2).create(&handle).unwrap();
+
+    thread_scope(|s| {
+        s.thread_builder()
+            .spawn(|| {
+                let mut reader = sut.reader_starting_at(0);
+                let mut number_of_read_values = 0;
+                barrier.wait();
+
+                loop {
+                    let is_finished = publish_finished.load(Ordering::Acquire);
+                    while let Some(value) = reader.read() {
+                        assert_that!(value, eq reader.next_sequence_number() - 1);
+                        number_of_read_values += 1;
+                    }
+
+                    if is_finished {
+                        break;
+                    }
+                }
+
+                assert_that!(reader.next_sequence_number(), eq NUMBER_OF_RUNS);
+                assert_that!(number_of_read_values + reader.number_of_missed_values(), eq NUMBER_OF_RUNS);
+            })
+            .expect("failed to spawn thread");
+
+        s.thread_builder()
+            .spawn(|| {
+                let producer = sut.acquire_producer().unwrap();
+                barrier.wait();
+
+                for i in 0..NUMBER_OF_RUNS {
+                    producer.publish(i);
+                }
+
+                publish_finished.store(true, Ordering::Release);
+            })
+            .expect("failed to spawn thread");
+
+        Ok(())
+    })
+    .expect("failed to run thread scope");
+}
@@ -0,0 +1,44 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Model checked tests of the lock-free constructs of `iceoryx2-bb-lock-free`. The tests
+//! explore all possible interleavings of the involved threads with
+//! [loom](https://docs.rs/loom) and are only compiled when the `loom` cfg is set.
+//!
+//! ```text
+//! RUSTFLAGS="--cfg loom" cargo test -p iceoryx2-bb-lock-free-tests-loom --release
+//! ```
+//!
+//! The number of preemptions per execution can be adjusted with the environment variable
+//! `LOOM_MAX_PREEMPTIONS`.
+
+extern crate iceoryx2_bb_loggers;
+
+#[cfg(loom)]
+mod model;
+
+#[cfg(loom)]
+pub mod mpmc_container_tests;
+#[cfg(loom)]
+pub mod mpmc_index_queue_tests;
+#[cfg(loom)]
+pub mod mpmc_queue_tests;
+#[cfg(loom)]
+pub mod mpmc_unique_index_set_tests;
+#[cfg(loom)]
+pub mod spmc_broadcast_ring_tests;
+#[cfg(loom)]
+pub mod spmc_unrestricted_atomic_tests;
+#[cfg(loom)]
+pub mod spsc_queue_tests;
+#[cfg(loom)]
+pub mod spsc_safely_overflowing_index_queue_tests;
@@ -0,0 +1,26 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use loom::model::Builder;
+
+const DEFAULT_MAX_PREEMPTIONS: usize = 3;
+
+/// Explores all interleavings of the threads spawned in `f`. Unless `LOOM_MAX_PREEMPTIONS`
+/// is set, the number of preemptions per execution is bounded to keep the state space
+/// manageable.
+pub fn check<F: Fn() + Sync + Send + 'static>(f: F) {
+    let mut builder = Builder::new();
+    if builder.preemption_bound.is_none() {
+        builder.preemption_bound = Some(DEFAULT_MAX_PREEMPTIONS);
+    }
+    builder.check(f);
+}
@@ -0,0 +1,77 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_lock_free::mpmc::container::*;
+use iceoryx2_bb_lock_free::mpmc::unique_index_set_enums::ReleaseMode;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing_macros::test;
+use loom::sync::Arc;
+use loom::thread;
+
+use crate::model;
+
+const CAPACITY: usize = 2;
+
+fn collect(state: &ContainerState<u64>) -> Vec<u64> {
+    let mut values = vec![];
+    state.for_each(|_, value| {
+        values.push(*value);
+        CallbackProgression::Continue
+    });
+    values.sort();
+    values
+}
+
+#[test]
+pub fn concurrently_added_elements_are_contained_in_state() {
+    model::check(|| {
+        let sut = Arc::new(FixedSizeContainer::<u64, CAPACITY>::new());
+        let owner_id = OwnerId::new(1).unwrap();
+
+        let other_sut = sut.clone();
+        let other = thread::spawn(move || other_sut.add(73, OwnerId::new(2).unwrap()).is_ok());
+
+        assert_that!(sut.add(37, owner_id), is_ok);
+        assert_that!(other.join().unwrap(), eq true);
+
+        assert_that!(collect(&sut.get_state()), eq vec![37, 73]);
+    });
+}
+
+#[test]
+pub fn update_state_observes_concurrent_add_and_remove() {
+    model::check(|| {
+        let sut = Arc::new(FixedSizeContainer::<u64, CAPACITY>::new());
+        let handle = sut.add(37, OwnerId::new(1).unwrap()).unwrap();
+
+        let writer_sut = sut.clone();
+        // The state copies the elements optimistically and discards the copy when the
+        // generation counter changed in the meantime. Loom reports such a read, which is
+        // concurrent to a write into the same slot, as data race. Therefore, the writer
+        // removes the element only after the new one was added so that no slot is reused.
+        let writer = thread::spawn(move || {
+            writer_sut.add(73, OwnerId::new(2).unwrap()).unwrap();
+            unsafe { writer_sut.remove(handle, ReleaseMode::Default) }.unwrap();
+        });
+
+        let mut state = sut.get_state();
+        let values = collect(&state);
+        // every intermediate state contains only values that were added
+        for value in &values {
+            assert_that!([37, 73], contains * value);
+        }
+
+        writer.join().unwrap();
+        unsafe { sut.update_state(&mut state) };
+        assert_that!(collect(&state), eq vec![73]);
+    });
+}
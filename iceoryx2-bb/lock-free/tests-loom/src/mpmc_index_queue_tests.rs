@@ -0,0 +1,89 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_lock_free::mpmc::index_queue::*;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing_macros::test;
+use loom::sync::Arc;
+use loom::thread;
+
+use crate::model;
+
+const CAPACITY: usize = 2;
+
+#[test]
+pub fn concurrently_pushed_values_are_popped_exactly_once() {
+    model::check(|| {
+        let sut = Arc::new(FixedSizeIndexQueue::<CAPACITY>::new());
+
+        let producer_sut = sut.clone();
+        let producer = thread::spawn(move || producer_sut.push(73));
+
+        assert_that!(sut.push(37), eq true);
+        assert_that!(producer.join().unwrap(), eq true);
+
+        let mut values = vec![sut.pop().unwrap(), sut.pop().unwrap()];
+        values.sort();
+        assert_that!(values, eq vec![37, 73]);
+        assert_that!(sut.pop(), is_none);
+    });
+}
+
+#[test]
+pub fn concurrent_consumers_never_acquire_the_same_value() {
+    model::check(|| {
+        let sut = Arc::new(FixedSizeIndexQueue::<CAPACITY>::new());
+        assert_that!(sut.push(37), eq true);
+        assert_that!(sut.push(73), eq true);
+
+        let consumer_sut = sut.clone();
+        let consumer = thread::spawn(move || consumer_sut.pop());
+
+        let value = sut.pop();
+        let other_value = consumer.join().unwrap();
+
+        let mut values = vec![value.unwrap(), other_value.unwrap()];
+        values.sort();
+        assert_that!(values, eq vec![37, 73]);
+        assert_that!(sut, is_empty);
+    });
+}
+
+#[test]
+pub fn concurrent_push_and_pop_preserves_order() {
+    model::check(|| {
+        let sut = Arc::new(FixedSizeIndexQueue::<CAPACITY>::new());
+
+        let producer_sut = sut.clone();
+        let producer = thread::spawn(move || {
+            for value in 0..3 {
+                while !producer_sut.push(value) {
+                    thread::yield_now();
+                }
+            }
+        });
+
+        let mut expected_value = 0;
+        while expected_value < 3 {
+            match sut.pop() {
+                Some(value) => {
+                    assert_that!(value, eq expected_value);
+                    expected_value += 1;
+                }
+                None => thread::yield_now(),
+            }
+        }
+
+        producer.join().unwrap();
+        assert_that!(sut.pop(), is_none);
+    });
+}
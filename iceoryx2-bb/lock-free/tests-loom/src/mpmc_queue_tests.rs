@@ -0,0 +1,74 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_lock_free::mpmc::queue::*;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing_macros::test;
+use loom::sync::Arc;
+use loom::thread;
+
+use crate::model;
+
+const CAPACITY: usize = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Payload {
+    value: u64,
+    checksum: u64,
+}
+
+impl Payload {
+    fn new(value: u64) -> Self {
+        Self {
+            value,
+            checksum: !value,
+        }
+    }
+}
+
+#[test]
+pub fn concurrently_pushed_values_are_popped_intact() {
+    model::check(|| {
+        let sut = Arc::new(Queue::<Payload, CAPACITY>::new());
+
+        let producer_sut = sut.clone();
+        let producer = thread::spawn(move || producer_sut.push(&Payload::new(73)));
+
+        assert_that!(sut.push(&Payload::new(37)), eq true);
+        assert_that!(producer.join().unwrap(), eq true);
+
+        let mut values = vec![sut.pop().unwrap().value, sut.pop().unwrap().value];
+        values.sort();
+        assert_that!(values, eq vec![37, 73]);
+        assert_that!(sut.pop(), is_none);
+    });
+}
+
+#[test]
+pub fn slot_is_reused_only_after_the_consumer_has_read_it() {
+    model::check(|| {
+        let sut = Arc::new(Queue::<Payload, 1>::new());
+        assert_that!(sut.push(&Payload::new(37)), eq true);
+
+        let consumer_sut = sut.clone();
+        let consumer = thread::spawn(move || consumer_sut.pop());
+
+        // the push succeeds only when the consumer released the single slot
+        let is_pushed = sut.push(&Payload::new(73));
+        assert_that!(consumer.join().unwrap(), eq Some(Payload::new(37)));
+
+        if is_pushed {
+            assert_that!(sut.pop(), eq Some(Payload::new(73)));
+        }
+        assert_that!(sut, is_empty);
+    });
+}
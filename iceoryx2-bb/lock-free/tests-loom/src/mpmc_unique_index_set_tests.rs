@@ -0,0 +1,92 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_lock_free::mpmc::unique_index_set::*;
+use iceoryx2_bb_lock_free::mpmc::unique_index_set_enums::*;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing_macros::test;
+use loom::sync::Arc;
+use loom::thread;
+
+use crate::model;
+
+const CAPACITY: usize = 2;
+
+// an acquire that loses the race for the head reads the next free index of the old head while
+// the winner overwrites it, therefore the next free indices must be atomic
+#[test]
+pub fn concurrently_acquired_indices_are_unique() {
+    model::check(|| {
+        let sut = Arc::new(FixedSizeUniqueIndexSet::<CAPACITY>::new());
+
+        let other_sut = sut.clone();
+        let other = thread::spawn(move || unsafe { other_sut.acquire_raw_index() }.ok());
+
+        let index = unsafe { sut.acquire_raw_index() }.ok();
+        let other_index = other.join().unwrap();
+
+        assert_that!(index, is_some);
+        assert_that!(other_index, is_some);
+        assert_that!(index, ne other_index);
+        assert_that!(sut.borrowed_indices(), eq 2);
+    });
+}
+
+#[test]
+pub fn concurrently_released_indices_can_be_acquired_again() {
+    model::check(|| {
+        let sut = Arc::new(FixedSizeUniqueIndexSet::<CAPACITY>::new());
+        let index = unsafe { sut.acquire_raw_index() }.unwrap();
+
+        let other_sut = sut.clone();
+        let other = thread::spawn(move || {
+            let other_index = unsafe { other_sut.acquire_raw_index() }.unwrap();
+            unsafe { other_sut.release_raw_index(other_index, ReleaseMode::Default) };
+        });
+
+        unsafe { sut.release_raw_index(index, ReleaseMode::Default) };
+        other.join().unwrap();
+
+        assert_that!(sut.borrowed_indices(), eq 0);
+        let first = unsafe { sut.acquire_raw_index() };
+        let second = unsafe { sut.acquire_raw_index() };
+        assert_that!(first, is_ok);
+        assert_that!(second, is_ok);
+        assert_that!(first.unwrap(), ne second.unwrap());
+    });
+}
+
+#[test]
+pub fn lock_if_last_index_prevents_concurrent_acquire() {
+    model::check(|| {
+        let sut = Arc::new(FixedSizeUniqueIndexSet::<CAPACITY>::new());
+        let index = unsafe { sut.acquire_raw_index() }.unwrap();
+
+        let other_sut = sut.clone();
+        let other = thread::spawn(move || unsafe { other_sut.acquire_raw_index() });
+
+        let release_state = unsafe { sut.release_raw_index(index, ReleaseMode::LockIfLastIndex) };
+        let other_index = other.join().unwrap();
+
+        match other_index {
+            // the index was acquired before the release, therefore the set cannot be locked
+            Ok(_) => {
+                assert_that!(release_state, eq ReleaseState::Unlocked);
+                assert_that!(sut.is_locked(), eq false);
+            }
+            Err(e) => {
+                assert_that!(e, eq UniqueIndexSetAcquireFailure::IsLocked);
+                assert_that!(release_state, eq ReleaseState::Locked);
+            }
+        }
+    });
+}
@@ -0,0 +1,113 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+use iceoryx2_bb_lock_free::spmc::broadcast_ring::*;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing_macros::test;
+use loom::sync::Arc;
+use loom::thread;
+
+use crate::model;
+
+const CAPACITY: usize = 4;
+
+type Value = [u64; 4];
+
+fn value(n: u64) -> Value {
+    [n; 4]
+}
+
+fn verify_is_consistent(value: &Value) {
+    for element in value {
+        assert_that!(*element, eq value[0]);
+    }
+}
+
+// A load copies the slot optimistically and verifies the sequence of the slot afterwards.
+// Loom reports such a copy, which is concurrent to a write into the same slot, as data race.
+// Therefore, the producer publishes fewer values than the capacity so that the slots are not
+// reused during the model run.
+
+#[test]
+pub fn concurrent_load_latest_returns_published_values_in_order() {
+    model::check(|| {
+        let sut = Arc::new(BroadcastRing::<Value, CAPACITY>::new());
+
+        let producer_sut = sut.clone();
+        let producer = thread::spawn(move || {
+            let producer = producer_sut.acquire_producer().unwrap();
+            producer.publish(value(1));
+            producer.publish(value(2));
+        });
+
+        let mut previous = 0;
+        while previous != 2 {
+            if let Some(latest) = sut.load_latest() {
+                verify_is_consistent(&latest);
+                assert_that!(latest[0], ge previous);
+                previous = latest[0];
+            }
+            thread::yield_now();
+        }
+
+        producer.join().unwrap();
+    });
+}
+
+#[test]
+pub fn concurrent_reader_returns_every_value_once() {
+    model::check(|| {
+        let sut = Arc::new(BroadcastRing::<Value, CAPACITY>::new());
+
+        let producer_sut = sut.clone();
+        let producer = thread::spawn(move || {
+            let producer = producer_sut.acquire_producer().unwrap();
+            producer.publish(value(0));
+            producer.publish(value(1));
+        });
+
+        let mut reader = sut.reader_starting_at(0);
+        let mut expected = 0;
+        while expected != 2 {
+            if let Some(received) = reader.read() {
+                assert_that!(received, eq value(expected));
+                expected += 1;
+            }
+            thread::yield_now();
+        }
+        assert_that!(reader.number_of_missed_values(), eq 0);
+
+        producer.join().unwrap();
+    });
+}
+
+#[test]
+pub fn concurrent_load_last_returns_consecutive_values() {
+    model::check(|| {
+        let sut = Arc::new(BroadcastRing::<Value, CAPACITY>::new());
+
+        let producer_sut = sut.clone();
+        let producer = thread::spawn(move || {
+            let producer = producer_sut.acquire_producer().unwrap();
+            producer.publish(value(0));
+            producer.publish(value(1));
+        });
+
+        let mut values = [value(0); CAPACITY];
+        let number_of_values = sut.load_last(&mut values);
+        for (n, v) in values.iter().take(number_of_values).enumerate() {
+            assert_that!(*v, eq value(n as u64));
+        }
+
+        producer.join().unwrap();
+        assert_that!(sut.load_last(&mut values), eq 2);
+    });
+}
@@ -0,0 +1,101 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_lock_free::spmc::unrestricted_atomic::*;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing_macros::test;
+use loom::sync::Arc;
+use loom::thread;
+
+use crate::model;
+
+type Value = [u64; 4];
+
+fn value(n: u64) -> Value {
+    [n; 4]
+}
+
+fn verify_is_consistent(value: &Value) {
+    for element in value {
+        assert_that!(*element, eq value[0]);
+    }
+}
+
+#[test]
+pub fn concurrent_load_never_returns_partially_written_value() {
+    model::check(|| {
+        let sut = Arc::new(UnrestrictedAtomic::<Value>::new(value(0)));
+
+        // A load copies the cell optimistically and retries when the producer has written
+        // into the same cell in the meantime. Loom reports such a copy, which is concurrent
+        // to a write into the same cell, as data race. Therefore, the producer stores only
+        // one value so that the cells are not reused during the model run.
+        let producer_sut = sut.clone();
+        let producer = thread::spawn(move || {
+            let producer = producer_sut.acquire_producer().unwrap();
+            producer.store(value(1));
+        });
+
+        let first = sut.load();
+        verify_is_consistent(&first);
+        let second = sut.load();
+        verify_is_consistent(&second);
+        assert_that!(first[0], le second[0]);
+
+        producer.join().unwrap();
+        assert_that!(sut.load(), eq value(1));
+    });
+}
+
+#[test]
+pub fn multiple_consumers_load_consistent_values() {
+    model::check(|| {
+        let sut = Arc::new(UnrestrictedAtomic::<Value>::new(value(0)));
+
+        let consumer_sut = sut.clone();
+        let consumer = thread::spawn(move || {
+            let loaded = consumer_sut.load();
+            verify_is_consistent(&loaded);
+        });
+
+        let producer_sut = sut.clone();
+        let producer = thread::spawn(move || {
+            let producer = producer_sut.acquire_producer().unwrap();
+            producer.store(value(1));
+        });
+
+        let loaded = sut.load();
+        verify_is_consistent(&loaded);
+
+        consumer.join().unwrap();
+        producer.join().unwrap();
+        assert_that!(sut.load(), eq value(1));
+    });
+}
+
+#[test]
+pub fn only_one_producer_can_be_acquired_concurrently() {
+    model::check(|| {
+        let sut = Arc::new(UnrestrictedAtomic::<Value>::new(value(0)));
+
+        let other_sut = sut.clone();
+        let other = thread::spawn(move || other_sut.acquire_producer().is_some());
+
+        let producer = sut.acquire_producer();
+        let has_acquired = producer.is_some();
+        drop(producer);
+        let other_has_acquired = other.join().unwrap();
+
+        assert_that!(has_acquired || other_has_acquired, eq true);
+        assert_that!(sut.acquire_producer(), is_some);
+    });
+}
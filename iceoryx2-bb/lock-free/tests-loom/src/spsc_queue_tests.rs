@@ -0,0 +1,81 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_lock_free::spsc::queue::*;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing_macros::test;
+use loom::sync::Arc;
+use loom::thread;
+
+use crate::model;
+
+const CAPACITY: usize = 2;
+const NUMBER_OF_VALUES: u64 = 3;
+
+#[test]
+pub fn concurrent_push_and_pop_preserves_order() {
+    model::check(|| {
+        let sut = Arc::new(Queue::<u64, CAPACITY>::new());
+
+        let producer_sut = sut.clone();
+        let producer = thread::spawn(move || {
+            let mut producer = producer_sut.acquire_producer().unwrap();
+            for value in 0..NUMBER_OF_VALUES {
+                while !producer.push(&value) {
+                    thread::yield_now();
+                }
+            }
+        });
+
+        let mut consumer = sut.acquire_consumer().unwrap();
+        let mut expected_value = 0;
+        while expected_value < NUMBER_OF_VALUES {
+            match consumer.pop() {
+                Some(value) => {
+                    assert_that!(value, eq expected_value);
+                    expected_value += 1;
+                }
+                None => thread::yield_now(),
+            }
+        }
+
+        producer.join().unwrap();
+        assert_that!(consumer.pop(), is_none);
+    });
+}
+
+#[test]
+pub fn concurrent_pop_never_returns_more_than_was_pushed() {
+    model::check(|| {
+        let sut = Arc::new(Queue::<u64, CAPACITY>::new());
+
+        let consumer_sut = sut.clone();
+        let consumer = thread::spawn(move || {
+            let mut consumer = consumer_sut.acquire_consumer().unwrap();
+            let mut received = 0;
+            while let Some(value) = consumer.pop() {
+                assert_that!(value, eq received);
+                received += 1;
+            }
+            received
+        });
+
+        let mut producer = sut.acquire_producer().unwrap();
+        for value in 0..CAPACITY as u64 {
+            assert_that!(producer.push(&value), eq true);
+        }
+
+        let received = consumer.join().unwrap();
+        assert_that!(received, le CAPACITY as u64);
+        assert_that!(sut.len(), eq CAPACITY - received as usize);
+    });
+}
@@ -0,0 +1,73 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_lock_free::spsc::safely_overflowing_index_queue::*;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing_macros::test;
+use loom::sync::Arc;
+use loom::thread;
+
+use crate::model;
+
+const CAPACITY: usize = 2;
+// The consumer reads a value optimistically and discards it when the producer has overflown
+// the queue in the meantime. Loom reports such a read, which is concurrent to a write into
+// the same slot, as data race. With one value more than the capacity the queue overflows but
+// the producer never writes into a slot twice.
+const NUMBER_OF_VALUES: u64 = CAPACITY as u64 + 1;
+
+#[test]
+pub fn every_value_is_either_received_or_returned_as_overflow() {
+    model::check(|| {
+        let sut = Arc::new(FixedSizeSafelyOverflowingIndexQueue::<CAPACITY>::new());
+
+        let producer_sut = sut.clone();
+        let producer = thread::spawn(move || {
+            let mut producer = producer_sut.acquire_producer().unwrap();
+            let mut overflow = vec![];
+            for value in 0..NUMBER_OF_VALUES {
+                if let Some(v) = producer.push(value) {
+                    overflow.push(v);
+                }
+            }
+            overflow
+        });
+
+        let mut consumer = sut.acquire_consumer().unwrap();
+        let mut received = vec![];
+        loop {
+            match consumer.pop() {
+                Some(value) => {
+                    received.push(value);
+                    break;
+                }
+                None => thread::yield_now(),
+            }
+        }
+
+        let overflow = producer.join().unwrap();
+        while let Some(value) = consumer.pop() {
+            received.push(value);
+        }
+
+        // the consumer receives the values in order
+        for pair in received.windows(2) {
+            assert_that!(pair[0], lt pair[1]);
+        }
+
+        // no value is lost or duplicated
+        let mut all_values = received;
+        all_values.extend_from_slice(&overflow);
+        all_values.sort();
+        assert_that!(all_values, eq(0..NUMBER_OF_VALUES).collect::<Vec<_>>());
+    });
+}
@@ -51,8 +51,8 @@
 
 pub use core::alloc::Layout;
 use iceoryx2_bb_concurrency::atomic::AtomicBool;
+use iceoryx2_bb_concurrency::atomic::AtomicU32;
 use iceoryx2_bb_concurrency::atomic::Ordering;
-use iceoryx2_bb_concurrency::cell::UnsafeCell;
 use iceoryx2_bb_derive_macros::ZeroCopySend;
 use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
 use iceoryx2_bb_elementary::math::align;
@@ -295,8 +295,8 @@ impl Allocator for PoolAllocator {
 #[repr(C)]
 pub struct FixedSizePoolAllocator<const MAX_NUMBER_OF_BUCKETS: usize> {
     state: PoolAllocator,
-    next_free_index: [UnsafeCell<u32>; MAX_NUMBER_OF_BUCKETS],
-    next_free_index_plus_one: UnsafeCell<u32>,
+    next_free_index: [AtomicU32; MAX_NUMBER_OF_BUCKETS],
+    next_free_index_plus_one: AtomicU32,
 }
 
 unsafe impl<const MAX_NUMBER_OF_BUCKETS: usize> Send
@@ -345,8 +345,8 @@ impl<const MAX_NUMBER_OF_BUCKETS: usize> FixedSizePoolAllocator<MAX_NUMBER_OF_BU
                 size,
                 is_memory_initialized: AtomicBool::new(true),
             },
-            next_free_index: core::array::from_fn(|i| UnsafeCell::new(i as u32 + 1)),
-            next_free_index_plus_one: UnsafeCell::new(MAX_NUMBER_OF_BUCKETS as u32 + 1),
+            next_free_index: core::array::from_fn(|i| AtomicU32::new(i as u32 + 1)),
+            next_free_index_plus_one: AtomicU32::new(MAX_NUMBER_OF_BUCKETS as u32 + 1),
         };
 
         // SAFETY: Creating a pointer to an existing member is always not null
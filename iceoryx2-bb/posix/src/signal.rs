@@ -74,6 +74,7 @@ use crate::{
     mutex::*,
 };
 use enum_iterator::{Sequence, all};
+#[cfg(not(all(loom, feature = "std")))]
 use iceoryx2_bb_concurrency::atomic::AtomicUsize;
 use iceoryx2_bb_concurrency::atomic::Ordering;
 use iceoryx2_bb_concurrency::lazy_lock::LazyLock;
@@ -283,13 +284,15 @@ impl Drop for SignalGuard {
     }
 }
 
-#[cfg(not(all(test, loom, feature = "std")))]
+#[cfg(not(all(loom, feature = "std")))]
 static LAST_SIGNAL: AtomicUsize = AtomicUsize::new(posix::MAX_SIGNAL_VALUE);
 
-#[cfg(all(test, loom, feature = "std"))]
-static LAST_SIGNAL: std::sync::LazyLock<IoxAtomicUsize> = std::sync::LazyLock::new(|| {
-    unimplemented!("loom does not provide const-initialization for atomic variables.")
-});
+// loom atomics cannot be const-initialized and are only usable inside a loom model, the
+// process-global signal state is never part of one
+#[cfg(all(loom, feature = "std"))]
+#[allow(clippy::disallowed_types)]
+static LAST_SIGNAL: core::sync::atomic::AtomicUsize =
+    core::sync::atomic::AtomicUsize::new(posix::MAX_SIGNAL_VALUE);
 
 /// Manages POSIX signal handling. It provides an interface to register custom callbacks for
 /// signals, to perform a blocking wait until a certain signal arrived (for instance like CTRL+c) and
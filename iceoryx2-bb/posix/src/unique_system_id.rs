@@ -39,6 +39,7 @@
 //! }
 //! ```
 
+#[cfg(not(all(loom, feature = "std")))]
 use iceoryx2_bb_concurrency::atomic::AtomicU32;
 use iceoryx2_bb_concurrency::atomic::Ordering;
 use iceoryx2_bb_derive_macros::ZeroCopySend;
@@ -140,12 +141,13 @@ impl UniqueSystemId {
     }
 
     fn create(pid: u32, now: Time) -> UniqueSystemId {
-        #[cfg(not(all(test, loom, feature = "std")))]
+        #[cfg(not(all(loom, feature = "std")))]
         static COUNTER: AtomicU32 = AtomicU32::new(0);
-        #[cfg(all(test, loom, feature = "std"))]
-        static COUNTER: std::sync::LazyLock<IoxAtomicU32> = std::sync::LazyLock::new(|| {
-            unimplemented!("loom does not provide const-initialization for atomic variables.")
-        });
+        // loom atomics cannot be const-initialized and are only usable inside a loom model,
+        // the process-global counter is never part of one
+        #[cfg(all(loom, feature = "std"))]
+        #[allow(clippy::disallowed_types)]
+        static COUNTER: core::sync::atomic::AtomicU32 = core::sync::atomic::AtomicU32::new(0);
 
         let counter = COUNTER.fetch_add(1, Ordering::Relaxed);
 
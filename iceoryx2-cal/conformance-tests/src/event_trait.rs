@@ -881,6 +881,38 @@ pub mod event_trait {
         assert_that!(sut_notifier.max_event_count(), eq sut_listener.max_event_count());
     }
 
+    #[conformance_test]
+    pub fn notifications_rejected_by_event_id_filter_are_not_delivered<
+        E: EventState,
+        Sut: Event<E>,
+    >() {
+        let name = generate_file_path().file_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_listener = Sut::ListenerBuilder::new(&name)
+            .event_id_max(EventId::new(16))
+            .event_id_filter(EventIdFilter::new().range(EventId::new(4), EventId::new(12)))
+            .config(&config)
+            .create()
+            .unwrap();
+        let sut_notifier = Sut::NotifierBuilder::new(&name)
+            .config(&config)
+            .open()
+            .unwrap();
+
+        for id in [3, 13] {
+            let result = sut_notifier.notify(EventId::new(id));
+            assert_that!(result.err(), eq Some(NotifierNotifyError::EventIdNotAccepted));
+        }
+        assert_that!(sut_notifier.notify(EventId::new(4)), is_ok);
+        assert_that!(sut_notifier.notify(EventId::new(12)), is_ok);
+
+        let mut ids = vec![];
+        sut_listener.try_wait(|event| ids.push(event.id)).unwrap();
+        ids.sort();
+        assert_that!(ids, eq vec![EventId::new(4), EventId::new(12)]);
+    }
+
     #[conformance_test]
     pub fn events_are_counted<E: EventState, Sut: Event<E>>() {
         let _watchdog = Watchdog::new();
@@ -15,9 +15,9 @@ use crate::{
         DynamicStorage, DynamicStorageBuilder, DynamicStorageCreateError, DynamicStorageOpenError,
     },
     event::{
-        DEFAULT_MAX_EVENT_ID, Event, EventId, Listener, ListenerBuilder, ListenerCreateError,
-        ListenerWaitError, NamedConcept, NamedConceptBuilder, NamedConceptMgmt, Notifier,
-        NotifierBuilder, NotifierNotifyError, NotifierOpenError,
+        DEFAULT_MAX_EVENT_ID, Event, EventId, EventIdFilter, Listener, ListenerBuilder,
+        ListenerCreateError, ListenerWaitError, NamedConcept, NamedConceptBuilder,
+        NamedConceptMgmt, Notifier, NotifierBuilder, NotifierNotifyError, NotifierOpenError,
         event_state::{EventActivation, EventState},
         trigger::{HandlerInterface, State, WaiterInterface, stub::Stub},
     },
@@ -310,6 +310,12 @@ impl<
         let msg = "Unable to notify";
         let mgmt = self.storage.get();
 
+        if !mgmt.event_id_filter.accepts(event_id) {
+            fail!(from self, with NotifierNotifyError::EventIdNotAccepted,
+                "{msg} with {event_id:?} since the listener does not accept it ({:?}).",
+                mgmt.event_id_filter);
+        }
+
         fail!(from self,
               when mgmt.event.activate(event_id),
               "{msg} with {event_id:?} since the activation failed.");
@@ -609,6 +615,7 @@ pub struct WaiterBuilder<
 > {
     name: FileName,
     event_id_max: EventId,
+    event_id_filter: EventIdFilter,
     config: Configuration<E, Mgmt, Storage>,
     _data_1: PhantomData<H>,
     _data_2: PhantomData<W>,
@@ -626,6 +633,7 @@ impl<
         Self {
             name: *name,
             event_id_max: DEFAULT_MAX_EVENT_ID,
+            event_id_filter: EventIdFilter::new(),
             config: Configuration::default(),
             _data_1: PhantomData,
             _data_2: PhantomData,
@@ -654,6 +662,11 @@ impl<
         self
     }
 
+    fn event_id_filter(mut self, filter: EventIdFilter) -> Self {
+        self.event_id_filter = filter;
+        self
+    }
+
     fn create(
         self,
     ) -> Result<<EventImpl<E, Mgmt, Storage, H, W> as Event<E>>::Listener, ListenerCreateError>
@@ -670,6 +683,7 @@ impl<
                     event: unsafe { E::new_uninit(self.event_id_max.as_value() + 1) },
                     handle: MaybeUninit::uninit(),
                     event_id_max: self.event_id_max,
+                    event_id_filter: self.event_id_filter,
                     notification_state: AtomicU8::new(NOTIFICATION_STATE_IDLE)
                 });
 
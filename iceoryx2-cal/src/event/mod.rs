@@ -44,11 +44,90 @@ impl EventId {
     }
 }
 
+/// Defines which [`EventId`]s are accepted by a [`Listener`]. An [`EventId`] is accepted when
+/// it is contained in the range `[min, max]` and when `event_id & mask == pattern & mask`.
+/// By default, all [`EventId`]s are accepted.
+///
+/// The filter is stored in the shared trigger state and enforced by the [`Notifier`] before it
+/// activates the [`EventState`]. The [`EventState`] implementations, like the
+/// `RelocatableCountingBitSet`, are unaware of it.
+#[derive(ZeroCopySend, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[repr(C)]
+pub struct EventIdFilter {
+    min: EventId,
+    max: EventId,
+    mask: usize,
+    pattern: usize,
+}
+
+impl Default for EventIdFilter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EventIdFilter {
+    /// Creates a new [`EventIdFilter`] that accepts all [`EventId`]s.
+    pub const fn new() -> Self {
+        Self {
+            min: EventId::new(0),
+            max: EventId::new(usize::MAX),
+            mask: 0,
+            pattern: 0,
+        }
+    }
+
+    /// Restricts the accepted [`EventId`]s to the range `[min, max]`.
+    pub const fn range(mut self, min: EventId, max: EventId) -> Self {
+        self.min = min;
+        self.max = max;
+        self
+    }
+
+    /// Restricts the accepted [`EventId`]s to the ones that satisfy
+    /// `event_id & mask == pattern & mask`.
+    pub const fn mask(mut self, mask: usize, pattern: usize) -> Self {
+        self.mask = mask;
+        self.pattern = pattern;
+        self
+    }
+
+    /// Returns the smallest accepted [`EventId`].
+    pub const fn min(&self) -> EventId {
+        self.min
+    }
+
+    /// Returns the largest accepted [`EventId`].
+    pub const fn max(&self) -> EventId {
+        self.max
+    }
+
+    /// Returns the mask that is applied to an [`EventId`] before it is compared with the
+    /// pattern.
+    pub const fn bit_mask(&self) -> usize {
+        self.mask
+    }
+
+    /// Returns the pattern the masked [`EventId`] must match.
+    pub const fn pattern(&self) -> usize {
+        self.pattern
+    }
+
+    /// Returns true when the [`EventId`] is accepted, otherwise false.
+    pub const fn accepts(&self, event_id: EventId) -> bool {
+        let value = event_id.as_value();
+        self.min.as_value() <= value
+            && value <= self.max.as_value()
+            && value & self.mask == self.pattern & self.mask
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum NotifierNotifyError {
     Interrupt,
     BufferIsFull,
     EventIdOutOfBounds,
+    EventIdNotAccepted,
     InsufficientPermissions,
     Disconnected,
     InternalFailure,
@@ -149,6 +228,7 @@ pub trait NotifierBuilder<E: EventState, T: Event<E>>: NamedConceptBuilder<T> +
 
 pub trait ListenerBuilder<E: EventState, T: Event<E>>: NamedConceptBuilder<T> + Debug {
     fn event_id_max(self, id: EventId) -> Self;
+    fn event_id_filter(self, filter: EventIdFilter) -> Self;
     fn create(self) -> Result<T::Listener, ListenerCreateError>;
 }
 
@@ -11,7 +11,8 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use crate::event::{
-    EventId, ListenerCreateError, ListenerWaitError, NotifierNotifyError, NotifierOpenError,
+    EventId, EventIdFilter, ListenerCreateError, ListenerWaitError, NotifierNotifyError,
+    NotifierOpenError,
 };
 use crate::named_concept::NamedConceptRemoveError;
 use crate::{dynamic_storage::DynamicStorage, event::event_state::EventState};
@@ -63,6 +64,11 @@ pub struct State<E: EventState, Mgmt: ZeroCopySend + Send + Sync + Debug> {
     pub event: E,
     pub handle: MaybeUninit<Mgmt>,
     pub event_id_max: EventId,
+    /// Checked in [`Notifier::notify()`](crate::event::Notifier::notify()) before `event` is
+    /// activated. The [`EventState`] itself accepts every [`EventId`] up to `event_id_max` and
+    /// the listener does not filter again when it drains it, therefore anyone who activates
+    /// `event` directly bypasses the filter.
+    pub event_id_filter: EventIdFilter,
     pub notification_state: AtomicU8,
 }
 
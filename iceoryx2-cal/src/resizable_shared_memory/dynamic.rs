@@ -49,7 +49,8 @@ use super::{
     NamedConcept, NamedConceptBuilder, NamedConceptDoesExistError, NamedConceptListError,
     NamedConceptMgmt, NamedConceptRemoveError, ResizableSharedMemory, ResizableSharedMemoryBuilder,
     ResizableSharedMemoryForPoolAllocator, ResizableSharedMemoryView,
-    ResizableSharedMemoryViewBuilder, ResizableShmAllocationError,
+    ResizableSharedMemoryViewBuilder, ResizableSharedMemoryViewForPoolAllocator,
+    ResizableShmAllocationError,
 };
 
 const MAX_NUMBER_OF_REALLOCATIONS: usize = SegmentId::max_segment_id() as usize + 1;
@@ -744,6 +745,23 @@ where
     }
 }
 
+impl<Shm: SharedMemoryForPoolAllocator> ResizableSharedMemoryViewForPoolAllocator<Shm>
+    for DynamicView<PoolAllocator, Shm>
+where
+    Shm::Builder: Debug,
+{
+    fn bucket_size(&self, segment_id: SegmentId) -> usize {
+        let segment_id_key = SlotMapKey::new(segment_id.value() as usize);
+        let shared_memory_map = unsafe { &*self.shared_memory_map.get() };
+        match shared_memory_map.get(segment_id_key) {
+            Some(entry) => entry.shm.bucket_size(),
+            None => fatal_panic!(from self,
+                        "This should never happen! Unable to acquire bucket size since the segment {:?} is not mapped.",
+                        segment_id),
+        }
+    }
+}
+
 impl<Shm: SharedMemoryForPoolAllocator> ResizableSharedMemoryForPoolAllocator<Shm>
     for DynamicMemory<PoolAllocator, Shm>
 where
@@ -245,8 +245,17 @@ pub trait ResizableSharedMemory<Allocator: ShmAllocator, Shm: SharedMemory<Alloc
     unsafe fn deallocate(&self, offset: PointerOffset, layout: core::alloc::Layout);
 }
 
+pub trait ResizableSharedMemoryViewForPoolAllocator<Shm: SharedMemory<PoolAllocator>>:
+    ResizableSharedMemoryView<PoolAllocator, Shm>
+{
+    /// Returns the bucket size of the [`PoolAllocator`] of the segment. The segment must be
+    /// mapped, meaning at least one [`PointerOffset`] of it must be registered via
+    /// [`ResizableSharedMemoryView::register_and_translate_offset()`].
+    fn bucket_size(&self, segment_id: SegmentId) -> usize;
+}
+
 pub trait ResizableSharedMemoryForPoolAllocator<Shm: SharedMemory<PoolAllocator>>:
-    ResizableSharedMemory<PoolAllocator, Shm>
+    ResizableSharedMemory<PoolAllocator, Shm, View: ResizableSharedMemoryViewForPoolAllocator<Shm>>
 {
     /// Release previously allocated memory
     ///
@@ -184,14 +184,14 @@ impl ChannelState {
     pub fn new(value: u64) -> Result<Self, ChannelStateNewError> {
         if value > Self::max_value() {
             fail!(from "ChannelState::new()", with ChannelStateNewError::ValueOutOfBounds,
-                "Unable to create new ChannelState since the value must be less or equal than 2^62 and this value is {value},");
+                "Unable to create new ChannelState since the value must be less than 2^62 and this value is {value},");
         }
 
         Ok(Self(value))
     }
 
     pub const fn max_value() -> u64 {
-        2u64.pow(62)
+        2u64.pow(62) - 1
     }
 
     pub fn value(&self) -> u64 {
@@ -211,6 +211,10 @@ pub const CHANNEL_STATE_OPEN: ChannelState = ChannelState(0);
 pub const CHANNEL_STATE_CLOSED: ChannelState = ChannelState(u64::MAX);
 /// Hints the channel that the other side intends to disconnect.
 const CHANNEL_STATE_DISCONNECT_HINT_BIT: u64 = 1u64 << 63;
+/// Requests the other side of the channel to cancel the ongoing operation.
+const CHANNEL_STATE_CANCEL_REQUEST_BIT: u64 = 1u64 << 62;
+const CHANNEL_STATE_FLAG_BITS: u64 =
+    CHANNEL_STATE_DISCONNECT_HINT_BIT | CHANNEL_STATE_CANCEL_REQUEST_BIT;
 
 pub trait ZeroCopyConnectionBuilder<C: ZeroCopyConnection>: NamedConceptBuilder<C> {
     fn buffer_size(self, value: usize) -> Self;
@@ -253,32 +257,69 @@ pub trait ZeroCopyPortDetails {
     }
 
     fn set_disconnect_hint(&self, channel_id: ChannelId, expected_state: ChannelState) {
-        let disconnect_hint_state = expected_state.0 | CHANNEL_STATE_DISCONNECT_HINT_BIT;
-
-        let _ = self
-            .__internal_get_channel_state(channel_id)
-            .compare_exchange(
-                expected_state.0,
-                disconnect_hint_state,
-                Ordering::Relaxed,
-                Ordering::Relaxed,
-            );
+        self.set_channel_state_flag(
+            channel_id,
+            expected_state,
+            CHANNEL_STATE_DISCONNECT_HINT_BIT,
+        );
     }
 
     fn has_disconnect_hint(&self, channel_id: ChannelId, expected_state: ChannelState) -> bool {
-        let disconnect_hint_state = expected_state.0 | CHANNEL_STATE_DISCONNECT_HINT_BIT;
-        disconnect_hint_state
-            == self
-                .__internal_get_channel_state(channel_id)
-                .load(Ordering::Relaxed)
+        self.has_channel_state_flag(
+            channel_id,
+            expected_state,
+            CHANNEL_STATE_DISCONNECT_HINT_BIT,
+        )
+    }
+
+    fn set_cancel_request(&self, channel_id: ChannelId, expected_state: ChannelState) {
+        self.set_channel_state_flag(channel_id, expected_state, CHANNEL_STATE_CANCEL_REQUEST_BIT);
+    }
+
+    fn has_cancel_request(&self, channel_id: ChannelId, expected_state: ChannelState) -> bool {
+        self.has_channel_state_flag(channel_id, expected_state, CHANNEL_STATE_CANCEL_REQUEST_BIT)
+    }
+
+    #[doc(hidden)]
+    fn set_channel_state_flag(
+        &self,
+        channel_id: ChannelId,
+        expected_state: ChannelState,
+        flag: u64,
+    ) {
+        let _ = self.__internal_get_channel_state(channel_id).fetch_update(
+            Ordering::Relaxed,
+            Ordering::Relaxed,
+            |state| {
+                if state & !CHANNEL_STATE_FLAG_BITS == expected_state.0 {
+                    Some(state | flag)
+                } else {
+                    None
+                }
+            },
+        );
+    }
+
+    #[doc(hidden)]
+    fn has_channel_state_flag(
+        &self,
+        channel_id: ChannelId,
+        expected_state: ChannelState,
+        flag: u64,
+    ) -> bool {
+        let state = self
+            .__internal_get_channel_state(channel_id)
+            .load(Ordering::Relaxed);
+        state != CHANNEL_STATE_CLOSED.0
+            && state & !CHANNEL_STATE_FLAG_BITS == expected_state.0
+            && state & flag != 0
     }
 
     fn has_channel_state(&self, channel_id: ChannelId, expected_state: ChannelState) -> bool {
         let state = self
             .__internal_get_channel_state(channel_id)
             .load(Ordering::Relaxed);
-        let state_without_disconnect_hint_bit = state & !(CHANNEL_STATE_DISCONNECT_HINT_BIT);
-        expected_state.0 == state_without_disconnect_hint_bit
+        state != CHANNEL_STATE_CLOSED.0 && expected_state.0 == state & !CHANNEL_STATE_FLAG_BITS
     }
 
     fn is_channel_closed(&self, channel_id: ChannelId) -> bool {
@@ -289,30 +330,20 @@ pub trait ZeroCopyPortDetails {
     }
 
     fn close_channel(&self, channel_id: ChannelId, expected_state: ChannelState) {
-        match self
-            .__internal_get_channel_state(channel_id)
-            .compare_exchange(
-                expected_state.0,
-                CHANNEL_STATE_CLOSED.0,
-                Ordering::Relaxed,
-                Ordering::Relaxed,
-            ) {
-            Ok(_) => (),
-            Err(v) => {
-                let graceful_disconnect_state =
-                    expected_state.0 | CHANNEL_STATE_DISCONNECT_HINT_BIT;
-                if v == graceful_disconnect_state {
-                    let _ = self
-                        .__internal_get_channel_state(channel_id)
-                        .compare_exchange(
-                            graceful_disconnect_state,
-                            CHANNEL_STATE_CLOSED.0,
-                            Ordering::Relaxed,
-                            Ordering::Relaxed,
-                        );
+        let _ = self.__internal_get_channel_state(channel_id).fetch_update(
+            Ordering::Relaxed,
+            Ordering::Relaxed,
+            |state| {
+                // the channel can be closed independent of the flags that are set
+                if state != CHANNEL_STATE_CLOSED.0
+                    && state & !CHANNEL_STATE_FLAG_BITS == expected_state.0
+                {
+                    Some(CHANNEL_STATE_CLOSED.0)
+                } else {
+                    None
                 }
-            }
-        }
+            },
+        );
     }
 }
 
@@ -14,6 +14,7 @@ use anyhow::Result;
 use colored::Colorize;
 
 use iceoryx2::config::Config;
+use iceoryx2_bb_elementary::units;
 
 /// Represents a configuration field with its metadata.
 ///
@@ -77,18 +78,15 @@ pub(crate) fn describe_schema(config: &Config) -> Vec<Section> {
                     description: "Prefix that is used for every file iceoryx2 creates.",
                 },
                 Field {
-                    key: "global.creation-timeout.secs",
-                    value_type: "int",
-                    default_value: config.global.creation_timeout.as_secs().to_string(),
-                    description: "Defines timeout that iceoryx2 will wait until an entity like a Node or Service is opened or created.\n
-                    Attention: Both 'secs' and 'nanos' must be set together; leaving one unset will cause the configuration to be invalid.",
-                },
-                Field {
-                    key: "global.creation-timeout.nanos",
-                    value_type: "int",
-                    default_value: config.global.creation_timeout.subsec_nanos().to_string(),
-                    description: "Additional nanoseconds for global entity creation timeout.\n   \
-                    Attention: Both 'secs' and 'nanos' must be set together; leaving one unset will cause the configuration to be invalid.",
+                    key: "global.creation-timeout",
+                    value_type: "duration",
+                    default_value: format!(
+                        "\"{}\"",
+                        units::duration::format(&config.global.creation_timeout)
+                    ),
+                    description: "Defines timeout that iceoryx2 will wait until an entity like a Node or Service is opened or created.\n   \
+                    The value is written with a unit, like \"1s\" or \"500ms\". Supported units are \
+                    ns, us, ms, s, min, h and d.",
                 },
             ],
         },
@@ -358,19 +356,18 @@ pub(crate) fn describe_schema(config: &Config) -> Vec<Section> {
                 },
                 Field {
                     key: "defaults.event.deadline",
-                    value_type: "Option<Duration>",
+                    value_type: "Option<duration>",
                     default_value: config
                         .defaults
                         .event
                         .deadline
-                        .map_or("None".to_string(), |e| format!("{e:?}")),
+                        .map_or("None".to_string(), |e| {
+                            format!("\"{}\"", units::duration::format(&e))
+                        }),
                     description: "\
                     Maximum allowed time between two consecutive notifications. If not sent after \
                     this time, all listeners attached to a WaitSet will be notified.\n   \
-                    Due to a current limitation, the keys are actually \
-                    `defaults.event.deadline.secs` and `defaults.event.deadline.nanos`.\n   \
-                    Attention: Both 'secs' and 'nanos' must be set together; leaving one unset \
-                    will cause the configuration to be invalid.",
+                    The value is written with a unit, like \"1s\" or \"500ms\".",
                 },
                 Field {
                     key: "defaults.event.notifier-created-event",
@@ -0,0 +1,82 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use clap::Args;
+use clap::Parser;
+use clap::ValueEnum;
+
+use iceoryx2_cli::Format;
+use iceoryx2_cli::filter::MessagingPatternFilter;
+use iceoryx2_cli::help_template;
+
+#[derive(Parser)]
+#[command(
+    name = "iox2 graph",
+    bin_name = "iox2 graph",
+    about = "Export the topology of the running iceoryx2 system",
+    long_about = None,
+    version = env!("CARGO_PKG_VERSION"),
+    disable_help_subcommand = true,
+    arg_required_else_help = false,
+    help_template = help_template().build(),
+)]
+pub struct Cli {
+    #[clap(long, short = 'f', value_enum, default_value_t = GraphFormat::Dot)]
+    pub format: GraphFormat,
+
+    #[clap(
+        long,
+        short,
+        value_enum,
+        default_value_t = Collapse::None,
+        help = "Collapse the ports into node-service edges or the services into node-node edges"
+    )]
+    pub collapse: Collapse,
+
+    #[command(flatten)]
+    pub filter: OutputFilter,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct OutputFilter {
+    #[clap(short, long, value_enum, default_value_t = MessagingPatternFilter::All)]
+    pub pattern: MessagingPatternFilter,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+#[value(rename_all = "UPPERCASE")]
+pub enum GraphFormat {
+    Dot,
+    Ron,
+    Json,
+    Yaml,
+}
+
+impl GraphFormat {
+    /// Returns the structured [`Format`] or [`None`] for the Graphviz DOT format.
+    pub fn structured(self) -> Option<Format> {
+        match self {
+            GraphFormat::Dot => None,
+            GraphFormat::Ron => Some(Format::Ron),
+            GraphFormat::Json => Some(Format::Json),
+            GraphFormat::Yaml => Some(Format::Yaml),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+#[clap(rename_all = "PascalCase")]
+pub enum Collapse {
+    None,
+    Node,
+    Service,
+}
@@ -0,0 +1,97 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::fmt::Write;
+
+use crate::graph::{Graph, VertexKind};
+
+/// Renders the [`Graph`] in the Graphviz DOT language.
+pub(crate) fn to_dot(graph: &Graph) -> String {
+    let mut dot = String::from("digraph iceoryx2 {\n    rankdir=LR;\n");
+
+    for vertex in &graph.vertices {
+        let shape = match vertex.kind {
+            VertexKind::Node => "box",
+            VertexKind::Service => "ellipse",
+            VertexKind::Port(_) => "plaintext",
+        };
+        let _ = writeln!(
+            dot,
+            "    \"{}\" [label=\"{}\", shape={}];",
+            escape(&vertex.id),
+            escape(&vertex.label),
+            shape
+        );
+    }
+
+    for edge in &graph.edges {
+        let _ = match &edge.label {
+            Some(label) => writeln!(
+                dot,
+                "    \"{}\" -> \"{}\" [label=\"{}\"];",
+                escape(&edge.from),
+                escape(&edge.to),
+                escape(label)
+            ),
+            None => writeln!(
+                dot,
+                "    \"{}\" -> \"{}\";",
+                escape(&edge.from),
+                escape(&edge.to)
+            ),
+        };
+    }
+
+    dot.push_str("}\n");
+    dot
+}
+
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{Edge, Vertex};
+
+    #[test]
+    fn dot_output_contains_escaped_vertices_and_edges() {
+        let graph = Graph {
+            vertices: vec![
+                Vertex {
+                    id: "node_1".to_string(),
+                    kind: VertexKind::Node,
+                    label: "my \"node\" [42]".to_string(),
+                },
+                Vertex {
+                    id: "service_1".to_string(),
+                    kind: VertexKind::Service,
+                    label: "a\\b (Event)".to_string(),
+                },
+            ],
+            edges: vec![Edge {
+                from: "node_1".to_string(),
+                to: "service_1".to_string(),
+                label: Some("Notifier".to_string()),
+            }],
+        };
+
+        let dot = to_dot(&graph);
+
+        assert!(dot.starts_with("digraph iceoryx2 {"));
+        assert!(dot.contains("\"node_1\" [label=\"my \\\"node\\\" [42]\", shape=box];"));
+        assert!(dot.contains("\"service_1\" [label=\"a\\\\b (Event)\", shape=ellipse];"));
+        assert!(dot.contains("\"node_1\" -> \"service_1\" [label=\"Notifier\"];"));
+        assert!(dot.ends_with("}\n"));
+    }
+}
@@ -0,0 +1,278 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use anyhow::{Context, Result};
+use iceoryx2::node::{NodeState, NodeView};
+use iceoryx2::prelude::*;
+use iceoryx2_cli::filter::Filter;
+use iceoryx2_cli::opened_service::{OpenedService, Port, PortKind};
+
+use crate::cli::{Collapse, OutputFilter};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+pub(crate) enum VertexKind {
+    Node,
+    Service,
+    Port(PortKind),
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub(crate) struct Vertex {
+    pub id: String,
+    pub kind: VertexKind,
+    pub label: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, serde::Serialize)]
+pub(crate) struct Edge {
+    pub from: String,
+    pub to: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+#[derive(Debug, Default, serde::Serialize)]
+pub(crate) struct Graph {
+    pub vertices: Vec<Vertex>,
+    pub edges: Vec<Edge>,
+}
+
+struct ServiceInfo {
+    id: String,
+    label: String,
+    ports: Vec<Port>,
+}
+
+/// The nodes, services and ports of the running system, collected from the dynamic
+/// configs of all services.
+pub(crate) struct Topology {
+    node_labels: BTreeMap<u128, String>,
+    services: Vec<ServiceInfo>,
+}
+
+impl Topology {
+    pub(crate) fn collect(filter: &OutputFilter) -> Result<Self> {
+        let node = NodeBuilder::new()
+            .name(&NodeName::new("iox2-graph")?)
+            .create::<ipc::Service>()?;
+        let own_id = node.id().value();
+
+        let mut node_labels = BTreeMap::new();
+        Node::<ipc::Service>::list(node.config(), |state| {
+            let (id, label) = node_label(&state);
+            if id != own_id {
+                node_labels.insert(id, label);
+            }
+            CallbackProgression::Continue
+        })
+        .context("failed to retrieve nodes")?;
+
+        let mut details = vec![];
+        ipc::Service::list(node.config(), |service| {
+            if filter.matches(&service) {
+                details.push(service);
+            }
+            CallbackProgression::Continue
+        })
+        .context("failed to retrieve services")?;
+
+        let mut services = Vec::with_capacity(details.len());
+        for service in &details {
+            let mut ports = vec![];
+            if let Some(opened) = OpenedService::open(&node, service) {
+                opened.list_ports(|port| ports.push(port));
+            }
+            ports.sort_by_key(|port| (port.kind, port.id));
+
+            services.push(ServiceInfo {
+                id: format!("service_{}", service.static_details.service_hash()),
+                label: format!(
+                    "{} ({})",
+                    service.static_details.name(),
+                    service.static_details.messaging_pattern()
+                ),
+                ports,
+            });
+        }
+        services.sort_by(|lhs, rhs| lhs.label.cmp(&rhs.label));
+
+        Ok(Self {
+            node_labels,
+            services,
+        })
+    }
+
+    fn node_vertex(&self, node_id: u128) -> Vertex {
+        Vertex {
+            id: node_vertex_id(node_id),
+            kind: VertexKind::Node,
+            label: self
+                .node_labels
+                .get(&node_id)
+                .cloned()
+                .unwrap_or_else(|| format!("{node_id:032x}")),
+        }
+    }
+
+    /// Returns the ids of all nodes that own at least one port.
+    fn connected_nodes(&self) -> BTreeSet<u128> {
+        self.services
+            .iter()
+            .flat_map(|service| service.ports.iter().map(|port| port.node_id.value()))
+            .collect()
+    }
+
+    pub(crate) fn graph(&self, collapse: Collapse) -> Graph {
+        let mut graph = Graph::default();
+        for node_id in self.connected_nodes() {
+            graph.vertices.push(self.node_vertex(node_id));
+        }
+
+        match collapse {
+            Collapse::None => self.port_graph(&mut graph),
+            Collapse::Node => self.node_service_graph(&mut graph),
+            Collapse::Service => self.node_graph(&mut graph),
+        }
+
+        graph.edges.sort();
+        graph.edges.dedup();
+        graph
+    }
+
+    /// Every port is a vertex that is connected to its node and its service.
+    fn port_graph(&self, graph: &mut Graph) {
+        for service in &self.services {
+            graph.vertices.push(service_vertex(service));
+            for port in &service.ports {
+                let port_id = format!("port_{:032x}", port.id);
+                graph.vertices.push(Vertex {
+                    id: port_id.clone(),
+                    kind: VertexKind::Port(port.kind),
+                    label: format!("{:?}", port.kind),
+                });
+                graph.edges.push(Edge {
+                    from: node_vertex_id(port.node_id.value()),
+                    to: port_id.clone(),
+                    label: None,
+                });
+                graph
+                    .edges
+                    .push(directed_edge(port.kind, port_id, service.id.clone(), None));
+            }
+        }
+    }
+
+    /// The ports are collapsed into one edge per node, service and port kind that is
+    /// labeled with the number of ports.
+    fn node_service_graph(&self, graph: &mut Graph) {
+        for service in &self.services {
+            graph.vertices.push(service_vertex(service));
+
+            let mut counts = BTreeMap::<(u128, PortKind), usize>::new();
+            for port in &service.ports {
+                *counts.entry((port.node_id.value(), port.kind)).or_default() += 1;
+            }
+
+            for ((node_id, kind), count) in counts {
+                let label = match count {
+                    1 => format!("{kind:?}"),
+                    n => format!("{n}x {kind:?}"),
+                };
+                graph.edges.push(directed_edge(
+                    kind,
+                    node_vertex_id(node_id),
+                    service.id.clone(),
+                    Some(label),
+                ));
+            }
+        }
+    }
+
+    /// The services are collapsed into edges from every producing node to every consuming
+    /// node of the service that are labeled with the service.
+    fn node_graph(&self, graph: &mut Graph) {
+        for service in &self.services {
+            let nodes_of = |is_producer: bool| {
+                service
+                    .ports
+                    .iter()
+                    .filter(|port| port.kind.is_producer() == is_producer)
+                    .map(|port| port.node_id.value())
+                    .collect::<BTreeSet<_>>()
+            };
+
+            for producer in nodes_of(true) {
+                for consumer in nodes_of(false) {
+                    graph.edges.push(Edge {
+                        from: node_vertex_id(producer),
+                        to: node_vertex_id(consumer),
+                        label: Some(service.label.clone()),
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Creates an edge in the direction of the data flow, from the producing side into the
+/// service and from the service to the consuming side.
+fn directed_edge(
+    kind: PortKind,
+    port_side: String,
+    service_side: String,
+    label: Option<String>,
+) -> Edge {
+    if kind.is_producer() {
+        Edge {
+            from: port_side,
+            to: service_side,
+            label,
+        }
+    } else {
+        Edge {
+            from: service_side,
+            to: port_side,
+            label,
+        }
+    }
+}
+
+fn service_vertex(service: &ServiceInfo) -> Vertex {
+    Vertex {
+        id: service.id.clone(),
+        kind: VertexKind::Service,
+        label: service.label.clone(),
+    }
+}
+
+fn node_vertex_id(node_id: u128) -> String {
+    format!("node_{node_id:032x}")
+}
+
+fn node_label(state: &NodeState<ipc::Service>) -> (u128, String) {
+    let (id, details) = match state {
+        NodeState::Alive(view) => (view.id(), view.details().as_ref()),
+        NodeState::Dead(view) => (view.id(), view.details().as_ref()),
+        NodeState::Inaccessible(id) | NodeState::Undefined(id) => (id, None),
+    };
+
+    let name = details
+        .map(|d| match d.name().as_str() {
+            "" => d.executable().to_string(),
+            name => name.to_string(),
+        })
+        .unwrap_or_else(|| format!("{:032x}", id.value()));
+
+    (id.value(), format!("{name} [{}]", id.pid().value()))
+}
@@ -0,0 +1,48 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod cli;
+mod dot;
+mod filter;
+mod graph;
+
+use anyhow::Result;
+use clap::Parser;
+use cli::Cli;
+use graph::Topology;
+use iceoryx2_cli::install_panic_handlers;
+use iceoryx2_log::error;
+use iceoryx2_log::{LogLevel, set_log_level_from_env_or};
+
+fn main() -> Result<()> {
+    install_panic_handlers!();
+
+    set_log_level_from_env_or(LogLevel::Warn);
+
+    let cli = Cli::parse();
+    if let Err(e) = export(&cli) {
+        error!("failed to export the system graph: {}", e);
+    }
+
+    Ok(())
+}
+
+fn export(cli: &Cli) -> Result<()> {
+    let graph = Topology::collect(&cli.filter)?.graph(cli.collapse);
+
+    match cli.format.structured() {
+        Some(format) => println!("{}", format.as_string(&graph)?),
+        None => print!("{}", dot::to_dot(&graph)),
+    }
+
+    Ok(())
+}
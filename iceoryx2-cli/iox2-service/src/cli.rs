@@ -172,11 +172,63 @@ impl From<DataRepresentation> for iceoryx2_userland_record_and_replay::record::D
     }
 }
 
+#[derive(Clone, Copy, ValueEnum, Default)]
+#[value(rename_all = "UPPERCASE")]
+pub enum RecordDataRepresentation {
+    Iox2Dump,
+    #[default]
+    HumanReadable,
+    Mcap,
+}
+
+impl From<RecordDataRepresentation>
+    for iceoryx2_userland_record_and_replay::record::DataRepresentation
+{
+    fn from(value: RecordDataRepresentation) -> Self {
+        match value {
+            RecordDataRepresentation::HumanReadable => {
+                iceoryx2_userland_record_and_replay::record::DataRepresentation::HumanReadable
+            }
+            RecordDataRepresentation::Iox2Dump => {
+                iceoryx2_userland_record_and_replay::record::DataRepresentation::Iox2Dump
+            }
+            RecordDataRepresentation::Mcap => {
+                iceoryx2_userland_record_and_replay::record::DataRepresentation::Mcap
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum, Default)]
+#[value(rename_all = "UPPERCASE")]
+pub enum Compression {
+    #[default]
+    None,
+    Lz4,
+    Zstd,
+}
+
+impl From<Compression> for iceoryx2_userland_record_and_replay::compression::Compression {
+    fn from(value: Compression) -> Self {
+        match value {
+            Compression::None => {
+                iceoryx2_userland_record_and_replay::compression::Compression::None
+            }
+            Compression::Lz4 => iceoryx2_userland_record_and_replay::compression::Compression::Lz4,
+            Compression::Zstd => {
+                iceoryx2_userland_record_and_replay::compression::Compression::Zstd
+            }
+        }
+    }
+}
+
 #[derive(Clone, Copy, ValueEnum, Default)]
 #[value(rename_all = "UPPERCASE")]
 pub enum MessagingPattern {
     #[default]
     PublishSubscribe,
+    Event,
+    RequestResponse,
 }
 
 impl From<MessagingPattern> for iceoryx2::prelude::MessagingPattern {
@@ -185,6 +237,10 @@ impl From<MessagingPattern> for iceoryx2::prelude::MessagingPattern {
             MessagingPattern::PublishSubscribe => {
                 iceoryx2::prelude::MessagingPattern::PublishSubscribe
             }
+            MessagingPattern::Event => iceoryx2::prelude::MessagingPattern::Event,
+            MessagingPattern::RequestResponse => {
+                iceoryx2::prelude::MessagingPattern::RequestResponse
+            }
         }
     }
 }
@@ -314,10 +370,310 @@ pub struct SubscribeOptions {
     pub max_messages: Option<u64>,
 }
 
+#[derive(Args)]
+pub struct RequestResponseTypeOptions {
+    #[clap(
+        long,
+        default_value = "u8",
+        help = "Defines the unique type identifier of the request type. Only used when the service does not exist."
+    )]
+    pub request_type_name: String,
+    #[clap(
+        long,
+        default_value = "1",
+        help = "Defines the type size of the request type. Only used when the service does not exist."
+    )]
+    pub request_type_size: usize,
+    #[clap(
+        long,
+        default_value = "1",
+        help = "Defines the type alignment of the request type. Only used when the service does not exist."
+    )]
+    pub request_type_alignment: usize,
+    #[clap(
+        long,
+        default_value = "DYNAMIC",
+        help = "Defines the variant of the request type. Only used when the service does not exist."
+    )]
+    pub request_type_variant: CliTypeVariant,
+
+    #[clap(
+        long,
+        default_value = "u8",
+        help = "Defines the unique type identifier of the response type. Only used when the service does not exist."
+    )]
+    pub response_type_name: String,
+    #[clap(
+        long,
+        default_value = "1",
+        help = "Defines the type size of the response type. Only used when the service does not exist."
+    )]
+    pub response_type_size: usize,
+    #[clap(
+        long,
+        default_value = "1",
+        help = "Defines the type alignment of the response type. Only used when the service does not exist."
+    )]
+    pub response_type_alignment: usize,
+    #[clap(
+        long,
+        default_value = "DYNAMIC",
+        help = "Defines the variant of the response type. Only used when the service does not exist."
+    )]
+    pub response_type_variant: CliTypeVariant,
+}
+
 #[derive(Parser)]
-pub struct RecordOptions {
-    #[clap(help = "Name of the service which shall be recorded.")]
+pub struct CallOptions {
+    #[clap(help = "Name of the request-response service which shall be called.")]
+    pub service: String,
+    #[clap(
+        short,
+        long,
+        default_value = "iox2-cli-service-client",
+        help = "Defines the node name of the client endpoint."
+    )]
+    pub node_name: String,
+    #[clap(
+        short,
+        long,
+        required = true,
+        help = "The requests that shall be sent. Can be multiple requests, they are sent one after another."
+    )]
+    pub message: Vec<String>,
+    #[clap(
+        short,
+        long,
+        default_value = "HUMANREADABLE",
+        help = "Defines how the provided requests are encoded and how the responses shall be displayed."
+    )]
+    pub data_representation: DataRepresentation,
+    #[clap(
+        short,
+        long,
+        help = "The user header of the requests as hex string. If not set the user header is zeroed."
+    )]
+    pub user_header: Option<String>,
+    #[clap(
+        short,
+        long,
+        default_value = "5000",
+        help = "Maximum time in milliseconds to wait for the responses of a request."
+    )]
+    pub timeout: u64,
+
+    #[command(flatten)]
+    pub types: RequestResponseTypeOptions,
+}
+
+#[derive(Parser)]
+pub struct ServeOptions {
+    #[clap(help = "Name of the request-response service which shall be served.")]
     pub service: String,
+    #[clap(
+        short,
+        long,
+        default_value = "iox2-cli-service-server",
+        help = "Defines the node name of the server endpoint."
+    )]
+    pub node_name: String,
+    #[clap(
+        short,
+        long,
+        required_unless_present = "echo",
+        conflicts_with = "echo",
+        help = "The responses that are sent for every request. Can be multiple responses, they are streamed in the given order."
+    )]
+    pub message: Vec<String>,
+    #[clap(
+        short,
+        long,
+        help = "Answer every request with its own payload and user header."
+    )]
+    pub echo: bool,
+    #[clap(
+        short,
+        long,
+        default_value = "HUMANREADABLE",
+        help = "Defines how the provided responses are encoded and how the requests shall be displayed."
+    )]
+    pub data_representation: DataRepresentation,
+    #[clap(
+        short,
+        long,
+        help = "The user header of the responses as hex string. If not set the user header is zeroed."
+    )]
+    pub user_header: Option<String>,
+    #[clap(
+        short,
+        long,
+        help = "Maximum runtime in milliseconds. When the timeout has passed the process stops."
+    )]
+    pub timeout: Option<u64>,
+    #[clap(
+        long,
+        help = "Maximum number of requests to be answered before the process stops."
+    )]
+    pub max_requests: Option<u64>,
+
+    #[command(flatten)]
+    pub types: RequestResponseTypeOptions,
+}
+
+#[derive(Parser)]
+pub struct BlackboardKeysOptions {
+    #[clap(help = "Name of the blackboard service.")]
+    pub service: String,
+    #[clap(
+        short,
+        long,
+        default_value = "iox2-cli-service-blackboard",
+        help = "Defines the node name of the blackboard endpoint."
+    )]
+    pub node_name: String,
+}
+
+#[derive(Parser)]
+pub struct BlackboardGetOptions {
+    #[clap(help = "Name of the blackboard service.")]
+    pub service: String,
+    #[clap(
+        short,
+        long,
+        default_value = "iox2-cli-service-blackboard",
+        help = "Defines the node name of the blackboard reader."
+    )]
+    pub node_name: String,
+    #[clap(
+        short,
+        long,
+        help = "The key of the entry. Keys of non-primitive types are provided as hex string. If not set all entries are printed."
+    )]
+    pub key: Option<String>,
+    #[clap(
+        short,
+        long,
+        help = "Print the values as hex string instead of decoding them via their type."
+    )]
+    pub raw: bool,
+}
+
+#[derive(Parser)]
+pub struct BlackboardSetOptions {
+    #[clap(help = "Name of the blackboard service.")]
+    pub service: String,
+    #[clap(
+        short,
+        long,
+        default_value = "iox2-cli-service-blackboard",
+        help = "Defines the node name of the blackboard writer."
+    )]
+    pub node_name: String,
+    #[clap(
+        short,
+        long,
+        help = "The key of the entry. Keys of non-primitive types are provided as hex string."
+    )]
+    pub key: String,
+    #[clap(
+        short,
+        long,
+        help = "The new value. Values of non-primitive types are provided as hex string."
+    )]
+    pub value: String,
+    #[clap(short, long, help = "The value is provided as hex string.")]
+    pub raw: bool,
+}
+
+#[derive(Parser)]
+pub struct BlackboardWatchOptions {
+    #[clap(help = "Name of the blackboard service.")]
+    pub service: String,
+    #[clap(
+        short,
+        long,
+        default_value = "iox2-cli-service-blackboard",
+        help = "Defines the node name of the blackboard reader."
+    )]
+    pub node_name: String,
+    #[clap(
+        short,
+        long,
+        help = "The key of the entry. Keys of non-primitive types are provided as hex string. If not set all entries are watched."
+    )]
+    pub key: Option<String>,
+    #[clap(
+        short,
+        long,
+        help = "Print the values as hex string instead of decoding them via their type."
+    )]
+    pub raw: bool,
+    #[clap(
+        short,
+        long,
+        help = "Maximum runtime in milliseconds. When the timeout has passed the process stops."
+    )]
+    pub timeout: Option<u64>,
+    #[clap(
+        short,
+        long,
+        default_value = "100",
+        help = "Time in milliseconds between two checks for updated values."
+    )]
+    pub cycle_time: u64,
+}
+
+#[derive(Subcommand)]
+pub enum BlackboardAction {
+    #[clap(
+        about = "List all keys and value types of a blackboard.",
+        help_template = help_template().with_positionals().build()
+    )]
+    Keys(BlackboardKeysOptions),
+    #[clap(
+        about = "Print the values of a blackboard.",
+        help_template = help_template().with_positionals().build()
+    )]
+    Get(BlackboardGetOptions),
+    #[clap(
+        about = "Write a new value into a blackboard entry.",
+        help_template = help_template().with_positionals().build()
+    )]
+    Set(BlackboardSetOptions),
+    #[clap(
+        about = "Print the values of a blackboard whenever they are updated.",
+        help_template = help_template().with_positionals().build()
+    )]
+    Watch(BlackboardWatchOptions),
+}
+
+#[derive(Parser)]
+pub struct BlackboardOptions {
+    #[clap(subcommand)]
+    pub action: BlackboardAction,
+}
+
+#[derive(Parser)]
+pub struct RecordOptions {
+    #[clap(
+        required = true,
+        num_args = 1..,
+        help = "Name of the service which shall be recorded. With --bag, one or more glob patterns (or regular expressions with --regex) that select the services."
+    )]
+    pub services: Vec<String>,
+
+    #[clap(
+        long,
+        help = "Records all selected publish-subscribe services into one multi-service bag file."
+    )]
+    pub bag: bool,
+
+    #[clap(
+        long,
+        requires = "bag",
+        help = "Interprets the service selection as regular expressions instead of glob patterns."
+    )]
+    pub regex: bool,
 
     #[clap(
         short,
@@ -348,7 +704,14 @@ pub struct RecordOptions {
         default_value = "HUMANREADABLE",
         help = "Defines the data format of the recorded file."
     )]
-    pub data_representation: DataRepresentation,
+    pub data_representation: RecordDataRepresentation,
+
+    #[clap(
+        long,
+        default_value = "NONE",
+        help = "Compresses the recorded data in chunks. Requires the IOX2DUMP data representation."
+    )]
+    pub compression: Compression,
 
     #[clap(
         short,
@@ -398,6 +761,73 @@ pub struct HzOptions {
     pub timeout: Option<u64>,
 }
 
+#[derive(Parser)]
+pub struct BwOptions {
+    #[clap(help = "Name of the service to measure the throughput of.")]
+    pub service: String,
+    #[clap(
+        short,
+        long,
+        default_value = "iox2-cli-service-bw",
+        help = "Defines the node name of the bandwidth measurement endpoint."
+    )]
+    pub node_name: String,
+    #[clap(
+        short,
+        long,
+        default_value = "10000",
+        help = "Rolling window size, in # of messages, for calculating the throughput (default: 10000)."
+    )]
+    pub window: usize,
+    #[clap(
+        short,
+        long,
+        help = "Maximum runtime in seconds. When the timeout has passed the process stops."
+    )]
+    pub timeout: Option<u64>,
+}
+
+#[derive(Parser)]
+pub struct LatencyOptions {
+    #[clap(
+        help = "Name of the service to measure the latency of. The probes are delivered to all subscribers of the service."
+    )]
+    pub service: String,
+    #[clap(
+        short,
+        long,
+        default_value = "iox2-cli-service-latency",
+        help = "Defines the node name of the latency measurement endpoint."
+    )]
+    pub node_name: String,
+    #[clap(
+        short,
+        long,
+        default_value = "100",
+        help = "Time in milliseconds between two probes."
+    )]
+    pub interval: u64,
+    #[clap(
+        short,
+        long,
+        help = "Number of probes to send. When not provided, probes are sent until the timeout has passed."
+    )]
+    pub count: Option<u64>,
+    #[clap(
+        short,
+        long,
+        default_value = "10000",
+        help = "Rolling window size, in # of probes, for calculating the latency statistics (default: 10000)."
+    )]
+    pub window: usize,
+    #[clap(
+        short,
+        long,
+        help = "Maximum runtime in seconds. When the timeout has passed the process stops."
+    )]
+    pub timeout: Option<u64>,
+}
+
 #[derive(Parser)]
 pub struct ReplayOptions {
     #[clap(help = "When provided, it overrides the service name of the record file.")]
@@ -420,7 +850,7 @@ pub struct ReplayOptions {
         default_value = "HUMANREADABLE",
         help = "Defines how the data in the file is encoded."
     )]
-    pub data_representation: DataRepresentation,
+    pub data_representation: RecordDataRepresentation,
 
     #[clap(
         short,
@@ -445,6 +875,46 @@ pub struct ReplayOptions {
         help = "The timings in the file will be multiplied by the given factor to increase or slow down the playback."
     )]
     pub time_factor: f32,
+
+    #[clap(
+        long,
+        conflicts_with = "service",
+        help = "The input is a multi-service bag file and all contained services are replayed."
+    )]
+    pub bag: bool,
+
+    #[clap(
+        long,
+        help = "Timestamp in milliseconds of the recording from which the replay starts."
+    )]
+    pub start: Option<u64>,
+
+    #[clap(
+        long,
+        help = "Timestamp in milliseconds of the recording at which the replay ends."
+    )]
+    pub end: Option<u64>,
+
+    #[clap(
+        long = "loop",
+        conflicts_with = "repetitions",
+        help = "Replays the recorded data in an endless loop."
+    )]
+    pub endless_loop: bool,
+
+    #[clap(
+        long,
+        default_value = "1000",
+        help = "Request-response only: how long to wait for the responses of a replayed request."
+    )]
+    pub response_timeout_in_ms: u64,
+
+    #[clap(
+        long,
+        conflicts_with = "bag",
+        help = "Request-response only: non-existing file in which the replayed requests and the responses of the live server are stored. It can be used as new baseline."
+    )]
+    pub output: Option<String>,
 }
 
 #[derive(Subcommand)]
@@ -484,6 +954,21 @@ pub enum Action {
         help_template = help_template().with_positionals().build()
     )]
     Subscribe(SubscribeOptions),
+    #[clap(
+        about = "Send requests to a request-response service and print the responses.",
+        help_template = help_template().with_positionals().build()
+    )]
+    Call(CallOptions),
+    #[clap(
+        about = "Answer the requests of a request-response service with fixed or echoed responses.",
+        help_template = help_template().with_positionals().build()
+    )]
+    Serve(ServeOptions),
+    #[clap(
+        about = "Inspect and modify the entries of a blackboard service.",
+        help_template = help_template().with_subcommands().build()
+    )]
+    Blackboard(BlackboardOptions),
     #[clap(
         about = "Record data from any service.",
         help_template = help_template().with_positionals().build()
@@ -499,4 +984,14 @@ pub enum Action {
         help_template = help_template().with_positionals().build()
     )]
     Hz(HzOptions),
+    #[clap(
+        about = "Measure the throughput (bytes/s) of a publish-subscribe service.",
+        help_template = help_template().with_positionals().build()
+    )]
+    Bw(BwOptions),
+    #[clap(
+        about = "Measure the publish-to-receive latency of a publish-subscribe service with probe samples.",
+        help_template = help_template().with_positionals().build()
+    )]
+    Latency(LatencyOptions),
 }
@@ -0,0 +1,323 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time::Duration;
+use std::time::Instant;
+
+use crate::cli::{
+    BlackboardAction, BlackboardGetOptions, BlackboardKeysOptions, BlackboardOptions,
+    BlackboardSetOptions, BlackboardWatchOptions,
+};
+use anyhow::{Result, anyhow};
+use iceoryx2::constants::MAX_BLACKBOARD_KEY_SIZE;
+use iceoryx2::prelude::*;
+use iceoryx2::service::builder::CustomKeyMarker;
+use iceoryx2::service::builder::blackboard::KeyMemory;
+use iceoryx2::service::port_factory::blackboard::PortFactory;
+use iceoryx2::service::static_config::message_type_details::TypeDetail;
+use iceoryx2_bb_container::string::String as _;
+use iceoryx2_cli::Format;
+use iceoryx2_userland_record_and_replay::hex_conversion::{
+    bytes_to_hex_string, hex_string_to_bytes,
+};
+
+type CustomBlackboard = PortFactory<ipc::Service, CustomKeyMarker>;
+
+#[derive(serde::Serialize)]
+struct BlackboardEntry {
+    key: String,
+    value_type: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    value: String,
+}
+
+struct Entry {
+    key: Vec<u8>,
+    value_type: TypeDetail,
+}
+
+macro_rules! primitive_types {
+    ($macro:ident) => {
+        $macro!(
+            u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+        )
+    };
+}
+
+fn is_primitive(type_detail: &TypeDetail) -> bool {
+    macro_rules! is_primitive {
+        ($($t:ty),*) => {
+            matches!(type_detail.type_name().as_str(), $(stringify!($t))|* | "bool")
+        };
+    }
+
+    primitive_types!(is_primitive)
+}
+
+/// Returns the value as string when its type is a primitive, otherwise [`None`].
+fn decode(type_detail: &TypeDetail, bytes: &[u8]) -> Option<String> {
+    macro_rules! decode_primitive {
+        ($($t:ty),*) => {
+            match type_detail.type_name().as_str() {
+                $(stringify!($t) if bytes.len() == core::mem::size_of::<$t>() => {
+                    Some(<$t>::from_ne_bytes(bytes.try_into().ok()?).to_string())
+                })*
+                "bool" if bytes.len() == 1 => Some((bytes[0] != 0).to_string()),
+                _ => None,
+            }
+        };
+    }
+
+    primitive_types!(decode_primitive)
+}
+
+/// Converts a value that was provided on the command line into the bytes of the provided type.
+/// Values of non-primitive types, or all values when `raw` is set, are expected as hex string.
+fn encode(type_detail: &TypeDetail, value: &str, raw: bool) -> Result<Vec<u8>> {
+    macro_rules! encode_primitive {
+        ($($t:ty),*) => {
+            match type_detail.type_name().as_str() {
+                $(stringify!($t) => value.trim().parse::<$t>()?.to_ne_bytes().to_vec(),)*
+                "bool" => vec![value.trim().parse::<bool>()? as u8],
+                type_name => {
+                    return Err(anyhow!(
+                        "values of type \"{type_name}\" can only be provided as hex string"
+                    ));
+                }
+            }
+        };
+    }
+
+    let bytes = if raw {
+        hex_string_to_bytes(value)?
+    } else {
+        primitive_types!(encode_primitive)
+    };
+
+    if bytes.len() != type_detail.size() {
+        return Err(anyhow!(
+            "raw value size of {} does not fit required type size of {}",
+            bytes.len(),
+            type_detail.size()
+        ));
+    }
+
+    Ok(bytes)
+}
+
+fn to_string(type_detail: &TypeDetail, bytes: &[u8], raw: bool) -> String {
+    match raw {
+        true => bytes_to_hex_string(bytes),
+        false => decode(type_detail, bytes).unwrap_or_else(|| bytes_to_hex_string(bytes)),
+    }
+}
+
+fn print_entry(
+    key_type: &TypeDetail,
+    entry: &Entry,
+    value: Option<&[u8]>,
+    raw: bool,
+    format: Format,
+) -> Result<()> {
+    let entry = BlackboardEntry {
+        key: to_string(key_type, &entry.key, false),
+        value_type: entry.value_type.type_name().to_string(),
+        value: value
+            .map(|value| to_string(&entry.value_type, value, raw))
+            .unwrap_or_default(),
+    };
+
+    println!("{}", format.as_string(&entry)?);
+
+    Ok(())
+}
+
+/// Opens the blackboard without knowing the key type at compile time. Keys are compared
+/// bytewise, therefore keys with padding bytes may not be found.
+fn open_blackboard(
+    service_name: &ServiceName,
+    node: &Node<ipc::Service>,
+) -> Result<(CustomBlackboard, TypeDetail)> {
+    let service_details =
+        match ipc::Service::details(service_name, node.config(), MessagingPattern::Blackboard)? {
+            Some(v) => v,
+            None => {
+                return Err(anyhow!(
+                    "unable to access blackboard service \"{service_name}\", does it exist?",
+                ));
+            }
+        };
+
+    let key_type = *service_details.static_details.blackboard().type_details();
+    let key_size = key_type.size();
+    let key_eq = move |lhs: *const u8, rhs: *const u8| unsafe {
+        core::slice::from_raw_parts(lhs, key_size) == core::slice::from_raw_parts(rhs, key_size)
+    };
+
+    let service = unsafe {
+        node.service_builder(service_name)
+            .blackboard_opener::<CustomKeyMarker>()
+            .__internal_set_key_type_details(&key_type)
+            .__internal_set_key_eq_cmp_func(Box::new(move |lhs, rhs| {
+                KeyMemory::<MAX_BLACKBOARD_KEY_SIZE>::key_eq_comparison(lhs, rhs, &key_eq)
+            }))
+            .open()?
+    };
+
+    Ok((service, key_type))
+}
+
+/// Returns all entries of the blackboard, or only the one with the provided key.
+fn entries(
+    service: &CustomBlackboard,
+    key_type: &TypeDetail,
+    key: Option<&str>,
+) -> Result<Vec<Entry>> {
+    let key = match key {
+        Some(key) => Some(encode(key_type, key, !is_primitive(key_type))?),
+        None => None,
+    };
+
+    let mut entries = vec![];
+    service.__internal_list_entries(|key_ptr, value_type| {
+        let entry_key = unsafe { core::slice::from_raw_parts(key_ptr, key_type.size()) };
+        if key.as_ref().is_none_or(|key| key == entry_key) {
+            entries.push(Entry {
+                key: entry_key.to_vec(),
+                value_type: *value_type,
+            });
+        }
+        CallbackProgression::Continue
+    });
+
+    if key.is_some() && entries.is_empty() {
+        return Err(anyhow!("the blackboard has no entry with the provided key"));
+    }
+
+    Ok(entries)
+}
+
+fn keys(options: BlackboardKeysOptions, format: Format) -> Result<()> {
+    let node = NodeBuilder::new()
+        .name(&NodeName::new(&options.node_name)?)
+        .create::<ipc::Service>()?;
+    let (service, key_type) = open_blackboard(&ServiceName::new(&options.service)?, &node)?;
+
+    for entry in entries(&service, &key_type, None)? {
+        print_entry(&key_type, &entry, None, false, format)?;
+    }
+
+    Ok(())
+}
+
+fn get(options: BlackboardGetOptions, format: Format) -> Result<()> {
+    let node = NodeBuilder::new()
+        .name(&NodeName::new(&options.node_name)?)
+        .create::<ipc::Service>()?;
+    let (service, key_type) = open_blackboard(&ServiceName::new(&options.service)?, &node)?;
+    let reader = service.reader_builder().create()?;
+
+    for entry in entries(&service, &key_type, options.key.as_deref())? {
+        let handle = unsafe { reader.__internal_entry(entry.key.as_ptr(), &entry.value_type)? };
+        let mut value = vec![0u8; entry.value_type.size()];
+        unsafe {
+            handle.get(
+                value.as_mut_ptr(),
+                entry.value_type.size(),
+                entry.value_type.alignment(),
+                core::ptr::null_mut(),
+            )
+        };
+        print_entry(&key_type, &entry, Some(&value), options.raw, format)?;
+    }
+
+    Ok(())
+}
+
+fn set(options: BlackboardSetOptions, format: Format) -> Result<()> {
+    let node = NodeBuilder::new()
+        .name(&NodeName::new(&options.node_name)?)
+        .create::<ipc::Service>()?;
+    let (service, key_type) = open_blackboard(&ServiceName::new(&options.service)?, &node)?;
+    let writer = service.writer_builder().create()?;
+
+    let entry = entries(&service, &key_type, Some(&options.key))?
+        .pop()
+        .ok_or_else(|| anyhow!("the blackboard has no entry with the provided key"))?;
+    let value = encode(&entry.value_type, &options.value, options.raw)?;
+
+    let handle = unsafe { writer.__internal_entry(entry.key.as_ptr(), &entry.value_type)? };
+    let value_uninit = handle.loan_uninit(entry.value_type.size(), entry.value_type.alignment());
+    unsafe {
+        core::ptr::copy_nonoverlapping(value.as_ptr(), value_uninit.write_cell(), value.len())
+    };
+    value_uninit.update();
+
+    print_entry(&key_type, &entry, Some(&value), options.raw, format)
+}
+
+fn watch(options: BlackboardWatchOptions, format: Format) -> Result<()> {
+    let node = NodeBuilder::new()
+        .name(&NodeName::new(&options.node_name)?)
+        .create::<ipc::Service>()?;
+    let (service, key_type) = open_blackboard(&ServiceName::new(&options.service)?, &node)?;
+    let reader = service.reader_builder().create()?;
+
+    let mut watched_entries = vec![];
+    for entry in entries(&service, &key_type, options.key.as_deref())? {
+        let handle = unsafe { reader.__internal_entry(entry.key.as_ptr(), &entry.value_type)? };
+        // the generation counter 0 is never up to date, therefore all values are printed once
+        watched_entries.push((entry, handle, 0u64));
+    }
+
+    let cycle_time = Duration::from_millis(options.cycle_time);
+    let start = Instant::now();
+    loop {
+        for (entry, handle, generation_counter) in &mut watched_entries {
+            if handle.is_up_to_date(*generation_counter) {
+                continue;
+            }
+
+            let mut value = vec![0u8; entry.value_type.size()];
+            unsafe {
+                handle.get(
+                    value.as_mut_ptr(),
+                    entry.value_type.size(),
+                    entry.value_type.alignment(),
+                    generation_counter,
+                )
+            };
+            print_entry(&key_type, entry, Some(&value), options.raw, format)?;
+        }
+
+        if let Some(timeout) = options.timeout {
+            if start.elapsed().as_millis() >= timeout as _ {
+                break;
+            }
+        }
+
+        if node.wait(cycle_time).is_err() {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+pub(crate) fn blackboard(options: BlackboardOptions, format: Format) -> Result<()> {
+    match options.action {
+        BlackboardAction::Keys(options) => keys(options, format),
+        BlackboardAction::Get(options) => get(options, format),
+        BlackboardAction::Set(options) => set(options, format),
+        BlackboardAction::Watch(options) => watch(options, format),
+    }
+}
@@ -0,0 +1,134 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use crate::cli::BwOptions;
+use crate::command::{Distribution, get_pubsub_service_types, reached_timeout};
+use anyhow::Result;
+use iceoryx2::prelude::*;
+use iceoryx2::service::builder::{CustomHeaderMarker, CustomPayloadMarker};
+use iceoryx2_cli::Format;
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+#[derive(serde::Serialize)]
+struct BwStats {
+    bytes_per_s: f64,
+    message_size_bytes: Distribution,
+    window: usize,
+}
+
+struct Message {
+    arrival: Instant,
+    size: usize,
+}
+
+pub(crate) fn bw(options: BwOptions, format: Format) -> Result<()> {
+    let node = NodeBuilder::new()
+        .name(&NodeName::new(&options.node_name)?)
+        .create::<ipc::Service>()?;
+
+    let service_name = ServiceName::new(&options.service)?;
+    let cycle_time = Duration::from_micros(100);
+    let start = Instant::now();
+
+    while !ipc::Service::does_exist(
+        &service_name,
+        node.config(),
+        MessagingPattern::PublishSubscribe,
+    )? {
+        if reached_timeout(start, options.timeout) {
+            return Ok(());
+        }
+
+        if node.wait(cycle_time).is_err() {
+            return Ok(());
+        }
+    }
+
+    let service_types = get_pubsub_service_types(&service_name, &node)?;
+
+    let service = unsafe {
+        node.service_builder(&service_name)
+            .publish_subscribe::<[CustomPayloadMarker]>()
+            .user_header::<CustomHeaderMarker>()
+            .__internal_set_payload_type_details(&service_types.payload)
+            .__internal_set_user_header_type_details(&service_types.user_header)
+            .open_or_create()?
+    };
+
+    let subscriber = service.subscriber_builder().create()?;
+    let mut messages: VecDeque<Message> = VecDeque::new();
+    let mut last_print = Instant::now();
+    let mut last_msg_time: Option<Instant> = None;
+    let mut last_printed_msg_time: Option<Instant> = None;
+
+    while node.wait(cycle_time).is_ok() {
+        let mut timeout_reached = reached_timeout(start, options.timeout);
+
+        while let Some(sample) = unsafe { subscriber.receive_custom_payload()? } {
+            let now = Instant::now();
+            // the header stores the number of elements, for slices this is the slice length
+            let size = sample.header().number_of_elements() as usize * service_types.payload.size();
+            messages.push_back(Message { arrival: now, size });
+            if messages.len() > options.window {
+                messages.pop_front();
+            }
+            last_msg_time = Some(now);
+
+            if reached_timeout(start, options.timeout) {
+                timeout_reached = true;
+                break;
+            }
+        }
+
+        if last_print.elapsed() >= Duration::from_secs(1) {
+            last_print = Instant::now();
+            if last_msg_time == last_printed_msg_time {
+                continue;
+            }
+            last_printed_msg_time = last_msg_time;
+            print_stats(&messages, format)?;
+        }
+
+        if timeout_reached || reached_timeout(start, options.timeout) {
+            if last_msg_time != last_printed_msg_time {
+                print_stats(&messages, format)?;
+            }
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+fn print_stats(messages: &VecDeque<Message>, format: Format) -> Result<()> {
+    let (Some(first), Some(last)) = (messages.front(), messages.back()) else {
+        return Ok(());
+    };
+    let Some(message_size_bytes) = Distribution::new(messages.iter().map(|m| m.size as f64)) else {
+        return Ok(());
+    };
+
+    // the bytes of the first message arrived before the measured time span started
+    let span_s = last.arrival.duration_since(first.arrival).as_secs_f64();
+    let bytes = messages.iter().skip(1).map(|m| m.size).sum::<usize>() as f64;
+    let bytes_per_s = if span_s > 0.0 { bytes / span_s } else { 0.0 };
+
+    let stats = BwStats {
+        bytes_per_s,
+        message_size_bytes,
+        window: messages.len(),
+    };
+
+    println!("{}", format.as_string(&stats)?);
+    Ok(())
+}
@@ -0,0 +1,118 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::ptr::copy_nonoverlapping;
+use core::time::Duration;
+use std::time::Instant;
+
+use crate::cli::CallOptions;
+use crate::command::{
+    message_to_bytes, number_of_elements, open_or_create_request_response_service, print_message,
+    user_header_to_bytes, verify_payload_size,
+};
+use anyhow::Result;
+use iceoryx2::prelude::*;
+use iceoryx2::service::builder::CustomHeaderMarker;
+use iceoryx2::service::header::request_response::ResponseHeader;
+use iceoryx2::service::static_config::message_type_details::TypeVariant;
+use iceoryx2_cli::Format;
+
+pub(crate) fn call(options: CallOptions, format: Format) -> Result<()> {
+    let node = NodeBuilder::new()
+        .name(&NodeName::new(&options.node_name)?)
+        .create::<ipc::Service>()?;
+
+    let service_name = ServiceName::new(&options.service)?;
+    let (service, request_types, response_types) =
+        open_or_create_request_response_service(&service_name, &node, &options.types)?;
+
+    let mut requests = vec![];
+    for message in &options.message {
+        let payload = message_to_bytes(message, options.data_representation)?;
+        verify_payload_size(&request_types.payload, &payload)?;
+        requests.push(payload);
+    }
+    let user_header = user_header_to_bytes(&options.user_header, &request_types.user_header)?;
+
+    let client = match request_types.payload.variant() {
+        TypeVariant::FixedSize => service.client_builder().create()?,
+        TypeVariant::Dynamic => service
+            .client_builder()
+            .initial_max_slice_len(4096)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create()?,
+    };
+
+    let timeout = Duration::from_millis(options.timeout);
+    for payload in &requests {
+        let pending_response = unsafe {
+            let mut request = client
+                .loan_custom_payload(number_of_elements(&request_types.payload, payload.len()))?;
+            copy_nonoverlapping(
+                payload.as_ptr(),
+                request.payload_mut().as_mut_ptr() as *mut u8,
+                payload.len(),
+            );
+            copy_nonoverlapping(
+                user_header.as_ptr(),
+                (request.user_header_mut() as *mut CustomHeaderMarker) as *mut u8,
+                user_header.len(),
+            );
+            request.assume_init().send()?
+        };
+
+        let deadline = Instant::now() + timeout;
+        loop {
+            // the server drops the active request after its last response, therefore all
+            // responses must be received before the stream is considered done
+            let is_stream_done = pending_response.is_finished() || !pending_response.is_connected();
+            while let Some(response) = unsafe { pending_response.receive_custom_payload()? } {
+                let system_header = unsafe {
+                    core::slice::from_raw_parts(
+                        (response.header() as *const ResponseHeader).cast::<u8>(),
+                        core::mem::size_of::<ResponseHeader>(),
+                    )
+                };
+                let user_header = unsafe {
+                    core::slice::from_raw_parts(
+                        (response.user_header() as *const CustomHeaderMarker).cast::<u8>(),
+                        response_types.user_header.size(),
+                    )
+                };
+                let payload = unsafe {
+                    core::slice::from_raw_parts(
+                        response.payload().as_ptr().cast::<u8>(),
+                        response.payload().len(),
+                    )
+                };
+
+                print_message(
+                    system_header,
+                    user_header,
+                    payload,
+                    options.data_representation,
+                    format,
+                )?;
+            }
+
+            if is_stream_done || deadline <= Instant::now() {
+                break;
+            }
+
+            if node.wait(Duration::from_millis(1)).is_err() {
+                return Ok(());
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -109,7 +109,7 @@ pub(crate) fn hz(options: HzOptions, format: Format) -> Result<()> {
     Ok(())
 }
 
-fn reached_timeout(start: Instant, timeout_s: Option<u64>) -> bool {
+pub(crate) fn reached_timeout(start: Instant, timeout_s: Option<u64>) -> bool {
     timeout_s
         .map(|timeout| start.elapsed() >= Duration::from_secs(timeout))
         .unwrap_or(false)
@@ -0,0 +1,241 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use crate::cli::LatencyOptions;
+use crate::command::{Distribution, get_pubsub_service_types, reached_timeout};
+use anyhow::{Result, anyhow};
+use iceoryx2::port::publisher::Publisher;
+use iceoryx2::port::subscriber::Subscriber;
+use iceoryx2::prelude::*;
+use iceoryx2::service::builder::{CustomHeaderMarker, CustomPayloadMarker};
+use iceoryx2::service::static_config::message_type_details::TypeVariant;
+use iceoryx2_cli::Format;
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// The size of the timestamp that is stored at the beginning of the payload of every probe.
+const TIMESTAMP_SIZE: usize = core::mem::size_of::<u64>();
+
+type CustomPublisher = Publisher<ipc::Service, [CustomPayloadMarker], CustomHeaderMarker>;
+type CustomSubscriber = Subscriber<ipc::Service, [CustomPayloadMarker], CustomHeaderMarker>;
+
+#[derive(serde::Serialize)]
+struct LatencyStats {
+    probes_sent: u64,
+    probes_received: u64,
+    latency_s: Distribution,
+    window: usize,
+}
+
+/// Sends the probes into the service and measures their arrival at the probe subscriber.
+/// Every probe carries the time it was sent, so that probes which arrive after the next
+/// probe was sent are still measured correctly.
+struct Probe {
+    publisher: CustomPublisher,
+    subscriber: CustomSubscriber,
+    number_of_elements: usize,
+    user_header_size: usize,
+    epoch: Instant,
+}
+
+impl Probe {
+    fn send(&self) -> Result<()> {
+        let mut sample = unsafe { self.publisher.loan_custom_payload(self.number_of_elements) }
+            .map_err(|e| anyhow!("failed to loan probe sample ({e:?})"))?;
+
+        let payload_size = sample.payload().len();
+        let timestamp = self.epoch.elapsed().as_nanos() as u64;
+        unsafe {
+            let payload = sample.payload_mut().as_mut_ptr().cast::<u8>();
+            core::ptr::write_bytes(payload, 0, payload_size);
+            core::ptr::write_unaligned(payload.cast::<u64>(), timestamp);
+            core::ptr::write_bytes(
+                (sample.user_header_mut() as *mut CustomHeaderMarker).cast::<u8>(),
+                0,
+                self.user_header_size,
+            );
+        }
+
+        unsafe { sample.assume_init() }.send()?;
+        Ok(())
+    }
+
+    /// Returns the latency of the next probe that arrived. Samples of other publishers are
+    /// discarded.
+    fn receive(&self) -> Result<Option<Duration>> {
+        while let Some(sample) = unsafe { self.subscriber.receive_custom_payload()? } {
+            if sample.header().publisher_id() != self.publisher.id()
+                || sample.payload().len() < TIMESTAMP_SIZE
+            {
+                continue;
+            }
+
+            let timestamp =
+                unsafe { core::ptr::read_unaligned(sample.payload().as_ptr().cast::<u64>()) };
+            let arrival = self.epoch.elapsed().as_nanos() as u64;
+            return Ok(Some(Duration::from_nanos(
+                arrival.saturating_sub(timestamp),
+            )));
+        }
+
+        Ok(None)
+    }
+}
+
+pub(crate) fn latency(options: LatencyOptions, format: Format) -> Result<()> {
+    let node = NodeBuilder::new()
+        .name(&NodeName::new(&options.node_name)?)
+        .create::<ipc::Service>()?;
+
+    let service_name = ServiceName::new(&options.service)?;
+    let cycle_time = Duration::from_micros(100);
+    let start = Instant::now();
+
+    while !ipc::Service::does_exist(
+        &service_name,
+        node.config(),
+        MessagingPattern::PublishSubscribe,
+    )? {
+        if reached_timeout(start, options.timeout) {
+            return Ok(());
+        }
+
+        if node.wait(cycle_time).is_err() {
+            return Ok(());
+        }
+    }
+
+    let service_types = get_pubsub_service_types(&service_name, &node)?;
+
+    let service = unsafe {
+        node.service_builder(&service_name)
+            .publish_subscribe::<[CustomPayloadMarker]>()
+            .user_header::<CustomHeaderMarker>()
+            .__internal_set_payload_type_details(&service_types.payload)
+            .__internal_set_user_header_type_details(&service_types.user_header)
+            .open_or_create()?
+    };
+
+    let element_size = service_types.payload.size().max(1);
+    let number_of_elements = match service_types.payload.variant() {
+        TypeVariant::FixedSize => 1,
+        TypeVariant::Dynamic => TIMESTAMP_SIZE.div_ceil(element_size),
+    };
+    if number_of_elements * service_types.payload.size() < TIMESTAMP_SIZE {
+        return Err(anyhow!(
+            "the payload size of {} is too small to store the probe timestamp of {} bytes",
+            service_types.payload.size(),
+            TIMESTAMP_SIZE
+        ));
+    }
+
+    // the subscriber must be connected before the first probe is sent
+    let subscriber = service.subscriber_builder().create()?;
+    let publisher = match service_types.payload.variant() {
+        TypeVariant::FixedSize => service.publisher_builder().create()?,
+        TypeVariant::Dynamic => service
+            .publisher_builder()
+            .initial_max_slice_len(number_of_elements)
+            .create()?,
+    };
+
+    let probe = Probe {
+        publisher,
+        subscriber,
+        number_of_elements,
+        user_header_size: service_types.user_header.size(),
+        epoch: Instant::now(),
+    };
+
+    let interval = Duration::from_millis(options.interval);
+    let mut latencies: VecDeque<Duration> = VecDeque::new();
+    let mut probes_sent = 0u64;
+    let mut probes_received = 0u64;
+    let mut last_print = Instant::now();
+    let mut last_printed_probe = 0u64;
+
+    loop {
+        if reached_timeout(start, options.timeout)
+            || options.count.is_some_and(|count| probes_sent >= count)
+        {
+            break;
+        }
+
+        probe.send()?;
+        probes_sent += 1;
+
+        // busy wait for the probe so that the polling does not add to the measured latency
+        let deadline = Instant::now() + interval;
+        while Instant::now() < deadline {
+            match probe.receive()? {
+                Some(latency) => {
+                    latencies.push_back(latency);
+                    if latencies.len() > options.window {
+                        latencies.pop_front();
+                    }
+                    probes_received += 1;
+                    break;
+                }
+                None => core::hint::spin_loop(),
+            }
+        }
+
+        if last_print.elapsed() >= Duration::from_secs(1) && last_printed_probe != probes_received {
+            last_print = Instant::now();
+            last_printed_probe = probes_received;
+            print_stats(&latencies, probes_sent, probes_received, format)?;
+        }
+
+        if node
+            .wait(deadline.saturating_duration_since(Instant::now()))
+            .is_err()
+        {
+            break;
+        }
+    }
+
+    // collect the probes that arrived after the last deadline
+    while let Some(latency) = probe.receive()? {
+        latencies.push_back(latency);
+        if latencies.len() > options.window {
+            latencies.pop_front();
+        }
+        probes_received += 1;
+    }
+
+    if last_printed_probe != probes_received {
+        print_stats(&latencies, probes_sent, probes_received, format)?;
+    }
+
+    Ok(())
+}
+
+fn print_stats(
+    latencies: &VecDeque<Duration>,
+    probes_sent: u64,
+    probes_received: u64,
+    format: Format,
+) -> Result<()> {
+    let Some(latency_s) = Distribution::new(latencies.iter().map(Duration::as_secs_f64)) else {
+        return Ok(());
+    };
+
+    let stats = LatencyStats {
+        probes_sent,
+        probes_received,
+        latency_s,
+        window: latencies.len(),
+    };
+
+    println!("{}", format.as_string(&stats)?);
+    Ok(())
+}
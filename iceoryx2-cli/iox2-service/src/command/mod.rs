@@ -10,30 +10,42 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+mod blackboard;
+mod bw;
+mod call;
 mod details;
 mod discovery;
 mod hz;
+mod latency;
 mod list;
 mod listen;
 mod notify;
 mod publish;
 mod record;
 mod replay;
+mod serve;
 mod subscribe;
 
+pub(crate) use blackboard::*;
+pub(crate) use bw::*;
+pub(crate) use call::*;
 pub(crate) use details::*;
 pub(crate) use discovery::*;
 pub(crate) use hz::*;
+pub(crate) use latency::*;
 pub(crate) use list::*;
 pub(crate) use listen::*;
 pub(crate) use notify::*;
 pub(crate) use publish::*;
 pub(crate) use record::*;
 pub(crate) use replay::*;
+pub(crate) use serve::*;
 pub(crate) use subscribe::*;
 
 use anyhow::{Result, anyhow};
 use iceoryx2::service::builder::{CustomHeaderMarker, CustomPayloadMarker};
+use iceoryx2::service::port_factory::request_response::PortFactory;
+use iceoryx2::service::static_config::message_type_details::TypeName;
 use iceoryx2::{
     prelude::*,
     sample::Sample,
@@ -42,9 +54,12 @@ use iceoryx2::{
         static_config::message_type_details::{TypeDetail, TypeVariant},
     },
 };
-use iceoryx2_userland_record_and_replay::prelude::ServiceTypes;
+use iceoryx2_userland_record_and_replay::hex_conversion::hex_string_to_bytes;
+use iceoryx2_userland_record_and_replay::prelude::{RequestResponseRecordHeader, ServiceTypes};
 use serde::Serialize;
 
+use crate::cli::{CliTypeVariant, DataRepresentation, RequestResponseTypeOptions};
+
 // explicitly allow same prefix Notification since it shall
 // be human readable on command line
 #[allow(clippy::enum_variant_names)]
@@ -107,6 +122,139 @@ pub(crate) fn get_pubsub_service_types(
     })
 }
 
+/// Returns the recorded [`ServiceTypes`] of the requests and the responses of a
+/// request-response service.
+pub(crate) fn get_request_response_service_types(
+    service_name: &ServiceName,
+    node: &Node<ipc::Service>,
+) -> Result<(ServiceTypes, ServiceTypes)> {
+    let service_details = match ipc::Service::details(
+        service_name,
+        node.config(),
+        MessagingPattern::RequestResponse,
+    )? {
+        Some(v) => v,
+        None => {
+            return Err(anyhow!(
+                "unable to access service \"{service_name}\", does it exist?",
+            ));
+        }
+    };
+
+    let static_config = unsafe {
+        service_details
+            .static_details
+            .messaging_pattern()
+            .request_response()
+    };
+    let request = static_config.request_message_type_details();
+    let response = static_config.response_message_type_details();
+
+    Ok((
+        RequestResponseRecordHeader::service_types(&request.payload, &request.user_header),
+        RequestResponseRecordHeader::service_types(&response.payload, &response.user_header),
+    ))
+}
+
+pub(crate) type CustomRequestResponseService = PortFactory<
+    ipc::Service,
+    [CustomPayloadMarker],
+    CustomHeaderMarker,
+    [CustomPayloadMarker],
+    CustomHeaderMarker,
+>;
+
+/// Creates the [`TypeDetail`] of a type that was defined on the command line.
+pub(crate) fn type_detail(
+    name: &str,
+    size: usize,
+    alignment: usize,
+    variant: CliTypeVariant,
+) -> Result<TypeDetail> {
+    let mut type_detail = TypeDetail::new::<()>(match variant {
+        CliTypeVariant::Dynamic => TypeVariant::Dynamic,
+        CliTypeVariant::FixedSize => TypeVariant::FixedSize,
+    });
+    iceoryx2::testing::type_detail_set_size(&mut type_detail, size);
+    iceoryx2::testing::type_detail_set_alignment(&mut type_detail, alignment);
+    iceoryx2::testing::type_detail_set_name(&mut type_detail, TypeName::from_str_truncated(name)?);
+
+    Ok(type_detail)
+}
+
+/// Opens the request-response service with its discovered types. When the service does not
+/// exist it is created with the types defined in the [`RequestResponseTypeOptions`]. Returns
+/// the service together with the [`ServiceTypes`] of the requests and the responses.
+pub(crate) fn open_or_create_request_response_service(
+    service_name: &ServiceName,
+    node: &Node<ipc::Service>,
+    options: &RequestResponseTypeOptions,
+) -> Result<(CustomRequestResponseService, ServiceTypes, ServiceTypes)> {
+    let (request_types, response_types) =
+        match get_request_response_service_types(service_name, node) {
+            Ok(types) => types,
+            Err(_) => {
+                let unit_header = TypeDetail::new::<()>(TypeVariant::FixedSize);
+                (
+                    RequestResponseRecordHeader::service_types(
+                        &type_detail(
+                            &options.request_type_name,
+                            options.request_type_size,
+                            options.request_type_alignment,
+                            options.request_type_variant,
+                        )?,
+                        &unit_header,
+                    ),
+                    RequestResponseRecordHeader::service_types(
+                        &type_detail(
+                            &options.response_type_name,
+                            options.response_type_size,
+                            options.response_type_alignment,
+                            options.response_type_variant,
+                        )?,
+                        &unit_header,
+                    ),
+                )
+            }
+        };
+
+    let service = unsafe {
+        node.service_builder(service_name)
+            .request_response::<[CustomPayloadMarker], [CustomPayloadMarker]>()
+            .request_user_header::<CustomHeaderMarker>()
+            .response_user_header::<CustomHeaderMarker>()
+            .__internal_set_request_payload_type_details(&request_types.payload)
+            .__internal_set_request_header_type_details(&request_types.user_header)
+            .__internal_set_response_payload_type_details(&response_types.payload)
+            .__internal_set_response_header_type_details(&response_types.user_header)
+            .open_or_create()?
+    };
+
+    Ok((service, request_types, response_types))
+}
+
+/// Converts the user header that was provided on the command line into bytes. When no user
+/// header was provided a zeroed user header of the required size is returned.
+pub(crate) fn user_header_to_bytes(
+    user_header: &Option<String>,
+    user_header_type: &TypeDetail,
+) -> Result<Vec<u8>> {
+    match user_header {
+        None => Ok(vec![0u8; user_header_type.size()]),
+        Some(user_header) => {
+            let bytes = hex_string_to_bytes(user_header)?;
+            if bytes.len() != user_header_type.size() {
+                return Err(anyhow!(
+                    "raw user header size of {} does not fit required user header type size of {}",
+                    bytes.len(),
+                    user_header_type.size()
+                ));
+            }
+            Ok(bytes)
+        }
+    }
+}
+
 pub(crate) fn extract_pubsub_payload<'a>(
     sample: &'a Sample<ipc::Service, [CustomPayloadMarker], CustomHeaderMarker>,
     user_header_type: &TypeDetail,
@@ -129,3 +277,76 @@ pub(crate) fn extract_pubsub_payload<'a>(
 
     (system_header, user_header, payload)
 }
+
+/// Returns the number of elements that must be loaned for a payload of the provided size.
+pub(crate) fn number_of_elements(payload_type: &TypeDetail, payload_len: usize) -> usize {
+    match payload_type.variant() {
+        TypeVariant::FixedSize => 1,
+        TypeVariant::Dynamic => payload_len / payload_type.size().max(1),
+    }
+}
+
+/// Converts a message that was provided on the command line into bytes.
+pub(crate) fn message_to_bytes(
+    message: &str,
+    data_representation: DataRepresentation,
+) -> Result<Vec<u8>> {
+    match data_representation {
+        DataRepresentation::Iox2Dump => Ok(message.as_bytes().to_vec()),
+        DataRepresentation::HumanReadable => Ok(hex_string_to_bytes(message)?),
+    }
+}
+
+/// Verifies that the payload can be stored in a message of the provided payload type.
+pub(crate) fn verify_payload_size(payload_type: &TypeDetail, payload: &[u8]) -> Result<()> {
+    match payload_type.variant() {
+        TypeVariant::FixedSize if payload.len() != payload_type.size() => Err(anyhow!(
+            "raw message size of {} does not fit required type size of {}",
+            payload.len(),
+            payload_type.size()
+        )),
+        TypeVariant::Dynamic if payload.len() % payload_type.size().max(1) != 0 => Err(anyhow!(
+            "raw message size of {} is not a multiple of the required type size of {}",
+            payload.len(),
+            payload_type.size()
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// The distribution of a series of measurements. The percentiles use the nearest-rank
+/// method.
+#[derive(Serialize)]
+pub(crate) struct Distribution {
+    min: f64,
+    mean: f64,
+    max: f64,
+    p50: f64,
+    p90: f64,
+    p99: f64,
+}
+
+impl Distribution {
+    /// Returns [`None`] when no measurements are provided.
+    pub(crate) fn new<I: Iterator<Item = f64>>(values: I) -> Option<Self> {
+        let mut values: Vec<f64> = values.collect();
+        if values.is_empty() {
+            return None;
+        }
+        values.sort_by(f64::total_cmp);
+
+        let percentile = |p: f64| {
+            let rank = (p / 100.0 * values.len() as f64).ceil() as usize;
+            values[rank.clamp(1, values.len()) - 1]
+        };
+
+        Some(Self {
+            min: values[0],
+            mean: values.iter().sum::<f64>() / values.len() as f64,
+            max: values[values.len() - 1],
+            p50: percentile(50.0),
+            p90: percentile(90.0),
+            p99: percentile(99.0),
+        })
+    }
+}
@@ -10,16 +10,15 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use crate::cli::{CliTypeVariant, DataRepresentation, PublishOptions};
+use crate::cli::{CliTypeVariant, PublishOptions};
+use crate::command::{message_to_bytes, type_detail};
 use anyhow::Result;
 use core::mem::MaybeUninit;
 use iceoryx2::port::publisher::Publisher;
 use iceoryx2::prelude::*;
 use iceoryx2::sample_mut_uninit::SampleMutUninit;
 use iceoryx2::service::builder::{CustomHeaderMarker, CustomPayloadMarker};
-use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeName, TypeVariant};
 use iceoryx2_cli::Format;
-use iceoryx2_userland_record_and_replay::hex_conversion::hex_string_to_bytes;
 use std::ptr::copy_nonoverlapping;
 use std::time::Duration;
 
@@ -95,15 +94,10 @@ fn read_cli_msg_into_buffer(
     options: &PublishOptions,
 ) -> Result<()> {
     for message in &options.message {
-        match options.data_representation {
-            DataRepresentation::Iox2Dump => {
-                message_buffer.push((vec![], message.as_bytes().to_vec()))
-            }
-            DataRepresentation::HumanReadable => {
-                let payload = hex_string_to_bytes(message)?;
-                message_buffer.push((vec![], payload));
-            }
-        }
+        message_buffer.push((
+            vec![],
+            message_to_bytes(message, options.data_representation)?,
+        ));
     }
 
     Ok(())
@@ -114,24 +108,18 @@ pub(crate) fn publish(options: PublishOptions, _format: Format) -> Result<()> {
         .name(&NodeName::new(&options.node_name)?)
         .create::<ipc::Service>()?;
 
-    let mut payload_type = TypeDetail::new::<()>(match options.type_variant {
-        CliTypeVariant::Dynamic => TypeVariant::Dynamic,
-        CliTypeVariant::FixedSize => TypeVariant::FixedSize,
-    });
-    iceoryx2::testing::type_detail_set_size(&mut payload_type, options.type_size);
-    iceoryx2::testing::type_detail_set_alignment(&mut payload_type, options.type_alignment);
-    iceoryx2::testing::type_detail_set_name(
-        &mut payload_type,
-        TypeName::from_str_truncated(options.type_name.as_str())?,
-    );
-
-    let mut header_type = TypeDetail::new::<()>(TypeVariant::FixedSize);
-    iceoryx2::testing::type_detail_set_size(&mut header_type, options.header_type_size);
-    iceoryx2::testing::type_detail_set_alignment(&mut header_type, options.header_type_alignment);
-    iceoryx2::testing::type_detail_set_name(
-        &mut header_type,
-        TypeName::from_str_truncated(options.header_type_name.as_str())?,
-    );
+    let payload_type = type_detail(
+        &options.type_name,
+        options.type_size,
+        options.type_alignment,
+        options.type_variant,
+    )?;
+    let header_type = type_detail(
+        &options.header_type_name,
+        options.header_type_size,
+        options.header_type_alignment,
+        CliTypeVariant::FixedSize,
+    )?;
 
     let service = unsafe {
         node.service_builder(&ServiceName::new(&options.service)?)
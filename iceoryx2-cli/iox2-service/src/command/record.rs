@@ -10,27 +10,36 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use crate::cli::RecordOptions;
+use crate::cli::{Compression, MessagingPattern as RecordMessagingPattern, RecordOptions};
 use crate::command::{extract_pubsub_payload, get_pubsub_service_types};
-use anyhow::Result;
+use crate::filter::ServiceSelection;
+use anyhow::{Result, anyhow};
 use core::time::Duration;
+use iceoryx2::port::listener::Listener;
+use iceoryx2::port::subscriber::Subscriber;
 use iceoryx2::prelude::*;
 use iceoryx2::service::builder::{CustomHeaderMarker, CustomPayloadMarker};
+use iceoryx2::service::static_config::messaging_pattern::MessagingPattern as StaticMessagingPattern;
 use iceoryx2_cli::Format;
 use iceoryx2_userland_record_and_replay::prelude::*;
 use std::io::Write;
 use std::time::Instant;
 
-pub(crate) fn record(options: RecordOptions, _format: Format) -> Result<()> {
-    let node = NodeBuilder::new()
-        .name(&NodeName::new(&options.node_name)?)
-        .create::<ipc::Service>()?;
+type CustomSubscriber = Subscriber<ipc::Service, [CustomPayloadMarker], CustomHeaderMarker>;
 
-    let service_name = ServiceName::new(&options.service)?;
-    let service_types = get_pubsub_service_types(&service_name, &node)?;
+struct Channel {
+    id: ChannelId,
+    subscriber: CustomSubscriber,
+    types: ServiceTypes,
+}
 
+fn create_subscriber(
+    service_name: &ServiceName,
+    service_types: &ServiceTypes,
+    node: &Node<ipc::Service>,
+) -> Result<CustomSubscriber> {
     let service = unsafe {
-        node.service_builder(&service_name)
+        node.service_builder(service_name)
             .publish_subscribe::<[CustomPayloadMarker]>()
             .user_header::<CustomHeaderMarker>()
             .__internal_set_payload_type_details(&service_types.payload)
@@ -38,41 +47,240 @@ pub(crate) fn record(options: RecordOptions, _format: Format) -> Result<()> {
             .open_or_create()?
     };
 
-    let subscriber = service.subscriber_builder().create()?;
+    Ok(service.subscriber_builder().create()?)
+}
+
+fn select_services(options: &RecordOptions, node: &Node<ipc::Service>) -> Result<Vec<ServiceName>> {
+    let selection = if options.regex {
+        ServiceSelection::from_regexes(&options.services)?
+    } else {
+        ServiceSelection::from_globs(&options.services)?
+    };
+
+    let mut service_names = vec![];
+    ipc::Service::list(node.config(), |service| {
+        if matches!(
+            service.static_details.messaging_pattern(),
+            StaticMessagingPattern::PublishSubscribe(_)
+        ) && selection.matches(service.static_details.name())
+        {
+            service_names.push(*service.static_details.name());
+        }
+        CallbackProgression::Continue
+    })?;
+
+    service_names.sort_by(|lhs, rhs| lhs.as_str().cmp(rhs.as_str()));
+    service_names.dedup();
+
+    if service_names.is_empty() {
+        return Err(anyhow!(
+            "no publish-subscribe service matches the selection {:?}",
+            options.services
+        ));
+    }
+
+    Ok(service_names)
+}
+
+pub(crate) fn record(options: RecordOptions, _format: Format) -> Result<()> {
+    let node = NodeBuilder::new()
+        .name(&NodeName::new(&options.node_name)?)
+        .create::<ipc::Service>()?;
+
+    if options.bag {
+        if !matches!(
+            options.messaging_pattern,
+            RecordMessagingPattern::PublishSubscribe
+        ) {
+            return Err(anyhow!(
+                "only publish-subscribe services can be recorded into a bag"
+            ));
+        }
+        if !matches!(options.compression, Compression::None) {
+            return Err(anyhow!("bag files cannot be compressed"));
+        }
+        return record_bag(options, node);
+    }
+
+    if options.services.len() != 1 {
+        return Err(anyhow!(
+            "exactly one service can be recorded, use --bag to record multiple services"
+        ));
+    }
+
+    let service_name = ServiceName::new(&options.services[0])?;
+    match options.messaging_pattern {
+        RecordMessagingPattern::PublishSubscribe => {
+            record_publish_subscribe(&options, &node, &service_name)
+        }
+        RecordMessagingPattern::Event => record_event(&options, &node, &service_name),
+        RecordMessagingPattern::RequestResponse => Err(anyhow!(
+            "request-response services cannot be recorded from the outside since the responses \
+             are only delivered to the requesting client, record them inside the client with the \
+             record-and-replay library or create a baseline with \"iox2 service replay --output\""
+        )),
+    }
+}
+
+fn record_publish_subscribe(
+    options: &RecordOptions,
+    node: &Node<ipc::Service>,
+    service_name: &ServiceName,
+) -> Result<()> {
+    let service_types = get_pubsub_service_types(service_name, node)?;
+    let subscriber = create_subscriber(service_name, &service_types, node)?;
+
     let mut recorder = RecorderBuilder::new(&service_types)
         .data_representation(options.data_representation.into())
-        .messaging_pattern(options.messaging_pattern.into())
-        .create(&FilePath::new(options.output.as_bytes())?, &service_name)?;
+        .compression(options.compression.into())
+        .messaging_pattern(MessagingPattern::PublishSubscribe)
+        .create(&FilePath::new(options.output.as_bytes())?, service_name)?;
+
+    println!("Start recording data on \"{service_name}\".");
+
+    let channels = [Channel {
+        id: ChannelId::new(0),
+        subscriber,
+        types: service_types,
+    }];
+    receive_loop(options, node, &channels, |_, record| {
+        Ok(recorder.write(record)?)
+    })
+}
+
+fn record_event(
+    options: &RecordOptions,
+    node: &Node<ipc::Service>,
+    service_name: &ServiceName,
+) -> Result<()> {
+    let service = node.service_builder(service_name).event().open()?;
+
+    let mut recorder = RecorderBuilder::new(&EventRecordHeader::service_types())
+        .data_representation(options.data_representation.into())
+        .compression(options.compression.into())
+        .messaging_pattern(MessagingPattern::Event)
+        .create(&FilePath::new(options.output.as_bytes())?, service_name)?;
 
-    println!("Start recording data on \"{}\".", options.service);
+    println!("Start recording notifications on \"{service_name}\".");
 
+    // every notifier gets its own listener that accepts only its notifications so that
+    // the notifier of a received notification is known
+    let mut listeners: Vec<(u128, Listener<ipc::Service>)> = vec![];
     let start = Instant::now();
     let mut msg_counter = 0u64;
     let cycle_time = Duration::from_millis(options.cycle_time_in_ms);
     'node_loop: loop {
-        while let Some(sample) = unsafe { subscriber.receive_custom_payload()? } {
-            let (system_header, user_header, payload) =
-                extract_pubsub_payload(&sample, &service_types.user_header);
-
-            let elapsed = start.elapsed();
-            recorder.write(RawRecord {
-                timestamp: elapsed,
-                system_header,
-                user_header,
-                payload,
-            })?;
+        let mut notifiers = vec![];
+        service.dynamic_config().list_notifiers(|details| {
+            notifiers.push(details.notifier_id);
+            CallbackProgression::Continue
+        });
 
-            print!(".");
-            std::io::stdout().flush()?;
-            msg_counter += 1;
-            if let Some(max_messages) = options.max_messages {
-                if msg_counter >= max_messages {
-                    break 'node_loop;
-                }
+        for notifier_id in &notifiers {
+            if !listeners.iter().any(|(id, _)| *id == notifier_id.value()) {
+                let listener = service
+                    .listener_builder()
+                    .allow_notifier(*notifier_id)
+                    .create()?;
+                listeners.push((notifier_id.value(), listener));
             }
+        }
+
+        for (notifier_id, listener) in &listeners {
+            let mut result = Ok(());
+            listener.try_wait_with_data(|event, data| {
+                if result.is_err() {
+                    return;
+                }
+
+                let header = EventRecordHeader::new(Some(*notifier_id), event.id, event.count);
+                result = recorder.write(RawRecord {
+                    timestamp: start.elapsed(),
+                    system_header: &header.to_bytes(),
+                    user_header: &[],
+                    payload: data,
+                });
+                print!(".");
+                msg_counter += 1;
+            })?;
+            result?;
+        }
+        std::io::stdout().flush()?;
 
-            if let Some(timeout) = options.timeout_in_sec {
-                if start.elapsed().as_secs() >= timeout as _ {
+        if is_recording_done(options, &start, msg_counter) {
+            break 'node_loop;
+        }
+
+        // the notifications of a removed notifier were received in the previous cycle
+        listeners.retain(|(id, _)| notifiers.iter().any(|n| n.value() == *id));
+
+        if node.wait(cycle_time).is_err() {
+            break 'node_loop;
+        }
+    }
+    println!(" ");
+
+    Ok(())
+}
+
+fn record_bag(options: RecordOptions, node: Node<ipc::Service>) -> Result<()> {
+    let service_names = select_services(&options, &node)?;
+
+    let mut builder =
+        BagRecorderBuilder::new().data_representation(options.data_representation.into());
+    let mut channels = vec![];
+    for (n, service_name) in service_names.iter().enumerate() {
+        let types = get_pubsub_service_types(service_name, &node)?;
+        builder = builder.add_channel(service_name, &types, MessagingPattern::PublishSubscribe);
+        channels.push(Channel {
+            id: ChannelId::new(n as u64),
+            subscriber: create_subscriber(service_name, &types, &node)?,
+            types,
+        });
+    }
+
+    let mut recorder = builder.create(&FilePath::new(options.output.as_bytes())?)?;
+
+    for service_name in &service_names {
+        println!("Start recording data on \"{service_name}\".");
+    }
+
+    receive_loop(&options, &node, &channels, |channel_id, record| {
+        Ok(recorder.write(channel_id, record)?)
+    })
+}
+
+fn receive_loop<F: FnMut(ChannelId, RawRecord) -> Result<()>>(
+    options: &RecordOptions,
+    node: &Node<ipc::Service>,
+    channels: &[Channel],
+    mut write: F,
+) -> Result<()> {
+    let start = Instant::now();
+    let mut msg_counter = 0u64;
+    let cycle_time = Duration::from_millis(options.cycle_time_in_ms);
+    'node_loop: loop {
+        for channel in channels {
+            while let Some(sample) = unsafe { channel.subscriber.receive_custom_payload()? } {
+                let (system_header, user_header, payload) =
+                    extract_pubsub_payload(&sample, &channel.types.user_header);
+
+                // all channels share one clock so that the records are globally ordered
+                let elapsed = start.elapsed();
+                write(
+                    channel.id,
+                    RawRecord {
+                        timestamp: elapsed,
+                        system_header,
+                        user_header,
+                        payload,
+                    },
+                )?;
+
+                print!(".");
+                std::io::stdout().flush()?;
+                msg_counter += 1;
+                if is_recording_done(options, &start, msg_counter) {
                     break 'node_loop;
                 }
             }
@@ -86,3 +294,19 @@ pub(crate) fn record(options: RecordOptions, _format: Format) -> Result<()> {
 
     Ok(())
 }
+
+fn is_recording_done(options: &RecordOptions, start: &Instant, msg_counter: u64) -> bool {
+    if let Some(max_messages) = options.max_messages {
+        if msg_counter >= max_messages {
+            return true;
+        }
+    }
+
+    if let Some(timeout) = options.timeout_in_sec {
+        if start.elapsed().as_secs() >= timeout as _ {
+            return true;
+        }
+    }
+
+    false
+}
@@ -12,42 +12,169 @@
 
 use core::ptr::copy_nonoverlapping;
 use core::time::Duration;
+use std::collections::HashMap;
 use std::io::Write;
 use std::time::Instant;
 
-use crate::cli::ReplayOptions;
-use crate::command::get_pubsub_service_types;
-use anyhow::Result;
+use crate::cli::{RecordDataRepresentation, ReplayOptions};
+use crate::command::{
+    get_pubsub_service_types, get_request_response_service_types, number_of_elements,
+};
+use anyhow::{Result, anyhow};
+use iceoryx2::port::publisher::Publisher;
 use iceoryx2::prelude::*;
 use iceoryx2::service::builder::{CustomHeaderMarker, CustomPayloadMarker};
 use iceoryx2::service::static_config::message_type_details::TypeVariant;
 use iceoryx2_cli::Format;
+use iceoryx2_userland_record_and_replay::bag::FILE_FORMAT_BAG_VERSION;
 use iceoryx2_userland_record_and_replay::prelude::*;
 use iceoryx2_userland_record_and_replay::record_header::{
-    FILE_FORMAT_HUMAN_READABLE_VERSION, FILE_FORMAT_IOX2_DUMP_VERSION, RecordHeaderDetails,
+    FILE_FORMAT_HUMAN_READABLE_VERSION, FILE_FORMAT_IOX2_DUMP_VERSION, FILE_FORMAT_MCAP_VERSION,
+    RecordHeaderDetails,
 };
+use iceoryx2_userland_record_and_replay::request_response::RequestResponseRecordKind;
+
+type CustomPublisher = Publisher<ipc::Service, [CustomPayloadMarker], CustomHeaderMarker>;
+
+fn create_publisher(
+    service_name: &ServiceName,
+    types: &ServiceTypes,
+    node: &Node<ipc::Service>,
+) -> Result<CustomPublisher> {
+    let service = unsafe {
+        node.service_builder(service_name)
+            .publish_subscribe::<[CustomPayloadMarker]>()
+            .user_header::<CustomHeaderMarker>()
+            .__internal_set_payload_type_details(&types.payload)
+            .__internal_set_user_header_type_details(&types.user_header)
+            .open_or_create()?
+    };
+
+    let publisher = match types.payload.variant() {
+        TypeVariant::FixedSize => service.publisher_builder().create()?,
+        TypeVariant::Dynamic => service
+            .publisher_builder()
+            .initial_max_slice_len(4096)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create()?,
+    };
+
+    Ok(publisher)
+}
+
+fn send_record(
+    publisher: &CustomPublisher,
+    types: &ServiceTypes,
+    data: &Record,
+    start: &Instant,
+    time_offset: Duration,
+    time_factor: f32,
+) -> Result<()> {
+    let payload_len = match types.payload.variant() {
+        TypeVariant::FixedSize => 1,
+        TypeVariant::Dynamic => data.payload.len(),
+    };
+
+    let sample = unsafe {
+        let mut sample = publisher.loan_custom_payload(payload_len)?;
+        copy_nonoverlapping(
+            data.payload.as_ptr(),
+            sample.payload_mut().as_ptr() as *mut u8,
+            data.payload.len(),
+        );
+        if !data.user_header.is_empty() {
+            copy_nonoverlapping(
+                data.user_header.as_ptr(),
+                (sample.user_header_mut() as *mut CustomHeaderMarker) as *mut u8,
+                data.user_header.len(),
+            );
+        }
+        sample.assume_init()
+    };
+
+    wait_for_timestamp(data.timestamp, start, time_offset, time_factor);
+    sample.send()?;
+    print!(".");
+    std::io::stdout().flush()?;
+
+    Ok(())
+}
+
+fn wait_for_timestamp(
+    timestamp: Duration,
+    start: &Instant,
+    time_offset: Duration,
+    time_factor: f32,
+) {
+    let elapsed = start.elapsed().as_millis() as f64 * time_factor as f64;
+    let timestamp = timestamp.saturating_sub(time_offset).as_millis() as f64 * time_factor as f64;
+    if elapsed < timestamp {
+        std::thread::sleep(Duration::from_millis((timestamp - elapsed) as u64));
+    }
+}
+
+fn time_window(options: &ReplayOptions) -> Result<(Duration, Duration)> {
+    let start = options.start.map_or(Duration::ZERO, Duration::from_millis);
+    let end = options.end.map_or(Duration::MAX, Duration::from_millis);
+    if end < start {
+        return Err(anyhow!(
+            "The end of the replay ({end:?}) must not be before its start ({start:?})."
+        ));
+    }
+
+    Ok((start, end))
+}
+
+fn is_last_repetition(options: &ReplayOptions, n: u64) -> bool {
+    !options.endless_loop && options.repetitions <= n
+}
 
 pub(crate) fn replay(options: ReplayOptions, _format: Format) -> Result<()> {
     let node = NodeBuilder::new()
         .name(&NodeName::new(&options.node_name)?)
         .create::<ipc::Service>()?;
 
+    if options.bag {
+        return replay_bag(options, node);
+    }
+
     let replay = ReplayerOpener::new(&FilePath::new(options.input.as_bytes())?)
         .data_representation(options.data_representation.into())
         .open()?;
 
-    let service_name = match options.service {
-        Some(v) => ServiceName::new(&v)?,
+    let service_name = match &options.service {
+        Some(v) => ServiceName::new(v)?,
         None => replay.header().service_name,
     };
 
+    let messaging_pattern = options.messaging_pattern.into();
+    if options.output.is_some() && messaging_pattern != MessagingPattern::RequestResponse {
+        return Err(anyhow!(
+            "Only the replay of request-response services can be stored with --output."
+        ));
+    }
+
+    let (types, response_types) = match messaging_pattern {
+        MessagingPattern::Event => (EventRecordHeader::service_types(), None),
+        MessagingPattern::RequestResponse => {
+            let (request_types, response_types) =
+                get_request_response_service_types(&service_name, &node)?;
+            (request_types, Some(response_types))
+        }
+        _ => (get_pubsub_service_types(&service_name, &node)?, None),
+    };
+
     let required_header = RecordHeaderDetails {
         file_format_version: match options.data_representation {
-            crate::cli::DataRepresentation::HumanReadable => FILE_FORMAT_HUMAN_READABLE_VERSION,
-            crate::cli::DataRepresentation::Iox2Dump => FILE_FORMAT_IOX2_DUMP_VERSION,
+            RecordDataRepresentation::HumanReadable => FILE_FORMAT_HUMAN_READABLE_VERSION,
+            RecordDataRepresentation::Iox2Dump => FILE_FORMAT_IOX2_DUMP_VERSION,
+            RecordDataRepresentation::Mcap => FILE_FORMAT_MCAP_VERSION,
         },
-        types: get_pubsub_service_types(&service_name, &node)?,
-        messaging_pattern: options.messaging_pattern.into(),
+        types,
+        response_types,
+        messaging_pattern,
+        // the compression is transparent for the replay
+        compression: replay.header().details.compression,
     };
 
     if required_header != replay.header().details {
@@ -57,64 +184,346 @@ pub(crate) fn replay(options: ReplayOptions, _format: Format) -> Result<()> {
         ));
     }
 
-    let buffer = replay.read_into_buffer()?;
+    println!(
+        "The recording contains {} records with a duration of {:?}.",
+        replay.number_of_records(),
+        replay.duration()
+    );
+
+    let (window_start, window_end) = time_window(&options)?;
+    let buffer = replay.read_range_into_buffer(window_start, window_end)?;
+
+    match (messaging_pattern, &required_header.response_types) {
+        (MessagingPattern::Event, _) => {
+            replay_event(&options, &node, &service_name, &buffer, window_start)
+        }
+        (MessagingPattern::RequestResponse, Some(response_types)) => replay_request_response(
+            &options,
+            &node,
+            &service_name,
+            (&required_header.types, response_types),
+            &buffer,
+            window_start,
+        ),
+        _ => replay_publish_subscribe(
+            &options,
+            &node,
+            &service_name,
+            &required_header.types,
+            &buffer,
+            window_start,
+        ),
+    }
+}
+
+fn replay_publish_subscribe(
+    options: &ReplayOptions,
+    node: &Node<ipc::Service>,
+    service_name: &ServiceName,
+    types: &ServiceTypes,
+    buffer: &[Record],
+    window_start: Duration,
+) -> Result<()> {
+    let publisher = create_publisher(service_name, types, node)?;
+
+    println!("Start replaying data on \"{service_name}\".");
+    for n in 0..u64::MAX {
+        let start = Instant::now();
+        for data in buffer {
+            send_record(
+                &publisher,
+                types,
+                data,
+                &start,
+                window_start,
+                options.time_factor,
+            )?;
+        }
+
+        if is_last_repetition(options, n) {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+fn replay_event(
+    options: &ReplayOptions,
+    node: &Node<ipc::Service>,
+    service_name: &ServiceName,
+    buffer: &[Record],
+    window_start: Duration,
+) -> Result<()> {
+    let service = node.service_builder(service_name).event().open()?;
+    let notifier = service.notifier_builder().create()?;
+
+    println!("Start replaying notifications on \"{service_name}\".");
+    for n in 0..u64::MAX {
+        let start = Instant::now();
+        for data in buffer {
+            // the replayer verified the size of every system header
+            let header = EventRecordHeader::from_bytes(&data.system_header)
+                .ok_or_else(|| anyhow!("The recording contains a corrupted event record."))?;
+
+            wait_for_timestamp(data.timestamp, &start, window_start, options.time_factor);
+            for _ in 0..header.count.max(1) {
+                if data.payload.is_empty() {
+                    notifier.notify_with_custom_event_id(header.event_id())?;
+                } else {
+                    notifier
+                        .notify_with_custom_event_id_and_data(header.event_id(), &data.payload)?;
+                }
+            }
+            print!(".");
+            std::io::stdout().flush()?;
+        }
+
+        if is_last_repetition(options, n) {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+fn replay_request_response(
+    options: &ReplayOptions,
+    node: &Node<ipc::Service>,
+    service_name: &ServiceName,
+    (request_types, response_types): (&ServiceTypes, &ServiceTypes),
+    buffer: &[Record],
+    window_start: Duration,
+) -> Result<()> {
+    if options.repetitions != 0 || options.endless_loop {
+        return Err(anyhow!(
+            "The replay of request-response services cannot be repeated since the responses are compared with the recording."
+        ));
+    }
 
     let service = unsafe {
-        node.service_builder(&service_name)
-            .publish_subscribe::<[CustomPayloadMarker]>()
-            .user_header::<CustomHeaderMarker>()
-            .__internal_set_payload_type_details(&required_header.types.payload)
-            .__internal_set_user_header_type_details(&required_header.types.user_header)
-            .open_or_create()?
+        node.service_builder(service_name)
+            .request_response::<[CustomPayloadMarker], [CustomPayloadMarker]>()
+            .request_user_header::<CustomHeaderMarker>()
+            .response_user_header::<CustomHeaderMarker>()
+            .__internal_set_request_payload_type_details(&request_types.payload)
+            .__internal_set_request_header_type_details(&request_types.user_header)
+            .__internal_set_response_payload_type_details(&response_types.payload)
+            .__internal_set_response_header_type_details(&response_types.user_header)
+            .open()?
     };
 
-    let publisher = match required_header.types.payload.variant() {
-        TypeVariant::FixedSize => service.publisher_builder().create()?,
+    let client = match request_types.payload.variant() {
+        TypeVariant::FixedSize => service.client_builder().create()?,
         TypeVariant::Dynamic => service
-            .publisher_builder()
+            .client_builder()
             .initial_max_slice_len(4096)
             .allocation_strategy(AllocationStrategy::PowerOfTwo)
             .create()?,
     };
 
-    println!("Start replaying data on \"{service_name}\".");
-    for n in 0..u64::MAX {
-        let start = Instant::now();
-        for data in &buffer {
-            let payload_len = match required_header.types.payload.variant() {
-                TypeVariant::FixedSize => 1,
-                TypeVariant::Dynamic => data.payload.len(),
-            };
+    let mut recorder = match &options.output {
+        Some(output) => Some(
+            RecorderBuilder::new(request_types)
+                .data_representation(options.data_representation.into())
+                .messaging_pattern(MessagingPattern::RequestResponse)
+                .response_types(response_types)
+                .create(&FilePath::new(output.as_bytes())?, service_name)?,
+        ),
+        None => None,
+    };
+
+    let client_id = client.id().value();
+    let response_timeout = Duration::from_millis(options.response_timeout_in_ms);
+    let mut produced_responses = vec![];
 
-            let sample = unsafe {
-                let mut sample = publisher.loan_custom_payload(payload_len)?;
+    println!("Start replaying requests on \"{service_name}\".");
+    let start = Instant::now();
+    for data in buffer {
+        let header = match RequestResponseRecordHeader::from_bytes(&data.system_header) {
+            Some(header) if header.kind == RequestResponseRecordKind::Request => header,
+            _ => continue,
+        };
+
+        wait_for_timestamp(data.timestamp, &start, window_start, options.time_factor);
+        let pending_response = unsafe {
+            let mut request = client.loan_custom_payload(number_of_elements(
+                &request_types.payload,
+                data.payload.len(),
+            ))?;
+            copy_nonoverlapping(
+                data.payload.as_ptr(),
+                request.payload_mut().as_mut_ptr() as *mut u8,
+                data.payload.len(),
+            );
+            if !data.user_header.is_empty() {
                 copy_nonoverlapping(
-                    data.payload.as_ptr(),
-                    sample.payload_mut().as_ptr() as *mut u8,
-                    data.payload.len(),
+                    data.user_header.as_ptr(),
+                    (request.user_header_mut() as *mut CustomHeaderMarker) as *mut u8,
+                    data.user_header.len(),
                 );
-                if !data.user_header.is_empty() {
-                    copy_nonoverlapping(
-                        data.user_header.as_ptr(),
-                        (sample.user_header_mut() as *mut CustomHeaderMarker) as *mut u8,
-                        data.user_header.len(),
-                    );
+            }
+            request.assume_init().send()?
+        };
+
+        let request_header = RequestResponseRecordHeader::request(client_id, header.request_number);
+        if let Some(recorder) = &mut recorder {
+            recorder.write(RawRecord {
+                timestamp: start.elapsed(),
+                system_header: &request_header.to_bytes(),
+                user_header: &data.user_header,
+                payload: &data.payload,
+            })?;
+        }
+        print!(".");
+        std::io::stdout().flush()?;
+
+        let deadline = Instant::now() + response_timeout;
+        let mut sequence_number = 0;
+        loop {
+            let is_stream_done = pending_response.is_finished() || !pending_response.is_connected();
+            while let Some(response) = unsafe { pending_response.receive_custom_payload()? } {
+                let user_header = unsafe {
+                    core::slice::from_raw_parts(
+                        (response.user_header() as *const CustomHeaderMarker).cast::<u8>(),
+                        response_types.user_header.size(),
+                    )
+                };
+                let payload = unsafe {
+                    core::slice::from_raw_parts(
+                        response.payload().as_ptr().cast::<u8>(),
+                        response.payload().len(),
+                    )
+                };
+
+                let record = Record {
+                    timestamp: start.elapsed(),
+                    system_header: RequestResponseRecordHeader::response(
+                        client_id,
+                        response.origin().value(),
+                        header.request_number,
+                        sequence_number,
+                    )
+                    .to_bytes()
+                    .to_vec(),
+                    user_header: user_header.to_vec(),
+                    payload: payload.to_vec(),
+                };
+                sequence_number += 1;
+
+                if let Some(recorder) = &mut recorder {
+                    recorder.write(RawRecord {
+                        timestamp: record.timestamp,
+                        system_header: &record.system_header,
+                        user_header: &record.user_header,
+                        payload: &record.payload,
+                    })?;
                 }
-                sample.assume_init()
-            };
+                produced_responses.push(record);
+            }
 
-            let elapsed = start.elapsed().as_millis() as f64 * options.time_factor as f64;
-            let timestamp = data.timestamp.as_millis() as f64 * options.time_factor as f64;
-            if elapsed < timestamp {
-                std::thread::sleep(Duration::from_millis((timestamp - elapsed) as u64));
+            // responses that arrived before the stream ended were received above
+            if is_stream_done || deadline <= Instant::now() {
+                break;
             }
 
-            sample.send()?;
-            print!(".");
-            std::io::stdout().flush()?;
+            if node.wait(Duration::from_millis(1)).is_err() {
+                break;
+            }
+        }
+    }
+    println!(" ");
+
+    let has_recorded_responses = buffer.iter().any(|data| {
+        RequestResponseRecordHeader::from_bytes(&data.system_header)
+            .is_some_and(|header| header.kind == RequestResponseRecordKind::Response)
+    });
+    if !has_recorded_responses {
+        println!(
+            "The recording contains no responses, the {} responses of the server are not compared.",
+            produced_responses.len()
+        );
+        return Ok(());
+    }
+
+    let mismatches = diff_responses(buffer, &produced_responses);
+    for mismatch in &mismatches {
+        println!(
+            "request {} response {}: {:?}",
+            mismatch.request_number, mismatch.sequence_number, mismatch.kind
+        );
+    }
+
+    if !mismatches.is_empty() {
+        return Err(anyhow!(
+            "{} responses of the server differ from the recording.",
+            mismatches.len()
+        ));
+    }
+
+    println!(
+        "All {} responses of the server match the recording.",
+        produced_responses.len()
+    );
+
+    Ok(())
+}
+
+fn replay_bag(options: ReplayOptions, node: Node<ipc::Service>) -> Result<()> {
+    let replay = BagReplayerOpener::new(&FilePath::new(options.input.as_bytes())?)
+        .data_representation(options.data_representation.into())
+        .open()?;
+
+    let header = replay.header().clone();
+    if header.file_format_version != FILE_FORMAT_BAG_VERSION {
+        return Err(anyhow!(
+            "The bag file format version {} is not supported, expected version {FILE_FORMAT_BAG_VERSION}.",
+            header.file_format_version
+        ));
+    }
+
+    let (window_start, window_end) = time_window(&options)?;
+    let mut buffer = replay.read_into_buffer()?;
+    buffer.retain(|data| {
+        window_start <= data.record.timestamp && data.record.timestamp <= window_end
+    });
+
+    let mut publishers = HashMap::new();
+    for channel in &header.channels {
+        if channel.messaging_pattern != MessagingPattern::PublishSubscribe {
+            return Err(anyhow!(
+                "The channel of \"{}\" uses the unsupported messaging pattern {:?}.",
+                channel.service_name,
+                channel.messaging_pattern
+            ));
+        }
+
+        publishers.insert(
+            channel.id,
+            (
+                create_publisher(&channel.service_name, &channel.types, &node)?,
+                &channel.types,
+            ),
+        );
+        println!("Start replaying data on \"{}\".", channel.service_name);
+    }
+
+    for n in 0..u64::MAX {
+        let start = Instant::now();
+        for data in &buffer {
+            // the replayer guarantees that every record belongs to a channel of the header
+            let (publisher, types) = &publishers[&data.channel_id];
+            send_record(
+                publisher,
+                types,
+                &data.record,
+                &start,
+                window_start,
+                options.time_factor,
+            )?;
         }
 
-        if options.repetitions <= n {
+        if is_last_repetition(&options, n) {
             break;
         }
     }
@@ -0,0 +1,140 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::ptr::copy_nonoverlapping;
+use core::time::Duration;
+use std::time::Instant;
+
+use crate::cli::ServeOptions;
+use crate::command::{
+    message_to_bytes, number_of_elements, open_or_create_request_response_service, print_message,
+    user_header_to_bytes, verify_payload_size,
+};
+use anyhow::Result;
+use iceoryx2::prelude::*;
+use iceoryx2::service::builder::CustomHeaderMarker;
+use iceoryx2::service::header::request_response::RequestHeader;
+use iceoryx2::service::static_config::message_type_details::TypeVariant;
+use iceoryx2_cli::Format;
+
+pub(crate) fn serve(options: ServeOptions, format: Format) -> Result<()> {
+    let node = NodeBuilder::new()
+        .name(&NodeName::new(&options.node_name)?)
+        .create::<ipc::Service>()?;
+
+    let service_name = ServiceName::new(&options.service)?;
+    let (service, request_types, response_types) =
+        open_or_create_request_response_service(&service_name, &node, &options.types)?;
+
+    let mut responses = vec![];
+    for message in &options.message {
+        let payload = message_to_bytes(message, options.data_representation)?;
+        verify_payload_size(&response_types.payload, &payload)?;
+        responses.push(payload);
+    }
+    let user_header = user_header_to_bytes(&options.user_header, &response_types.user_header)?;
+
+    let server = match response_types.payload.variant() {
+        TypeVariant::FixedSize => service.server_builder().create()?,
+        TypeVariant::Dynamic => service
+            .server_builder()
+            .initial_max_slice_len(4096)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create()?,
+    };
+    let cycle_time = Duration::from_millis(10);
+
+    let start = Instant::now();
+    let mut request_counter = 0u64;
+    'node_loop: while node.wait(cycle_time).is_ok() {
+        while let Some(active_request) = unsafe { server.receive_custom_payload()? } {
+            let request_header = unsafe {
+                core::slice::from_raw_parts(
+                    (active_request.header() as *const RequestHeader).cast::<u8>(),
+                    core::mem::size_of::<RequestHeader>(),
+                )
+            };
+            let request_user_header = unsafe {
+                core::slice::from_raw_parts(
+                    (active_request.user_header() as *const CustomHeaderMarker).cast::<u8>(),
+                    request_types.user_header.size(),
+                )
+            };
+            let request_payload = unsafe {
+                core::slice::from_raw_parts(
+                    active_request.payload().as_ptr().cast::<u8>(),
+                    active_request.payload().len(),
+                )
+            };
+
+            print_message(
+                request_header,
+                request_user_header,
+                request_payload,
+                options.data_representation,
+                format,
+            )?;
+
+            let echo;
+            let (user_header, responses) = if options.echo {
+                verify_payload_size(&response_types.payload, request_payload)?;
+                echo = [request_payload.to_vec()];
+                // the user header can only be echoed when requests and responses share its layout
+                if request_user_header.len() == user_header.len() {
+                    (request_user_header, &echo[..])
+                } else {
+                    (&user_header[..], &echo[..])
+                }
+            } else {
+                (&user_header[..], &responses[..])
+            };
+
+            for payload in responses {
+                unsafe {
+                    let mut response = active_request.loan_custom_payload(number_of_elements(
+                        &response_types.payload,
+                        payload.len(),
+                    ))?;
+                    copy_nonoverlapping(
+                        payload.as_ptr(),
+                        response.payload_mut().as_mut_ptr() as *mut u8,
+                        payload.len(),
+                    );
+                    copy_nonoverlapping(
+                        user_header.as_ptr(),
+                        (response.user_header_mut() as *mut CustomHeaderMarker) as *mut u8,
+                        user_header.len(),
+                    );
+                    response.assume_init().send()?;
+                }
+            }
+
+            // dropping the active request ends the response stream on the client side
+            drop(active_request);
+
+            request_counter += 1;
+            if let Some(max_requests) = options.max_requests {
+                if request_counter >= max_requests {
+                    break 'node_loop;
+                }
+            }
+        }
+
+        if let Some(timeout) = options.timeout {
+            if start.elapsed().as_millis() >= timeout as _ {
+                break 'node_loop;
+            }
+        }
+    }
+
+    Ok(())
+}
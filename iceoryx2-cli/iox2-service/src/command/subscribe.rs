@@ -80,6 +80,24 @@ fn print_iox2_dump(
     Ok(())
 }
 
+/// Prints a received message in the provided [`DataRepresentation`].
+pub(crate) fn print_message(
+    system_header: &[u8],
+    user_header: &[u8],
+    payload: &[u8],
+    data_representation: DataRepresentation,
+    format: Format,
+) -> Result<()> {
+    match data_representation {
+        DataRepresentation::Iox2Dump => {
+            print_iox2_dump(system_header, user_header, payload, format)
+        }
+        DataRepresentation::HumanReadable => {
+            print_hex_dump(system_header, user_header, payload, format)
+        }
+    }
+}
+
 pub(crate) fn subscribe(options: SubscribeOptions, format: Format) -> Result<()> {
     let node = NodeBuilder::new()
         .name(&NodeName::new(&options.node_name)?)
@@ -107,14 +125,13 @@ pub(crate) fn subscribe(options: SubscribeOptions, format: Format) -> Result<()>
             let (system_header, user_header, payload) =
                 extract_pubsub_payload(&sample, &service_types.user_header);
 
-            match options.data_representation {
-                DataRepresentation::Iox2Dump => {
-                    print_iox2_dump(system_header, user_header, payload, format)?;
-                }
-                DataRepresentation::HumanReadable => {
-                    print_hex_dump(system_header, user_header, payload, format)?;
-                }
-            }
+            print_message(
+                system_header,
+                user_header,
+                payload,
+                options.data_representation,
+                format,
+            )?;
 
             msg_counter += 1;
             if let Some(max_messages) = options.max_messages {
@@ -11,12 +11,56 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use crate::cli::OutputFilter;
+use anyhow::Result;
+use iceoryx2::prelude::ServiceName;
 use iceoryx2::service::ServiceDetails;
 use iceoryx2::service::ipc::Service;
 use iceoryx2_cli::filter::Filter;
+use regex::Regex;
 
 impl Filter<ServiceDetails<Service>> for OutputFilter {
     fn matches(&self, service: &ServiceDetails<Service>) -> bool {
         self.pattern.matches(service)
     }
 }
+
+/// Selects services by their name, either with glob patterns (`*` and `?`) or with
+/// regular expressions. A service is selected when its whole name matches any pattern.
+pub(crate) struct ServiceSelection {
+    patterns: Vec<Regex>,
+}
+
+impl ServiceSelection {
+    pub(crate) fn from_globs(globs: &[String]) -> Result<Self> {
+        let mut patterns = Vec::with_capacity(globs.len());
+        for glob in globs {
+            let mut pattern = String::from("^");
+            for c in glob.chars() {
+                match c {
+                    '*' => pattern.push_str(".*"),
+                    '?' => pattern.push('.'),
+                    c => pattern.push_str(&regex::escape(&c.to_string())),
+                }
+            }
+            pattern.push('$');
+            patterns.push(Regex::new(&pattern)?);
+        }
+
+        Ok(Self { patterns })
+    }
+
+    pub(crate) fn from_regexes(regexes: &[String]) -> Result<Self> {
+        let mut patterns = Vec::with_capacity(regexes.len());
+        for regex in regexes {
+            patterns.push(Regex::new(&format!("^(?:{regex})$"))?);
+        }
+
+        Ok(Self { patterns })
+    }
+
+    pub(crate) fn matches(&self, service_name: &ServiceName) -> bool {
+        self.patterns
+            .iter()
+            .any(|pattern| pattern.is_match(service_name.as_str()))
+    }
+}
@@ -61,6 +61,21 @@ fn main() -> Result<()> {
                     error!("failed to subscribe and receive messages: {}", e);
                 }
             }
+            Action::Call(options) => {
+                if let Err(e) = command::call(options, cli.format) {
+                    error!("failed to call service: {}", e);
+                }
+            }
+            Action::Serve(options) => {
+                if let Err(e) = command::serve(options, cli.format) {
+                    error!("failed to serve requests: {}", e);
+                }
+            }
+            Action::Blackboard(options) => {
+                if let Err(e) = command::blackboard(options, cli.format) {
+                    error!("failed to access blackboard: {}", e);
+                }
+            }
             Action::Record(options) => {
                 if let Err(e) = command::record(options, cli.format) {
                     error!("failed to record data: {}", e);
@@ -76,6 +91,16 @@ fn main() -> Result<()> {
                     error!("failed to measure service frequency: {}", e);
                 }
             }
+            Action::Bw(options) => {
+                if let Err(e) = command::bw(options, cli.format) {
+                    error!("failed to measure service throughput: {}", e);
+                }
+            }
+            Action::Latency(options) => {
+                if let Err(e) = command::latency(options, cli.format) {
+                    error!("failed to measure service latency: {}", e);
+                }
+            }
             Action::Discovery(options) => {
                 let should_publish = !options.disable_publish;
                 let should_notify = !options.disable_notify;
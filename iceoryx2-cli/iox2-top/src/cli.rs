@@ -0,0 +1,122 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use clap::Args;
+use clap::Parser;
+use clap::ValueEnum;
+
+use iceoryx2_cli::Format;
+use iceoryx2_cli::filter::MessagingPatternFilter;
+use iceoryx2_cli::filter::StateFilter;
+use iceoryx2_cli::help_template;
+
+#[derive(Parser)]
+#[command(
+    name = "iox2 top",
+    bin_name = "iox2 top",
+    about = "Live monitor of iceoryx2 nodes, services and ports",
+    long_about = None,
+    version = env!("CARGO_PKG_VERSION"),
+    disable_help_subcommand = true,
+    arg_required_else_help = false,
+    help_template = help_template().build(),
+)]
+pub struct Cli {
+    #[clap(
+        short,
+        long,
+        default_value = "1000",
+        help = "Refresh interval in milliseconds"
+    )]
+    pub refresh_interval: u64,
+
+    #[clap(
+        long,
+        value_enum,
+        default_value_t = NodeSortKey::Name,
+        help = "Initial sort order of the node table, cycle with 'n'"
+    )]
+    pub sort_nodes: NodeSortKey,
+
+    #[clap(
+        long,
+        value_enum,
+        default_value_t = ServiceSortKey::Name,
+        help = "Initial sort order of the service table, cycle with 's'"
+    )]
+    pub sort_services: ServiceSortKey,
+
+    #[command(flatten)]
+    pub filter: OutputFilter,
+
+    #[clap(
+        long,
+        help = "Print a single snapshot, measured over one refresh interval, and exit"
+    )]
+    pub once: bool,
+
+    #[clap(
+        long,
+        short = 'f',
+        value_enum,
+        default_value_t = Format::Ron,
+        help = "Output format of the snapshot when '--once' is used"
+    )]
+    pub format: Format,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct OutputFilter {
+    #[clap(short, long, value_enum, default_value_t = StateFilter::All)]
+    pub state: StateFilter,
+
+    #[clap(short, long, value_enum, default_value_t = MessagingPatternFilter::All)]
+    pub pattern: MessagingPatternFilter,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+#[clap(rename_all = "PascalCase")]
+pub enum NodeSortKey {
+    Name,
+    Pid,
+    State,
+}
+
+impl NodeSortKey {
+    pub fn next(self) -> Self {
+        match self {
+            NodeSortKey::Name => NodeSortKey::Pid,
+            NodeSortKey::Pid => NodeSortKey::State,
+            NodeSortKey::State => NodeSortKey::Name,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+#[clap(rename_all = "PascalCase")]
+pub enum ServiceSortKey {
+    Name,
+    Nodes,
+    Ports,
+    Rate,
+}
+
+impl ServiceSortKey {
+    pub fn next(self) -> Self {
+        match self {
+            ServiceSortKey::Name => ServiceSortKey::Nodes,
+            ServiceSortKey::Nodes => ServiceSortKey::Ports,
+            ServiceSortKey::Ports => ServiceSortKey::Rate,
+            ServiceSortKey::Rate => ServiceSortKey::Name,
+        }
+    }
+}
@@ -0,0 +1,67 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod cli;
+mod filter;
+mod snapshot;
+mod ui;
+
+use std::time::Duration;
+
+use anyhow::Result;
+use clap::Parser;
+use cli::Cli;
+use iceoryx2_cli::install_panic_handlers;
+use iceoryx2_log::{LogLevel, set_log_level_from_env_or};
+use snapshot::Monitor;
+
+fn main() -> Result<()> {
+    install_panic_handlers!();
+
+    // warnings would corrupt the terminal user interface
+    set_log_level_from_env_or(LogLevel::Fatal);
+
+    let cli = Cli::parse();
+    let refresh_interval = Duration::from_millis(cli.refresh_interval);
+    let result = Monitor::new(cli.filter.clone()).and_then(|monitor| {
+        if cli.once {
+            print_snapshot(monitor, refresh_interval, &cli)
+        } else {
+            ui::run(
+                monitor,
+                ui::Settings {
+                    refresh_interval,
+                    node_sort: cli.sort_nodes,
+                    service_sort: cli.sort_services,
+                },
+            )
+        }
+    });
+
+    if let Err(e) = result {
+        eprintln!("Failed to monitor the system: {e}");
+    }
+
+    Ok(())
+}
+
+fn print_snapshot(mut monitor: Monitor, refresh_interval: Duration, cli: &Cli) -> Result<()> {
+    // the first collection provides the reference counters for the message rates
+    monitor.collect()?;
+    std::thread::sleep(refresh_interval);
+    let mut snapshot = monitor.collect()?;
+    snapshot.sort(cli.sort_nodes, cli.sort_services);
+
+    println!("{}", cli.format.as_string(&snapshot)?);
+
+    Ok(())
+}
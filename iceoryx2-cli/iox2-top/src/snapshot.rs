@@ -0,0 +1,329 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::collections::HashMap;
+use std::time::Instant;
+
+use anyhow::{Context, Result};
+use iceoryx2::node::{NodeState, NodeView};
+use iceoryx2::prelude::*;
+use iceoryx2::service::ServiceDetails;
+use iceoryx2::service::service_hash::ServiceHash;
+use iceoryx2_cli::filter::Filter;
+use iceoryx2_cli::opened_service::OpenedService;
+use iceoryx2_cli::output::NodeIdString;
+use iceoryx2_pal_posix::posix::pid_t;
+
+use crate::cli::{NodeSortKey, OutputFilter, ServiceSortKey};
+
+#[derive(Clone, serde::Serialize)]
+pub(crate) struct NodeEntry {
+    pub state: String,
+    pub id: NodeIdString,
+    pub pid: pid_t,
+    pub name: String,
+    pub executable: String,
+}
+
+#[derive(Clone, serde::Serialize)]
+pub(crate) struct PublisherEntry {
+    pub id: String,
+    pub pid: pid_t,
+    pub rate_hz: f64,
+    pub sent_samples: u64,
+    pub samples_in_use: u64,
+    pub number_of_samples: usize,
+}
+
+impl PublisherEntry {
+    pub(crate) fn segment_usage(&self) -> f64 {
+        percentage(self.samples_in_use, self.number_of_samples)
+    }
+}
+
+#[derive(Clone, serde::Serialize)]
+pub(crate) struct SubscriberEntry {
+    pub id: String,
+    pub pid: pid_t,
+    pub rate_hz: f64,
+    pub received_samples: u64,
+    pub buffered_samples: u64,
+    pub buffer_size: usize,
+}
+
+impl SubscriberEntry {
+    pub(crate) fn buffer_fill_level(&self) -> f64 {
+        percentage(self.buffered_samples, self.buffer_size)
+    }
+}
+
+#[derive(Clone, serde::Serialize)]
+pub(crate) struct PortCount {
+    pub producers: usize,
+    pub consumers: usize,
+}
+
+#[derive(Clone, serde::Serialize)]
+pub(crate) struct ServiceEntry {
+    pub name: String,
+    pub pattern: String,
+    pub nodes: usize,
+    pub ports: Option<PortCount>,
+    pub publishers: Vec<PublisherEntry>,
+    pub subscribers: Vec<SubscriberEntry>,
+}
+
+impl ServiceEntry {
+    pub(crate) fn number_of_ports(&self) -> usize {
+        self.ports
+            .as_ref()
+            .map(|p| p.producers + p.consumers)
+            .unwrap_or(0)
+    }
+
+    pub(crate) fn rate_hz(&self) -> f64 {
+        self.publishers.iter().map(|p| p.rate_hz).sum()
+    }
+}
+
+#[derive(Clone, serde::Serialize)]
+pub(crate) struct Snapshot {
+    pub nodes: Vec<NodeEntry>,
+    pub services: Vec<ServiceEntry>,
+}
+
+impl Snapshot {
+    pub(crate) fn sort(&mut self, node_key: NodeSortKey, service_key: ServiceSortKey) {
+        match node_key {
+            NodeSortKey::Name => self.nodes.sort_by(|a, b| a.name.cmp(&b.name)),
+            NodeSortKey::Pid => self.nodes.sort_by_key(|n| n.pid),
+            NodeSortKey::State => self
+                .nodes
+                .sort_by(|a, b| a.state.cmp(&b.state).then(a.name.cmp(&b.name))),
+        }
+
+        match service_key {
+            ServiceSortKey::Name => self.services.sort_by(|a, b| a.name.cmp(&b.name)),
+            ServiceSortKey::Nodes => self
+                .services
+                .sort_by(|a, b| b.nodes.cmp(&a.nodes).then(a.name.cmp(&b.name))),
+            ServiceSortKey::Ports => self.services.sort_by(|a, b| {
+                b.number_of_ports()
+                    .cmp(&a.number_of_ports())
+                    .then(a.name.cmp(&b.name))
+            }),
+            ServiceSortKey::Rate => self.services.sort_by(|a, b| {
+                b.rate_hz()
+                    .total_cmp(&a.rate_hz())
+                    .then(a.name.cmp(&b.name))
+            }),
+        }
+    }
+}
+
+/// Collects [`Snapshot`]s of the system and derives the message rates from the counters
+/// of the previous [`Snapshot`].
+pub(crate) struct Monitor {
+    node: Node<ipc::Service>,
+    filter: OutputFilter,
+    services: HashMap<ServiceHash, OpenedService>,
+    previous_counters: HashMap<u128, u64>,
+    previous_collection: Option<Instant>,
+}
+
+impl Monitor {
+    pub(crate) fn new(filter: OutputFilter) -> Result<Self> {
+        let node = NodeBuilder::new()
+            .name(&NodeName::new("iox2-top")?)
+            .create::<ipc::Service>()?;
+
+        Ok(Self {
+            node,
+            filter,
+            services: HashMap::new(),
+            previous_counters: HashMap::new(),
+            previous_collection: None,
+        })
+    }
+
+    pub(crate) fn collect(&mut self) -> Result<Snapshot> {
+        let now = Instant::now();
+        let elapsed = self
+            .previous_collection
+            .map(|previous| now.duration_since(previous).as_secs_f64());
+        self.previous_collection = Some(now);
+
+        let mut nodes = vec![];
+        Node::<ipc::Service>::list(self.node.config(), |node| {
+            if self.filter.matches(&node) {
+                nodes.push(node_entry(&node));
+            }
+            CallbackProgression::Continue
+        })
+        .context("failed to retrieve nodes")?;
+
+        let mut details = vec![];
+        ipc::Service::list(self.node.config(), |service| {
+            if self.filter.matches(&service) {
+                details.push(service);
+            }
+            CallbackProgression::Continue
+        })
+        .context("failed to retrieve services")?;
+
+        self.services.retain(|hash, _| {
+            details
+                .iter()
+                .any(|d| d.static_details.service_hash() == hash)
+        });
+
+        let mut counters = HashMap::new();
+        let mut services = Vec::with_capacity(details.len());
+        for service in &details {
+            services.push(self.service_entry(service, elapsed, &mut counters));
+        }
+        self.previous_counters = counters;
+
+        Ok(Snapshot { nodes, services })
+    }
+
+    fn service_entry(
+        &mut self,
+        service: &ServiceDetails<ipc::Service>,
+        elapsed: Option<f64>,
+        counters: &mut HashMap<u128, u64>,
+    ) -> ServiceEntry {
+        let own_id = *self.node.id();
+        let nodes = service
+            .dynamic_details
+            .as_ref()
+            .map(|d| {
+                d.nodes
+                    .iter()
+                    .filter(|n| node_id(n) != own_id.value())
+                    .count()
+            })
+            .unwrap_or(0);
+
+        let mut entry = ServiceEntry {
+            name: service.static_details.name().to_string(),
+            pattern: service.static_details.messaging_pattern().to_string(),
+            nodes,
+            ports: None,
+            publishers: vec![],
+            subscribers: vec![],
+        };
+
+        let hash = *service.static_details.service_hash();
+        if !self.services.contains_key(&hash) {
+            match OpenedService::open(&self.node, service) {
+                Some(opened) => {
+                    self.services.insert(hash, opened);
+                }
+                None => return entry,
+            }
+        }
+
+        let previous_counters = &self.previous_counters;
+        let mut rate = |id: u128, counter: u64| -> f64 {
+            counters.insert(id, counter);
+            match (elapsed, previous_counters.get(&id)) {
+                (Some(elapsed), Some(previous)) if elapsed > 0.0 => {
+                    counter.saturating_sub(*previous) as f64 / elapsed
+                }
+                _ => 0.0,
+            }
+        };
+
+        match &self.services[&hash] {
+            OpenedService::PublishSubscribe(service) => {
+                let dynamic_config = service.dynamic_config();
+                dynamic_config.list_publisher_statistics(|details, statistics| {
+                    let id = details.publisher_id.value();
+                    entry.publishers.push(PublisherEntry {
+                        id: format!("{id:032x}"),
+                        pid: details.node_id.pid().value(),
+                        rate_hz: rate(id, statistics.number_of_sent_samples()),
+                        sent_samples: statistics.number_of_sent_samples(),
+                        samples_in_use: statistics.number_of_samples_in_use(),
+                        number_of_samples: details.number_of_samples,
+                    });
+                    CallbackProgression::Continue
+                });
+                dynamic_config.list_subscriber_statistics(|details, statistics| {
+                    let id = details.subscriber_id.value();
+                    entry.subscribers.push(SubscriberEntry {
+                        id: format!("{id:032x}"),
+                        pid: details.node_id.pid().value(),
+                        rate_hz: rate(id, statistics.number_of_received_samples()),
+                        received_samples: statistics.number_of_received_samples(),
+                        buffered_samples: statistics
+                            .number_of_buffered_samples()
+                            .min(details.buffer_size as u64),
+                        buffer_size: details.buffer_size,
+                    });
+                    CallbackProgression::Continue
+                });
+                entry.ports = Some(PortCount {
+                    producers: entry.publishers.len(),
+                    consumers: entry.subscribers.len(),
+                });
+            }
+            opened => {
+                let (producers, consumers) = opened.number_of_ports();
+                entry.ports = Some(PortCount {
+                    producers,
+                    consumers,
+                });
+            }
+        }
+
+        entry
+    }
+}
+
+fn node_entry(node: &NodeState<ipc::Service>) -> NodeEntry {
+    let (state, id, details) = match node {
+        NodeState::Alive(view) => ("Alive", view.id(), view.details().as_ref()),
+        NodeState::Dead(view) => ("Dead", view.id(), view.details().as_ref()),
+        NodeState::Inaccessible(id) => ("Inaccessible", id, None),
+        NodeState::Undefined(id) => ("Undefined", id, None),
+    };
+
+    NodeEntry {
+        state: state.to_string(),
+        id: NodeIdString::from(id),
+        pid: id.pid().value(),
+        name: details
+            .map(|d| d.name().as_str().to_string())
+            .unwrap_or_default(),
+        executable: details
+            .map(|d| d.executable().to_string())
+            .unwrap_or_default(),
+    }
+}
+
+fn node_id(node: &NodeState<ipc::Service>) -> u128 {
+    match node {
+        NodeState::Alive(view) => view.id().value(),
+        NodeState::Dead(view) => view.id().value(),
+        NodeState::Inaccessible(id) | NodeState::Undefined(id) => id.value(),
+    }
+}
+
+fn percentage(value: u64, capacity: usize) -> f64 {
+    if capacity == 0 {
+        0.0
+    } else {
+        value as f64 * 100.0 / capacity as f64
+    }
+}
@@ -0,0 +1,205 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::io::{Stdout, Write, stdout};
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::style::{Print, Stylize};
+use crossterm::{cursor, execute, queue, terminal};
+
+use crate::cli::{NodeSortKey, ServiceSortKey};
+use crate::snapshot::{Monitor, Snapshot};
+
+/// Restores the terminal when dropped, also when the monitor is left with an error.
+struct Terminal {
+    stdout: Stdout,
+}
+
+impl Terminal {
+    fn new() -> Result<Self> {
+        let mut stdout = stdout();
+        terminal::enable_raw_mode()?;
+        execute!(stdout, terminal::EnterAlternateScreen, cursor::Hide)?;
+        Ok(Self { stdout })
+    }
+}
+
+impl Drop for Terminal {
+    fn drop(&mut self) {
+        let _ = execute!(self.stdout, cursor::Show, terminal::LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+pub(crate) struct Settings {
+    pub refresh_interval: Duration,
+    pub node_sort: NodeSortKey,
+    pub service_sort: ServiceSortKey,
+}
+
+pub(crate) fn run(mut monitor: Monitor, mut settings: Settings) -> Result<()> {
+    let mut terminal = Terminal::new()?;
+    let mut snapshot = monitor.collect()?;
+    let mut last_collection = Instant::now();
+
+    loop {
+        snapshot.sort(settings.node_sort, settings.service_sort);
+        draw(&mut terminal.stdout, &snapshot, &settings)?;
+
+        let timeout = settings
+            .refresh_interval
+            .saturating_sub(last_collection.elapsed());
+        if event::poll(timeout)? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+                match key.code {
+                    KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                    KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                        return Ok(());
+                    }
+                    KeyCode::Char('n') => settings.node_sort = settings.node_sort.next(),
+                    KeyCode::Char('s') => settings.service_sort = settings.service_sort.next(),
+                    _ => (),
+                }
+            }
+            continue;
+        }
+
+        snapshot = monitor.collect()?;
+        last_collection = Instant::now();
+    }
+}
+
+fn draw(stdout: &mut Stdout, snapshot: &Snapshot, settings: &Settings) -> Result<()> {
+    let (columns, rows) = terminal::size()?;
+    let lines = render(snapshot, settings, rows as usize);
+
+    queue!(stdout, terminal::Clear(terminal::ClearType::All))?;
+    for (row, (line, is_header)) in lines.iter().take(rows as usize).enumerate() {
+        let line: String = line.chars().take(columns as usize).collect();
+        queue!(stdout, cursor::MoveTo(0, row as u16))?;
+        if *is_header {
+            queue!(stdout, Print(line.reverse()))?;
+        } else {
+            queue!(stdout, Print(line))?;
+        }
+    }
+    stdout.flush()?;
+
+    Ok(())
+}
+
+/// Renders the [`Snapshot`] into lines. The flag marks table headers. The node table
+/// uses at most a third of the available rows so that the services stay visible.
+fn render(snapshot: &Snapshot, settings: &Settings, rows: usize) -> Vec<(String, bool)> {
+    let mut lines = vec![];
+    lines.push((
+        format!(
+            "iox2 top - nodes: {}, services: {} | sort nodes [n]: {:?}, sort services [s]: {:?} | quit [q]",
+            snapshot.nodes.len(),
+            snapshot.services.len(),
+            settings.node_sort,
+            settings.service_sort
+        ),
+        false,
+    ));
+    lines.push((String::new(), false));
+
+    lines.push((
+        format!(
+            "{:<12} {:>8} {:<32} {}",
+            "STATE", "PID", "NAME", "EXECUTABLE"
+        ),
+        true,
+    ));
+    let max_node_rows = (rows / 3).max(1);
+    for (n, node) in snapshot.nodes.iter().enumerate() {
+        if n + 1 == max_node_rows && snapshot.nodes.len() > max_node_rows {
+            lines.push((
+                format!("... {} more nodes", snapshot.nodes.len() - n),
+                false,
+            ));
+            break;
+        }
+        lines.push((
+            format!(
+                "{:<12} {:>8} {:<32} {}",
+                node.state, node.pid, node.name, node.executable
+            ),
+            false,
+        ));
+    }
+    lines.push((String::new(), false));
+
+    lines.push((
+        format!(
+            "{:<40} {:<16} {:>5} {:>9} {:>12}",
+            "SERVICE", "PATTERN", "NODES", "PORTS", "RATE"
+        ),
+        true,
+    ));
+    for service in &snapshot.services {
+        let ports = match &service.ports {
+            Some(ports) => format!("{}/{}", ports.producers, ports.consumers),
+            None => "-".to_string(),
+        };
+        let rate = if service.publishers.is_empty() {
+            "-".to_string()
+        } else {
+            format!("{:.1} Hz", service.rate_hz())
+        };
+        lines.push((
+            format!(
+                "{:<40} {:<16} {:>5} {:>9} {:>12}",
+                service.name, service.pattern, service.nodes, ports, rate
+            ),
+            false,
+        ));
+
+        for publisher in &service.publishers {
+            lines.push((
+                format!(
+                    "  pub pid {:<8} {:>10.1} Hz  sent {:<10} segment {}/{} ({:.1}%)  id {}",
+                    publisher.pid,
+                    publisher.rate_hz,
+                    publisher.sent_samples,
+                    publisher.samples_in_use,
+                    publisher.number_of_samples,
+                    publisher.segment_usage(),
+                    publisher.id
+                ),
+                false,
+            ));
+        }
+        for subscriber in &service.subscribers {
+            lines.push((
+                format!(
+                    "  sub pid {:<8} {:>10.1} Hz  recv {:<10} buffer  {}/{} ({:.1}%)  id {}",
+                    subscriber.pid,
+                    subscriber.rate_hz,
+                    subscriber.received_samples,
+                    subscriber.buffered_samples,
+                    subscriber.buffer_size,
+                    subscriber.buffer_fill_level(),
+                    subscriber.id
+                ),
+                false,
+            ));
+        }
+    }
+
+    lines
+}
@@ -116,6 +116,7 @@ pub enum MessagingPatternFilter {
     PublishSubscribe,
     Event,
     RequestResponse,
+    Blackboard,
     #[default]
     All,
 }
@@ -142,6 +143,12 @@ impl Filter<ServiceDetails<Service>> for MessagingPatternFilter {
                     MessagingPattern::RequestResponse(_)
                 )
             }
+            MessagingPatternFilter::Blackboard => {
+                matches!(
+                    service.static_details.messaging_pattern(),
+                    MessagingPattern::Blackboard(_)
+                )
+            }
         }
     }
 }
@@ -16,6 +16,7 @@ mod format;
 mod panic;
 
 pub mod filter;
+pub mod opened_service;
 pub mod output;
 
 pub use cli::*;
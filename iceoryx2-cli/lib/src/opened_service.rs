@@ -0,0 +1,228 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::constants::MAX_BLACKBOARD_KEY_SIZE;
+use iceoryx2::identifiers::UniqueNodeId;
+use iceoryx2::node::Node;
+use iceoryx2::prelude::{CallbackProgression, PortFactory};
+use iceoryx2::service::ServiceDetails;
+use iceoryx2::service::builder::blackboard::KeyMemory;
+use iceoryx2::service::builder::{CustomHeaderMarker, CustomKeyMarker, CustomPayloadMarker};
+use iceoryx2::service::ipc::Service;
+use iceoryx2::service::port_factory::{blackboard, event, publish_subscribe, request_response};
+use iceoryx2::service::static_config::messaging_pattern::MessagingPattern;
+
+pub type CustomPublishSubscribeService =
+    publish_subscribe::PortFactory<Service, [CustomPayloadMarker], CustomHeaderMarker>;
+pub type CustomRequestResponseService = request_response::PortFactory<
+    Service,
+    [CustomPayloadMarker],
+    CustomHeaderMarker,
+    [CustomPayloadMarker],
+    CustomHeaderMarker,
+>;
+pub type CustomBlackboardService = blackboard::PortFactory<Service, CustomKeyMarker>;
+
+/// The kind of a port that is connected to a service.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize)]
+pub enum PortKind {
+    Publisher,
+    Subscriber,
+    Notifier,
+    Listener,
+    Client,
+    Server,
+    Writer,
+    Reader,
+}
+
+impl PortKind {
+    /// Returns true for ports that send data into the service, false for ports that
+    /// receive data from it.
+    pub fn is_producer(&self) -> bool {
+        matches!(
+            self,
+            PortKind::Publisher | PortKind::Notifier | PortKind::Client | PortKind::Writer
+        )
+    }
+}
+
+/// A port that is connected to a service.
+#[derive(Debug, Clone, Copy)]
+pub struct Port {
+    pub kind: PortKind,
+    pub id: u128,
+    pub node_id: UniqueNodeId,
+}
+
+/// A service of any messaging pattern that was opened with the types stored in its static
+/// config, so that its dynamic config can be inspected without knowing the types at
+/// compile time.
+pub enum OpenedService {
+    PublishSubscribe(CustomPublishSubscribeService),
+    Event(event::PortFactory<Service>),
+    RequestResponse(CustomRequestResponseService),
+    Blackboard(CustomBlackboardService),
+}
+
+impl OpenedService {
+    /// Opens the service described by the [`ServiceDetails`]. Returns [`None`] when the
+    /// service could not be opened, for instance when it was removed in the meantime.
+    pub fn open(node: &Node<Service>, service: &ServiceDetails<Service>) -> Option<Self> {
+        let name = service.static_details.name();
+        match service.static_details.messaging_pattern() {
+            MessagingPattern::PublishSubscribe(config) => {
+                let types = config.message_type_details();
+                unsafe {
+                    node.service_builder(name)
+                        .publish_subscribe::<[CustomPayloadMarker]>()
+                        .user_header::<CustomHeaderMarker>()
+                        .__internal_set_payload_type_details(&types.payload)
+                        .__internal_set_user_header_type_details(&types.user_header)
+                        .open()
+                }
+                .ok()
+                .map(OpenedService::PublishSubscribe)
+            }
+            MessagingPattern::Event(_) => node
+                .service_builder(name)
+                .event()
+                .open()
+                .ok()
+                .map(OpenedService::Event),
+            MessagingPattern::RequestResponse(config) => {
+                let request = config.request_message_type_details();
+                let response = config.response_message_type_details();
+                unsafe {
+                    node.service_builder(name)
+                        .request_response::<[CustomPayloadMarker], [CustomPayloadMarker]>()
+                        .request_user_header::<CustomHeaderMarker>()
+                        .response_user_header::<CustomHeaderMarker>()
+                        .__internal_set_request_payload_type_details(&request.payload)
+                        .__internal_set_request_header_type_details(&request.user_header)
+                        .__internal_set_response_payload_type_details(&response.payload)
+                        .__internal_set_response_header_type_details(&response.user_header)
+                        .open()
+                }
+                .ok()
+                .map(OpenedService::RequestResponse)
+            }
+            MessagingPattern::Blackboard(config) => {
+                let key_type = *config.type_details();
+                let key_size = key_type.size();
+                let key_eq = move |lhs: *const u8, rhs: *const u8| unsafe {
+                    core::slice::from_raw_parts(lhs, key_size)
+                        == core::slice::from_raw_parts(rhs, key_size)
+                };
+                unsafe {
+                    node.service_builder(name)
+                        .blackboard_opener::<CustomKeyMarker>()
+                        .__internal_set_key_type_details(&key_type)
+                        .__internal_set_key_eq_cmp_func(Box::new(move |lhs, rhs| {
+                            KeyMemory::<MAX_BLACKBOARD_KEY_SIZE>::key_eq_comparison(
+                                lhs, rhs, &key_eq,
+                            )
+                        }))
+                        .open()
+                }
+                .ok()
+                .map(OpenedService::Blackboard)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the number of producing and consuming ports, see [`PortKind::is_producer()`].
+    pub fn number_of_ports(&self) -> (usize, usize) {
+        match self {
+            OpenedService::PublishSubscribe(service) => (
+                service.dynamic_config().number_of_publishers(),
+                service.dynamic_config().number_of_subscribers(),
+            ),
+            OpenedService::Event(service) => (
+                service.dynamic_config().number_of_notifiers(),
+                service.dynamic_config().number_of_listeners(),
+            ),
+            OpenedService::RequestResponse(service) => (
+                service.dynamic_config().number_of_clients(),
+                service.dynamic_config().number_of_servers(),
+            ),
+            OpenedService::Blackboard(service) => (
+                service.dynamic_config().number_of_writers(),
+                service.dynamic_config().number_of_readers(),
+            ),
+        }
+    }
+
+    /// Calls the callback for every port that is currently connected to the service.
+    pub fn list_ports<F: FnMut(Port)>(&self, mut callback: F) {
+        let mut port = |kind, id, node_id| {
+            callback(Port { kind, id, node_id });
+            CallbackProgression::Continue
+        };
+
+        match self {
+            OpenedService::PublishSubscribe(service) => {
+                let dynamic_config = service.dynamic_config();
+                dynamic_config.list_publishers(|details| {
+                    port(
+                        PortKind::Publisher,
+                        details.publisher_id.value(),
+                        details.node_id,
+                    )
+                });
+                dynamic_config.list_subscribers(|details| {
+                    port(
+                        PortKind::Subscriber,
+                        details.subscriber_id.value(),
+                        details.node_id,
+                    )
+                });
+            }
+            OpenedService::Event(service) => {
+                let dynamic_config = service.dynamic_config();
+                dynamic_config.list_notifiers(|details| {
+                    port(
+                        PortKind::Notifier,
+                        details.notifier_id.value(),
+                        details.node_id,
+                    )
+                });
+                dynamic_config.list_listeners(|details| {
+                    port(
+                        PortKind::Listener,
+                        details.listener_id.value(),
+                        details.node_id,
+                    )
+                });
+            }
+            OpenedService::RequestResponse(service) => {
+                let dynamic_config = service.dynamic_config();
+                dynamic_config.list_clients(|details| {
+                    port(PortKind::Client, details.client_id.value(), details.node_id)
+                });
+                dynamic_config.list_servers(|details| {
+                    port(PortKind::Server, details.server_id.value(), details.node_id)
+                });
+            }
+            OpenedService::Blackboard(service) => {
+                let dynamic_config = service.dynamic_config();
+                dynamic_config.list_writers(|details| {
+                    port(PortKind::Writer, details.writer_id.value(), details.node_id)
+                });
+                dynamic_config.list_readers(|details| {
+                    port(PortKind::Reader, details.reader_id.value(), details.node_id)
+                });
+            }
+        }
+    }
+}
@@ -22,6 +22,8 @@ enum class EntryHandleMutError : uint8_t {
     EntryDoesNotExist,
     /// The [`EntryHandleMut`] already exists.
     HandleAlreadyExists,
+    /// The entry is currently owned by another [`Writer`].
+    EntryOwnedByAnotherWriter,
 };
 } // namespace iox2
 
@@ -352,6 +352,8 @@ constexpr auto from<int, iox2::EventOpenOrCreateError>(const int value) noexcept
         return iox2::EventOpenOrCreateError::OpenDoesNotSupportRequestedAmountOfListeners;
     case iox2_event_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_MAX_EVENT_ID:
         return iox2::EventOpenOrCreateError::OpenDoesNotSupportRequestedMaxEventId;
+    case iox2_event_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_NOTIFICATION_DATA_SIZE:
+        return iox2::EventOpenOrCreateError::OpenDoesNotSupportRequestedNotificationDataSize;
     case iox2_event_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NODES:
         return iox2::EventOpenOrCreateError::OpenDoesNotSupportRequestedAmountOfNodes;
     case iox2_event_open_or_create_error_e_O_EXCEEDS_MAX_NUMBER_OF_NODES:
@@ -418,6 +420,8 @@ from<iox2::EventOpenOrCreateError, iox2_event_open_or_create_error_e>(const iox2
         return iox2_event_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_LISTENERS;
     case iox2::EventOpenOrCreateError::OpenDoesNotSupportRequestedMaxEventId:
         return iox2_event_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_MAX_EVENT_ID;
+    case iox2::EventOpenOrCreateError::OpenDoesNotSupportRequestedNotificationDataSize:
+        return iox2_event_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_NOTIFICATION_DATA_SIZE;
     case iox2::EventOpenOrCreateError::OpenDoesNotSupportRequestedAmountOfNodes:
         return iox2_event_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NODES;
     case iox2::EventOpenOrCreateError::OpenExceedsMaxNumberOfNodes:
@@ -495,6 +499,8 @@ constexpr auto from<int, iox2::EventOpenError>(const int value) noexcept -> iox2
         return iox2::EventOpenError::DoesNotSupportRequestedAmountOfListeners;
     case iox2_event_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_MAX_EVENT_ID:
         return iox2::EventOpenError::DoesNotSupportRequestedMaxEventId;
+    case iox2_event_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_NOTIFICATION_DATA_SIZE:
+        return iox2::EventOpenError::DoesNotSupportRequestedNotificationDataSize;
     case iox2_event_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NODES:
         return iox2::EventOpenError::DoesNotSupportRequestedAmountOfNodes;
     case iox2_event_open_or_create_error_e_O_EXCEEDS_MAX_NUMBER_OF_NODES:
@@ -565,6 +571,8 @@ constexpr auto from<iox2::EventOpenError, iox2_event_open_or_create_error_e>(con
         return iox2_event_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_LISTENERS;
     case iox2::EventOpenError::DoesNotSupportRequestedMaxEventId:
         return iox2_event_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_MAX_EVENT_ID;
+    case iox2::EventOpenError::DoesNotSupportRequestedNotificationDataSize:
+        return iox2_event_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_NOTIFICATION_DATA_SIZE;
     case iox2::EventOpenError::DoesNotSupportRequestedAmountOfNodes:
         return iox2_event_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NODES;
     case iox2::EventOpenError::ExceedsMaxNumberOfNodes:
@@ -638,6 +646,8 @@ constexpr auto from<int, iox2::EventCreateError>(const int value) noexcept -> io
         IOX2_UNREACHABLE();
     case iox2_event_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_MAX_EVENT_ID:
         IOX2_UNREACHABLE();
+    case iox2_event_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_NOTIFICATION_DATA_SIZE:
+        IOX2_UNREACHABLE();
     case iox2_event_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NODES:
         IOX2_UNREACHABLE();
     case iox2_event_open_or_create_error_e_O_EXCEEDS_MAX_NUMBER_OF_NODES:
@@ -1589,6 +1599,8 @@ constexpr auto from<int, iox2::BlackboardOpenError>(const int value) noexcept ->
         return iox2::BlackboardOpenError::IncompatibleMessagingPattern;
     case iox2_blackboard_open_error_e_O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_READERS:
         return iox2::BlackboardOpenError::DoesNotSupportRequestedAmountOfReaders;
+    case iox2_blackboard_open_error_e_O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_WRITERS:
+        return iox2::BlackboardOpenError::DoesNotSupportRequestedAmountOfWriters;
     case iox2_blackboard_open_error_e_O_INSUFFICIENT_PERMISSIONS:
         return iox2::BlackboardOpenError::InsufficientPermissions;
     case iox2_blackboard_open_error_e_O_HANGS_IN_CREATION:
@@ -1629,6 +1641,8 @@ from<iox2::BlackboardOpenError, iox2_blackboard_open_error_e>(const iox2::Blackb
         return iox2_blackboard_open_error_e_O_INCOMPATIBLE_MESSAGING_PATTERN;
     case iox2::BlackboardOpenError::DoesNotSupportRequestedAmountOfReaders:
         return iox2_blackboard_open_error_e_O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_READERS;
+    case iox2::BlackboardOpenError::DoesNotSupportRequestedAmountOfWriters:
+        return iox2_blackboard_open_error_e_O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_WRITERS;
     case iox2::BlackboardOpenError::InsufficientPermissions:
         return iox2_blackboard_open_error_e_O_INSUFFICIENT_PERMISSIONS;
     case iox2::BlackboardOpenError::HangsInCreation:
@@ -1701,6 +1715,8 @@ constexpr auto from<int, iox2::EntryHandleMutError>(const int value) noexcept ->
         return iox2::EntryHandleMutError::EntryDoesNotExist;
     case iox2_entry_handle_mut_error_e_HANDLE_ALREADY_EXISTS:
         return iox2::EntryHandleMutError::HandleAlreadyExists;
+    case iox2_entry_handle_mut_error_e_ENTRY_OWNED_BY_ANOTHER_WRITER:
+        return iox2::EntryHandleMutError::EntryOwnedByAnotherWriter;
     }
 
     IOX2_UNREACHABLE();
@@ -1715,6 +1731,8 @@ from<iox2::EntryHandleMutError, iox2_entry_handle_mut_error_e>(const iox2::Entry
         return iox2_entry_handle_mut_error_e_ENTRY_DOES_NOT_EXIST;
     case iox2::EntryHandleMutError::HandleAlreadyExists:
         return iox2_entry_handle_mut_error_e_HANDLE_ALREADY_EXISTS;
+    case iox2::EntryHandleMutError::EntryOwnedByAnotherWriter:
+        return iox2_entry_handle_mut_error_e_ENTRY_OWNED_BY_ANOTHER_WRITER;
     }
 
     IOX2_UNREACHABLE();
@@ -1919,6 +1937,8 @@ constexpr auto from<int, iox2::ListenerCreateError>(const int value) noexcept ->
         return iox2::ListenerCreateError::FailedToDeployThreadsafetyPolicy;
     case iox2_listener_create_error_e_UNABLE_TO_CREATE_PORT_TAG:
         return iox2::ListenerCreateError::UnableToCreatePortTag;
+    case iox2_listener_create_error_e_EXCEEDS_MAX_NUMBER_OF_ALLOWED_NOTIFIERS:
+        return iox2::ListenerCreateError::ExceedsMaxNumberOfAllowedNotifiers;
     }
 
     IOX2_UNREACHABLE();
@@ -1937,6 +1957,8 @@ from<iox2::ListenerCreateError, iox2_listener_create_error_e>(const iox2::Listen
         return iox2_listener_create_error_e_FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY;
     case iox2::ListenerCreateError::UnableToCreatePortTag:
         return iox2_listener_create_error_e_UNABLE_TO_CREATE_PORT_TAG;
+    case iox2::ListenerCreateError::ExceedsMaxNumberOfAllowedNotifiers:
+        return iox2_listener_create_error_e_EXCEEDS_MAX_NUMBER_OF_ALLOWED_NOTIFIERS;
     }
 
     IOX2_UNREACHABLE();
@@ -1958,6 +1980,10 @@ constexpr auto from<int, iox2::NotifierNotifyError>(const int value) noexcept ->
         return iox2::NotifierNotifyError::MissedDeadline;
     case iox2_notifier_notify_error_e_UNABLE_TO_ACQUIRE_ELAPSED_TIME:
         return iox2::NotifierNotifyError::UnableToAcquireElapsedTime;
+    case iox2_notifier_notify_error_e_NOTIFICATION_DATA_TOO_LARGE:
+        return iox2::NotifierNotifyError::NotificationDataTooLarge;
+    case iox2_notifier_notify_error_e_UNABLE_TO_STORE_NOTIFICATION_DATA:
+        return iox2::NotifierNotifyError::UnableToStoreNotificationData;
     }
 
     IOX2_UNREACHABLE();
@@ -1974,6 +2000,10 @@ from<iox2::NotifierNotifyError, iox2_notifier_notify_error_e>(const iox2::Notifi
         return iox2_notifier_notify_error_e_MISSED_DEADLINE;
     case iox2::NotifierNotifyError::UnableToAcquireElapsedTime:
         return iox2_notifier_notify_error_e_UNABLE_TO_ACQUIRE_ELAPSED_TIME;
+    case iox2::NotifierNotifyError::NotificationDataTooLarge:
+        return iox2_notifier_notify_error_e_NOTIFICATION_DATA_TOO_LARGE;
+    case iox2::NotifierNotifyError::UnableToStoreNotificationData:
+        return iox2_notifier_notify_error_e_UNABLE_TO_STORE_NOTIFICATION_DATA;
     }
 
     IOX2_UNREACHABLE();
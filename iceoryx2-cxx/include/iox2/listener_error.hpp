@@ -31,6 +31,9 @@ enum class ListenerCreateError : uint8_t {
     FailedToDeployThreadsafetyPolicy,
     /// The tracking port tag, required for cleanup, could not be created.
     UnableToCreatePortTag,
+    /// More than `MAX_NUMBER_OF_ALLOWED_NOTIFIERS` [`Notifier`]s were added to the
+    /// set of allowed [`Notifier`]s of the [`Listener`].
+    ExceedsMaxNumberOfAllowedNotifiers,
 };
 
 /// Defines failures that can occur while waiting for a notification from a
@@ -43,6 +43,12 @@ enum class NotifierNotifyError : uint8_t {
     /// The notification was delivered but the elapsed system time could not be acquired.
     /// Therefore, it is unknown if the deadline was missed or not.
     UnableToAcquireElapsedTime,
+    /// The data provided to [`Notifier::notify_with_data()`] exceeds the
+    /// notification data size of the [`Service`].
+    NotificationDataTooLarge,
+    /// The data provided to [`Notifier::notify_with_data()`] could not be stored
+    /// since another [`Notifier`] blocked the storage for too long.
+    UnableToStoreNotificationData,
 };
 
 } // namespace iox2
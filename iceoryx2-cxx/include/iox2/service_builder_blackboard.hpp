@@ -45,6 +45,15 @@ class ServiceBuilderBlackboardCreator {
     IOX2_BUILDER_OPTIONAL(uint64_t, max_readers);
 #endif
 
+    /// Defines how many [`Writer`]s shall be supported at most. When more than one [`Writer`]
+    /// is supported, every entry is exclusively owned by the [`Writer`] that acquired it first
+    /// with [`Writer::entry()`] until the corresponding [`EntryHandleMut`] is dropped.
+#ifdef DOXYGEN_MACRO_FIX
+    auto max_writers(const uint64_t value) -> decltype(auto);
+#else
+    IOX2_BUILDER_OPTIONAL(uint64_t, max_writers);
+#endif
+
     /// Defines how many [`Node`]s shall be able to open it in parallel.
 #ifdef DOXYGEN_MACRO_FIX
     auto max_nodes(const uint64_t value) -> decltype(auto);
@@ -90,6 +99,13 @@ class ServiceBuilderBlackboardOpener {
     IOX2_BUILDER_OPTIONAL(uint64_t, max_readers);
 #endif
 
+    /// Defines how many [`Writer`]s must be at least supported.
+#ifdef DOXYGEN_MACRO_FIX
+    auto max_writers(const uint64_t value) -> decltype(auto);
+#else
+    IOX2_BUILDER_OPTIONAL(uint64_t, max_writers);
+#endif
+
     /// Defines how many [`Node`]s must be at least supported.
 #ifdef DOXYGEN_MACRO_FIX
     auto max_nodes(const uint64_t value) -> decltype(auto);
@@ -143,6 +159,9 @@ inline void ServiceBuilderBlackboardCreator<KeyType, S>::set_parameters() {
     if (m_max_readers.has_value()) {
         iox2_service_builder_blackboard_creator_set_max_readers(&m_handle, m_max_readers.value());
     }
+    if (m_max_writers.has_value()) {
+        iox2_service_builder_blackboard_creator_set_max_writers(&m_handle, m_max_writers.value());
+    }
     if (m_max_nodes.has_value()) {
         iox2_service_builder_blackboard_creator_set_max_nodes(&m_handle, m_max_nodes.value());
     }
@@ -231,6 +250,9 @@ inline void ServiceBuilderBlackboardOpener<KeyType, S>::set_parameters() {
     if (m_max_readers.has_value()) {
         iox2_service_builder_blackboard_opener_set_max_readers(&m_handle, m_max_readers.value());
     }
+    if (m_max_writers.has_value()) {
+        iox2_service_builder_blackboard_opener_set_max_writers(&m_handle, m_max_writers.value());
+    }
     if (m_max_nodes.has_value()) {
         iox2_service_builder_blackboard_opener_set_max_nodes(&m_handle, m_max_nodes.value());
     }
@@ -49,6 +49,9 @@ enum class EventOpenError : uint8_t {
     /// The [`Service`] supported [`EventId`] is smaller than the requested max
     /// [`EventId`].
     DoesNotSupportRequestedMaxEventId,
+    /// The [`Service`] supports a smaller notification data size than
+    /// requested.
+    DoesNotSupportRequestedNotificationDataSize,
     /// The [`Service`] supports less [`Node`]s than
     /// requested.
     DoesNotSupportRequestedAmountOfNodes,
@@ -152,6 +155,9 @@ enum class EventOpenOrCreateError : uint8_t {
     /// The [`Service`] supported [`EventId`] is smaller than the requested max
     /// [`EventId`].
     OpenDoesNotSupportRequestedMaxEventId,
+    /// The [`Service`] supports a smaller notification data size than
+    /// requested.
+    OpenDoesNotSupportRequestedNotificationDataSize,
     /// The [`Service`] supports less [`Node`]s than
     /// requested.
     OpenDoesNotSupportRequestedAmountOfNodes,
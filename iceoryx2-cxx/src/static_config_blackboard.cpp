@@ -25,6 +25,10 @@ auto StaticConfigBlackboard::max_readers() const -> size_t {
     return m_value.max_readers;
 }
 
+auto StaticConfigBlackboard::max_writers() const -> size_t {
+    return m_value.max_writers;
+}
+
 auto StaticConfigBlackboard::type_details() const -> TypeDetail {
     return TypeDetail(m_value.type_details);
 }
@@ -32,6 +36,7 @@ auto StaticConfigBlackboard::type_details() const -> TypeDetail {
 
 auto operator<<(std::ostream& stream, const iox2::StaticConfigBlackboard& value) -> std::ostream& {
     stream << "iox2::StaticConfigBlackboard { max_nodes: " << value.max_nodes()
-           << ", max_readers: " << value.max_readers() << ", type_details: " << value.type_details() << " }";
+           << ", max_readers: " << value.max_readers() << ", max_writers: " << value.max_writers()
+           << ", type_details: " << value.type_details() << " }";
     return stream;
 }
@@ -46,6 +46,7 @@ TEST(EnumConversionTest, listener_create_into_c_str) {
     using Sut = iox2::ListenerCreateError;
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::ExceedsMaxSupportedListeners)), 1U);
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::ResourceCreationFailed)), 1U);
+    ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::ExceedsMaxNumberOfAllowedNotifiers)), 1U);
 }
 
 TEST(EnumConversionTest, listener_wait_into_c_str) {
@@ -121,6 +122,7 @@ TEST(EnumConversionTest, event_open_into_c_str) {
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::DoesNotSupportRequestedAmountOfNotifiers)), 1U);
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::DoesNotSupportRequestedAmountOfListeners)), 1U);
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::DoesNotSupportRequestedMaxEventId)), 1U);
+    ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::DoesNotSupportRequestedNotificationDataSize)), 1U);
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::DoesNotSupportRequestedAmountOfNodes)), 1U);
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::ExceedsMaxNumberOfNodes)), 1U);
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::IsMarkedForDestruction)), 1U);
@@ -148,6 +150,7 @@ TEST(EnumConversionTest, event_open_or_create_into_c_str) {
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::OpenDoesNotSupportRequestedAmountOfNotifiers)), 1U);
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::OpenDoesNotSupportRequestedAmountOfListeners)), 1U);
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::OpenDoesNotSupportRequestedMaxEventId)), 1U);
+    ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::OpenDoesNotSupportRequestedNotificationDataSize)), 1U);
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::OpenDoesNotSupportRequestedAmountOfNodes)), 1U);
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::OpenExceedsMaxNumberOfNodes)), 1U);
     ASSERT_GT(strlen(iox2::bb::into<const char*>(Sut::OpenIsMarkedForDestruction)), 1U);
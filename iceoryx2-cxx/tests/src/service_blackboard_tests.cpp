@@ -341,6 +341,36 @@ TYPED_TEST(ServiceBlackboardTest, open_fails_when_service_does_not_satisfy_max_r
     ASSERT_TRUE(service_success.has_value());
 }
 
+TYPED_TEST(ServiceBlackboardTest, open_fails_when_service_does_not_satisfy_max_writers_requirement) {
+    constexpr ServiceType SERVICE_TYPE = TestFixture::TYPE;
+    constexpr uint64_t NUMBER_OF_WRITERS = 3;
+
+    const auto service_name = iox2_testing::generate_service_name();
+
+    auto node = NodeBuilder().create<SERVICE_TYPE>().value();
+    auto service = node.service_builder(service_name)
+                       .template blackboard_creator<uint64_t>()
+                       .max_writers(NUMBER_OF_WRITERS)
+                       .template add_with_default<uint64_t>(0)
+                       .create()
+                       .value();
+
+    auto service_fail = node.service_builder(service_name)
+                            .template blackboard_opener<uint64_t>()
+                            .max_writers(NUMBER_OF_WRITERS + 1)
+                            .open();
+
+    ASSERT_FALSE(service_fail.has_value());
+    ASSERT_THAT(service_fail.error(), Eq(BlackboardOpenError::DoesNotSupportRequestedAmountOfWriters));
+
+    auto service_success = node.service_builder(service_name)
+                               .template blackboard_opener<uint64_t>()
+                               .max_writers(NUMBER_OF_WRITERS - 1)
+                               .open();
+
+    ASSERT_TRUE(service_success.has_value());
+}
+
 TYPED_TEST(ServiceBlackboardTest, open_works_when_service_owner_goes_out_of_scope) {
     constexpr ServiceType SERVICE_TYPE = TestFixture::TYPE;
 
@@ -430,6 +460,7 @@ TYPED_TEST(ServiceBlackboardTest, setting_service_properties_works) {
     constexpr ServiceType SERVICE_TYPE = TestFixture::TYPE;
     constexpr uint64_t NUMBER_OF_NODES = 10;
     constexpr uint64_t NUMBER_OF_READERS = 11;
+    constexpr uint64_t NUMBER_OF_WRITERS = 3;
 
     const auto service_name = iox2_testing::generate_service_name();
 
@@ -438,6 +469,7 @@ TYPED_TEST(ServiceBlackboardTest, setting_service_properties_works) {
                        .template blackboard_creator<uint64_t>()
                        .max_nodes(NUMBER_OF_NODES)
                        .max_readers(NUMBER_OF_READERS)
+                       .max_writers(NUMBER_OF_WRITERS)
                        .template add_with_default<uint64_t>(0)
                        .create()
                        .value();
@@ -446,6 +478,7 @@ TYPED_TEST(ServiceBlackboardTest, setting_service_properties_works) {
 
     ASSERT_THAT(static_config.max_nodes(), Eq(NUMBER_OF_NODES));
     ASSERT_THAT(static_config.max_readers(), Eq(NUMBER_OF_READERS));
+    ASSERT_THAT(static_config.max_writers(), Eq(NUMBER_OF_WRITERS));
     ASSERT_THAT(static_config.type_details().variant(), Eq(TypeVariant::FixedSize));
     ASSERT_THAT(static_config.type_details().size(), Eq(sizeof(uint64_t)));
     ASSERT_THAT(static_config.type_details().alignment(), Eq(alignof(uint64_t)));
@@ -34,6 +34,8 @@ pub enum iox2_notifier_notify_error_e {
     EVENT_ID_OUT_OF_BOUNDS = IOX2_OK as isize + 1,
     MISSED_DEADLINE,
     UNABLE_TO_ACQUIRE_ELAPSED_TIME,
+    NOTIFICATION_DATA_TOO_LARGE,
+    UNABLE_TO_STORE_NOTIFICATION_DATA,
 }
 
 impl IntoCInt for NotifierNotifyError {
@@ -46,6 +48,12 @@ impl IntoCInt for NotifierNotifyError {
             NotifierNotifyError::UnableToAcquireElapsedTime => {
                 iox2_notifier_notify_error_e::UNABLE_TO_ACQUIRE_ELAPSED_TIME
             }
+            NotifierNotifyError::NotificationDataTooLarge => {
+                iox2_notifier_notify_error_e::NOTIFICATION_DATA_TOO_LARGE
+            }
+            NotifierNotifyError::UnableToStoreNotificationData => {
+                iox2_notifier_notify_error_e::UNABLE_TO_STORE_NOTIFICATION_DATA
+            }
         }) as c_int
     }
 }
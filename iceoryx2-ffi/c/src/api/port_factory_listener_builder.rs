@@ -36,6 +36,7 @@ pub enum iox2_listener_create_error_e {
     RESOURCE_CREATION_FAILED,
     FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY,
     UNABLE_TO_CREATE_PORT_TAG,
+    EXCEEDS_MAX_NUMBER_OF_ALLOWED_NOTIFIERS,
 }
 
 impl IntoCInt for ListenerCreateError {
@@ -53,6 +54,9 @@ impl IntoCInt for ListenerCreateError {
             ListenerCreateError::UnableToCreatePortTag => {
                 iox2_listener_create_error_e::UNABLE_TO_CREATE_PORT_TAG
             }
+            ListenerCreateError::ExceedsMaxNumberOfAllowedNotifiers => {
+                iox2_listener_create_error_e::EXCEEDS_MAX_NUMBER_OF_ALLOWED_NOTIFIERS
+            }
         }) as c_int
     }
 }
@@ -80,7 +84,7 @@ impl PortFactoryListenerBuilderUnion {
 #[repr(C)]
 #[repr(align(8))] // alignment of Option<PortFactoryListenerBuilderUnion>
 pub struct iox2_port_factory_listener_builder_storage_t {
-    internal: [u8; 24], // magic number obtained with size_of::<Option<PortFactoryListenerBuilderUnion>>()
+    internal: [u8; 72], // magic number obtained with size_of::<Option<PortFactoryListenerBuilderUnion>>()
 }
 
 #[repr(C)]
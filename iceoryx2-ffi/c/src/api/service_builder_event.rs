@@ -69,6 +69,8 @@ pub enum iox2_event_open_or_create_error_e {
     O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_LISTENERS,
     #[CStr = "does not support requested max event id"]
     O_DOES_NOT_SUPPORT_REQUESTED_MAX_EVENT_ID,
+    #[CStr = "does not support requested notification data size"]
+    O_DOES_NOT_SUPPORT_REQUESTED_NOTIFICATION_DATA_SIZE,
     #[CStr = "does not support requested amount of nodes"]
     O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NODES,
     #[CStr = "exceeds max number of nodes"]
@@ -131,6 +133,9 @@ impl IntoCInt for EventOpenError {
             EventOpenError::DoesNotSupportRequestedMaxEventId => {
                 iox2_event_open_or_create_error_e::O_DOES_NOT_SUPPORT_REQUESTED_MAX_EVENT_ID
             }
+            EventOpenError::DoesNotSupportRequestedNotificationDataSize => {
+                iox2_event_open_or_create_error_e::O_DOES_NOT_SUPPORT_REQUESTED_NOTIFICATION_DATA_SIZE
+            }
             EventOpenError::DoesNotSupportRequestedAmountOfNodes => {
                 iox2_event_open_or_create_error_e::O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NODES
             }
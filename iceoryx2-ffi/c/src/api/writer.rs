@@ -32,6 +32,7 @@ use iceoryx2_ffi_macros::{CStrRepr, iceoryx2_ffi};
 pub enum iox2_entry_handle_mut_error_e {
     ENTRY_DOES_NOT_EXIST = IOX2_OK as isize + 1,
     HANDLE_ALREADY_EXISTS,
+    ENTRY_OWNED_BY_ANOTHER_WRITER,
 }
 
 impl IntoCInt for EntryHandleMutError {
@@ -43,6 +44,9 @@ impl IntoCInt for EntryHandleMutError {
             EntryHandleMutError::HandleAlreadyExists => {
                 iox2_entry_handle_mut_error_e::HANDLE_ALREADY_EXISTS
             }
+            EntryHandleMutError::EntryOwnedByAnotherWriter => {
+                iox2_entry_handle_mut_error_e::ENTRY_OWNED_BY_ANOTHER_WRITER
+            }
         }) as c_int
     }
 }
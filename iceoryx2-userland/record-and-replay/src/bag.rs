@@ -0,0 +1,609 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A bag file contains the records of multiple services. Every recorded service is
+//! described by a [`BagChannel`] in the [`BagHeader`] and every [`BagRecord`] refers to the
+//! [`ChannelId`] of the service it was captured from. The records of all channels are
+//! interleaved and stored in global time order.
+//!
+//! ## Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2_userland_record_and_replay::prelude::*;
+//! use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
+//! use core::time::Duration;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let service_types = ServiceTypes {
+//!     payload: TypeDetail::new::<u64>(TypeVariant::FixedSize),
+//!     user_header: TypeDetail::new::<()>(TypeVariant::FixedSize),
+//!     system_header: TypeDetail::new::<u64>(TypeVariant::FixedSize),
+//! };
+//!
+//! let mut recorder = BagRecorderBuilder::new()
+//!     .data_representation(DataRepresentation::HumanReadable)
+//!     .add_channel(&ServiceName::new("camera")?, &service_types, MessagingPattern::PublishSubscribe)
+//!     .add_channel(&ServiceName::new("lidar")?, &service_types, MessagingPattern::PublishSubscribe)
+//!     .create(&FilePath::new(b"recorded_bag.iox2")?)?;
+//!
+//! # iceoryx2_bb_posix::file::File::remove(&FilePath::new(b"recorded_bag.iox2")?)?;
+//!
+//! let lidar = recorder
+//!     .header()
+//!     .channel_id(&ServiceName::new("lidar")?, MessagingPattern::PublishSubscribe)
+//!     .unwrap();
+//!
+//! recorder.write(lidar, RawRecord {
+//!     timestamp: Duration::ZERO,
+//!     system_header: &[0u8; 8],
+//!     user_header: &[0u8; 0],
+//!     payload: &[0u8; 8]
+//! })?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use iceoryx2::prelude::{MessagingPattern, ServiceName};
+use iceoryx2_bb_elementary::package_version::PackageVersion;
+use iceoryx2_bb_posix::file::FileReadLineState;
+use iceoryx2_bb_posix::file::{AccessMode, CreationMode, File, FileBuilder, FileCreationError};
+use iceoryx2_bb_posix::file_descriptor::FileDescriptorManagement;
+use iceoryx2_bb_system_types::file_path::FilePath;
+use iceoryx2_cal::serialize::Serialize;
+use iceoryx2_cal::serialize::toml::Toml;
+use iceoryx2_log::{fail, warn};
+
+use crate::compression::{ChunkReader, Compression};
+use crate::mcap::{self, McapChannel, McapReader};
+use crate::record::{
+    DataRepresentation, HEX_START_RECORD_MARKER, RawRecord, Record, RecordReader, RecordWriter,
+};
+use crate::record_header::{RecordHeaderDetails, Version};
+use crate::recorder::{RecorderCreateError, RecorderWriteError, ServiceTypes};
+use crate::replayer::{ReplayerOpenError, read_human_readable_header};
+
+/// Defines the current file format version of bag files
+pub const FILE_FORMAT_BAG_VERSION: u64 = 1;
+
+const READABLE_CHANNEL_PREFIX: &str = "channel:  ";
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Identifies a [`BagChannel`] inside a bag file.
+pub struct ChannelId(u64);
+
+impl ChannelId {
+    /// Creates a new [`ChannelId`] from the provided value.
+    pub const fn new(value: u64) -> Self {
+        Self(value)
+    }
+
+    /// Returns the underlying value of the [`ChannelId`].
+    pub const fn value(&self) -> u64 {
+        self.0
+    }
+}
+
+impl core::fmt::Display for ChannelId {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Clone)]
+/// Describes a single recorded service inside a bag file.
+pub struct BagChannel {
+    /// The id with which the records of the service are stored.
+    pub id: ChannelId,
+    /// The name of the recorded service.
+    pub service_name: ServiceName,
+    /// The messaging pattern of the recorded service.
+    pub messaging_pattern: MessagingPattern,
+    /// The types to which the stored payload corresponds.
+    pub types: ServiceTypes,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Clone)]
+/// Represents the header of a bag file which describes all contained channels.
+pub struct BagHeader {
+    /// The version of iceoryx2 used when the data was captured.
+    pub iceoryx2_version: Version,
+    /// Defines the file format version
+    pub file_format_version: u64,
+    /// All services that are recorded in the bag file.
+    pub channels: Vec<BagChannel>,
+}
+
+impl BagHeader {
+    /// Returns the [`BagChannel`] with the provided [`ChannelId`]. If it does not exist
+    /// it returns [`None`].
+    pub fn channel(&self, id: ChannelId) -> Option<&BagChannel> {
+        self.channels.iter().find(|channel| channel.id == id)
+    }
+
+    /// Returns the [`ChannelId`] of the recorded service. If the service is not part of
+    /// the bag file it returns [`None`].
+    pub fn channel_id(
+        &self,
+        service_name: &ServiceName,
+        messaging_pattern: MessagingPattern,
+    ) -> Option<ChannelId> {
+        self.channels
+            .iter()
+            .find(|channel| {
+                channel.service_name == *service_name
+                    && channel.messaging_pattern == messaging_pattern
+            })
+            .map(|channel| channel.id)
+    }
+
+    fn record_details(&self, channel: &BagChannel) -> RecordHeaderDetails {
+        RecordHeaderDetails {
+            file_format_version: self.file_format_version,
+            types: channel.types.clone(),
+            response_types: None,
+            messaging_pattern: channel.messaging_pattern,
+            compression: Compression::None,
+        }
+    }
+}
+
+#[derive(Debug)]
+/// Represents a stored record of a specific channel.
+pub struct BagRecord {
+    /// The channel from which the record was captured.
+    pub channel_id: ChannelId,
+    /// The captured data.
+    pub record: Record,
+}
+
+#[derive(Debug, Default)]
+/// Builder to create a new [`BagRecorder`].
+pub struct BagRecorderBuilder {
+    channels: Vec<BagChannel>,
+    data_representation: DataRepresentation,
+}
+
+impl BagRecorderBuilder {
+    /// Creates a new [`BagRecorderBuilder`] without any channel.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Defines the data representation of the file content the [`BagRecorder`] will create.
+    pub fn data_representation(mut self, value: DataRepresentation) -> Self {
+        self.data_representation = value;
+        self
+    }
+
+    /// Adds a service as new [`BagChannel`] to the bag file. The channels are numbered in
+    /// the order they were added, starting with [`ChannelId`] 0.
+    pub fn add_channel(
+        mut self,
+        service_name: &ServiceName,
+        types: &ServiceTypes,
+        messaging_pattern: MessagingPattern,
+    ) -> Self {
+        self.channels.push(BagChannel {
+            id: ChannelId::new(self.channels.len() as u64),
+            service_name: *service_name,
+            messaging_pattern,
+            types: types.clone(),
+        });
+        self
+    }
+
+    /// Creates a new file and writes the [`BagHeader`] into it. On failure
+    /// [`RecorderCreateError`] is returned describing the error.
+    pub fn create(self, file_name: &FilePath) -> Result<BagRecorder, RecorderCreateError> {
+        let msg = format!("Unable to create bag recorder for \"{file_name}\"");
+
+        for (n, channel) in self.channels.iter().enumerate() {
+            if self.channels[..n].iter().any(|c| {
+                c.service_name == channel.service_name
+                    && c.messaging_pattern == channel.messaging_pattern
+            }) {
+                fail!(from self, with RecorderCreateError::DuplicateChannel,
+                    "{msg} since the service \"{}\" was added more than once.", channel.service_name);
+            }
+        }
+
+        let mut file = match FileBuilder::new(file_name)
+            .has_ownership(false)
+            .creation_mode(CreationMode::CreateExclusive)
+            .create()
+        {
+            Ok(v) => v,
+            Err(FileCreationError::FileAlreadyExists) => {
+                fail!(from self, with RecorderCreateError::FileAlreadyExists,
+                    "{msg} since the file already exists.");
+            }
+            Err(e) => {
+                fail!(from self, with RecorderCreateError::FailedToCreateRecordFile,
+                    "{msg} since the underlying file could not be created ({e:?}).");
+            }
+        };
+
+        let header = BagHeader {
+            iceoryx2_version: PackageVersion::get().into(),
+            file_format_version: FILE_FORMAT_BAG_VERSION,
+            channels: self.channels.clone(),
+        };
+
+        let serialized = fail!(from self,
+                               when Toml::serialize(&header),
+                               with RecorderCreateError::UnableToSerializeRecordHeader,
+                               "{msg} since the BagHeader could not be serialized.");
+
+        let mut write_to_file = |data: &[u8]| -> Result<(), RecorderCreateError> {
+            fail!(from self,
+              when file.write(data),
+              with RecorderCreateError::UnableToWriteFile,
+              "{msg} since the file could not be written.");
+            Ok(())
+        };
+
+        match self.data_representation {
+            DataRepresentation::HumanReadable => {
+                write_to_file(&serialized)?;
+                write_to_file(b"\n\n")?;
+                write_to_file(HEX_START_RECORD_MARKER)?;
+                write_to_file(b"\n")?;
+            }
+            DataRepresentation::Iox2Dump => {
+                write_to_file(&(serialized.len() as u64).to_le_bytes())?;
+                write_to_file(&serialized)?;
+            }
+            DataRepresentation::Mcap => {
+                let channels: Vec<McapChannel> = header
+                    .channels
+                    .iter()
+                    .map(|channel| McapChannel {
+                        id: channel.id.value() as u16,
+                        service_name: channel.service_name,
+                        messaging_pattern: channel.messaging_pattern,
+                        types: channel.types.clone(),
+                        response_types: None,
+                        is_native: true,
+                    })
+                    .collect();
+                mcap::write_preamble(&mut file, &channels)?;
+            }
+        }
+
+        Ok(BagRecorder {
+            file,
+            header,
+            data_representation: self.data_representation,
+            last_timestamp: 0,
+            sequence: 0,
+        })
+    }
+}
+
+#[derive(Debug)]
+/// Is created by [`BagRecorderBuilder`] and stores the captured records of all channels
+/// into the underlying file.
+pub struct BagRecorder {
+    file: File,
+    data_representation: DataRepresentation,
+    header: BagHeader,
+    last_timestamp: u64,
+    sequence: u32,
+}
+
+impl Drop for BagRecorder {
+    fn drop(&mut self) {
+        if let DataRepresentation::Mcap = self.data_representation {
+            if let Err(e) = mcap::write_trailer(&mut self.file, None) {
+                warn!(from self, "Unable to finalize the MCAP file ({e:?}).");
+            }
+        }
+    }
+}
+
+impl BagRecorder {
+    /// Writes a captured record of the given channel into the file. The timestamps of all
+    /// records must be monotonic across all channels.
+    pub fn write(
+        &mut self,
+        channel_id: ChannelId,
+        record: RawRecord,
+    ) -> Result<(), RecorderWriteError> {
+        let msg = "Unable to write new record";
+
+        let channel = match self.header.channel(channel_id) {
+            Some(channel) => channel,
+            None => {
+                fail!(from self, with RecorderWriteError::UnknownChannel,
+                    "{msg} since the channel {channel_id} is not part of the bag file.");
+            }
+        };
+
+        channel.types.verify_raw_record(&record)?;
+
+        let new_timestamp = record.timestamp.as_millis() as u64;
+        if self.last_timestamp > new_timestamp {
+            fail!(from self, with RecorderWriteError::TimestampOlderThanPreviousRecord,
+                "{msg} since record timestamp is older than the previous record entry. Records are not allowed to jump back in time.");
+        }
+        self.last_timestamp = new_timestamp;
+
+        let origin = format!("{self:?}");
+        let channel_entry = match self.data_representation {
+            DataRepresentation::HumanReadable => {
+                Some(format!("{READABLE_CHANNEL_PREFIX}{channel_id}\n").into_bytes())
+            }
+            DataRepresentation::Iox2Dump => Some(channel_id.value().to_le_bytes().to_vec()),
+            // the channel is part of the MCAP message record
+            DataRepresentation::Mcap => None,
+        };
+        if let Some(channel_entry) = channel_entry {
+            if let Err(e) = self.file.write(&channel_entry) {
+                fail!(from origin, with RecorderWriteError::FileWriteError(e),
+                    "{msg} since the channel entry could not be written into the file ({e:?}).");
+            }
+        }
+
+        RecordWriter::new(&mut self.file)
+            .data_representation(self.data_representation)
+            .mcap_message(channel_id.value() as u16, self.sequence)
+            .write(record)?;
+        self.sequence = self.sequence.wrapping_add(1);
+
+        Ok(())
+    }
+
+    /// Returns the [`BagHeader`] of the underlying file.
+    pub fn header(&self) -> &BagHeader {
+        &self.header
+    }
+}
+
+#[derive(Debug)]
+/// Builder to open a bag file. It returns the [`BagReplayer`] which can read the file
+/// entry by entry.
+pub struct BagReplayerOpener {
+    file_path: FilePath,
+    data_representation: DataRepresentation,
+}
+
+impl BagReplayerOpener {
+    /// Creates a new [`BagReplayerOpener`]
+    pub fn new(file_path: &FilePath) -> Self {
+        Self {
+            file_path: *file_path,
+            data_representation: DataRepresentation::default(),
+        }
+    }
+
+    /// Defines the [`DataRepresentation`] of the file content.
+    pub fn data_representation(mut self, value: DataRepresentation) -> Self {
+        self.data_representation = value;
+        self
+    }
+
+    /// Opens the bag file and returns the [`BagReplayer`] which allows the user to
+    /// read one entry at a time.
+    pub fn open(self) -> Result<BagReplayer, ReplayerOpenError> {
+        let msg = "Unable to read bag file";
+        let mut file = match FileBuilder::new(&self.file_path)
+            .has_ownership(false)
+            .open_existing(AccessMode::Read)
+        {
+            Ok(v) => v,
+            Err(e) => {
+                fail!(from self, with ReplayerOpenError::FailedToOpenFile,
+                                "{msg} since the file could not be opened ({e:?}).");
+            }
+        };
+
+        let buffer = match self.data_representation {
+            DataRepresentation::HumanReadable => read_human_readable_header(&mut file)?,
+            DataRepresentation::Iox2Dump => {
+                let mut len = [0u8; 8];
+                let read_bytes = fail!(from self, when file.read(&mut len),
+                                    with ReplayerOpenError::FailedToReadFile,
+                                    "{msg} since the bag header length could not be read.");
+                if read_bytes != len.len() as u64 {
+                    fail!(from self, with ReplayerOpenError::UnableToDeserializeRecordHeader,
+                        "{msg} since the bag file is too short.");
+                }
+
+                let file_size = fail!(from self, when file.metadata(),
+                                    with ReplayerOpenError::FailedToReadFile,
+                                    "{msg} since the file size could not be acquired.")
+                .size();
+                let header_len = u64::from_le_bytes(len);
+                if header_len > file_size - len.len() as u64 {
+                    fail!(from self, with ReplayerOpenError::CorruptedContent,
+                        "{msg} since the bag header length of {header_len} bytes exceeds the file size of {file_size} bytes.");
+                }
+
+                let mut buffer = vec![0u8; header_len as usize];
+                let read_bytes = fail!(from self, when file.read(&mut buffer),
+                                    with ReplayerOpenError::FailedToReadFile,
+                                    "{msg} since the bag header could not be read.");
+                if read_bytes != buffer.len() as u64 {
+                    fail!(from self, with ReplayerOpenError::UnableToDeserializeRecordHeader,
+                        "{msg} since the bag header entry is too short.");
+                }
+                buffer
+            }
+            DataRepresentation::Mcap => return Self::open_mcap(file),
+        };
+
+        let header = fail!(from self,
+            when Toml::deserialize::<BagHeader>(&buffer),
+            with ReplayerOpenError::UnableToDeserializeRecordHeader,
+            "{msg} since the bag header could not be deserialized.");
+
+        Ok(BagReplayer {
+            file,
+            data_representation: self.data_representation,
+            header,
+            last_timestamp: 0,
+            mcap_reader: McapReader::default(),
+        })
+    }
+
+    fn open_mcap(file: File) -> Result<BagReplayer, ReplayerOpenError> {
+        let summary = mcap::read_summary(&file)?;
+        let header = BagHeader {
+            iceoryx2_version: summary.iceoryx2_version.clone(),
+            file_format_version: FILE_FORMAT_BAG_VERSION,
+            channels: summary
+                .channels
+                .iter()
+                .map(|channel| BagChannel {
+                    id: ChannelId::new(channel.id as u64),
+                    service_name: channel.service_name,
+                    messaging_pattern: channel.messaging_pattern,
+                    types: channel.types.clone(),
+                })
+                .collect(),
+        };
+
+        Ok(BagReplayer {
+            file,
+            data_representation: DataRepresentation::Mcap,
+            header,
+            last_timestamp: 0,
+            mcap_reader: McapReader::new(&summary),
+        })
+    }
+}
+
+#[derive(Debug)]
+/// Has read access to the bag file and can extract one [`BagRecord`] at a time in global
+/// time order.
+pub struct BagReplayer {
+    file: File,
+    data_representation: DataRepresentation,
+    header: BagHeader,
+    last_timestamp: u64,
+    mcap_reader: McapReader,
+}
+
+impl BagReplayer {
+    /// Reads the content of the bag file into a buffer.
+    pub fn read_into_buffer(mut self) -> Result<Vec<BagRecord>, ReplayerOpenError> {
+        let mut buffer = vec![];
+        while let Some(record) = self.next_record()? {
+            buffer.push(record);
+        }
+
+        Ok(buffer)
+    }
+
+    /// Returns the next contained [`BagRecord`]. If it reached the end of the file it
+    /// returns [`None`].
+    pub fn next_record(&mut self) -> Result<Option<BagRecord>, ReplayerOpenError> {
+        let msg = "Unable to read next record";
+        let channel_id = match self.read_channel_id()? {
+            Some(id) => id,
+            None => return Ok(None),
+        };
+
+        let channel = match self.header.channel(channel_id) {
+            Some(channel) => channel,
+            None => {
+                fail!(from self, with ReplayerOpenError::UnknownChannel,
+                    "{msg} since the record belongs to the channel {channel_id} which is not defined in the bag header.");
+            }
+        };
+
+        let record = match RecordReader::new(&self.header.record_details(channel))
+            .data_representation(self.data_representation)
+            .read(
+                &self.file,
+                &mut self.mcap_reader,
+                &mut ChunkReader::default(),
+            )? {
+            Some(record) => record,
+            None => {
+                fail!(from self, with ReplayerOpenError::CorruptedContent,
+                    "{msg} since the file ends prematurely after the channel entry.");
+            }
+        };
+
+        let new_timestamp = record.timestamp.as_millis() as u64;
+        if self.last_timestamp > new_timestamp {
+            fail!(from self, with ReplayerOpenError::CorruptedTimeline,
+                "{msg} since the next entries time stamp is older than the previous entries timestamp. The entries are not allowed to jump back and forth in time.");
+        }
+        self.last_timestamp = new_timestamp;
+
+        Ok(Some(BagRecord { channel_id, record }))
+    }
+
+    fn read_channel_id(&mut self) -> Result<Option<ChannelId>, ReplayerOpenError> {
+        let msg = "Unable to read channel of next record";
+        match self.data_representation {
+            DataRepresentation::HumanReadable => loop {
+                let mut line = alloc::string::String::new();
+                match self.file.read_line_to_string(&mut line) {
+                    Ok(FileReadLineState::EndOfFile(_)) if line.is_empty() => return Ok(None),
+                    Ok(FileReadLineState::LineLen(0)) => continue,
+                    Ok(_) => (),
+                    Err(e) => {
+                        fail!(from self, with ReplayerOpenError::FailedToReadFile,
+                            "{msg} since the file could not be read ({e:?}).");
+                    }
+                }
+
+                let value = match line.strip_prefix(READABLE_CHANNEL_PREFIX) {
+                    Some(v) => v,
+                    None => {
+                        fail!(from self, with ReplayerOpenError::CorruptedContent,
+                            "{msg} since the channel entry is corrupted.");
+                    }
+                };
+
+                return Ok(Some(ChannelId::new(
+                    fail!(from self, when value.parse::<u64>(),
+                    with ReplayerOpenError::CorruptedContent,
+                    "{msg} since the channel entry is not a number."),
+                )));
+            },
+            DataRepresentation::Iox2Dump => {
+                let mut buffer = [0u8; 8];
+                let len = fail!(from self, when self.file.read(&mut buffer),
+                    with ReplayerOpenError::FailedToReadFile,
+                    "{msg} since the underlying file could not be read.");
+
+                match len {
+                    0 => Ok(None),
+                    8 => Ok(Some(ChannelId::new(u64::from_le_bytes(buffer)))),
+                    _ => {
+                        fail!(from self, with ReplayerOpenError::CorruptedContent,
+                            "{msg} since the channel entry is incomplete.");
+                    }
+                }
+            }
+            DataRepresentation::Mcap => Ok(self
+                .mcap_reader
+                .peek_channel_id(&self.file)?
+                .map(|id| ChannelId::new(id as u64))),
+        }
+    }
+
+    /// Returns the header of the bag file.
+    pub fn header(&self) -> &BagHeader {
+        &self.header
+    }
+}
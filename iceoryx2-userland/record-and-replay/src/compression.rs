@@ -0,0 +1,339 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Files in the [`DataRepresentation::Iox2Dump`](crate::record::DataRepresentation::Iox2Dump)
+//! format can be compressed. The [`Recorder`](crate::recorder::Recorder) collects the records
+//! in chunks of [`RecorderBuilder::chunk_size()`](crate::recorder::RecorderBuilder::chunk_size())
+//! bytes and stores every chunk compressed with the codec defined in
+//! [`RecordHeaderDetails::compression`](crate::record_header::RecordHeaderDetails::compression).
+//! The [`Replayer`](crate::replayer::Replayer) decompresses the chunks transparently. Every
+//! record that is referenced by the [`RecordIndex`](crate::record_index::RecordIndex) starts
+//! a new chunk, therefore the offset of an index entry is the start of that chunk.
+//!
+//! A chunk holds at most [`MAX_CHUNK_SIZE`] uncompressed bytes. Chunks that claim to be
+//! larger are rejected by the [`Replayer`](crate::replayer::Replayer) as corrupted before
+//! any memory is allocated for them.
+//!
+//! ## Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
+//! use iceoryx2_userland_record_and_replay::prelude::*;
+//! use core::time::Duration;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let service_types = ServiceTypes {
+//!     payload: TypeDetail::new::<u8>(TypeVariant::Dynamic),
+//!     user_header: TypeDetail::new::<()>(TypeVariant::FixedSize),
+//!     system_header: TypeDetail::new::<u64>(TypeVariant::FixedSize),
+//! };
+//!
+//! let mut recorder = RecorderBuilder::new(&service_types)
+//!     .data_representation(DataRepresentation::Iox2Dump)
+//!     .compression(Compression::Zstd)
+//!     .chunk_size(64 * 1024)
+//!     .create(&FilePath::new(b"compressed_data.iox2")?, &ServiceName::new("my-camera")?)?;
+//!
+//! # iceoryx2_bb_posix::file::File::remove(&FilePath::new(b"compressed_data.iox2")?)?;
+//!
+//! recorder.write(RawRecord {
+//!     timestamp: Duration::ZERO,
+//!     system_header: &[0u8; 8],
+//!     user_header: &[],
+//!     payload: &[0u8; 1024],
+//! })?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use alloc::vec::Vec;
+
+use iceoryx2_bb_posix::file::File;
+use iceoryx2_bb_posix::file_descriptor::FileDescriptorManagement;
+use iceoryx2_log::fail;
+
+use crate::record_index::IOX2_DUMP_END_OF_RECORDS;
+use crate::recorder::RecorderWriteError;
+use crate::replayer::ReplayerOpenError;
+
+/// The default uncompressed size of a chunk.
+pub const DEFAULT_CHUNK_SIZE: usize = 1024 * 1024;
+
+/// The maximum uncompressed size of a chunk. Records larger than this cannot be recorded
+/// compressed.
+pub const MAX_CHUNK_SIZE: usize = 64 * 1024 * 1024;
+
+const CHUNK_HEADER_LEN: usize = 16;
+
+#[repr(C)]
+#[derive(serde::Serialize, serde::Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+/// The codec with which the chunks of a recorded file are compressed.
+pub enum Compression {
+    /// The records are stored uncompressed.
+    #[default]
+    None,
+    /// Fast compression with a moderate compression ratio.
+    Lz4,
+    /// Higher compression ratio at the cost of a slower compression.
+    Zstd,
+}
+
+impl Compression {
+    /// Returns `true` when the records are stored uncompressed.
+    pub fn is_none(&self) -> bool {
+        *self == Compression::None
+    }
+
+    pub(crate) fn compress(&self, data: &[u8]) -> Vec<u8> {
+        match self {
+            Compression::None => data.to_vec(),
+            Compression::Lz4 => lz4_flex::block::compress(data),
+            Compression::Zstd => {
+                ruzstd::encoding::compress_to_vec(data, ruzstd::encoding::CompressionLevel::Fastest)
+            }
+        }
+    }
+
+    /// Returns [`None`] when the data could not be decompressed, does not have the
+    /// expected size or the expected size exceeds [`MAX_CHUNK_SIZE`].
+    pub(crate) fn decompress(&self, data: &[u8], uncompressed_size: usize) -> Option<Vec<u8>> {
+        if MAX_CHUNK_SIZE < uncompressed_size {
+            return None;
+        }
+
+        let decompressed = match self {
+            Compression::None => data.to_vec(),
+            Compression::Lz4 => lz4_flex::block::decompress(data, uncompressed_size).ok()?,
+            Compression::Zstd => {
+                let mut decompressed = Vec::with_capacity(uncompressed_size);
+                ruzstd::decoding::FrameDecoder::new()
+                    .decode_all_to_vec(data, &mut decompressed)
+                    .ok()?;
+                decompressed
+            }
+        };
+
+        (decompressed.len() == uncompressed_size).then_some(decompressed)
+    }
+
+    /// Returns the [`Compression`] that corresponds to the compression of a MCAP chunk.
+    pub(crate) fn from_mcap(compression: &str) -> Option<Self> {
+        match compression {
+            "" => Some(Compression::None),
+            "zstd" => Some(Compression::Zstd),
+            _ => None,
+        }
+    }
+}
+
+/// Collects the serialized records until the chunk is full and writes it compressed into
+/// the file. A chunk consists of the compressed size, the uncompressed size and the
+/// compressed records.
+pub(crate) struct ChunkWriter {
+    compression: Compression,
+    chunk_size: usize,
+    data: Vec<u8>,
+}
+
+// the pending records are not printed since a chunk can be large
+impl core::fmt::Debug for ChunkWriter {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "ChunkWriter {{ compression: {:?}, chunk_size: {}, pending_bytes: {} }}",
+            self.compression,
+            self.chunk_size,
+            self.data.len()
+        )
+    }
+}
+
+impl ChunkWriter {
+    pub(crate) fn new(compression: Compression, chunk_size: usize) -> Self {
+        Self {
+            compression,
+            chunk_size,
+            data: Vec::new(),
+        }
+    }
+
+    /// Adds a serialized record to the chunk and writes the chunk when it is full. The
+    /// pending records are written first when the record would exceed [`MAX_CHUNK_SIZE`].
+    pub(crate) fn write(
+        &mut self,
+        file: &mut File,
+        record: &[u8],
+    ) -> Result<(), RecorderWriteError> {
+        if MAX_CHUNK_SIZE < record.len() {
+            fail!(from "ChunkWriter::write()", with RecorderWriteError::RecordExceedsMaxChunkSize,
+                "Unable to write the record since its size of {} bytes exceeds the maximum chunk size of {MAX_CHUNK_SIZE} bytes.",
+                record.len());
+        }
+
+        if MAX_CHUNK_SIZE < self.data.len() + record.len() {
+            self.flush(file)?;
+        }
+
+        self.data.extend_from_slice(record);
+        if self.chunk_size <= self.data.len() {
+            self.flush(file)?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the pending records as chunk into the file.
+    pub(crate) fn flush(&mut self, file: &mut File) -> Result<(), RecorderWriteError> {
+        if self.data.is_empty() {
+            return Ok(());
+        }
+
+        let compressed = self.compression.compress(&self.data);
+        let mut chunk = Vec::with_capacity(CHUNK_HEADER_LEN + compressed.len());
+        chunk.extend_from_slice(&(compressed.len() as u64).to_le_bytes());
+        chunk.extend_from_slice(&(self.data.len() as u64).to_le_bytes());
+        chunk.extend_from_slice(&compressed);
+
+        if let Err(e) = file.write(&chunk) {
+            fail!(from "ChunkWriter::flush()", with RecorderWriteError::FileWriteError(e),
+                "Unable to write the compressed chunk into the file ({e:?}).");
+        }
+        self.data.clear();
+
+        Ok(())
+    }
+}
+
+/// Provides the content of a file that was written with the [`ChunkWriter`]. Uncompressed
+/// files are read directly.
+#[derive(Default)]
+pub(crate) struct ChunkReader {
+    compression: Compression,
+    data: Vec<u8>,
+    position: usize,
+}
+
+impl core::fmt::Debug for ChunkReader {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "ChunkReader {{ compression: {:?}, position: {}, chunk_len: {} }}",
+            self.compression,
+            self.position,
+            self.data.len()
+        )
+    }
+}
+
+impl ChunkReader {
+    pub(crate) fn new(compression: Compression) -> Self {
+        Self {
+            compression,
+            ..Self::default()
+        }
+    }
+
+    /// Discards the current chunk. Must be called whenever the file offset was changed.
+    pub(crate) fn reset(&mut self) {
+        self.data.clear();
+        self.position = 0;
+    }
+
+    /// Fills the buffer with the next bytes and returns the number of bytes that were read.
+    /// Returns `0` when the end of the records is reached.
+    pub(crate) fn read(
+        &mut self,
+        file: &File,
+        buffer: &mut [u8],
+    ) -> Result<u64, ReplayerOpenError> {
+        let msg = "Unable to read next record";
+        let origin = "ChunkReader::read()";
+        if self.compression.is_none() {
+            return Ok(fail!(from origin, when file.read(buffer),
+                with ReplayerOpenError::FailedToReadFile,
+                "{msg} since the underlying file could not be read."));
+        }
+
+        let mut len = 0;
+        while len < buffer.len() {
+            if self.position == self.data.len() && !self.read_chunk(file)? {
+                break;
+            }
+
+            let n = (buffer.len() - len).min(self.data.len() - self.position);
+            buffer[len..len + n].copy_from_slice(&self.data[self.position..self.position + n]);
+            self.position += n;
+            len += n;
+        }
+
+        Ok(len as u64)
+    }
+
+    fn read_chunk(&mut self, file: &File) -> Result<bool, ReplayerOpenError> {
+        let msg = "Unable to read compressed chunk";
+        let origin = "ChunkReader::read_chunk()";
+        let mut header = [0u8; CHUNK_HEADER_LEN];
+        let len = fail!(from origin, when file.read(&mut header),
+            with ReplayerOpenError::FailedToReadFile,
+            "{msg} since the underlying file could not be read.");
+
+        let compressed_len = u64::from_le_bytes(header[..8].try_into().unwrap());
+        // the end of records marker is not followed by a complete chunk header
+        if len < 8 || compressed_len == IOX2_DUMP_END_OF_RECORDS {
+            return Ok(false);
+        }
+        if len != CHUNK_HEADER_LEN as u64 {
+            fail!(from origin, with ReplayerOpenError::CorruptedContent,
+                "{msg} since the chunk header is incomplete.");
+        }
+
+        let uncompressed_len = u64::from_le_bytes(header[8..].try_into().unwrap());
+        if uncompressed_len > MAX_CHUNK_SIZE as u64 {
+            fail!(from origin, with ReplayerOpenError::CorruptedContent,
+                "{msg} since the uncompressed size of {uncompressed_len} bytes exceeds the maximum chunk size of {MAX_CHUNK_SIZE} bytes.");
+        }
+
+        let file_size = fail!(from origin, when file.metadata(),
+            with ReplayerOpenError::FailedToReadFile,
+            "{msg} since the file size could not be acquired.")
+        .size();
+        if file_size < compressed_len {
+            fail!(from origin, with ReplayerOpenError::CorruptedContent,
+                "{msg} since the compressed size of {compressed_len} bytes exceeds the file size of {file_size} bytes.");
+        }
+
+        let mut compressed = alloc::vec![0u8; compressed_len as usize];
+        let len = fail!(from origin, when file.read(&mut compressed),
+            with ReplayerOpenError::FailedToReadFile,
+            "{msg} since the underlying file could not be read.");
+        if len != compressed_len {
+            fail!(from origin, with ReplayerOpenError::CorruptedContent,
+                "{msg} since the chunk has a size of {len} and {compressed_len} bytes are expected.");
+        }
+
+        self.data = match self
+            .compression
+            .decompress(&compressed, uncompressed_len as usize)
+        {
+            Some(data) => data,
+            None => {
+                fail!(from origin, with ReplayerOpenError::CorruptedContent,
+                    "{msg} since it could not be decompressed with {:?}.", self.compression);
+            }
+        };
+        self.position = 0;
+
+        Ok(true)
+    }
+}
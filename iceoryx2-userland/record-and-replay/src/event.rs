@@ -0,0 +1,126 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Every notification of an event service is stored as one record. The system header
+//! contains the [`EventRecordHeader`], the user header is empty and the payload contains
+//! the notification data that was received together with the [`EventId`].
+//!
+//! ## Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2_userland_record_and_replay::prelude::*;
+//! use iceoryx2_userland_record_and_replay::event::EventRecordHeader;
+//! use core::time::Duration;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let mut recorder = RecorderBuilder::new(&EventRecordHeader::service_types())
+//!     .messaging_pattern(MessagingPattern::Event)
+//!     .create(&FilePath::new(b"recorded_events.iox2")?, &ServiceName::new("my-event")?)?;
+//!
+//! # iceoryx2_bb_posix::file::File::remove(&FilePath::new(b"recorded_events.iox2")?)?;
+//!
+//! let header = EventRecordHeader::new(None, EventId::new(12), 1);
+//! recorder.write(RawRecord {
+//!     timestamp: Duration::ZERO,
+//!     system_header: &header.to_bytes(),
+//!     user_header: &[],
+//!     payload: &[],
+//! })?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use iceoryx2::port::event_id::EventId;
+use iceoryx2::prelude::ZeroCopySend;
+use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
+
+use crate::recorder::ServiceTypes;
+
+/// The value of [`EventRecordHeader::notifier_id`] when the
+/// [`Notifier`](iceoryx2::port::notifier::Notifier) of the notification is unknown.
+pub const UNKNOWN_NOTIFIER: u128 = 0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ZeroCopySend)]
+#[type_name("EventRecordHeader")]
+#[repr(C)]
+/// The system header of a recorded notification.
+pub struct EventRecordHeader {
+    /// The [`UniqueNotifierId::value()`](iceoryx2::identifiers::UniqueNotifierId::value())
+    /// of the [`Notifier`](iceoryx2::port::notifier::Notifier) that sent the notification or
+    /// [`UNKNOWN_NOTIFIER`].
+    pub notifier_id: u128,
+    /// The value of the [`EventId`] that was notified.
+    pub event_id: u64,
+    /// How often the [`EventId`] was notified since the previous record.
+    pub count: u64,
+}
+
+impl EventRecordHeader {
+    /// The size of the serialized [`EventRecordHeader`].
+    pub const SIZE: usize = core::mem::size_of::<Self>();
+
+    /// Creates a new [`EventRecordHeader`]. When the notifier is not known, [`None`] can be
+    /// provided.
+    pub fn new(notifier_id: Option<u128>, event_id: EventId, count: u64) -> Self {
+        Self {
+            notifier_id: notifier_id.unwrap_or(UNKNOWN_NOTIFIER),
+            event_id: event_id.as_value() as u64,
+            count,
+        }
+    }
+
+    /// Returns the [`ServiceTypes`] of a recorded event service.
+    pub fn service_types() -> ServiceTypes {
+        ServiceTypes {
+            payload: TypeDetail::new::<u8>(TypeVariant::Dynamic),
+            user_header: TypeDetail::new::<()>(TypeVariant::FixedSize),
+            system_header: TypeDetail::new::<EventRecordHeader>(TypeVariant::FixedSize),
+        }
+    }
+
+    /// Returns the [`EventId`] of the notification.
+    pub fn event_id(&self) -> EventId {
+        EventId::new(self.event_id as usize)
+    }
+
+    /// Returns the id of the notifier or [`None`] when it is unknown.
+    pub fn notifier_id(&self) -> Option<u128> {
+        (self.notifier_id != UNKNOWN_NOTIFIER).then_some(self.notifier_id)
+    }
+
+    /// Serializes the [`EventRecordHeader`] so that it can be used as system header of a
+    /// [`RawRecord`](crate::record::RawRecord).
+    pub fn to_bytes(&self) -> [u8; Self::SIZE] {
+        let mut bytes = [0u8; Self::SIZE];
+        bytes[0..16].copy_from_slice(&self.notifier_id.to_le_bytes());
+        bytes[16..24].copy_from_slice(&self.event_id.to_le_bytes());
+        bytes[24..32].copy_from_slice(&self.count.to_le_bytes());
+        bytes
+    }
+
+    /// Deserializes the [`EventRecordHeader`] from the system header of a
+    /// [`Record`](crate::record::Record). Returns [`None`] when the bytes do not contain
+    /// a valid [`EventRecordHeader`].
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != Self::SIZE {
+            return None;
+        }
+
+        Some(Self {
+            notifier_id: u128::from_le_bytes(bytes[0..16].try_into().ok()?),
+            event_id: u64::from_le_bytes(bytes[16..24].try_into().ok()?),
+            count: u64::from_le_bytes(bytes[24..32].try_into().ok()?),
+        })
+    }
+}
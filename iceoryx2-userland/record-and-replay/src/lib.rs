@@ -123,9 +123,21 @@
 
 extern crate alloc;
 
+/// Contains the [`BagRecorder`](crate::bag::BagRecorder) and
+/// [`BagReplayer`](crate::bag::BagReplayer) to capture multiple services into a single file.
+pub mod bag;
+
+/// Chunk-level compression of recorded files.
+pub mod compression;
+
+/// Defines how the notifications of an event service are stored in a record file.
+pub mod event;
+
 /// Free functions to convert bytes to a hex string and back.
 pub mod hex_conversion;
 
+mod mcap;
+
 /// Loads a meaninful subset.
 pub mod prelude;
 
@@ -136,9 +148,16 @@ pub mod record;
 /// The header of the record file which contains all necessary type information.
 pub mod record_header;
 
+/// The time index of the record file which allows seeking without reading all records.
+pub mod record_index;
+
 /// Contains the [`Recorder`](crate::recorder::Recorder) to write captured payload into a file.
 pub mod recorder;
 
+/// Defines how requests and responses are stored in a record file and how recorded
+/// responses are compared with the responses of a live server.
+pub mod request_response;
+
 /// Contains the [`Replayer`](crate::replayer::Replayer) to read captured payload from a file.
 pub mod replayer;
 
@@ -0,0 +1,652 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Minimal reader and writer of the [MCAP](https://mcap.dev/spec) container format.
+//!
+//! Every recorded service is stored as MCAP channel with the service name as topic. The
+//! [`ServiceTypes`], the response [`ServiceTypes`] of request-response services and the
+//! [`MessagingPattern`] are stored as TOML in the schema of the channel. A message contains the system header, the user header and the payload in this
+//! order. Channels that were not written by iceoryx2 are interpreted as publish-subscribe
+//! services with a dynamic `u8` payload and the message data as payload.
+//!
+//! Chunks are supported on the reader side as long as they are uncompressed or compressed
+//! with `zstd`. The writer writes an unchunked file without summary section.
+
+use alloc::borrow::Cow;
+use alloc::collections::VecDeque;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use iceoryx2::prelude::{MessagingPattern, ServiceName};
+use iceoryx2::service::header::publish_subscribe::Header;
+use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
+use iceoryx2_bb_elementary::package_version::PackageVersion;
+use iceoryx2_bb_posix::file::{File, FileWriteError};
+use iceoryx2_bb_posix::file_descriptor::FileDescriptorManagement;
+use iceoryx2_cal::serialize::Serialize;
+use iceoryx2_cal::serialize::toml::Toml;
+use iceoryx2_log::fail;
+
+use crate::compression::Compression;
+use crate::record::RawRecord;
+use crate::record_header::Version;
+use crate::recorder::{RecorderCreateError, RecorderWriteError, ServiceTypes};
+use crate::replayer::ReplayerOpenError;
+
+const MAGIC: &[u8] = b"\x89MCAP0\r\n";
+const LIBRARY_PREFIX: &str = "iceoryx2 ";
+const SCHEMA_ENCODING: &str = "iceoryx2-service-types";
+const MESSAGE_ENCODING: &str = "iceoryx2";
+
+const OP_HEADER: u8 = 0x01;
+const OP_FOOTER: u8 = 0x02;
+const OP_SCHEMA: u8 = 0x03;
+const OP_CHANNEL: u8 = 0x04;
+const OP_MESSAGE: u8 = 0x05;
+const OP_CHUNK: u8 = 0x06;
+const OP_DATA_END: u8 = 0x0F;
+/// Private record in the summary section that contains the
+/// [`RecordIndex`](crate::record_index::RecordIndex).
+const OP_RECORD_INDEX: u8 = 0x80;
+const FOOTER_RECORD_LEN: u64 = 1 + 8 + 20;
+
+#[derive(serde::Serialize, serde::Deserialize, Debug)]
+struct SchemaData {
+    messaging_pattern: MessagingPattern,
+    types: ServiceTypes,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    response_types: Option<ServiceTypes>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct McapChannel {
+    pub(crate) id: u16,
+    pub(crate) service_name: ServiceName,
+    pub(crate) messaging_pattern: MessagingPattern,
+    pub(crate) types: ServiceTypes,
+    pub(crate) response_types: Option<ServiceTypes>,
+    /// Defines if the channel was written by iceoryx2 and contains the system and user header
+    pub(crate) is_native: bool,
+}
+
+#[derive(Debug)]
+pub(crate) struct McapSummary {
+    pub(crate) iceoryx2_version: Version,
+    pub(crate) channels: Vec<McapChannel>,
+    pub(crate) file_size: u64,
+}
+
+#[derive(Debug)]
+pub(crate) struct McapMessage {
+    pub(crate) channel_id: u16,
+    pub(crate) log_time: u64,
+    pub(crate) data: Vec<u8>,
+    /// Defines if the data contains the system and user header in front of the payload
+    pub(crate) is_native: bool,
+}
+
+/// Returns the [`ServiceTypes`] that are used for channels that were not written by iceoryx2.
+pub(crate) fn foreign_service_types() -> ServiceTypes {
+    ServiceTypes {
+        payload: TypeDetail::new::<u8>(TypeVariant::Dynamic),
+        user_header: TypeDetail::new::<()>(TypeVariant::FixedSize),
+        system_header: TypeDetail::new::<Header>(TypeVariant::FixedSize),
+    }
+}
+
+fn put_u16(buffer: &mut Vec<u8>, value: u16) {
+    buffer.extend_from_slice(&value.to_le_bytes());
+}
+
+fn put_u32(buffer: &mut Vec<u8>, value: u32) {
+    buffer.extend_from_slice(&value.to_le_bytes());
+}
+
+fn put_u64(buffer: &mut Vec<u8>, value: u64) {
+    buffer.extend_from_slice(&value.to_le_bytes());
+}
+
+fn put_bytes(buffer: &mut Vec<u8>, value: &[u8]) {
+    put_u32(buffer, value.len() as u32);
+    buffer.extend_from_slice(value);
+}
+
+fn write_record(file: &mut File, opcode: u8, content: &[u8]) -> Result<(), FileWriteError> {
+    let mut buffer = Vec::with_capacity(9 + content.len());
+    buffer.push(opcode);
+    put_u64(&mut buffer, content.len() as u64);
+    buffer.extend_from_slice(content);
+    file.write(&buffer)?;
+    Ok(())
+}
+
+/// Writes the magic, the header and the schema and channel records of all channels.
+pub(crate) fn write_preamble(
+    file: &mut File,
+    channels: &[McapChannel],
+) -> Result<(), RecorderCreateError> {
+    let msg = "Unable to write MCAP preamble";
+    let origin = "mcap::write_preamble()";
+
+    let mut content = vec![];
+    put_bytes(&mut content, b"");
+    let version: Version = PackageVersion::get().into();
+    put_bytes(
+        &mut content,
+        format!(
+            "{LIBRARY_PREFIX}{}.{}.{}",
+            version.major, version.minor, version.patch
+        )
+        .as_bytes(),
+    );
+
+    fail!(from origin, when file.write(MAGIC),
+        with RecorderCreateError::UnableToWriteFile,
+        "{msg} since the magic could not be written.");
+    fail!(from origin, when write_record(file, OP_HEADER, &content),
+        with RecorderCreateError::UnableToWriteFile,
+        "{msg} since the header record could not be written.");
+
+    for channel in channels {
+        let schema_id = channel.id as u32 + 1;
+        let schema_data = fail!(from origin,
+            when Toml::serialize(&SchemaData {
+                messaging_pattern: channel.messaging_pattern,
+                types: channel.types.clone(),
+                response_types: channel.response_types.clone(),
+            }),
+            with RecorderCreateError::UnableToSerializeRecordHeader,
+            "{msg} since the service types of \"{}\" could not be serialized.", channel.service_name);
+
+        let mut content = vec![];
+        put_u16(&mut content, schema_id as u16);
+        put_bytes(
+            &mut content,
+            channel.types.payload.type_name().as_bytes_const(),
+        );
+        put_bytes(&mut content, SCHEMA_ENCODING.as_bytes());
+        put_bytes(&mut content, &schema_data);
+        fail!(from origin, when write_record(file, OP_SCHEMA, &content),
+            with RecorderCreateError::UnableToWriteFile,
+            "{msg} since the schema record could not be written.");
+
+        let mut content = vec![];
+        put_u16(&mut content, channel.id);
+        put_u16(&mut content, schema_id as u16);
+        put_bytes(&mut content, channel.service_name.as_str().as_bytes());
+        put_bytes(&mut content, MESSAGE_ENCODING.as_bytes());
+        put_u32(&mut content, 0);
+        fail!(from origin, when write_record(file, OP_CHANNEL, &content),
+            with RecorderCreateError::UnableToWriteFile,
+            "{msg} since the channel record could not be written.");
+    }
+
+    Ok(())
+}
+
+/// Writes a message record that contains the system header, user header and payload.
+pub(crate) fn write_message(
+    file: &mut File,
+    channel_id: u16,
+    sequence: u32,
+    record: &RawRecord,
+) -> Result<(), RecorderWriteError> {
+    let log_time = record.timestamp.as_nanos() as u64;
+    let mut content = Vec::with_capacity(
+        22 + record.system_header.len() + record.user_header.len() + record.payload.len(),
+    );
+    put_u16(&mut content, channel_id);
+    put_u32(&mut content, sequence);
+    put_u64(&mut content, log_time);
+    put_u64(&mut content, log_time);
+    content.extend_from_slice(record.system_header);
+    content.extend_from_slice(record.user_header);
+    content.extend_from_slice(record.payload);
+
+    if let Err(e) = write_record(file, OP_MESSAGE, &content) {
+        fail!(from "mcap::write_message()", with RecorderWriteError::FileWriteError(e),
+            "Failed to write MCAP message record into file ({e:?}).");
+    }
+
+    Ok(())
+}
+
+/// Finalizes the file with the data end record, the footer and the closing magic. When a
+/// record index is provided, it is stored in the summary section.
+pub(crate) fn write_trailer(
+    file: &mut File,
+    record_index: Option<&[u8]>,
+) -> Result<(), FileWriteError> {
+    write_record(file, OP_DATA_END, &0u32.to_le_bytes())?;
+
+    let mut summary_start = 0;
+    if let Some(record_index) = record_index {
+        // without a known summary start the index is not written, the reader falls back
+        // to scanning the records
+        if let Ok(metadata) = file.metadata() {
+            summary_start = metadata.size();
+            write_record(file, OP_RECORD_INDEX, record_index)?;
+        }
+    }
+
+    let mut content = vec![];
+    put_u64(&mut content, summary_start);
+    put_u64(&mut content, 0);
+    put_u32(&mut content, 0);
+    write_record(file, OP_FOOTER, &content)?;
+
+    file.write(MAGIC)?;
+    Ok(())
+}
+
+/// Reads the serialized [`RecordIndex`](crate::record_index::RecordIndex) from the summary
+/// section. Returns [`None`] when the file does not contain one.
+pub(crate) fn read_index(file: &File, size: u64) -> Result<Option<Vec<u8>>, ReplayerOpenError> {
+    let origin = "mcap::read_index()";
+    let msg = "Unable to read the record index";
+    let read_range = |start: u64, len: usize| -> Result<Option<Vec<u8>>, ReplayerOpenError> {
+        let mut buffer = vec![0u8; len];
+        let read_bytes = fail!(from origin, when file.read_range(start, &mut buffer),
+            with ReplayerOpenError::FailedToReadFile,
+            "{msg} since the file could not be read.");
+        Ok((read_bytes == len as u64).then_some(buffer))
+    };
+
+    let Some(footer_start) = size.checked_sub(FOOTER_RECORD_LEN + MAGIC.len() as u64) else {
+        return Ok(None);
+    };
+    let Some(footer) = read_range(footer_start, FOOTER_RECORD_LEN as usize)? else {
+        return Ok(None);
+    };
+    let mut cursor = Cursor { data: &footer };
+    let summary_start = match cursor.record() {
+        Some((OP_FOOTER, content)) => Cursor { data: content }.u64().unwrap_or(0),
+        _ => return Ok(None),
+    };
+    if summary_start == 0 || summary_start >= footer_start {
+        return Ok(None);
+    }
+
+    let Some(prefix) = read_range(summary_start, 9)? else {
+        return Ok(None);
+    };
+    if prefix[0] != OP_RECORD_INDEX {
+        return Ok(None);
+    }
+    let len = u64::from_le_bytes(prefix[1..].try_into().unwrap());
+    if len > (footer_start - summary_start).saturating_sub(9) {
+        return corrupted(msg);
+    }
+    read_range(summary_start + 9, len as usize)
+}
+
+struct Cursor<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Cursor<'a> {
+    fn bytes(&mut self, len: usize) -> Option<&'a [u8]> {
+        if self.data.len() < len {
+            return None;
+        }
+        let (value, rest) = self.data.split_at(len);
+        self.data = rest;
+        Some(value)
+    }
+
+    fn u16(&mut self) -> Option<u16> {
+        Some(u16::from_le_bytes(self.bytes(2)?.try_into().ok()?))
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        Some(u32::from_le_bytes(self.bytes(4)?.try_into().ok()?))
+    }
+
+    fn u64(&mut self) -> Option<u64> {
+        Some(u64::from_le_bytes(self.bytes(8)?.try_into().ok()?))
+    }
+
+    fn prefixed_bytes(&mut self) -> Option<&'a [u8]> {
+        let len = self.u32()? as usize;
+        self.bytes(len)
+    }
+
+    fn long_prefixed_bytes(&mut self) -> Option<&'a [u8]> {
+        let len = self.u64()? as usize;
+        self.bytes(len)
+    }
+
+    fn string(&mut self) -> Option<&'a str> {
+        core::str::from_utf8(self.prefixed_bytes()?).ok()
+    }
+
+    fn record(&mut self) -> Option<(u8, &'a [u8])> {
+        let opcode = self.bytes(1)?[0];
+        let len = self.u64()? as usize;
+        Some((opcode, self.bytes(len)?))
+    }
+
+    fn rest(&mut self) -> &'a [u8] {
+        core::mem::take(&mut self.data)
+    }
+}
+
+fn corrupted<T>(msg: &str) -> Result<T, ReplayerOpenError> {
+    fail!(from "mcap", with ReplayerOpenError::CorruptedContent,
+        "{msg} since the MCAP content is corrupted.");
+}
+
+/// Reads the next record from the file. Returns [`None`] when the end of the file is reached.
+/// Records that claim to be larger than the file are rejected before anything is allocated.
+fn read_record(file: &File, file_size: u64) -> Result<Option<(u8, Vec<u8>)>, ReplayerOpenError> {
+    let msg = "Unable to read next MCAP record";
+    let origin = "mcap::read_record()";
+    let mut prefix = [0u8; 9];
+    let len = fail!(from origin, when file.read(&mut prefix),
+        with ReplayerOpenError::FailedToReadFile,
+        "{msg} since the file could not be read.");
+
+    match len {
+        0 => return Ok(None),
+        9 => (),
+        // the closing magic is shorter than a record prefix
+        _ if prefix[..len as usize] == MAGIC[..len as usize] => return Ok(None),
+        _ => return corrupted(msg),
+    }
+
+    let content_len = u64::from_le_bytes(prefix[1..].try_into().unwrap());
+    if content_len > file_size {
+        return corrupted(msg);
+    }
+
+    let mut content = vec![0u8; content_len as usize];
+    let len = fail!(from origin, when file.read(&mut content),
+        with ReplayerOpenError::FailedToReadFile,
+        "{msg} since the file could not be read.");
+    if len != content.len() as u64 {
+        return corrupted(msg);
+    }
+
+    Ok(Some((prefix[0], content)))
+}
+
+fn chunk_records(content: &[u8]) -> Result<Cow<'_, [u8]>, ReplayerOpenError> {
+    let msg = "Unable to read MCAP chunk";
+    let mut cursor = Cursor { data: content };
+    let (Some(_), Some(_), Some(uncompressed_size), Some(_), Some(compression)) = (
+        cursor.u64(),
+        cursor.u64(),
+        cursor.u64(),
+        cursor.u32(),
+        cursor.string(),
+    ) else {
+        return corrupted(msg);
+    };
+    let Some(records) = cursor.long_prefixed_bytes() else {
+        return corrupted(msg);
+    };
+
+    match Compression::from_mcap(compression) {
+        Some(Compression::None) => Ok(Cow::Borrowed(records)),
+        Some(codec) => match codec.decompress(records, uncompressed_size as usize) {
+            Some(records) => Ok(Cow::Owned(records)),
+            None => corrupted(msg),
+        },
+        None => {
+            fail!(from "mcap::chunk_records()", with ReplayerOpenError::UnsupportedCompression,
+                "{msg} since the chunk is compressed with \"{compression}\" which is not supported.");
+        }
+    }
+}
+
+fn parse_message(content: &[u8]) -> Result<McapMessage, ReplayerOpenError> {
+    let mut cursor = Cursor { data: content };
+    let (Some(channel_id), Some(_), Some(log_time), Some(_)) =
+        (cursor.u16(), cursor.u32(), cursor.u64(), cursor.u64())
+    else {
+        return corrupted("Unable to read MCAP message");
+    };
+
+    Ok(McapMessage {
+        channel_id,
+        log_time,
+        data: cursor.rest().to_vec(),
+        is_native: true,
+    })
+}
+
+fn parse_version(library: &str) -> Option<Version> {
+    let mut parts = library.strip_prefix(LIBRARY_PREFIX)?.split('.');
+    Some(Version {
+        major: parts.next()?.parse().ok()?,
+        minor: parts.next()?.parse().ok()?,
+        patch: parts.next()?.parse().ok()?,
+    })
+}
+
+#[derive(Default)]
+struct SummaryBuilder {
+    iceoryx2_version: Option<Version>,
+    schemas: Vec<(u16, String, Vec<u8>)>,
+    channels: Vec<McapChannel>,
+}
+
+impl SummaryBuilder {
+    fn add_record(&mut self, opcode: u8, content: &[u8]) -> Result<(), ReplayerOpenError> {
+        let msg = "Unable to read MCAP summary";
+        let mut cursor = Cursor { data: content };
+        match opcode {
+            OP_HEADER => {
+                let (Some(_), Some(library)) = (cursor.string(), cursor.string()) else {
+                    return corrupted(msg);
+                };
+                self.iceoryx2_version = parse_version(library);
+            }
+            OP_SCHEMA => {
+                let (Some(id), Some(_), Some(encoding), Some(data)) = (
+                    cursor.u16(),
+                    cursor.string(),
+                    cursor.string(),
+                    cursor.prefixed_bytes(),
+                ) else {
+                    return corrupted(msg);
+                };
+                self.schemas
+                    .push((id, String::from(encoding), data.to_vec()));
+            }
+            OP_CHANNEL => {
+                let (Some(id), Some(schema_id), Some(topic), Some(message_encoding)) =
+                    (cursor.u16(), cursor.u16(), cursor.string(), cursor.string())
+                else {
+                    return corrupted(msg);
+                };
+
+                if self.channels.iter().any(|c| c.id == id) {
+                    return Ok(());
+                }
+
+                let service_name = fail!(from "mcap", when ServiceName::new(topic),
+                    with ReplayerOpenError::UnableToDeserializeRecordHeader,
+                    "{msg} since the topic \"{topic}\" is not a valid service name.");
+
+                let schema = self.schemas.iter().find(|(schema, encoding, _)| {
+                    *schema == schema_id && encoding == SCHEMA_ENCODING
+                });
+
+                let channel = match schema {
+                    Some((_, _, data)) if message_encoding == MESSAGE_ENCODING => {
+                        let schema_data = fail!(from "mcap", when Toml::deserialize::<SchemaData>(data),
+                            with ReplayerOpenError::UnableToDeserializeRecordHeader,
+                            "{msg} since the schema of \"{topic}\" could not be deserialized.");
+                        McapChannel {
+                            id,
+                            service_name,
+                            messaging_pattern: schema_data.messaging_pattern,
+                            types: schema_data.types,
+                            response_types: schema_data.response_types,
+                            is_native: true,
+                        }
+                    }
+                    _ => McapChannel {
+                        id,
+                        service_name,
+                        messaging_pattern: MessagingPattern::PublishSubscribe,
+                        types: foreign_service_types(),
+                        response_types: None,
+                        is_native: false,
+                    },
+                };
+                self.channels.push(channel);
+            }
+            _ => (),
+        }
+
+        Ok(())
+    }
+}
+
+/// Scans the whole file for schemas and channels and positions the file afterwards at the
+/// beginning of the data section.
+pub(crate) fn read_summary(file: &File) -> Result<McapSummary, ReplayerOpenError> {
+    let msg = "Unable to read MCAP summary";
+    let origin = "mcap::read_summary()";
+
+    let seek_to_data = || -> Result<(), ReplayerOpenError> {
+        fail!(from origin, when file.seek(MAGIC.len() as u64),
+            with ReplayerOpenError::FailedToReadFile,
+            "{msg} since the file offset could not be set.");
+        Ok(())
+    };
+
+    let mut magic = [0u8; 8];
+    let len = fail!(from origin, when file.read(&mut magic),
+        with ReplayerOpenError::FailedToReadFile,
+        "{msg} since the file could not be read.");
+    if len != MAGIC.len() as u64 || magic != MAGIC {
+        fail!(from origin, with ReplayerOpenError::UnableToDeserializeRecordHeader,
+            "{msg} since the file is not a MCAP file.");
+    }
+
+    let file_size = fail!(from origin, when file.metadata(),
+        with ReplayerOpenError::FailedToReadFile,
+        "{msg} since the file size could not be acquired.")
+    .size();
+
+    let mut summary = SummaryBuilder::default();
+    while let Some((opcode, content)) = read_record(file, file_size)? {
+        match opcode {
+            OP_DATA_END | OP_FOOTER => break,
+            OP_CHUNK => {
+                let records = chunk_records(&content)?;
+                let mut cursor = Cursor { data: &records };
+                while let Some((opcode, content)) = cursor.record() {
+                    summary.add_record(opcode, content)?;
+                }
+            }
+            _ => summary.add_record(opcode, &content)?,
+        }
+    }
+
+    seek_to_data()?;
+
+    Ok(McapSummary {
+        iceoryx2_version: summary.iceoryx2_version.unwrap_or(Version {
+            major: 0,
+            minor: 0,
+            patch: 0,
+        }),
+        channels: summary.channels,
+        file_size,
+    })
+}
+
+/// Reads the messages of a MCAP file one by one. The file must be positioned at the beginning
+/// of the data section, see [`read_summary()`].
+#[derive(Debug, Default)]
+pub(crate) struct McapReader {
+    foreign_channels: Vec<u16>,
+    file_size: u64,
+    pending: VecDeque<McapMessage>,
+    has_finished: bool,
+}
+
+impl McapReader {
+    pub(crate) fn new(summary: &McapSummary) -> Self {
+        Self {
+            foreign_channels: summary
+                .channels
+                .iter()
+                .filter(|channel| !channel.is_native)
+                .map(|channel| channel.id)
+                .collect(),
+            file_size: summary.file_size,
+            ..Self::default()
+        }
+    }
+
+    /// Discards all pending messages. Must be called whenever the file offset was changed.
+    pub(crate) fn reset(&mut self) {
+        self.pending.clear();
+        self.has_finished = false;
+    }
+
+    /// Returns the channel id of the next message without consuming it.
+    pub(crate) fn peek_channel_id(
+        &mut self,
+        file: &File,
+    ) -> Result<Option<u16>, ReplayerOpenError> {
+        if self.pending.is_empty() {
+            self.fill(file)?;
+        }
+
+        Ok(self.pending.front().map(|message| message.channel_id))
+    }
+
+    pub(crate) fn next_message(
+        &mut self,
+        file: &File,
+    ) -> Result<Option<McapMessage>, ReplayerOpenError> {
+        if self.pending.is_empty() {
+            self.fill(file)?;
+        }
+
+        Ok(self.pending.pop_front())
+    }
+
+    fn push(&mut self, mut message: McapMessage) {
+        message.is_native = !self.foreign_channels.contains(&message.channel_id);
+        self.pending.push_back(message);
+    }
+
+    fn fill(&mut self, file: &File) -> Result<(), ReplayerOpenError> {
+        while self.pending.is_empty() && !self.has_finished {
+            match read_record(file, self.file_size)? {
+                None | Some((OP_DATA_END | OP_FOOTER, _)) => self.has_finished = true,
+                Some((OP_MESSAGE, content)) => self.push(parse_message(&content)?),
+                Some((OP_CHUNK, content)) => {
+                    let records = chunk_records(&content)?;
+                    let mut cursor = Cursor { data: &records };
+                    while let Some((opcode, content)) = cursor.record() {
+                        if opcode == OP_MESSAGE {
+                            self.push(parse_message(content)?);
+                        }
+                    }
+                }
+                Some(_) => (),
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -10,7 +10,14 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+pub use crate::bag::{
+    BagChannel, BagHeader, BagRecord, BagRecorder, BagRecorderBuilder, BagReplayer,
+    BagReplayerOpener, ChannelId,
+};
+pub use crate::compression::Compression;
+pub use crate::event::EventRecordHeader;
 pub use crate::record::{DataRepresentation, RawRecord, Record};
 pub use crate::recorder::{RecorderBuilder, RecorderCreateError, RecorderWriteError, ServiceTypes};
 pub use crate::replayer::{Replayer, ReplayerOpenError, ReplayerOpener};
+pub use crate::request_response::{RequestResponseRecordHeader, diff_responses};
 pub use iceoryx2_bb_system_types::{file_name::FileName, file_path::FilePath};
@@ -24,9 +24,12 @@ use iceoryx2_log::fail;
 use anyhow::Result;
 
 use crate::{
+    compression::{ChunkReader, ChunkWriter},
     hex_conversion::{bytes_to_hex_string, hex_string_to_bytes},
+    mcap::{self, McapReader},
     record_header::RecordHeaderDetails,
-    recorder::RecorderWriteError,
+    record_index::{HUMAN_READABLE_INDEX_MARKER, IOX2_DUMP_END_OF_RECORDS},
+    recorder::{RecorderWriteError, ServiceTypes},
     replayer::ReplayerOpenError,
 };
 
@@ -40,6 +43,21 @@ pub enum DataRepresentation {
     #[default]
     /// Human Readable hex-codes for all payloads
     HumanReadable,
+    /// Standard [MCAP](https://mcap.dev) container that can be processed by third-party
+    /// tools. External MCAP files can be replayed as well.
+    Mcap,
+}
+
+impl DataRepresentation {
+    /// Returns the timestamp with the precision in which it is stored in the file.
+    pub(crate) fn stored_timestamp(&self, timestamp: Duration) -> Duration {
+        match self {
+            DataRepresentation::Mcap => Duration::from_nanos(timestamp.as_nanos() as u64),
+            DataRepresentation::HumanReadable | DataRepresentation::Iox2Dump => {
+                Duration::from_millis(timestamp.as_millis() as u64)
+            }
+        }
+    }
 }
 
 /// Represents a all the data required for a record captured by a receiver.
@@ -86,25 +104,35 @@ impl RecordReader {
         self
     }
 
-    fn verify_payload(&self, payload: &[u8], error_msg: &str) -> Result<(), ReplayerOpenError> {
-        if (self.header.types.payload.variant() == TypeVariant::FixedSize
-            && payload.len() != self.header.types.payload.size())
-            || (self.header.types.payload.variant() == TypeVariant::Dynamic
-                && payload.len() % self.header.types.payload.size() != 0)
+    fn verify_payload(
+        &self,
+        types: &ServiceTypes,
+        payload: &[u8],
+        error_msg: &str,
+    ) -> Result<(), ReplayerOpenError> {
+        if (types.payload.variant() == TypeVariant::FixedSize
+            && payload.len() != types.payload.size())
+            || (types.payload.variant() == TypeVariant::Dynamic
+                && payload.len() % types.payload.size() != 0)
         {
             fail!(from self, with ReplayerOpenError::CorruptedPayloadRecord,
                                 "{error_msg} since the payload record is corrupted (has wrong size {}, expected {}).",
-                                payload.len(), self.header.types.payload.size());
+                                payload.len(), types.payload.size());
         }
 
         Ok(())
     }
 
-    fn verify_user_header(&self, header: &[u8], error_msg: &str) -> Result<(), ReplayerOpenError> {
-        if header.len() != self.header.types.user_header.size() {
+    fn verify_user_header(
+        &self,
+        types: &ServiceTypes,
+        header: &[u8],
+        error_msg: &str,
+    ) -> Result<(), ReplayerOpenError> {
+        if header.len() != types.user_header.size() {
             fail!(from self, with ReplayerOpenError::CorruptedUserHeaderRecord,
                                 "{error_msg} since the system header record is corrupted (has wrong size {}, expected {}).",
-                                header.len(), self.header.types.user_header.size());
+                                header.len(), types.user_header.size());
         }
 
         Ok(())
@@ -112,22 +140,24 @@ impl RecordReader {
 
     fn verify_system_header(
         &self,
+        types: &ServiceTypes,
         header: &[u8],
         error_msg: &str,
     ) -> Result<(), ReplayerOpenError> {
-        if header.len() != self.header.types.system_header.size() {
+        if header.len() != types.system_header.size() {
             fail!(from self, with ReplayerOpenError::CorruptedSystemHeaderRecord,
                                 "{error_msg} since the system header record is corrupted (has wrong size {}, expected {}).",
-                                header.len(), self.header.types.system_header.size());
+                                header.len(), types.system_header.size());
         }
 
         Ok(())
     }
 
     fn verify_record(&self, record: &Record, error_msg: &str) -> Result<(), ReplayerOpenError> {
-        self.verify_payload(&record.payload, error_msg)?;
-        self.verify_user_header(&record.user_header, error_msg)?;
-        self.verify_system_header(&record.system_header, error_msg)?;
+        let types = self.header.types_of(&record.system_header);
+        self.verify_payload(types, &record.payload, error_msg)?;
+        self.verify_user_header(types, &record.user_header, error_msg)?;
+        self.verify_system_header(types, &record.system_header, error_msg)?;
         Ok(())
     }
 
@@ -144,6 +174,11 @@ impl RecordReader {
             match file.read_line_to_string(&mut line) {
                 Ok(FileReadLineState::EndOfFile(_)) => break,
                 Ok(FileReadLineState::LineLen(0)) => continue,
+                Ok(FileReadLineState::LineLen(_))
+                    if line.as_bytes() == HUMAN_READABLE_INDEX_MARKER =>
+                {
+                    break;
+                }
                 Ok(FileReadLineState::LineLen(n)) => {
                     if n < READABLE_PREFIX_LEN {
                         fail!(from self, with ReplayerOpenError::CorruptedContent,
@@ -182,12 +217,14 @@ impl RecordReader {
         Ok(None)
     }
 
-    fn read_iox2dump_from_file(&self, file: &File) -> Result<Option<Record>, ReplayerOpenError> {
+    fn read_iox2dump_from_file(
+        &self,
+        file: &File,
+        chunk_reader: &mut ChunkReader,
+    ) -> Result<Option<Record>, ReplayerOpenError> {
         let msg = "Unable to read next record";
-        let read = |buffer: &mut [u8]| {
-            let len = fail!(from self, when file.read(buffer),
-                with ReplayerOpenError::FailedToReadFile,
-                "{msg} since the underlying file could not be read.");
+        let mut read = |buffer: &mut [u8]| {
+            let len = chunk_reader.read(file, buffer)?;
 
             if len == 0 {
                 return Ok(false);
@@ -206,6 +243,9 @@ impl RecordReader {
             return Ok(None);
         }
         let timestamp = u64::from_le_bytes(buffer);
+        if timestamp == IOX2_DUMP_END_OF_RECORDS {
+            return Ok(None);
+        }
 
         read(&mut buffer)?;
         let system_header_len = u64::from_le_bytes(buffer);
@@ -233,10 +273,63 @@ impl RecordReader {
         Ok(Some(record))
     }
 
-    pub(crate) fn read(self, file: &File) -> Result<Option<Record>, ReplayerOpenError> {
+    fn read_mcap_from_file(
+        &self,
+        file: &File,
+        mcap_reader: &mut McapReader,
+    ) -> Result<Option<Record>, ReplayerOpenError> {
+        let msg = "Unable to read next record";
+        let message = match mcap_reader.next_message(file)? {
+            Some(message) => message,
+            None => return Ok(None),
+        };
+
+        let system_header_len = self.header.types.system_header.size();
+        let user_header_len = self.header.types.user_header.size();
+        let timestamp = Duration::from_nanos(message.log_time);
+        let record = if message.is_native {
+            if message.data.len() < system_header_len {
+                fail!(from self, with ReplayerOpenError::CorruptedContent,
+                    "{msg} since the MCAP message is too short to contain the system header.");
+            }
+
+            let mut system_header = message.data;
+            let mut user_header = system_header.split_off(system_header_len);
+            let user_header_len = self.header.types_of(&system_header).user_header.size();
+            if user_header.len() < user_header_len {
+                fail!(from self, with ReplayerOpenError::CorruptedContent,
+                    "{msg} since the MCAP message is too short to contain the user header.");
+            }
+            let payload = user_header.split_off(user_header_len);
+            Record {
+                timestamp,
+                system_header,
+                user_header,
+                payload,
+            }
+        } else {
+            Record {
+                timestamp,
+                system_header: vec![0u8; system_header_len],
+                user_header: vec![0u8; user_header_len],
+                payload: message.data,
+            }
+        };
+        self.verify_record(&record, msg)?;
+
+        Ok(Some(record))
+    }
+
+    pub(crate) fn read(
+        self,
+        file: &File,
+        mcap_reader: &mut McapReader,
+        chunk_reader: &mut ChunkReader,
+    ) -> Result<Option<Record>, ReplayerOpenError> {
         match self.data_representation {
             DataRepresentation::HumanReadable => self.read_human_readable_from_file(file),
-            DataRepresentation::Iox2Dump => self.read_iox2dump_from_file(file),
+            DataRepresentation::Iox2Dump => self.read_iox2dump_from_file(file, chunk_reader),
+            DataRepresentation::Mcap => self.read_mcap_from_file(file, mcap_reader),
         }
     }
 }
@@ -244,14 +337,20 @@ impl RecordReader {
 #[derive(Debug)]
 pub(crate) struct RecordWriter<'a> {
     file: &'a mut File,
+    chunk_writer: Option<&'a mut ChunkWriter>,
     data_representation: DataRepresentation,
+    mcap_channel_id: u16,
+    mcap_sequence: u32,
 }
 
 impl<'a> RecordWriter<'a> {
     pub(crate) fn new(file: &'a mut File) -> Self {
         Self {
             file,
+            chunk_writer: None,
             data_representation: DataRepresentation::default(),
+            mcap_channel_id: 0,
+            mcap_sequence: 0,
         }
     }
 
@@ -260,6 +359,21 @@ impl<'a> RecordWriter<'a> {
         self
     }
 
+    /// Collects the records in the provided [`ChunkWriter`] instead of writing them directly
+    /// into the file. Only relevant for [`DataRepresentation::Iox2Dump`].
+    pub(crate) fn chunk_writer(mut self, chunk_writer: Option<&'a mut ChunkWriter>) -> Self {
+        self.chunk_writer = chunk_writer;
+        self
+    }
+
+    /// Defines the MCAP channel and sequence number of the record. Only relevant for
+    /// [`DataRepresentation::Mcap`].
+    pub(crate) fn mcap_message(mut self, channel_id: u16, sequence: u32) -> Self {
+        self.mcap_channel_id = channel_id;
+        self.mcap_sequence = sequence;
+        self
+    }
+
     pub(crate) fn write(self, record: RawRecord) -> Result<(), RecorderWriteError> {
         let origin = format!("{self:?}");
         let mut write_to_file = |data| -> Result<(), RecorderWriteError> {
@@ -291,17 +405,26 @@ impl<'a> RecordWriter<'a> {
                 write_to_file(b"\n\n")?;
             }
             DataRepresentation::Iox2Dump => {
-                let time_stamp = (record.timestamp.as_millis() as u64).to_le_bytes();
-                write_to_file(&time_stamp)?;
-                let system_header_len = (record.system_header.len() as u64).to_le_bytes();
-                write_to_file(&system_header_len)?;
-                write_to_file(record.system_header)?;
-                let user_header_len = (record.user_header.len() as u64).to_le_bytes();
-                write_to_file(&user_header_len)?;
-                write_to_file(record.user_header)?;
-                let payload_len = (record.payload.len() as u64).to_le_bytes();
-                write_to_file(&payload_len)?;
-                write_to_file(record.payload)?;
+                let mut buffer = Vec::with_capacity(
+                    32 + record.system_header.len()
+                        + record.user_header.len()
+                        + record.payload.len(),
+                );
+                buffer.extend_from_slice(&(record.timestamp.as_millis() as u64).to_le_bytes());
+                buffer.extend_from_slice(&(record.system_header.len() as u64).to_le_bytes());
+                buffer.extend_from_slice(record.system_header);
+                buffer.extend_from_slice(&(record.user_header.len() as u64).to_le_bytes());
+                buffer.extend_from_slice(record.user_header);
+                buffer.extend_from_slice(&(record.payload.len() as u64).to_le_bytes());
+                buffer.extend_from_slice(record.payload);
+
+                match self.chunk_writer {
+                    Some(chunk_writer) => chunk_writer.write(self.file, &buffer)?,
+                    None => write_to_file(&buffer)?,
+                }
+            }
+            DataRepresentation::Mcap => {
+                mcap::write_message(self.file, self.mcap_channel_id, self.mcap_sequence, &record)?;
             }
         }
 
@@ -13,13 +13,18 @@
 use iceoryx2::prelude::{MessagingPattern, ServiceName};
 use iceoryx2_bb_elementary::package_version::PackageVersion;
 
+use crate::compression::Compression;
 use crate::recorder::ServiceTypes;
+use crate::request_response::{RequestResponseRecordHeader, RequestResponseRecordKind};
 
 /// Defines the current file format version of the human readable format
-pub const FILE_FORMAT_HUMAN_READABLE_VERSION: u64 = 1;
+pub const FILE_FORMAT_HUMAN_READABLE_VERSION: u64 = 2;
 
 /// Defines the current file format version of the iox2dump version
-pub const FILE_FORMAT_IOX2_DUMP_VERSION: u64 = 1;
+pub const FILE_FORMAT_IOX2_DUMP_VERSION: u64 = 4;
+
+/// Defines the current file format version of the mcap version
+pub const FILE_FORMAT_MCAP_VERSION: u64 = 1;
 
 #[repr(C)]
 #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Clone)]
@@ -49,10 +54,35 @@ impl From<PackageVersion> for Version {
 pub struct RecordHeaderDetails {
     /// Defines the file format version
     pub file_format_version: u64,
-    /// The types to which the stored payload corresponds.
+    /// The types to which the stored payload corresponds. For request-response services
+    /// these are the types of the requests.
     pub types: ServiceTypes,
+    /// The types of the responses. Only set for request-response services.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub response_types: Option<ServiceTypes>,
     /// The messaging pattern of the recorded service.
     pub messaging_pattern: MessagingPattern,
+    /// The codec with which the records are compressed.
+    #[serde(default, skip_serializing_if = "Compression::is_none")]
+    pub compression: Compression,
+}
+
+impl RecordHeaderDetails {
+    /// Returns the [`ServiceTypes`] of a record with the provided system header. Responses of
+    /// request-response services use the
+    /// [`RecordHeaderDetails::response_types`], all other records the
+    /// [`RecordHeaderDetails::types`].
+    pub fn types_of(&self, system_header: &[u8]) -> &ServiceTypes {
+        match (&self.response_types, self.messaging_pattern) {
+            (Some(response_types), MessagingPattern::RequestResponse)
+                if RequestResponseRecordHeader::from_bytes(system_header)
+                    .is_some_and(|header| header.kind == RequestResponseRecordKind::Response) =>
+            {
+                response_types
+            }
+            _ => &self.types,
+        }
+    }
 }
 
 #[repr(C)]
@@ -0,0 +1,232 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time::Duration;
+
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use iceoryx2_bb_posix::file::File;
+use iceoryx2_bb_posix::file_descriptor::FileDescriptorManagement;
+use iceoryx2_cal::serialize::Serialize;
+use iceoryx2_cal::serialize::toml::Toml;
+use iceoryx2_log::fail;
+
+use crate::mcap;
+use crate::record::DataRepresentation;
+use crate::recorder::RecorderWriteError;
+use crate::replayer::ReplayerOpenError;
+
+/// The default recorded time between two [`RecordIndexEntry`]s.
+pub const DEFAULT_INDEX_INTERVAL: Duration = Duration::from_secs(1);
+
+pub(crate) const HUMAN_READABLE_INDEX_MARKER: &[u8] = b"### Recorded Data Index ###";
+const HUMAN_READABLE_INDEX_SIZE_PREFIX: &str = "### Index Size: ";
+const HUMAN_READABLE_INDEX_SIZE_SUFFIX: &str = " ###\n";
+const HUMAN_READABLE_INDEX_SIZE_DIGITS: usize = 20;
+const HUMAN_READABLE_TRAILER_LEN: usize = HUMAN_READABLE_INDEX_SIZE_PREFIX.len()
+    + HUMAN_READABLE_INDEX_SIZE_DIGITS
+    + HUMAN_READABLE_INDEX_SIZE_SUFFIX.len();
+
+/// Timestamp that marks the end of the records in an iox2dump file.
+pub(crate) const IOX2_DUMP_END_OF_RECORDS: u64 = u64::MAX;
+const IOX2_DUMP_INDEX_MAGIC: &[u8; 8] = b"IOX2IDX\0";
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Eq, Clone, Copy)]
+/// Points to the position of a record inside the recorded file.
+pub struct RecordIndexEntry {
+    /// The timestamp of the record.
+    pub timestamp: Duration,
+    /// The byte offset of the record in the file.
+    pub offset: u64,
+    /// The number of records that are stored in front of the record.
+    pub record_number: u64,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Eq, Clone, Default)]
+/// The time index of a recorded file. It is written by the
+/// [`Recorder`](crate::recorder::Recorder) into the footer of the file and allows the
+/// [`Replayer`](crate::replayer::Replayer) to seek and to provide statistics without
+/// reading all records.
+pub struct RecordIndex {
+    /// The total number of records in the file.
+    pub number_of_records: u64,
+    /// The timestamp of the first record.
+    pub first_timestamp: Duration,
+    /// The timestamp of the last record.
+    pub last_timestamp: Duration,
+    /// Sparse list of record positions, ordered by time.
+    pub entries: Vec<RecordIndexEntry>,
+}
+
+impl RecordIndex {
+    /// Returns the time between the first and the last record.
+    pub fn duration(&self) -> Duration {
+        self.last_timestamp.saturating_sub(self.first_timestamp)
+    }
+
+    /// Returns the last [`RecordIndexEntry`] that is not newer than the provided timestamp.
+    pub fn entry_before(&self, timestamp: Duration) -> Option<&RecordIndexEntry> {
+        self.entries
+            .iter()
+            .rev()
+            .find(|entry| entry.timestamp <= timestamp)
+    }
+
+    pub(crate) fn is_entry_due(&self, timestamp: Duration, interval: Duration) -> bool {
+        match self.entries.last() {
+            Some(entry) => entry.timestamp + interval <= timestamp,
+            None => true,
+        }
+    }
+
+    pub(crate) fn add_record(&mut self, timestamp: Duration) {
+        if self.number_of_records == 0 {
+            self.first_timestamp = timestamp;
+        }
+        self.last_timestamp = timestamp;
+        self.number_of_records += 1;
+    }
+}
+
+/// Returns the current size of the file which is the offset of the next written record.
+pub(crate) fn current_offset(file: &File) -> Result<u64, RecorderWriteError> {
+    match file.metadata() {
+        Ok(metadata) => Ok(metadata.size()),
+        Err(e) => {
+            fail!(from "record_index::current_offset()", with RecorderWriteError::UnableToWriteIndex,
+                "Unable to acquire the current file offset for the record index ({e:?}).");
+        }
+    }
+}
+
+/// Terminates the records and appends the [`RecordIndex`] as footer to the file.
+pub(crate) fn write_footer(
+    file: &mut File,
+    data_representation: DataRepresentation,
+    index: &RecordIndex,
+) -> Result<(), RecorderWriteError> {
+    let msg = "Unable to write record index";
+    let origin = "record_index::write_footer()";
+    let serialized = fail!(from origin, when Toml::serialize(index),
+        with RecorderWriteError::UnableToWriteIndex,
+        "{msg} since the index could not be serialized.");
+
+    let write_to_file = |file: &mut File, data: &[u8]| -> Result<(), RecorderWriteError> {
+        if let Err(e) = file.write(data) {
+            fail!(from origin, with RecorderWriteError::FileWriteError(e),
+                "{msg} since the file could not be written ({e:?}).");
+        }
+        Ok(())
+    };
+
+    match data_representation {
+        DataRepresentation::HumanReadable => {
+            write_to_file(file, b"\n")?;
+            write_to_file(file, HUMAN_READABLE_INDEX_MARKER)?;
+            write_to_file(file, b"\n")?;
+            write_to_file(file, &serialized)?;
+            write_to_file(
+                file,
+                format!(
+                    "\n{HUMAN_READABLE_INDEX_SIZE_PREFIX}{:0width$}{HUMAN_READABLE_INDEX_SIZE_SUFFIX}",
+                    serialized.len(),
+                    width = HUMAN_READABLE_INDEX_SIZE_DIGITS
+                )
+                .as_bytes(),
+            )?;
+        }
+        DataRepresentation::Iox2Dump => {
+            write_to_file(file, &IOX2_DUMP_END_OF_RECORDS.to_le_bytes())?;
+            write_to_file(file, &serialized)?;
+            write_to_file(file, &(serialized.len() as u64).to_le_bytes())?;
+            write_to_file(file, IOX2_DUMP_INDEX_MAGIC)?;
+        }
+        DataRepresentation::Mcap => {
+            if let Err(e) = mcap::write_trailer(file, Some(&serialized)) {
+                fail!(from origin, with RecorderWriteError::FileWriteError(e),
+                    "{msg} since the MCAP trailer could not be written ({e:?}).");
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn read_exact(file: &File, start: u64, len: usize) -> Result<Option<Vec<u8>>, ReplayerOpenError> {
+    let mut buffer = vec![0u8; len];
+    let read_bytes = fail!(from "record_index::read_exact()", when file.read_range(start, &mut buffer),
+        with ReplayerOpenError::FailedToReadFile,
+        "Unable to read the record index since the file could not be read.");
+
+    Ok((read_bytes == len as u64).then_some(buffer))
+}
+
+/// Reads the [`RecordIndex`] from the footer of the file. If the file has no footer, for
+/// instance since the recording was interrupted, [`None`] is returned. The file offset is
+/// undefined afterwards.
+pub(crate) fn read_footer(
+    file: &File,
+    data_representation: DataRepresentation,
+) -> Result<Option<RecordIndex>, ReplayerOpenError> {
+    let size = fail!(from "record_index::read_footer()", when file.metadata(),
+        with ReplayerOpenError::FailedToReadFile,
+        "Unable to read the record index since the file size could not be acquired.")
+    .size();
+
+    let serialized = match data_representation {
+        DataRepresentation::HumanReadable => {
+            let Some(trailer_start) = size.checked_sub(HUMAN_READABLE_TRAILER_LEN as u64) else {
+                return Ok(None);
+            };
+            let Some(trailer) = read_exact(file, trailer_start, HUMAN_READABLE_TRAILER_LEN)? else {
+                return Ok(None);
+            };
+            let len = core::str::from_utf8(&trailer)
+                .ok()
+                .and_then(|v| v.strip_prefix(HUMAN_READABLE_INDEX_SIZE_PREFIX))
+                .and_then(|v| v.strip_suffix(HUMAN_READABLE_INDEX_SIZE_SUFFIX))
+                .and_then(|v| v.parse::<u64>().ok());
+            // the index is followed by a line break in front of the trailer
+            match len.and_then(|len| trailer_start.checked_sub(len + 1).map(|s| (s, len))) {
+                Some((start, len)) => read_exact(file, start, len as usize)?,
+                None => None,
+            }
+        }
+        DataRepresentation::Iox2Dump => {
+            let Some(trailer_start) = size.checked_sub(16) else {
+                return Ok(None);
+            };
+            let Some(trailer) = read_exact(file, trailer_start, 16)? else {
+                return Ok(None);
+            };
+            if &trailer[8..] != IOX2_DUMP_INDEX_MAGIC {
+                return Ok(None);
+            }
+            let len = u64::from_le_bytes(trailer[..8].try_into().unwrap());
+            match trailer_start.checked_sub(len) {
+                Some(start) => read_exact(file, start, len as usize)?,
+                None => None,
+            }
+        }
+        DataRepresentation::Mcap => mcap::read_index(file, size)?,
+    };
+
+    match serialized {
+        Some(serialized) => Ok(Some(fail!(from "record_index::read_footer()",
+            when Toml::deserialize::<RecordIndex>(&serialized),
+            with ReplayerOpenError::CorruptedContent,
+            "Unable to read the record index since it could not be deserialized."))),
+        None => Ok(None),
+    }
+}
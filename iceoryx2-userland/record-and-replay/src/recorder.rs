@@ -51,6 +51,8 @@
 //! # }
 //! ```
 
+use core::time::Duration;
+
 use alloc::format;
 
 use iceoryx2::prelude::{MessagingPattern, ServiceName};
@@ -61,15 +63,18 @@ use iceoryx2_bb_posix::file::{File, FileBuilder};
 use iceoryx2_bb_system_types::file_path::FilePath;
 use iceoryx2_cal::serialize::Serialize;
 use iceoryx2_cal::serialize::toml::Toml;
-use iceoryx2_log::fail;
+use iceoryx2_log::{fail, warn};
 
+use crate::compression::{ChunkWriter, Compression, DEFAULT_CHUNK_SIZE, MAX_CHUNK_SIZE};
+use crate::mcap::{self, McapChannel};
 use crate::record::HEX_START_RECORD_MARKER;
 use crate::record::RecordWriter;
 use crate::record::{DataRepresentation, RawRecord};
 use crate::record_header::{
-    FILE_FORMAT_HUMAN_READABLE_VERSION, FILE_FORMAT_IOX2_DUMP_VERSION, RecordHeader,
-    RecordHeaderDetails,
+    FILE_FORMAT_HUMAN_READABLE_VERSION, FILE_FORMAT_IOX2_DUMP_VERSION, FILE_FORMAT_MCAP_VERSION,
+    RecordHeader, RecordHeaderDetails,
 };
+use crate::record_index::{self, DEFAULT_INDEX_INTERVAL, RecordIndex, RecordIndexEntry};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 /// Errors that can occur when a new [`Recorder`] is created with
@@ -83,6 +88,10 @@ pub enum RecorderCreateError {
     UnableToWriteFile,
     /// The record header could not be serialized.
     UnableToSerializeRecordHeader,
+    /// The same service with the same messaging pattern was added more than once to a bag file.
+    DuplicateChannel,
+    /// The [`Compression`] is not supported by the [`DataRepresentation`].
+    UnsupportedCompression,
 }
 
 impl core::fmt::Display for RecorderCreateError {
@@ -107,6 +116,13 @@ pub enum RecorderWriteError {
     /// The record was older than the previously stored record. All records must have a
     /// monotonic timestamp - no time backward jumps.
     TimestampOlderThanPreviousRecord,
+    /// The record was written into a channel that is not part of the bag file.
+    UnknownChannel,
+    /// The [`RecordIndex`] could not be updated or written.
+    UnableToWriteIndex,
+    /// The record is larger than [`MAX_CHUNK_SIZE`](crate::compression::MAX_CHUNK_SIZE)
+    /// and cannot be stored in a compressed chunk.
+    RecordExceedsMaxChunkSize,
 }
 
 impl core::fmt::Display for RecorderWriteError {
@@ -128,12 +144,52 @@ pub struct ServiceTypes {
     pub system_header: TypeDetail,
 }
 
+impl ServiceTypes {
+    pub(crate) fn verify_raw_record(&self, record: &RawRecord) -> Result<(), RecorderWriteError> {
+        let msg = "Unable to write new record";
+
+        if record.system_header.len() != self.system_header.size() {
+            fail!(from self, with RecorderWriteError::CorruptedSystemHeaderRecord,
+                "{msg} since the system header entry is corrupted. Expected a size of {} but provided a size of {}.",
+                self.system_header.size(), record.system_header.len());
+        }
+
+        if record.user_header.len() != self.user_header.size() {
+            fail!(from self, with RecorderWriteError::CorruptedUserHeaderRecord,
+                "{msg} since the user header entry is corrupted. Expected a size of {} but provided a size of {}.",
+                self.user_header.size(), record.user_header.len());
+        }
+
+        if self.payload.variant() == TypeVariant::FixedSize
+            && record.payload.len() != self.payload.size()
+        {
+            fail!(from self, with RecorderWriteError::CorruptedPayloadRecord,
+                "{msg} since the payload entry is corrupted. Expected a size of {} but provided a size of {}.",
+                self.payload.size(), record.payload.len());
+        }
+
+        if self.payload.variant() == TypeVariant::Dynamic
+            && record.payload.len() % self.payload.size() != 0
+        {
+            fail!(from self, with RecorderWriteError::CorruptedPayloadRecord,
+                "{msg} since the payload entry is corrupted. Expected a size which is a multiple of {} but provided a size of {}.",
+                self.payload.size(), record.payload.len());
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Debug)]
 /// Builder to create a new [`Recorder`].
 pub struct RecorderBuilder {
     types: ServiceTypes,
+    response_types: Option<ServiceTypes>,
     data_representation: DataRepresentation,
     messaging_pattern: MessagingPattern,
+    index_interval: Duration,
+    compression: Compression,
+    chunk_size: usize,
 }
 
 impl RecorderBuilder {
@@ -141,11 +197,39 @@ impl RecorderBuilder {
     pub fn new(types: &ServiceTypes) -> Self {
         Self {
             types: types.clone(),
+            response_types: None,
             data_representation: DataRepresentation::default(),
             messaging_pattern: MessagingPattern::PublishSubscribe,
+            index_interval: DEFAULT_INDEX_INTERVAL,
+            compression: Compression::default(),
+            chunk_size: DEFAULT_CHUNK_SIZE,
         }
     }
 
+    /// Defines the recorded time between two [`RecordIndexEntry`]s. A smaller interval
+    /// increases the size of the [`RecordIndex`] but reduces the number of records that
+    /// must be read when seeking.
+    pub fn index_interval(mut self, value: Duration) -> Self {
+        self.index_interval = value;
+        self
+    }
+
+    /// Defines the [`Compression`] of the recorded records. Only supported by
+    /// [`DataRepresentation::Iox2Dump`]. See [`compression`](crate::compression) for details.
+    pub fn compression(mut self, value: Compression) -> Self {
+        self.compression = value;
+        self
+    }
+
+    /// Defines the uncompressed size of a compressed chunk in bytes. Larger chunks achieve a
+    /// better compression ratio but the records of the last chunk are lost when the
+    /// [`Recorder`] is not dropped, for instance when the process crashes. The value is
+    /// limited to [`MAX_CHUNK_SIZE`].
+    pub fn chunk_size(mut self, value: usize) -> Self {
+        self.chunk_size = value.min(MAX_CHUNK_SIZE);
+        self
+    }
+
     /// Defines the data representation of the file content the [`Recorder`] will create.
     pub fn data_representation(mut self, value: DataRepresentation) -> Self {
         self.data_representation = value;
@@ -158,6 +242,14 @@ impl RecorderBuilder {
         self
     }
 
+    /// Defines the [`ServiceTypes`] of the responses when a request-response service is
+    /// recorded. The [`ServiceTypes`] provided in [`RecorderBuilder::new()`] are used for the
+    /// requests. See [`request_response`](crate::request_response) for details.
+    pub fn response_types(mut self, value: &ServiceTypes) -> Self {
+        self.response_types = Some(value.clone());
+        self
+    }
+
     /// Creates a new file with and writes the record header into it. On failure
     /// [`RecorderCreateError`] is returned describing the error.
     pub fn create(
@@ -166,6 +258,14 @@ impl RecorderBuilder {
         service_name: &ServiceName,
     ) -> Result<Recorder, RecorderCreateError> {
         let msg = format!("Unable to create file recorder for \"{file_name}\"");
+        if !self.compression.is_none()
+            && !matches!(self.data_representation, DataRepresentation::Iox2Dump)
+        {
+            fail!(from self, with RecorderCreateError::UnsupportedCompression,
+                "{msg} since the compression {:?} is not supported by the data representation {:?}.",
+                self.compression, self.data_representation);
+        }
+
         let mut file = match FileBuilder::new(file_name)
             .has_ownership(false)
             .creation_mode(CreationMode::CreateExclusive)
@@ -189,9 +289,12 @@ impl RecorderBuilder {
                 file_format_version: match self.data_representation {
                     DataRepresentation::HumanReadable => FILE_FORMAT_HUMAN_READABLE_VERSION,
                     DataRepresentation::Iox2Dump => FILE_FORMAT_IOX2_DUMP_VERSION,
+                    DataRepresentation::Mcap => FILE_FORMAT_MCAP_VERSION,
                 },
                 types: self.types.clone(),
+                response_types: self.response_types.clone(),
                 messaging_pattern: self.messaging_pattern,
+                compression: self.compression,
             },
         };
         self.write_header(&mut file, &header, self.data_representation)?;
@@ -201,6 +304,11 @@ impl RecorderBuilder {
             header,
             data_representation: self.data_representation,
             last_timestamp: 0,
+            sequence: 0,
+            index: RecordIndex::default(),
+            index_interval: self.index_interval,
+            chunk_writer: (!self.compression.is_none())
+                .then(|| ChunkWriter::new(self.compression, self.chunk_size)),
         })
     }
 
@@ -213,6 +321,17 @@ impl RecorderBuilder {
         match data_representation {
             DataRepresentation::HumanReadable => self.write_hex_header(file, file_header),
             DataRepresentation::Iox2Dump => self.write_iox2dump_header(file, file_header),
+            DataRepresentation::Mcap => mcap::write_preamble(
+                file,
+                &[McapChannel {
+                    id: 0,
+                    service_name: file_header.service_name,
+                    messaging_pattern: file_header.details.messaging_pattern,
+                    types: file_header.details.types.clone(),
+                    response_types: file_header.details.response_types.clone(),
+                    is_native: true,
+                }],
+            ),
         }
     }
 
@@ -279,40 +398,37 @@ pub struct Recorder {
     data_representation: DataRepresentation,
     header: RecordHeader,
     last_timestamp: u64,
+    sequence: u32,
+    index: RecordIndex,
+    index_interval: Duration,
+    chunk_writer: Option<ChunkWriter>,
 }
 
-impl Recorder {
-    /// Writes a captured record into the file.
-    pub fn write(&mut self, record: RawRecord) -> Result<(), RecorderWriteError> {
-        let msg = "Unable to write new record";
-
-        if record.system_header.len() != self.header.details.types.system_header.size() {
-            fail!(from self, with RecorderWriteError::CorruptedSystemHeaderRecord,
-                "{msg} since the system header entry is corrupted. Expected a size of {} but provided a size of {}.",
-                self.header.details.types.system_header.size(), record.system_header.len());
-        }
-
-        if record.user_header.len() != self.header.details.types.user_header.size() {
-            fail!(from self, with RecorderWriteError::CorruptedUserHeaderRecord,
-                "{msg} since the user header entry is corrupted. Expected a size of {} but provided a size of {}.",
-                self.header.details.types.user_header.size(), record.user_header.len());
+impl Drop for Recorder {
+    fn drop(&mut self) {
+        if let Some(chunk_writer) = &mut self.chunk_writer {
+            if let Err(e) = chunk_writer.flush(&mut self.file) {
+                warn!(from self, "Unable to write the last compressed chunk into the file ({e:?}).");
+            }
         }
 
-        if self.header.details.types.payload.variant() == TypeVariant::FixedSize
-            && record.payload.len() != self.header.details.types.payload.size()
+        if let Err(e) =
+            record_index::write_footer(&mut self.file, self.data_representation, &self.index)
         {
-            fail!(from self, with RecorderWriteError::CorruptedPayloadRecord,
-                "{msg} since the payload entry is corrupted. Expected a size of {} but provided a size of {}.",
-                self.header.details.types.payload.size(), record.payload.len());
+            warn!(from self, "Unable to write the record index into the file ({e:?}).");
         }
+    }
+}
 
-        if self.header.details.types.payload.variant() == TypeVariant::Dynamic
-            && record.payload.len() % self.header.details.types.payload.size() != 0
-        {
-            fail!(from self, with RecorderWriteError::CorruptedPayloadRecord,
-                "{msg} since the payload entry is corrupted. Expected a size which is a multiple of {} but provided a size of {}.",
-                self.header.details.types.payload.size(), record.payload.len());
-        }
+impl Recorder {
+    /// Writes a captured record into the file.
+    pub fn write(&mut self, record: RawRecord) -> Result<(), RecorderWriteError> {
+        let msg = "Unable to write new record";
+
+        self.header
+            .details
+            .types_of(record.system_header)
+            .verify_raw_record(&record)?;
 
         let new_timestamp = record.timestamp.as_millis() as u64;
         if self.last_timestamp > new_timestamp {
@@ -325,13 +441,38 @@ impl Recorder {
     }
 
     pub(crate) fn write_unchecked(&mut self, record: RawRecord) -> Result<(), RecorderWriteError> {
+        let timestamp = self.data_representation.stored_timestamp(record.timestamp);
+        if self.index.is_entry_due(timestamp, self.index_interval) {
+            // an indexed record starts a new chunk so that the offset points exactly to it
+            if let Some(chunk_writer) = self.chunk_writer.as_mut() {
+                chunk_writer.flush(&mut self.file)?;
+            }
+            self.index.entries.push(RecordIndexEntry {
+                timestamp,
+                offset: record_index::current_offset(&self.file)?,
+                record_number: self.index.number_of_records,
+            });
+        }
+
         RecordWriter::new(&mut self.file)
             .data_representation(self.data_representation)
-            .write(record)
+            .chunk_writer(self.chunk_writer.as_mut())
+            .mcap_message(0, self.sequence)
+            .write(record)?;
+        self.sequence = self.sequence.wrapping_add(1);
+        self.index.add_record(timestamp);
+
+        Ok(())
     }
 
     /// Returns the [`RecordHeader`] of the underlying file.
     pub fn header(&self) -> &RecordHeader {
         &self.header
     }
+
+    /// Returns the [`RecordIndex`] of all records written so far. It is stored in the footer
+    /// of the file when the [`Recorder`] goes out of scope.
+    pub fn index(&self) -> &RecordIndex {
+        &self.index
+    }
 }
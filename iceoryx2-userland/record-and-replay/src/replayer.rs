@@ -71,8 +71,34 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! ### Replay A Time Window
+//!
+//! The [`RecordIndex`] in the footer of the file is used to seek to the start of the window
+//! without reading the records in front of it.
+//!
+//! ```no_run
+//! use core::time::Duration;
+//! use iceoryx2::prelude::*;
+//! use iceoryx2_userland_record_and_replay::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//!
+//! let replayer = ReplayerOpener::new(&FilePath::new(b"recorded_data.iox2")?)
+//!     .data_representation(DataRepresentation::HumanReadable)
+//!     .open()?;
+//!
+//! println!("{} records over {:?}", replayer.number_of_records(), replayer.duration());
+//!
+//! let buffer = replayer
+//!     .read_range_into_buffer(Duration::from_secs(10), Duration::from_secs(20))?;
+//!
+//! # Ok(())
+//! # }
+//! ```
 
 use core::mem::MaybeUninit;
+use core::time::Duration;
 
 use alloc::vec;
 use alloc::vec::Vec;
@@ -86,12 +112,15 @@ use iceoryx2_cal::serialize::Serialize;
 use iceoryx2_cal::serialize::toml::Toml;
 use iceoryx2_log::fail;
 
+use crate::compression::{ChunkReader, Compression};
 use crate::hex_conversion::HexToBytesConversionError;
+use crate::mcap::{self, McapReader};
 use crate::record::DataRepresentation;
 use crate::record::HEX_START_RECORD_MARKER;
 use crate::record::Record;
 use crate::record::RecordReader;
-use crate::record_header::RecordHeader;
+use crate::record_header::{FILE_FORMAT_MCAP_VERSION, RecordHeader, RecordHeaderDetails};
+use crate::record_index::{self, RecordIndex};
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 /// Failures that can occur when a recorded file is opened with [`ReplayerOpener::open()`]
@@ -117,6 +146,13 @@ pub enum ReplayerOpenError {
     CorruptedContent,
     /// The file contains records that jump back and forth in time.
     CorruptedTimeline,
+    /// The file contains a record of a channel that is not defined in the bag header.
+    UnknownChannel,
+    /// The file contains chunks that are compressed with an unsupported codec.
+    UnsupportedCompression,
+    /// The file contains more than one channel and must be opened with the
+    /// [`BagReplayerOpener`](crate::bag::BagReplayerOpener).
+    MultipleChannels,
 }
 
 impl From<HexToBytesConversionError> for ReplayerOpenError {
@@ -172,53 +208,76 @@ impl ReplayerOpener {
             }
         };
 
-        let actual_header = Self::read_header(&mut file, self.data_representation)?;
+        let stored_index = record_index::read_footer(&file, self.data_representation)?;
+        fail!(from self, when file.seek(0),
+            with ReplayerOpenError::FailedToReadFile,
+            "{msg} since the file offset could not be reset after reading the record index.");
+        let (header, mcap_reader) = Self::read_header(&mut file, self.data_representation)?;
 
-        Ok(Replayer {
+        let mut replayer = Replayer {
             file,
             data_representation: self.data_representation,
-            header: actual_header.clone(),
+            chunk_reader: ChunkReader::new(header.details.compression),
+            header,
             last_timestamp: 0,
-        })
+            mcap_reader,
+            index: stored_index.clone().unwrap_or_default(),
+            pending_record: None,
+            has_reached_end: false,
+        };
+
+        // files without footer, for instance from an interrupted recording, are scanned once.
+        // Corrupted records are reported when they are read, the index covers only the
+        // records in front of them.
+        if stored_index.is_none() {
+            while let Ok(Some(record)) = replayer.next_record() {
+                replayer.index.add_record(record.timestamp);
+            }
+            replayer.rewind()?;
+        }
+
+        Ok(replayer)
     }
 
     fn read_header(
         file: &mut File,
         data_representation: DataRepresentation,
-    ) -> Result<RecordHeader, ReplayerOpenError> {
+    ) -> Result<(RecordHeader, McapReader), ReplayerOpenError> {
         let msg = "Unable to read record file header";
         let origin = "read_header()";
 
         match data_representation {
-            DataRepresentation::HumanReadable => {
-                let mut buffer: Vec<u8> = vec![];
-                let mut buffer_position = 0;
-
-                loop {
-                    let line_length = fail!(from origin, when file.read_line_to_vector(&mut buffer),
-                            with ReplayerOpenError::FailedToReadFile,
-                            "{msg} since the next line could not be read.");
-
-                    if &buffer.as_slice()[buffer_position..] == HEX_START_RECORD_MARKER {
-                        break;
-                    }
-                    buffer.push(b'\n');
-
-                    if let FileReadLineState::LineLen(line_length) = line_length {
-                        buffer_position += line_length + 1;
-                    } else {
-                        fail!(from origin,
-                            with ReplayerOpenError::FailedToReadFile,
-                            "{msg} since the file ends prematurely.");
-                    }
+            DataRepresentation::Mcap => {
+                let summary = mcap::read_summary(file)?;
+                if summary.channels.len() != 1 {
+                    fail!(from origin, with ReplayerOpenError::MultipleChannels,
+                        "{msg} since the MCAP file contains {} channels but exactly one is required. Use the BagReplayerOpener instead.",
+                        summary.channels.len());
                 }
 
+                let channel = &summary.channels[0];
+                let header = RecordHeader {
+                    iceoryx2_version: summary.iceoryx2_version.clone(),
+                    service_name: channel.service_name,
+                    details: RecordHeaderDetails {
+                        file_format_version: FILE_FORMAT_MCAP_VERSION,
+                        types: channel.types.clone(),
+                        response_types: channel.response_types.clone(),
+                        messaging_pattern: channel.messaging_pattern,
+                        compression: Compression::None,
+                    },
+                };
+
+                Ok((header, McapReader::new(&summary)))
+            }
+            DataRepresentation::HumanReadable => {
+                let buffer = read_human_readable_header(file)?;
                 let record_file_header = fail!(from origin,
-                    when Toml::deserialize::<RecordHeader>(&buffer.as_slice()[0..buffer_position]),
+                    when Toml::deserialize::<RecordHeader>(&buffer),
                     with ReplayerOpenError::UnableToDeserializeRecordHeader,
                     "{msg} since the record header could not be deserialized.");
 
-                Ok(record_file_header)
+                Ok((record_file_header, McapReader::default()))
             }
             DataRepresentation::Iox2Dump => {
                 let mut header = MaybeUninit::<RecordHeader>::uninit();
@@ -238,12 +297,42 @@ impl ReplayerOpener {
                         "{msg} since the record file entry is too short.");
                 }
 
-                Ok(unsafe { header.assume_init() })
+                Ok((unsafe { header.assume_init() }, McapReader::default()))
             }
         }
     }
 }
 
+/// Reads the serialized header of a human readable file up to the start record marker.
+pub(crate) fn read_human_readable_header(file: &mut File) -> Result<Vec<u8>, ReplayerOpenError> {
+    let msg = "Unable to read record file header";
+    let origin = "read_human_readable_header()";
+    let mut buffer: Vec<u8> = vec![];
+    let mut buffer_position = 0;
+
+    loop {
+        let line_length = fail!(from origin, when file.read_line_to_vector(&mut buffer),
+                with ReplayerOpenError::FailedToReadFile,
+                "{msg} since the next line could not be read.");
+
+        if &buffer.as_slice()[buffer_position..] == HEX_START_RECORD_MARKER {
+            break;
+        }
+        buffer.push(b'\n');
+
+        if let FileReadLineState::LineLen(line_length) = line_length {
+            buffer_position += line_length + 1;
+        } else {
+            fail!(from origin,
+                with ReplayerOpenError::FailedToReadFile,
+                "{msg} since the file ends prematurely.");
+        }
+    }
+
+    buffer.truncate(buffer_position);
+    Ok(buffer)
+}
+
 #[derive(Debug)]
 /// Has read access to the recorded file and can extract one [`Record`] at a time.
 pub struct Replayer {
@@ -251,6 +340,12 @@ pub struct Replayer {
     data_representation: DataRepresentation,
     header: RecordHeader,
     last_timestamp: u64,
+    mcap_reader: McapReader,
+    chunk_reader: ChunkReader,
+    index: RecordIndex,
+    pending_record: Option<Record>,
+    // the footer follows the records and must not be interpreted as record
+    has_reached_end: bool,
 }
 
 impl Replayer {
@@ -265,12 +360,82 @@ impl Replayer {
         Ok(buffer)
     }
 
+    /// Reads all records with a timestamp in the range `[start, end]` into a buffer.
+    pub fn read_range_into_buffer(
+        mut self,
+        start: Duration,
+        end: Duration,
+    ) -> Result<Vec<Record>, ReplayerOpenError> {
+        let mut buffer = vec![];
+        self.seek(start)?;
+        while let Some(record) = self.next_record()? {
+            if end < record.timestamp {
+                break;
+            }
+            buffer.push(record);
+        }
+
+        Ok(buffer)
+    }
+
+    /// Positions the [`Replayer`] so that [`Replayer::next_record()`] returns the first
+    /// [`Record`] with a timestamp equal or newer than the provided timestamp. Uses the
+    /// [`RecordIndex`] to skip the records in front of the closest index entry.
+    pub fn seek(&mut self, timestamp: Duration) -> Result<(), ReplayerOpenError> {
+        match self.index.entry_before(timestamp) {
+            Some(entry) => {
+                fail!(from self, when self.file.seek(entry.offset),
+                    with ReplayerOpenError::FailedToReadFile,
+                    "Unable to seek to {timestamp:?} since the file offset could not be set.");
+                self.mcap_reader.reset();
+                self.chunk_reader.reset();
+                self.pending_record = None;
+                self.has_reached_end = false;
+                self.last_timestamp = 0;
+            }
+            None => self.rewind()?,
+        }
+
+        while let Some(record) = self.next_record()? {
+            if timestamp <= record.timestamp {
+                self.pending_record = Some(record);
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Positions the [`Replayer`] at the first [`Record`] of the file.
+    pub fn rewind(&mut self) -> Result<(), ReplayerOpenError> {
+        fail!(from self, when self.file.seek(0),
+            with ReplayerOpenError::FailedToReadFile,
+            "Unable to rewind since the file offset could not be set.");
+        let (_, mcap_reader) =
+            ReplayerOpener::read_header(&mut self.file, self.data_representation)?;
+        self.mcap_reader = mcap_reader;
+        self.chunk_reader.reset();
+        self.pending_record = None;
+        self.has_reached_end = false;
+        self.last_timestamp = 0;
+
+        Ok(())
+    }
+
     /// Returns the next contained [`Record`]. If it reached the end of the file it
     /// returns [`None`].
     pub fn next_record(&mut self) -> Result<Option<Record>, ReplayerOpenError> {
+        if let Some(record) = self.pending_record.take() {
+            return Ok(Some(record));
+        }
+
+        if self.has_reached_end {
+            return Ok(None);
+        }
+
         if let Some(record) = RecordReader::new(&self.header.details)
             .data_representation(self.data_representation)
-            .read(&self.file)?
+            .read(&self.file, &mut self.mcap_reader, &mut self.chunk_reader)?
         {
             let new_timestamp = record.timestamp.as_millis() as u64;
             if self.last_timestamp > new_timestamp {
@@ -283,6 +448,7 @@ impl Replayer {
             return Ok(Some(record));
         }
 
+        self.has_reached_end = true;
         Ok(None)
     }
 
@@ -290,4 +456,19 @@ impl Replayer {
     pub fn header(&self) -> &RecordHeader {
         &self.header
     }
+
+    /// Returns the [`RecordIndex`] of the recorded file.
+    pub fn index(&self) -> &RecordIndex {
+        &self.index
+    }
+
+    /// Returns the number of records in the recorded file.
+    pub fn number_of_records(&self) -> u64 {
+        self.index.number_of_records
+    }
+
+    /// Returns the time between the first and the last record in the recorded file.
+    pub fn duration(&self) -> Duration {
+        self.index.duration()
+    }
 }
@@ -0,0 +1,275 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Requests and responses of a request-response service are stored in the same file in the
+//! order in which they were captured. The system header of every record contains the
+//! [`RequestResponseRecordHeader`] which defines if the record is a request or a response
+//! and correlates the responses with their request. The user header and payload of requests
+//! correspond to the [`RecordHeaderDetails::types`](crate::record_header::RecordHeaderDetails::types),
+//! the ones of responses to the
+//! [`RecordHeaderDetails::response_types`](crate::record_header::RecordHeaderDetails::response_types).
+//!
+//! The responses of a live server can be compared with the recorded responses with
+//! [`diff_responses()`] to detect regressions.
+//!
+//! ## Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
+//! use iceoryx2_userland_record_and_replay::prelude::*;
+//! use iceoryx2_userland_record_and_replay::request_response::RequestResponseRecordHeader;
+//! use core::time::Duration;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let request_types = RequestResponseRecordHeader::service_types(
+//!     &TypeDetail::new::<u64>(TypeVariant::FixedSize),
+//!     &TypeDetail::new::<()>(TypeVariant::FixedSize),
+//! );
+//! let response_types = RequestResponseRecordHeader::service_types(
+//!     &TypeDetail::new::<u32>(TypeVariant::FixedSize),
+//!     &TypeDetail::new::<()>(TypeVariant::FixedSize),
+//! );
+//!
+//! let mut recorder = RecorderBuilder::new(&request_types)
+//!     .messaging_pattern(MessagingPattern::RequestResponse)
+//!     .response_types(&response_types)
+//!     .create(&FilePath::new(b"recorded_rpc.iox2")?, &ServiceName::new("my-rpc")?)?;
+//!
+//! # iceoryx2_bb_posix::file::File::remove(&FilePath::new(b"recorded_rpc.iox2")?)?;
+//!
+//! let client_id = 1;
+//! let server_id = 2;
+//! recorder.write(RawRecord {
+//!     timestamp: Duration::ZERO,
+//!     system_header: &RequestResponseRecordHeader::request(client_id, 0).to_bytes(),
+//!     user_header: &[],
+//!     payload: &8u64.to_le_bytes(),
+//! })?;
+//!
+//! recorder.write(RawRecord {
+//!     timestamp: Duration::from_millis(1),
+//!     system_header: &RequestResponseRecordHeader::response(client_id, server_id, 0, 0)
+//!         .to_bytes(),
+//!     user_header: &[],
+//!     payload: &64u32.to_le_bytes(),
+//! })?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use iceoryx2::prelude::ZeroCopySend;
+use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
+
+use crate::record::Record;
+use crate::recorder::ServiceTypes;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u64)]
+/// Defines if a record of a request-response service is a request or a response.
+pub enum RequestResponseRecordKind {
+    /// The record was sent by a [`Client`](iceoryx2::port::client::Client).
+    Request = 0,
+    /// The record was sent by a [`Server`](iceoryx2::port::server::Server).
+    Response = 1,
+}
+
+unsafe impl ZeroCopySend for RequestResponseRecordKind {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ZeroCopySend)]
+#[type_name("RequestResponseRecordHeader")]
+#[repr(C)]
+/// The system header of a recorded request or response.
+pub struct RequestResponseRecordHeader {
+    /// The [`UniqueClientId::value()`](iceoryx2::identifiers::UniqueClientId::value())
+    /// of the [`Client`](iceoryx2::port::client::Client) that sent the request.
+    pub client_id: u128,
+    /// The [`UniqueServerId::value()`](iceoryx2::identifiers::UniqueServerId::value())
+    /// of the [`Server`](iceoryx2::port::server::Server) that sent the response. Is `0` for
+    /// requests.
+    pub server_id: u128,
+    /// The number with which the responses are correlated to their request. It is assigned
+    /// by the recorder and unique inside a recorded file.
+    pub request_number: u64,
+    /// The position of the response in the stream of responses to the request. Is `0` for
+    /// requests.
+    pub sequence_number: u64,
+    /// Defines if the record is a request or a response.
+    pub kind: RequestResponseRecordKind,
+}
+
+impl RequestResponseRecordHeader {
+    /// The size of the serialized [`RequestResponseRecordHeader`].
+    pub const SIZE: usize = core::mem::size_of::<Self>();
+
+    /// Creates the [`RequestResponseRecordHeader`] of a request.
+    pub fn request(client_id: u128, request_number: u64) -> Self {
+        Self {
+            client_id,
+            server_id: 0,
+            request_number,
+            sequence_number: 0,
+            kind: RequestResponseRecordKind::Request,
+        }
+    }
+
+    /// Creates the [`RequestResponseRecordHeader`] of the response with the provided
+    /// sequence number to the request with the provided request number.
+    pub fn response(
+        client_id: u128,
+        server_id: u128,
+        request_number: u64,
+        sequence_number: u64,
+    ) -> Self {
+        Self {
+            client_id,
+            server_id,
+            request_number,
+            sequence_number,
+            kind: RequestResponseRecordKind::Response,
+        }
+    }
+
+    /// Returns the [`ServiceTypes`] of the recorded requests or responses with the provided
+    /// payload and user header.
+    pub fn service_types(payload: &TypeDetail, user_header: &TypeDetail) -> ServiceTypes {
+        ServiceTypes {
+            payload: *payload,
+            user_header: *user_header,
+            system_header: TypeDetail::new::<RequestResponseRecordHeader>(TypeVariant::FixedSize),
+        }
+    }
+
+    /// Serializes the [`RequestResponseRecordHeader`] so that it can be used as system header
+    /// of a [`RawRecord`](crate::record::RawRecord).
+    pub fn to_bytes(&self) -> [u8; Self::SIZE] {
+        let mut bytes = [0u8; Self::SIZE];
+        bytes[0..16].copy_from_slice(&self.client_id.to_le_bytes());
+        bytes[16..32].copy_from_slice(&self.server_id.to_le_bytes());
+        bytes[32..40].copy_from_slice(&self.request_number.to_le_bytes());
+        bytes[40..48].copy_from_slice(&self.sequence_number.to_le_bytes());
+        bytes[48..56].copy_from_slice(&(self.kind as u64).to_le_bytes());
+        bytes
+    }
+
+    /// Deserializes the [`RequestResponseRecordHeader`] from the system header of a
+    /// [`Record`]. Returns [`None`] when the bytes do not contain a valid
+    /// [`RequestResponseRecordHeader`].
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != Self::SIZE {
+            return None;
+        }
+
+        let kind = match u64::from_le_bytes(bytes[48..56].try_into().ok()?) {
+            0 => RequestResponseRecordKind::Request,
+            1 => RequestResponseRecordKind::Response,
+            _ => return None,
+        };
+
+        Some(Self {
+            client_id: u128::from_le_bytes(bytes[0..16].try_into().ok()?),
+            server_id: u128::from_le_bytes(bytes[16..32].try_into().ok()?),
+            request_number: u64::from_le_bytes(bytes[32..40].try_into().ok()?),
+            sequence_number: u64::from_le_bytes(bytes[40..48].try_into().ok()?),
+            kind,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Describes how a produced response differs from the recorded one.
+pub enum ResponseMismatchKind {
+    /// The response was recorded but not produced.
+    Missing,
+    /// The response was produced but not recorded.
+    Unexpected,
+    /// The user header of the produced response differs from the recorded one.
+    UserHeaderDiffers,
+    /// The payload of the produced response differs from the recorded one.
+    PayloadDiffers,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// A difference between a recorded and a produced response that was detected by
+/// [`diff_responses()`].
+pub struct ResponseMismatch {
+    /// The [`RequestResponseRecordHeader::request_number`] of the request.
+    pub request_number: u64,
+    /// The [`RequestResponseRecordHeader::sequence_number`] of the response.
+    pub sequence_number: u64,
+    /// Describes the difference.
+    pub kind: ResponseMismatchKind,
+}
+
+fn responses<'a, I: IntoIterator<Item = &'a Record>>(
+    records: I,
+) -> BTreeMap<(u64, u64), &'a Record> {
+    records
+        .into_iter()
+        .filter_map(|record| {
+            RequestResponseRecordHeader::from_bytes(&record.system_header)
+                .filter(|header| header.kind == RequestResponseRecordKind::Response)
+                .map(|header| ((header.request_number, header.sequence_number), record))
+        })
+        .collect()
+}
+
+/// Compares the responses of the produced records with the responses of the recorded
+/// records. Responses are matched by their request and sequence number, the timestamps and
+/// the ids of the clients and servers are ignored. Requests are skipped. Returns all
+/// detected mismatches ordered by request and sequence number, an empty result means that
+/// the server behaves like the recorded one.
+pub fn diff_responses<
+    'a,
+    R: IntoIterator<Item = &'a Record>,
+    P: IntoIterator<Item = &'a Record>,
+>(
+    recorded: R,
+    produced: P,
+) -> Vec<ResponseMismatch> {
+    let recorded = responses(recorded);
+    let mut produced = responses(produced);
+    let mut mismatches = Vec::new();
+
+    let mismatch = |(request_number, sequence_number): (u64, u64), kind| ResponseMismatch {
+        request_number,
+        sequence_number,
+        kind,
+    };
+
+    for (key, expected) in recorded {
+        match produced.remove(&key) {
+            None => mismatches.push(mismatch(key, ResponseMismatchKind::Missing)),
+            Some(actual) => {
+                if expected.user_header != actual.user_header {
+                    mismatches.push(mismatch(key, ResponseMismatchKind::UserHeaderDiffers));
+                }
+                if expected.payload != actual.payload {
+                    mismatches.push(mismatch(key, ResponseMismatchKind::PayloadDiffers));
+                }
+            }
+        }
+    }
+
+    mismatches.extend(
+        produced
+            .into_keys()
+            .map(|key| mismatch(key, ResponseMismatchKind::Unexpected)),
+    );
+    mismatches.sort_by_key(|m| (m.request_number, m.sequence_number));
+
+    mismatches
+}
@@ -0,0 +1,270 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(test)]
+mod bag {
+    use core::time::Duration;
+
+    use iceoryx2::prelude::MessagingPattern;
+    use iceoryx2::service::static_config::message_type_details::{
+        TypeDetail, TypeName, TypeVariant,
+    };
+    use iceoryx2_bb_posix::file::{CreationMode, File, FileBuilder};
+    use iceoryx2_bb_posix::testing::generate_file_path;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_userland_record_and_replay::bag::{
+        BagRecorderBuilder, BagReplayerOpener, ChannelId, FILE_FORMAT_BAG_VERSION,
+    };
+    use iceoryx2_userland_record_and_replay::record::{DataRepresentation, RawRecord};
+    use iceoryx2_userland_record_and_replay::recorder::{
+        RecorderCreateError, RecorderWriteError, ServiceTypes,
+    };
+    use iceoryx2_userland_record_and_replay::replayer::ReplayerOpenError;
+
+    fn generate_type_detail(variant: TypeVariant, size: usize, alignment: usize) -> TypeDetail {
+        iceoryx2::testing::create_custom_type_detail(
+            variant,
+            TypeName::from_str_truncated(&UniqueSystemId::new().unwrap().value().to_string())
+                .unwrap(),
+            size,
+            alignment,
+        )
+    }
+
+    fn generate_types(payload_size: usize) -> ServiceTypes {
+        ServiceTypes {
+            payload: generate_type_detail(TypeVariant::FixedSize, payload_size, 1),
+            user_header: generate_type_detail(TypeVariant::FixedSize, 4, 4),
+            system_header: generate_type_detail(TypeVariant::FixedSize, 16, 8),
+        }
+    }
+
+    fn interleaved_channels_are_replayed_in_time_order(data_representation: DataRepresentation) {
+        const NUMBER_OF_CHANNELS: usize = 3;
+        const NUMBER_OF_RECORDS: usize = 31;
+        let file_name = generate_file_path();
+
+        let mut builder = BagRecorderBuilder::new().data_representation(data_representation);
+        let mut types = vec![];
+        for n in 0..NUMBER_OF_CHANNELS {
+            types.push(generate_types(n + 1));
+            builder = builder.add_channel(
+                &iceoryx2::testing::generate_service_name(),
+                &types[n],
+                MessagingPattern::PublishSubscribe,
+            );
+        }
+        let mut sut = builder.create(&file_name).unwrap();
+
+        for n in 0..NUMBER_OF_RECORDS {
+            let channel = n % NUMBER_OF_CHANNELS;
+            assert_that!(
+                sut.write(
+                    ChannelId::new(channel as u64),
+                    RawRecord {
+                        timestamp: Duration::from_millis(n as u64 / 2),
+                        system_header: &[n as u8; 16],
+                        user_header: &[n as u8; 4],
+                        payload: &vec![n as u8; channel + 1],
+                    }
+                ),
+                is_ok
+            );
+        }
+
+        let replayer = BagReplayerOpener::new(&file_name)
+            .data_representation(data_representation)
+            .open()
+            .unwrap();
+        assert_that!(*replayer.header(), eq * sut.header());
+        assert_that!(replayer.header().file_format_version, eq FILE_FORMAT_BAG_VERSION);
+        assert_that!(replayer.header().channels, len NUMBER_OF_CHANNELS);
+
+        let buffer = replayer.read_into_buffer().unwrap();
+        assert_that!(buffer, len NUMBER_OF_RECORDS);
+        for (n, entry) in buffer.iter().enumerate() {
+            let channel = n % NUMBER_OF_CHANNELS;
+            assert_that!(entry.channel_id, eq ChannelId::new(channel as u64));
+            assert_that!(entry.record.timestamp, eq Duration::from_millis(n as u64 / 2));
+            assert_that!(entry.record.system_header, eq vec![n as u8; 16]);
+            assert_that!(entry.record.user_header, eq vec![n as u8; 4]);
+            assert_that!(entry.record.payload, eq vec![n as u8; channel + 1]);
+        }
+
+        File::remove(&file_name).unwrap();
+    }
+
+    #[test]
+    fn interleaved_channels_are_replayed_in_time_order_for_human_readable() {
+        interleaved_channels_are_replayed_in_time_order(DataRepresentation::HumanReadable);
+    }
+
+    #[test]
+    fn interleaved_channels_are_replayed_in_time_order_for_mcap() {
+        interleaved_channels_are_replayed_in_time_order(DataRepresentation::Mcap);
+    }
+
+    #[test]
+    fn interleaved_channels_are_replayed_in_time_order_for_iox2dump() {
+        interleaved_channels_are_replayed_in_time_order(DataRepresentation::Iox2Dump);
+    }
+
+    #[test]
+    fn channel_id_can_be_acquired_via_service_name() {
+        let file_name = generate_file_path();
+        let service_a = iceoryx2::testing::generate_service_name();
+        let service_b = iceoryx2::testing::generate_service_name();
+        let types = generate_types(8);
+
+        let sut = BagRecorderBuilder::new()
+            .add_channel(&service_a, &types, MessagingPattern::PublishSubscribe)
+            .add_channel(&service_b, &types, MessagingPattern::PublishSubscribe)
+            .create(&file_name)
+            .unwrap();
+
+        assert_that!(sut.header().channel_id(&service_a, MessagingPattern::PublishSubscribe), eq Some(ChannelId::new(0)));
+        assert_that!(sut.header().channel_id(&service_b, MessagingPattern::PublishSubscribe), eq Some(ChannelId::new(1)));
+        assert_that!(sut.header().channel_id(&service_b, MessagingPattern::Event), eq None);
+        assert_that!(sut.header().channel(ChannelId::new(1)).unwrap().service_name, eq service_b);
+
+        File::remove(&file_name).unwrap();
+    }
+
+    #[test]
+    fn adding_the_same_service_twice_fails() {
+        let file_name = generate_file_path();
+        let service_name = iceoryx2::testing::generate_service_name();
+        let types = generate_types(8);
+
+        let sut = BagRecorderBuilder::new()
+            .add_channel(&service_name, &types, MessagingPattern::PublishSubscribe)
+            .add_channel(&service_name, &types, MessagingPattern::PublishSubscribe)
+            .create(&file_name);
+
+        assert_that!(sut.err(), eq Some(RecorderCreateError::DuplicateChannel));
+    }
+
+    #[test]
+    fn writing_into_unknown_channel_fails() {
+        let file_name = generate_file_path();
+        let types = generate_types(8);
+
+        let mut sut = BagRecorderBuilder::new()
+            .add_channel(
+                &iceoryx2::testing::generate_service_name(),
+                &types,
+                MessagingPattern::PublishSubscribe,
+            )
+            .create(&file_name)
+            .unwrap();
+
+        let result = sut.write(
+            ChannelId::new(1),
+            RawRecord {
+                timestamp: Duration::ZERO,
+                system_header: &[0; 16],
+                user_header: &[0; 4],
+                payload: &[0; 8],
+            },
+        );
+        assert_that!(result.err(), eq Some(RecorderWriteError::UnknownChannel));
+
+        File::remove(&file_name).unwrap();
+    }
+
+    #[test]
+    fn writing_record_with_wrong_channel_types_fails() {
+        let file_name = generate_file_path();
+
+        let mut sut = BagRecorderBuilder::new()
+            .add_channel(
+                &iceoryx2::testing::generate_service_name(),
+                &generate_types(8),
+                MessagingPattern::PublishSubscribe,
+            )
+            .add_channel(
+                &iceoryx2::testing::generate_service_name(),
+                &generate_types(2),
+                MessagingPattern::PublishSubscribe,
+            )
+            .create(&file_name)
+            .unwrap();
+
+        let result = sut.write(
+            ChannelId::new(1),
+            RawRecord {
+                timestamp: Duration::ZERO,
+                system_header: &[0; 16],
+                user_header: &[0; 4],
+                payload: &[0; 8],
+            },
+        );
+        assert_that!(result.err(), eq Some(RecorderWriteError::CorruptedPayloadRecord));
+
+        File::remove(&file_name).unwrap();
+    }
+
+    #[test]
+    fn records_must_be_ordered_in_time_across_channels() {
+        let file_name = generate_file_path();
+        let types = generate_types(8);
+
+        let mut sut = BagRecorderBuilder::new()
+            .add_channel(
+                &iceoryx2::testing::generate_service_name(),
+                &types,
+                MessagingPattern::PublishSubscribe,
+            )
+            .add_channel(
+                &iceoryx2::testing::generate_service_name(),
+                &types,
+                MessagingPattern::PublishSubscribe,
+            )
+            .create(&file_name)
+            .unwrap();
+
+        let record = |timestamp| RawRecord {
+            timestamp,
+            system_header: &[0; 16],
+            user_header: &[0; 4],
+            payload: &[0; 8],
+        };
+
+        assert_that!(
+            sut.write(ChannelId::new(0), record(Duration::from_millis(10))),
+            is_ok
+        );
+        let result = sut.write(ChannelId::new(1), record(Duration::from_millis(9)));
+        assert_that!(result.err(), eq Some(RecorderWriteError::TimestampOlderThanPreviousRecord));
+
+        File::remove(&file_name).unwrap();
+    }
+
+    #[test]
+    fn open_iox2dump_with_header_length_exceeding_the_file_fails() {
+        let file_name = generate_file_path();
+
+        let mut file = FileBuilder::new(&file_name)
+            .has_ownership(true)
+            .creation_mode(CreationMode::PurgeAndCreate)
+            .create()
+            .unwrap();
+        file.write(&u64::MAX.to_le_bytes()).unwrap();
+        file.write(b"schalalala").unwrap();
+
+        let result = BagReplayerOpener::new(&file_name)
+            .data_representation(DataRepresentation::Iox2Dump)
+            .open();
+        assert_that!(result.err(), eq Some(ReplayerOpenError::CorruptedContent));
+    }
+}
@@ -0,0 +1,303 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(test)]
+mod compression {
+    use core::time::Duration;
+
+    use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
+    use iceoryx2_bb_posix::file::{AccessMode, File, FileBuilder};
+    use iceoryx2_bb_posix::file_descriptor::FileDescriptorManagement;
+    use iceoryx2_bb_posix::testing::generate_file_path;
+    use iceoryx2_bb_system_types::file_path::FilePath;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_userland_record_and_replay::compression::{Compression, MAX_CHUNK_SIZE};
+    use iceoryx2_userland_record_and_replay::record::{DataRepresentation, RawRecord, Record};
+    use iceoryx2_userland_record_and_replay::record_header::RecordHeader;
+    use iceoryx2_userland_record_and_replay::recorder::{
+        RecorderBuilder, RecorderCreateError, ServiceTypes,
+    };
+    use iceoryx2_userland_record_and_replay::replayer::{ReplayerOpenError, ReplayerOpener};
+
+    const NUMBER_OF_RECORDS: u64 = 200;
+    const PAYLOAD_SIZE: usize = 256;
+    const CHUNK_SIZE: usize = 4096;
+
+    fn types() -> ServiceTypes {
+        ServiceTypes {
+            payload: TypeDetail::new::<u8>(TypeVariant::Dynamic),
+            user_header: TypeDetail::new::<()>(TypeVariant::FixedSize),
+            system_header: TypeDetail::new::<u64>(TypeVariant::FixedSize),
+        }
+    }
+
+    fn payload(n: u64) -> Vec<u8> {
+        vec![n as u8; PAYLOAD_SIZE]
+    }
+
+    fn create_recording(file_name: &FilePath, compression: Compression) {
+        let mut recorder = RecorderBuilder::new(&types())
+            .data_representation(DataRepresentation::Iox2Dump)
+            .compression(compression)
+            .chunk_size(CHUNK_SIZE)
+            .index_interval(Duration::from_millis(10))
+            .create(file_name, &iceoryx2::testing::generate_service_name())
+            .unwrap();
+        assert_that!(recorder.header().details.compression, eq compression);
+
+        for n in 0..NUMBER_OF_RECORDS {
+            assert_that!(
+                recorder.write(RawRecord {
+                    timestamp: Duration::from_millis(n),
+                    system_header: &n.to_le_bytes(),
+                    user_header: &[],
+                    payload: &payload(n),
+                }),
+                is_ok
+            );
+        }
+    }
+
+    fn file_size(file_name: &FilePath) -> u64 {
+        FileBuilder::new(file_name)
+            .open_existing(AccessMode::Read)
+            .unwrap()
+            .metadata()
+            .unwrap()
+            .size()
+    }
+
+    fn verify_record(record: &Record, n: u64) {
+        assert_that!(record.timestamp, eq Duration::from_millis(n));
+        assert_that!(record.system_header, eq n.to_le_bytes().to_vec());
+        assert_that!(record.payload, eq payload(n));
+    }
+
+    fn compressed_recording_can_be_replayed(compression: Compression) {
+        let file_name = generate_file_path();
+        create_recording(&file_name, compression);
+
+        let sut = ReplayerOpener::new(&file_name)
+            .data_representation(DataRepresentation::Iox2Dump)
+            .open()
+            .unwrap();
+        assert_that!(sut.header().details.compression, eq compression);
+        assert_that!(sut.number_of_records(), eq NUMBER_OF_RECORDS);
+
+        let buffer = sut.read_into_buffer().unwrap();
+        assert_that!(buffer, len NUMBER_OF_RECORDS as usize);
+        for (n, record) in buffer.iter().enumerate() {
+            verify_record(record, n as u64);
+        }
+
+        File::remove(&file_name).unwrap();
+    }
+
+    #[test]
+    fn compressed_recording_can_be_replayed_for_lz4() {
+        compressed_recording_can_be_replayed(Compression::Lz4);
+    }
+
+    #[test]
+    fn compressed_recording_can_be_replayed_for_zstd() {
+        compressed_recording_can_be_replayed(Compression::Zstd);
+    }
+
+    fn compressed_recording_is_smaller_than_uncompressed_recording(compression: Compression) {
+        let uncompressed_file_name = generate_file_path();
+        let compressed_file_name = generate_file_path();
+        create_recording(&uncompressed_file_name, Compression::None);
+        create_recording(&compressed_file_name, compression);
+
+        assert_that!(
+            file_size(&compressed_file_name) * 4,
+            lt file_size(&uncompressed_file_name)
+        );
+
+        File::remove(&uncompressed_file_name).unwrap();
+        File::remove(&compressed_file_name).unwrap();
+    }
+
+    #[test]
+    fn compressed_recording_is_smaller_than_uncompressed_recording_for_lz4() {
+        compressed_recording_is_smaller_than_uncompressed_recording(Compression::Lz4);
+    }
+
+    #[test]
+    fn compressed_recording_is_smaller_than_uncompressed_recording_for_zstd() {
+        compressed_recording_is_smaller_than_uncompressed_recording(Compression::Zstd);
+    }
+
+    fn seek_in_compressed_recording_works(compression: Compression) {
+        let file_name = generate_file_path();
+        create_recording(&file_name, compression);
+
+        let mut sut = ReplayerOpener::new(&file_name)
+            .data_representation(DataRepresentation::Iox2Dump)
+            .open()
+            .unwrap();
+        assert_that!(sut.index().entries, is_not_empty);
+
+        assert_that!(sut.seek(Duration::from_millis(137)), is_ok);
+        verify_record(&sut.next_record().unwrap().unwrap(), 137);
+        verify_record(&sut.next_record().unwrap().unwrap(), 138);
+
+        assert_that!(sut.seek(Duration::from_millis(3)), is_ok);
+        verify_record(&sut.next_record().unwrap().unwrap(), 3);
+
+        let buffer = sut
+            .read_range_into_buffer(Duration::from_millis(50), Duration::from_millis(59))
+            .unwrap();
+        assert_that!(buffer, len 10);
+        for (n, record) in buffer.iter().enumerate() {
+            verify_record(record, n as u64 + 50);
+        }
+
+        File::remove(&file_name).unwrap();
+    }
+
+    #[test]
+    fn index_entries_of_compressed_recording_point_to_their_record() {
+        let file_name = generate_file_path();
+        create_recording(&file_name, Compression::Zstd);
+
+        let mut sut = ReplayerOpener::new(&file_name)
+            .data_representation(DataRepresentation::Iox2Dump)
+            .open()
+            .unwrap();
+        let entries = sut.index().entries.clone();
+        assert_that!(entries.len(), gt 1);
+
+        let file = FileBuilder::new(&file_name)
+            .open_existing(AccessMode::Read)
+            .unwrap();
+        for entry in entries {
+            assert_that!(entry.timestamp, eq Duration::from_millis(entry.record_number));
+
+            // the chunk at the offset starts with the timestamp of the indexed record
+            let mut header = [0u8; 16];
+            file.read_range(entry.offset, &mut header).unwrap();
+            let mut compressed =
+                vec![0u8; u64::from_le_bytes(header[..8].try_into().unwrap()) as usize];
+            file.read_range(entry.offset + 16, &mut compressed).unwrap();
+            let mut records =
+                Vec::with_capacity(u64::from_le_bytes(header[8..].try_into().unwrap()) as usize);
+            ruzstd::decoding::FrameDecoder::new()
+                .decode_all_to_vec(&compressed, &mut records)
+                .unwrap();
+            assert_that!(records[..8], eq entry.record_number.to_le_bytes());
+
+            assert_that!(sut.seek(entry.timestamp), is_ok);
+            verify_record(&sut.next_record().unwrap().unwrap(), entry.record_number);
+        }
+
+        File::remove(&file_name).unwrap();
+    }
+
+    #[test]
+    fn seek_in_compressed_recording_works_for_lz4() {
+        seek_in_compressed_recording_works(Compression::Lz4);
+    }
+
+    #[test]
+    fn seek_in_compressed_recording_works_for_zstd() {
+        seek_in_compressed_recording_works(Compression::Zstd);
+    }
+
+    fn compression_is_not_supported(data_representation: DataRepresentation) {
+        let file_name = generate_file_path();
+
+        let sut = RecorderBuilder::new(&types())
+            .data_representation(data_representation)
+            .compression(Compression::Lz4)
+            .create(&file_name, &iceoryx2::testing::generate_service_name());
+
+        assert_that!(sut.err(), eq Some(RecorderCreateError::UnsupportedCompression));
+        assert_that!(File::does_exist(&file_name).unwrap(), eq false);
+    }
+
+    #[test]
+    fn compression_is_not_supported_for_human_readable() {
+        compression_is_not_supported(DataRepresentation::HumanReadable);
+    }
+
+    #[test]
+    fn compression_is_not_supported_for_mcap() {
+        compression_is_not_supported(DataRepresentation::Mcap);
+    }
+
+    fn recording_with_corrupted_chunk_header_cannot_be_replayed(
+        compression: Compression,
+        header_offset: u64,
+    ) {
+        let file_name = generate_file_path();
+        create_recording(&file_name, compression);
+
+        let mut file = FileBuilder::new(&file_name)
+            .open_existing(AccessMode::ReadWrite)
+            .unwrap();
+        let first_chunk = core::mem::size_of::<RecordHeader>() as u64;
+        file.write_at(first_chunk + header_offset, &(1u64 << 40).to_le_bytes())
+            .unwrap();
+
+        let sut = ReplayerOpener::new(&file_name)
+            .data_representation(DataRepresentation::Iox2Dump)
+            .open()
+            .unwrap();
+        assert_that!(sut.read_into_buffer().err(), eq Some(ReplayerOpenError::CorruptedContent));
+
+        File::remove(&file_name).unwrap();
+    }
+
+    #[test]
+    fn recording_with_too_large_compressed_chunk_size_cannot_be_replayed() {
+        recording_with_corrupted_chunk_header_cannot_be_replayed(Compression::Lz4, 0);
+    }
+
+    #[test]
+    fn recording_with_too_large_uncompressed_chunk_size_cannot_be_replayed() {
+        recording_with_corrupted_chunk_header_cannot_be_replayed(Compression::Zstd, 8);
+    }
+
+    #[test]
+    fn chunk_size_is_limited_to_max_chunk_size() {
+        let file_name = generate_file_path();
+        let mut recorder = RecorderBuilder::new(&types())
+            .data_representation(DataRepresentation::Iox2Dump)
+            .compression(Compression::Lz4)
+            .chunk_size(usize::MAX)
+            .create(&file_name, &iceoryx2::testing::generate_service_name())
+            .unwrap();
+
+        let payload = vec![0u8; MAX_CHUNK_SIZE / 2];
+        for n in 0..3 {
+            assert_that!(
+                recorder.write(RawRecord {
+                    timestamp: Duration::from_millis(n),
+                    system_header: &n.to_le_bytes(),
+                    user_header: &[],
+                    payload: &payload,
+                }),
+                is_ok
+            );
+        }
+        drop(recorder);
+
+        let sut = ReplayerOpener::new(&file_name)
+            .data_representation(DataRepresentation::Iox2Dump)
+            .open()
+            .unwrap();
+        assert_that!(sut.read_into_buffer().unwrap(), len 3);
+
+        File::remove(&file_name).unwrap();
+    }
+}
@@ -0,0 +1,132 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(test)]
+mod event_record {
+    use core::time::Duration;
+
+    use iceoryx2::prelude::{EventId, MessagingPattern};
+    use iceoryx2_bb_posix::file::File;
+    use iceoryx2_bb_posix::testing::generate_file_path;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_userland_record_and_replay::event::{EventRecordHeader, UNKNOWN_NOTIFIER};
+    use iceoryx2_userland_record_and_replay::record::{DataRepresentation, RawRecord};
+    use iceoryx2_userland_record_and_replay::recorder::{RecorderBuilder, RecorderWriteError};
+    use iceoryx2_userland_record_and_replay::replayer::ReplayerOpener;
+
+    #[test]
+    fn header_can_be_serialized_and_deserialized() {
+        let sut = EventRecordHeader::new(Some(0x1234_5678_9abc), EventId::new(73), 5);
+
+        let restored = EventRecordHeader::from_bytes(&sut.to_bytes());
+
+        assert_that!(restored, eq Some(sut));
+        assert_that!(sut.event_id(), eq EventId::new(73));
+        assert_that!(sut.notifier_id(), eq Some(0x1234_5678_9abc));
+        assert_that!(sut.count, eq 5);
+    }
+
+    #[test]
+    fn header_without_notifier_has_unknown_notifier() {
+        let sut = EventRecordHeader::new(None, EventId::new(1), 1);
+
+        assert_that!(sut.notifier_id, eq UNKNOWN_NOTIFIER);
+        assert_that!(sut.notifier_id(), is_none);
+    }
+
+    #[test]
+    fn header_with_wrong_size_cannot_be_deserialized() {
+        let bytes = EventRecordHeader::new(None, EventId::new(1), 1).to_bytes();
+
+        assert_that!(EventRecordHeader::from_bytes(&bytes[1..]), is_none);
+        assert_that!(EventRecordHeader::from_bytes(&[]), is_none);
+    }
+
+    fn recorded_events_can_be_replayed(data_representation: DataRepresentation) {
+        const NUMBER_OF_EVENTS: usize = 12;
+        let file_name = generate_file_path();
+
+        let mut recorder = RecorderBuilder::new(&EventRecordHeader::service_types())
+            .data_representation(data_representation)
+            .messaging_pattern(MessagingPattern::Event)
+            .create(&file_name, &iceoryx2::testing::generate_service_name())
+            .unwrap();
+
+        for n in 0..NUMBER_OF_EVENTS {
+            let header = EventRecordHeader::new(Some(n as u128 + 1), EventId::new(n * 3), 1);
+            assert_that!(
+                recorder.write(RawRecord {
+                    timestamp: Duration::from_millis(n as u64),
+                    system_header: &header.to_bytes(),
+                    user_header: &[],
+                    payload: &vec![n as u8; n],
+                }),
+                is_ok
+            );
+        }
+        drop(recorder);
+
+        let sut = ReplayerOpener::new(&file_name)
+            .data_representation(data_representation)
+            .open()
+            .unwrap();
+        assert_that!(sut.header().details.messaging_pattern, eq MessagingPattern::Event);
+
+        let records = sut.read_into_buffer().unwrap();
+        assert_that!(records, len NUMBER_OF_EVENTS);
+        for (n, record) in records.iter().enumerate() {
+            let header = EventRecordHeader::from_bytes(&record.system_header).unwrap();
+            assert_that!(header.event_id(), eq EventId::new(n * 3));
+            assert_that!(header.notifier_id(), eq Some(n as u128 + 1));
+            assert_that!(record.payload, eq vec![n as u8; n]);
+            assert_that!(record.user_header, is_empty);
+        }
+
+        File::remove(&file_name).unwrap();
+    }
+
+    #[test]
+    fn recorded_events_can_be_replayed_for_iox2dump() {
+        recorded_events_can_be_replayed(DataRepresentation::Iox2Dump);
+    }
+
+    #[test]
+    fn recorded_events_can_be_replayed_for_human_readable() {
+        recorded_events_can_be_replayed(DataRepresentation::HumanReadable);
+    }
+
+    #[test]
+    fn recorded_events_can_be_replayed_for_mcap() {
+        recorded_events_can_be_replayed(DataRepresentation::Mcap);
+    }
+
+    #[test]
+    fn event_record_without_event_header_cannot_be_written() {
+        let file_name = generate_file_path();
+
+        let mut recorder = RecorderBuilder::new(&EventRecordHeader::service_types())
+            .messaging_pattern(MessagingPattern::Event)
+            .create(&file_name, &iceoryx2::testing::generate_service_name())
+            .unwrap();
+
+        let result = recorder.write(RawRecord {
+            timestamp: Duration::ZERO,
+            system_header: &[0u8; 8],
+            user_header: &[],
+            payload: &[],
+        });
+        assert_that!(result.err(), eq Some(RecorderWriteError::CorruptedSystemHeaderRecord));
+
+        drop(recorder);
+        File::remove(&file_name).unwrap();
+    }
+}
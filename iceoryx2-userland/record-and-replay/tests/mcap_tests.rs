@@ -0,0 +1,264 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(test)]
+mod mcap {
+    use core::time::Duration;
+
+    use iceoryx2::prelude::{MessagingPattern, ServiceName};
+    use iceoryx2::service::header::publish_subscribe::Header;
+    use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
+    use iceoryx2_bb_posix::file::{AccessMode, CreationMode, File, FileBuilder};
+    use iceoryx2_bb_posix::testing::generate_file_path;
+    use iceoryx2_bb_system_types::file_path::FilePath;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_userland_record_and_replay::bag::{BagRecorderBuilder, BagReplayerOpener};
+    use iceoryx2_userland_record_and_replay::record::{DataRepresentation, RawRecord};
+    use iceoryx2_userland_record_and_replay::record_header::FILE_FORMAT_MCAP_VERSION;
+    use iceoryx2_userland_record_and_replay::recorder::{RecorderBuilder, ServiceTypes};
+    use iceoryx2_userland_record_and_replay::replayer::{ReplayerOpenError, ReplayerOpener};
+
+    const MAGIC: &[u8] = b"\x89MCAP0\r\n";
+
+    fn string(value: &str) -> Vec<u8> {
+        let mut buffer = (value.len() as u32).to_le_bytes().to_vec();
+        buffer.extend_from_slice(value.as_bytes());
+        buffer
+    }
+
+    fn record(opcode: u8, content: &[u8]) -> Vec<u8> {
+        let mut buffer = vec![opcode];
+        buffer.extend_from_slice(&(content.len() as u64).to_le_bytes());
+        buffer.extend_from_slice(content);
+        buffer
+    }
+
+    fn message(channel_id: u16, log_time: u64, data: &[u8]) -> Vec<u8> {
+        let mut content = channel_id.to_le_bytes().to_vec();
+        content.extend_from_slice(&0u32.to_le_bytes());
+        content.extend_from_slice(&log_time.to_le_bytes());
+        content.extend_from_slice(&log_time.to_le_bytes());
+        content.extend_from_slice(data);
+        record(0x05, &content)
+    }
+
+    fn chunk(compression: &str, records: &[u8]) -> Vec<u8> {
+        let compressed = match compression {
+            "zstd" => ruzstd::encoding::compress_to_vec(
+                records,
+                ruzstd::encoding::CompressionLevel::Fastest,
+            ),
+            _ => records.to_vec(),
+        };
+
+        let mut content = vec![];
+        content.extend_from_slice(&0u64.to_le_bytes());
+        content.extend_from_slice(&0u64.to_le_bytes());
+        content.extend_from_slice(&(records.len() as u64).to_le_bytes());
+        content.extend_from_slice(&0u32.to_le_bytes());
+        content.extend_from_slice(&string(compression));
+        content.extend_from_slice(&(compressed.len() as u64).to_le_bytes());
+        content.extend_from_slice(&compressed);
+        record(0x06, &content)
+    }
+
+    fn write_foreign_mcap_file(file_name: &FilePath, compression: &str) {
+        let mut content = MAGIC.to_vec();
+        content.extend(record(0x01, &[string(""), string("some-library")].concat()));
+
+        let mut schema = 1u16.to_le_bytes().to_vec();
+        schema.extend(string("my.Message"));
+        schema.extend(string("protobuf"));
+        schema.extend(string("descriptor"));
+        content.extend(record(0x03, &schema));
+
+        let mut channel = 3u16.to_le_bytes().to_vec();
+        channel.extend_from_slice(&1u16.to_le_bytes());
+        channel.extend(string("camera/image"));
+        channel.extend(string("protobuf"));
+        channel.extend_from_slice(&0u32.to_le_bytes());
+        content.extend(record(0x04, &channel));
+
+        content.extend(chunk(
+            compression,
+            &[message(3, 1000, &[1, 2, 3]), message(3, 2000, &[4, 5])].concat(),
+        ));
+        content.extend(message(3, 3000, &[6]));
+        content.extend(record(0x0F, &0u32.to_le_bytes()));
+        content.extend(record(0x02, &[0u8; 20]));
+        content.extend_from_slice(MAGIC);
+
+        let mut file = FileBuilder::new(file_name)
+            .has_ownership(false)
+            .creation_mode(CreationMode::PurgeAndCreate)
+            .create()
+            .unwrap();
+        file.write(&content).unwrap();
+    }
+
+    fn types() -> ServiceTypes {
+        ServiceTypes {
+            payload: TypeDetail::new::<u64>(TypeVariant::FixedSize),
+            user_header: TypeDetail::new::<u32>(TypeVariant::FixedSize),
+            system_header: TypeDetail::new::<u64>(TypeVariant::FixedSize),
+        }
+    }
+
+    #[test]
+    fn recorded_file_is_framed_by_mcap_magic() {
+        let file_name = generate_file_path();
+
+        let mut sut = RecorderBuilder::new(&types())
+            .data_representation(DataRepresentation::Mcap)
+            .create(&file_name, &iceoryx2::testing::generate_service_name())
+            .unwrap();
+        assert_that!(sut.header().details.file_format_version, eq FILE_FORMAT_MCAP_VERSION);
+        assert_that!(
+            sut.write(RawRecord {
+                timestamp: Duration::from_millis(12),
+                system_header: &[1; 8],
+                user_header: &[2; 4],
+                payload: &[3; 8],
+            }),
+            is_ok
+        );
+        drop(sut);
+
+        let file = FileBuilder::new(&file_name)
+            .open_existing(AccessMode::Read)
+            .unwrap();
+        let mut content = vec![];
+        file.read_to_vector(&mut content).unwrap();
+
+        assert_that!(content.starts_with(MAGIC), eq true);
+        assert_that!(content.ends_with(MAGIC), eq true);
+
+        let records = ReplayerOpener::new(&file_name)
+            .data_representation(DataRepresentation::Mcap)
+            .open()
+            .unwrap()
+            .read_into_buffer()
+            .unwrap();
+        assert_that!(records, len 1);
+        assert_that!(records[0].timestamp, eq Duration::from_millis(12));
+        assert_that!(records[0].payload, eq vec![3; 8]);
+
+        File::remove(&file_name).unwrap();
+    }
+
+    fn foreign_mcap_file_is_replayed_as_dynamic_byte_payload(compression: &str) {
+        let file_name = generate_file_path();
+        write_foreign_mcap_file(&file_name, compression);
+
+        let sut = ReplayerOpener::new(&file_name)
+            .data_representation(DataRepresentation::Mcap)
+            .open()
+            .unwrap();
+
+        let header = sut.header().clone();
+        assert_that!(header.service_name, eq ServiceName::new("camera/image").unwrap());
+        assert_that!(header.details.messaging_pattern, eq MessagingPattern::PublishSubscribe);
+        assert_that!(header.details.types.payload, eq TypeDetail::new::<u8>(TypeVariant::Dynamic));
+
+        let records = sut.read_into_buffer().unwrap();
+        assert_that!(records, len 3);
+        let expected: [&[u8]; 3] = [&[1, 2, 3], &[4, 5], &[6]];
+        for (n, record) in records.iter().enumerate() {
+            assert_that!(record.timestamp, eq Duration::from_micros(n as u64 + 1));
+            assert_that!(record.payload, eq expected[n]);
+            assert_that!(record.user_header, len 0);
+            assert_that!(record.system_header, len core::mem::size_of::<Header>());
+        }
+
+        File::remove(&file_name).unwrap();
+    }
+
+    #[test]
+    fn foreign_mcap_file_is_replayed_as_dynamic_byte_payload_for_uncompressed_chunks() {
+        foreign_mcap_file_is_replayed_as_dynamic_byte_payload("");
+    }
+
+    #[test]
+    fn foreign_mcap_file_is_replayed_as_dynamic_byte_payload_for_zstd_chunks() {
+        foreign_mcap_file_is_replayed_as_dynamic_byte_payload("zstd");
+    }
+
+    #[test]
+    fn mcap_file_with_unsupported_compression_cannot_be_opened() {
+        let file_name = generate_file_path();
+        write_foreign_mcap_file(&file_name, "lz4");
+
+        let sut = ReplayerOpener::new(&file_name)
+            .data_representation(DataRepresentation::Mcap)
+            .open();
+        assert_that!(sut.err(), eq Some(ReplayerOpenError::UnsupportedCompression));
+
+        File::remove(&file_name).unwrap();
+    }
+
+    #[test]
+    fn mcap_file_with_record_length_exceeding_the_file_cannot_be_opened() {
+        let file_name = generate_file_path();
+        let mut content = MAGIC.to_vec();
+        content.extend(record(0x01, &[string(""), string("some-library")].concat()));
+        content.push(0x05);
+        content.extend_from_slice(&u64::MAX.to_le_bytes());
+        content.extend_from_slice(MAGIC);
+
+        let mut file = FileBuilder::new(&file_name)
+            .has_ownership(true)
+            .creation_mode(CreationMode::PurgeAndCreate)
+            .create()
+            .unwrap();
+        file.write(&content).unwrap();
+
+        let sut = ReplayerOpener::new(&file_name)
+            .data_representation(DataRepresentation::Mcap)
+            .open();
+        assert_that!(sut.err(), eq Some(ReplayerOpenError::CorruptedContent));
+    }
+
+    #[test]
+    fn mcap_file_with_multiple_channels_requires_bag_replayer() {
+        let file_name = generate_file_path();
+
+        let recorder = BagRecorderBuilder::new()
+            .data_representation(DataRepresentation::Mcap)
+            .add_channel(
+                &iceoryx2::testing::generate_service_name(),
+                &types(),
+                MessagingPattern::PublishSubscribe,
+            )
+            .add_channel(
+                &iceoryx2::testing::generate_service_name(),
+                &types(),
+                MessagingPattern::PublishSubscribe,
+            )
+            .create(&file_name)
+            .unwrap();
+        let header = recorder.header().clone();
+        drop(recorder);
+
+        let sut = ReplayerOpener::new(&file_name)
+            .data_representation(DataRepresentation::Mcap)
+            .open();
+        assert_that!(sut.err(), eq Some(ReplayerOpenError::MultipleChannels));
+
+        let sut = BagReplayerOpener::new(&file_name)
+            .data_representation(DataRepresentation::Mcap)
+            .open()
+            .unwrap();
+        assert_that!(sut.header().channels, eq header.channels);
+
+        File::remove(&file_name).unwrap();
+    }
+}
@@ -0,0 +1,218 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(test)]
+mod record_index {
+    use core::time::Duration;
+
+    use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
+    use iceoryx2_bb_posix::file::File;
+    use iceoryx2_bb_posix::testing::generate_file_path;
+    use iceoryx2_bb_system_types::file_path::FilePath;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_userland_record_and_replay::record::{DataRepresentation, RawRecord};
+    use iceoryx2_userland_record_and_replay::recorder::{Recorder, RecorderBuilder, ServiceTypes};
+    use iceoryx2_userland_record_and_replay::replayer::ReplayerOpener;
+
+    const NUMBER_OF_RECORDS: u64 = 100;
+    const TIME_BETWEEN_RECORDS: Duration = Duration::from_millis(2);
+    const INDEX_INTERVAL: Duration = Duration::from_millis(10);
+
+    fn timestamp(n: u64) -> Duration {
+        TIME_BETWEEN_RECORDS * n as u32
+    }
+
+    fn create_recording(file_name: &FilePath, data_representation: DataRepresentation) -> Recorder {
+        let types = ServiceTypes {
+            payload: TypeDetail::new::<u64>(TypeVariant::FixedSize),
+            user_header: TypeDetail::new::<()>(TypeVariant::FixedSize),
+            system_header: TypeDetail::new::<u64>(TypeVariant::FixedSize),
+        };
+
+        let mut recorder = RecorderBuilder::new(&types)
+            .data_representation(data_representation)
+            .index_interval(INDEX_INTERVAL)
+            .create(file_name, &iceoryx2::testing::generate_service_name())
+            .unwrap();
+
+        for n in 0..NUMBER_OF_RECORDS {
+            assert_that!(
+                recorder.write(RawRecord {
+                    timestamp: timestamp(n),
+                    system_header: &[0u8; 8],
+                    user_header: &[],
+                    payload: &n.to_le_bytes(),
+                }),
+                is_ok
+            );
+        }
+
+        recorder
+    }
+
+    fn payload_of(record: &iceoryx2_userland_record_and_replay::record::Record) -> u64 {
+        u64::from_le_bytes(record.payload.as_slice().try_into().unwrap())
+    }
+
+    fn index_is_stored_in_footer(data_representation: DataRepresentation) {
+        let file_name = generate_file_path();
+        let recorder = create_recording(&file_name, data_representation);
+        let index = recorder.index().clone();
+        drop(recorder);
+
+        assert_that!(index.number_of_records, eq NUMBER_OF_RECORDS);
+        assert_that!(
+            index.entries,
+            len(timestamp(NUMBER_OF_RECORDS - 1).as_millis() / INDEX_INTERVAL.as_millis()) as usize
+                + 1
+        );
+
+        let sut = ReplayerOpener::new(&file_name)
+            .data_representation(data_representation)
+            .open()
+            .unwrap();
+
+        assert_that!(*sut.index(), eq index);
+        assert_that!(sut.number_of_records(), eq NUMBER_OF_RECORDS);
+        assert_that!(sut.duration(), eq timestamp(NUMBER_OF_RECORDS - 1));
+
+        let buffer = sut.read_into_buffer().unwrap();
+        assert_that!(buffer, len NUMBER_OF_RECORDS as usize);
+
+        File::remove(&file_name).unwrap();
+    }
+
+    #[test]
+    fn index_is_stored_in_footer_for_iox2dump() {
+        index_is_stored_in_footer(DataRepresentation::Iox2Dump);
+    }
+
+    #[test]
+    fn index_is_stored_in_footer_for_human_readable() {
+        index_is_stored_in_footer(DataRepresentation::HumanReadable);
+    }
+
+    #[test]
+    fn index_is_stored_in_footer_for_mcap() {
+        index_is_stored_in_footer(DataRepresentation::Mcap);
+    }
+
+    fn seek_positions_at_first_record_not_older_than_timestamp(
+        data_representation: DataRepresentation,
+    ) {
+        let file_name = generate_file_path();
+        drop(create_recording(&file_name, data_representation));
+
+        let mut sut = ReplayerOpener::new(&file_name)
+            .data_representation(data_representation)
+            .open()
+            .unwrap();
+
+        assert_that!(sut.seek(Duration::from_millis(37)), is_ok);
+        let record = sut.next_record().unwrap().unwrap();
+        assert_that!(record.timestamp, eq Duration::from_millis(38));
+        assert_that!(payload_of(&record), eq 19);
+
+        assert_that!(sut.seek(Duration::from_millis(4)), is_ok);
+        assert_that!(payload_of(&sut.next_record().unwrap().unwrap()), eq 2);
+        assert_that!(payload_of(&sut.next_record().unwrap().unwrap()), eq 3);
+
+        assert_that!(sut.seek(timestamp(NUMBER_OF_RECORDS)), is_ok);
+        assert_that!(sut.next_record().unwrap(), is_none);
+
+        File::remove(&file_name).unwrap();
+    }
+
+    #[test]
+    fn seek_positions_at_first_record_not_older_than_timestamp_for_iox2dump() {
+        seek_positions_at_first_record_not_older_than_timestamp(DataRepresentation::Iox2Dump);
+    }
+
+    #[test]
+    fn seek_positions_at_first_record_not_older_than_timestamp_for_human_readable() {
+        seek_positions_at_first_record_not_older_than_timestamp(DataRepresentation::HumanReadable);
+    }
+
+    #[test]
+    fn seek_positions_at_first_record_not_older_than_timestamp_for_mcap() {
+        seek_positions_at_first_record_not_older_than_timestamp(DataRepresentation::Mcap);
+    }
+
+    fn read_range_returns_records_in_time_window(data_representation: DataRepresentation) {
+        let file_name = generate_file_path();
+        drop(create_recording(&file_name, data_representation));
+
+        let buffer = ReplayerOpener::new(&file_name)
+            .data_representation(data_representation)
+            .open()
+            .unwrap()
+            .read_range_into_buffer(Duration::from_millis(21), Duration::from_millis(60))
+            .unwrap();
+
+        assert_that!(buffer, len 20);
+        for (n, record) in buffer.iter().enumerate() {
+            assert_that!(payload_of(record), eq n as u64 + 11);
+        }
+
+        File::remove(&file_name).unwrap();
+    }
+
+    #[test]
+    fn read_range_returns_records_in_time_window_for_iox2dump() {
+        read_range_returns_records_in_time_window(DataRepresentation::Iox2Dump);
+    }
+
+    #[test]
+    fn read_range_returns_records_in_time_window_for_human_readable() {
+        read_range_returns_records_in_time_window(DataRepresentation::HumanReadable);
+    }
+
+    #[test]
+    fn read_range_returns_records_in_time_window_for_mcap() {
+        read_range_returns_records_in_time_window(DataRepresentation::Mcap);
+    }
+
+    fn file_without_footer_is_indexed_by_scanning(data_representation: DataRepresentation) {
+        let file_name = generate_file_path();
+        // the footer is written when the recorder goes out of scope
+        let _recorder = create_recording(&file_name, data_representation);
+
+        let mut sut = ReplayerOpener::new(&file_name)
+            .data_representation(data_representation)
+            .open()
+            .unwrap();
+
+        assert_that!(sut.number_of_records(), eq NUMBER_OF_RECORDS);
+        assert_that!(sut.duration(), eq timestamp(NUMBER_OF_RECORDS - 1));
+        assert_that!(sut.index().entries, is_empty);
+
+        assert_that!(sut.seek(Duration::from_millis(100)), is_ok);
+        assert_that!(payload_of(&sut.next_record().unwrap().unwrap()), eq 50);
+
+        File::remove(&file_name).unwrap();
+    }
+
+    #[test]
+    fn file_without_footer_is_indexed_by_scanning_for_iox2dump() {
+        file_without_footer_is_indexed_by_scanning(DataRepresentation::Iox2Dump);
+    }
+
+    #[test]
+    fn file_without_footer_is_indexed_by_scanning_for_human_readable() {
+        file_without_footer_is_indexed_by_scanning(DataRepresentation::HumanReadable);
+    }
+
+    #[test]
+    fn file_without_footer_is_indexed_by_scanning_for_mcap() {
+        file_without_footer_is_indexed_by_scanning(DataRepresentation::Mcap);
+    }
+}
@@ -146,6 +146,16 @@ mod recorder_replayer {
         );
     }
 
+    #[test]
+    fn record_and_replay_works_for_mcap() {
+        record_and_replay_works(
+            DataRepresentation::Mcap,
+            MessagingPattern::RequestResponse,
+            generate_type_detail(TypeVariant::FixedSize, 32, 8),
+            89,
+        );
+    }
+
     #[test]
     fn record_and_replay_works_for_iox2dump_with_unit_user_header() {
         record_and_replay_works(
@@ -166,6 +176,16 @@ mod recorder_replayer {
         );
     }
 
+    #[test]
+    fn record_and_replay_works_for_mcap_with_unit_user_header() {
+        record_and_replay_works(
+            DataRepresentation::Mcap,
+            MessagingPattern::RequestResponse,
+            TypeDetail::new::<()>(TypeVariant::FixedSize),
+            99,
+        );
+    }
+
     #[test]
     fn record_and_replay_works_with_empty_record_for_iox2dump() {
         record_and_replay_works(
@@ -186,6 +206,16 @@ mod recorder_replayer {
         );
     }
 
+    #[test]
+    fn record_and_replay_works_with_empty_record_for_mcap() {
+        record_and_replay_works(
+            DataRepresentation::Mcap,
+            MessagingPattern::RequestResponse,
+            generate_type_detail(TypeVariant::FixedSize, 32, 8),
+            0,
+        );
+    }
+
     fn record_and_replay_with_dynamic_payload_works(
         data_representation: DataRepresentation,
         messaging_pattern: MessagingPattern,
@@ -260,6 +290,14 @@ mod recorder_replayer {
         );
     }
 
+    #[test]
+    fn record_and_replay_with_dynamic_payload_works_for_mcap() {
+        record_and_replay_with_dynamic_payload_works(
+            DataRepresentation::Mcap,
+            MessagingPattern::RequestResponse,
+        );
+    }
+
     fn writing_decreasing_timestamps_fails(data_representation: DataRepresentation) {
         let service_name = iceoryx2::testing::generate_service_name();
         let file_name = generate_file_path();
@@ -312,6 +350,11 @@ mod recorder_replayer {
         writing_decreasing_timestamps_fails(DataRepresentation::HumanReadable);
     }
 
+    #[test]
+    fn writing_decreasing_timestamps_fails_for_mcap() {
+        writing_decreasing_timestamps_fails(DataRepresentation::Mcap);
+    }
+
     fn writing_invalid_fixed_size_payload_fails(
         data_representation: DataRepresentation,
         messaging_pattern: MessagingPattern,
@@ -372,6 +415,14 @@ mod recorder_replayer {
         );
     }
 
+    #[test]
+    fn writing_invalid_fixed_size_payload_fails_for_mcap() {
+        writing_invalid_fixed_size_payload_fails(
+            DataRepresentation::Mcap,
+            MessagingPattern::RequestResponse,
+        );
+    }
+
     fn writing_invalid_dynamic_payload_fails(
         data_representation: DataRepresentation,
         messaging_pattern: MessagingPattern,
@@ -432,6 +483,14 @@ mod recorder_replayer {
         );
     }
 
+    #[test]
+    fn writing_invalid_dynamic_payload_fails_for_mcap() {
+        writing_invalid_dynamic_payload_fails(
+            DataRepresentation::Mcap,
+            MessagingPattern::RequestResponse,
+        );
+    }
+
     fn writing_invalid_user_header_fails(
         data_representation: DataRepresentation,
         messaging_pattern: MessagingPattern,
@@ -495,6 +554,15 @@ mod recorder_replayer {
         );
     }
 
+    #[test]
+    fn writing_invalid_user_header_fails_for_mcap() {
+        writing_invalid_user_header_fails(
+            DataRepresentation::Mcap,
+            MessagingPattern::RequestResponse,
+            generate_type_detail(TypeVariant::FixedSize, 8, 4),
+        );
+    }
+
     #[test]
     fn writing_invalid_user_header_for_unit_type_fails_for_iox2dump() {
         writing_invalid_user_header_fails(
@@ -513,6 +581,15 @@ mod recorder_replayer {
         );
     }
 
+    #[test]
+    fn writing_invalid_user_header_for_unit_type_fails_for_mcap() {
+        writing_invalid_user_header_fails(
+            DataRepresentation::Mcap,
+            MessagingPattern::RequestResponse,
+            TypeDetail::new::<()>(TypeVariant::FixedSize),
+        );
+    }
+
     fn writing_invalid_system_header_fails(
         data_representation: DataRepresentation,
         messaging_pattern: MessagingPattern,
@@ -574,6 +651,14 @@ mod recorder_replayer {
         );
     }
 
+    #[test]
+    fn writing_invalid_system_header_fails_for_mcap() {
+        writing_invalid_system_header_fails(
+            DataRepresentation::Mcap,
+            MessagingPattern::PublishSubscribe,
+        );
+    }
+
     fn record_and_replay_by_reading_step_by_step_works(
         data_representation: DataRepresentation,
         messaging_pattern: MessagingPattern,
@@ -644,6 +729,14 @@ mod recorder_replayer {
         );
     }
 
+    #[test]
+    fn record_and_replay_by_reading_step_by_step_works_for_mcap() {
+        record_and_replay_by_reading_step_by_step_works(
+            DataRepresentation::Mcap,
+            MessagingPattern::PublishSubscribe,
+        );
+    }
+
     fn reading_corrupted_payload_fails(
         data_representation: DataRepresentation,
         messaging_pattern: MessagingPattern,
@@ -707,6 +800,14 @@ mod recorder_replayer {
         );
     }
 
+    #[test]
+    fn reading_corrupted_payload_fails_for_mcap() {
+        reading_corrupted_payload_fails(
+            DataRepresentation::Mcap,
+            MessagingPattern::PublishSubscribe,
+        );
+    }
+
     fn reading_corrupted_user_header_fails(
         data_representation: DataRepresentation,
         messaging_pattern: MessagingPattern,
@@ -889,4 +990,9 @@ mod recorder_replayer {
     fn reading_decreasing_timestamps_fails_for_human_readable() {
         reading_decreasing_timestamps_fails(DataRepresentation::HumanReadable);
     }
+
+    #[test]
+    fn reading_decreasing_timestamps_fails_for_mcap() {
+        reading_decreasing_timestamps_fails(DataRepresentation::Mcap);
+    }
 }
@@ -0,0 +1,237 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(test)]
+mod request_response_record {
+    use core::time::Duration;
+
+    use iceoryx2::prelude::MessagingPattern;
+    use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
+    use iceoryx2_bb_posix::file::File;
+    use iceoryx2_bb_posix::testing::generate_file_path;
+    use iceoryx2_bb_system_types::file_path::FilePath;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_userland_record_and_replay::record::{DataRepresentation, RawRecord, Record};
+    use iceoryx2_userland_record_and_replay::recorder::{
+        Recorder, RecorderBuilder, RecorderWriteError, ServiceTypes,
+    };
+    use iceoryx2_userland_record_and_replay::replayer::ReplayerOpener;
+    use iceoryx2_userland_record_and_replay::request_response::{
+        RequestResponseRecordHeader, RequestResponseRecordKind, ResponseMismatch,
+        ResponseMismatchKind, diff_responses,
+    };
+
+    const CLIENT_ID: u128 = 0xc11e;
+    const SERVER_ID: u128 = 0x5e4e;
+
+    fn request_types() -> ServiceTypes {
+        RequestResponseRecordHeader::service_types(
+            &TypeDetail::new::<u64>(TypeVariant::FixedSize),
+            &TypeDetail::new::<u16>(TypeVariant::FixedSize),
+        )
+    }
+
+    fn response_types() -> ServiceTypes {
+        RequestResponseRecordHeader::service_types(
+            &TypeDetail::new::<u8>(TypeVariant::Dynamic),
+            &TypeDetail::new::<u32>(TypeVariant::FixedSize),
+        )
+    }
+
+    fn create_recorder(file_name: &FilePath, data_representation: DataRepresentation) -> Recorder {
+        RecorderBuilder::new(&request_types())
+            .data_representation(data_representation)
+            .messaging_pattern(MessagingPattern::RequestResponse)
+            .response_types(&response_types())
+            .create(file_name, &iceoryx2::testing::generate_service_name())
+            .unwrap()
+    }
+
+    fn request(request_number: u64) -> Record {
+        Record {
+            timestamp: Duration::from_millis(request_number),
+            system_header: RequestResponseRecordHeader::request(CLIENT_ID, request_number)
+                .to_bytes()
+                .to_vec(),
+            user_header: vec![1; 2],
+            payload: request_number.to_le_bytes().to_vec(),
+        }
+    }
+
+    fn response(request_number: u64, sequence_number: u64, payload: &[u8]) -> Record {
+        Record {
+            timestamp: Duration::from_millis(request_number),
+            system_header: RequestResponseRecordHeader::response(
+                CLIENT_ID,
+                SERVER_ID,
+                request_number,
+                sequence_number,
+            )
+            .to_bytes()
+            .to_vec(),
+            user_header: vec![2; 4],
+            payload: payload.to_vec(),
+        }
+    }
+
+    fn write(recorder: &mut Recorder, record: &Record) -> Result<(), RecorderWriteError> {
+        recorder.write(RawRecord {
+            timestamp: record.timestamp,
+            system_header: &record.system_header,
+            user_header: &record.user_header,
+            payload: &record.payload,
+        })
+    }
+
+    #[test]
+    fn header_can_be_serialized_and_deserialized() {
+        let sut = RequestResponseRecordHeader::response(CLIENT_ID, SERVER_ID, 91, 3);
+
+        assert_that!(RequestResponseRecordHeader::from_bytes(&sut.to_bytes()), eq Some(sut));
+
+        let sut = RequestResponseRecordHeader::request(CLIENT_ID, 92);
+        let restored = RequestResponseRecordHeader::from_bytes(&sut.to_bytes()).unwrap();
+        assert_that!(restored.kind, eq RequestResponseRecordKind::Request);
+        assert_that!(restored.server_id, eq 0);
+        assert_that!(restored.request_number, eq 92);
+    }
+
+    #[test]
+    fn header_with_invalid_kind_cannot_be_deserialized() {
+        let mut bytes = RequestResponseRecordHeader::request(CLIENT_ID, 1).to_bytes();
+        bytes[48] = 2;
+
+        assert_that!(RequestResponseRecordHeader::from_bytes(&bytes), is_none);
+        assert_that!(
+            RequestResponseRecordHeader::from_bytes(&bytes[..8]),
+            is_none
+        );
+    }
+
+    fn requests_and_responses_can_be_replayed(data_representation: DataRepresentation) {
+        let file_name = generate_file_path();
+        let records = [
+            request(0),
+            response(0, 0, &[1, 2, 3]),
+            response(0, 1, &[]),
+            request(1),
+            response(1, 0, &[4; 17]),
+        ];
+
+        let mut recorder = create_recorder(&file_name, data_representation);
+        for record in &records {
+            assert_that!(write(&mut recorder, record), is_ok);
+        }
+        drop(recorder);
+
+        let sut = ReplayerOpener::new(&file_name)
+            .data_representation(data_representation)
+            .open()
+            .unwrap();
+        assert_that!(sut.header().details.response_types, eq Some(response_types()));
+        assert_that!(sut.header().details.messaging_pattern, eq MessagingPattern::RequestResponse);
+
+        let buffer = sut.read_into_buffer().unwrap();
+        assert_that!(buffer, len records.len());
+        for (replayed, recorded) in buffer.iter().zip(records.iter()) {
+            assert_that!(replayed.system_header, eq recorded.system_header);
+            assert_that!(replayed.user_header, eq recorded.user_header);
+            assert_that!(replayed.payload, eq recorded.payload);
+        }
+        assert_that!(diff_responses(&records, &buffer), is_empty);
+
+        File::remove(&file_name).unwrap();
+    }
+
+    #[test]
+    fn requests_and_responses_can_be_replayed_for_iox2dump() {
+        requests_and_responses_can_be_replayed(DataRepresentation::Iox2Dump);
+    }
+
+    #[test]
+    fn requests_and_responses_can_be_replayed_for_human_readable() {
+        requests_and_responses_can_be_replayed(DataRepresentation::HumanReadable);
+    }
+
+    #[test]
+    fn requests_and_responses_can_be_replayed_for_mcap() {
+        requests_and_responses_can_be_replayed(DataRepresentation::Mcap);
+    }
+
+    #[test]
+    fn response_with_request_types_cannot_be_written() {
+        let file_name = generate_file_path();
+        let mut recorder = create_recorder(&file_name, DataRepresentation::HumanReadable);
+
+        let mut record = response(0, 0, &[1]);
+        record.user_header = vec![0; 2];
+        assert_that!(
+            write(&mut recorder, &record).err(),
+            eq Some(RecorderWriteError::CorruptedUserHeaderRecord)
+        );
+
+        let mut record = request(0);
+        record.payload = vec![0; 3];
+        assert_that!(
+            write(&mut recorder, &record).err(),
+            eq Some(RecorderWriteError::CorruptedPayloadRecord)
+        );
+
+        drop(recorder);
+        File::remove(&file_name).unwrap();
+    }
+
+    #[test]
+    fn diff_ignores_requests_timestamps_and_ids() {
+        let recorded = [request(0), response(0, 0, &[1, 2])];
+        let mut produced = response(0, 0, &[1, 2]);
+        produced.timestamp = Duration::from_secs(99);
+        produced.system_header = RequestResponseRecordHeader::response(7, 8, 0, 0)
+            .to_bytes()
+            .to_vec();
+
+        assert_that!(diff_responses(&recorded, [&produced]), is_empty);
+    }
+
+    #[test]
+    fn diff_detects_all_mismatches() {
+        let recorded = [
+            request(0),
+            response(0, 0, &[1, 2]),
+            response(0, 1, &[3]),
+            request(1),
+            response(1, 0, &[4]),
+        ];
+
+        let mut changed_user_header = response(1, 0, &[5]);
+        changed_user_header.user_header = vec![9; 4];
+        let produced = [
+            response(0, 0, &[1, 2]),
+            changed_user_header,
+            response(1, 1, &[6]),
+        ];
+
+        let sut = diff_responses(&recorded, &produced);
+
+        let mismatch = |request_number, sequence_number, kind| ResponseMismatch {
+            request_number,
+            sequence_number,
+            kind,
+        };
+        assert_that!(sut, eq vec![
+            mismatch(0, 1, ResponseMismatchKind::Missing),
+            mismatch(1, 0, ResponseMismatchKind::UserHeaderDiffers),
+            mismatch(1, 0, ResponseMismatchKind::PayloadDiffers),
+            mismatch(1, 1, ResponseMismatchKind::Unexpected),
+        ]);
+    }
+}
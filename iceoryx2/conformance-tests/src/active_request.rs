@@ -17,12 +17,15 @@ use iceoryx2_bb_testing_macros::conformance_tests;
 pub mod active_request {
     use iceoryx2::port::client::Client;
     use iceoryx2::port::server::Server;
+    use iceoryx2::port::{BackpressureAction, SendError};
     use iceoryx2::service::port_factory::request_response::PortFactory;
     use iceoryx2::{node::Node, prelude::ZeroCopySend, service::Service};
     use iceoryx2_bb_testing::assert_that;
     use iceoryx2_bb_testing_macros::conformance_test;
     use iceoryx2_testing::*;
 
+    const RESPONSE_BUFFER_SIZE: usize = 4;
+
     struct TestFixture<Sut: Service> {
         context: Test<Sut>,
         _node: Node<Sut>,
@@ -42,6 +45,7 @@ pub mod active_request {
                 .request_user_header::<u64>()
                 .max_servers(1)
                 .max_clients(2)
+                .max_response_buffer_size(RESPONSE_BUFFER_SIZE)
                 .create()
                 .unwrap();
             let client = service.client_builder().create().unwrap();
@@ -310,4 +314,115 @@ pub mod active_request {
         assert_that!(*sut.user_header(), eq USER_HEADER);
         assert_that!(*sut.payload(), eq PAYLOAD);
     }
+    #[conformance_test]
+    pub fn is_cancelled_when_client_cancels_the_pending_response<Sut: Service>() {
+        let test = TestFixture::<Sut>::new();
+        let pending_response = test.client.send_copy(123).unwrap();
+        let sut = test.server.receive().unwrap().unwrap();
+
+        assert_that!(sut.is_cancelled(), eq false);
+        pending_response.cancel();
+
+        assert_that!(sut.is_cancelled(), eq true);
+        assert_that!(sut.is_connected(), eq true);
+    }
+
+    #[conformance_test]
+    pub fn responses_have_increasing_sequence_numbers<Sut: Service>() {
+        const NUMBER_OF_RESPONSES: u64 = RESPONSE_BUFFER_SIZE as u64;
+        let test = TestFixture::<Sut>::new();
+        let pending_response = test.client.send_copy(123).unwrap();
+        let sut = test.server.receive().unwrap().unwrap();
+
+        for n in 0..NUMBER_OF_RESPONSES {
+            sut.send_copy(n).unwrap();
+        }
+
+        for n in 0..NUMBER_OF_RESPONSES {
+            let response = pending_response.receive().unwrap().unwrap();
+            assert_that!(response.header().sequence_number(), eq n);
+            assert_that!(*response.payload(), eq n);
+        }
+    }
+
+    #[conformance_test]
+    pub fn sequence_number_gap_reveals_overflowed_responses<Sut: Service>() {
+        const NUMBER_OF_OVERFLOWED_RESPONSES: u64 = 2;
+        let test = TestFixture::<Sut>::new();
+        let pending_response = test.client.send_copy(123).unwrap();
+        let sut = test.server.receive().unwrap().unwrap();
+
+        for n in 0..RESPONSE_BUFFER_SIZE as u64 + NUMBER_OF_OVERFLOWED_RESPONSES {
+            sut.send_copy(n).unwrap();
+        }
+
+        let response = pending_response.receive().unwrap().unwrap();
+        assert_that!(response.header().sequence_number(), eq NUMBER_OF_OVERFLOWED_RESPONSES);
+    }
+
+    #[conformance_test]
+    pub fn undelivered_response_does_not_consume_a_sequence_number<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service = node
+            .service_builder(&generate_service_name())
+            .request_response::<u64, u64>()
+            .max_response_buffer_size(1)
+            .enable_safe_overflow_for_responses(false)
+            .create()
+            .unwrap();
+        let client = service.client_builder().create().unwrap();
+        let server = service
+            .server_builder()
+            .set_backpressure_handler(|_| BackpressureAction::DiscardDataAndFail)
+            .create()
+            .unwrap();
+
+        let pending_response = client.send_copy(123).unwrap();
+        let sut = server.receive().unwrap().unwrap();
+
+        assert_that!(sut.send_copy(0), is_ok);
+        assert_that!(sut.send_copy(1).err(), eq Some(SendError::UnableToDeliver));
+
+        let response = pending_response.receive().unwrap().unwrap();
+        assert_that!(response.header().sequence_number(), eq 0);
+        drop(response);
+
+        assert_that!(sut.send_copy(2), is_ok);
+        let response = pending_response.receive().unwrap().unwrap();
+        assert_that!(response.header().sequence_number(), eq 1);
+        assert_that!(*response.payload(), eq 2);
+    }
+
+    #[conformance_test]
+    pub fn finish_delivers_status_code_after_all_responses<Sut: Service>() {
+        const STATUS_CODE: u64 = 8912;
+        let test = TestFixture::<Sut>::new();
+        let pending_response = test.client.send_copy(123).unwrap();
+        let sut = test.server.receive().unwrap().unwrap();
+
+        sut.send_copy(1).unwrap();
+        sut.send_copy(2).unwrap();
+        assert_that!(sut.finish(STATUS_CODE), is_ok);
+
+        assert_that!(pending_response.is_finished(), eq false);
+        assert_that!(*pending_response.receive().unwrap().unwrap(), eq 1);
+        assert_that!(*pending_response.receive().unwrap().unwrap(), eq 2);
+        assert_that!(pending_response.receive().unwrap(), is_none);
+
+        assert_that!(pending_response.is_finished(), eq true);
+        assert_that!(pending_response.finish_status(), eq Some(STATUS_CODE));
+        assert_that!(pending_response.is_connected(), eq false);
+    }
+
+    #[conformance_test]
+    pub fn finish_succeeds_when_pending_response_is_dropped<Sut: Service>() {
+        let test = TestFixture::<Sut>::new();
+        let pending_response = test.client.send_copy(123).unwrap();
+        let sut = test.server.receive().unwrap().unwrap();
+
+        drop(pending_response);
+
+        assert_that!(sut.finish(0), is_ok);
+    }
 }
@@ -34,6 +34,8 @@ use iceoryx2_cal::static_storage::StaticStorage;
 #[conformance_tests]
 pub mod node_death {
 
+    use iceoryx2::port::notifier::NotifierNotifyError;
+    use iceoryx2::port::writer::EntryHandleMutError;
     use iceoryx2_bb_testing::watchdog::Watchdog;
 
     use super::*;
@@ -259,6 +261,65 @@ pub mod node_death {
         assert_that!(received_events, eq 1);
     }
 
+    #[conformance_test]
+    pub fn notification_data_blocked_by_dead_notifier_is_released<S: iceoryx2::service::Service>() {
+        let test = Test::<S>::new();
+
+        let service_name = generate_service_name();
+        let event_id = EventId::new(3);
+
+        let dead_node = test.create_node();
+        let node = test.create_node();
+
+        let dead_service = dead_node
+            .service_builder(&service_name)
+            .event()
+            .notification_data_size(8)
+            .create()
+            .unwrap();
+        let dead_notifier = dead_service.notifier_builder().create().unwrap();
+
+        let service = node.service_builder(&service_name).event().open().unwrap();
+        let notifier = service.notifier_builder().create().unwrap();
+        let listener = service.listener_builder().create().unwrap();
+
+        // the notifier of the dead node died while writing the notification data
+        assert_that!(
+            dead_notifier.notify_with_custom_event_id_and_data(event_id, b"old"),
+            is_ok
+        );
+        assert_that!(
+            event_abandon_notification_data_write(service.dynamic_config(), event_id),
+            eq true
+        );
+
+        dead_node.abandon();
+        dead_notifier.abandon();
+        dead_service.abandon();
+
+        assert_that!(
+            notifier.notify_with_custom_event_id_and_data(event_id, b"blocked").err(),
+            eq Some(NotifierNotifyError::UnableToStoreNotificationData)
+        );
+
+        assert_that!(node.try_cleanup_dead_nodes(), eq CleanupState { cleanups: 1, failed_cleanups: 0});
+
+        assert_that!(
+            notifier.notify_with_custom_event_id_and_data(event_id, b"new"),
+            is_ok
+        );
+
+        let mut received = vec![];
+        listener
+            .try_wait_with_data(|event, data| {
+                if event.id == event_id {
+                    received.push(data.to_vec());
+                }
+            })
+            .unwrap();
+        assert_that!(received, eq vec![b"new".to_vec()]);
+    }
+
     #[conformance_test]
     pub fn dead_node_is_removed_from_request_response_service<S: iceoryx2::service::Service>() {
         test_requires!(does_support_persistency::<S>());
@@ -445,6 +506,57 @@ pub mod node_death {
         assert_that!(*reader.entry::<u64>(&0).unwrap().get(), eq 1);
     }
 
+    #[conformance_test]
+    pub fn blackboard_entry_ownership_is_released_when_writer_node_dies<
+        S: iceoryx2::service::Service,
+    >() {
+        let test = Test::<S>::new();
+        let service_name = generate_service_name();
+
+        let good_node = test.create_node();
+        let good_service = good_node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .max_writers(2)
+            .add_with_default::<u64>(0)
+            .add_with_default::<u64>(1)
+            .create()
+            .unwrap();
+        let good_writer = good_service.writer_builder().create().unwrap();
+        let reader = good_service.reader_builder().create().unwrap();
+
+        let bad_node = test.create_node();
+        let bad_service = bad_node
+            .service_builder(&service_name)
+            .blackboard_opener::<u64>()
+            .open()
+            .unwrap();
+        let bad_writer = bad_service.writer_builder().create().unwrap();
+        let bad_entry_handle_mut = bad_writer.entry::<u64>(&0).unwrap();
+        let bad_entry_value_uninit = bad_writer.entry::<u64>(&1).unwrap().loan_uninit();
+
+        assert_that!(good_writer.entry::<u64>(&0).err(), eq Some(EntryHandleMutError::EntryOwnedByAnotherWriter));
+        assert_that!(good_writer.entry::<u64>(&1).err(), eq Some(EntryHandleMutError::EntryOwnedByAnotherWriter));
+
+        bad_node.abandon();
+        bad_entry_handle_mut.abandon();
+        bad_entry_value_uninit.abandon();
+        bad_writer.abandon();
+        bad_service.abandon();
+
+        assert_that!(good_node.try_cleanup_dead_nodes(), eq CleanupState { cleanups: 1, failed_cleanups: 0});
+        assert_that!(good_service.dynamic_config().number_of_writers(), eq 1);
+        assert_that!(good_service.dynamic_config().number_of_owned_entries(), eq 0);
+
+        let entry_handle_mut = good_writer.entry::<u64>(&0).unwrap();
+        entry_handle_mut.update_with_copy(5);
+        assert_that!(*reader.entry::<u64>(&0).unwrap().get(), eq 5);
+
+        let entry_handle_mut = good_writer.entry::<u64>(&1).unwrap();
+        entry_handle_mut.update_with_copy(7);
+        assert_that!(*reader.entry::<u64>(&1).unwrap().get(), eq 7);
+    }
+
     #[conformance_test]
     pub fn event_service_is_removed_when_last_node_dies<S: iceoryx2::service::Service>() {
         let test = Test::<S>::new();
@@ -20,6 +20,7 @@ pub mod reader {
     use alloc::vec;
 
     use iceoryx2::constants::MAX_BLACKBOARD_KEY_SIZE;
+    use iceoryx2::port::blackboard_snapshot::*;
     use iceoryx2::port::reader::*;
     use iceoryx2::prelude::*;
     use iceoryx2::service::Service;
@@ -27,8 +28,11 @@ pub mod reader {
     use iceoryx2::service::builder::blackboard::KeyMemory;
     use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
     use iceoryx2::testing::generate_service_name;
+    use iceoryx2_bb_posix::file::File;
+    use iceoryx2_bb_posix::testing::generate_file_path;
     use iceoryx2_bb_testing::assert_that;
     use iceoryx2_bb_testing_macros::conformance_test;
+    use iceoryx2_cal::serialize::postcard::Postcard;
     use iceoryx2_testing::*;
 
     #[conformance_test]
@@ -269,4 +273,116 @@ pub mod reader {
             eq EntryHandleError::EntryDoesNotExist
         );
     }
+
+    #[conformance_test]
+    pub fn snapshot_contains_all_entries<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let service_name = generate_service_name();
+        let node = test.create_node();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u8>(0, 3)
+            .add::<i32>(1, -4)
+            .add::<u64>(2, 5)
+            .create()
+            .unwrap();
+
+        let reader = sut.reader_builder().create().unwrap();
+        let snapshot = reader.snapshot().unwrap();
+
+        assert_that!(snapshot.len(), eq 3);
+        assert_that!(snapshot.get::<u8>(&0), eq Some(3));
+        assert_that!(snapshot.get::<i32>(&1), eq Some(-4));
+        assert_that!(snapshot.get::<u64>(&2), eq Some(5));
+    }
+
+    #[conformance_test]
+    pub fn snapshot_does_not_provide_value_for_wrong_key_or_value_type<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let service_name = generate_service_name();
+        let node = test.create_node();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u8>(0, 3)
+            .create()
+            .unwrap();
+
+        let reader = sut.reader_builder().create().unwrap();
+        let snapshot = reader.snapshot().unwrap();
+
+        assert_that!(snapshot.get::<u8>(&1), eq None);
+        assert_that!(snapshot.get::<i8>(&0), eq None);
+    }
+
+    #[conformance_test]
+    pub fn snapshot_is_not_affected_by_later_updates<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let service_name = generate_service_name();
+        let node = test.create_node();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u64>(0, 0)
+            .add::<u64>(1, 1)
+            .create()
+            .unwrap();
+
+        let reader = sut.reader_builder().create().unwrap();
+        let writer = sut.writer_builder().create().unwrap();
+        let entry_handle_mut = writer.entry::<u64>(&0).unwrap();
+        entry_handle_mut.update_with_copy(10);
+
+        let snapshot = reader.snapshot().unwrap();
+        entry_handle_mut.update_with_copy(20);
+
+        assert_that!(snapshot.get::<u64>(&0), eq Some(10));
+        assert_that!(snapshot.get::<u64>(&1), eq Some(1));
+        assert_that!(reader.snapshot().unwrap().get::<u64>(&0), eq Some(20));
+    }
+
+    #[conformance_test]
+    pub fn snapshot_can_be_saved_to_and_loaded_from_file<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let service_name = generate_service_name();
+        let node = test.create_node();
+        let file_path = generate_file_path();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u16>(0, 16)
+            .add::<i64>(1, -64)
+            .create()
+            .unwrap();
+
+        let reader = sut.reader_builder().create().unwrap();
+        let snapshot = reader.snapshot().unwrap();
+
+        assert_that!(snapshot.save_to_file::<Postcard>(&file_path), is_ok);
+        let loaded_snapshot = BlackboardSnapshot::<u64>::load_from_file::<Postcard>(&file_path);
+        File::remove(&file_path).unwrap();
+
+        assert_that!(loaded_snapshot, is_ok);
+        let loaded_snapshot = loaded_snapshot.unwrap();
+        assert_that!(loaded_snapshot, eq snapshot);
+        assert_that!(loaded_snapshot.get::<u16>(&0), eq Some(16));
+        assert_that!(loaded_snapshot.get::<i64>(&1), eq Some(-64));
+    }
+
+    #[conformance_test]
+    pub fn loading_snapshot_from_non_existing_file_fails<Sut: Service>() {
+        let file_path = generate_file_path();
+
+        let loaded_snapshot = BlackboardSnapshot::<u64>::load_from_file::<Postcard>(&file_path);
+
+        assert_that!(
+            loaded_snapshot.err(),
+            eq Some(BlackboardSnapshotLoadError::SnapshotFileDoesNotExist)
+        );
+    }
 }
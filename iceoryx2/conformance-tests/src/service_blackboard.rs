@@ -295,6 +295,39 @@ pub mod service_blackboard {
         assert_that!(sut2, is_ok);
     }
 
+    #[conformance_test]
+    pub fn open_fails_when_service_does_not_satisfy_max_writers_requirement<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u8>(0, 0)
+            .max_writers(2)
+            .create();
+        assert_that!(sut, is_ok);
+        assert_that!(sut.as_ref().unwrap().static_config().max_writers(), eq 2);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .blackboard_opener::<u64>()
+            .max_writers(3)
+            .open();
+
+        assert_that!(sut2, is_err);
+        assert_that!(
+    sut2.err().unwrap(), eq BlackboardOpenError::DoesNotSupportRequestedAmountOfWriters);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .blackboard_opener::<u64>()
+            .max_writers(2)
+            .open();
+
+        assert_that!(sut2, is_ok);
+    }
+
     #[conformance_test]
     pub fn open_does_not_fail_when_service_owner_is_dropped<Sut: Service>() {
         let test = Test::<Sut>::new();
@@ -2301,6 +2334,158 @@ pub mod service_blackboard {
         assert_that!(keys, len 1);
     }
 
+    #[conformance_test]
+    pub fn list_entries_works_when_custom_key_type_is_used<S: Service>() {
+        let test = Test::<S>::new();
+        let node = test.create_node();
+        type KeyType = Foo;
+        let key_1 = Foo {
+            a: 1,
+            b: 1,
+            c: StaticString::new(),
+        };
+        let key_ptr_1: *const KeyType = &key_1;
+        let key_2 = Foo {
+            a: 2,
+            b: 2,
+            c: StaticString::new(),
+        };
+        let key_ptr_2: *const KeyType = &key_2;
+        let value_1 = 0u64;
+        let value_ptr_1: *const u64 = &value_1;
+        let value_2 = 0u16;
+        let value_ptr_2: *const u16 = &value_2;
+
+        let service_name = generate_service_name();
+        let service = unsafe {
+            node.service_builder(&service_name)
+                .blackboard_creator::<CustomKeyMarker>()
+                .__internal_set_key_type_details(&TypeDetail::new::<KeyType>(
+                    TypeVariant::FixedSize,
+                ))
+                .__internal_set_key_eq_cmp_func(Box::new(move |lhs: *const u8, rhs: *const u8| {
+                    KeyMemory::<MAX_BLACKBOARD_KEY_SIZE>::key_eq_comparison(lhs, rhs, &cmp_for_foo)
+                }))
+                .__internal_add(
+                    key_ptr_1 as *const u8,
+                    value_ptr_1 as *mut u8,
+                    TypeDetail::new::<u64>(TypeVariant::FixedSize),
+                    Box::new(|| {}),
+                )
+                .__internal_add(
+                    key_ptr_2 as *const u8,
+                    value_ptr_2 as *mut u8,
+                    TypeDetail::new::<u16>(TypeVariant::FixedSize),
+                    Box::new(|| {}),
+                )
+                .create()
+                .unwrap()
+        };
+
+        let mut entries = vec![];
+        service.__internal_list_entries(|key_ptr: *const u8, type_details: &TypeDetail| {
+            let key = unsafe { *(key_ptr as *const Foo) };
+            entries.push((key, *type_details));
+            CallbackProgression::Continue
+        });
+        assert_that!(entries, len 2);
+        assert_that!(
+            entries.contains(&(key_1, TypeDetail::new::<u64>(TypeVariant::FixedSize))),
+            eq true
+        );
+        assert_that!(
+            entries.contains(&(key_2, TypeDetail::new::<u16>(TypeVariant::FixedSize))),
+            eq true
+        );
+
+        entries.clear();
+        service.__internal_list_entries(|key_ptr: *const u8, type_details: &TypeDetail| {
+            let key = unsafe { *(key_ptr as *const Foo) };
+            entries.push((key, *type_details));
+            CallbackProgression::Stop
+        });
+        assert_that!(entries, len 1);
+    }
+
+    #[conformance_test]
+    pub fn opener_with_custom_key_type_uses_provided_key_eq_func<S: Service>() {
+        let test = Test::<S>::new();
+        let node = test.create_node();
+        type KeyType = Foo;
+        // both keys share the first byte so that they can only be distinguished by the
+        // complete comparison
+        let key_1 = Foo {
+            a: 1,
+            b: 1,
+            c: StaticString::new(),
+        };
+        let key_ptr_1: *const KeyType = &key_1;
+        let key_2 = Foo {
+            a: 1,
+            b: 2,
+            c: StaticString::new(),
+        };
+        let key_ptr_2: *const KeyType = &key_2;
+        let value_1 = 11u64;
+        let value_ptr_1: *const u64 = &value_1;
+        let value_2 = 22u64;
+        let value_ptr_2: *const u64 = &value_2;
+        let type_details = TypeDetail::new::<u64>(TypeVariant::FixedSize);
+
+        let service_name = generate_service_name();
+        let _creator = unsafe {
+            node.service_builder(&service_name)
+                .blackboard_creator::<CustomKeyMarker>()
+                .__internal_set_key_type_details(&TypeDetail::new::<KeyType>(
+                    TypeVariant::FixedSize,
+                ))
+                .__internal_set_key_eq_cmp_func(Box::new(move |lhs: *const u8, rhs: *const u8| {
+                    KeyMemory::<MAX_BLACKBOARD_KEY_SIZE>::key_eq_comparison(lhs, rhs, &cmp_for_foo)
+                }))
+                .__internal_add(
+                    key_ptr_1 as *const u8,
+                    value_ptr_1 as *mut u8,
+                    type_details,
+                    Box::new(|| {}),
+                )
+                .__internal_add(
+                    key_ptr_2 as *const u8,
+                    value_ptr_2 as *mut u8,
+                    type_details,
+                    Box::new(|| {}),
+                )
+                .create()
+                .unwrap()
+        };
+
+        let sut = unsafe {
+            node.service_builder(&service_name)
+                .blackboard_opener::<CustomKeyMarker>()
+                .__internal_set_key_type_details(&TypeDetail::new::<KeyType>(
+                    TypeVariant::FixedSize,
+                ))
+                .__internal_set_key_eq_cmp_func(Box::new(move |lhs: *const u8, rhs: *const u8| {
+                    KeyMemory::<MAX_BLACKBOARD_KEY_SIZE>::key_eq_comparison(lhs, rhs, &cmp_for_foo)
+                }))
+                .open()
+                .unwrap()
+        };
+        let reader = sut.reader_builder().create().unwrap();
+
+        let entry_handle =
+            unsafe { reader.__internal_entry(key_ptr_2 as *const u8, &type_details) }.unwrap();
+        let mut read_value = 0u64;
+        unsafe {
+            entry_handle.get(
+                (&mut read_value as *mut u64).cast(),
+                size_of::<u64>(),
+                align_of::<u64>(),
+                core::ptr::null_mut::<u64>(),
+            );
+        }
+        assert_that!(read_value, eq value_2);
+    }
+
     #[conformance_test]
     pub fn key_memory_creation_fails_when_value_is_too_large<Sut: Service>() {
         let key: u16 = 256;
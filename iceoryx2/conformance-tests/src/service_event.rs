@@ -16,7 +16,7 @@ use iceoryx2_bb_testing_macros::conformance_tests;
 #[conformance_tests]
 pub mod service_event {
     use alloc::collections::BTreeSet;
-    use alloc::{format, vec};
+    use alloc::{format, vec, vec::Vec};
     use core::time::Duration;
     use iceoryx2_bb_posix::barrier::{BarrierBuilder, BarrierHandle};
     use iceoryx2_bb_posix::clock::Time;
@@ -28,6 +28,7 @@ pub mod service_event {
     use iceoryx2::port::notifier::{NotifierCreateError, NotifierNotifyError};
     use iceoryx2::prelude::*;
     use iceoryx2::service::builder::event::{EventCreateError, EventOpenError};
+    use iceoryx2::service::dynamic_config::event::MAX_NUMBER_OF_ALLOWED_NOTIFIERS;
     use iceoryx2_bb_concurrency::atomic::Ordering;
     use iceoryx2_bb_concurrency::atomic::{AtomicBool, AtomicU64};
     use iceoryx2_bb_testing::assert_that;
@@ -273,6 +274,40 @@ pub mod service_event {
         assert_that!(sut2, is_ok);
     }
 
+    #[conformance_test]
+    pub fn open_fails_when_service_does_not_satisfy_notification_data_size_requirement<
+        Sut: Service,
+    >() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+        const NOTIFICATION_DATA_SIZE: usize = 12;
+
+        let _sut = node
+            .service_builder(&service_name)
+            .event()
+            .notification_data_size(NOTIFICATION_DATA_SIZE)
+            .create();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .event()
+            .notification_data_size(NOTIFICATION_DATA_SIZE + 1)
+            .open();
+
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq EventOpenError::DoesNotSupportRequestedNotificationDataSize);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .event()
+            .notification_data_size(NOTIFICATION_DATA_SIZE - 1)
+            .open();
+
+        assert_that!(sut2, is_ok);
+        assert_that!(sut2.unwrap().static_config().notification_data_size(), eq NOTIFICATION_DATA_SIZE);
+    }
+
     #[conformance_test]
     pub fn open_uses_predefined_settings_when_nothing_is_specified<Sut: Service>() {
         let test = Test::<Sut>::new();
@@ -1523,4 +1558,356 @@ pub mod service_event {
             .unwrap();
         assert_that!(received_events, eq 1);
     }
+
+    #[conformance_test]
+    pub fn listener_receives_latest_notification_data_of_event_id<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .notification_data_size(16)
+            .create()
+            .unwrap();
+
+        let notifier = sut.notifier_builder().create().unwrap();
+        let listener = sut.listener_builder().create().unwrap();
+
+        assert_that!(
+            notifier.notify_with_custom_event_id_and_data(EventId::new(3), b"first"),
+            is_ok
+        );
+        assert_that!(
+            notifier.notify_with_custom_event_id_and_data(EventId::new(3), b"second data"),
+            is_ok
+        );
+        assert_that!(
+            notifier.notify_with_custom_event_id_and_data(EventId::new(5), &[7; 16]),
+            is_ok
+        );
+
+        let mut received = vec![];
+        listener
+            .try_wait_with_data(|event, data| {
+                received.push((event.id, event.count, data.to_vec()));
+            })
+            .unwrap();
+        received.sort();
+
+        assert_that!(received, len 2);
+        assert_that!(received[0], eq(EventId::new(3), 2, b"second data".to_vec()));
+        assert_that!(received[1], eq(EventId::new(5), 1, vec![7; 16]));
+    }
+
+    #[conformance_test]
+    pub fn listener_receives_empty_data_when_notified_without_data<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .notification_data_size(8)
+            .create()
+            .unwrap();
+
+        let notifier = sut.notifier_builder().create().unwrap();
+        let listener = sut.listener_builder().create().unwrap();
+
+        assert_that!(notifier.notify_with_custom_event_id(EventId::new(1)), is_ok);
+
+        let mut number_of_events = 0;
+        listener
+            .timed_wait_with_data(
+                |_, data| {
+                    assert_that!(data, is_empty);
+                    number_of_events += 1;
+                },
+                TIMEOUT,
+            )
+            .unwrap();
+        assert_that!(number_of_events, eq 1);
+    }
+
+    #[conformance_test]
+    pub fn notify_with_data_larger_than_notification_data_size_fails<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .notification_data_size(4)
+            .create()
+            .unwrap();
+
+        let notifier = sut.notifier_builder().create().unwrap();
+        let listener = sut.listener_builder().create().unwrap();
+
+        let result = notifier.notify_with_data(&[1, 2, 3, 4, 5]);
+        assert_that!(result.err(), eq Some(NotifierNotifyError::NotificationDataTooLarge));
+
+        let mut number_of_events = 0;
+        listener
+            .try_wait_with_data(|_, _| number_of_events += 1)
+            .unwrap();
+        assert_that!(number_of_events, eq 0);
+    }
+
+    #[conformance_test]
+    pub fn notify_with_data_fails_when_service_does_not_support_notification_data<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        let notifier = sut.notifier_builder().create().unwrap();
+
+        assert_that!(notifier.notification_data_size(), eq 0);
+        let result = notifier.notify_with_data(&[1]);
+        assert_that!(result.err(), eq Some(NotifierNotifyError::NotificationDataTooLarge));
+        assert_that!(notifier.notify_with_data(&[]), is_ok);
+    }
+
+    #[conformance_test]
+    pub fn notification_data_is_shared_between_notifiers<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .notification_data_size(8)
+            .create()
+            .unwrap();
+
+        let notifier_1 = sut
+            .notifier_builder()
+            .default_event_id(EventId::new(2))
+            .create()
+            .unwrap();
+        let notifier_2 = sut
+            .notifier_builder()
+            .default_event_id(EventId::new(2))
+            .create()
+            .unwrap();
+        let listener = sut.listener_builder().create().unwrap();
+
+        assert_that!(notifier_1.notify_with_data(&[1, 1]), is_ok);
+        assert_that!(notifier_2.notify_with_data(&[2]), is_ok);
+
+        let mut received = vec![];
+        listener
+            .blocking_wait_with_data(|event, data| {
+                received.push((event.id, data.to_vec()));
+            })
+            .unwrap();
+
+        assert_that!(received, eq vec![(EventId::new(2), vec![2])]);
+    }
+
+    #[conformance_test]
+    pub fn listener_with_event_id_range_filter_receives_only_ids_in_range<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .event_id_max_value(32)
+            .create()
+            .unwrap();
+
+        let notifier = sut.notifier_builder().create().unwrap();
+        let listener = sut
+            .listener_builder()
+            .event_id_filter(EventIdFilter::new().range(EventId::new(8), EventId::new(15)))
+            .create()
+            .unwrap();
+
+        for id in [0, 7, 8, 12, 15, 16, 32] {
+            let number_of_listeners = notifier
+                .notify_with_custom_event_id(EventId::new(id))
+                .unwrap();
+            assert_that!(number_of_listeners, eq usize::from((8..=15).contains(&id)));
+        }
+
+        let mut received = vec![];
+        listener.try_wait(|event| received.push(event.id)).unwrap();
+        received.sort();
+
+        assert_that!(received, eq vec![EventId::new(8), EventId::new(12), EventId::new(15)]);
+    }
+
+    #[conformance_test]
+    pub fn listener_with_event_id_mask_filter_receives_only_matching_ids<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .event_id_max_value(16)
+            .create()
+            .unwrap();
+
+        let notifier = sut.notifier_builder().create().unwrap();
+        let listener = sut
+            .listener_builder()
+            .event_id_filter(EventIdFilter::new().mask(0b11, 0b01))
+            .create()
+            .unwrap();
+
+        for id in 0..=16 {
+            assert_that!(
+                notifier.notify_with_custom_event_id(EventId::new(id)),
+                is_ok
+            );
+        }
+
+        let mut received = vec![];
+        listener.try_wait(|event| received.push(event.id)).unwrap();
+        received.sort();
+
+        assert_that!(received, eq vec![EventId::new(1), EventId::new(5), EventId::new(9), EventId::new(13)]);
+    }
+
+    #[conformance_test]
+    pub fn listener_with_allowed_notifiers_receives_only_their_notifications<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        let allowed_notifier = sut
+            .notifier_builder()
+            .default_event_id(EventId::new(1))
+            .create()
+            .unwrap();
+        let other_notifier = sut
+            .notifier_builder()
+            .default_event_id(EventId::new(2))
+            .create()
+            .unwrap();
+        let listener = sut
+            .listener_builder()
+            .allow_notifier(allowed_notifier.id())
+            .create()
+            .unwrap();
+        let unfiltered_listener = sut.listener_builder().create().unwrap();
+
+        assert_that!(other_notifier.notify(), eq Ok(1));
+        assert_that!(allowed_notifier.notify(), eq Ok(2));
+
+        let mut received = vec![];
+        listener.try_wait(|event| received.push(event.id)).unwrap();
+        assert_that!(received, eq vec![EventId::new(1)]);
+
+        let mut received = vec![];
+        unfiltered_listener
+            .try_wait(|event| received.push(event.id))
+            .unwrap();
+        received.sort();
+        assert_that!(received, eq vec![EventId::new(1), EventId::new(2)]);
+    }
+
+    #[conformance_test]
+    pub fn filtered_notification_does_not_wake_up_listener<Sut: Service>() {
+        const TIMEOUT: Duration = Duration::from_millis(25);
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .event_id_max_value(8)
+            .create()
+            .unwrap();
+
+        let notifier = sut.notifier_builder().create().unwrap();
+        let listener = sut
+            .listener_builder()
+            .event_id_filter(EventIdFilter::new().range(EventId::new(4), EventId::new(8)))
+            .create()
+            .unwrap();
+
+        assert_that!(notifier.notify_with_custom_event_id(EventId::new(3)), eq Ok(0));
+
+        let mut counter = 0;
+        listener.timed_wait(|_| counter += 1, TIMEOUT).unwrap();
+        assert_that!(counter, eq 0);
+    }
+
+    #[conformance_test]
+    pub fn listener_with_too_many_allowed_notifiers_cannot_be_created<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .max_notifiers(MAX_NUMBER_OF_ALLOWED_NOTIFIERS + 1)
+            .create()
+            .unwrap();
+
+        let notifiers: Vec<_> = (0..=MAX_NUMBER_OF_ALLOWED_NOTIFIERS)
+            .map(|_| sut.notifier_builder().create().unwrap())
+            .collect();
+
+        let mut builder = sut.listener_builder();
+        for notifier in notifiers.iter().take(MAX_NUMBER_OF_ALLOWED_NOTIFIERS) {
+            builder = builder.allow_notifier(notifier.id());
+        }
+        let listener = builder.create();
+        assert_that!(listener, is_ok);
+        assert_that!(listener.unwrap().allowed_notifiers().len(), eq MAX_NUMBER_OF_ALLOWED_NOTIFIERS);
+
+        let mut builder = sut.listener_builder();
+        for notifier in notifiers.iter() {
+            builder = builder.allow_notifier(notifier.id());
+        }
+        let listener = builder.create();
+        assert_that!(listener.err(), eq Some(ListenerCreateError::ExceedsMaxNumberOfAllowedNotifiers));
+    }
+
+    #[conformance_test]
+    pub fn listener_filters_are_visible_in_listener_details<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        let notifier = sut.notifier_builder().create().unwrap();
+        let filter = EventIdFilter::new().range(EventId::new(2), EventId::new(5));
+        let listener = sut
+            .listener_builder()
+            .event_id_filter(filter)
+            .allow_notifier(notifier.id())
+            .create()
+            .unwrap();
+
+        let mut counter = 0;
+        sut.dynamic_config().list_listeners(|details| {
+            assert_that!(details.listener_id, eq listener.id());
+            assert_that!(details.event_id_filter, eq filter);
+            assert_that!(details.allowed_notifiers.accepts(&notifier.id()), eq true);
+            counter += 1;
+            CallbackProgression::Continue
+        });
+        assert_that!(counter, eq 1);
+        assert_that!(*listener.event_id_filter(), eq filter);
+    }
 }
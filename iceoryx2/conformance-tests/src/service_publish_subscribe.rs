@@ -3843,6 +3843,180 @@ pub mod service_publish_subscribe {
         assert_that!(counter, eq 1);
     }
 
+    #[conformance_test]
+    pub fn publisher_statistics_count_sent_samples<S: Service>() {
+        const NUMBER_OF_SAMPLES: u64 = 5;
+        let test = Test::<S>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES as usize)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let _subscriber = sut
+            .subscriber_builder()
+            .buffer_size(NUMBER_OF_SAMPLES as usize)
+            .create()
+            .unwrap();
+
+        for n in 0..NUMBER_OF_SAMPLES {
+            publisher.send_copy(n).unwrap();
+        }
+
+        let mut sent_samples = vec![];
+        let mut samples_in_use = vec![];
+        sut.dynamic_config()
+            .list_publisher_statistics(|details, statistics| {
+                assert_that!(details.publisher_id, eq publisher.id());
+                sent_samples.push(statistics.number_of_sent_samples());
+                samples_in_use.push(statistics.number_of_samples_in_use());
+                CallbackProgression::Continue
+            });
+
+        assert_that!(sent_samples, eq vec![NUMBER_OF_SAMPLES]);
+        assert_that!(samples_in_use, eq vec![NUMBER_OF_SAMPLES]);
+    }
+
+    #[conformance_test]
+    pub fn subscriber_statistics_track_buffered_samples<S: Service>() {
+        const BUFFER_SIZE: usize = 3;
+        let test = Test::<S>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .enable_safe_overflow(true)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut
+            .subscriber_builder()
+            .buffer_size(BUFFER_SIZE)
+            .create()
+            .unwrap();
+
+        for n in 0..BUFFER_SIZE as u64 + 2 {
+            publisher.send_copy(n).unwrap();
+        }
+        let sample = subscriber.receive().unwrap();
+        assert_that!(sample, is_some);
+
+        let mut statistics_of_subscriber = vec![];
+        sut.dynamic_config()
+            .list_subscriber_statistics(|details, statistics| {
+                assert_that!(details.subscriber_id, eq subscriber.id());
+                statistics_of_subscriber.push((
+                    statistics.number_of_delivered_samples(),
+                    statistics.number_of_overflowed_samples(),
+                    statistics.number_of_received_samples(),
+                    statistics.number_of_buffered_samples(),
+                ));
+                CallbackProgression::Continue
+            });
+
+        assert_that!(
+            statistics_of_subscriber,
+            eq vec![(BUFFER_SIZE as u64 + 2, 2, 1, BUFFER_SIZE as u64 - 1)]
+        );
+    }
+
+    #[conformance_test]
+    pub fn statistics_of_different_ports_are_on_separate_cache_lines<S: Service>() {
+        const CACHE_LINE_SIZE: usize = 64;
+        let test = Test::<S>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(2)
+            .max_subscribers(2)
+            .create()
+            .unwrap();
+
+        let _publishers = [
+            sut.publisher_builder().create().unwrap(),
+            sut.publisher_builder().create().unwrap(),
+        ];
+        let _subscribers = [
+            sut.subscriber_builder().create().unwrap(),
+            sut.subscriber_builder().create().unwrap(),
+        ];
+
+        let mut addresses = vec![];
+        sut.dynamic_config()
+            .list_publisher_statistics(|_, statistics| {
+                addresses.push(statistics as *const _ as usize);
+                CallbackProgression::Continue
+            });
+        sut.dynamic_config()
+            .list_subscriber_statistics(|_, statistics| {
+                addresses.push(statistics as *const _ as usize);
+                CallbackProgression::Continue
+            });
+
+        assert_that!(addresses, len 4);
+        for address in &addresses {
+            assert_that!(address % CACHE_LINE_SIZE, eq 0);
+        }
+        addresses.sort();
+        for pair in addresses.windows(2) {
+            assert_that!(pair[1] - pair[0], ge CACHE_LINE_SIZE);
+        }
+    }
+
+    #[conformance_test]
+    pub fn port_statistics_are_reset_when_a_port_is_recreated<S: Service>() {
+        let test = Test::<S>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(1)
+            .max_subscribers(1)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        publisher.send_copy(1).unwrap();
+        drop(subscriber.receive().unwrap());
+        drop(publisher);
+        drop(subscriber);
+
+        let _publisher = sut.publisher_builder().create().unwrap();
+        let _subscriber = sut.subscriber_builder().create().unwrap();
+
+        let mut counter = 0;
+        sut.dynamic_config()
+            .list_publisher_statistics(|_, statistics| {
+                assert_that!(statistics.number_of_sent_samples(), eq 0);
+                counter += 1;
+                CallbackProgression::Continue
+            });
+        sut.dynamic_config()
+            .list_subscriber_statistics(|_, statistics| {
+                assert_that!(statistics.number_of_delivered_samples(), eq 0);
+                assert_that!(statistics.number_of_received_samples(), eq 0);
+                counter += 1;
+                CallbackProgression::Continue
+            });
+
+        assert_that!(counter, eq 2);
+    }
+
     #[conformance_test]
     pub fn receive_does_not_return_error_when_pub_goes_out_of_scope_after_reallocation<
         S: Service,
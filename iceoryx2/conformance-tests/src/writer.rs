@@ -16,6 +16,7 @@ use iceoryx2_bb_testing_macros::conformance_tests;
 #[conformance_tests]
 pub mod writer {
     use alloc::boxed::Box;
+    use alloc::vec;
 
     use iceoryx2::constants::MAX_BLACKBOARD_KEY_SIZE;
     use iceoryx2::port::writer::*;
@@ -222,6 +223,254 @@ pub mod writer {
         assert_that!(counter.load(Ordering::Relaxed), eq 1);
     }
 
+    #[conformance_test]
+    pub fn multiple_writers_can_be_created_when_supported<Sut: Service>() {
+        const MAX_WRITERS: usize = 4;
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .max_writers(MAX_WRITERS)
+            .add::<u64>(0, 0)
+            .create()
+            .unwrap();
+
+        let mut writers = vec![];
+        for _ in 0..MAX_WRITERS {
+            writers.push(sut.writer_builder().create().unwrap());
+        }
+        assert_that!(sut.dynamic_config().number_of_writers(), eq MAX_WRITERS);
+
+        let res = sut.writer_builder().create();
+        assert_that!(res, is_err);
+        assert_that!(res.err().unwrap(), eq WriterCreateError::ExceedsMaxSupportedWriters);
+    }
+
+    #[conformance_test]
+    pub fn entry_owned_by_writer_cannot_be_acquired_by_another_writer<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .max_writers(2)
+            .add::<u64>(0, 0)
+            .add::<u64>(1, 0)
+            .create()
+            .unwrap();
+
+        let writer_1 = sut.writer_builder().create().unwrap();
+        let writer_2 = sut.writer_builder().create().unwrap();
+
+        let entry_handle_mut = writer_1.entry::<u64>(&0).unwrap();
+        assert_that!(sut.dynamic_config().number_of_owned_entries(), eq 1);
+
+        let res = writer_2.entry::<u64>(&0);
+        assert_that!(res, is_err);
+        assert_that!(res.err().unwrap(), eq EntryHandleMutError::EntryOwnedByAnotherWriter);
+
+        let other_entry_handle_mut = writer_2.entry::<u64>(&1);
+        assert_that!(other_entry_handle_mut, is_ok);
+        assert_that!(sut.dynamic_config().number_of_owned_entries(), eq 2);
+
+        drop(entry_handle_mut);
+        assert_that!(sut.dynamic_config().number_of_owned_entries(), eq 1);
+
+        let entry_handle_mut = writer_2.entry::<u64>(&0);
+        assert_that!(entry_handle_mut, is_ok);
+        assert_that!(sut.dynamic_config().number_of_owned_entries(), eq 2);
+    }
+
+    #[conformance_test]
+    pub fn entry_ownership_is_transferred_when_owner_loans_and_updates<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .max_writers(2)
+            .add::<u64>(0, 0)
+            .create()
+            .unwrap();
+
+        let writer_1 = sut.writer_builder().create().unwrap();
+        let writer_2 = sut.writer_builder().create().unwrap();
+        let reader = sut.reader_builder().create().unwrap();
+        let entry_handle = reader.entry::<u64>(&0).unwrap();
+
+        let entry_handle_mut = writer_1.entry::<u64>(&0).unwrap();
+        let entry_value_uninit = entry_handle_mut.loan_uninit();
+        assert_that!(writer_2.entry::<u64>(&0), is_err);
+        let entry_handle_mut = entry_value_uninit.update_with_copy(12);
+        assert_that!(*entry_handle.get(), eq 12);
+        drop(entry_handle_mut);
+
+        let entry_handle_mut = writer_2.entry::<u64>(&0).unwrap();
+        entry_handle_mut.update_with_copy(13);
+        assert_that!(*entry_handle.get(), eq 13);
+    }
+
+    #[conformance_test]
+    pub fn entry_ownership_is_released_when_writer_is_dropped<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .max_writers(2)
+            .add::<u64>(0, 0)
+            .create()
+            .unwrap();
+
+        let writer_1 = sut.writer_builder().create().unwrap();
+        let writer_2 = sut.writer_builder().create().unwrap();
+
+        let entry_handle_mut = writer_1.entry::<u64>(&0).unwrap();
+        drop(writer_1);
+        assert_that!(writer_2.entry::<u64>(&0), is_err);
+
+        drop(entry_handle_mut);
+        assert_that!(sut.dynamic_config().number_of_writers(), eq 1);
+        assert_that!(writer_2.entry::<u64>(&0), is_ok);
+    }
+
+    #[conformance_test]
+    pub fn apply_snapshot_restores_all_values<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u64>(0, 0)
+            .add::<i8>(1, -1)
+            .create()
+            .unwrap();
+
+        let reader = sut.reader_builder().create().unwrap();
+        let writer = sut.writer_builder().create().unwrap();
+        let snapshot = reader.snapshot().unwrap();
+
+        writer.entry::<u64>(&0).unwrap().update_with_copy(100);
+        writer.entry::<i8>(&1).unwrap().update_with_copy(-100);
+
+        assert_that!(writer.apply_snapshot(&snapshot), is_ok);
+        assert_that!(*reader.entry::<u64>(&0).unwrap().get(), eq 0);
+        assert_that!(*reader.entry::<i8>(&1).unwrap().get(), eq - 1);
+        assert_that!(sut.dynamic_config().number_of_owned_entries(), eq 0);
+    }
+
+    #[conformance_test]
+    pub fn apply_snapshot_fails_when_entry_does_not_exist<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+
+        let other_sut = node
+            .service_builder(&generate_service_name())
+            .blackboard_creator::<u64>()
+            .add::<u64>(0, 0)
+            .add::<u64>(1, 1)
+            .create()
+            .unwrap();
+        let snapshot = other_sut
+            .reader_builder()
+            .create()
+            .unwrap()
+            .snapshot()
+            .unwrap();
+
+        let sut = node
+            .service_builder(&generate_service_name())
+            .blackboard_creator::<u64>()
+            .add::<u64>(0, 5)
+            .create()
+            .unwrap();
+        let writer = sut.writer_builder().create().unwrap();
+
+        assert_that!(writer.apply_snapshot(&snapshot).err(), eq Some(ApplySnapshotError::EntryDoesNotExist));
+        let reader = sut.reader_builder().create().unwrap();
+        assert_that!(*reader.entry::<u64>(&0).unwrap().get(), eq 5);
+    }
+
+    #[conformance_test]
+    pub fn apply_snapshot_fails_when_value_type_differs<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+
+        let other_sut = node
+            .service_builder(&generate_service_name())
+            .blackboard_creator::<u64>()
+            .add::<u64>(0, 0)
+            .add::<u32>(1, 1)
+            .create()
+            .unwrap();
+        let snapshot = other_sut
+            .reader_builder()
+            .create()
+            .unwrap()
+            .snapshot()
+            .unwrap();
+
+        let sut = node
+            .service_builder(&generate_service_name())
+            .blackboard_creator::<u64>()
+            .add::<u64>(0, 5)
+            .add::<u64>(1, 6)
+            .create()
+            .unwrap();
+        let writer = sut.writer_builder().create().unwrap();
+
+        assert_that!(writer.apply_snapshot(&snapshot).err(), eq Some(ApplySnapshotError::ValueTypeMismatch));
+        let reader = sut.reader_builder().create().unwrap();
+        assert_that!(*reader.entry::<u64>(&0).unwrap().get(), eq 5);
+        assert_that!(*reader.entry::<u64>(&1).unwrap().get(), eq 6);
+    }
+
+    #[conformance_test]
+    pub fn apply_snapshot_fails_when_entry_is_owned_by_another_writer<Sut: Service>() {
+        let test = Test::<Sut>::new();
+        let node = test.create_node();
+        let service_name = generate_service_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .max_writers(2)
+            .add::<u64>(0, 0)
+            .add::<u64>(1, 1)
+            .create()
+            .unwrap();
+
+        let reader = sut.reader_builder().create().unwrap();
+        let writer_1 = sut.writer_builder().create().unwrap();
+        let writer_2 = sut.writer_builder().create().unwrap();
+        let snapshot = reader.snapshot().unwrap();
+
+        writer_1.entry::<u64>(&0).unwrap().update_with_copy(10);
+        let entry_handle_mut = writer_2.entry::<u64>(&1).unwrap();
+        entry_handle_mut.update_with_copy(11);
+
+        assert_that!(writer_1.apply_snapshot(&snapshot).err(), eq Some(ApplySnapshotError::EntryOwnedByAnotherWriter));
+        assert_that!(*reader.entry::<u64>(&0).unwrap().get(), eq 10);
+        assert_that!(*reader.entry::<u64>(&1).unwrap().get(), eq 11);
+        assert_that!(sut.dynamic_config().number_of_owned_entries(), eq 1);
+
+        drop(entry_handle_mut);
+        assert_that!(writer_1.apply_snapshot(&snapshot), is_ok);
+        assert_that!(*reader.entry::<u64>(&0).unwrap().get(), eq 0);
+        assert_that!(*reader.entry::<u64>(&1).unwrap().get(), eq 1);
+    }
+
     #[repr(C)]
     #[derive(ZeroCopySend)]
     struct Foo {
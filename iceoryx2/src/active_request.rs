@@ -28,13 +28,18 @@
 //! let active_request = server.receive()?.unwrap();
 //!
 //! // send a stream of responses until the corresponding client
-//! // lets the pending response go out-of-scope and signaling that there is no more interest
-//! // in further responses
-//! while active_request.is_connected() {
+//! // lets the pending response go out-of-scope or cancels the request and signaling that
+//! // there is no more interest in further responses
+//! let mut counter = 0;
+//! while active_request.is_connected() && !active_request.is_cancelled() && counter < 5 {
 //!     let response = active_request.loan_uninit()?;
 //!     response.write_payload(456).send()?;
+//!     counter += 1;
 //! }
 //!
+//! // explicitly mark the end of the stream with a final status code
+//! active_request.finish(0)?;
+//!
 //! # Ok(())
 //! # }
 //! ```
@@ -44,8 +49,8 @@ use core::{any::TypeId, fmt::Debug, marker::PhantomData, mem::MaybeUninit, ops::
 use iceoryx2_bb_elementary_traits::testing::abandonable::Abandonable;
 use iceoryx2_cal::zero_copy_connection::ChannelState;
 
-use iceoryx2_bb_concurrency::atomic::AtomicUsize;
 use iceoryx2_bb_concurrency::atomic::Ordering;
+use iceoryx2_bb_concurrency::atomic::{AtomicU64, AtomicUsize};
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 use iceoryx2_cal::{
@@ -96,6 +101,7 @@ pub struct ActiveRequest<
     >,
     pub(crate) shared_state: Service::ArcThreadSafetyPolicy<SharedServerState<Service>>,
     pub(crate) shared_loan_counter: Arc<AtomicUsize>,
+    pub(crate) shared_sequence_counter: Arc<AtomicU64>,
     pub(crate) max_loan_count: usize,
     pub(crate) details: ChunkDetails,
     pub(crate) request_id: RequestId,
@@ -118,6 +124,7 @@ impl<
         let this = unsafe { this.as_mut() };
         unsafe { core::ptr::drop_in_place(&mut this.shared_state) };
         unsafe { core::ptr::drop_in_place(&mut this.shared_loan_counter) };
+        unsafe { core::ptr::drop_in_place(&mut this.shared_sequence_counter) };
     }
 }
 
@@ -184,7 +191,7 @@ impl<
             .lock()
             .request_receiver
             .release_offset(&self.details, ChannelId::new(0));
-        self.finish();
+        self.close();
     }
 }
 
@@ -196,7 +203,7 @@ impl<
     ResponseHeader: Debug + ZeroCopySend,
 > ActiveRequest<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
 {
-    fn finish(&self) {
+    fn close(&self) {
         if self.connection_id != INVALID_CONNECTION_ID {
             self.shared_state.lock().response_sender.close_channel(
                 self.channel_id,
@@ -221,6 +228,118 @@ impl<
         }
     }
 
+    /// Returns [`true`] when the [`Client`](crate::port::client::Client) cancelled the request
+    /// with [`PendingResponse::cancel()`](crate::pending_response::PendingResponse::cancel()).
+    /// The [`Server`](crate::port::server::Server) shall stop sending further
+    /// [`ResponseMut`]s and finish the stream with [`ActiveRequest::finish()`].
+    pub fn is_cancelled(&self) -> bool {
+        if self.connection_id != INVALID_CONNECTION_ID {
+            self.shared_state.lock().response_sender.has_cancel_request(
+                self.channel_id,
+                self.connection_id,
+                self.request_id,
+            )
+        } else {
+            false
+        }
+    }
+
+    /// Consumes the [`ActiveRequest`] and explicitly marks the end of the response stream.
+    /// The `status_code` is delivered in order after all previously sent [`ResponseMut`]s,
+    /// so that the [`Client`](crate::port::client::Client) knows that it has received all
+    /// responses once it can acquire the status code via
+    /// [`PendingResponse::finish_status()`](crate::pending_response::PendingResponse::finish_status()).
+    ///
+    /// When the [`ActiveRequest`] is dropped without calling [`ActiveRequest::finish()`], the
+    /// connection is closed without an end-of-stream marker.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let service = node
+    /// #     .service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .request_response::<u64, u64>()
+    /// #     .open_or_create()?;
+    /// # let client = service.client_builder().create()?;
+    /// # let server = service.server_builder().create()?;
+    /// #
+    /// let pending_response = client.send_copy(123)?;
+    ///
+    /// let active_request = server.receive()?.unwrap();
+    /// active_request.send_copy(456)?;
+    /// active_request.finish(0)?;
+    ///
+    /// while let Some(response) = pending_response.receive()? {
+    ///     println!("received response: {}", *response);
+    /// }
+    /// assert_eq!(pending_response.finish_status(), Some(0));
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn finish(self, status_code: u64) -> Result<(), SendError> {
+        let msg = "Unable to finish the response stream";
+
+        if !self.is_connected() {
+            return Ok(());
+        }
+
+        let shared_state = self.shared_state.lock();
+        fail!(from self, when shared_state.update_connections(),
+            "{} since the connections could not be updated.", msg);
+
+        let chunk = fail!(from self,
+            when shared_state.response_sender.allocate(shared_state.response_sender.sample_layout(0)),
+            "{} since the end-of-stream marker could not be loaned.", msg);
+
+        // the sequence number is consumed only when the marker was delivered, the shared
+        // state lock serializes all responses of the stream
+        let sequence_number = self.shared_sequence_counter.load(Ordering::Relaxed);
+        let header_ptr: *mut service::header::request_response::ResponseHeader =
+            chunk.header.cast();
+        // the end-of-stream marker has no payload and the user header is never exposed to the
+        // client, therefore only the header is initialized
+        unsafe {
+            header_ptr.write(service::header::request_response::ResponseHeader {
+                node_id: *shared_state.response_sender.shared_node.id(),
+                server_id: UniqueServerId(UniqueSystemId::from(
+                    shared_state.response_sender.sender_port_id,
+                )),
+                request_id: self.request_id,
+                number_of_elements: 0,
+                sequence_number,
+                status_code,
+                is_end_of_stream: true,
+            })
+        };
+
+        let result = shared_state.response_sender.deliver_offset_to_connection(
+            chunk.offset,
+            chunk.size,
+            self.channel_id,
+            self.connection_id,
+        );
+        shared_state
+            .response_sender
+            .return_loaned_sample(chunk.offset);
+
+        match result {
+            Ok(_) => {
+                self.shared_sequence_counter
+                    .store(sequence_number + 1, Ordering::Relaxed);
+                Ok(())
+            }
+            Err(e) => {
+                fail!(from self, with e,
+                    "{} since the end-of-stream marker could not be delivered.", msg);
+            }
+        }
+    }
+
     /// Returns [`true`] until the [`PendingResponse`](crate::pending_response::PendingResponse)
     /// goes out of scope on the [`Client`](crate::port::client::Client)s side indicating that the
     /// [`Client`](crate::port::client::Client) no longer receives the [`ResponseMut`].
@@ -341,6 +460,9 @@ impl<
                 )),
                 request_id: self.request_id,
                 number_of_elements: 1,
+                sequence_number: 0,
+                status_code: 0,
+                is_end_of_stream: false,
             })
         };
         unsafe { user_header_ptr.write(ResponseHeader::default()) };
@@ -357,6 +479,7 @@ impl<
             response: ResponseMut {
                 ptr,
                 shared_loan_counter: self.shared_loan_counter.clone(),
+                shared_sequence_counter: self.shared_sequence_counter.clone(),
                 shared_state: self.shared_state.clone(),
                 offset_to_chunk: chunk.offset,
                 channel_id: self.channel_id,
@@ -588,6 +711,9 @@ impl<
                 )),
                 request_id: self.request_id,
                 number_of_elements: slice_len as _,
+                sequence_number: 0,
+                status_code: 0,
+                is_end_of_stream: false,
             })
         };
         unsafe { user_header_ptr.write(ResponseHeader::default()) };
@@ -611,6 +737,7 @@ impl<
             response: ResponseMut {
                 ptr,
                 shared_loan_counter: self.shared_loan_counter.clone(),
+                shared_sequence_counter: self.shared_sequence_counter.clone(),
                 shared_state: self.shared_state.clone(),
                 offset_to_chunk: chunk.offset,
                 channel_id: self.channel_id,
@@ -229,7 +229,9 @@ pub struct Global {
     /// [`Node`](crate::node::Node) settings
     pub node: Node,
     /// Defines the time how long the process will wait until an entity, that shares inter-process
-    /// resources with others, is opened or created. This entity could be for instance a service
+    /// resources with others, is opened or created. This entity could be for instance a service.
+    /// In the config file it is written with a unit, like `"1s"` or `"500ms"`.
+    #[serde(with = "iceoryx2_bb_elementary::units::duration")]
     pub creation_timeout: Duration,
 }
 
@@ -372,6 +374,8 @@ pub struct Event {
     /// Defines the maximum allowed time between two consecutive notifications. If a notifiation
     /// is not sent after the defined time, every [`Listener`](crate::port::listener::Listener)
     /// that is attached to a [`WaitSet`](crate::waitset::WaitSet) will be notified.
+    /// In the config file it is written with a unit, like `"1s"` or `"500ms"`.
+    #[serde(with = "iceoryx2_bb_elementary::units::duration::option")]
     pub deadline: Option<Duration>,
     /// Defines the event id value that is emitted after a new notifier was created.
     pub notifier_created_event: Option<usize>,
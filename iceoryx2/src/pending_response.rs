@@ -35,16 +35,26 @@
 //!
 //! // we receive a stream of responses from the server and are interested in 5 of them
 //! for i in 0..5 {
+//!     if pending_response.is_finished() {
+//!         println!("server finished the stream with status {:?}",
+//!                   pending_response.finish_status());
+//!         break;
+//!     }
+//!
 //!     if !pending_response.is_connected() {
 //!         println!("server terminated connection - abort");
 //!         break;
 //!     }
 //!
 //!     if let Some(response) = pending_response.receive()? {
-//!         println!("received response: {}", *response);
+//!         println!("received response {}: {}",
+//!                   response.header().sequence_number(), *response);
 //!     }
 //! }
 //!
+//! // Ask the servers to stop sending further responses
+//! pending_response.cancel();
+//!
 //! // We are no longer interested in the responses from the server and
 //! // drop the object. This informs the corresponding servers, that hold
 //! // an ActiveRequest that the connection was terminated from the client
@@ -57,6 +67,7 @@
 
 use core::ops::Deref;
 use core::{fmt::Debug, marker::PhantomData};
+use iceoryx2_bb_concurrency::cell::Cell;
 
 use iceoryx2_bb_concurrency::atomic::Ordering;
 use iceoryx2_bb_elementary_traits::non_null::NonNullCompat;
@@ -89,6 +100,8 @@ pub struct PendingResponse<
     pub(crate) request:
         RequestMut<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>,
     pub(crate) number_of_server_connections: usize,
+    pub(crate) number_of_finished_streams: Cell<usize>,
+    pub(crate) finish_status: Cell<Option<u64>>,
     pub(crate) _service: PhantomData<Service>,
     pub(crate) _response_payload: PhantomData<ResponsePayload>,
     pub(crate) _response_header: PhantomData<ResponseHeader>,
@@ -209,6 +222,49 @@ impl<
             .set_disconnect_hint(self.request.channel_id, self.request.header().request_id);
     }
 
+    /// Requests all [`Server`](crate::port::server::Server)s to stop sending further
+    /// [`Response`]s. The [`Server`](crate::port::server::Server)s can observe the request with
+    /// [`ActiveRequest::is_cancelled()`](crate::active_request::ActiveRequest::is_cancelled()).
+    /// [`Response`]s that were already sent can still be received.
+    pub fn cancel(&self) {
+        self.request
+            .client_shared_state
+            .lock()
+            .response_receiver
+            .set_cancel_request(self.request.channel_id, self.request.header().request_id);
+    }
+
+    /// Returns [`true`] when every [`Server`](crate::port::server::Server) that received the
+    /// [`RequestMut`] finished its stream with
+    /// [`ActiveRequest::finish()`](crate::active_request::ActiveRequest::finish()) and all of
+    /// their [`Response`]s were received.
+    pub fn is_finished(&self) -> bool {
+        self.number_of_finished_streams.get() >= self.number_of_server_connections
+    }
+
+    /// Returns the status code of the most recently finished stream that was provided with
+    /// [`ActiveRequest::finish()`](crate::active_request::ActiveRequest::finish()). It becomes
+    /// available as soon as all [`Response`]s of that stream were received. If no stream was
+    /// finished yet, it returns [`None`].
+    pub fn finish_status(&self) -> Option<u64> {
+        self.finish_status.get()
+    }
+
+    // Returns true when the response is an end-of-stream marker which is consumed
+    // internally and not handed out to the user.
+    fn handle_end_of_stream(
+        &self,
+        header: &service::header::request_response::ResponseHeader,
+    ) -> bool {
+        if header.is_end_of_stream {
+            self.number_of_finished_streams
+                .set(self.number_of_finished_streams.get() + 1);
+            self.finish_status.set(Some(header.status_code));
+        }
+
+        header.is_end_of_stream
+    }
+
     /// Returns [`true`] until the [`ActiveRequest`](crate::active_request::ActiveRequest)
     /// goes out of scope on the [`Server`](crate::port::server::Server)s side indicating that the
     /// [`Server`](crate::port::server::Server) will no longer send [`Response`]s.
@@ -329,7 +385,9 @@ impl<
                         },
                     };
 
-                    if response.header().request_id != self.request.header().request_id {
+                    if response.header().request_id != self.request.header().request_id
+                        || self.handle_end_of_stream(response.header())
+                    {
                         continue;
                     }
 
@@ -405,7 +463,9 @@ impl<
                         },
                     };
 
-                    if response.header().request_id != self.request.header().request_id {
+                    if response.header().request_id != self.request.header().request_id
+                        || self.handle_end_of_stream(response.header())
+                    {
                         continue;
                     }
 
@@ -467,7 +527,9 @@ impl<
                         },
                     };
 
-                    if response.header().request_id != self.request.header().request_id {
+                    if response.header().request_id != self.request.header().request_id
+                        || self.handle_end_of_stream(response.header())
+                    {
                         continue;
                     }
 
@@ -0,0 +1,282 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Examples
+//!
+//! ```
+//! # use iceoryx2::prelude::*;
+//! # use iceoryx2_bb_system_types::file_path::FilePath;
+//! # use iceoryx2_bb_container::semantic_string::SemanticString;
+//! # use iceoryx2_cal::serialize::postcard::Postcard;
+//! use iceoryx2::port::blackboard_snapshot::BlackboardSnapshot;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! type KeyType = u64;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .blackboard_creator::<KeyType>()
+//!     .add::<i32>(1, -1)
+//!     .add::<u32>(9, 17)
+//!     .create()?;
+//!
+//! let reader = service.reader_builder().create()?;
+//!
+//! // acquire a consistent copy of all entries
+//! let snapshot = reader.snapshot()?;
+//! assert_eq!(snapshot.get::<u32>(&9), Some(17));
+//!
+//! // store the snapshot and load it again, e.g. after a restart
+//! # let file_path = FilePath::new(b"/tmp/my_funk_blackboard_snapshot.bin")?;
+//! snapshot.save_to_file::<Postcard>(&file_path)?;
+//! let restored_snapshot = BlackboardSnapshot::<KeyType>::load_from_file::<Postcard>(&file_path)?;
+//!
+//! // write the restored values back into the blackboard
+//! let writer = service.writer_builder().create()?;
+//! writer.apply_snapshot(&restored_snapshot)?;
+//! # iceoryx2_bb_posix::file::File::remove(&file_path)?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_posix::creation_mode::CreationMode;
+use iceoryx2_bb_posix::file::{AccessMode, FileBuilder, FileCreationError, FileOpenError};
+use iceoryx2_bb_system_types::file_path::FilePath;
+use iceoryx2_cal::serialize::Serialize;
+use iceoryx2_log::{fail, trace};
+use serde::de::DeserializeOwned;
+
+use crate::service::static_config::message_type_details::{TypeDetail, TypeVariant};
+
+/// Defines a failure that can occur when a [`BlackboardSnapshot`] is stored with
+/// [`BlackboardSnapshot::save_to_file()`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum BlackboardSnapshotSaveError {
+    /// The snapshot file could not be created due to insufficient permissions.
+    InsufficientPermissions,
+    /// The snapshot file could not be created.
+    UnableToCreateSnapshotFile,
+    /// The snapshot could not be serialized.
+    UnableToSerializeContents,
+    /// The serialized snapshot could not be written into the file.
+    FailedToWriteSnapshotFileContents,
+}
+
+impl core::fmt::Display for BlackboardSnapshotSaveError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "BlackboardSnapshotSaveError::{self:?}")
+    }
+}
+
+impl core::error::Error for BlackboardSnapshotSaveError {}
+
+/// Defines a failure that can occur when a [`BlackboardSnapshot`] is loaded with
+/// [`BlackboardSnapshot::load_from_file()`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum BlackboardSnapshotLoadError {
+    /// The snapshot file could not be opened due to insufficient permissions.
+    InsufficientPermissions,
+    /// The snapshot file does not exist.
+    SnapshotFileDoesNotExist,
+    /// The snapshot file could not be opened.
+    UnableToOpenSnapshotFile,
+    /// The contents of the snapshot file could not be read.
+    FailedToReadSnapshotFileContents,
+    /// The contents of the snapshot file could not be deserialized.
+    UnableToDeserializeContents,
+}
+
+impl core::fmt::Display for BlackboardSnapshotLoadError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "BlackboardSnapshotLoadError::{self:?}")
+    }
+}
+
+impl core::error::Error for BlackboardSnapshotLoadError {}
+
+/// A single key-value pair of a [`BlackboardSnapshot`].
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct BlackboardSnapshotEntry<KeyType> {
+    key: KeyType,
+    type_details: TypeDetail,
+    value: Vec<u8>,
+}
+
+impl<KeyType> BlackboardSnapshotEntry<KeyType> {
+    pub(crate) fn new(key: KeyType, type_details: TypeDetail, value: Vec<u8>) -> Self {
+        Self {
+            key,
+            type_details,
+            value,
+        }
+    }
+
+    /// Returns the key of the entry.
+    pub fn key(&self) -> &KeyType {
+        &self.key
+    }
+
+    /// Returns the [`TypeDetail`] of the value.
+    pub fn type_details(&self) -> &TypeDetail {
+        &self.type_details
+    }
+
+    /// Returns the raw bytes of the value.
+    pub fn value(&self) -> &[u8] {
+        &self.value
+    }
+}
+
+/// A copy of all entries of a blackboard acquired with
+/// [`Reader::snapshot()`](crate::port::reader::Reader::snapshot()). All values stem from a
+/// single point in time, meaning no entry was updated while the snapshot was taken.
+///
+/// It can be stored in and loaded from a file with any
+/// [`Serialize`] implementation and applied to the blackboard again with
+/// [`Writer::apply_snapshot()`](crate::port::writer::Writer::apply_snapshot()).
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct BlackboardSnapshot<KeyType> {
+    entries: Vec<BlackboardSnapshotEntry<KeyType>>,
+}
+
+impl<KeyType: Eq + Debug> BlackboardSnapshot<KeyType> {
+    pub(crate) fn new(entries: Vec<BlackboardSnapshotEntry<KeyType>>) -> Self {
+        Self { entries }
+    }
+
+    /// Returns all entries of the snapshot.
+    pub fn entries(&self) -> &[BlackboardSnapshotEntry<KeyType>] {
+        &self.entries
+    }
+
+    /// Returns the number of entries contained in the snapshot.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true when the snapshot does not contain any entry, otherwise false.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns a copy of the value stored under the provided key. If no entry with the given
+    /// key and value type exists, [`None`] is returned.
+    pub fn get<ValueType: Copy + ZeroCopySend>(&self, key: &KeyType) -> Option<ValueType> {
+        let type_details = TypeDetail::new::<ValueType>(TypeVariant::FixedSize);
+        self.entries
+            .iter()
+            .find(|entry| entry.key == *key && entry.type_details.is_identical_to(&type_details))
+            .filter(|entry| entry.value.len() == core::mem::size_of::<ValueType>())
+            .map(|entry| unsafe {
+                core::ptr::read_unaligned(entry.value.as_ptr() as *const ValueType)
+            })
+    }
+
+    /// Serializes the snapshot with the provided [`Serialize`] implementation and stores it in
+    /// the given file. An already existing file is overridden.
+    pub fn save_to_file<S: Serialize>(
+        &self,
+        file_path: &FilePath,
+    ) -> Result<(), BlackboardSnapshotSaveError>
+    where
+        KeyType: serde::Serialize,
+    {
+        let msg = "Unable to save blackboard snapshot";
+
+        let contents = match S::serialize(self) {
+            Ok(contents) => contents,
+            Err(e) => {
+                fail!(from self, with BlackboardSnapshotSaveError::UnableToSerializeContents,
+                    "{} since the snapshot could not be serialized ({:?}).", msg, e);
+            }
+        };
+
+        let mut file = match FileBuilder::new(file_path)
+            .has_ownership(false)
+            .creation_mode(CreationMode::PurgeAndCreate)
+            .create()
+        {
+            Ok(file) => file,
+            Err(FileCreationError::InsufficientPermissions) => {
+                fail!(from self, with BlackboardSnapshotSaveError::InsufficientPermissions,
+                    "{} since the snapshot file \"{}\" could not be created due to insufficient permissions.",
+                    msg, file_path);
+            }
+            Err(e) => {
+                fail!(from self, with BlackboardSnapshotSaveError::UnableToCreateSnapshotFile,
+                    "{} since the snapshot file \"{}\" could not be created ({:?}).",
+                    msg, file_path, e);
+            }
+        };
+
+        fail!(from self, when file.write(&contents),
+            with BlackboardSnapshotSaveError::FailedToWriteSnapshotFileContents,
+            "{} since the contents could not be written into the snapshot file \"{}\".",
+            msg, file_path);
+
+        fail!(from self, when file.sync_all(),
+            with BlackboardSnapshotSaveError::FailedToWriteSnapshotFileContents,
+            "{} since the contents of the snapshot file \"{}\" could not be synced to disk.",
+            msg, file_path);
+
+        trace!(from self, "Saved to \"{}\".", file_path);
+        Ok(())
+    }
+
+    /// Loads a snapshot from the given file that was stored with
+    /// [`BlackboardSnapshot::save_to_file()`] using the same [`Serialize`] implementation.
+    pub fn load_from_file<S: Serialize>(
+        file_path: &FilePath,
+    ) -> Result<Self, BlackboardSnapshotLoadError>
+    where
+        KeyType: DeserializeOwned,
+    {
+        let origin = "BlackboardSnapshot::load_from_file()";
+        let msg = "Unable to load blackboard snapshot";
+
+        let file = match FileBuilder::new(file_path).open_existing(AccessMode::Read) {
+            Ok(file) => file,
+            Err(FileOpenError::InsufficientPermissions) => {
+                fail!(from origin, with BlackboardSnapshotLoadError::InsufficientPermissions,
+                    "{} since the snapshot file \"{}\" could not be opened due to insufficient permissions.",
+                    msg, file_path);
+            }
+            Err(FileOpenError::FileDoesNotExist) => {
+                fail!(from origin, with BlackboardSnapshotLoadError::SnapshotFileDoesNotExist,
+                    "{} since the snapshot file \"{}\" does not exist.", msg, file_path);
+            }
+            Err(e) => {
+                fail!(from origin, with BlackboardSnapshotLoadError::UnableToOpenSnapshotFile,
+                    "{} since the snapshot file \"{}\" could not be opened ({:?}).",
+                    msg, file_path, e);
+            }
+        };
+
+        let mut contents = Vec::new();
+        fail!(from origin, when file.read_to_vector(&mut contents),
+            with BlackboardSnapshotLoadError::FailedToReadSnapshotFileContents,
+            "{} since the contents of the snapshot file \"{}\" could not be read.", msg, file_path);
+
+        match S::deserialize::<Self>(&contents) {
+            Ok(snapshot) => Ok(snapshot),
+            Err(e) => {
+                fail!(from origin, with BlackboardSnapshotLoadError::UnableToDeserializeContents,
+                    "{} since the contents of the snapshot file \"{}\" could not be deserialized ({:?}).",
+                    msg, file_path, e);
+            }
+        }
+    }
+}
@@ -302,6 +302,13 @@ impl<Service: service::Service> DataSegmentView<Service> {
         }
     }
 
+    pub(crate) fn bucket_size(&self, segment_id: SegmentId) -> usize {
+        match &self.memory {
+            MemoryViewType::Static(memory) => memory.bucket_size(),
+            MemoryViewType::Dynamic(memory) => memory.bucket_size(segment_id),
+        }
+    }
+
     pub(crate) fn is_dynamic(&self) -> bool {
         matches!(&self.memory, MemoryViewType::Dynamic(_))
     }
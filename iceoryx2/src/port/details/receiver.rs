@@ -177,6 +177,20 @@ impl<Service: service::Service> Receiver<Service> {
         }
     }
 
+    /// Returns the size of the memory chunk in which the received chunk is stored. If the
+    /// connection to the origin no longer exists, [`None`] is returned.
+    pub(crate) fn chunk_size(&self, chunk: &ChunkDetails) -> Option<usize> {
+        let connection_storage = unsafe { &*self.connection_storage.get() };
+        match connection_storage.get(chunk.connection_key) {
+            Some(connection) if connection.sender_port_id == chunk.origin => Some(
+                connection
+                    .data_segment
+                    .bucket_size(chunk.offset.segment_id()),
+            ),
+            _ => None,
+        }
+    }
+
     pub(crate) fn set_channel_state(&self, channel_id: ChannelId, state: ChannelState) -> bool {
         let mut ret_val = true;
         let connection_storage = unsafe { &mut *self.connection_storage.get() };
@@ -213,6 +227,15 @@ impl<Service: service::Service> Receiver<Service> {
         }
     }
 
+    pub(crate) fn set_cancel_request(&self, channel_id: ChannelId, expected_state: ChannelState) {
+        let connection_storage = unsafe { &mut *self.connection_storage.get() };
+        for (_, connection) in connection_storage.iter() {
+            connection
+                .receiver
+                .set_cancel_request(channel_id, expected_state);
+        }
+    }
+
     pub(crate) fn close_channel(&self, channel_id: ChannelId, expected_state: ChannelState) {
         let connection_storage = unsafe { &mut *self.connection_storage.get() };
         for (_, connection) in connection_storage.iter() {
@@ -135,6 +135,7 @@ pub(crate) struct Sender<Service: service::Service> {
     pub(crate) service_state: SharedServiceState<Service, NoResource>,
     pub(crate) tagger: CyclicTagger,
     pub(crate) loan_counter: AtomicUsize,
+    pub(crate) used_samples_counter: AtomicUsize,
     pub(crate) backpressure_strategy: BackpressureStrategy,
     pub(crate) message_type_details: MessageTypeDetails,
     pub(crate) number_of_channels: usize,
@@ -188,12 +189,13 @@ impl<Service: service::Service> Sender<Service> {
         None
     }
 
-    fn deliver_offset_to_connection_impl(
+    fn deliver_offset_to_connection_impl<F: FnMut(usize, bool)>(
         &self,
         offset: PointerOffset,
         sample_size: usize,
         channel_id: ChannelId,
         connection_id: usize,
+        on_delivery: &mut F,
     ) -> Result<usize, SendError> {
         let msg = "While delivering the sample:";
 
@@ -308,6 +310,7 @@ impl<Service: service::Service> Sender<Service> {
                 Ok(overflow) => {
                     self.borrow_sample(offset);
                     number_of_recipients += 1;
+                    on_delivery(connection_id, overflow.is_some());
 
                     if let Some(old) = overflow {
                         self.release_sample(old)
@@ -331,6 +334,19 @@ impl<Service: service::Service> Sender<Service> {
         }
     }
 
+    pub(crate) fn has_cancel_request(
+        &self,
+        channel_id: ChannelId,
+        connection_id: usize,
+        state: ChannelState,
+    ) -> bool {
+        if let Some(connection) = self.get(connection_id) {
+            connection.sender.has_cancel_request(channel_id, state)
+        } else {
+            false
+        }
+    }
+
     pub(crate) fn has_channel_state(
         &self,
         channel_id: ChannelId,
@@ -363,7 +379,13 @@ impl<Service: service::Service> Sender<Service> {
         connection_id: usize,
     ) -> Result<usize, SendError> {
         self.retrieve_returned_samples();
-        self.deliver_offset_to_connection_impl(offset, sample_size, channel_id, connection_id)
+        self.deliver_offset_to_connection_impl(
+            offset,
+            sample_size,
+            channel_id,
+            connection_id,
+            &mut |_, _| (),
+        )
     }
 
     pub(crate) fn deliver_offset(
@@ -371,13 +393,31 @@ impl<Service: service::Service> Sender<Service> {
         offset: PointerOffset,
         sample_size: usize,
         channel_id: ChannelId,
+    ) -> Result<usize, SendError> {
+        self.deliver_offset_and_track(offset, sample_size, channel_id, |_, _| ())
+    }
+
+    /// Delivers the offset to all connections and calls `on_delivery` with the connection id
+    /// and the overflow flag for every connection that accepted the offset.
+    pub(crate) fn deliver_offset_and_track<F: FnMut(usize, bool)>(
+        &self,
+        offset: PointerOffset,
+        sample_size: usize,
+        channel_id: ChannelId,
+        mut on_delivery: F,
     ) -> Result<usize, SendError> {
         self.retrieve_returned_samples();
 
         let mut number_of_recipients = 0;
         let mut delivery_error = None;
         for i in 0..self.len() {
-            match self.deliver_offset_to_connection_impl(offset, sample_size, channel_id, i) {
+            match self.deliver_offset_to_connection_impl(
+                offset,
+                sample_size,
+                channel_id,
+                i,
+                &mut on_delivery,
+            ) {
                 Ok(n) => number_of_recipients += n,
                 Err(error) => match error {
                     SendError::ConnectionCorrupted => {
@@ -470,6 +510,7 @@ impl<Service: service::Service> Sender<Service> {
         }
 
         self.loan_counter.fetch_add(1, Ordering::Relaxed);
+        self.used_samples_counter.fetch_add(1, Ordering::Relaxed);
         Ok(ChunkMut::new(
             &self.message_type_details,
             shm_pointer,
@@ -516,9 +557,15 @@ impl<Service: service::Service> Sender<Service> {
             unsafe {
                 self.data_segment.deallocate_bucket(offset);
             }
+            self.used_samples_counter.fetch_sub(1, Ordering::Relaxed);
         }
     }
 
+    /// Returns the number of samples of the data segment that are currently in use.
+    pub(crate) fn number_of_used_samples(&self) -> usize {
+        self.used_samples_counter.load(Ordering::Relaxed)
+    }
+
     fn remove_connection(&self, i: usize) {
         if let Some(connection) = self.get(i) {
             // # SAFETY: the receiver no longer exist, therefore we can
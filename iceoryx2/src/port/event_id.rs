@@ -38,4 +38,4 @@
 //! # }
 //! ```
 
-pub use iceoryx2_cal::event::EventId;
+pub use iceoryx2_cal::event::{EventId, EventIdFilter};
@@ -57,14 +57,39 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! ## Receive Notification Data
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let event = node.service_builder(&"MyEventName".try_into()?)
+//!     .event()
+//!     .notification_data_size(16)
+//!     .open_or_create()?;
+//!
+//! let mut listener = event.listener_builder().create()?;
+//!
+//! listener.try_wait_with_data(|event, data| {
+//!     println!("event was triggered with id: {:?} and data: {:?}", event.id, data);
+//! })?;
+//!
+//! # Ok(())
+//! # }
+//! ```
 
 use crate::config::Config;
+use crate::identifiers::UniqueNotifierId;
+use crate::port::event_id::EventIdFilter;
 use crate::service::config_scheme::event_config;
-use crate::service::dynamic_config::event::ListenerDetails;
+use crate::service::dynamic_config::event::{AllowedNotifiers, ListenerDetails};
 use crate::service::naming_scheme::event_concept_name;
 use crate::service::{NoResource, SharedServiceState};
 use crate::{identifiers::UniqueListenerId, service};
 use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
 use core::ptr::NonNull;
 use core::time::Duration;
 use iceoryx2_bb_concurrency::atomic::Ordering;
@@ -79,7 +104,7 @@ use iceoryx2_cal::dynamic_storage::DynamicStorage;
 use iceoryx2_cal::event::event_state::EventActivation;
 use iceoryx2_cal::event::{EventId, ListenerBuilder, ListenerWaitError, NamedConceptMgmt};
 use iceoryx2_cal::named_concept::{NamedConceptBuilder, NamedConceptRemoveError};
-use iceoryx2_log::fail;
+use iceoryx2_log::{fail, warn};
 
 /// Defines the failures that can occur when a [`Listener`] is created with the
 /// [`crate::service::port_factory::listener::PortFactoryListener`].
@@ -97,6 +122,11 @@ pub enum ListenerCreateError {
     FailedToDeployThreadsafetyPolicy,
     /// The tracking port tag, required for cleanup, could not be created.
     UnableToCreatePortTag,
+    /// More than
+    /// [`MAX_NUMBER_OF_ALLOWED_NOTIFIERS`](crate::service::dynamic_config::event::MAX_NUMBER_OF_ALLOWED_NOTIFIERS)
+    /// [`Notifier`](crate::port::notifier::Notifier)s were added with
+    /// [`PortFactoryListener::allow_notifier()`](crate::service::port_factory::listener::PortFactoryListener::allow_notifier()).
+    ExceedsMaxNumberOfAllowedNotifiers,
 }
 
 impl core::fmt::Display for ListenerCreateError {
@@ -116,6 +146,8 @@ pub struct Listener<Service: service::Service> {
     >,
     service_state: SharedServiceState<Service, NoResource>,
     listener_id: UniqueListenerId,
+    event_id_filter: EventIdFilter,
+    allowed_notifiers: Vec<UniqueNotifierId>,
     // IMPORTANT!
     // Fields of a rust struct are dropped in declaration order. Since this tag is our marker that the
     // port exists and might require cleanup after a crash, the tag must be defined as last member of
@@ -190,9 +222,21 @@ impl<Service: service::Service> Drop for Listener<Service> {
 impl<Service: service::Service> Listener<Service> {
     pub(crate) fn new(
         service: SharedServiceState<Service, NoResource>,
+        event_id_filter: EventIdFilter,
+        allowed_notifiers: &[UniqueNotifierId],
     ) -> Result<Self, ListenerCreateError> {
         let msg = "Failed to create listener";
         let origin = "Listener::new()";
+
+        let allowed_notifier_details = match AllowedNotifiers::new(allowed_notifiers) {
+            Some(v) => v,
+            None => {
+                fail!(from origin, with ListenerCreateError::ExceedsMaxNumberOfAllowedNotifiers,
+                    "{} since {} notifiers were allowed but at most {} are supported.",
+                    msg, allowed_notifiers.len(), crate::service::dynamic_config::event::MAX_NUMBER_OF_ALLOWED_NOTIFIERS);
+            }
+        };
+
         let listener_id = UniqueListenerId::new();
 
         // !MUST! be the first thing that is created when a new port is instantiated otherwise the
@@ -215,6 +259,7 @@ impl<Service: service::Service> Listener<Service> {
         let listener = fail!(from origin,
                              when <Service::Event as iceoryx2_cal::event::Event<RelocatableCountingBitSet>>::ListenerBuilder::new(&event_name).config(&event_config)
                                 .event_id_max(EventId::new(service.static_config().event().event_id_max_value))
+                                .event_id_filter(event_id_filter)
                                 .create(),
                              with ListenerCreateError::ResourceCreationFailed,
                              "{} since the underlying event concept \"{}\" could not be created.", msg, event_name);
@@ -233,6 +278,8 @@ impl<Service: service::Service> Listener<Service> {
             dynamic_listener_handle: None,
             listener,
             listener_id,
+            event_id_filter,
+            allowed_notifiers: allowed_notifiers.to_vec(),
         };
 
         core::sync::atomic::compiler_fence(Ordering::SeqCst);
@@ -243,6 +290,8 @@ impl<Service: service::Service> Listener<Service> {
             ListenerDetails {
                 listener_id,
                 node_id: *service.shared_node().id(),
+                event_id_filter,
+                allowed_notifiers: allowed_notifier_details,
             },
         ) {
             Some(unique_index) => unique_index,
@@ -307,6 +356,89 @@ impl<Service: service::Service> Listener<Service> {
         Ok(number_of_notifications)
     }
 
+    /// Non-blocking wait for new [`EventId`]s. Collects all [`EventId`]s that were received and
+    /// calls the provided callback with the [`EventActivation`] and the latest data that was
+    /// sent with the [`EventId`] via
+    /// [`Notifier::notify_with_data()`](crate::port::notifier::Notifier::notify_with_data())
+    /// as input argument. The data is empty when no data was sent with the [`EventId`] so far.
+    pub fn try_wait_with_data<F: FnMut(EventActivation, &[u8])>(
+        &self,
+        callback: F,
+    ) -> Result<u64, ListenerWaitError> {
+        self.try_wait(self.attach_notification_data(callback))
+    }
+
+    /// Blocking wait for new [`EventId`]s until the provided timeout has passed. Unblocks as soon
+    /// as an [`EventId`] was received and then collects all [`EventId`]s that were received and
+    /// calls the provided callback with the [`EventActivation`] and the latest data that was
+    /// sent with the [`EventId`] as input argument.
+    pub fn timed_wait_with_data<F: FnMut(EventActivation, &[u8])>(
+        &self,
+        callback: F,
+        timeout: Duration,
+    ) -> Result<u64, ListenerWaitError> {
+        self.timed_wait(self.attach_notification_data(callback), timeout)
+    }
+
+    /// Blocking wait for new [`EventId`]s. Unblocks as soon
+    /// as an [`EventId`] was received and then collects all [`EventId`]s that were received and
+    /// calls the provided callback with the [`EventActivation`] and the latest data that was
+    /// sent with the [`EventId`] as input argument.
+    pub fn blocking_wait_with_data<F: FnMut(EventActivation, &[u8])>(
+        &self,
+        callback: F,
+    ) -> Result<u64, ListenerWaitError> {
+        self.blocking_wait(self.attach_notification_data(callback))
+    }
+
+    /// Returns the maximum size in bytes of the data that can be received with a notification.
+    pub fn notification_data_size(&self) -> usize {
+        self.service_state
+            .static_config()
+            .event()
+            .notification_data_size
+    }
+
+    fn attach_notification_data<'a, F: FnMut(EventActivation, &[u8]) + 'a>(
+        &'a self,
+        mut callback: F,
+    ) -> impl FnMut(EventActivation) + 'a {
+        let mut buffer = vec![0u8; self.notification_data_size()];
+        move |activation| {
+            let len = match self
+                .service_state
+                .dynamic_storage()
+                .get()
+                .event()
+                .read_notification_data(activation.id.as_value(), &mut buffer)
+            {
+                Some(len) => len,
+                None => {
+                    if !buffer.is_empty() {
+                        warn!(from self,
+                            "Unable to acquire the notification data of {:?} since the storage is blocked by a notifier.",
+                            activation.id);
+                    }
+                    0
+                }
+            };
+
+            callback(activation, &buffer[..len]);
+        }
+    }
+
+    /// Returns the [`EventIdFilter`] that defines which [`EventId`]s the [`Listener`] accepts.
+    pub fn event_id_filter(&self) -> &EventIdFilter {
+        &self.event_id_filter
+    }
+
+    /// Returns the [`Notifier`](crate::port::notifier::Notifier)s from which the [`Listener`]
+    /// accepts notifications. When it is empty, notifications from all
+    /// [`Notifier`](crate::port::notifier::Notifier)s are accepted.
+    pub fn allowed_notifiers(&self) -> &[UniqueNotifierId] {
+        &self.allowed_notifiers
+    }
+
     /// Returns the [`UniqueListenerId`] of the [`Listener`]
     pub fn id(&self) -> UniqueListenerId {
         self.listener_id
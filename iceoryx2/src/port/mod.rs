@@ -13,6 +13,7 @@
 use core::fmt::Debug;
 use core::time::Duration;
 
+use iceoryx2_bb_elementary_traits::validate::ValidationError;
 use tiny_fn::tiny_fn;
 use update_connections::ConnectionFailure;
 
@@ -22,6 +23,8 @@ pub use iceoryx2_cal::event::event_state::EventActivation;
 pub(crate) mod details;
 pub use details::data_segment::DataSegmentType;
 
+/// Consistent copy of all blackboard entries that can be persisted and restored
+pub mod blackboard_snapshot;
 /// Sends requests to a [`Server`](crate::port::server::Server) and receives responses.
 pub mod client;
 /// Defines the event id used to identify the source of an event.
@@ -295,3 +298,31 @@ impl core::fmt::Display for ReceiveError {
 }
 
 impl core::error::Error for ReceiveError {}
+
+/// Defines the failure that can occur when receiving data with
+/// [`Subscriber::receive_validated()`](crate::port::subscriber::Subscriber::receive_validated()).
+/// When the received data is invalid, the sample is released and not handed out to the user.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ReceiveValidatedError {
+    /// A failure occurred while receiving the sample.
+    ReceiveError(ReceiveError),
+    /// The payload of the received sample does not contain a valid value of the payload type.
+    InvalidPayload(ValidationError),
+    /// The user header of the received sample does not contain a valid value of the user header
+    /// type.
+    InvalidUserHeader(ValidationError),
+}
+
+impl From<ReceiveError> for ReceiveValidatedError {
+    fn from(value: ReceiveError) -> Self {
+        ReceiveValidatedError::ReceiveError(value)
+    }
+}
+
+impl core::fmt::Display for ReceiveValidatedError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "ReceiveValidatedError::{self:?}")
+    }
+}
+
+impl core::error::Error for ReceiveValidatedError {}
@@ -34,6 +34,29 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! ## Notify With Data
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let event = node.service_builder(&"MyEventName".try_into()?)
+//!     .event()
+//!     .notification_data_size(16)
+//!     .open_or_create()?;
+//!
+//! let notifier = event.notifier_builder().create()?;
+//!
+//! // notify with the default event id and attach some context
+//! notifier.notify_with_data(b"temperature: 23")?;
+//!
+//! // notify with some custom event id and attach some context
+//! notifier.notify_with_custom_event_id_and_data(EventId::new(6), &[1, 2, 3])?;
+//!
+//! # Ok(())
+//! # }
+//! ```
 
 use core::ptr::NonNull;
 use core::time::Duration;
@@ -66,7 +89,7 @@ use crate::{
     },
 };
 
-use super::event_id::EventId;
+use super::event_id::{EventId, EventIdFilter};
 
 /// Failures that can occur when a new [`Notifier`] is created with the
 /// [`crate::service::port_factory::notifier::PortFactoryNotifier`].
@@ -106,6 +129,12 @@ pub enum NotifierNotifyError {
     /// The notification was delivered but the elapsed system time could not be acquired.
     /// Therefore, it is unknown if the deadline was missed or not.
     UnableToAcquireElapsedTime,
+    /// The data provided to [`Notifier::notify_with_data()`] exceeds the notification data size
+    /// of the [`Service`](crate::service::Service).
+    NotificationDataTooLarge,
+    /// The data provided to [`Notifier::notify_with_data()`] could not be stored since another
+    /// [`Notifier`] blocked the storage for too long, e.g. because it died while updating it.
+    UnableToStoreNotificationData,
 }
 
 impl core::fmt::Display for NotifierNotifyError {
@@ -121,6 +150,8 @@ struct Connection<Service: service::Service> {
     notifier: <Service::Event as Event<RelocatableCountingBitSet>>::Notifier,
     listener_id: UniqueListenerId,
     node_id: UniqueNodeId,
+    event_id_filter: EventIdFilter,
+    accepts_notifier: bool,
 }
 
 #[derive(Debug)]
@@ -129,6 +160,7 @@ struct ListenerConnections<Service: service::Service> {
     connections: Vec<UnsafeCell<Option<Connection<Service>>>>,
     service_state: SharedServiceState<Service, NoResource>,
     list_state: UnsafeCell<ContainerState<ListenerDetails>>,
+    notifier_id: UniqueNotifierId,
 }
 
 impl<Service: service::Service> Abandonable for ListenerConnections<Service> {
@@ -145,11 +177,13 @@ impl<Service: service::Service> ListenerConnections<Service> {
         size: usize,
         service_state: SharedServiceState<Service, NoResource>,
         list_state: UnsafeCell<ContainerState<ListenerDetails>>,
+        notifier_id: UniqueNotifierId,
     ) -> Self {
         let mut new_self = Self {
             connections: vec![],
             service_state,
             list_state,
+            notifier_id,
         };
 
         new_self.connections.reserve(size);
@@ -160,9 +194,9 @@ impl<Service: service::Service> ListenerConnections<Service> {
         new_self
     }
 
-    fn create(&self, index: usize, listener_id: UniqueListenerId, node_id: UniqueNodeId) {
+    fn create(&self, index: usize, details: &ListenerDetails) {
         let msg = "Unable to establish connection to listener";
-        let event_name = event_concept_name(&listener_id);
+        let event_name = event_concept_name(&details.listener_id);
         let event_config = event_config::<Service>(self.service_state.shared_node().config());
         if self.get(index).is_none() {
             match <Service::Event as iceoryx2_cal::event::Event<RelocatableCountingBitSet>>::NotifierBuilder::new(&event_name)
@@ -172,8 +206,10 @@ impl<Service: service::Service> ListenerConnections<Service> {
                 Ok(notifier) => {
                     *self.get_mut(index) = Some(Connection {
                         notifier,
-                        listener_id,
-                        node_id,
+                        listener_id: details.listener_id,
+                        node_id: details.node_id,
+                        event_id_filter: details.event_id_filter,
+                        accepts_notifier: details.allowed_notifiers.accepts(&self.notifier_id),
                     });
                 }
                 Err(
@@ -233,8 +269,8 @@ impl<Service: service::Service> ListenerConnections<Service> {
         visited_indices.resize(self.len(), None);
 
         unsafe {
-            (*self.list_state.get()).for_each(|index, listener_id| {
-                visited_indices[index] = Some(*listener_id);
+            (*self.list_state.get()).for_each(|index, details| {
+                visited_indices[index] = Some(*details);
                 CallbackProgression::Continue
             })
         };
@@ -254,7 +290,7 @@ impl<Service: service::Service> ListenerConnections<Service> {
                     };
 
                     if create_connection {
-                        self.create(i, details.listener_id, details.node_id);
+                        self.create(i, details);
                     }
                 }
                 None => self.remove(i),
@@ -269,6 +305,7 @@ pub struct Notifier<Service: service::Service> {
     listener_connections: Service::ArcThreadSafetyPolicy<ListenerConnections<Service>>,
     default_event_id: EventId,
     event_id_max_value: usize,
+    notification_data_size: usize,
     dynamic_notifier_handle: Option<ContainerHandle>,
     notifier_id: UniqueNotifierId,
     on_drop_notification: Option<EventId>,
@@ -396,6 +433,7 @@ impl<Service: service::Service> Notifier<Service> {
             listener_list.capacity(),
             service.clone(),
             UnsafeCell::new(unsafe { listener_list.get_state() }),
+            notifier_id,
         ));
 
         let listener_connections = match listener_connections {
@@ -411,6 +449,7 @@ impl<Service: service::Service> Notifier<Service> {
             listener_connections,
             default_event_id,
             event_id_max_value: static_config.event_id_max_value,
+            notification_data_size: static_config.notification_data_size,
             dynamic_notifier_handle: None,
             notifier_id,
             on_drop_notification: None,
@@ -463,6 +502,63 @@ impl<Service: service::Service> Notifier<Service> {
         self.notify_with_custom_event_id(self.default_event_id)
     }
 
+    /// Stores the provided data as latest data of the default event id, provided on creation,
+    /// and notifies all [`crate::port::listener::Listener`] connected to the service.
+    /// On success the number of
+    /// [`crate::port::listener::Listener`]s that were notified otherwise it returns
+    /// [`NotifierNotifyError`].
+    pub fn notify_with_data(&self, data: &[u8]) -> Result<usize, NotifierNotifyError> {
+        self.notify_with_custom_event_id_and_data(self.default_event_id, data)
+    }
+
+    /// Stores the provided data as latest data of the custom [`EventId`] and notifies all
+    /// [`crate::port::listener::Listener`] connected to the service.
+    /// On success the number of
+    /// [`crate::port::listener::Listener`]s that were notified otherwise it returns
+    /// [`NotifierNotifyError`].
+    pub fn notify_with_custom_event_id_and_data(
+        &self,
+        value: EventId,
+        data: &[u8],
+    ) -> Result<usize, NotifierNotifyError> {
+        let msg = "Unable to notify event with data";
+
+        if self.event_id_max_value < value.as_value() {
+            fail!(from self, with NotifierNotifyError::EventIdOutOfBounds,
+                "{} since the EventId {:?} exceeds the maximum supported EventId value of {}.",
+                msg, value, self.event_id_max_value);
+        }
+
+        if self.notification_data_size < data.len() {
+            fail!(from self, with NotifierNotifyError::NotificationDataTooLarge,
+                "{} since the data has a size of {} bytes but the service supports only a notification data size of {} bytes.",
+                msg, data.len(), self.notification_data_size);
+        }
+
+        if self.notification_data_size != 0
+            && !self
+                .listener_connections
+                .lock()
+                .service_state
+                .dynamic_storage()
+                .get()
+                .event()
+                .write_notification_data(value.as_value(), data)
+        {
+            fail!(from self, with NotifierNotifyError::UnableToStoreNotificationData,
+                "{} since the storage of the EventId {:?} is blocked by another notifier.",
+                msg, value);
+        }
+
+        self.__internal_notify(value, false)
+    }
+
+    /// Returns the maximum size in bytes of the data that can be sent with
+    /// [`Notifier::notify_with_data()`].
+    pub fn notification_data_size(&self) -> usize {
+        self.notification_data_size
+    }
+
     /// Returns the deadline of the corresponding [`Service`](crate::service::Service).
     pub fn deadline(&self) -> Option<Duration> {
         self.listener_connections
@@ -517,18 +613,27 @@ impl<Service: service::Service> Notifier<Service> {
 
         for i in 0..listener_connections.len() {
             if let Some(connection) = listener_connections.get(i) {
-                if !(skip_self_deliver && connection.node_id == self.node_id) {
-                    match connection.notifier.notify(value) {
-                        Err(iceoryx2_cal::event::NotifierNotifyError::Disconnected) => {
-                            listener_connections.remove(i);
-                        }
-                        Err(e) => {
-                            warn!(from self, "Unable to send notification via connection {:?} due to {:?}.",
-                                    connection, e)
-                        }
-                        Ok(_) => {
-                            number_of_triggered_listeners += 1;
-                        }
+                if skip_self_deliver && connection.node_id == self.node_id {
+                    continue;
+                }
+
+                // the listener is not interested in notifications from this notifier or with
+                // this event id, the filter is also enforced by the listener itself
+                if !connection.accepts_notifier || !connection.event_id_filter.accepts(value) {
+                    continue;
+                }
+
+                match connection.notifier.notify(value) {
+                    Err(iceoryx2_cal::event::NotifierNotifyError::Disconnected) => {
+                        listener_connections.remove(i);
+                    }
+                    Err(iceoryx2_cal::event::NotifierNotifyError::EventIdNotAccepted) => (),
+                    Err(e) => {
+                        warn!(from self, "Unable to send notification via connection {:?} due to {:?}.",
+                                connection, e)
+                    }
+                    Ok(_) => {
+                        number_of_triggered_listeners += 1;
                     }
                 }
             }
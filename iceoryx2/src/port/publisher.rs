@@ -172,6 +172,8 @@ impl core::fmt::Display for PublisherCreateError {
 
 impl core::error::Error for PublisherCreateError {}
 
+const NO_STATISTICS_INDEX: usize = usize::MAX;
+
 #[derive(Debug, Clone, Copy)]
 struct OffsetAndSize {
     offset: u64,
@@ -185,6 +187,7 @@ pub(crate) struct PublisherSharedState<Service: service::Service> {
     subscriber_list_state: UnsafeCell<ContainerState<SubscriberDetails>>,
     history: Option<UnsafeCell<Queue<OffsetAndSize>>>,
     is_active: AtomicBool,
+    statistics_index: AtomicUsize,
     // IMPORTANT!
     // Fields of a rust struct are dropped in declaration order. Since this tag is our marker that the
     // port exists and might require cleanup after a crash, the tag must be defined as last member of
@@ -316,8 +319,27 @@ impl<Service: service::Service> PublisherSharedState<Service> {
             "{} since the connections could not be updated.", msg);
 
         self.add_sample_to_history(offset, sample_size);
-        self.sender
-            .deliver_offset(offset, sample_size, ChannelId::new(0))
+        let dynamic_config = self.sender.service_state.dynamic_storage().get();
+        let dynamic_config = dynamic_config.publish_subscribe();
+        let number_of_recipients = self.sender.deliver_offset_and_track(
+            offset,
+            sample_size,
+            ChannelId::new(0),
+            |connection_id, has_overflowed| {
+                dynamic_config
+                    .subscriber_statistics(connection_id)
+                    .increment_delivered_samples(has_overflowed)
+            },
+        );
+
+        let statistics_index = self.statistics_index.load(Ordering::Relaxed);
+        if statistics_index != NO_STATISTICS_INDEX {
+            let statistics = dynamic_config.publisher_statistics(statistics_index);
+            statistics.increment_sent_samples();
+            statistics.set_samples_in_use(self.sender.number_of_used_samples());
+        }
+
+        number_of_recipients
     }
 }
 
@@ -488,6 +510,7 @@ impl<
             <Service as service::Service>::ArcThreadSafetyPolicy::new(PublisherSharedState {
                 port_tag,
                 is_active: AtomicBool::new(true),
+                statistics_index: AtomicUsize::new(NO_STATISTICS_INDEX),
                 sender: Sender {
                     data_segment,
                     segment_states: {
@@ -513,6 +536,7 @@ impl<
                     service_state: service.clone(),
                     tagger: CyclicTagger::new(),
                     loan_counter: AtomicUsize::new(0),
+                    used_samples_counter: AtomicUsize::new(0),
                     sender_max_borrowed_samples: config.max_loaned_samples,
                     backpressure_strategy: config.backpressure_strategy,
                     message_type_details: static_config.message_type_details,
@@ -570,6 +594,11 @@ impl<
             }
         };
 
+        new_self
+            .publisher_shared_state
+            .lock()
+            .statistics_index
+            .store(dynamic_publisher_handle.index(), Ordering::Relaxed);
         new_self.dynamic_publisher_handle = Some(dynamic_publisher_handle);
 
         Ok(new_self)
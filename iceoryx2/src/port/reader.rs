@@ -37,12 +37,15 @@
 
 use crate::constants::MAX_BLACKBOARD_KEY_SIZE;
 use crate::identifiers::UniqueReaderId;
+use crate::port::blackboard_snapshot::{BlackboardSnapshot, BlackboardSnapshotEntry};
 use crate::prelude::EventId;
 use crate::service::builder::CustomKeyMarker;
 use crate::service::builder::blackboard::{BlackboardResources, KeyMemory};
 use crate::service::dynamic_config::blackboard::ReaderDetails;
 use crate::service::static_config::message_type_details::{TypeDetail, TypeVariant};
 use crate::service::{self, SharedServiceState};
+use alloc::vec;
+use alloc::vec::Vec;
 use core::alloc::Layout;
 use core::fmt::Debug;
 use core::hash::Hash;
@@ -50,6 +53,7 @@ use core::marker::PhantomData;
 use core::ops::Deref;
 use core::ptr::NonNull;
 use iceoryx2_bb_concurrency::atomic::Ordering;
+use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_elementary::math::align;
 use iceoryx2_bb_elementary_traits::non_null::NonNullCompat;
 use iceoryx2_bb_elementary_traits::testing::abandonable::Abandonable;
@@ -157,6 +161,27 @@ impl core::fmt::Display for ReaderCreateError {
 
 impl core::error::Error for ReaderCreateError {}
 
+/// Defines a failure that can occur when a [`BlackboardSnapshot`] is acquired with
+/// [`Reader::snapshot()`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SnapshotError {
+    /// The entries were updated continuously while the snapshot was taken so that no
+    /// consistent view of all entries could be acquired.
+    UnableToAcquireConsistentView,
+}
+
+impl core::fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SnapshotError::{self:?}")
+    }
+}
+
+impl core::error::Error for SnapshotError {}
+
+// The maximum number of attempts to acquire a consistent view of all entries before
+// [`Reader::snapshot()`] gives up.
+const MAX_SNAPSHOT_ATTEMPTS: usize = 64;
+
 /// Reading endpoint of a blackboard based communication.
 #[derive(Debug)]
 pub struct Reader<
@@ -325,6 +350,118 @@ impl<
         Ok(EntryHandle::new(self.shared_state.clone(), atomic, offset))
     }
 
+    /// Acquires a [`BlackboardSnapshot`] that contains a copy of every key-value pair of the
+    /// blackboard. All values stem from a single point in time, meaning that no entry was
+    /// updated while the snapshot was taken. If the entries are updated so frequently that no
+    /// consistent view can be acquired, [`SnapshotError::UnableToAcquireConsistentView`] is
+    /// returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .blackboard_creator::<u64>()
+    /// #     .add::<i32>(1, -1)
+    /// #     .create()?;
+    /// #
+    /// # let reader = service.reader_builder().create()?;
+    /// let snapshot = reader.snapshot()?;
+    /// for entry in snapshot.entries() {
+    ///     println!("key: {}, value type: {:?}", entry.key(), entry.type_details());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn snapshot(&self) -> Result<BlackboardSnapshot<KeyType>, SnapshotError> {
+        let shared_state = self.shared_state.lock();
+        let resources = shared_state.service_state.additional_resource();
+        let mgmt = resources.mgmt.get();
+        let payload_start_address = resources.data.payload_start_address();
+
+        // the entries are fixed after the creation of the blackboard, only their values change
+        let mut keys = Vec::new();
+        let mut atomics = Vec::new();
+        mgmt.map.list_keys(|key_mem: &KeyMemory<MAX_BLACKBOARD_KEY_SIZE>| {
+            let index = match unsafe {
+                mgmt.map
+                    .__internal_get(key_mem, resources.key_eq_func.as_ref())
+            } {
+                Some(index) => index,
+                None => {
+                    fatal_panic!(from self, "This should never happen! A listed key has no entry.");
+                }
+            };
+            let entry = &mgmt.entries[index];
+            let atomic_mgmt_ptr = (payload_start_address as u64
+                + entry.offset.load(core::sync::atomic::Ordering::Relaxed))
+                as *const UnrestrictedAtomicMgmt;
+            let data_ptr = align(
+                atomic_mgmt_ptr as usize + core::mem::size_of::<UnrestrictedAtomicMgmt>(),
+                entry.type_details.alignment,
+            );
+
+            keys.push(unsafe { *(key_mem.data.as_ptr() as *const KeyType) });
+            atomics.push((
+                atomic_mgmt_ptr,
+                data_ptr as *const u8,
+                entry.type_details,
+            ));
+            CallbackProgression::Continue
+        });
+
+        let generation_counters = |counters: &mut Vec<u64>| {
+            counters.clear();
+            counters.extend(
+                atomics
+                    .iter()
+                    .map(|(mgmt_ptr, _, _)| unsafe { (**mgmt_ptr).__internal_get_write_cell() }),
+            );
+        };
+
+        let mut counters_before = Vec::with_capacity(atomics.len());
+        let mut counters_after = Vec::with_capacity(atomics.len());
+        for _ in 0..MAX_SNAPSHOT_ATTEMPTS {
+            generation_counters(&mut counters_before);
+
+            let values: Vec<Vec<u8>> = atomics
+                .iter()
+                .map(|(mgmt_ptr, data_ptr, type_details)| {
+                    let mut value = vec![0u8; type_details.size];
+                    unsafe {
+                        (**mgmt_ptr).load(
+                            value.as_mut_ptr(),
+                            type_details.size,
+                            type_details.alignment,
+                            *data_ptr,
+                        )
+                    };
+                    value
+                })
+                .collect();
+
+            // when no generation counter changed, all values were valid at the same time
+            generation_counters(&mut counters_after);
+            if counters_before == counters_after {
+                let entries = keys
+                    .iter()
+                    .zip(atomics.iter())
+                    .zip(values)
+                    .map(|((key, (_, _, type_details)), value)| {
+                        BlackboardSnapshotEntry::new(*key, *type_details, value)
+                    })
+                    .collect();
+                return Ok(BlackboardSnapshot::new(entries));
+            }
+        }
+
+        fail!(from self, with SnapshotError::UnableToAcquireConsistentView,
+            "Unable to acquire blackboard snapshot since the entries were updated continuously in all {} attempts.",
+            MAX_SNAPSHOT_ATTEMPTS);
+    }
+
     fn get_entry_offset(
         &self,
         key_mem: &KeyMemory<MAX_BLACKBOARD_KEY_SIZE>,
@@ -366,7 +503,7 @@ impl<
             .entries[index];
 
         // check if ValueType matches
-        if *value_type_details != entry.type_details {
+        if !value_type_details.is_identical_to(&entry.type_details) {
             fail!(from self, with EntryHandleError::EntryDoesNotExist,
                 "{} since no entry with the given key and value type exists.", msg);
         }
@@ -92,8 +92,8 @@ use crate::{
 use alloc::sync::Arc;
 use core::ptr::NonNull;
 use core::{fmt::Debug, marker::PhantomData};
-use iceoryx2_bb_concurrency::atomic::AtomicUsize;
 use iceoryx2_bb_concurrency::atomic::Ordering;
+use iceoryx2_bb_concurrency::atomic::{AtomicU64, AtomicUsize};
 use iceoryx2_bb_concurrency::cell::UnsafeCell;
 use iceoryx2_bb_container::slotmap::SlotMap;
 use iceoryx2_bb_container::vector::polymorphic_vec::*;
@@ -465,6 +465,7 @@ impl<
             service_state: service.clone(),
             tagger: CyclicTagger::new(),
             loan_counter: AtomicUsize::new(0),
+            used_samples_counter: AtomicUsize::new(0),
             backpressure_strategy: server_factory.config.backpressure_strategy,
             message_type_details: static_config.response_message_type_details,
             number_of_channels: number_of_requests_per_client,
@@ -605,6 +606,7 @@ impl<
         ActiveRequest {
             details,
             shared_loan_counter: Arc::new(AtomicUsize::new(0)),
+            shared_sequence_counter: Arc::new(AtomicU64::new(0)),
             max_loan_count: self.max_loaned_responses_per_request,
             request_id: header.request_id,
             channel_id: header.channel_id,
@@ -714,6 +716,7 @@ impl<
         ActiveRequest {
             details,
             shared_loan_counter: Arc::new(AtomicUsize::new(0)),
+            shared_sequence_counter: Arc::new(AtomicU64::new(0)),
             max_loan_count: self.max_loaned_responses_per_request,
             request_id: header.request_id,
             channel_id: header.channel_id,
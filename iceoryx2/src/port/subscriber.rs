@@ -44,6 +44,7 @@ use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_elementary::cyclic_tagger::CyclicTagger;
 use iceoryx2_bb_elementary_traits::non_null::NonNullCompat;
 use iceoryx2_bb_elementary_traits::testing::abandonable::Abandonable;
+use iceoryx2_bb_elementary_traits::validate::{Validate, ValidationBounds, ValidationError};
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
 use iceoryx2_bb_memory::heap_allocator::HeapAllocator;
@@ -62,11 +63,11 @@ use crate::service::static_config::publish_subscribe::StaticConfig;
 use crate::service::{NoResource, SharedServiceState};
 use crate::{raw_sample::RawSample, sample::Sample, service};
 
-use super::ReceiveError;
 use super::details::chunk::Chunk;
 use super::details::chunk_details::ChunkDetails;
 use super::details::receiver::*;
 use super::update_connections::ConnectionFailure;
+use super::{ReceiveError, ReceiveValidatedError};
 use crate::identifiers::UniqueSubscriberId;
 
 /// Describes the failures when a new [`Subscriber`] is created via the
@@ -401,10 +402,51 @@ impl<
         fail!(from self, when self.update_connections(),
                 "Some samples are not being received since not all connections to publishers could be established.");
 
-        self.subscriber_shared_state
-            .lock()
-            .receiver
-            .receive(ChannelId::new(0))
+        let subscriber_shared_state = self.subscriber_shared_state.lock();
+        let result = subscriber_shared_state.receiver.receive(ChannelId::new(0));
+
+        if let (Ok(Some(_)), Some(handle)) = (&result, self.dynamic_subscriber_handle) {
+            subscriber_shared_state
+                .receiver
+                .service_state
+                .dynamic_storage()
+                .get()
+                .publish_subscribe()
+                .subscriber_statistics(handle.index())
+                .increment_received_samples();
+        }
+
+        result
+    }
+
+    /// # Safety
+    ///
+    ///  * `user_header` and `payload` must point to the user header and payload of a received
+    ///    sample that is not released during the call
+    ///  * `payload_size` must be the size of the payload in bytes
+    unsafe fn validate_sample(
+        &self,
+        user_header: *const UserHeader,
+        payload: *const Payload,
+        payload_size: usize,
+    ) -> Result<(), ReceiveValidatedError>
+    where
+        Payload: Validate,
+        UserHeader: Validate,
+    {
+        let bounds = ValidationBounds::of_elements(user_header, 1);
+        if let Err(e) = unsafe { UserHeader::validate(user_header, &bounds) } {
+            fail!(from self, with ReceiveValidatedError::InvalidUserHeader(e),
+                "Unable to receive sample since the user header is invalid ({e:?}).");
+        }
+
+        let bounds = ValidationBounds::new(payload.cast(), payload_size);
+        if let Err(e) = unsafe { Payload::validate(payload, &bounds) } {
+            fail!(from self, with ReceiveValidatedError::InvalidPayload(e),
+                "Unable to receive sample since the payload is invalid ({e:?}).");
+        }
+
+        Ok(())
     }
 }
 
@@ -452,6 +494,39 @@ impl<Service: service::Service, Payload: Debug + ZeroCopySend, UserHeader: Debug
             },
         }))
     }
+
+    /// Receives a [`crate::sample::Sample`] like [`Subscriber::receive()`] but validates the
+    /// user header and the payload with [`Validate`] before the sample is handed out. This
+    /// detects malformed data of a [`crate::port::publisher::Publisher`], like a `bool` with a
+    /// value of `2` or a container whose length exceeds its capacity. An invalid sample is
+    /// released and [`ReceiveValidatedError`] is returned.
+    ///
+    /// The sample is validated in place in the shared memory. A
+    /// [`crate::port::publisher::Publisher`] that keeps writing into a sample after it was sent
+    /// can change it during or after the validation. Therefore, this protects only against
+    /// publishers that do not modify sent samples and not against a malicious publisher.
+    pub fn receive_validated(
+        &self,
+    ) -> Result<Option<Sample<Service, Payload, UserHeader>>, ReceiveValidatedError>
+    where
+        Payload: Validate,
+        UserHeader: Validate,
+    {
+        let sample = match self.receive()? {
+            Some(sample) => sample,
+            None => return Ok(None),
+        };
+
+        unsafe {
+            self.validate_sample(
+                sample.ptr.user_header_ptr(),
+                sample.ptr.payload_ptr(),
+                core::mem::size_of::<Payload>(),
+            )?
+        };
+
+        Ok(Some(sample))
+    }
 }
 
 impl<Service: service::Service, Payload: Debug + ZeroCopySend, UserHeader: Debug + ZeroCopySend>
@@ -482,6 +557,50 @@ impl<Service: service::Service, Payload: Debug + ZeroCopySend, UserHeader: Debug
             }
         }))
     }
+
+    /// Receives a [`crate::sample::Sample`] like [`Subscriber::receive()`] but validates the
+    /// user header and every element of the payload with [`Validate`] before the sample is
+    /// handed out. An invalid sample is released and [`ReceiveValidatedError`] is returned.
+    ///
+    /// Like the non-slice variant, the sample is validated in place in the shared memory and
+    /// is not protected against a publisher that modifies it after it was sent.
+    pub fn receive_validated(
+        &self,
+    ) -> Result<Option<Sample<Service, [Payload], UserHeader>>, ReceiveValidatedError>
+    where
+        Payload: Validate,
+        UserHeader: Validate,
+    {
+        let sample = match self.receive()? {
+            Some(sample) => sample,
+            None => return Ok(None),
+        };
+
+        // the number of elements is written by the publisher and must not be trusted before it
+        // is verified that the payload fits into the received chunk
+        let payload = sample.ptr.payload_ptr();
+        let payload_offset =
+            payload as *const u8 as usize - sample.ptr.as_header_ref() as *const Header as usize;
+        let payload_capacity = self
+            .subscriber_shared_state
+            .lock()
+            .receiver
+            .chunk_size(&sample.details)
+            .unwrap_or(0)
+            .saturating_sub(payload_offset);
+        let payload_size = match core::mem::size_of::<Payload>().checked_mul(payload.len()) {
+            Some(payload_size) if payload_size <= payload_capacity => payload_size,
+            _ => {
+                fail!(from self, with ReceiveValidatedError::InvalidPayload(ValidationError::OutOfBounds),
+                    "Unable to receive sample since the number of elements ({}) exceeds the payload capacity of the chunk ({} bytes).",
+                    payload.len(), payload_capacity);
+            }
+        };
+
+        unsafe { self.validate_sample(sample.ptr.user_header_ptr(), payload, payload_size)? };
+
+        Ok(Some(sample))
+    }
 }
 
 impl<Service: service::Service, UserHeader: Debug + ZeroCopySend>
@@ -42,17 +42,19 @@
 
 use crate::constants::MAX_BLACKBOARD_KEY_SIZE;
 use crate::identifiers::UniqueWriterId;
+use crate::port::blackboard_snapshot::BlackboardSnapshot;
 use crate::prelude::EventId;
 use crate::service::builder::CustomKeyMarker;
 use crate::service::builder::blackboard::{BlackboardResources, KeyMemory};
-use crate::service::dynamic_config::blackboard::WriterDetails;
+use crate::service::dynamic_config::blackboard::{EntryOwner, WriterDetails};
 use crate::service::static_config::message_type_details::{TypeDetail, TypeVariant};
 use crate::service::{self, SharedServiceState};
+use alloc::vec::Vec;
 use core::alloc::Layout;
 use core::fmt::Debug;
 use core::hash::Hash;
 use core::marker::PhantomData;
-use core::mem::MaybeUninit;
+use core::mem::{ManuallyDrop, MaybeUninit};
 use core::ptr::NonNull;
 use iceoryx2_bb_concurrency::atomic::Ordering;
 use iceoryx2_bb_concurrency::cell::UnsafeCell;
@@ -69,6 +71,19 @@ use iceoryx2_cal::dynamic_storage::DynamicStorage;
 use iceoryx2_cal::shared_memory::SharedMemory;
 use iceoryx2_log::{fail, fatal_panic};
 
+// The caller must own the entry to which the UnrestrictedAtomicMgmt belongs.
+fn acquire_producer_of_owned_entry(atomic_mgmt: &UnrestrictedAtomicMgmt, origin: &str) {
+    if unsafe { atomic_mgmt.__internal_acquire_producer() }.is_err() {
+        // The entry is owned by this writer, therefore the producer can only be held
+        // by a previous owner that died without releasing it.
+        unsafe { atomic_mgmt.__internal_release_producer() };
+        if unsafe { atomic_mgmt.__internal_acquire_producer() }.is_err() {
+            fatal_panic!(from origin,
+                "This should never happen! The producer of an owned entry could not be acquired.");
+        }
+    }
+}
+
 #[derive(Debug)]
 struct WriterSharedState<
     Service: service::Service,
@@ -79,6 +94,43 @@ struct WriterSharedState<
     _key: PhantomData<KeyType>,
 }
 
+impl<
+    Service: service::Service,
+    KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+> WriterSharedState<Service, KeyType>
+{
+    fn acquire_entry_ownership(&self, entry_index: usize) -> Result<(), EntryHandleMutError> {
+        let handle = match unsafe { &*self.dynamic_writer_handle.get() } {
+            Some(handle) => *handle,
+            None => {
+                fatal_panic!(from self, "This should never happen! Acquiring an entry with an unregistered writer.");
+            }
+        };
+
+        match self
+            .service_state
+            .dynamic_storage()
+            .get()
+            .blackboard()
+            .acquire_entry_ownership(entry_index, handle)
+        {
+            Ok(()) => Ok(()),
+            Err(EntryOwner::SameWriter) => Err(EntryHandleMutError::HandleAlreadyExists),
+            Err(EntryOwner::OtherWriter) => Err(EntryHandleMutError::EntryOwnedByAnotherWriter),
+        }
+    }
+
+    fn release_entry_ownership(&self, entry_index: usize) {
+        if let Some(handle) = unsafe { &*self.dynamic_writer_handle.get() } {
+            self.service_state
+                .dynamic_storage()
+                .get()
+                .blackboard()
+                .release_entry_ownership(entry_index, *handle)
+        }
+    }
+}
+
 impl<
     Service: service::Service,
     KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
@@ -252,7 +304,9 @@ impl<
     }
 
     /// Creates a [`EntryHandleMut`] for direct write access to the value. There can be only one
-    /// [`EntryHandleMut`] per value.
+    /// [`EntryHandleMut`] per value. The [`Writer`] acquires the exclusive ownership of the entry
+    /// until the [`EntryHandleMut`] is dropped. If the entry is already owned by another
+    /// [`Writer`], [`EntryHandleMutError::EntryOwnedByAnotherWriter`] is returned.
     ///
     /// # Example
     ///
@@ -284,14 +338,155 @@ impl<
             }
         };
 
-        let offset = self.get_entry_offset(
+        let (entry_index, offset) = self.get_entry_index_and_offset(
             &key_mem,
             &TypeDetail::new::<ValueType>(TypeVariant::FixedSize),
             msg,
         )?;
 
-        match EntryHandleMut::new(self.shared_state.clone(), offset) {
-            Ok(handle) => Ok(handle),
+        self.acquire_entry_ownership(entry_index, msg)?;
+
+        Ok(EntryHandleMut::new(
+            self.shared_state.clone(),
+            entry_index,
+            offset,
+        ))
+    }
+
+    /// Writes all values of the [`BlackboardSnapshot`] into the corresponding entries of the
+    /// blackboard. If an entry of the snapshot does not exist,
+    /// [`ApplySnapshotError::EntryDoesNotExist`] is returned and if its value type differs,
+    /// [`ApplySnapshotError::ValueTypeMismatch`] is returned. If one of the entries is owned
+    /// by another [`Writer`], [`ApplySnapshotError::EntryOwnedByAnotherWriter`] is returned
+    /// and if this [`Writer`] holds an [`EntryHandleMut`] to one of them,
+    /// [`ApplySnapshotError::HandleAlreadyExists`] is returned.
+    ///
+    /// All entries are verified and their ownership is acquired before the first value is
+    /// written, therefore no value is written when an error is returned. The values are
+    /// written one after another, so a [`Reader`](crate::port::reader::Reader) that accesses
+    /// the blackboard concurrently may observe some entries with the values of the snapshot
+    /// and others with their previous values until this method returns.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .blackboard_creator::<u64>()
+    /// #     .add::<i32>(1, -1)
+    /// #     .create()?;
+    /// #
+    /// # let reader = service.reader_builder().create()?;
+    /// # let writer = service.writer_builder().create()?;
+    /// let snapshot = reader.snapshot()?;
+    /// writer.entry::<i32>(&1)?.update_with_copy(8);
+    ///
+    /// writer.apply_snapshot(&snapshot)?;
+    /// assert_eq!(*reader.entry::<i32>(&1)?.get(), -1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn apply_snapshot(
+        &self,
+        snapshot: &BlackboardSnapshot<KeyType>,
+    ) -> Result<(), ApplySnapshotError> {
+        let msg = "Unable to apply blackboard snapshot";
+
+        let mut entries = Vec::with_capacity(snapshot.len());
+        for entry in snapshot.entries() {
+            if entry.value().len() != entry.type_details().size() {
+                fail!(from self, with ApplySnapshotError::ValueTypeMismatch,
+                    "{} since the value of the entry with the key {:?} does not match its type details.",
+                    msg, entry.key());
+            }
+
+            let key_mem = match KeyMemory::try_from(entry.key()) {
+                Ok(mem) => mem,
+                Err(_) => {
+                    fatal_panic!(from self, "This should never happen! Key with invalid layout passed.");
+                }
+            };
+
+            let (entry_index, offset) = match self.lookup_entry(&key_mem, entry.type_details()) {
+                Ok(v) => v,
+                Err(EntryLookupFailure::KeyDoesNotExist) => {
+                    fail!(from self, with ApplySnapshotError::EntryDoesNotExist,
+                        "{} since no entry with the key {:?} exists.", msg, entry.key());
+                }
+                Err(EntryLookupFailure::ValueTypeMismatch) => {
+                    fail!(from self, with ApplySnapshotError::ValueTypeMismatch,
+                        "{} since the entry with the key {:?} has a different value type.",
+                        msg, entry.key());
+                }
+            };
+            entries.push((entry_index, offset, entry));
+        }
+
+        for (n, (entry_index, _, _)) in entries.iter().enumerate() {
+            if let Err(e) = self.acquire_entry_ownership(*entry_index, msg) {
+                let shared_state = self.shared_state.lock();
+                for (owned_entry_index, _, _) in entries.iter().take(n) {
+                    shared_state.release_entry_ownership(*owned_entry_index);
+                }
+                return Err(e.into());
+            }
+        }
+
+        let shared_state = self.shared_state.lock();
+        let payload_start_address = shared_state
+            .service_state
+            .additional_resource()
+            .data
+            .payload_start_address();
+        for (entry_index, offset, entry) in entries {
+            let atomic_mgmt = unsafe {
+                &*((payload_start_address as u64 + offset) as *const UnrestrictedAtomicMgmt)
+            };
+            let type_details = entry.type_details();
+            let data_ptr = align(
+                atomic_mgmt as *const UnrestrictedAtomicMgmt as usize
+                    + core::mem::size_of::<UnrestrictedAtomicMgmt>(),
+                type_details.alignment(),
+            );
+
+            acquire_producer_of_owned_entry(atomic_mgmt, "Writer::apply_snapshot()");
+            unsafe {
+                let write_cell = atomic_mgmt.__internal_get_ptr_to_write_cell(
+                    type_details.size(),
+                    type_details.alignment(),
+                    data_ptr as *mut u8,
+                );
+                core::ptr::copy_nonoverlapping(
+                    entry.value().as_ptr(),
+                    write_cell,
+                    type_details.size(),
+                );
+                atomic_mgmt.__internal_update_write_cell();
+                atomic_mgmt.__internal_release_producer();
+            }
+            shared_state.release_entry_ownership(entry_index);
+        }
+
+        Ok(())
+    }
+
+    fn acquire_entry_ownership(
+        &self,
+        entry_index: usize,
+        msg: &str,
+    ) -> Result<(), EntryHandleMutError> {
+        match self
+            .shared_state
+            .lock()
+            .acquire_entry_ownership(entry_index)
+        {
+            Ok(()) => Ok(()),
+            Err(EntryHandleMutError::EntryOwnedByAnotherWriter) => {
+                fail!(from self, with EntryHandleMutError::EntryOwnedByAnotherWriter,
+                    "{} since the entry is owned by another writer.", msg);
+            }
             Err(e) => {
                 fail!(from self, with e,
                     "{} since a handle for the passed key and value type already exists.", msg);
@@ -299,12 +494,30 @@ impl<
         }
     }
 
-    fn get_entry_offset(
+    fn get_entry_index_and_offset(
         &self,
         key_mem: &KeyMemory<MAX_BLACKBOARD_KEY_SIZE>,
         value_type_details: &TypeDetail,
         msg: &str,
-    ) -> Result<u64, EntryHandleMutError> {
+    ) -> Result<(usize, u64), EntryHandleMutError> {
+        match self.lookup_entry(key_mem, value_type_details) {
+            Ok(v) => Ok(v),
+            Err(EntryLookupFailure::KeyDoesNotExist) => {
+                fail!(from self, with EntryHandleMutError::EntryDoesNotExist,
+                    "{} since no entry with the given key exists.", msg);
+            }
+            Err(EntryLookupFailure::ValueTypeMismatch) => {
+                fail!(from self, with EntryHandleMutError::EntryDoesNotExist,
+                    "{} since no entry with the given key and value type exists.", msg);
+            }
+        }
+    }
+
+    fn lookup_entry(
+        &self,
+        key_mem: &KeyMemory<MAX_BLACKBOARD_KEY_SIZE>,
+        value_type_details: &TypeDetail,
+    ) -> Result<(usize, u64), EntryLookupFailure> {
         // check if key exists
         let shared_state = self.shared_state.lock();
         let index = match unsafe {
@@ -324,10 +537,7 @@ impl<
                 )
         } {
             Some(i) => i,
-            None => {
-                fail!(from self, with EntryHandleMutError::EntryDoesNotExist,
-                "{} since no entry with the given key exists.", msg);
-            }
+            None => return Err(EntryLookupFailure::KeyDoesNotExist),
         };
 
         let entry = &shared_state
@@ -338,14 +548,13 @@ impl<
             .entries[index];
 
         // check if ValueType matches
-        if *value_type_details != entry.type_details {
-            fail!(from self, with EntryHandleMutError::EntryDoesNotExist,
-                "{} since no entry with the given key and value type exists.", msg);
+        if !value_type_details.is_identical_to(&entry.type_details) {
+            return Err(EntryLookupFailure::ValueTypeMismatch);
         }
 
         let offset = entry.offset.load(core::sync::atomic::Ordering::Relaxed);
 
-        Ok(offset)
+        Ok((index, offset))
     }
 }
 
@@ -356,6 +565,8 @@ pub enum EntryHandleMutError {
     EntryDoesNotExist,
     /// The [`EntryHandleMut`] already exists.
     HandleAlreadyExists,
+    /// The entry is currently owned by another [`Writer`].
+    EntryOwnedByAnotherWriter,
 }
 
 impl core::fmt::Display for EntryHandleMutError {
@@ -366,15 +577,87 @@ impl core::fmt::Display for EntryHandleMutError {
 
 impl core::error::Error for EntryHandleMutError {}
 
+/// Defines a failure that can occur when a [`BlackboardSnapshot`] is applied with
+/// [`Writer::apply_snapshot()`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ApplySnapshotError {
+    /// An entry of the snapshot does not exist in the blackboard.
+    EntryDoesNotExist,
+    /// An entry of the snapshot exists in the blackboard but with a different value type.
+    ValueTypeMismatch,
+    /// This [`Writer`] holds an [`EntryHandleMut`] to an entry of the snapshot.
+    HandleAlreadyExists,
+    /// An entry of the snapshot is currently owned by another [`Writer`].
+    EntryOwnedByAnotherWriter,
+}
+
+impl From<EntryHandleMutError> for ApplySnapshotError {
+    fn from(value: EntryHandleMutError) -> Self {
+        match value {
+            EntryHandleMutError::EntryDoesNotExist => ApplySnapshotError::EntryDoesNotExist,
+            EntryHandleMutError::HandleAlreadyExists => ApplySnapshotError::HandleAlreadyExists,
+            EntryHandleMutError::EntryOwnedByAnotherWriter => {
+                ApplySnapshotError::EntryOwnedByAnotherWriter
+            }
+        }
+    }
+}
+
+impl core::fmt::Display for ApplySnapshotError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "ApplySnapshotError::{self:?}")
+    }
+}
+
+impl core::error::Error for ApplySnapshotError {}
+
+enum EntryLookupFailure {
+    KeyDoesNotExist,
+    ValueTypeMismatch,
+}
+
 /// A handle for direct write access to a specific blackboard value.
 pub struct EntryHandleMut<
     Service: service::Service,
     KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
     ValueType: Copy + 'static,
 > {
-    producer: Producer<'static, ValueType>,
+    producer: ManuallyDrop<Producer<'static, ValueType>>,
     entry_id: EventId,
-    _shared_state: Service::ArcThreadSafetyPolicy<WriterSharedState<Service, KeyType>>,
+    entry_index: usize,
+    shared_state: Service::ArcThreadSafetyPolicy<WriterSharedState<Service, KeyType>>,
+}
+
+impl<
+    Service: service::Service,
+    KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+    ValueType: Copy + 'static,
+> Drop for EntryHandleMut<Service, KeyType, ValueType>
+{
+    fn drop(&mut self) {
+        // the producer must be released before the ownership, otherwise the next owner is
+        // unable to acquire it
+        unsafe { ManuallyDrop::drop(&mut self.producer) };
+        self.shared_state
+            .lock()
+            .release_entry_ownership(self.entry_index);
+    }
+}
+
+impl<
+    Service: service::Service,
+    KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+    ValueType: Copy + 'static,
+> Abandonable for EntryHandleMut<Service, KeyType, ValueType>
+{
+    unsafe fn abandon_in_place(mut this: NonNull<Self>) {
+        let this = unsafe { this.as_mut() };
+        unsafe {
+            Service::ArcThreadSafetyPolicy::abandon_in_place(NonNull::iox2_from_mut(
+                &mut this.shared_state,
+            ))
+        };
+    }
 }
 
 // Safe since the producer implements Send + Sync and shared_state ensures the lifetime of the
@@ -400,10 +683,12 @@ impl<
     ValueType: Copy + 'static,
 > EntryHandleMut<Service, KeyType, ValueType>
 {
+    // The caller must own the entry.
     fn new(
         writer_state: Service::ArcThreadSafetyPolicy<WriterSharedState<Service, KeyType>>,
+        entry_index: usize,
         offset: u64,
-    ) -> Result<Self, EntryHandleMutError> {
+    ) -> Self {
         let atomic = (writer_state
             .lock()
             .service_state
@@ -411,19 +696,35 @@ impl<
             .data
             .payload_start_address() as u64
             + offset) as *mut UnrestrictedAtomic<ValueType>;
-        match unsafe { (*atomic).acquire_producer() } {
-            None => Err(EntryHandleMutError::HandleAlreadyExists),
-            Some(producer) => {
-                // change to static lifetime is safe since shared_state owns the service state and
-                // the dynamic writer handle + the struct fields are dropped in the same order as
-                // declared
-                let p: Producer<'static, ValueType> = unsafe { core::mem::transmute(producer) };
-                Ok(Self {
-                    producer: p,
-                    _shared_state: writer_state.clone(),
-                    entry_id: EventId::new(offset as _),
-                })
+        let producer = match unsafe { (*atomic).acquire_producer() } {
+            Some(producer) => producer,
+            None => {
+                // The entry is owned by this writer, therefore the producer can only be held
+                // by a previous owner that died without releasing it.
+                unsafe {
+                    (*atomic)
+                        .__internal_get_mgmt()
+                        .__internal_release_producer()
+                };
+                match unsafe { (*atomic).acquire_producer() } {
+                    Some(producer) => producer,
+                    None => {
+                        fatal_panic!(from "EntryHandleMut::new()",
+                            "This should never happen! The producer of an owned entry could not be acquired.");
+                    }
+                }
             }
+        };
+
+        // change to static lifetime is safe since shared_state owns the service state and
+        // the dynamic writer handle and the producer is released in drop before the
+        // shared_state
+        let p: Producer<'static, ValueType> = unsafe { core::mem::transmute(producer) };
+        Self {
+            producer: ManuallyDrop::new(p),
+            shared_state: writer_state.clone(),
+            entry_id: EventId::new(offset as _),
+            entry_index,
         }
     }
 
@@ -492,6 +793,20 @@ pub struct EntryValueUninit<
     entry_handle_mut: EntryHandleMut<Service, KeyType, ValueType>,
 }
 
+impl<
+    Service: service::Service,
+    KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+    ValueType: Copy + 'static,
+> Abandonable for EntryValueUninit<Service, KeyType, ValueType>
+{
+    unsafe fn abandon_in_place(mut this: NonNull<Self>) {
+        let this = unsafe { this.as_mut() };
+        unsafe {
+            EntryHandleMut::abandon_in_place(NonNull::iox2_from_mut(&mut this.entry_handle_mut))
+        };
+    }
+}
+
 // Safe since the EntryHandleMut implements Send + Sync and the EntryHandleMut's shared_state ensures that
 // the memory address ptr is pointing to remains valid, and all methods of EntryValueUninit are
 // consuming.
@@ -679,8 +994,13 @@ impl<Service: service::Service> Writer<Service, CustomKeyMarker> {
             }
         };
 
-        let offset = self.get_entry_offset(&key_mem, value_type_details, msg)?;
+        let (entry_index, offset) =
+            self.get_entry_index_and_offset(&key_mem, value_type_details, msg)?;
+        drop(shared_state);
 
+        self.acquire_entry_ownership(entry_index, msg)?;
+
+        let shared_state = self.shared_state.lock();
         let atomic_mgmt_ptr = (shared_state
             .service_state
             .additional_resource()
@@ -691,18 +1011,13 @@ impl<Service: service::Service> Writer<Service, CustomKeyMarker> {
         let data_ptr = atomic_mgmt_ptr as usize + core::mem::size_of::<UnrestrictedAtomicMgmt>();
         let data_ptr = align(data_ptr, value_type_details.alignment);
 
-        match __InternalEntryHandleMut::new(
+        Ok(__InternalEntryHandleMut::new(
             atomic_mgmt_ptr,
             data_ptr as *mut u8,
             EventId::new(offset as _),
+            entry_index,
             self.shared_state.clone(),
-        ) {
-            Ok(handle) => Ok(handle),
-            Err(e) => {
-                fail!(from self, with e,
-                    "{} since a handle for the passed key and value type already exists.", msg);
-            }
-        }
+        ))
     }
 }
 
@@ -713,12 +1028,16 @@ pub struct __InternalEntryHandleMut<Service: service::Service> {
     atomic_mgmt_ptr: *const UnrestrictedAtomicMgmt,
     data_ptr: *mut u8,
     entry_id: EventId,
-    _shared_state: Service::ArcThreadSafetyPolicy<WriterSharedState<Service, CustomKeyMarker>>,
+    entry_index: usize,
+    shared_state: Service::ArcThreadSafetyPolicy<WriterSharedState<Service, CustomKeyMarker>>,
 }
 
 impl<Service: service::Service> Drop for __InternalEntryHandleMut<Service> {
     fn drop(&mut self) {
         unsafe { (*self.atomic_mgmt_ptr).__internal_release_producer() };
+        self.shared_state
+            .lock()
+            .release_entry_ownership(self.entry_index);
     }
 }
 
@@ -729,20 +1048,25 @@ unsafe impl<Service: service::Service> Send for __InternalEntryHandleMut<Service
 unsafe impl<Service: service::Service> Sync for __InternalEntryHandleMut<Service> {}
 
 impl<Service: service::Service> __InternalEntryHandleMut<Service> {
+    // The caller must own the entry.
     fn new(
         atomic_mgmt_ptr: *const UnrestrictedAtomicMgmt,
         data_ptr: *mut u8,
         entry_id: EventId,
+        entry_index: usize,
         writer_state: Service::ArcThreadSafetyPolicy<WriterSharedState<Service, CustomKeyMarker>>,
-    ) -> Result<Self, EntryHandleMutError> {
-        match unsafe { (*atomic_mgmt_ptr).__internal_acquire_producer() } {
-            Ok(_) => Ok(Self {
-                atomic_mgmt_ptr,
-                data_ptr,
-                entry_id,
-                _shared_state: writer_state.clone(),
-            }),
-            Err(_) => Err(EntryHandleMutError::HandleAlreadyExists),
+    ) -> Self {
+        acquire_producer_of_owned_entry(
+            unsafe { &*atomic_mgmt_ptr },
+            "__InternalEntryHandleMut::new()",
+        );
+
+        Self {
+            atomic_mgmt_ptr,
+            data_ptr,
+            entry_id,
+            entry_index,
+            shared_state: writer_state.clone(),
         }
     }
 
@@ -13,8 +13,12 @@
 pub use crate::config::Config;
 pub use crate::node::{Node, NodeBuilder, NodeState, node_name::NodeName};
 pub use crate::port::EventActivation;
-pub use crate::port::{backpressure_strategy::BackpressureStrategy, event_id::EventId};
+pub use crate::port::{
+    backpressure_strategy::BackpressureStrategy,
+    event_id::{EventId, EventIdFilter},
+};
 pub use crate::service::messaging_pattern::MessagingPattern;
+pub use crate::service::static_config::message_type_details::TypeCompatibilityPolicy;
 pub use crate::service::{
     Service, ServiceDetails, attribute::AttributeSet, attribute::AttributeSpecifier,
     attribute::AttributeVerifier, ipc, ipc_threadsafe, local, local_threadsafe,
@@ -25,10 +29,12 @@ pub use crate::waitset::{WaitSet, WaitSetAttachmentId, WaitSetBuilder, WaitSetGu
 pub use iceoryx2_bb_container::semantic_string::SemanticString;
 pub use iceoryx2_bb_container::semantic_string::SemanticStringError;
 pub use iceoryx2_bb_derive_macros::PlacementDefault;
+pub use iceoryx2_bb_derive_macros::Validate;
 pub use iceoryx2_bb_derive_macros::ZeroCopySend;
 pub use iceoryx2_bb_elementary::CallbackProgression;
 pub use iceoryx2_bb_elementary::alignment::Alignment;
 pub use iceoryx2_bb_elementary_traits::placement_default::PlacementDefault;
+pub use iceoryx2_bb_elementary_traits::validate::{Validate, ValidationBounds, ValidationError};
 pub use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 pub use iceoryx2_bb_posix::file_descriptor::{FileDescriptor, FileDescriptorBased};
 pub use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
@@ -69,6 +69,18 @@ impl<Header, UserHeader, Payload: ?Sized> RawSample<Header, UserHeader, Payload>
     pub(crate) fn as_payload_ref(&self) -> &Payload {
         unsafe { &*self.payload }
     }
+
+    /// Returns the pointer to the underlying user header without creating a reference.
+    #[inline(always)]
+    pub(crate) fn user_header_ptr(&self) -> *const UserHeader {
+        self.user_header
+    }
+
+    /// Returns the pointer to the underlying data without creating a reference.
+    #[inline(always)]
+    pub(crate) fn payload_ptr(&self) -> *const Payload {
+        self.payload
+    }
 }
 
 impl<Header, UserHeader, Payload> RawSample<Header, UserHeader, Payload> {
@@ -164,6 +176,13 @@ impl<Header, UserHeader, Payload: ?Sized> RawSampleMut<Header, UserHeader, Paylo
         unsafe { &*self.header }
     }
 
+    /// Acquires the underlying header as mutable reference.
+    #[must_use]
+    #[inline(always)]
+    pub(crate) fn as_header_mut(&mut self) -> &mut Header {
+        unsafe { &mut *self.header }
+    }
+
     /// Acquires the underlying payload as reference.
     #[must_use]
     #[inline(always)]
@@ -37,6 +37,7 @@
 
 use core::ops::{Deref, DerefMut};
 use core::{fmt::Debug, marker::PhantomData};
+use iceoryx2_bb_concurrency::cell::Cell;
 
 use iceoryx2_bb_concurrency::atomic::AtomicBool;
 use iceoryx2_bb_concurrency::atomic::Ordering;
@@ -246,6 +247,8 @@ impl<
                 drop(client_shared_state);
                 let active_request = PendingResponse {
                     number_of_server_connections,
+                    number_of_finished_streams: Cell::new(0),
+                    finish_status: Cell::new(None),
                     request: self,
                     _service: PhantomData,
                     _response_payload: PhantomData,
@@ -46,8 +46,8 @@ use core::{
     ops::{Deref, DerefMut},
 };
 
-use iceoryx2_bb_concurrency::atomic::AtomicUsize;
 use iceoryx2_bb_concurrency::atomic::Ordering;
+use iceoryx2_bb_concurrency::atomic::{AtomicU64, AtomicUsize};
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_cal::{
     arc_sync_policy::ArcSyncPolicy, shm_allocator::PointerOffset, zero_copy_connection::ChannelId,
@@ -84,6 +84,7 @@ pub struct ResponseMut<
     >,
     pub(crate) shared_state: Service::ArcThreadSafetyPolicy<SharedServerState<Service>>,
     pub(crate) shared_loan_counter: Arc<AtomicUsize>,
+    pub(crate) shared_sequence_counter: Arc<AtomicU64>,
     pub(crate) offset_to_chunk: PointerOffset,
     pub(crate) sample_size: usize,
     pub(crate) channel_id: ChannelId,
@@ -330,7 +331,7 @@ impl<
     /// # Ok(())
     /// # }
     /// ```
-    pub fn send(self) -> Result<(), SendError> {
+    pub fn send(mut self) -> Result<(), SendError> {
         let msg = "Unable to send response";
 
         let shared_state = self.shared_state.lock();
@@ -338,12 +339,18 @@ impl<
             "{} since the connections could not be updated.", msg);
 
         if self.connection_id != INVALID_CONNECTION_ID {
+            // the sequence number is consumed only when the response was delivered, the
+            // shared state lock serializes all responses of the stream
+            let sequence_number = self.shared_sequence_counter.load(Ordering::Relaxed);
+            self.ptr.as_header_mut().sequence_number = sequence_number;
             shared_state.response_sender.deliver_offset_to_connection(
                 self.offset_to_chunk,
                 self.sample_size,
                 self.channel_id,
                 self.connection_id,
             )?;
+            self.shared_sequence_counter
+                .store(sequence_number + 1, Ordering::Relaxed);
         }
 
         Ok(())
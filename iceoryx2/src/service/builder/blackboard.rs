@@ -74,6 +74,8 @@ pub enum BlackboardOpenError {
     IncompatibleMessagingPattern,
     /// The [`Service`] supports less [`Reader`](crate::port::reader::Reader)s than requested.
     DoesNotSupportRequestedAmountOfReaders,
+    /// The [`Service`] supports less [`Writer`](crate::port::writer::Writer)s than requested.
+    DoesNotSupportRequestedAmountOfWriters,
     /// The process has not enough permissions to open the [`Service`]
     InsufficientPermissions,
     /// The [`Service`]s creation timeout has passed and it is still not initialized. Can be caused
@@ -420,6 +422,7 @@ impl<ServiceType: service::Service> ServiceResource for BlackboardResources<Serv
 #[derive(Debug, Default, Clone, Copy)]
 struct Verify {
     max_readers: bool,
+    max_writers: bool,
     max_nodes: bool,
 }
 
@@ -443,10 +446,11 @@ impl<
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(
             f,
-            "Builder<{}, {}> {{ verify_max_readers: {}, verify_max_nodes: {}, internals: {:?} }}",
+            "Builder<{}, {}> {{ verify_max_readers: {}, verify_max_writers: {}, verify_max_nodes: {}, internals: {:?} }}",
             core::any::type_name::<KeyType>(),
             core::any::type_name::<ServiceType>(),
             self.verify.max_readers,
+            self.verify.max_writers,
             self.verify.max_nodes,
             self.internals
         )
@@ -485,7 +489,10 @@ impl<
         let blackboard_service_config = *self.config_details();
         match self.base.is_service_available(error_msg) {
             Ok(Some((config, storage))) => {
-                if !(blackboard_service_config.type_details == config.blackboard().type_details) {
+                if !blackboard_service_config
+                    .type_details
+                    .is_identical_to(&config.blackboard().type_details)
+                {
                     fail!(from self, with ServiceState::IncompatiblePayload,
                         "{} since the service offers the type \"{:?}\" which is not compatible to the requested type \"{:?}\".",
                         error_msg, &config.blackboard().type_details , blackboard_service_config.type_details);
@@ -536,6 +543,14 @@ impl<
         self.verify.max_readers = true;
     }
 
+    /// If the [`Service`] is created it defines how many [`Writer`](crate::port::writer::Writer)s
+    /// shall be supported at most. If an existing [`Service`] is opened it defines how many
+    /// [`Writer`](crate::port::writer::Writer)s must be at least supported.
+    fn max_writers(&mut self, value: usize) {
+        self.config_details_mut().max_writers = value;
+        self.verify.max_writers = true;
+    }
+
     /// If the [`Service`] is created it defines how many [`Node`](crate::node::Node)s shall
     /// be able to open it in parallel. If an existing [`Service`] is opened it defines how many
     /// [`Node`](crate::node::Node)s must be at least supported.
@@ -575,6 +590,18 @@ impl<
         self
     }
 
+    /// Defines how many [`Writer`](crate::port::writer::Writer)s shall be supported at most.
+    /// When more than one [`Writer`](crate::port::writer::Writer) is supported, every entry is
+    /// exclusively owned by the [`Writer`](crate::port::writer::Writer) that acquired it first
+    /// with [`Writer::entry()`](crate::port::writer::Writer::entry()). The ownership is released
+    /// when the corresponding entry handle is dropped or when the
+    /// [`Node`](crate::node::Node) of the owning [`Writer`](crate::port::writer::Writer) died
+    /// and was cleaned up.
+    pub fn max_writers(mut self, value: usize) -> Self {
+        self.builder.max_writers(value);
+        self
+    }
+
     /// Defines how many [`Node`](crate::node::Node)s shall be able to open it in parallel.
     pub fn max_nodes(mut self, value: usize) -> Self {
         self.builder.max_nodes(value);
@@ -632,6 +659,11 @@ impl<
             settings.max_readers = 1;
         }
 
+        if settings.max_writers == 0 {
+            warn!(from origin, "Setting the maximum amount of writers to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.max_writers = 1;
+        }
+
         if settings.max_nodes == 0 {
             warn!(from origin,
                 "Setting the maximum amount of nodes to 0 is not supported. Adjust it to 1, the smallest supported value.");
@@ -769,11 +801,13 @@ impl<
                 "{} without entries. At least one key-value pair is required.", msg);
         }
 
+        let number_of_entries = self.builder.internals.len();
         let generate_dynamic_config = |service_config: &StaticConfig| {
             let blackboard_config = service_config.blackboard();
             let dynamic_config_setting = DynamicConfigSettings {
                 number_of_writers: blackboard_config.max_writers,
                 number_of_readers: blackboard_config.max_readers,
+                number_of_entries,
             };
 
             DynamicConfigCreationArgs {
@@ -907,6 +941,12 @@ impl<
         self
     }
 
+    /// Defines how many [`Writer`](crate::port::writer::Writer)s must be at least supported.
+    pub fn max_writers(mut self, value: usize) -> Self {
+        self.builder.max_writers(value);
+        self
+    }
+
     /// Defines how many [`Node`](crate::node::Node)s must be at least supported.
     pub fn max_nodes(mut self, value: usize) -> Self {
         self.builder.max_nodes(value);
@@ -945,6 +985,14 @@ impl<
                                 msg, existing_settings.max_readers, required_settings.max_readers);
         }
 
+        if self.builder.verify.max_writers
+            && existing_settings.max_writers < required_settings.max_writers
+        {
+            fail!(from self, with BlackboardOpenError::DoesNotSupportRequestedAmountOfWriters,
+                                "{} since the service supports only {} writers but a support of {} writers was requested.",
+                                msg, existing_settings.max_writers, required_settings.max_writers);
+        }
+
         if self.builder.verify.max_nodes
             && existing_settings.max_nodes < required_settings.max_nodes
         {
@@ -1052,4 +1100,13 @@ impl<ServiceType: service::Service> Opener<CustomKeyMarker, ServiceType> {
         self.builder.override_key_type = Some(*value);
         self
     }
+
+    #[doc(hidden)]
+    pub unsafe fn __internal_set_key_eq_cmp_func(
+        mut self,
+        key_eq_func: Box<dyn Fn(*const u8, *const u8) -> bool + Send + Sync>,
+    ) -> Self {
+        self.builder.key_eq_func = Arc::new(key_eq_func);
+        self
+    }
 }
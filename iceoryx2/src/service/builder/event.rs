@@ -74,6 +74,8 @@ pub enum EventOpenError {
     DoesNotSupportRequestedMaxEventId,
     /// The [`Service`] supports less [`Node`](crate::node::Node)s than requested.
     DoesNotSupportRequestedAmountOfNodes,
+    /// The [`Service`] supports a smaller notification data size than requested.
+    DoesNotSupportRequestedNotificationDataSize,
     /// The maximum number of [`Node`](crate::node::Node)s have already opened the [`Service`].
     ExceedsMaxNumberOfNodes,
     /// The [`Service`] is marked for destruction and currently cleaning up since no one is using it anymore.
@@ -152,6 +154,7 @@ impl From<EventOpenError> for ServiceOpenError {
             | EventOpenError::DoesNotSupportRequestedAmountOfNodes
             | EventOpenError::DoesNotSupportRequestedAmountOfNotifiers
             | EventOpenError::DoesNotSupportRequestedMaxEventId
+            | EventOpenError::DoesNotSupportRequestedNotificationDataSize
             | EventOpenError::IncompatibleAttributes
             | EventOpenError::IncompatibleDeadline
             | EventOpenError::IncompatibleNotifierCreatedEvent
@@ -289,6 +292,7 @@ struct Verify {
     notifier_created_event: bool,
     notifier_dropped_event: bool,
     notifier_dead_event: bool,
+    notification_data_size: bool,
 }
 
 /// Builder to create new [`MessagingPattern::Event`] based [`Service`]s
@@ -428,6 +432,16 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
         self
     }
 
+    /// If the [`Service`] is created it defines the maximum size in bytes of the data that can
+    /// be sent with every notification via [`Notifier::notify_with_data()`]. For every
+    /// [`EventId`] the latest data is stored in the [`Service`]. If an existing [`Service`] is
+    /// opened it defines the notification data size that must be at least supported.
+    pub fn notification_data_size(mut self, value: usize) -> Self {
+        self.config_details().notification_data_size = value;
+        self.verify.notification_data_size = true;
+        self
+    }
+
     /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
     /// created.
     pub fn open_or_create(self) -> Result<event::PortFactory<ServiceType>, EventOpenOrCreateError> {
@@ -523,6 +537,8 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
             let dynamic_config_setting = DynamicConfigSettings {
                 number_of_listeners: event_config.max_listeners,
                 number_of_notifiers: event_config.max_notifiers,
+                number_of_event_ids: event_config.event_id_max_value.saturating_add(1),
+                notification_data_size: event_config.notification_data_size,
             };
 
             DynamicConfigCreationArgs {
@@ -621,6 +637,14 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
                 msg, existing_settings.max_nodes, required_settings.max_nodes);
         }
 
+        if self.verify.notification_data_size
+            && existing_settings.notification_data_size < required_settings.notification_data_size
+        {
+            fail!(from self, with EventOpenError::DoesNotSupportRequestedNotificationDataSize,
+                "{} since the event supports notification data with a size of at most {} bytes but {} bytes are required.",
+                msg, existing_settings.notification_data_size, required_settings.notification_data_size);
+        }
+
         if self.verify.notifier_created_event
             && existing_settings.notifier_created_event != required_settings.notifier_created_event
         {
@@ -34,7 +34,7 @@ use super::{CustomHeaderMarker, CustomPayloadMarker, ServiceState};
 
 use self::{
     attribute::{AttributeSpecifier, AttributeVerifier},
-    message_type_details::{MessageTypeDetails, TypeDetail, TypeVariant},
+    message_type_details::{MessageTypeDetails, TypeCompatibilityPolicy, TypeDetail, TypeVariant},
 };
 
 /// Errors that can occur when an existing [`MessagingPattern::PublishSubscribe`] [`Service`] shall be opened.
@@ -356,6 +356,7 @@ pub struct Builder<
     override_alignment: Option<usize>,
     override_payload_type: Option<TypeDetail>,
     override_user_header_type: Option<TypeDetail>,
+    type_compatibility_policy: TypeCompatibilityPolicy,
     verify: Verify,
     _data: PhantomData<Payload>,
     _user_header: PhantomData<UserHeader>,
@@ -373,6 +374,7 @@ impl<
             override_alignment: self.override_alignment,
             override_payload_type: self.override_payload_type,
             override_user_header_type: self.override_user_header_type,
+            type_compatibility_policy: self.type_compatibility_policy,
             verify: self.verify,
             _data: PhantomData,
             _user_header: PhantomData,
@@ -393,6 +395,7 @@ impl<
             override_alignment: None,
             override_payload_type: None,
             override_user_header_type: None,
+            type_compatibility_policy: TypeCompatibilityPolicy::default(),
             _data: PhantomData,
             _user_header: PhantomData,
         };
@@ -430,13 +433,21 @@ impl<
         let pubsub_service_config = self.config_details();
         match self.base.is_service_available(error_msg) {
             Ok(Some((config, storage))) => {
-                if !pubsub_service_config
-                    .message_type_details
-                    .is_compatible_to(&config.publish_subscribe().message_type_details)
-                {
+                let comparison = pubsub_service_config.message_type_details.compare(
+                    &config.publish_subscribe().message_type_details,
+                    self.type_compatibility_policy,
+                );
+
+                if !comparison.is_compatible() {
                     fail!(from self, with ServiceState::IncompatiblePayload,
-                        "{} since the service offers the type \"{:?}\" which is not compatible to the requested type \"{:?}\".",
-                        error_msg, &config.publish_subscribe().message_type_details , pubsub_service_config.message_type_details);
+                        "{} since the service offers types that are not compatible to the requested types with the {:?} policy ({}).",
+                        error_msg, self.type_compatibility_policy, comparison.incompatibilities());
+                }
+
+                if !comparison.tolerated_differences().is_empty() {
+                    warn!(from self,
+                        "The service offers types that differ from the requested types but are tolerated by the {:?} policy ({}).",
+                        self.type_compatibility_policy, comparison.tolerated_differences());
                 }
 
                 Ok(Some((config, storage)))
@@ -460,6 +471,13 @@ impl<
         self
     }
 
+    /// If an existing [`Service`] is opened, it defines which properties of the payload and user
+    /// header types must be equal to the requested types, see [`TypeCompatibilityPolicy`].
+    pub fn type_compatibility_policy(mut self, value: TypeCompatibilityPolicy) -> Self {
+        self.type_compatibility_policy = value;
+        self
+    }
+
     /// If the [`Service`] is created, defines the overflow behavior of the service. If an existing
     /// [`Service`] is opened it requires the service to have the defined overflow behavior.
     pub fn enable_safe_overflow(mut self, value: bool) -> Self {
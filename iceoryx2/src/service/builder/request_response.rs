@@ -30,7 +30,7 @@ use crate::service::static_config::messaging_pattern::MessagingPattern;
 use crate::service::{NoResource, header, static_config};
 use crate::service::{Service, builder, dynamic_config};
 
-use super::message_type_details::{MessageTypeDetails, TypeVariant};
+use super::message_type_details::{MessageTypeDetails, TypeCompatibilityPolicy, TypeVariant};
 use super::{CustomHeaderMarker, CustomPayloadMarker, ServiceState};
 
 /// Errors that can occur when an existing [`MessagingPattern::RequestResponse`] [`Service`] shall
@@ -367,6 +367,7 @@ pub struct Builder<
     override_response_payload_type: Option<TypeDetail>,
     override_request_header_type: Option<TypeDetail>,
     override_response_header_type: Option<TypeDetail>,
+    type_compatibility_policy: TypeCompatibilityPolicy,
     verify: Verify,
 
     _request_payload: PhantomData<RequestPayload>,
@@ -392,6 +393,7 @@ impl<
             override_response_payload_type: self.override_response_payload_type,
             override_request_header_type: self.override_request_header_type,
             override_response_header_type: self.override_response_header_type,
+            type_compatibility_policy: self.type_compatibility_policy,
             verify: self.verify,
             _request_payload: PhantomData,
             _request_header: PhantomData,
@@ -418,6 +420,7 @@ impl<
             override_request_payload_type: None,
             override_response_header_type: None,
             override_response_payload_type: None,
+            type_compatibility_policy: TypeCompatibilityPolicy::default(),
             verify: Verify::default(),
             _request_payload: PhantomData,
             _request_header: PhantomData,
@@ -486,6 +489,13 @@ impl<
         self
     }
 
+    /// If an existing [`Service`] is opened, it defines which properties of the request and
+    /// response types must be equal to the requested types, see [`TypeCompatibilityPolicy`].
+    pub fn type_compatibility_policy(mut self, value: TypeCompatibilityPolicy) -> Self {
+        self.type_compatibility_policy = value;
+        self
+    }
+
     /// If the [`Service`] is created, defines the overflow behavior of the service for requests.
     /// If an existing [`Service`] is opened it requires the service to have the defined overflow
     /// behavior.
@@ -746,24 +756,37 @@ impl<
 
         match self.base.is_service_available(error_msg) {
             Ok(Some((config, storage))) => {
-                if !reqres_service_config
-                    .request_message_type_details
-                    .is_compatible_to(&config.request_response().request_message_type_details)
-                {
-                    fail!(from self, with ServiceState::IncompatiblePayload,
-                        "{} since the services uses the request type \"{:?}\" which is not compatible to the requested type \"{:?}\".",
-                        error_msg, &config.request_response().request_message_type_details,
-                        reqres_service_config.request_message_type_details);
+                let comparisons = [
+                    (
+                        "request",
+                        reqres_service_config.request_message_type_details.compare(
+                            &config.request_response().request_message_type_details,
+                            self.type_compatibility_policy,
+                        ),
+                    ),
+                    (
+                        "response",
+                        reqres_service_config.response_message_type_details.compare(
+                            &config.request_response().response_message_type_details,
+                            self.type_compatibility_policy,
+                        ),
+                    ),
+                ];
+
+                for (kind, comparison) in &comparisons {
+                    if !comparison.is_compatible() {
+                        fail!(from self, with ServiceState::IncompatiblePayload,
+                            "{} since the service offers {} types that are not compatible to the requested types with the {:?} policy ({}).",
+                            error_msg, kind, self.type_compatibility_policy, comparison.incompatibilities());
+                    }
                 }
 
-                if !reqres_service_config
-                    .response_message_type_details
-                    .is_compatible_to(&config.request_response().response_message_type_details)
-                {
-                    fail!(from self, with ServiceState::IncompatiblePayload,
-                        "{} since the services uses the response type \"{:?}\" which is not compatible to the requested type \"{:?}\".",
-                        error_msg, &config.request_response().response_message_type_details,
-                        reqres_service_config.response_message_type_details);
+                for (kind, comparison) in &comparisons {
+                    if !comparison.tolerated_differences().is_empty() {
+                        warn!(from self,
+                            "The service offers {} types that differ from the requested types but are tolerated by the {:?} policy ({}).",
+                            kind, self.type_compatibility_policy, comparison.tolerated_differences());
+                    }
                 }
 
                 Ok(Some((config, storage)))
@@ -29,7 +29,9 @@
 //! ```
 
 use crate::identifiers::{UniqueNodeId, UniquePortId, UniqueReaderId, UniqueWriterId};
+use iceoryx2_bb_concurrency::atomic::{AtomicU64, Ordering};
 use iceoryx2_bb_container::queue::RelocatableContainer;
+use iceoryx2_bb_container::vector::relocatable_vec::*;
 use iceoryx2_bb_derive_macros::ZeroCopySend;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_lock_free::mpmc::{container::*, unique_index_set_enums::ReleaseMode};
@@ -43,8 +45,11 @@ use super::PortCleanupAction;
 pub(crate) struct DynamicConfigSettings {
     pub number_of_readers: usize,
     pub number_of_writers: usize,
+    pub number_of_entries: usize,
 }
 
+const NO_OWNER: u64 = 0;
+
 /// Contains the communication settings of the connected
 /// [`Reader`](crate::port::reader::Reader).
 #[repr(C)]
@@ -69,6 +74,13 @@ pub struct WriterDetails {
     pub node_id: UniqueNodeId,
 }
 
+/// Describes who owns a blackboard entry when its ownership could not be acquired.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum EntryOwner {
+    SameWriter,
+    OtherWriter,
+}
+
 /// The dynamic configuration of an
 /// [`crate::service::messaging_pattern::MessagingPattern::Blackboard`]
 /// based service. Contains dynamic parameters like the connected endpoints etc..
@@ -77,6 +89,10 @@ pub struct WriterDetails {
 pub struct DynamicConfig {
     pub(crate) readers: Container<ReaderDetails>,
     pub(crate) writers: Container<WriterDetails>,
+    // Contains for every blackboard entry the owner token of the
+    // [`Writer`](crate::port::writer::Writer) that currently owns it or [`NO_OWNER`].
+    // The owner token is the writers index in the `writers` container + 1.
+    pub(crate) entry_owners: RelocatableVec<AtomicU64>,
 }
 
 impl DynamicConfig {
@@ -84,6 +100,7 @@ impl DynamicConfig {
         Self {
             readers: unsafe { Container::new_uninit(config.number_of_readers) },
             writers: unsafe { Container::new_uninit(config.number_of_writers) },
+            entry_owners: unsafe { RelocatableVec::new_uninit(config.number_of_entries) },
         }
     }
 
@@ -95,12 +112,21 @@ impl DynamicConfig {
             fatal_panic!(from self,
             when self.writers.init(allocator),
             "This should never happen! Unable to initialize writer port id container.");
+            fatal_panic!(from self,
+            when self.entry_owners.init(allocator),
+            "This should never happen! Unable to initialize entry owner container.");
+        }
+
+        while !self.entry_owners.is_full() {
+            // cannot fail since the vector is not full
+            let _ = self.entry_owners.push(AtomicU64::new(NO_OWNER));
         }
     }
 
     pub(crate) fn memory_size(config: &DynamicConfigSettings) -> usize {
         Container::<ReaderDetails>::memory_size(config.number_of_readers)
             + Container::<WriterDetails>::memory_size(config.number_of_writers)
+            + RelocatableVec::<AtomicU64>::const_memory_size(config.number_of_entries)
     }
 
     /// Returns how many [`Reader`](crate::port::reader::Reader) ports are currently connected.
@@ -156,9 +182,17 @@ impl DynamicConfig {
                 node_id.owner_id(),
                 |registered_writer| {
                     // additional comparision, since the node_id.owner_id() might be not enough
-                    registered_writer.node_id == *node_id
+                    if registered_writer.node_id == *node_id
                         && port_cleanup_callback(UniquePortId::Writer(registered_writer.writer_id))
                             == PortCleanupAction::RemovePort
+                    {
+                        // must happen before the writer is removed from the container, otherwise
+                        // a new writer could acquire the same index and would lose its entries
+                        self.release_all_entries_of_dead_writer(&registered_writer.writer_id);
+                        true
+                    } else {
+                        false
+                    }
                 },
                 ReleaseMode::Default,
             );
@@ -184,4 +218,79 @@ impl DynamicConfig {
             error!(from self, "Unable to deregister writer from service. This could indicate a corrupted system! [{e:?}]");
         }
     }
+
+    /// Returns how many entries are currently owned by a
+    /// [`Writer`](crate::port::writer::Writer).
+    pub fn number_of_owned_entries(&self) -> usize {
+        self.entry_owners
+            .iter()
+            .filter(|owner| owner.load(Ordering::Relaxed) != NO_OWNER)
+            .count()
+    }
+
+    fn owner_token(writer_handle: ContainerHandle) -> u64 {
+        writer_handle.index() as u64 + 1
+    }
+
+    /// Tries to acquire the exclusive ownership of the entry with the provided index. On failure
+    /// it returns the owner token of the current owner.
+    pub(crate) fn acquire_entry_ownership(
+        &self,
+        entry_index: usize,
+        writer_handle: ContainerHandle,
+    ) -> Result<(), EntryOwner> {
+        let owner_token = Self::owner_token(writer_handle);
+        match self.entry_owners[entry_index].compare_exchange(
+            NO_OWNER,
+            owner_token,
+            Ordering::AcqRel,
+            Ordering::Acquire,
+        ) {
+            Ok(_) => Ok(()),
+            Err(current_owner) if current_owner == owner_token => Err(EntryOwner::SameWriter),
+            Err(_) => Err(EntryOwner::OtherWriter),
+        }
+    }
+
+    pub(crate) fn release_entry_ownership(
+        &self,
+        entry_index: usize,
+        writer_handle: ContainerHandle,
+    ) {
+        if self.entry_owners[entry_index]
+            .compare_exchange(
+                Self::owner_token(writer_handle),
+                NO_OWNER,
+                Ordering::AcqRel,
+                Ordering::Relaxed,
+            )
+            .is_err()
+        {
+            error!(from self, "Unable to release the ownership of the blackboard entry {entry_index} since it is not owned by the writer. This could indicate a corrupted system!");
+        }
+    }
+
+    fn release_all_entries_of_dead_writer(&self, writer_id: &UniqueWriterId) {
+        let mut writer_index = None;
+        unsafe { self.writers.get_state() }.for_each(|index, details| {
+            if details.writer_id == *writer_id {
+                writer_index = Some(index);
+                CallbackProgression::Stop
+            } else {
+                CallbackProgression::Continue
+            }
+        });
+
+        if let Some(index) = writer_index {
+            let owner_token = index as u64 + 1;
+            for owner in self.entry_owners.iter() {
+                let _ = owner.compare_exchange(
+                    owner_token,
+                    NO_OWNER,
+                    Ordering::AcqRel,
+                    Ordering::Relaxed,
+                );
+            }
+        }
+    }
 }
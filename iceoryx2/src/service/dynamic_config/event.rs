@@ -27,7 +27,10 @@
 //! # }
 //! ```
 
-use iceoryx2_bb_concurrency::atomic::AtomicU64;
+use crate::port::event_id::EventIdFilter;
+use iceoryx2_bb_concurrency::atomic::{AtomicU64, Ordering, fence};
+use iceoryx2_bb_container::relocatable_option::RelocatableOption;
+use iceoryx2_bb_container::vector::relocatable_vec::*;
 use iceoryx2_bb_derive_macros::ZeroCopySend;
 use iceoryx2_bb_elementary_traits::relocatable_container::RelocatableContainer;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
@@ -44,6 +47,42 @@ use super::PortCleanupAction;
 pub(crate) struct DynamicConfigSettings {
     pub number_of_listeners: usize,
     pub number_of_notifiers: usize,
+    pub number_of_event_ids: usize,
+    pub notification_data_size: usize,
+}
+
+impl DynamicConfigSettings {
+    fn number_of_notification_data_words(&self) -> usize {
+        if self.notification_data_size == 0 {
+            0
+        } else {
+            self.number_of_event_ids
+                .saturating_mul(NotificationDataSlot::len(self.notification_data_size))
+        }
+    }
+}
+
+// Upper limit of attempts to acquire the sequence lock of a notification data slot. Protects
+// the process from spinning forever when a notifier died while updating the slot.
+const MAX_NOTIFICATION_DATA_ACCESS_ATTEMPTS: usize = 1 << 16;
+
+// Layout of the notification data of a single EventId inside the `notification_data` vector.
+// A slot consists of a sequence counter, that is odd while a notifier writes into the slot, the
+// length of the stored data and the data itself, stored in 8 byte words.
+struct NotificationDataSlot;
+
+impl NotificationDataSlot {
+    const SEQUENCE: usize = 0;
+    const LENGTH: usize = 1;
+    const DATA: usize = 2;
+
+    const fn number_of_words(data_size: usize) -> usize {
+        data_size.div_ceil(core::mem::size_of::<u64>())
+    }
+
+    const fn len(data_size: usize) -> usize {
+        Self::DATA + Self::number_of_words(data_size)
+    }
 }
 
 /// The dynamic configuration of an [`crate::service::messaging_pattern::MessagingPattern::Event`]
@@ -54,6 +93,58 @@ pub struct DynamicConfig {
     pub(crate) listeners: Container<ListenerDetails>,
     pub(crate) notifiers: Container<NotifierDetails>,
     pub(crate) elapsed_time_since_last_notification: AtomicU64,
+    notification_data_size: usize,
+    // Contains for every EventId a NotificationDataSlot with the latest data that was sent with
+    // it. Is empty when the service does not support notification data.
+    notification_data: RelocatableVec<AtomicU64>,
+}
+
+/// The maximum number of [`UniqueNotifierId`]s a [`Listener`](crate::port::listener::Listener)
+/// can restrict its notifications to.
+pub const MAX_NUMBER_OF_ALLOWED_NOTIFIERS: usize = 8;
+
+/// Contains the [`UniqueNotifierId`]s of the [`Notifier`](crate::port::notifier::Notifier)s
+/// from which a [`Listener`](crate::port::listener::Listener) accepts notifications. When it
+/// is empty, notifications from all [`Notifier`](crate::port::notifier::Notifier)s are
+/// accepted.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, ZeroCopySend)]
+pub struct AllowedNotifiers {
+    ids: [RelocatableOption<UniqueNotifierId>; MAX_NUMBER_OF_ALLOWED_NOTIFIERS],
+}
+
+impl AllowedNotifiers {
+    /// Creates the [`AllowedNotifiers`] from the provided ids. Returns [`None`] when more than
+    /// [`MAX_NUMBER_OF_ALLOWED_NOTIFIERS`] ids are provided.
+    pub(crate) fn new(notifier_ids: &[UniqueNotifierId]) -> Option<Self> {
+        if notifier_ids.len() > MAX_NUMBER_OF_ALLOWED_NOTIFIERS {
+            return None;
+        }
+
+        let mut ids = [RelocatableOption::None; MAX_NUMBER_OF_ALLOWED_NOTIFIERS];
+        for (id, notifier_id) in ids.iter_mut().zip(notifier_ids.iter()) {
+            *id = RelocatableOption::Some(*notifier_id);
+        }
+
+        Some(Self { ids })
+    }
+
+    /// Returns true when notifications from all [`Notifier`](crate::port::notifier::Notifier)s
+    /// are accepted.
+    pub fn is_empty(&self) -> bool {
+        self.iter().next().is_none()
+    }
+
+    /// Returns an iterator over all allowed [`UniqueNotifierId`]s.
+    pub fn iter(&self) -> impl Iterator<Item = &UniqueNotifierId> {
+        self.ids.iter().filter_map(|id| id.as_option_ref())
+    }
+
+    /// Returns true when notifications from the [`Notifier`](crate::port::notifier::Notifier)
+    /// with the provided [`UniqueNotifierId`] are accepted, otherwise false.
+    pub fn accepts(&self, notifier_id: &UniqueNotifierId) -> bool {
+        self.is_empty() || self.iter().any(|id| id == notifier_id)
+    }
 }
 
 /// Contains the communication settings of the connected
@@ -66,6 +157,12 @@ pub struct ListenerDetails {
     /// The [`UniqueNodeId`] of the [`Node`](crate::node::Node) under which the
     /// [`Listener`](crate::port::listener::Listener) was created.
     pub node_id: UniqueNodeId,
+    /// The [`EventIdFilter`] that defines which [`EventId`](crate::prelude::EventId)s the
+    /// [`Listener`](crate::port::listener::Listener) accepts.
+    pub event_id_filter: EventIdFilter,
+    /// The [`Notifier`](crate::port::notifier::Notifier)s from which the
+    /// [`Listener`](crate::port::listener::Listener) accepts notifications.
+    pub allowed_notifiers: AllowedNotifiers,
 }
 
 /// Contains the communication settings of the connected
@@ -86,6 +183,10 @@ impl DynamicConfig {
             listeners: unsafe { Container::new_uninit(config.number_of_listeners) },
             notifiers: unsafe { Container::new_uninit(config.number_of_notifiers) },
             elapsed_time_since_last_notification: AtomicU64::new(0),
+            notification_data_size: config.notification_data_size,
+            notification_data: unsafe {
+                RelocatableVec::new_uninit(config.number_of_notification_data_words())
+            },
         }
     }
 
@@ -97,12 +198,27 @@ impl DynamicConfig {
             fatal_panic!(from "event::DynamicConfig::init",
             when self.notifiers.init(allocator),
             "This should never happen! Unable to initialize notifier port id container.");
+
+            // the allocator does not support zero sized allocations
+            if self.notification_data.capacity() != 0 {
+                fatal_panic!(from "event::DynamicConfig::init",
+                when self.notification_data.init(allocator),
+                "This should never happen! Unable to initialize notification data container.");
+            }
+        }
+
+        while !self.notification_data.is_full() {
+            // cannot fail since the vector is not full
+            let _ = self.notification_data.push(AtomicU64::new(0));
         }
     }
 
     pub(crate) fn memory_size(config: &DynamicConfigSettings) -> usize {
         Container::<ListenerDetails>::memory_size(config.number_of_listeners)
             + Container::<NotifierDetails>::memory_size(config.number_of_notifiers)
+            + RelocatableVec::<AtomicU64>::const_memory_size(
+                config.number_of_notification_data_words(),
+            )
     }
 
     /// Returns how many [`Listener`](crate::port::listener::Listener) ports are currently connected.
@@ -148,6 +264,7 @@ impl DynamicConfig {
         node_id: &UniqueNodeId,
         mut port_cleanup_callback: PortCleanup,
     ) {
+        let mut has_removed_notifier = false;
         unsafe {
             self.listeners.recover(
                 node_id.owner_id(),
@@ -165,14 +282,65 @@ impl DynamicConfig {
                 node_id.owner_id(),
                 |registered_notifier| {
                     // additional comparision, since the node_id.owner_id() might be not enough
-                    registered_notifier.node_id == *node_id
+                    let remove_port = registered_notifier.node_id == *node_id
                         && port_cleanup_callback(UniquePortId::Notifier(
                             registered_notifier.notifier_id,
-                        )) == PortCleanupAction::RemovePort
+                        )) == PortCleanupAction::RemovePort;
+                    has_removed_notifier |= remove_port;
+                    remove_port
                 },
                 ReleaseMode::Default,
             );
         }
+
+        if has_removed_notifier {
+            self.release_abandoned_notification_data();
+        }
+    }
+
+    // A notifier that died while writing notification data leaves the sequence of the slot odd
+    // and blocks every other notifier. A living notifier finishes the write in far less than
+    // the maximum number of access attempts, therefore a slot whose sequence does not change
+    // during this time is considered abandoned. Its data may be incomplete and is discarded.
+    fn release_abandoned_notification_data(&self) {
+        if self.notification_data_size == 0 {
+            return;
+        }
+
+        let slot_len = NotificationDataSlot::len(self.notification_data_size);
+        for slot in self.notification_data.as_slice().chunks_exact(slot_len) {
+            let sequence = &slot[NotificationDataSlot::SEQUENCE];
+            let blocked_sequence = sequence.load(Ordering::Relaxed);
+            if blocked_sequence % 2 == 0 {
+                continue;
+            }
+
+            let mut is_abandoned = true;
+            for _ in 0..MAX_NOTIFICATION_DATA_ACCESS_ATTEMPTS {
+                if sequence.load(Ordering::Relaxed) != blocked_sequence {
+                    is_abandoned = false;
+                    break;
+                }
+                core::hint::spin_loop();
+            }
+
+            // the slot stays blocked with a new odd sequence while the data is discarded so
+            // that no reader accepts the incomplete data
+            if is_abandoned
+                && sequence
+                    .compare_exchange(
+                        blocked_sequence,
+                        blocked_sequence + 2,
+                        Ordering::Acquire,
+                        Ordering::Relaxed,
+                    )
+                    .is_ok()
+            {
+                fence(Ordering::Release);
+                slot[NotificationDataSlot::LENGTH].store(0, Ordering::Relaxed);
+                sequence.store(blocked_sequence + 3, Ordering::Release);
+            }
+        }
     }
 
     pub(crate) fn add_listener_id(&self, details: ListenerDetails) -> Option<ContainerHandle> {
@@ -194,4 +362,112 @@ impl DynamicConfig {
             error!(from self, "Unable to deregister notifier from service. This could indicate a corrupted system! [{e:?}]");
         }
     }
+
+    fn notification_data_slot(&self, event_id: usize) -> Option<&[AtomicU64]> {
+        let slot_len = NotificationDataSlot::len(self.notification_data_size);
+        let start = event_id.checked_mul(slot_len)?;
+        if self.notification_data_size == 0 || start + slot_len > self.notification_data.len() {
+            return None;
+        }
+
+        Some(&self.notification_data.as_slice()[start..start + slot_len])
+    }
+
+    // Acquires the sequence lock of the slot and returns the sequence before it was acquired.
+    fn acquire_notification_data_slot(slot: &[AtomicU64]) -> Option<u64> {
+        let sequence = &slot[NotificationDataSlot::SEQUENCE];
+        for _ in 0..MAX_NOTIFICATION_DATA_ACCESS_ATTEMPTS {
+            let current = sequence.load(Ordering::Relaxed);
+            if current % 2 == 0
+                && sequence
+                    .compare_exchange_weak(
+                        current,
+                        current + 1,
+                        Ordering::Acquire,
+                        Ordering::Relaxed,
+                    )
+                    .is_ok()
+            {
+                fence(Ordering::Release);
+                return Some(current);
+            }
+            core::hint::spin_loop();
+        }
+
+        None
+    }
+
+    /// Acquires the notification data of the given EventId without releasing it, like a
+    /// notifier that died while writing it.
+    pub(crate) fn abandon_notification_data_write(&self, event_id: usize) -> bool {
+        self.notification_data_slot(event_id)
+            .and_then(Self::acquire_notification_data_slot)
+            .is_some()
+    }
+
+    /// Stores the provided data as latest notification data of the given EventId. Returns false
+    /// when the data could not be stored since another notifier blocks the slot.
+    pub(crate) fn write_notification_data(&self, event_id: usize, data: &[u8]) -> bool {
+        let slot = match self.notification_data_slot(event_id) {
+            Some(slot) => slot,
+            None => return false,
+        };
+        debug_assert!(data.len() <= self.notification_data_size);
+
+        let sequence = &slot[NotificationDataSlot::SEQUENCE];
+        let acquired_sequence = match Self::acquire_notification_data_slot(slot) {
+            Some(v) => v,
+            None => return false,
+        };
+
+        slot[NotificationDataSlot::LENGTH].store(data.len() as u64, Ordering::Relaxed);
+        for (n, chunk) in data.chunks(core::mem::size_of::<u64>()).enumerate() {
+            let mut word = [0u8; core::mem::size_of::<u64>()];
+            word[..chunk.len()].copy_from_slice(chunk);
+            slot[NotificationDataSlot::DATA + n].store(u64::from_ne_bytes(word), Ordering::Relaxed);
+        }
+
+        sequence.store(acquired_sequence + 2, Ordering::Release);
+        true
+    }
+
+    /// Copies the latest notification data of the given EventId into the provided buffer and
+    /// returns its length. Returns [`None`] when no consistent view of the data could be
+    /// acquired.
+    pub(crate) fn read_notification_data(
+        &self,
+        event_id: usize,
+        buffer: &mut [u8],
+    ) -> Option<usize> {
+        let slot = self.notification_data_slot(event_id)?;
+        let sequence = &slot[NotificationDataSlot::SEQUENCE];
+
+        for _ in 0..MAX_NOTIFICATION_DATA_ACCESS_ATTEMPTS {
+            let sequence_before = sequence.load(Ordering::Acquire);
+            if sequence_before % 2 == 1 {
+                core::hint::spin_loop();
+                continue;
+            }
+
+            let len = (slot[NotificationDataSlot::LENGTH].load(Ordering::Relaxed) as usize)
+                .min(self.notification_data_size)
+                .min(buffer.len());
+            for (n, chunk) in buffer[..len]
+                .chunks_mut(core::mem::size_of::<u64>())
+                .enumerate()
+            {
+                let word = slot[NotificationDataSlot::DATA + n]
+                    .load(Ordering::Relaxed)
+                    .to_ne_bytes();
+                chunk.copy_from_slice(&word[..chunk.len()]);
+            }
+
+            fence(Ordering::Acquire);
+            if sequence.load(Ordering::Relaxed) == sequence_before {
+                return Some(len);
+            }
+        }
+
+        None
+    }
 }
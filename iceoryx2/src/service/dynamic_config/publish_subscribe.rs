@@ -23,6 +23,11 @@
 //!
 //! println!("number of active publishers:      {:?}", pubsub.dynamic_config().number_of_publishers());
 //! println!("number of active subscribers:     {:?}", pubsub.dynamic_config().number_of_subscribers());
+//!
+//! pubsub.dynamic_config().list_publisher_statistics(|details, statistics| {
+//!     println!("publisher {:?} sent {} samples", details.publisher_id, statistics.number_of_sent_samples());
+//!     CallbackProgression::Continue
+//! });
 //! # Ok(())
 //! # }
 //! ```
@@ -30,6 +35,8 @@ use crate::{
     identifiers::{UniqueNodeId, UniquePortId, UniquePublisherId, UniqueSubscriberId},
     port::details::data_segment::DataSegmentType,
 };
+use iceoryx2_bb_concurrency::atomic::{AtomicU64, Ordering};
+use iceoryx2_bb_container::vector::relocatable_vec::*;
 use iceoryx2_bb_derive_macros::ZeroCopySend;
 use iceoryx2_bb_elementary_traits::relocatable_container::RelocatableContainer;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
@@ -84,6 +91,142 @@ pub struct SubscriberDetails {
     pub buffer_size: usize,
 }
 
+// The statistics are updated on every send and receive. Counters of different ports and
+// counters that are written by different processes are placed on separate cache lines so
+// that the updates do not cause false sharing.
+#[repr(C)]
+#[repr(align(64))]
+#[derive(Debug, ZeroCopySend)]
+struct CacheLineAligned<T: ZeroCopySend>(T);
+
+impl<T: ZeroCopySend> core::ops::Deref for CacheLineAligned<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Runtime statistics of a connected [`Publisher`](crate::port::publisher::Publisher)
+/// that are updated by the [`Publisher`](crate::port::publisher::Publisher) itself and
+/// can be observed by every process that has opened the
+/// [`Service`](crate::service::Service).
+#[repr(C)]
+#[repr(align(64))]
+#[derive(Debug, ZeroCopySend)]
+pub struct PublisherStatistics {
+    number_of_sent_samples: AtomicU64,
+    number_of_samples_in_use: AtomicU64,
+}
+
+impl PublisherStatistics {
+    fn new() -> Self {
+        Self {
+            number_of_sent_samples: AtomicU64::new(0),
+            number_of_samples_in_use: AtomicU64::new(0),
+        }
+    }
+
+    fn reset(&self) {
+        self.number_of_sent_samples.store(0, Ordering::Relaxed);
+        self.number_of_samples_in_use.store(0, Ordering::Relaxed);
+    }
+
+    pub(crate) fn increment_sent_samples(&self) {
+        self.number_of_sent_samples.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn set_samples_in_use(&self, value: usize) {
+        self.number_of_samples_in_use
+            .store(value as u64, Ordering::Relaxed);
+    }
+
+    /// Returns the total number of samples the
+    /// [`Publisher`](crate::port::publisher::Publisher) has sent.
+    pub fn number_of_sent_samples(&self) -> u64 {
+        self.number_of_sent_samples.load(Ordering::Relaxed)
+    }
+
+    /// Returns how many samples of the data segment were in use, either loaned by the
+    /// [`Publisher`](crate::port::publisher::Publisher) or held by
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)s, when the
+    /// [`Publisher`](crate::port::publisher::Publisher) sent its last sample.
+    pub fn number_of_samples_in_use(&self) -> u64 {
+        self.number_of_samples_in_use.load(Ordering::Relaxed)
+    }
+}
+
+/// Runtime statistics of a connected [`Subscriber`](crate::port::subscriber::Subscriber).
+/// The delivery counters are updated by the sending
+/// [`Publisher`](crate::port::publisher::Publisher)s, the receive counter by the
+/// [`Subscriber`](crate::port::subscriber::Subscriber) itself.
+#[repr(C)]
+#[repr(align(64))]
+#[derive(Debug, ZeroCopySend)]
+pub struct SubscriberStatistics {
+    number_of_delivered_samples: AtomicU64,
+    number_of_overflowed_samples: AtomicU64,
+    number_of_received_samples: CacheLineAligned<AtomicU64>,
+}
+
+impl SubscriberStatistics {
+    fn new() -> Self {
+        Self {
+            number_of_delivered_samples: AtomicU64::new(0),
+            number_of_overflowed_samples: AtomicU64::new(0),
+            number_of_received_samples: CacheLineAligned(AtomicU64::new(0)),
+        }
+    }
+
+    fn reset(&self) {
+        self.number_of_delivered_samples.store(0, Ordering::Relaxed);
+        self.number_of_overflowed_samples
+            .store(0, Ordering::Relaxed);
+        self.number_of_received_samples.store(0, Ordering::Relaxed);
+    }
+
+    pub(crate) fn increment_delivered_samples(&self, has_overflowed: bool) {
+        self.number_of_delivered_samples
+            .fetch_add(1, Ordering::Relaxed);
+        if has_overflowed {
+            self.number_of_overflowed_samples
+                .fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    pub(crate) fn increment_received_samples(&self) {
+        self.number_of_received_samples
+            .fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Returns the total number of samples that were delivered into the receive buffer of
+    /// the [`Subscriber`](crate::port::subscriber::Subscriber).
+    pub fn number_of_delivered_samples(&self) -> u64 {
+        self.number_of_delivered_samples.load(Ordering::Relaxed)
+    }
+
+    /// Returns the total number of samples that were discarded since the receive buffer of
+    /// the [`Subscriber`](crate::port::subscriber::Subscriber) was full.
+    pub fn number_of_overflowed_samples(&self) -> u64 {
+        self.number_of_overflowed_samples.load(Ordering::Relaxed)
+    }
+
+    /// Returns the total number of samples the
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) has received.
+    pub fn number_of_received_samples(&self) -> u64 {
+        self.number_of_received_samples.load(Ordering::Relaxed)
+    }
+
+    /// Returns an estimate of how many samples are currently stored in the receive buffer
+    /// of the [`Subscriber`](crate::port::subscriber::Subscriber). Samples that were
+    /// delivered as history when the connection was established are not accounted for.
+    pub fn number_of_buffered_samples(&self) -> u64 {
+        self.number_of_delivered_samples()
+            .saturating_sub(self.number_of_overflowed_samples())
+            .saturating_sub(self.number_of_received_samples())
+    }
+}
+
 /// The dynamic configuration of an
 /// [`crate::service::messaging_pattern::MessagingPattern::PublishSubscribe`]
 /// based service. Contains dynamic parameters like the connected endpoints etc..
@@ -92,6 +235,8 @@ pub struct SubscriberDetails {
 pub struct DynamicConfig {
     pub(crate) subscribers: Container<SubscriberDetails>,
     pub(crate) publishers: Container<PublisherDetails>,
+    subscriber_statistics: RelocatableVec<SubscriberStatistics>,
+    publisher_statistics: RelocatableVec<PublisherStatistics>,
 }
 
 impl DynamicConfig {
@@ -99,6 +244,12 @@ impl DynamicConfig {
         Self {
             subscribers: unsafe { Container::new_uninit(config.number_of_subscribers) },
             publishers: unsafe { Container::new_uninit(config.number_of_publishers) },
+            subscriber_statistics: unsafe {
+                RelocatableVec::new_uninit(config.number_of_subscribers)
+            },
+            publisher_statistics: unsafe {
+                RelocatableVec::new_uninit(config.number_of_publishers)
+            },
         }
     }
 
@@ -110,12 +261,32 @@ impl DynamicConfig {
             fatal_panic!(from self,
             when self.publishers.init(allocator),
             "This should never happen! Unable to initialize publisher port id container.");
+            fatal_panic!(from self,
+            when self.subscriber_statistics.init(allocator),
+            "This should never happen! Unable to initialize subscriber statistics container.");
+            fatal_panic!(from self,
+            when self.publisher_statistics.init(allocator),
+            "This should never happen! Unable to initialize publisher statistics container.");
+        }
+
+        while !self.subscriber_statistics.is_full() {
+            // cannot fail since the vector is not full
+            let _ = self.subscriber_statistics.push(SubscriberStatistics::new());
+        }
+
+        while !self.publisher_statistics.is_full() {
+            // cannot fail since the vector is not full
+            let _ = self.publisher_statistics.push(PublisherStatistics::new());
         }
     }
 
     pub(crate) fn memory_size(config: &DynamicConfigSettings) -> usize {
         Container::<SubscriberDetails>::memory_size(config.number_of_subscribers)
             + Container::<PublisherDetails>::memory_size(config.number_of_publishers)
+            + RelocatableVec::<SubscriberStatistics>::const_memory_size(
+                config.number_of_subscribers,
+            )
+            + RelocatableVec::<PublisherStatistics>::const_memory_size(config.number_of_publishers)
     }
 
     pub(crate) unsafe fn remove_dead_node_id<
@@ -188,12 +359,52 @@ impl DynamicConfig {
         state.for_each(|_, details| callback(details));
     }
 
+    /// Iterates over all [`Publisher`](crate::port::publisher::Publisher)s and calls the
+    /// callback with the corresponding [`PublisherDetails`] and [`PublisherStatistics`].
+    /// The callback shall return [`CallbackProgression::Continue`] when the iteration shall
+    /// continue otherwise [`CallbackProgression::Stop`].
+    pub fn list_publisher_statistics<
+        F: FnMut(&PublisherDetails, &PublisherStatistics) -> CallbackProgression,
+    >(
+        &self,
+        mut callback: F,
+    ) {
+        let state = unsafe { self.publishers.get_state() };
+
+        state.for_each(|index, details| callback(details, &self.publisher_statistics[index]));
+    }
+
+    /// Iterates over all [`Subscriber`](crate::port::subscriber::Subscriber)s and calls the
+    /// callback with the corresponding [`SubscriberDetails`] and [`SubscriberStatistics`].
+    /// The callback shall return [`CallbackProgression::Continue`] when the iteration shall
+    /// continue otherwise [`CallbackProgression::Stop`].
+    pub fn list_subscriber_statistics<
+        F: FnMut(&SubscriberDetails, &SubscriberStatistics) -> CallbackProgression,
+    >(
+        &self,
+        mut callback: F,
+    ) {
+        let state = unsafe { self.subscribers.get_state() };
+
+        state.for_each(|index, details| callback(details, &self.subscriber_statistics[index]));
+    }
+
+    pub(crate) fn publisher_statistics(&self, index: usize) -> &PublisherStatistics {
+        &self.publisher_statistics[index]
+    }
+
+    pub(crate) fn subscriber_statistics(&self, index: usize) -> &SubscriberStatistics {
+        &self.subscriber_statistics[index]
+    }
+
     pub(crate) fn add_subscriber_id(&self, details: SubscriberDetails) -> Option<ContainerHandle> {
-        unsafe {
+        let handle = unsafe {
             self.subscribers
                 .add(details, details.node_id.owner_id())
                 .ok()
-        }
+        }?;
+        self.subscriber_statistics[handle.index()].reset();
+        Some(handle)
     }
 
     pub(crate) fn release_subscriber_handle(&self, handle: ContainerHandle) {
@@ -203,11 +414,13 @@ impl DynamicConfig {
     }
 
     pub(crate) fn add_publisher_id(&self, details: PublisherDetails) -> Option<ContainerHandle> {
-        unsafe {
+        let handle = unsafe {
             self.publishers
                 .add(details, details.node_id.owner_id())
                 .ok()
-        }
+        }?;
+        self.publisher_statistics[handle.index()].reset();
+        Some(handle)
     }
 
     pub(crate) fn release_publisher_handle(&self, handle: ContainerHandle) {
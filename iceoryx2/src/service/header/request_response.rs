@@ -67,6 +67,9 @@ pub struct ResponseHeader {
     pub(crate) server_id: UniqueServerId,
     pub(crate) request_id: RequestId,
     pub(crate) number_of_elements: u64,
+    pub(crate) sequence_number: u64,
+    pub(crate) status_code: u64,
+    pub(crate) is_end_of_stream: bool,
 }
 
 impl ResponseHeader {
@@ -95,4 +98,15 @@ impl ResponseHeader {
     pub fn number_of_elements(&self) -> u64 {
         self.number_of_elements
     }
+
+    /// Returns the position of the [`Response`](crate::response::Response) in the stream of
+    /// responses of the corresponding
+    /// [`ActiveRequest`](crate::active_request::ActiveRequest). The first
+    /// [`Response`](crate::response::Response) has the sequence number 0 and every further
+    /// [`Response`](crate::response::Response) increments it by one. A gap between two
+    /// consecutively received sequence numbers means that responses were lost, for instance
+    /// since they were overridden in a buffer with safe overflow.
+    pub fn sequence_number(&self) -> u64 {
+        self.sequence_number
+    }
 }
@@ -50,6 +50,7 @@ use crate::service::builder::CustomKeyMarker;
 use crate::service::builder::blackboard::{BlackboardResources, KeyMemory};
 use crate::service::service_hash::ServiceHash;
 use crate::service::service_name::ServiceName;
+use crate::service::static_config::message_type_details::TypeDetail;
 use crate::service::{self, ServiceState, SharedServiceState, dynamic_config, static_config};
 use core::fmt::Debug;
 use core::hash::Hash;
@@ -238,4 +239,23 @@ impl<Service: service::Service> PortFactory<Service, CustomKeyMarker> {
             .map
             .list_keys(|key: &KeyMemory<MAX_BLACKBOARD_KEY_SIZE>| callback(key.data.as_ptr()));
     }
+
+    /// Iterates over all entries of the blackboard and calls the provided callback with the
+    /// key and the [`TypeDetail`] of the value. Used where the value types are not known at
+    /// compile time.
+    #[doc(hidden)]
+    pub fn __internal_list_entries<F: FnMut(*const u8, &TypeDetail) -> CallbackProgression>(
+        &self,
+        mut callback: F,
+    ) {
+        let resources = self.service.additional_resource();
+        let mgmt = resources.mgmt.get();
+        mgmt.map
+            .list_keys(|key: &KeyMemory<MAX_BLACKBOARD_KEY_SIZE>| {
+                match unsafe { mgmt.map.__internal_get(key, resources.key_eq_func.as_ref()) } {
+                    Some(index) => callback(key.data.as_ptr(), &mgmt.entries[index].type_details),
+                    None => CallbackProgression::Continue,
+                }
+            });
+    }
 }
@@ -22,13 +22,24 @@
 //!     .open_or_create()?;
 //!
 //! let listener = event.listener_builder().create()?;
+//!
+//! // a listener that is only woken up by the EventIds 8 to 15 of one specific notifier
+//! let notifier = event.notifier_builder().create()?;
+//! let filtered_listener = event
+//!     .listener_builder()
+//!     .event_id_filter(EventIdFilter::new().range(EventId::new(8), EventId::new(15)))
+//!     .allow_notifier(notifier.id())
+//!     .create()?;
 //! # Ok(())
 //! # }
 //! ```
 use core::fmt::Debug;
 
+use alloc::vec::Vec;
 use iceoryx2_log::fail;
 
+use crate::identifiers::UniqueNotifierId;
+use crate::port::event_id::EventIdFilter;
 use crate::port::{listener::Listener, listener::ListenerCreateError};
 use crate::service;
 
@@ -40,15 +51,47 @@ use super::event::PortFactory;
 #[derive(Debug, Clone)]
 pub struct PortFactoryListener<'factory, Service: service::Service> {
     pub(crate) factory: &'factory PortFactory<Service>,
+    event_id_filter: EventIdFilter,
+    allowed_notifiers: Vec<UniqueNotifierId>,
 }
 
 unsafe impl<Service: service::Service> Send for PortFactoryListener<'_, Service> {}
 
-impl<Service: service::Service> PortFactoryListener<'_, Service> {
+impl<'factory, Service: service::Service> PortFactoryListener<'factory, Service> {
+    pub(crate) fn new(factory: &'factory PortFactory<Service>) -> Self {
+        Self {
+            factory,
+            event_id_filter: EventIdFilter::new(),
+            allowed_notifiers: Vec::new(),
+        }
+    }
+
+    /// Defines which [`EventId`](crate::prelude::EventId)s the [`Listener`] accepts. The
+    /// [`Listener`] is not woken up by notifications with an [`EventId`](crate::prelude::EventId)
+    /// that does not pass the [`EventIdFilter`]. By default, all
+    /// [`EventId`](crate::prelude::EventId)s are accepted.
+    pub fn event_id_filter(mut self, filter: EventIdFilter) -> Self {
+        self.event_id_filter = filter;
+        self
+    }
+
+    /// Adds the [`UniqueNotifierId`] of a [`Notifier`](crate::port::notifier::Notifier) from
+    /// which the [`Listener`] accepts notifications. When no
+    /// [`Notifier`](crate::port::notifier::Notifier) is added, notifications from all
+    /// [`Notifier`](crate::port::notifier::Notifier)s are accepted. At most
+    /// [`MAX_NUMBER_OF_ALLOWED_NOTIFIERS`](crate::service::dynamic_config::event::MAX_NUMBER_OF_ALLOWED_NOTIFIERS)
+    /// [`Notifier`](crate::port::notifier::Notifier)s can be added.
+    pub fn allow_notifier(mut self, notifier_id: UniqueNotifierId) -> Self {
+        if !self.allowed_notifiers.contains(&notifier_id) {
+            self.allowed_notifiers.push(notifier_id);
+        }
+        self
+    }
+
     /// Creates the [`Listener`] port or returns a [`ListenerCreateError`] on failure.
     pub fn create(self) -> Result<Listener<Service>, ListenerCreateError> {
         Ok(
-            fail!(from self, when Listener::new(self.factory.service.clone()),
+            fail!(from self, when Listener::new(self.factory.service.clone(), self.event_id_filter, &self.allowed_notifiers),
                     "Failed to create new Listener port."),
         )
     }
@@ -70,6 +70,11 @@ impl StaticConfig {
         self.max_readers
     }
 
+    /// Returns the maximum supported amount of [`crate::port::writer::Writer`] ports
+    pub fn max_writers(&self) -> usize {
+        self.max_writers
+    }
+
     /// Returns the type details of the [`crate::service::Service`].
     pub fn type_details(&self) -> &TypeDetail {
         &self.type_details
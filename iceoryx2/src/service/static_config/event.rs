@@ -28,6 +28,7 @@
 //! println!("notifier created event:       {:?}", event.static_config().notifier_created_event());
 //! println!("notifier dropped event:       {:?}", event.static_config().notifier_dropped_event());
 //! println!("notifier dead event:          {:?}", event.static_config().notifier_dead_event());
+//! println!("notification data size:       {:?}", event.static_config().notification_data_size());
 //!
 //! # Ok(())
 //! # }
@@ -62,6 +63,9 @@ pub struct StaticConfig {
     pub(crate) notifier_created_event: RelocatableOption<usize>,
     pub(crate) notifier_dropped_event: RelocatableOption<usize>,
     pub(crate) notifier_dead_event: RelocatableOption<usize>,
+    // services that were created without notification data do not store the size
+    #[serde(default)]
+    pub(crate) notification_data_size: usize,
 }
 
 impl StaticConfig {
@@ -83,6 +87,7 @@ impl StaticConfig {
             notifier_created_event: config.defaults.event.notifier_created_event.into(),
             notifier_dropped_event: config.defaults.event.notifier_dropped_event.into(),
             notifier_dead_event: config.defaults.event.notifier_dead_event.into(),
+            notification_data_size: 0,
         }
     }
 
@@ -136,4 +141,11 @@ impl StaticConfig {
             .as_option_ref()
             .map(|v| EventId::new(*v))
     }
+
+    /// Returns the maximum size in bytes of the data that can be sent together with a
+    /// notification via [`Notifier::notify_with_data()`](crate::port::notifier::Notifier::notify_with_data()).
+    /// If it is zero, notifications cannot carry any data.
+    pub fn notification_data_size(&self) -> usize {
+        self.notification_data_size
+    }
 }
@@ -10,6 +10,7 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use alloc::vec::Vec;
 use core::alloc::Layout;
 
 use iceoryx2_bb_container::string::*;
@@ -63,6 +64,26 @@ pub struct TypeDetail {
     pub(crate) type_name: TypeName,
     pub(crate) size: usize,
     pub(crate) alignment: usize,
+    #[serde(default, with = "layout_hash_serde")]
+    pub(crate) layout_hash: u64,
+}
+
+// TOML supports only signed 64-bit integers, therefore the hash is stored as hex string
+mod layout_hash_serde {
+    use alloc::format;
+    use alloc::string::String;
+    use serde::{Deserialize, Deserializer, Serializer, de::Error};
+
+    pub(super) fn serialize<S: Serializer>(value: &u64, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("{value:#018x}"))
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        let digits = value.strip_prefix("0x").unwrap_or(&value);
+        u64::from_str_radix(digits, 16)
+            .map_err(|e| D::Error::custom(format!("invalid layout hash \"{value}\" ({e})")))
+    }
 }
 
 impl TypeDetail {
@@ -80,6 +101,7 @@ impl TypeDetail {
             },
             size: core::mem::size_of::<T>(),
             alignment: core::mem::align_of::<T>(),
+            layout_hash: T::TYPE_LAYOUT_HASH,
         }
     }
 
@@ -103,6 +125,253 @@ impl TypeDetail {
     pub fn alignment(&self) -> usize {
         self.alignment
     }
+
+    /// The hash of the structural layout of the underlying type, see
+    /// [`ZeroCopySend::TYPE_LAYOUT_HASH`]. Returns [`None`] when the type does not provide
+    /// structural layout information, for instance when it is defined in another language.
+    pub fn layout_hash(&self) -> Option<u64> {
+        match self.layout_hash {
+            0 => None,
+            v => Some(v),
+        }
+    }
+
+    /// Returns true when both types are equal. The layout hashes are only compared when both
+    /// types provide one.
+    pub(crate) fn is_identical_to(&self, rhs: &Self) -> bool {
+        let layouts_are_equal = match (self.layout_hash(), rhs.layout_hash()) {
+            (Some(lhs), Some(rhs)) => lhs == rhs,
+            _ => true,
+        };
+
+        self.variant == rhs.variant
+            && self.type_name == rhs.type_name
+            && self.size == rhs.size
+            && self.alignment == rhs.alignment
+            && layouts_are_equal
+    }
+
+    // self is the requested type detail
+    fn compare(
+        &self,
+        offered: &Self,
+        part: &'static str,
+        policy: TypeCompatibilityPolicy,
+        comparison: &mut TypeComparison,
+    ) {
+        if self.variant != offered.variant {
+            comparison.reject(
+                part,
+                TypeDetailDifference::Variant {
+                    offered: offered.variant,
+                    requested: self.variant,
+                },
+            );
+        }
+
+        if self.size != offered.size {
+            comparison.reject(
+                part,
+                TypeDetailDifference::Size {
+                    offered: offered.size,
+                    requested: self.size,
+                },
+            );
+        }
+
+        if self.alignment > offered.alignment {
+            comparison.reject(
+                part,
+                TypeDetailDifference::Alignment {
+                    offered: offered.alignment,
+                    requested: self.alignment,
+                },
+            );
+        }
+
+        let name_difference =
+            (self.type_name != offered.type_name).then_some(TypeDetailDifference::TypeName {
+                offered: offered.type_name,
+                requested: self.type_name,
+            });
+
+        let (requested_hash, offered_hash) = match (self.layout_hash(), offered.layout_hash()) {
+            (Some(requested), Some(offered)) => (requested, offered),
+            // without layout information only the names can be compared
+            _ => {
+                if let Some(difference) = name_difference {
+                    comparison.reject(part, difference);
+                }
+                return;
+            }
+        };
+
+        let layout_difference =
+            (requested_hash != offered_hash).then_some(TypeDetailDifference::LayoutHash {
+                offered: offered_hash,
+                requested: requested_hash,
+            });
+
+        let (rejected, tolerated) = match policy {
+            TypeCompatibilityPolicy::Strict => ([name_difference, layout_difference], [None, None]),
+            TypeCompatibilityPolicy::NameOnly => {
+                ([name_difference, None], [None, layout_difference])
+            }
+            TypeCompatibilityPolicy::LayoutOnly => {
+                ([None, layout_difference], [name_difference, None])
+            }
+        };
+
+        for difference in rejected.into_iter().flatten() {
+            comparison.reject(part, difference);
+        }
+
+        for difference in tolerated.into_iter().flatten() {
+            comparison.tolerate(part, difference);
+        }
+    }
+}
+
+/// Defines which properties of the types of an existing [`Service`](crate::service::Service)
+/// must be equal to the requested types when it is opened. Independent of the policy, the
+/// [`TypeVariant`] and the size must be equal and the existing [`Service`](crate::service::Service)
+/// must provide at least the requested alignment.
+///
+/// When one of the types does not provide structural layout information, see
+/// [`TypeDetail::layout_hash()`], the layouts cannot be compared and the type names must be equal.
+#[derive(Default, Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum TypeCompatibilityPolicy {
+    /// The type names and the structural layouts must be equal.
+    #[default]
+    Strict,
+    /// The type names must be equal, a different structural layout is reported as warning.
+    NameOnly,
+    /// The structural layouts must be equal, a different type name is reported as warning. Allows
+    /// to rename a type without breaking its consumers.
+    LayoutOnly,
+}
+
+/// A property in which the requested [`TypeDetail`] differs from the [`TypeDetail`] that is
+/// offered by an existing [`Service`](crate::service::Service).
+#[allow(clippy::large_enum_variant)] // `Box` is not allowed in a mission-critical context
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum TypeDetailDifference {
+    /// The [`TypeVariant`]s differ.
+    Variant {
+        /// The [`TypeVariant`] of the existing service
+        offered: TypeVariant,
+        /// The requested [`TypeVariant`]
+        requested: TypeVariant,
+    },
+    /// The type names differ.
+    TypeName {
+        /// The type name of the existing service
+        offered: TypeName,
+        /// The requested type name
+        requested: TypeName,
+    },
+    /// The sizes differ.
+    Size {
+        /// The size of the existing service
+        offered: usize,
+        /// The requested size
+        requested: usize,
+    },
+    /// The existing service provides a smaller alignment than requested.
+    Alignment {
+        /// The alignment of the existing service
+        offered: usize,
+        /// The requested alignment
+        requested: usize,
+    },
+    /// The structural layouts differ.
+    LayoutHash {
+        /// The layout hash of the existing service
+        offered: u64,
+        /// The requested layout hash
+        requested: u64,
+    },
+}
+
+impl core::fmt::Display for TypeDetailDifference {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Variant { offered, requested } => {
+                write!(f, "variant {requested:?} requested but {offered:?} offered")
+            }
+            Self::TypeName { offered, requested } => {
+                write!(
+                    f,
+                    "type name \"{requested}\" requested but \"{offered}\" offered"
+                )
+            }
+            Self::Size { offered, requested } => {
+                write!(f, "size {requested} requested but {offered} offered")
+            }
+            Self::Alignment { offered, requested } => {
+                write!(f, "alignment {requested} requested but {offered} offered")
+            }
+            Self::LayoutHash { offered, requested } => {
+                write!(
+                    f,
+                    "layout hash {requested:#018x} requested but {offered:#018x} offered"
+                )
+            }
+        }
+    }
+}
+
+/// The result of [`MessageTypeDetails::compare()`], contains the differences that make the
+/// types incompatible and the differences that are tolerated by the
+/// [`TypeCompatibilityPolicy`].
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub(crate) struct TypeComparison {
+    incompatibilities: Vec<(&'static str, TypeDetailDifference)>,
+    tolerated_differences: Vec<(&'static str, TypeDetailDifference)>,
+}
+
+impl TypeComparison {
+    fn reject(&mut self, part: &'static str, difference: TypeDetailDifference) {
+        self.incompatibilities.push((part, difference));
+    }
+
+    fn tolerate(&mut self, part: &'static str, difference: TypeDetailDifference) {
+        self.tolerated_differences.push((part, difference));
+    }
+
+    pub(crate) fn is_compatible(&self) -> bool {
+        self.incompatibilities.is_empty()
+    }
+
+    pub(crate) fn incompatibilities(&self) -> TypeDifferences<'_> {
+        TypeDifferences(&self.incompatibilities)
+    }
+
+    pub(crate) fn tolerated_differences(&self) -> TypeDifferences<'_> {
+        TypeDifferences(&self.tolerated_differences)
+    }
+}
+
+/// Formats a list of differences in the form "payload: size 8 requested but 16 offered; ..."
+pub(crate) struct TypeDifferences<'a>(&'a [(&'static str, TypeDetailDifference)]);
+
+impl TypeDifferences<'_> {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl core::fmt::Display for TypeDifferences<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for (n, (part, difference)) in self.0.iter().enumerate() {
+            if n != 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{part}: {difference}")?;
+        }
+
+        Ok(())
+    }
 }
 
 /// Contains all type information to the header and payload type.
@@ -159,16 +428,26 @@ impl MessageTypeDetails {
         }
     }
 
-    pub(crate) fn is_compatible_to(&self, rhs: &Self) -> bool {
-        self.header == rhs.header
-            && self.user_header.type_name == rhs.user_header.type_name
-            && self.user_header.variant == rhs.user_header.variant
-            && self.user_header.size == rhs.user_header.size
-            && self.user_header.alignment <= rhs.user_header.alignment
-            && self.payload.type_name == rhs.payload.type_name
-            && self.payload.variant == rhs.payload.variant
-            && self.payload.size == rhs.payload.size
-            && self.payload.alignment <= rhs.payload.alignment
+    /// Compares the requested types, `self`, with the types `offered` by an existing service.
+    pub(crate) fn compare(
+        &self,
+        offered: &Self,
+        policy: TypeCompatibilityPolicy,
+    ) -> TypeComparison {
+        let mut comparison = TypeComparison::default();
+        // the header is defined by iceoryx2 and must always be equal
+        self.header.compare(
+            &offered.header,
+            "header",
+            TypeCompatibilityPolicy::Strict,
+            &mut comparison,
+        );
+        self.user_header
+            .compare(&offered.user_header, "user header", policy, &mut comparison);
+        self.payload
+            .compare(&offered.payload, "payload", policy, &mut comparison);
+
+        comparison
     }
 }
 
@@ -200,18 +479,21 @@ mod tests {
                 type_name: "i32".try_into().unwrap(),
                 size: 4,
                 alignment: 4, // i32 uses 4 bytes, so its aliment is always 4 no matter x32 or x64.
+                layout_hash: i32::TYPE_LAYOUT_HASH,
             },
             user_header: TypeDetail{
                 variant: TypeVariant::FixedSize,
                 type_name: "i64".try_into().unwrap(),
                 size: 8,
                 alignment: ALIGNMENT,
+                layout_hash: i64::TYPE_LAYOUT_HASH,
             },
             payload: TypeDetail{
                 variant: TypeVariant::FixedSize,
                 type_name: "iceoryx2::service::static_config::message_type_details::tests::test_from::MyPayload".try_into().unwrap(),
                 size: 16,
                 alignment: ALIGNMENT,
+                layout_hash: MyPayload::TYPE_LAYOUT_HASH,
             },
         };
         assert_that!(sut, eq expected);
@@ -223,18 +505,21 @@ mod tests {
                 type_name: "i32".try_into().unwrap(),
                 size: 4,
                 alignment: 4,
+                layout_hash: i32::TYPE_LAYOUT_HASH,
             },
             user_header: TypeDetail {
                 variant: TypeVariant::FixedSize,
                 type_name: "bool".try_into().unwrap(),
                 size: 1,
                 alignment: 1,
+                layout_hash: bool::TYPE_LAYOUT_HASH,
             },
             payload: TypeDetail {
                 variant: TypeVariant::Dynamic,
                 type_name: "i64".try_into().unwrap(),
                 size: 8,
                 alignment: ALIGNMENT,
+                layout_hash: i64::TYPE_LAYOUT_HASH,
             },
         };
         assert_that!(sut, eq expected);
@@ -360,12 +645,16 @@ mod tests {
     fn test_is_compatible_to_failed_when_types_differ() {
         let left = MessageTypeDetails::from::<i64, i64, i8>(TypeVariant::FixedSize);
         let right = MessageTypeDetails::from::<i64, i64, u8>(TypeVariant::FixedSize);
-        let sut = left.is_compatible_to(&right);
+        let sut = left
+            .compare(&right, TypeCompatibilityPolicy::Strict)
+            .is_compatible();
         assert_that!(sut, eq false);
 
         let left = MessageTypeDetails::from::<i64, i64, i64>(TypeVariant::FixedSize);
         let right = MessageTypeDetails::from::<i64, i64, i32>(TypeVariant::FixedSize);
-        let sut = left.is_compatible_to(&right);
+        let sut = left
+            .compare(&right, TypeCompatibilityPolicy::Strict)
+            .is_compatible();
         assert_that!(sut, eq false);
     }
 
@@ -381,26 +670,33 @@ mod tests {
                 type_name: "i64".try_into().unwrap(),
                 size: 8,
                 alignment: ALIGNMENT,
+                layout_hash: 0,
             },
             user_header: TypeDetail {
                 variant: TypeVariant::FixedSize,
                 type_name: "i64".try_into().unwrap(),
                 size: 8,
                 alignment: 2 * ALIGNMENT,
+                layout_hash: 0,
             },
             payload: TypeDetail {
                 variant: TypeVariant::FixedSize,
                 type_name: "i64".try_into().unwrap(),
                 size: 8,
                 alignment: 2 * ALIGNMENT,
+                layout_hash: 0,
             },
         };
         // smaller to bigger is allowed.
-        let sut = left.is_compatible_to(&right);
+        let sut = left
+            .compare(&right, TypeCompatibilityPolicy::Strict)
+            .is_compatible();
         assert_that!(sut, eq true);
 
         // bigger to smaller is invalid.
-        let sut = right.is_compatible_to(&left);
+        let sut = right
+            .compare(&left, TypeCompatibilityPolicy::Strict)
+            .is_compatible();
         assert_that!(sut, eq false);
     }
 
@@ -416,22 +712,132 @@ mod tests {
                 type_name: "i64".try_into().unwrap(),
                 size: 8,
                 alignment: ALIGNMENT,
+                layout_hash: 0,
             },
             user_header: TypeDetail {
                 variant: TypeVariant::FixedSize,
                 type_name: "i64".try_into().unwrap(),
                 size: 8,
                 alignment: 2 * ALIGNMENT,
+                layout_hash: 0,
             },
             payload: TypeDetail {
                 variant: TypeVariant::FixedSize,
                 type_name: "i64".try_into().unwrap(),
                 size: 8,
                 alignment: 2 * ALIGNMENT,
+                layout_hash: 0,
             },
         };
         // bigger to smaller is invalid.
-        let sut = right.is_compatible_to(&left);
+        let sut = right
+            .compare(&left, TypeCompatibilityPolicy::Strict)
+            .is_compatible();
         assert_that!(sut, eq false);
     }
+
+    fn details_with_payload(type_name: &str, layout_hash: u64) -> MessageTypeDetails {
+        let mut details = MessageTypeDetails::from::<i64, i64, i64>(TypeVariant::FixedSize);
+        details.payload.type_name = type_name.try_into().unwrap();
+        details.payload.layout_hash = layout_hash;
+        details
+    }
+
+    #[test]
+    fn test_compare_strict_policy_requires_equal_name_and_layout() {
+        let requested = details_with_payload("Message", 1234);
+
+        let sut = requested.compare(
+            &details_with_payload("Message", 1234),
+            TypeCompatibilityPolicy::Strict,
+        );
+        assert_that!(sut.is_compatible(), eq true);
+        assert_that!(sut.tolerated_differences().is_empty(), eq true);
+
+        let sut = requested.compare(
+            &details_with_payload("Message", 5678),
+            TypeCompatibilityPolicy::Strict,
+        );
+        assert_that!(sut.is_compatible(), eq false);
+        assert_that!(sut.incompatibilities, eq vec![("payload", TypeDetailDifference::LayoutHash { offered: 5678, requested: 1234 })]);
+
+        let sut = requested.compare(
+            &details_with_payload("Msg", 1234),
+            TypeCompatibilityPolicy::Strict,
+        );
+        assert_that!(sut.is_compatible(), eq false);
+    }
+
+    #[test]
+    fn test_compare_name_only_policy_tolerates_different_layout() {
+        let requested = details_with_payload("Message", 1234);
+
+        let sut = requested.compare(
+            &details_with_payload("Message", 5678),
+            TypeCompatibilityPolicy::NameOnly,
+        );
+        assert_that!(sut.is_compatible(), eq true);
+        assert_that!(sut.tolerated_differences, eq vec![("payload", TypeDetailDifference::LayoutHash { offered: 5678, requested: 1234 })]);
+
+        let sut = requested.compare(
+            &details_with_payload("Msg", 1234),
+            TypeCompatibilityPolicy::NameOnly,
+        );
+        assert_that!(sut.is_compatible(), eq false);
+    }
+
+    #[test]
+    fn test_compare_layout_only_policy_tolerates_different_name() {
+        let requested = details_with_payload("Message", 1234);
+
+        let sut = requested.compare(
+            &details_with_payload("Msg", 1234),
+            TypeCompatibilityPolicy::LayoutOnly,
+        );
+        assert_that!(sut.is_compatible(), eq true);
+        assert_that!(sut.tolerated_differences.len(), eq 1);
+
+        let sut = requested.compare(
+            &details_with_payload("Message", 5678),
+            TypeCompatibilityPolicy::LayoutOnly,
+        );
+        assert_that!(sut.is_compatible(), eq false);
+    }
+
+    #[test]
+    fn test_compare_falls_back_to_name_without_layout_information() {
+        let requested = details_with_payload("Message", 1234);
+
+        for policy in [
+            TypeCompatibilityPolicy::Strict,
+            TypeCompatibilityPolicy::NameOnly,
+            TypeCompatibilityPolicy::LayoutOnly,
+        ] {
+            let sut = requested.compare(&details_with_payload("Message", 0), policy);
+            assert_that!(sut.is_compatible(), eq true);
+
+            let sut = requested.compare(&details_with_payload("Msg", 0), policy);
+            assert_that!(sut.is_compatible(), eq false);
+        }
+    }
+
+    #[test]
+    fn test_compare_lists_all_differences() {
+        let requested = details_with_payload("Message", 1234);
+        let mut offered = details_with_payload("Msg", 5678);
+        offered.payload.size = 16;
+        offered.user_header.alignment = 1;
+
+        let sut = requested.compare(&offered, TypeCompatibilityPolicy::Strict);
+        assert_that!(sut.incompatibilities, len 4);
+        assert_that!(
+            sut.incompatibilities().to_string(),
+            eq format!(
+                "user header: alignment {ALIGNMENT} requested but 1 offered; \
+                 payload: size 8 requested but 16 offered; \
+                 payload: type name \"Message\" requested but \"Msg\" offered; \
+                 payload: layout hash 0x00000000000004d2 requested but 0x000000000000162e offered"
+            )
+        );
+    }
 }
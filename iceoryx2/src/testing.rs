@@ -14,10 +14,12 @@ use crate::identifiers::{UniqueNodeId, UniqueServiceId};
 use crate::node::global_management_segment::GlobalManagementSegment;
 use crate::node::{Node, NodeListFailure, NodeState};
 use crate::prelude::MessagingPattern;
+use crate::sample_mut::SampleMut;
 use crate::service::config_scheme::{
     dynamic_config_storage_config, port_tag_config, service_tag_config,
 };
 use crate::service::dynamic_config::DynamicConfig;
+use crate::service::header::publish_subscribe::Header;
 use crate::service::naming_scheme::dynamic_config_name;
 use crate::service::service_hash::ServiceHash;
 use crate::service::static_config;
@@ -28,8 +30,10 @@ use crate::{
 };
 use alloc::format;
 use alloc::string::ToString;
+use core::fmt::Debug;
 use iceoryx2_bb_container::string::String;
 use iceoryx2_bb_elementary::math::ToB64;
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 use iceoryx2_bb_posix::{config::TEST_DIRECTORY, testing::*};
 use iceoryx2_bb_system_types::file_name::*;
@@ -79,6 +83,7 @@ pub fn create_custom_type_detail(
         type_name,
         size,
         alignment,
+        layout_hash: 0,
     }
 }
 
@@ -98,6 +103,31 @@ pub fn type_detail_set_variant(v: &mut TypeDetail, value: TypeVariant) {
     v.variant = value;
 }
 
+pub fn type_detail_set_layout_hash(v: &mut TypeDetail, value: u64) {
+    v.layout_hash = value;
+}
+
+pub fn sample_mut_set_number_of_elements<
+    S: crate::service::Service,
+    Payload: Debug + ZeroCopySend + ?Sized,
+    UserHeader: ZeroCopySend,
+>(
+    sample: &mut SampleMut<S, Payload, UserHeader>,
+    value: u64,
+) {
+    let header = sample.ptr.as_header_mut();
+    *header = Header::new(header.node_id(), header.publisher_id(), value);
+}
+
+/// Blocks the notification data of the [`EventId`](crate::port::event_id::EventId) like a
+/// [`Notifier`](crate::port::notifier::Notifier) that died while writing it.
+pub fn event_abandon_notification_data_write(
+    dynamic_config: &crate::service::dynamic_config::event::DynamicConfig,
+    event_id: crate::port::event_id::EventId,
+) -> bool {
+    dynamic_config.abandon_notification_data_write(event_id.as_value())
+}
+
 pub fn create_service_tag<S: crate::service::Service>(
     node: &Node<S>,
     service_hash: &ServiceHash,
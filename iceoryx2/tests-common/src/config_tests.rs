@@ -0,0 +1,97 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time::Duration;
+
+use iceoryx2::config::{Config, ConfigCreationError};
+use iceoryx2_bb_posix::file::{CreationMode, FileBuilder};
+use iceoryx2_bb_posix::testing::generate_file_path;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing_macros::test;
+
+fn load_config(contents: &str) -> Result<Config, ConfigCreationError> {
+    let file_path = generate_file_path();
+    let mut file = FileBuilder::new(&file_path)
+        .has_ownership(true)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .create()
+        .unwrap();
+    assert_that!(file.write(contents.as_bytes()), is_ok);
+
+    Config::from_file(&file_path)
+}
+
+#[test]
+fn config_durations_can_be_written_with_units() {
+    let sut = load_config(
+        r#"
+        [global]
+        creation-timeout = "1.5s"
+
+        [defaults.event]
+        deadline = "250ms"
+        "#,
+    )
+    .unwrap();
+
+    assert_that!(sut.global.creation_timeout, eq Duration::from_millis(1500));
+    assert_that!(sut.defaults.event.deadline, eq Some(Duration::from_millis(250)));
+}
+
+#[test]
+fn config_durations_can_be_written_as_table() {
+    let sut = load_config(
+        r#"
+        [global.creation-timeout]
+        secs = 2
+        nanos = 500
+
+        [defaults.event.deadline]
+        secs = 3
+        nanos = 0
+        "#,
+    )
+    .unwrap();
+
+    assert_that!(sut.global.creation_timeout, eq Duration::new(2, 500));
+    assert_that!(sut.defaults.event.deadline, eq Some(Duration::from_secs(3)));
+}
+
+#[test]
+fn config_with_invalid_duration_unit_cannot_be_loaded() {
+    let sut = load_config(
+        r#"
+        [global]
+        creation-timeout = "2 fortnights"
+        "#,
+    );
+
+    assert_that!(sut.err(), eq Some(ConfigCreationError::UnableToDeserializeContents));
+}
+
+#[test]
+fn config_with_durations_can_be_serialized_and_deserialized() {
+    let mut config = Config::default();
+    config.global.creation_timeout = Duration::from_micros(1250);
+    config.defaults.event.deadline = Some(Duration::from_secs(60));
+
+    let contents = toml::to_string(&config).unwrap();
+    assert_that!(contents.contains("creation-timeout = \"1250us\""), eq true);
+    assert_that!(contents.contains("deadline = \"1min\""), eq true);
+
+    let sut = load_config(&contents).unwrap();
+    assert_that!(sut, eq config);
+
+    config.defaults.event.deadline = None;
+    let sut = load_config(&toml::to_string(&config).unwrap()).unwrap();
+    assert_that!(sut, eq config);
+}
@@ -16,8 +16,12 @@ extern crate alloc;
 extern crate iceoryx2_bb_loggers;
 
 pub mod attribute_tests;
+pub mod config_tests;
 pub mod node_name_tests;
 pub mod service_event_thread_safety_tests;
+
 pub mod service_publish_subscribe_thread_safety_tests;
+pub mod service_publish_subscribe_validation_tests;
 pub mod service_request_response_thread_safety_tests;
 pub mod service_static_config_tests;
+pub mod service_type_compatibility_tests;
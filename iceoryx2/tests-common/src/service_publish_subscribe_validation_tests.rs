@@ -0,0 +1,157 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::port::ReceiveValidatedError;
+use iceoryx2::prelude::*;
+use iceoryx2::testing::*;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing_macros::test;
+
+type ServiceType = ipc::Service;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, ZeroCopySend, Validate)]
+#[repr(C)]
+struct Message {
+    is_active: bool,
+    value: u64,
+}
+
+fn create_node() -> Node<ServiceType> {
+    let config = generate_isolated_config();
+    NodeBuilder::new()
+        .config(&config)
+        .create::<ServiceType>()
+        .unwrap()
+}
+
+#[test]
+fn receive_validated_returns_valid_samples() {
+    let node = create_node();
+    let service = node
+        .service_builder(&generate_service_name())
+        .publish_subscribe::<Message>()
+        .create()
+        .unwrap();
+    let publisher = service.publisher_builder().create().unwrap();
+    let subscriber = service.subscriber_builder().create().unwrap();
+
+    assert_that!(subscriber.receive_validated().unwrap(), is_none);
+
+    let message = Message {
+        is_active: true,
+        value: 8912,
+    };
+    assert_that!(publisher.send_copy(message), is_ok);
+
+    let sample = subscriber.receive_validated().unwrap();
+    assert_that!(sample, is_some);
+    assert_that!(*sample.unwrap(), eq message);
+}
+
+#[test]
+fn receive_validated_rejects_invalid_payload() {
+    let node = create_node();
+    let service = node
+        .service_builder(&generate_service_name())
+        .publish_subscribe::<Message>()
+        .subscriber_max_borrowed_samples(1)
+        .subscriber_max_buffer_size(2)
+        .create()
+        .unwrap();
+    let publisher = service
+        .publisher_builder()
+        .max_loaned_samples(2)
+        .create()
+        .unwrap();
+    let subscriber = service.subscriber_builder().create().unwrap();
+
+    for _ in 0..2 {
+        let mut sample = publisher.loan_uninit().unwrap();
+        unsafe { sample.payload_mut().as_mut_ptr().cast::<u8>().write(2) };
+        assert_that!(unsafe { sample.assume_init() }.send(), is_ok);
+    }
+
+    let result = subscriber.receive_validated();
+    assert_that!(result.err(), eq Some(ReceiveValidatedError::InvalidPayload(ValidationError::InvalidBitPattern)));
+
+    // the invalid sample was released and does not count as borrowed
+    assert_that!(subscriber.receive_validated().err(), eq Some(ReceiveValidatedError::InvalidPayload(ValidationError::InvalidBitPattern)));
+    assert_that!(subscriber.receive_validated().unwrap(), is_none);
+}
+
+#[test]
+fn receive_validated_rejects_invalid_user_header() {
+    let node = create_node();
+    let service = node
+        .service_builder(&generate_service_name())
+        .publish_subscribe::<[u64]>()
+        .user_header::<Message>()
+        .create()
+        .unwrap();
+    let publisher = service
+        .publisher_builder()
+        .initial_max_slice_len(4)
+        .create()
+        .unwrap();
+    let subscriber = service.subscriber_builder().create().unwrap();
+
+    let mut sample = publisher.loan_slice(4).unwrap();
+    unsafe {
+        (sample.user_header_mut() as *mut Message)
+            .cast::<u8>()
+            .write(3)
+    };
+    assert_that!(sample.send(), is_ok);
+    let sample = publisher.loan_slice_uninit(3).unwrap();
+    assert_that!(sample.write_from_slice(&[1, 2, 3]).send(), is_ok);
+
+    let result = subscriber.receive_validated();
+    assert_that!(result.err(), eq Some(ReceiveValidatedError::InvalidUserHeader(ValidationError::InvalidBitPattern)));
+
+    let sample = subscriber.receive_validated().unwrap().unwrap();
+    assert_that!(sample.payload(), eq & [1, 2, 3]);
+}
+
+#[test]
+fn receive_validated_rejects_number_of_elements_exceeding_the_chunk() {
+    const MAX_SLICE_LEN: usize = 4;
+    let node = create_node();
+    let service = node
+        .service_builder(&generate_service_name())
+        .publish_subscribe::<[u64]>()
+        .create()
+        .unwrap();
+    let subscriber = service.subscriber_builder().create().unwrap();
+
+    for allocation_strategy in [AllocationStrategy::Static, AllocationStrategy::PowerOfTwo] {
+        let publisher = service
+            .publisher_builder()
+            .initial_max_slice_len(MAX_SLICE_LEN)
+            .allocation_strategy(allocation_strategy)
+            .create()
+            .unwrap();
+
+        for number_of_elements in [1 << 20, u64::MAX] {
+            let mut sample = publisher.loan_slice(MAX_SLICE_LEN).unwrap();
+            sample_mut_set_number_of_elements(&mut sample, number_of_elements);
+            assert_that!(sample.send(), is_ok);
+
+            let result = subscriber.receive_validated();
+            assert_that!(result.err(), eq Some(ReceiveValidatedError::InvalidPayload(ValidationError::OutOfBounds)));
+        }
+
+        let sample = publisher.loan_slice(MAX_SLICE_LEN).unwrap();
+        assert_that!(sample.send(), is_ok);
+        let sample = subscriber.receive_validated().unwrap().unwrap();
+        assert_that!(sample.payload(), len MAX_SLICE_LEN);
+    }
+}
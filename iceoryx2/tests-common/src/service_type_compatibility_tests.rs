@@ -0,0 +1,167 @@
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::prelude::*;
+use iceoryx2::service::builder::publish_subscribe::PublishSubscribeOpenError;
+use iceoryx2::service::builder::request_response::RequestResponseOpenError;
+use iceoryx2::testing::*;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing_macros::test;
+
+type ServiceType = ipc::Service;
+
+mod v1 {
+    use super::*;
+
+    #[derive(Debug, ZeroCopySend)]
+    #[type_name("Message")]
+    #[repr(C)]
+    pub struct Message {
+        pub x: u32,
+        pub y: u32,
+    }
+}
+
+mod v2 {
+    use super::*;
+
+    // same name and size but swapped fields
+    #[derive(Debug, ZeroCopySend)]
+    #[type_name("Message")]
+    #[repr(C)]
+    pub struct Message {
+        pub y: u32,
+        pub x: u32,
+    }
+
+    // same layout but renamed
+    #[derive(Debug, ZeroCopySend)]
+    #[type_name("RenamedMessage")]
+    #[repr(C)]
+    pub struct RenamedMessage {
+        pub x: u32,
+        pub y: u32,
+    }
+}
+
+fn create_node() -> Node<ServiceType> {
+    let config = generate_isolated_config();
+    NodeBuilder::new()
+        .config(&config)
+        .create::<ServiceType>()
+        .unwrap()
+}
+
+#[test]
+fn publish_subscribe_open_with_strict_policy_rejects_different_layout() {
+    let node = create_node();
+    let service_name = generate_service_name();
+    let _sut = node
+        .service_builder(&service_name)
+        .publish_subscribe::<v1::Message>()
+        .create()
+        .unwrap();
+
+    let result = node
+        .service_builder(&service_name)
+        .publish_subscribe::<v2::Message>()
+        .open();
+    assert_that!(result.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypes));
+
+    let result = node
+        .service_builder(&service_name)
+        .publish_subscribe::<v1::Message>()
+        .type_compatibility_policy(TypeCompatibilityPolicy::Strict)
+        .open();
+    assert_that!(result, is_ok);
+}
+
+#[test]
+fn publish_subscribe_open_with_name_only_policy_tolerates_different_layout() {
+    let node = create_node();
+    let service_name = generate_service_name();
+    let _sut = node
+        .service_builder(&service_name)
+        .publish_subscribe::<v1::Message>()
+        .create()
+        .unwrap();
+
+    let result = node
+        .service_builder(&service_name)
+        .publish_subscribe::<v2::Message>()
+        .type_compatibility_policy(TypeCompatibilityPolicy::NameOnly)
+        .open();
+    assert_that!(result, is_ok);
+
+    let result = node
+        .service_builder(&service_name)
+        .publish_subscribe::<v2::RenamedMessage>()
+        .type_compatibility_policy(TypeCompatibilityPolicy::NameOnly)
+        .open();
+    assert_that!(result.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypes));
+}
+
+#[test]
+fn publish_subscribe_open_with_layout_only_policy_tolerates_different_name() {
+    let node = create_node();
+    let service_name = generate_service_name();
+    let _sut = node
+        .service_builder(&service_name)
+        .publish_subscribe::<v1::Message>()
+        .create()
+        .unwrap();
+
+    let result = node
+        .service_builder(&service_name)
+        .publish_subscribe::<v2::RenamedMessage>()
+        .type_compatibility_policy(TypeCompatibilityPolicy::LayoutOnly)
+        .open();
+    assert_that!(result, is_ok);
+
+    let result = node
+        .service_builder(&service_name)
+        .publish_subscribe::<v2::Message>()
+        .type_compatibility_policy(TypeCompatibilityPolicy::LayoutOnly)
+        .open();
+    assert_that!(result.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypes));
+}
+
+#[test]
+fn request_response_open_respects_type_compatibility_policy() {
+    let node = create_node();
+    let service_name = generate_service_name();
+    let _sut = node
+        .service_builder(&service_name)
+        .request_response::<v1::Message, u64>()
+        .create()
+        .unwrap();
+
+    let result = node
+        .service_builder(&service_name)
+        .request_response::<v2::Message, u64>()
+        .open();
+    assert_that!(result.err(), eq Some(RequestResponseOpenError::IncompatibleRequestOrResponseType));
+
+    let result = node
+        .service_builder(&service_name)
+        .request_response::<v2::Message, u64>()
+        .type_compatibility_policy(TypeCompatibilityPolicy::NameOnly)
+        .open();
+    assert_that!(result, is_ok);
+
+    let result = node
+        .service_builder(&service_name)
+        .request_response::<v2::RenamedMessage, u64>()
+        .type_compatibility_policy(TypeCompatibilityPolicy::LayoutOnly)
+        .open();
+    assert_that!(result, is_ok);
+}